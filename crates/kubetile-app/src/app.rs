@@ -1,34 +1,70 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use ratatui::backend::Backend;
 use ratatui::Terminal;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Semaphore};
+use tokio_util::sync::DropGuard;
 
 use kubetile_core::informer::ResourceWatcher;
-use kubetile_core::{ContextResolver, ForwardId, KubeClient};
-use kubetile_tui::pane::{Pane, PaneId, ResourceKind, ViewType};
+use kubetile_core::{
+    AccessCheck, ClusterContext, ContainerImage, ContextResolver, DrainPlan, ForwardId, KubeClient, QueryEngine,
+    QuerySession, VolumeUsageMap,
+};
+use kubetile_tui::pane::{Pane, PaneCommand, PaneId, ResourceKind, ViewType};
 use kubetile_tui::tab::TabManager;
+use kubetile_tui::widgets::context_selector::ContextHealth;
 use kubetile_tui::widgets::toast::ToastMessage;
 
 use crate::command::Command;
 use crate::event::{AppEvent, EventHandler};
 use crate::keybindings::{InputMode, KeybindingDispatcher};
-use crate::panes::ResourceListPane;
+use crate::panes::resource_list::ResourceListViewState;
+use crate::panes::{LogHighlights, ResourceListPane};
 use crate::resource_switcher::ResourceSwitcher;
 
+mod access;
 mod actions;
+mod apply;
+mod connection;
+mod containers;
 mod context;
+mod dashboard;
+mod data_keys;
+mod dependency_graph;
+mod exec_sessions;
+mod frames;
+mod health;
+mod images;
+mod ingress_routes;
 mod input;
 mod logs_exec;
+mod memory;
+mod notifications;
 mod pane_ops;
+mod plugin_columns;
+mod plugins;
 mod port_forward;
 mod query;
+mod reconnect;
+mod related;
 mod render;
+mod rollouts;
+mod scripts;
+mod search;
+mod set_image;
+mod suspension;
 mod tabs;
+mod undo;
+mod usages;
+mod volume_stats;
+mod warning_events;
 mod watchers;
+mod welcome;
+mod workload_logs;
 
 #[allow(unused_imports)]
 use pane_ops::{find_item_index_by_identity, selected_resource_identity};
@@ -36,32 +72,81 @@ use pane_ops::{find_item_index_by_identity, selected_resource_identity};
 #[derive(Debug, Clone)]
 pub enum PendingAction {
     Delete { kind: ResourceKind, name: String, namespace: String },
+    EvictPod { name: String, namespace: String },
+    ForceDeletePod { name: String, namespace: String },
     SaveLogs { path: PathBuf, content: String },
     DownloadFullLogs { path: PathBuf, pod_name: String, namespace: String, container: Option<String> },
     ToggleDebugMode { name: String, namespace: String },
     ToggleRootDebugMode { name: String, namespace: String },
+    RestartRollout { name: String, namespace: String },
+    SetImage { kind: ResourceKind, name: String, namespace: String, images: Vec<ContainerImage> },
+    EditDataKey(data_keys::DataKeyPatch),
+    DrainNode(DrainPlan),
+    ApplyManifests { pane_id: PaneId },
+    UndoDelete { id: u64 },
     MutateCommand(Command),
 }
 
+impl PendingAction {
+    /// Actions that remove or disrupt workloads, whose prompts `auto_cancel_secs` times out.
+    fn is_destructive(&self) -> bool {
+        match self {
+            Self::Delete { .. } | Self::EvictPod { .. } | Self::ForceDeletePod { .. } | Self::DrainNode(_) => true,
+            Self::MutateCommand(cmd) => matches!(
+                cmd,
+                Command::DeleteResource | Command::EvictPod | Command::ForceDeletePod | Command::DrainNode
+            ),
+            _ => false,
+        }
+    }
+}
+
 pub struct PendingConfirmation {
     pub message: String,
     pub action: PendingAction,
+    /// Resource name the user has to type before the action runs (`"type-name"` mode).
+    pub type_to_confirm: Option<String>,
+    pub input: String,
+    /// Whether Enter confirms rather than cancels a y/n prompt.
+    pub confirm_focused: bool,
+    /// When the prompt cancels itself, for destructive actions with `auto_cancel_secs` set.
+    pub deadline: Option<Instant>,
+    /// Resource a `MutateCommand` acts on, selected when the prompt opened.
+    pub subject: Option<(ResourceKind, String, String)>,
 }
 
 impl PendingConfirmation {
+    pub fn new(message: String, action: PendingAction) -> Self {
+        Self {
+            message,
+            action,
+            type_to_confirm: None,
+            input: String::new(),
+            confirm_focused: false,
+            deadline: None,
+            subject: None,
+        }
+    }
+
     pub fn from_command(cmd: Command) -> Self {
         let label = match &cmd {
             Command::DeleteResource => "Delete resource",
+            Command::EvictPod => "Evict pod",
+            Command::ForceDeletePod => "Force delete pod",
             Command::ScaleResource => "Scale resource",
             Command::RestartRollout => "Restart rollout",
+            Command::SetImage => "Set image",
+            Command::EditDataKey => "Edit data key",
+            Command::DrainNode => "Drain node",
+            Command::ApplyManifests => "Apply manifests",
             Command::ToggleDebugMode => "Toggle debug mode",
             Command::ToggleRootDebugMode => "Toggle root debug mode",
             other => {
                 let msg = format!("{other:?}");
-                return Self { message: format!("Confirm: {msg}?"), action: PendingAction::MutateCommand(cmd) };
+                return Self::new(format!("Confirm: {msg}?"), PendingAction::MutateCommand(cmd));
             }
         };
-        Self { message: format!("{label}?"), action: PendingAction::MutateCommand(cmd) }
+        Self::new(format!("{label}?"), PendingAction::MutateCommand(cmd))
     }
 }
 
@@ -93,16 +178,21 @@ enum QueryDialogField {
     Database,
     User,
     Password,
+    /// Only offered for MongoDB.
+    AuthDatabase,
     Port,
+    Engine,
 }
 
 impl QueryDialogField {
-    fn next(self) -> Self {
+    fn next(self, engine: QueryEngine) -> Self {
         match self {
             Self::Database => Self::User,
             Self::User => Self::Password,
-            Self::Password => Self::Port,
-            Self::Port => Self::Database,
+            Self::Password if engine == QueryEngine::Mongo => Self::AuthDatabase,
+            Self::Password | Self::AuthDatabase => Self::Port,
+            Self::Port => Self::Engine,
+            Self::Engine => Self::Database,
         }
     }
 }
@@ -111,13 +201,29 @@ struct PendingQueryDialog {
     pod: String,
     namespace: String,
     container: Option<String>,
+    engine: QueryEngine,
     db_input: String,
     user_input: String,
     password_input: String,
+    auth_db_input: String,
     port_input: String,
     active_field: QueryDialogField,
 }
 
+impl PendingQueryDialog {
+    /// Cycles the engine, moving the port along unless it was edited. MongoDB starts out
+    /// authenticating against `admin`.
+    fn switch_engine(&mut self) {
+        if self.port_input.is_empty() || self.port_input == self.engine.default_port() {
+            self.port_input = self.engine.next().default_port().to_string();
+        }
+        self.engine = self.engine.next();
+        if self.engine == QueryEngine::Mongo && self.auth_db_input.is_empty() {
+            self.auth_db_input = "admin".to_string();
+        }
+    }
+}
+
 #[derive(Clone)]
 struct TabScope {
     kube_client: Option<KubeClient>,
@@ -126,6 +232,7 @@ struct TabScope {
     namespaces: Vec<String>,
     namespace_filter: String,
     namespace_selected: usize,
+    namespace_marked: Vec<String>,
     context_filter: String,
     context_selected: usize,
 }
@@ -140,11 +247,28 @@ pub struct App {
     namespaces: Vec<String>,
     namespace_filter: String,
     namespace_selected: usize,
+    /// Namespaces toggled with Space in the namespace selector, watched together on confirm.
+    namespace_marked: Vec<String>,
+    /// Recently used and favorite namespaces per context, listed first by the selector.
+    namespace_memory: kubetile_core::NamespaceMemory,
     context_filter: String,
     context_selected: usize,
+    context_health: HashMap<String, ContextHealth>,
+    offline: Option<reconnect::OfflineState>,
+    connection_failure: Option<connection::ConnectionFailure>,
+    reconnect_seq: u64,
     tab_scopes: HashMap<u32, TabScope>,
     active_watchers: HashMap<PaneId, ResourceWatcher>,
     watcher_seq_by_pane: HashMap<PaneId, u64>,
+    plugin_column_cache: HashMap<PaneId, HashMap<(String, String), Vec<String>>>,
+    plugin_column_limiter: Arc<Semaphore>,
+    volume_stats_pollers: HashMap<PaneId, DropGuard>,
+    volume_usage: VolumeUsageMap,
+    warning_watch: Option<warning_events::WarningWatch>,
+    /// Bumped whenever the warning watch restarts or stops, so events it already sent are dropped.
+    warning_watch_seq: u64,
+    warning_ticker: warning_events::WarningTicker,
+    list_view_states: HashMap<(PaneId, ResourceKind), ResourceListViewState>,
     active_forwards: HashMap<ForwardId, kubetile_core::PortForward>,
     pod_forward_index: HashMap<(String, String), ForwardId>,
     filter_input_buffer: String,
@@ -152,43 +276,126 @@ pub struct App {
     pending_confirmation: Option<PendingConfirmation>,
     pending_port_forward: Option<PendingPortForward>,
     pending_query_dialog: Option<PendingQueryDialog>,
+    /// Open transactions of query panes, each on its own long-lived database session.
+    query_sessions: HashMap<PaneId, Arc<tokio::sync::Mutex<QuerySession>>>,
+    can_i_dialog: Option<access::CanIDialog>,
+    apply_dialog: Option<apply::ApplyDialog>,
+    set_image_dialog: Option<set_image::SetImageDialog>,
+    /// Path typed the last time the apply dialog closed, offered again when it reopens.
+    last_apply_path: String,
+    container_picker: Option<containers::ContainerPicker>,
+    /// Last container picked per workload, preselected by the container picker.
+    container_memory: kubetile_core::ContainerMemory,
+    /// How often each view was picked in the resource switcher, used to rank its suggestions.
+    resource_usage: kubetile_core::ResourceUsage,
+    related_picker: Option<related::RelatedPicker>,
+    data_key_picker: Option<data_keys::DataKeyPicker>,
+    /// Editor panes open on a ConfigMap or Secret key, with what to patch once they exit.
+    data_key_edits: HashMap<PaneId, data_keys::DataKeyEdit>,
+    trash: undo::Trash,
+    undo_list: Option<undo::UndoList>,
+    /// Exec sessions kept running after their pane closed, oldest first.
+    detached_execs: Vec<exec_sessions::DetachedExec>,
+    exec_session_picker: Option<exec_sessions::ExecSessionPicker>,
+    global_search: Option<search::GlobalSearch>,
+    rollouts: rollouts::Rollouts,
+    api_health: health::ApiHealth,
+    memory_budget: memory::MemoryBudget,
+    frames: frames::FramePacer,
+    /// Panes whose watcher or log streams are stopped until they are on screen again.
+    suspended_streams: HashMap<PaneId, suspension::SuspendReason>,
+    /// When each tab was last the active one.
+    tab_last_active: HashMap<u32, Instant>,
+    /// YAML and describe output shared by repeated and concurrent fetches of the same object.
+    fetch_cache: kubetile_core::FetchCache,
+    access_cache: HashMap<(String, AccessCheck), bool>,
     clipboard: Option<arboard::Clipboard>,
     pane_help_overlay: Option<Vec<(String, String)>>,
+    pane_help_disabled: Vec<usize>,
     pane_help_prev_mode: InputMode,
-    toasts: Vec<ToastMessage>,
+    toasts: notifications::Toasts,
+    /// Scroll offset of the notification center while it is open.
+    notification_scroll: Option<usize>,
     tab_manager: TabManager,
     panes: HashMap<PaneId, Box<dyn Pane>>,
     pods_pane_id: PaneId,
+    startup_detail: Option<String>,
     app_tx: mpsc::UnboundedSender<AppEvent>,
     theme: kubetile_tui::theme::Theme,
+    /// The configured theme, before the current context's accent is applied.
+    base_theme: kubetile_tui::theme::Theme,
     views_config: kubetile_config::ViewsConfig,
-    query_open_new_tab: bool,
+    general_config: kubetile_config::GeneralConfig,
+    /// Zone absolute timestamps are shown in, from the current context's `timezone`.
+    display_timezone: jiff::tz::TimeZone,
+    features: kubetile_config::FeatureFlags,
+    plugins: BTreeMap<String, kubetile_config::PluginConfig>,
+    /// Lua scripts from the plugins directory; calls run on blocking threads.
+    scripts: Arc<kubetile_core::ScriptHost>,
+    /// `[logs.highlights]`, shared by every logs pane.
+    log_highlights: Arc<LogHighlights>,
+    terminal_config: kubetile_config::TerminalConfig,
+    /// First-run overlay, shown while no config file exists until dismissed.
+    welcome: Option<welcome::Welcome>,
+    empty_hints: welcome::EmptyHints,
+}
+
+/// What to open on launch instead of the kubeconfig's current context and namespace and the pods list.
+#[derive(Debug, Clone, Default)]
+pub struct StartupOptions {
+    pub context: Option<String>,
+    pub namespace: Option<String>,
+    pub kind: Option<ResourceKind>,
+    /// Resource of `kind` whose detail pane opens next to the list.
+    pub name: Option<String>,
+    pub filter: Option<String>,
 }
 
 impl App {
     pub async fn new(
-        tick_rate_ms: u64,
         dispatcher: KeybindingDispatcher,
         theme: kubetile_tui::theme::Theme,
         views_config: kubetile_config::ViewsConfig,
-        query_open_new_tab: bool,
+        general_config: kubetile_config::GeneralConfig,
+        features: kubetile_config::FeatureFlags,
+        plugins: BTreeMap<String, kubetile_config::PluginConfig>,
+        scope: StartupOptions,
     ) -> Self {
         let mut context_resolver = ContextResolver::new();
-        let kube_client = match KubeClient::from_kubeconfig().await {
-            Ok(client) => {
-                let ctx = client.cluster_context();
-                context_resolver.set_context(ctx);
+        let mut offline = None;
+        let target = scope.context.clone().or_else(KubeClient::current_context_name);
+        let ssh_host = general_config.ssh_host_for(target.as_deref());
+        let connected = KubeClient::connect_to(target.as_deref(), ssh_host).await;
+        let kube_client = match connected {
+            Ok(mut client) => {
+                if let Some(namespace) = &scope.namespace {
+                    client.set_namespace(namespace);
+                }
+                context_resolver.set_context(client.cluster_context());
                 Some(client)
             }
             Err(e) => {
                 tracing::warn!("Failed to connect to cluster: {e}");
+                // Keep the requested scope so reconnect attempts target it.
+                if let Some(context) = scope.context {
+                    let namespace = scope.namespace.unwrap_or_else(|| "default".into());
+                    context_resolver.set_context(ClusterContext { name: context, namespace });
+                }
+                offline = Some(reconnect::OfflineState::new(format!("{e:#}"), reconnect::ReconnectPhase::Connecting));
                 None
             }
         };
         let contexts = KubeClient::list_contexts().unwrap_or_default();
 
-        let pods_pane = ResourceListPane::new(ResourceKind::Pods, pods_headers());
-        let tab_manager = TabManager::new(ViewType::ResourceList(ResourceKind::Pods));
+        let initial_kind = scope.kind.unwrap_or(ResourceKind::Pods);
+        let headers = if initial_kind == ResourceKind::Pods { pods_headers() } else { Vec::new() };
+        let empty_hints = welcome::EmptyHints::new(&dispatcher);
+        let mut pods_pane = ResourceListPane::new(initial_kind.clone(), headers);
+        pods_pane.empty_hint = empty_hints.list.clone();
+        if let Some(filter) = scope.filter.filter(|f| !f.is_empty()) {
+            pods_pane.handle_command(&PaneCommand::Filter(filter));
+        }
+        let tab_manager = TabManager::new(ViewType::ResourceList(initial_kind));
         let pods_pane_id = 1;
 
         let mut panes: HashMap<PaneId, Box<dyn Pane>> = HashMap::new();
@@ -196,58 +403,151 @@ impl App {
 
         let (tx, _rx) = mpsc::unbounded_channel();
 
-        let mut toasts = Vec::new();
-        if !is_kubectl_available_with_logging() {
-            tracing::warn!("kubectl not found in PATH; exec workflows will be unavailable");
-            toasts.push(ToastMessage::error("kubectl was not found in PATH. Install kubectl to use exec sessions."));
+        let mut toasts = notifications::Toasts::default();
+        let needs_kubectl = match (general_config.exec_backend, general_config.log_backend) {
+            (kubetile_config::ExecBackend::Kubectl, kubetile_config::LogBackend::Kubectl) => {
+                Some("exec sessions and log streams")
+            }
+            (kubetile_config::ExecBackend::Kubectl, _) => Some("exec sessions"),
+            (_, kubetile_config::LogBackend::Kubectl) => Some("log streams"),
+            _ => None,
+        };
+        if let Some(needs) = needs_kubectl
+            .filter(|_| general_config.context_ssh_hosts.is_empty() && !is_kubectl_available_with_logging())
+        {
+            tracing::warn!("kubectl not found in PATH; {needs} will be unavailable");
+            toasts.push(ToastMessage::error(format!("kubectl was not found in PATH. Install kubectl to use {needs}.")));
         }
 
+        let connection_failure = offline
+            .as_ref()
+            .map(|o| connection::ConnectionFailure::new(context_resolver.context_name(), o.reason.clone()));
+
         let mut app = Self {
             running: true,
-            tick_rate: Duration::from_millis(tick_rate_ms),
+            tick_rate: Duration::from_millis(general_config.tick_rate_ms),
             kube_client,
             context_resolver,
             dispatcher,
             contexts,
             namespaces: Vec::new(),
             namespace_filter: String::new(),
+            namespace_marked: Vec::new(),
+            namespace_memory: kubetile_core::NamespaceMemory::load(),
             namespace_selected: 0,
             context_filter: String::new(),
             context_selected: 0,
+            context_health: HashMap::new(),
+            offline,
+            connection_failure,
+            reconnect_seq: 0,
             tab_scopes: HashMap::new(),
             active_watchers: HashMap::new(),
             watcher_seq_by_pane: HashMap::new(),
+            plugin_column_cache: HashMap::new(),
+            volume_stats_pollers: HashMap::new(),
+            volume_usage: VolumeUsageMap::new(),
+            warning_watch: None,
+            warning_watch_seq: 0,
+            warning_ticker: warning_events::WarningTicker::default(),
+            plugin_column_limiter: Arc::new(Semaphore::new(PLUGIN_COLUMN_CONCURRENCY)),
+            list_view_states: HashMap::new(),
             active_forwards: HashMap::new(),
             pod_forward_index: HashMap::new(),
             filter_input_buffer: String::new(),
             resource_switcher: None,
             pending_confirmation: None,
             pending_port_forward: None,
+            container_picker: None,
+            container_memory: kubetile_core::ContainerMemory::load(),
+            resource_usage: kubetile_core::ResourceUsage::load(),
+            related_picker: None,
+            data_key_picker: None,
+            data_key_edits: HashMap::new(),
+            trash: undo::Trash::default(),
+            undo_list: None,
+            detached_execs: Vec::new(),
+            exec_session_picker: None,
+            global_search: None,
+            rollouts: rollouts::Rollouts::default(),
+            api_health: health::ApiHealth::default(),
+            memory_budget: memory::MemoryBudget::default(),
+            frames: frames::FramePacer::new(general_config.max_fps),
+            suspended_streams: HashMap::new(),
+            tab_last_active: HashMap::new(),
+            fetch_cache: kubetile_core::FetchCache::new(Duration::from_secs(general_config.fetch_cache_secs)),
             pending_query_dialog: None,
+            query_sessions: HashMap::new(),
+            can_i_dialog: None,
+            apply_dialog: None,
+            set_image_dialog: None,
+            last_apply_path: String::new(),
+            access_cache: HashMap::new(),
             clipboard: arboard::Clipboard::new().ok(),
             pane_help_overlay: None,
+            pane_help_disabled: Vec::new(),
             pane_help_prev_mode: InputMode::Normal,
             toasts,
+            notification_scroll: None,
             tab_manager,
             panes,
             pods_pane_id,
+            startup_detail: scope.name,
             app_tx: tx,
+            base_theme: theme.clone(),
             theme,
             views_config,
-            query_open_new_tab,
+            general_config,
+            display_timezone: jiff::tz::TimeZone::system(),
+            features,
+            plugins,
+            scripts: Arc::default(),
+            log_highlights: Arc::default(),
+            terminal_config: kubetile_config::TerminalConfig::default(),
+            welcome: None,
+            empty_hints,
         };
         app.sync_active_scope();
+        app.apply_context_display();
         app.update_active_tab_title();
         app
     }
 
+    /// Styles log lines of logs panes opened from now on by the `[logs.highlights]` rules.
+    pub fn set_log_highlights(&mut self, highlights: LogHighlights) {
+        self.log_highlights = Arc::new(highlights);
+    }
+
+    /// Adds the columns, actions and panes registered by Lua scripts. Their keys are bound by
+    /// [`KeybindingDispatcher::with_scripts`].
+    pub fn set_scripts(&mut self, scripts: kubetile_core::ScriptHost) {
+        self.scripts = Arc::new(scripts);
+    }
+
+    /// Applies the `[terminal]` section to exec panes opened from now on.
+    pub fn set_terminal_config(&mut self, config: kubetile_config::TerminalConfig) {
+        self.terminal_config = config;
+    }
+
     pub async fn run(&mut self, terminal: &mut Terminal<impl Backend>) -> anyhow::Result<()> {
         let mut events = EventHandler::new(self.tick_rate);
         self.app_tx = events.app_tx();
 
-        if let Some(client) = &self.kube_client {
-            let ns = client.namespace().to_string();
-            self.start_watcher_for_pane(self.pods_pane_id, &ResourceKind::Pods, &ns);
+        let initial_kind = self
+            .panes
+            .get(&self.pods_pane_id)
+            .and_then(|p| p.as_any().downcast_ref::<ResourceListPane>())
+            .and_then(|rp| rp.kind().cloned())
+            .unwrap_or(ResourceKind::Pods);
+        let context_ns = self.context_resolver.namespace().unwrap_or("default").to_string();
+        if let Some(name) = self.startup_detail.take() {
+            let ns = if initial_kind.is_namespaced() { context_ns } else { String::new() };
+            self.open_detail_pane(initial_kind.clone(), name, ns);
+        }
+
+        if self.kube_client.is_some() {
+            let namespaces = self.watch_namespaces(&initial_kind, false);
+            self.start_watcher_for_pane(self.pods_pane_id, &initial_kind, &namespaces);
 
             if let Some(client) = &self.kube_client {
                 match client.list_namespaces().await {
@@ -255,7 +555,15 @@ impl App {
                         self.namespaces = ns_list;
                         self.sync_active_scope();
                     }
-                    Err(e) => tracing::warn!("Failed to list namespaces: {e}"),
+                    Err(e) => {
+                        tracing::warn!("Failed to list namespaces: {e}");
+                        let reason = format!("{e:#}");
+                        let context = self.context_resolver.context_name().map(str::to_string);
+                        self.record_connection_failure(context.as_deref(), reason.clone());
+                        self.offline =
+                            Some(reconnect::OfflineState::new(reason, reconnect::ReconnectPhase::Connecting));
+                        self.schedule_reconnect();
+                    }
                 }
             }
         } else {
@@ -263,23 +571,23 @@ impl App {
                 pane.state.loading = false;
                 pane.state.error = Some("No cluster connection".into());
             });
+            self.schedule_reconnect();
         }
 
         while self.running {
-            terminal.draw(|frame| {
-                let (mut ctx, tab_names, keys) = self.build_render_context();
-                ctx.tab_names = &tab_names;
-                ctx.help_key = keys[0].as_deref();
-                ctx.pane_help_key = keys[1].as_deref();
-                ctx.namespace_key = keys[2].as_deref();
-                ctx.context_key = keys[3].as_deref();
-                ctx.close_pane_key = keys[4].as_deref();
-                ctx.new_tab_key = keys[5].as_deref();
-                ctx.quit_key = keys[6].as_deref();
-                kubetile_tui::layout::render_root(frame, &ctx);
-            })?;
-
-            let first = events.next().await?;
+            if self.frames.wait() == Some(Duration::ZERO) {
+                self.draw(terminal)?;
+                self.frames.drawn();
+            }
+
+            // While a frame is pending, stop waiting for input once it is due.
+            let first = match self.frames.wait() {
+                Some(delay) => match tokio::time::timeout(delay, events.next()).await {
+                    Ok(event) => event?,
+                    Err(_) => continue,
+                },
+                None => events.next().await?,
+            };
             self.handle_event(first);
 
             for event in events.drain_pending() {
@@ -292,8 +600,31 @@ impl App {
 
         Ok(())
     }
+
+    fn draw(&mut self, terminal: &mut Terminal<impl Backend>) -> anyhow::Result<()> {
+        crate::crash::set_views(self.panes.values().map(|p| format!("{:?}", p.view_type())));
+        terminal.draw(|frame| {
+            let (mut ctx, tab_names, keys) = self.build_render_context();
+            ctx.tab_names = &tab_names;
+            ctx.help_key = keys[0].as_deref();
+            ctx.pane_help_key = keys[1].as_deref();
+            ctx.namespace_key = keys[2].as_deref();
+            ctx.context_key = keys[3].as_deref();
+            ctx.close_pane_key = keys[4].as_deref();
+            ctx.new_tab_key = keys[5].as_deref();
+            ctx.quit_key = keys[6].as_deref();
+            if let Some(offline) = ctx.offline.as_mut() {
+                offline.reconnect_key = keys[7].as_deref();
+                offline.diagnostics_key = keys[8].as_deref();
+            }
+            kubetile_tui::layout::render_root(frame, &ctx);
+        })?;
+        Ok(())
+    }
 }
 
+const PLUGIN_COLUMN_CONCURRENCY: usize = 4;
+
 fn pods_headers() -> Vec<String> {
     vec![
         "NAME".into(),
@@ -327,7 +658,14 @@ fn resource_kind_config_key(kind: &ResourceKind) -> &'static str {
         ResourceKind::Ingresses => "ingresses",
         ResourceKind::Nodes => "nodes",
         ResourceKind::Namespaces => "namespaces",
-        ResourceKind::PersistentVolumes | ResourceKind::PersistentVolumeClaims | ResourceKind::Custom(_) => "",
+        ResourceKind::PersistentVolumes
+        | ResourceKind::PersistentVolumeClaims
+        | ResourceKind::NetworkPolicies
+        | ResourceKind::ResourceQuotas
+        | ResourceKind::LimitRanges
+        | ResourceKind::PodDisruptionBudgets
+        | ResourceKind::Workloads
+        | ResourceKind::Custom(_) => "",
     }
 }
 
@@ -367,7 +705,8 @@ fn kubectl_binary_candidates(dir: &Path) -> Vec<PathBuf> {
     }
 }
 
-struct EmptyPane(ViewType);
+/// A freshly split pane, with the key hint of what can be opened in it.
+struct EmptyPane(ViewType, String);
 
 impl Pane for EmptyPane {
     fn render(
@@ -377,6 +716,7 @@ impl Pane for EmptyPane {
         focused: bool,
         theme: &kubetile_tui::theme::Theme,
     ) {
+        use ratatui::text::Line;
         use ratatui::widgets::{Block, Borders, Paragraph};
 
         let border_style = if focused { theme.border_active } else { theme.border };
@@ -389,8 +729,11 @@ impl Pane for EmptyPane {
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
-        let msg = Paragraph::new("Empty pane").style(theme.text_dim);
-        frame.render_widget(msg, inner);
+        let mut lines = vec![Line::raw("Empty pane")];
+        if !self.1.is_empty() {
+            lines.extend([Line::raw(""), Line::raw(self.1.as_str())]);
+        }
+        frame.render_widget(Paragraph::new(lines).style(theme.text_dim), inner);
     }
 
     fn handle_command(&mut self, _cmd: &kubetile_tui::pane::PaneCommand) {}
@@ -0,0 +1,240 @@
+use kubetile_core::{AccessCheck, AccessDecision};
+use kubetile_tui::pane::ResourceKind;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
+
+use super::App;
+
+/// Access-checked actions as `(access key, keybinding name)`.
+pub(super) const MUTATE_ACTIONS: &[(&str, &str)] = &[
+    ("delete", "delete"),
+    ("evict", "evict"),
+    ("force_delete", "force_delete"),
+    ("scale", "scale"),
+    ("restart", "restart_rollout"),
+    ("set_image", "set_image"),
+    ("edit_data", "edit_data"),
+    ("debug_mode", "debug_mode"),
+    ("root_debug_mode", "root_debug_mode"),
+    ("drain", "drain"),
+];
+
+pub(super) enum CanIStatus {
+    Idle,
+    Checking,
+    Decided(AccessDecision),
+    Failed(String),
+}
+
+pub(super) struct CanIDialog {
+    pub(super) input: String,
+    pub(super) check: Option<AccessCheck>,
+    /// `check` rendered back in `kubectl auth can-i` form.
+    pub(super) query: String,
+    pub(super) status: CanIStatus,
+}
+
+/// API group and plural resource name used in RBAC rules.
+pub(super) fn api_resource(kind: &ResourceKind) -> Option<(&'static str, &'static str)> {
+    Some(match kind {
+        ResourceKind::Pods => ("", "pods"),
+        ResourceKind::Deployments => ("apps", "deployments"),
+        ResourceKind::Services => ("", "services"),
+        ResourceKind::StatefulSets => ("apps", "statefulsets"),
+        ResourceKind::DaemonSets => ("apps", "daemonsets"),
+        ResourceKind::Jobs => ("batch", "jobs"),
+        ResourceKind::CronJobs => ("batch", "cronjobs"),
+        ResourceKind::ConfigMaps => ("", "configmaps"),
+        ResourceKind::Secrets => ("", "secrets"),
+        ResourceKind::Ingresses => ("networking.k8s.io", "ingresses"),
+        ResourceKind::Nodes => ("", "nodes"),
+        ResourceKind::Namespaces => ("", "namespaces"),
+        ResourceKind::PersistentVolumes => ("", "persistentvolumes"),
+        ResourceKind::PersistentVolumeClaims => ("", "persistentvolumeclaims"),
+        ResourceKind::NetworkPolicies => ("networking.k8s.io", "networkpolicies"),
+        ResourceKind::ResourceQuotas => ("", "resourcequotas"),
+        ResourceKind::LimitRanges => ("", "limitranges"),
+        ResourceKind::PodDisruptionBudgets => ("policy", "poddisruptionbudgets"),
+        ResourceKind::Workloads | ResourceKind::Custom(_) => return None,
+    })
+}
+
+/// The permissions a mutate action needs on `kind`, empty when the action does not apply to it.
+/// Debug mode and rollout restart patch the owning Deployment; a drain cordons the node and then
+/// evicts pods in any namespace.
+pub(super) fn mutate_access_checks(action: &str, kind: &ResourceKind, namespace: Option<&str>) -> Vec<AccessCheck> {
+    let namespace = namespace.filter(|_| kind.is_namespaced());
+    let check = match action {
+        "delete" => api_resource(kind).map(|(group, resource)| AccessCheck::new("delete", group, resource, namespace)),
+        "evict" if *kind == ResourceKind::Pods => {
+            Some(AccessCheck::new("create", "", "pods", namespace).with_subresource("eviction"))
+        }
+        "force_delete" if *kind == ResourceKind::Pods => Some(AccessCheck::new("delete", "", "pods", namespace)),
+        "scale" if matches!(kind, ResourceKind::Deployments | ResourceKind::StatefulSets) => api_resource(kind)
+            .map(|(group, resource)| AccessCheck::new("patch", group, resource, namespace).with_subresource("scale")),
+        "restart" if *kind == ResourceKind::Deployments => {
+            Some(AccessCheck::new("patch", "apps", "deployments", namespace))
+        }
+        "set_image" if matches!(kind, ResourceKind::Deployments | ResourceKind::StatefulSets) => {
+            api_resource(kind).map(|(group, resource)| AccessCheck::new("patch", group, resource, namespace))
+        }
+        "edit_data" if matches!(kind, ResourceKind::ConfigMaps | ResourceKind::Secrets) => {
+            api_resource(kind).map(|(group, resource)| AccessCheck::new("patch", group, resource, namespace))
+        }
+        "drain" if *kind == ResourceKind::Nodes => {
+            return vec![
+                AccessCheck::new("patch", "", "nodes", None),
+                AccessCheck::new("create", "", "pods", None).with_subresource("eviction"),
+            ];
+        }
+        "debug_mode" | "root_debug_mode" if *kind == ResourceKind::Pods => {
+            Some(AccessCheck::new("patch", "apps", "deployments", namespace))
+        }
+        _ => None,
+    };
+    check.into_iter().collect()
+}
+
+impl App {
+    /// Cached answer for `check` in the current context; `None` when it has not been reviewed yet.
+    pub(super) fn cached_access(&self, check: &AccessCheck) -> Option<bool> {
+        let context = self.context_resolver.context_name()?;
+        self.access_cache.get(&(context.to_string(), check.clone())).copied()
+    }
+
+    pub(super) fn spawn_access_review(&self, check: AccessCheck) {
+        let Some(client) = &self.kube_client else { return };
+        let client = client.inner_client();
+        let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = check.review(client).await.map_err(|e| e.to_string());
+            let _ = app_tx.send(AppEvent::AccessReviewed { context, check, result });
+        });
+    }
+
+    /// Reviews every mutate action that applies to `kind` so forbidden ones can be greyed out
+    /// before the user reaches for them. An empty `namespace` means all namespaces.
+    pub(super) fn prefetch_mutate_access(&self, kind: &ResourceKind, namespace: &str) {
+        let namespace = (!namespace.is_empty()).then_some(namespace);
+        let mut checks: Vec<AccessCheck> = Vec::new();
+        for (action, _) in MUTATE_ACTIONS {
+            for check in mutate_access_checks(action, kind, namespace) {
+                if !checks.contains(&check) {
+                    checks.push(check);
+                }
+            }
+        }
+        for check in checks {
+            self.spawn_access_review(check);
+        }
+    }
+
+    pub(super) fn handle_access_reviewed(
+        &mut self,
+        context: String,
+        check: AccessCheck,
+        result: Result<AccessDecision, String>,
+    ) {
+        match &result {
+            Ok(decision) => {
+                self.access_cache.insert((context.clone(), check.clone()), decision.allowed);
+            }
+            Err(e) => tracing::warn!("Access review for '{check}' failed: {e}"),
+        }
+
+        let Some(dialog) = &mut self.can_i_dialog else { return };
+        if dialog.check.as_ref() == Some(&check) && self.context_resolver.context_name() == Some(context.as_str()) {
+            dialog.status = match result {
+                Ok(decision) => CanIStatus::Decided(decision),
+                Err(e) => CanIStatus::Failed(e),
+            };
+        }
+    }
+
+    /// Refuses a mutate action on the selected resource when RBAC is known to forbid it.
+    /// Unreviewed permissions are allowed through (and reviewed for next time); the API server
+    /// still has the final word.
+    pub(super) fn deny_if_forbidden(&mut self, action: &str) -> bool {
+        let Some((kind, _, namespace)) = self.selected_resource_info() else { return false };
+        for check in mutate_access_checks(action, &kind, Some(&namespace)) {
+            match self.cached_access(&check) {
+                Some(false) => {
+                    self.toasts.push(ToastMessage::error(format!("Forbidden: you cannot {check}")));
+                    return true;
+                }
+                Some(true) => {}
+                None => self.spawn_access_review(check),
+            }
+        }
+        false
+    }
+
+    /// Indices of pane help entries for mutate actions the user is known not to be allowed to run.
+    pub(super) fn forbidden_help_entries(&self, entries: &[(String, String)]) -> Vec<usize> {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(rp) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<ResourceListPane>()) else {
+            return Vec::new();
+        };
+        let Some(kind) = rp.kind() else { return Vec::new() };
+        let namespace = if rp.all_namespaces { None } else { self.context_resolver.namespace() };
+
+        let forbidden_bindings: Vec<&str> = MUTATE_ACTIONS
+            .iter()
+            .filter(|(action, _)| {
+                mutate_access_checks(action, kind, namespace).iter().any(|c| self.cached_access(c) == Some(false))
+            })
+            .map(|(_, binding)| *binding)
+            .collect();
+        let forbidden = self.dispatcher.shortcuts_named(&forbidden_bindings);
+        entries.iter().enumerate().filter(|(_, entry)| forbidden.contains(entry)).map(|(i, _)| i).collect()
+    }
+
+    pub(super) fn open_can_i_dialog(&mut self) {
+        self.can_i_dialog =
+            Some(CanIDialog { input: String::new(), check: None, query: String::new(), status: CanIStatus::Idle });
+        self.dispatcher.set_mode(InputMode::CanIDialog);
+    }
+
+    pub(super) fn close_can_i_dialog(&mut self) {
+        self.can_i_dialog = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    pub(super) fn can_i_input(&mut self, c: char) {
+        if let Some(dialog) = &mut self.can_i_dialog {
+            dialog.input.push(c);
+        }
+    }
+
+    pub(super) fn can_i_backspace(&mut self) {
+        if let Some(dialog) = &mut self.can_i_dialog {
+            dialog.input.pop();
+        }
+    }
+
+    pub(super) fn submit_can_i(&mut self) {
+        let default_namespace = self.context_resolver.namespace().unwrap_or("default").to_string();
+        let connected = self.kube_client.is_some();
+        let Some(dialog) = &mut self.can_i_dialog else { return };
+        match AccessCheck::parse(&dialog.input, &default_namespace) {
+            Ok(check) => {
+                dialog.query = check.to_string();
+                dialog.check = Some(check.clone());
+                if connected {
+                    dialog.status = CanIStatus::Checking;
+                    self.spawn_access_review(check);
+                } else {
+                    dialog.status = CanIStatus::Failed("not connected to a cluster".into());
+                }
+            }
+            Err(e) => {
+                dialog.check = None;
+                dialog.status = CanIStatus::Failed(e.to_string());
+            }
+        }
+    }
+}
@@ -1,22 +1,29 @@
 use std::env;
 use std::fs;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use k8s_openapi::api::core::v1::Pod;
 use kube::Api;
-use kubetile_tui::pane::{Pane, ResourceKind, ViewType};
+use kubetile_config::{ConfirmButton, ConfirmMode};
+use kubetile_core::drain::{evict, plan_drain};
+use kubetile_core::{DrainOptions, DrainPlan};
+use kubetile_tui::pane::{ResourceKind, ViewType};
 use kubetile_tui::widgets::toast::ToastMessage;
 
-use crate::command::InputMode;
+use crate::command::{Command, InputMode};
 use crate::event::AppEvent;
-use crate::panes::{LogsPane, ResourceListPane};
+use crate::panes::{LogsPane, PluginPane, ResourceListPane};
 
+use super::reconnect::report_auth_expired;
 use super::{App, PendingAction, PendingConfirmation};
 
 impl App {
     pub(super) fn focused_supports_insert_mode(&self) -> bool {
         let focused = self.tab_manager.active().focused_pane;
-        self.panes.get(&focused).is_some_and(|pane| matches!(pane.view_type(), ViewType::Exec(_) | ViewType::Terminal))
+        self.panes.get(&focused).is_some_and(|pane| {
+            matches!(pane.view_type(), ViewType::Exec(_) | ViewType::Terminal) || pane.as_any().is::<PluginPane>()
+        })
     }
 
     pub(super) fn selected_resource_info(&self) -> Option<(ResourceKind, String, String)> {
@@ -24,65 +31,109 @@ impl App {
         let pane = self.panes.get(&focused)?;
         let rp = pane.as_any().downcast_ref::<ResourceListPane>()?;
 
-        let kind = rp.kind()?.clone();
-
-        let selected_idx = match rp.state.selected {
-            Some(s) => {
-                if rp.filtered_indices.is_empty() {
-                    s
-                } else {
-                    *rp.filtered_indices.get(s)?
-                }
-            }
-            None => return None,
-        };
+        let selected_idx = rp.selected_item_index()?;
 
         let row = rp.state.items.get(selected_idx)?;
+        let kind = rp.row_kind(row)?;
         let name = super::header_value(&rp.state.headers, row, "NAME", 0).unwrap_or_default();
-        let namespace = super::header_value(&rp.state.headers, row, "NAMESPACE", usize::MAX)
-            .unwrap_or_else(|| self.context_resolver.namespace().unwrap_or("default").to_string());
+        let namespace = self.row_namespace(rp, row);
 
         Some((kind, name, namespace))
     }
 
     pub(super) fn initiate_delete(&mut self) {
-        let focused = self.tab_manager.active().focused_pane;
-        let Some(pane) = self.panes.get(&focused) else { return };
-        let Some(rp) = pane.as_any().downcast_ref::<ResourceListPane>() else { return };
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
 
-        let kind = match rp.view_type() {
-            ViewType::ResourceList(k) => k.clone(),
-            _ => return,
+        let (message, namespace) = if kind.is_namespaced() {
+            (format!("Delete {} {}\nin namespace {}?", kind.display_name(), name, namespace), namespace)
+        } else {
+            (format!("Delete {} {}?", kind.display_name(), name), String::new())
         };
+        let target = name.clone();
+        self.request_confirmation("delete", &target, message, PendingAction::Delete { kind, name, namespace });
+    }
 
-        let selected_idx = match rp.state.selected {
-            Some(s) => {
-                if rp.filtered_indices.is_empty() {
-                    s
-                } else {
-                    match rp.filtered_indices.get(s) {
-                        Some(&i) => i,
-                        None => return,
-                    }
-                }
+    /// Runs `action` directly or asks for confirmation, depending on the `[general.confirm]` mode for `action_key`.
+    pub(super) fn request_confirmation(
+        &mut self,
+        action_key: &str,
+        target: &str,
+        message: String,
+        action: PendingAction,
+    ) {
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        if self.deny_if_forbidden(action_key) {
+            return;
+        }
+        let mut confirmation = PendingConfirmation::new(message, action);
+        match self.general_config.confirm_mode(action_key, self.context_resolver.context_name()) {
+            ConfirmMode::Off => {
+                self.pending_confirmation = Some(confirmation);
+                self.execute_confirmed_action();
             }
-            None => return,
-        };
+            ConfirmMode::Prompt => self.open_confirmation(confirmation, InputMode::ConfirmDialog),
+            ConfirmMode::TypeName => {
+                confirmation.type_to_confirm = Some(target.to_string());
+                self.open_confirmation(confirmation, InputMode::ConfirmTypeName);
+            }
+        }
+    }
 
-        let row = match rp.state.items.get(selected_idx) {
-            Some(r) => r,
-            None => return,
-        };
+    /// Shows a confirmation prompt focused on the configured default button, with the
+    /// auto-cancel countdown for destructive actions.
+    pub(super) fn open_confirmation(&mut self, mut confirmation: PendingConfirmation, mode: InputMode) {
+        let config = &self.general_config.confirm;
+        confirmation.confirm_focused = config.default_button == ConfirmButton::Confirm;
+        if config.auto_cancel_secs > 0 && confirmation.action.is_destructive() {
+            confirmation.deadline = Some(Instant::now() + Duration::from_secs(config.auto_cancel_secs));
+        }
+        if matches!(&confirmation.action, PendingAction::MutateCommand(cmd) if *cmd != Command::Quit) {
+            confirmation.subject = self.selected_resource_info();
+        }
+        self.pending_confirmation = Some(confirmation);
+        self.dispatcher.set_mode(mode);
+    }
 
-        let name = super::header_value(&rp.state.headers, row, "NAME", 0).unwrap_or_default();
-        let namespace = super::header_value(&rp.state.headers, row, "NAMESPACE", usize::MAX)
-            .unwrap_or_else(|| self.context_resolver.namespace().unwrap_or("default").to_string());
+    pub(super) fn toggle_confirm_focus(&mut self) {
+        if let Some(pc) = self.pending_confirmation.as_mut() {
+            pc.confirm_focused = !pc.confirm_focused;
+        }
+    }
 
-        let message = format!("Delete {} {}\nin namespace {}?", kind.display_name(), name, namespace);
+    /// Enter in a y/n prompt: runs the action or cancels, whichever button is focused.
+    pub(super) fn submit_confirmation(&mut self) {
+        if self.pending_confirmation.as_ref().is_some_and(|pc| pc.confirm_focused) {
+            self.execute_confirmed_action();
+        } else {
+            self.cancel_confirmation();
+        }
+    }
+
+    pub(super) fn cancel_confirmation(&mut self) {
+        self.pending_confirmation = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    /// Cancels a prompt whose auto-cancel countdown ran out.
+    pub(super) fn expire_confirmation(&mut self) {
+        if self.pending_confirmation.as_ref().and_then(|pc| pc.deadline).is_some_and(|d| Instant::now() >= d) {
+            self.cancel_confirmation();
+            self.toasts.push(ToastMessage::info("Confirmation timed out; nothing was changed"));
+        }
+    }
+
+    pub(super) fn is_read_only_context(&self) -> bool {
+        self.general_config.is_read_only(self.context_resolver.context_name())
+    }
 
-        self.pending_confirmation =
-            Some(PendingConfirmation { message, action: PendingAction::Delete { kind, name, namespace } });
-        self.dispatcher.set_mode(InputMode::ConfirmDialog);
+    pub(super) fn notify_read_only(&mut self) {
+        let context = self.context_resolver.context_name().unwrap_or_default();
+        self.toasts.push(ToastMessage::error(format!(
+            "Context '{context}' is read-only; mutate commands are disabled (general.read_only_contexts)"
+        )));
     }
 
     pub(super) fn initiate_save_logs(&mut self) {
@@ -131,9 +182,8 @@ impl App {
         }
 
         let message = format!("Save logs to:\n{}?", path.display());
-        self.pending_confirmation =
-            Some(PendingConfirmation { message, action: PendingAction::SaveLogs { path, content } });
-        self.dispatcher.set_mode(InputMode::ConfirmDialog);
+        let confirmation = PendingConfirmation::new(message, PendingAction::SaveLogs { path, content });
+        self.open_confirmation(confirmation, InputMode::ConfirmDialog);
     }
 
     pub(super) fn initiate_download_full_logs(&mut self) {
@@ -143,6 +193,10 @@ impl App {
             self.toasts.push(ToastMessage::info("Download logs is only available in a Logs pane"));
             return;
         };
+        if logs.is_workload() {
+            self.toasts.push(ToastMessage::info("Download full logs from a single pod's logs pane"));
+            return;
+        }
 
         let Some(downloads_dir) = home_downloads_dir() else {
             self.toasts.push(ToastMessage::error("HOME is not set; cannot resolve $HOME/Downloads"));
@@ -165,11 +219,70 @@ impl App {
         let path = downloads_dir.join(filename);
 
         let message = format!("Download full log history to:\n{}?", path.display());
-        self.pending_confirmation = Some(PendingConfirmation {
-            message,
-            action: PendingAction::DownloadFullLogs { path, pod_name, namespace, container },
+        let confirmation =
+            PendingConfirmation::new(message, PendingAction::DownloadFullLogs { path, pod_name, namespace, container });
+        self.open_confirmation(confirmation, InputMode::ConfirmDialog);
+    }
+
+    /// Asks for a file to append the focused logs pane's live stream to, or stops teeing.
+    pub(super) fn toggle_log_tee(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(logs) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<LogsPane>()) else {
+            self.toasts.push(ToastMessage::info("Tee is only available in a Logs pane"));
+            return;
+        };
+        if let Some(path) = logs.stop_tee() {
+            self.toasts.push(ToastMessage::success(format!("Stopped writing logs to {}", path.display())));
+            return;
+        }
+
+        let Some(downloads_dir) = home_downloads_dir() else {
+            self.toasts.push(ToastMessage::error("HOME is not set; cannot resolve $HOME/Downloads"));
+            return;
+        };
+        let context = self.context_resolver.context_name().unwrap_or("unknown-context");
+        let filename = format!(
+            "{}_{}_{}_{}_tee.log",
+            sanitize_filename_component(context),
+            sanitize_filename_component(logs.namespace()),
+            sanitize_filename_component(logs.pod_name()),
+            filename_timestamp_now()
+        );
+        logs.open_tee_prompt(downloads_dir.join(filename).display().to_string());
+        self.dispatcher.set_mode(InputMode::LogTeePath);
+    }
+
+    pub(super) fn with_tee_prompt(&mut self, f: impl FnOnce(&mut LogsPane)) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(logs) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<LogsPane>()) {
+            f(logs);
+        }
+    }
+
+    /// Starts teeing into the path typed into the prompt; `~` expands to the home directory.
+    pub(super) fn confirm_log_tee_path(&mut self) {
+        self.dispatcher.set_mode(InputMode::Normal);
+        let max_bytes = u64::from(self.general_config.log_tee_max_mb) * 1024 * 1024;
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(logs) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<LogsPane>()) else {
+            return;
+        };
+        let Some(path) = logs.close_tee_prompt().filter(|p| !p.trim().is_empty()) else {
+            self.toasts.push(ToastMessage::info("No file given; logs are not teed"));
+            return;
+        };
+        let path = super::query::expand_tilde(path.trim());
+        match logs.start_tee(path.clone(), max_bytes) {
+            Ok(()) => self.toasts.push(ToastMessage::success(format!("Writing logs to {}", path.display()))),
+            Err(e) => self.toasts.push(ToastMessage::error(format!("Failed to open {}: {e}", path.display()))),
+        }
+    }
+
+    pub(super) fn cancel_log_tee_path(&mut self) {
+        self.with_tee_prompt(|logs| {
+            logs.close_tee_prompt();
         });
-        self.dispatcher.set_mode(InputMode::ConfirmDialog);
+        self.dispatcher.set_mode(InputMode::Normal);
     }
 
     pub(super) fn initiate_debug_toggle(&mut self) {
@@ -179,9 +292,8 @@ impl App {
             return;
         }
         let message = format!("Toggle debug mode for pod/{name}\nin namespace {namespace}?");
-        self.pending_confirmation =
-            Some(PendingConfirmation { message, action: PendingAction::ToggleDebugMode { name, namespace } });
-        self.dispatcher.set_mode(InputMode::ConfirmDialog);
+        let target = name.clone();
+        self.request_confirmation("debug_mode", &target, message, PendingAction::ToggleDebugMode { name, namespace });
     }
 
     pub(super) fn initiate_root_debug_toggle(&mut self) {
@@ -191,17 +303,130 @@ impl App {
             return;
         }
         let message = format!("Toggle root debug mode for pod/{name}\nin namespace {namespace}?\n\nThis will set securityContext.runAsUser: 0");
-        self.pending_confirmation =
-            Some(PendingConfirmation { message, action: PendingAction::ToggleRootDebugMode { name, namespace } });
-        self.dispatcher.set_mode(InputMode::ConfirmDialog);
+        let target = name.clone();
+        self.request_confirmation(
+            "root_debug_mode",
+            &target,
+            message,
+            PendingAction::ToggleRootDebugMode { name, namespace },
+        );
+    }
+
+    pub(super) fn initiate_evict(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        if kind != ResourceKind::Pods {
+            self.toasts.push(ToastMessage::info("Evict is only available for Pods"));
+            return;
+        }
+        let message = format!("Evict pod/{name}\nin namespace {namespace}?\n\nPodDisruptionBudgets are respected.");
+        let target = name.clone();
+        self.request_confirmation("evict", &target, message, PendingAction::EvictPod { name, namespace });
+    }
+
+    /// Plans a drain of the selected node; the confirmation follows once the plan arrives.
+    pub(super) fn initiate_drain(&mut self) {
+        let Some((kind, name, _)) = self.selected_resource_info() else { return };
+        if kind != ResourceKind::Nodes {
+            self.toasts.push(ToastMessage::info("Drain is only available for Nodes"));
+            return;
+        }
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        if self.deny_if_forbidden("drain") {
+            return;
+        }
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        let drain = &self.general_config.drain;
+        let options = DrainOptions { force: drain.force, delete_emptydir_data: drain.delete_emptydir_data };
+
+        tokio::spawn(async move {
+            let event = match plan_drain(kube_client, &name, options).await {
+                Ok(plan) => AppEvent::DrainPlanned(plan),
+                Err(e) => {
+                    report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                    AppEvent::Toast(ToastMessage::error(format!("Failed to plan drain of no {name}: {e}")))
+                }
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+
+    pub(super) fn handle_drain_planned(&mut self, plan: DrainPlan) {
+        let mut message =
+            format!("Drain node/{}?\n\nCordons the node and evicts {} pod(s)", plan.node, plan.pods.len());
+        if plan.skipped > 0 {
+            message.push_str(&format!(", leaving {} DaemonSet or mirror pod(s)", plan.skipped));
+        }
+        message.push('.');
+        if !plan.unmanaged.is_empty() {
+            message.push_str("\n\nLeft in place, no controller would recreate them:");
+            for (namespace, name) in &plan.unmanaged {
+                message.push_str(&format!("\n  {namespace}/{name}"));
+            }
+        }
+        if !plan.local_data.is_empty() {
+            message.push_str("\n\nLeft in place, evicting them loses their emptyDir data:");
+            for (namespace, name) in &plan.local_data {
+                message.push_str(&format!("\n  {namespace}/{name}"));
+            }
+        }
+        if plan.violations.is_empty() {
+            message.push_str("\n\nNo PodDisruptionBudget would be violated.");
+        } else {
+            message.push_str("\n\nThese PodDisruptionBudgets will refuse evictions, retried for up to a minute:");
+            for violation in &plan.violations {
+                message.push_str(&format!("\n  {}", violation.describe()));
+            }
+        }
+        let target = plan.node.clone();
+        self.request_confirmation("drain", &target, message, PendingAction::DrainNode(plan));
+    }
+
+    pub(super) fn initiate_force_delete(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        if kind != ResourceKind::Pods {
+            self.toasts.push(ToastMessage::info("Force delete is only available for Pods"));
+            return;
+        }
+        let message = format!(
+            "FORCE delete pod/{name}\nin namespace {namespace}?\n\nGrace period 0: the pod is removed without waiting for its containers to stop."
+        );
+        let target = name.clone();
+        self.request_confirmation("force_delete", &target, message, PendingAction::ForceDeletePod { name, namespace });
+    }
+
+    pub(super) fn initiate_restart_rollout(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        if kind != ResourceKind::Deployments {
+            self.toasts.push(ToastMessage::info("Restart rollout is only available for Deployments"));
+            return;
+        }
+        let message = format!("Restart rollout of deploy/{name}\nin namespace {namespace}?");
+        let target = name.clone();
+        self.request_confirmation("restart", &target, message, PendingAction::RestartRollout { name, namespace });
     }
 
     pub(super) fn execute_confirmed_action(&mut self) {
+        if let Some(pc) = &self.pending_confirmation {
+            if let Some(expected) = pc.type_to_confirm.as_deref().filter(|expected| pc.input != *expected) {
+                self.toasts.push(ToastMessage::error(format!("Type '{expected}' to confirm")));
+                return;
+            }
+        }
         let confirmation = match self.pending_confirmation.take() {
             Some(c) => c,
             None => return,
         };
         self.dispatcher.set_mode(InputMode::Normal);
+        self.fetch_cache.clear();
 
         match confirmation.action {
             PendingAction::Delete { kind, name, namespace } => {
@@ -211,7 +436,11 @@ impl App {
                 };
                 let kube_client = client.inner_client();
                 let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
                 let display_name = format!("{} {}", kind.short_name(), name);
+                let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+                let undo_key =
+                    self.dispatcher.key_for("undo_delete").filter(|_| self.general_config.undo_window_secs > 0);
 
                 tokio::spawn(async move {
                     let executor = kubetile_core::ActionExecutor::new(kube_client);
@@ -251,14 +480,137 @@ impl App {
                                 .delete::<k8s_openapi::api::core::v1::PersistentVolumeClaim>(&name, &namespace)
                                 .await
                         }
+                        ResourceKind::NetworkPolicies => {
+                            executor.delete::<k8s_openapi::api::networking::v1::NetworkPolicy>(&name, &namespace).await
+                        }
+                        ResourceKind::ResourceQuotas => {
+                            executor.delete::<k8s_openapi::api::core::v1::ResourceQuota>(&name, &namespace).await
+                        }
+                        ResourceKind::LimitRanges => {
+                            executor.delete::<k8s_openapi::api::core::v1::LimitRange>(&name, &namespace).await
+                        }
+                        ResourceKind::PodDisruptionBudgets => {
+                            executor
+                                .delete::<k8s_openapi::api::policy::v1::PodDisruptionBudget>(&name, &namespace)
+                                .await
+                        }
+                        ResourceKind::Nodes => executor.delete_cluster::<k8s_openapi::api::core::v1::Node>(&name).await,
+                        ResourceKind::Namespaces => {
+                            executor.delete_cluster::<k8s_openapi::api::core::v1::Namespace>(&name).await
+                        }
+                        ResourceKind::PersistentVolumes => {
+                            executor.delete_cluster::<k8s_openapi::api::core::v1::PersistentVolume>(&name).await
+                        }
                         _ => Err(anyhow::anyhow!("Delete not supported for this resource type")),
                     };
 
-                    let toast_event = match result {
-                        Ok(()) => AppEvent::Toast(ToastMessage::success(format!("Deleted {display_name}"))),
-                        Err(e) => AppEvent::Toast(ToastMessage::error(format!("Failed to delete {display_name}: {e}"))),
+                    let toast = match result {
+                        Ok(manifest) => {
+                            let undo_key = undo_key.filter(|_| manifest.is_some());
+                            let _ = app_tx.send(AppEvent::ResourceDeleted { kind, name, namespace, context, manifest });
+                            match undo_key {
+                                Some(key) => ToastMessage::success(format!("Deleted {display_name}; {key} undoes")),
+                                None => ToastMessage::success(format!("Deleted {display_name}")),
+                            }
+                        }
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            ToastMessage::error(format!("Failed to delete {display_name}: {e}"))
+                        }
+                    };
+                    let _ = app_tx.send(AppEvent::Toast(toast));
+                });
+            }
+            PendingAction::EvictPod { name, namespace } => {
+                let Some(client) = &self.kube_client else {
+                    self.toasts.push(ToastMessage::error("No cluster connection"));
+                    return;
+                };
+                let kube_client = client.inner_client();
+                let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+
+                tokio::spawn(async move {
+                    let executor = kubetile_core::ActionExecutor::new(kube_client);
+                    let toast = match executor.evict_pod(&name, &namespace).await {
+                        Ok(()) => ToastMessage::success(format!("Evicted po {name}")),
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            ToastMessage::error(format!("Failed to evict po {name}: {e}"))
+                        }
+                    };
+                    let _ = app_tx.send(AppEvent::Toast(toast));
+                });
+            }
+            PendingAction::ApplyManifests { pane_id } => self.run_apply(pane_id),
+            PendingAction::UndoDelete { id } => self.run_restore(id),
+            PendingAction::DrainNode(plan) => {
+                let Some(client) = &self.kube_client else {
+                    self.toasts.push(ToastMessage::error("No cluster connection"));
+                    return;
+                };
+                let kube_client = client.inner_client();
+                let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+
+                tokio::spawn(async move {
+                    let executor = kubetile_core::ActionExecutor::new(kube_client);
+                    let node = plan.node;
+                    if let Err(e) = executor.cordon_node(&node).await {
+                        report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                        let toast = ToastMessage::error(format!("Failed to cordon no {node}: {e}"));
+                        let _ = app_tx.send(AppEvent::Toast(toast));
+                        return;
+                    }
+                    let mut failed = Vec::new();
+                    for (namespace, name) in &plan.pods {
+                        if let Err(e) = evict(&executor, namespace, name).await {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            failed.push(format!("{namespace}/{name}: {e}"));
+                        }
+                    }
+                    let evicted = plan.pods.len() - failed.len();
+                    let toast = match failed.first() {
+                        None => ToastMessage::success(format!("Drained no {node}: evicted {evicted} pod(s)")),
+                        Some(first) => ToastMessage::error(format!(
+                            "Drain of no {node} incomplete: evicted {evicted}, {} refused (first: {first})",
+                            failed.len()
+                        )),
+                    };
+                    let _ = app_tx.send(AppEvent::Toast(toast));
+                });
+            }
+            PendingAction::ForceDeletePod { name, namespace } => {
+                let Some(client) = &self.kube_client else {
+                    self.toasts.push(ToastMessage::error("No cluster connection"));
+                    return;
+                };
+                let kube_client = client.inner_client();
+                let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+                let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+                let undo_key =
+                    self.dispatcher.key_for("undo_delete").filter(|_| self.general_config.undo_window_secs > 0);
+
+                tokio::spawn(async move {
+                    let executor = kubetile_core::ActionExecutor::new(kube_client);
+                    let toast = match executor.force_delete_pod(&name, &namespace).await {
+                        Ok(manifest) => {
+                            let deleted = format!("Force deleted po {name}");
+                            let undo_key = undo_key.filter(|_| manifest.is_some());
+                            let kind = ResourceKind::Pods;
+                            let _ = app_tx.send(AppEvent::ResourceDeleted { kind, name, namespace, context, manifest });
+                            match undo_key {
+                                Some(key) => ToastMessage::success(format!("{deleted}; {key} undoes")),
+                                None => ToastMessage::success(deleted),
+                            }
+                        }
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            ToastMessage::error(format!("Failed to force delete po {name}: {e}"))
+                        }
                     };
-                    let _ = app_tx.send(toast_event);
+                    let _ = app_tx.send(AppEvent::Toast(toast));
                 });
             }
             PendingAction::SaveLogs { path, content } => {
@@ -281,6 +633,7 @@ impl App {
                 };
                 let kube_client = client.inner_client();
                 let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
                 let context = self.context_resolver.context_name().unwrap_or("unknown-context").to_string();
 
                 self.toasts.push(ToastMessage::info(format!("Downloading logs for {pod_name}...")));
@@ -326,7 +679,10 @@ impl App {
                                 Err(e) => AppEvent::Toast(ToastMessage::error(format!("Failed to write file: {e}"))),
                             }
                         }
-                        Err(e) => AppEvent::Toast(ToastMessage::error(format!("Failed to fetch logs: {e}"))),
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, &e);
+                            AppEvent::Toast(ToastMessage::error(format!("Failed to fetch logs: {e}")))
+                        }
                     };
                     let _ = app_tx.send(event);
                 });
@@ -338,6 +694,7 @@ impl App {
                 };
                 let kube_client = client.inner_client();
                 let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
 
                 tokio::spawn(async move {
                     let executor = kubetile_core::ActionExecutor::new(kube_client.clone());
@@ -345,6 +702,7 @@ impl App {
                     let deploy_name = match executor.resolve_owner_deployment(&pod_name, &namespace).await {
                         Ok(d) => d,
                         Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
                             let _ = app_tx.send(AppEvent::Toast(ToastMessage::error(format!("{e}"))));
                             return;
                         }
@@ -353,6 +711,7 @@ impl App {
                     let in_debug = match executor.is_in_debug_mode(&deploy_name, &namespace).await {
                         Ok(v) => v,
                         Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
                             let _ = app_tx
                                 .send(AppEvent::Toast(ToastMessage::error(format!("Debug mode check failed: {e}"))));
                             return;
@@ -372,7 +731,10 @@ impl App {
                         Ok(()) => ToastMessage::success(format!(
                             "Entered debug mode for deploy/{deploy_name} — pods will restart with sleep infinity"
                         )),
-                        Err(e) => ToastMessage::error(format!("Debug mode toggle failed: {e}")),
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            ToastMessage::error(format!("Debug mode toggle failed: {e}"))
+                        }
                     };
                     let _ = app_tx.send(AppEvent::Toast(toast));
                 });
@@ -384,6 +746,7 @@ impl App {
                 };
                 let kube_client = client.inner_client();
                 let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
 
                 tokio::spawn(async move {
                     let executor = kubetile_core::ActionExecutor::new(kube_client.clone());
@@ -391,6 +754,7 @@ impl App {
                     let deploy_name = match executor.resolve_owner_deployment(&pod_name, &namespace).await {
                         Ok(d) => d,
                         Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
                             let _ = app_tx.send(AppEvent::Toast(ToastMessage::error(format!("{e}"))));
                             return;
                         }
@@ -399,6 +763,7 @@ impl App {
                     let in_root_debug = match executor.is_in_root_debug_mode(&deploy_name, &namespace).await {
                         Ok(v) => v,
                         Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
                             let _ = app_tx.send(AppEvent::Toast(ToastMessage::error(format!(
                                 "Root debug mode check failed: {e}"
                             ))));
@@ -419,11 +784,46 @@ impl App {
                         Ok(()) => ToastMessage::success(format!(
                             "Entered root debug mode for deploy/{deploy_name} — pods will restart with sleep infinity as root"
                         )),
-                        Err(e) => ToastMessage::error(format!("Root debug mode toggle failed: {e}")),
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            ToastMessage::error(format!("Root debug mode toggle failed: {e}"))
+                        }
                     };
                     let _ = app_tx.send(AppEvent::Toast(toast));
                 });
             }
+            PendingAction::RestartRollout { name, namespace } => {
+                let Some(client) = &self.kube_client else {
+                    self.toasts.push(ToastMessage::error("No cluster connection"));
+                    return;
+                };
+                let kube_client = client.inner_client();
+                let app_tx = self.app_tx.clone();
+                let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+
+                tokio::spawn(async move {
+                    let executor = kubetile_core::ActionExecutor::new(kube_client);
+                    match executor.restart_rollout(&name, &namespace).await {
+                        Ok(()) => {
+                            let _ = app_tx.send(AppEvent::Toast(ToastMessage::success(format!("Restarted {name}"))));
+                            let kind = ResourceKind::Deployments;
+                            let _ = app_tx.send(AppEvent::FollowRollout { kind, name, namespace });
+                        }
+                        Err(e) => {
+                            report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                            let _ = app_tx.send(AppEvent::Toast(ToastMessage::error(format!("Restart failed: {e}"))));
+                        }
+                    }
+                });
+            }
+            PendingAction::SetImage { kind, name, namespace, images } => {
+                self.run_set_image(kind, name, namespace, images);
+            }
+            PendingAction::EditDataKey(patch) => self.run_data_key_patch(patch),
+            // The prompt does not block browsing, so the selection may have moved since it opened.
+            PendingAction::MutateCommand(_) if confirmation.subject != self.selected_resource_info() => {
+                self.toasts.push(ToastMessage::error("The selection changed; nothing was changed"));
+            }
             PendingAction::MutateCommand(cmd) => {
                 self.handle_command(cmd);
             }
@@ -0,0 +1,188 @@
+use kubetile_core::manifests::{apply, load_manifests, plan_apply};
+use kubetile_core::PlannedApply;
+use kubetile_tui::pane::{PaneId, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::ApplyPane;
+
+use super::query::expand_tilde;
+use super::reconnect::report_auth_expired;
+use super::{App, PendingAction};
+
+/// Path prompt for the manifests to diff and apply.
+pub(super) struct ApplyDialog {
+    pub(super) input: String,
+    /// Namespace for documents that name none; Tab cycles it through the selected namespaces.
+    pub(super) namespace: String,
+    /// Take over fields other field managers own instead of failing on conflicts.
+    pub(super) force: bool,
+    pub(super) planning: bool,
+    pub(super) error: Option<String>,
+}
+
+impl App {
+    /// Applies the accepted documents of the focused apply pane, or asks which manifests to diff
+    /// when no apply pane is focused.
+    pub(super) fn apply_manifests(&mut self) {
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<ApplyPane>()) {
+            let context = self.context_resolver.context_name().unwrap_or_default();
+            if pane.context() != context {
+                let message =
+                    format!("This diff was planned against context {}; switch to it to apply", pane.context());
+                self.toasts.push(ToastMessage::error(message));
+                return;
+            }
+            let accepted = pane.accepted_count();
+            if accepted == 0 {
+                self.toasts.push(ToastMessage::info("No documents accepted; Enter accepts the selected one"));
+                return;
+            }
+            let path = pane.path().to_string();
+            let message = format!("Apply {accepted} document(s) from {path}\nto context {context}?");
+            self.request_confirmation("apply", &path, message, PendingAction::ApplyManifests { pane_id: focused });
+            return;
+        }
+        self.apply_dialog = Some(ApplyDialog {
+            input: self.last_apply_path.clone(),
+            namespace: self.context_resolver.namespace().unwrap_or("default").to_string(),
+            force: false,
+            planning: false,
+            error: None,
+        });
+        self.dispatcher.set_mode(InputMode::ApplyPathInput);
+    }
+
+    pub(super) fn close_apply_dialog(&mut self) {
+        if let Some(dialog) = self.apply_dialog.take() {
+            self.last_apply_path = dialog.input;
+        }
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    pub(super) fn apply_path_input(&mut self, c: char) {
+        if let Some(dialog) = self.apply_dialog.as_mut().filter(|d| !d.planning) {
+            dialog.input.push(c);
+        }
+    }
+
+    pub(super) fn apply_path_backspace(&mut self) {
+        if let Some(dialog) = self.apply_dialog.as_mut().filter(|d| !d.planning) {
+            dialog.input.pop();
+        }
+    }
+
+    /// Moves the target namespace to the next of the selected namespaces.
+    pub(super) fn apply_next_namespace(&mut self) {
+        let namespaces = self.context_resolver.namespaces();
+        let Some(dialog) = self.apply_dialog.as_mut().filter(|d| !d.planning) else { return };
+        let next = namespaces.iter().position(|ns| *ns == dialog.namespace).map_or(0, |i| (i + 1) % namespaces.len());
+        if let Some(ns) = namespaces.get(next) {
+            dialog.namespace = ns.to_string();
+        }
+    }
+
+    pub(super) fn apply_toggle_force(&mut self) {
+        if let Some(dialog) = self.apply_dialog.as_mut().filter(|d| !d.planning) {
+            dialog.force = !dialog.force;
+        }
+    }
+
+    /// Reads the manifests at the typed path and dry-runs them in the background.
+    pub(super) fn plan_manifests(&mut self) {
+        let client = self.kube_client.as_ref().map(|c| c.inner_client());
+        let Some(dialog) = self.apply_dialog.as_mut().filter(|d| !d.planning) else { return };
+        let input = dialog.input.trim().to_string();
+        if input.is_empty() {
+            dialog.error = Some("enter a file or directory".into());
+            return;
+        }
+        let Some(client) = client else {
+            dialog.error = Some("not connected to a cluster".into());
+            return;
+        };
+        dialog.planning = true;
+        dialog.error = None;
+        let (namespace, force) = (dialog.namespace.clone(), dialog.force);
+        let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = match load_manifests(&expand_tilde(&input)) {
+                Ok(manifests) if manifests.is_empty() => Err(format!("no manifests found in {input}")),
+                Ok(manifests) => Ok(plan_apply(&client, manifests, &namespace, force).await),
+                Err(e) => Err(format!("{e:#}")),
+            };
+            let _ = app_tx.send(AppEvent::ApplyPlanReady { path: input, context, result });
+        });
+    }
+
+    pub(super) fn handle_apply_plan(
+        &mut self,
+        path: String,
+        context: String,
+        result: Result<Vec<PlannedApply>, String>,
+    ) {
+        let Some(dialog) = self.apply_dialog.as_mut() else { return };
+        let plans = match result {
+            Ok(plans) => plans,
+            Err(e) => {
+                dialog.planning = false;
+                dialog.error = Some(e);
+                return;
+            }
+        };
+        self.close_apply_dialog();
+
+        let focused = self.tab_manager.active().focused_pane;
+        let view = ViewType::Plugin("Apply".into());
+        if let Some(pane_id) = self.split_auto_opened(focused, view, self.general_config.splits.detail, 0.5) {
+            self.panes.insert(pane_id, Box::new(ApplyPane::new(path, context, plans)));
+            self.set_focus(pane_id);
+        }
+    }
+
+    /// Server-side applies the accepted documents of an apply pane one after another.
+    pub(super) fn run_apply(&mut self, pane_id: PaneId) {
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let Some(pane) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<ApplyPane>()) else {
+            return;
+        };
+        let documents = pane.start_apply();
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let total = documents.len();
+            let mut applied = 0;
+            for (index, plan) in documents {
+                let result = apply(&client, &plan)
+                    .await
+                    .inspect_err(|e| report_auth_expired(&app_tx, &auth_context, e.as_ref()))
+                    .map_err(|e| format!("{e:#}"));
+                applied += usize::from(result.is_ok());
+                let _ = app_tx.send(AppEvent::ManifestApplied { pane_id, index, result });
+            }
+            let toast = if applied == total {
+                ToastMessage::success(format!("Applied {applied} document(s)"))
+            } else {
+                ToastMessage::error(format!("Applied {applied} of {total} documents; see the apply pane"))
+            };
+            let _ = app_tx.send(AppEvent::Toast(toast));
+        });
+    }
+
+    pub(super) fn handle_manifest_applied(&mut self, pane_id: PaneId, index: usize, result: Result<(), String>) {
+        if let Some(pane) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<ApplyPane>()) {
+            pane.set_result(index, result);
+        }
+    }
+}
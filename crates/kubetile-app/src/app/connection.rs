@@ -0,0 +1,82 @@
+use kubetile_core::connection::{self, ConnectionProfile};
+use kubetile_tui::pane::ViewType;
+
+use crate::panes::ConnectionPane;
+
+use super::App;
+
+/// The last failed attempt to reach a cluster, kept for the connection diagnostics tab.
+pub(super) struct ConnectionFailure {
+    pub(super) error: String,
+    /// Likely cause, shown in the offline banner and toasts instead of the raw error.
+    pub(super) cause: String,
+    pub(super) profile: Option<ConnectionProfile>,
+}
+
+impl ConnectionFailure {
+    pub(super) fn new(context: Option<&str>, error: String) -> Self {
+        let profile = ConnectionProfile::load(context);
+        let cause = connection::diagnose(&error, profile.as_ref()).cause;
+        Self { error, cause, profile }
+    }
+}
+
+impl App {
+    /// Remembers why connecting to `context` failed and returns a one-line summary that
+    /// points at the diagnostics tab.
+    pub(super) fn record_connection_failure(&mut self, context: Option<&str>, error: String) -> String {
+        let failure = ConnectionFailure::new(context, error);
+        let summary = match self.dispatcher.key_for("connection_diagnostics") {
+            Some(key) => format!("{} ({key}: details)", failure.cause),
+            None => failure.cause.clone(),
+        };
+        self.connection_failure = Some(failure);
+        self.refresh_connection_panes();
+        summary
+    }
+
+    pub(super) fn clear_connection_failure(&mut self) {
+        if self.connection_failure.take().is_some() {
+            self.refresh_connection_panes();
+        }
+    }
+
+    fn connection_report(&self) -> Vec<kubetile_core::DetailSection> {
+        match &self.connection_failure {
+            Some(failure) => connection::report(Some(&failure.error), failure.profile.as_ref()),
+            None => connection::report(None, ConnectionProfile::load(self.context_resolver.context_name()).as_ref()),
+        }
+    }
+
+    fn refresh_connection_panes(&mut self) {
+        if !self.panes.values().any(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == "Connection")) {
+            return;
+        }
+        let sections = self.connection_report();
+        for pane in self.panes.values_mut() {
+            if let Some(pane) = pane.as_any_mut().downcast_mut::<ConnectionPane>() {
+                pane.set_sections(sections.clone());
+            }
+        }
+    }
+
+    pub(super) fn toggle_connection_tab(&mut self) {
+        let active_tab_id = self.tab_manager.active().id;
+        if self.is_plugin_tab(active_tab_id, "Connection") {
+            self.close_tab();
+            return;
+        }
+
+        if let Some(idx) = self.find_plugin_tab_index("Connection") {
+            self.switch_to_tab_index(idx);
+            return;
+        }
+
+        self.sync_active_scope();
+        let tab_id = self.tab_manager.new_tab("Connection", ViewType::Plugin("Connection".into()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        self.panes.insert(pane_id, Box::new(ConnectionPane::new(self.connection_report())));
+        self.sync_active_scope();
+        self.update_active_tab_title();
+    }
+}
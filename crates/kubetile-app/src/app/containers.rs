@@ -0,0 +1,173 @@
+use k8s_openapi::api::core::v1::Pod;
+use kube::Api;
+
+use kubetile_core::container_memory::{pod_containers, pod_workload};
+use kubetile_core::ContainerMemory;
+use kubetile_tui::pane::PaneId;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::{AppEvent, ContainerPurpose};
+use crate::panes::{ExecPane, LogsPane};
+
+use super::App;
+
+pub(super) struct ContainerPicker {
+    pub(super) purpose: ContainerPurpose,
+    pub(super) pane_id: Option<PaneId>,
+    pub(super) pod: String,
+    pub(super) namespace: String,
+    pub(super) memory_key: String,
+    pub(super) containers: Vec<String>,
+    pub(super) selected: usize,
+    pub(super) remembered: Option<String>,
+}
+
+impl App {
+    /// Reads the pod's containers before opening logs/exec so a remembered choice can be reused.
+    /// Without a cluster connection the pane opens straight away and kubectl picks the container.
+    pub(super) fn resolve_container(
+        &mut self,
+        purpose: ContainerPurpose,
+        pane_id: Option<PaneId>,
+        pod: String,
+        namespace: String,
+    ) {
+        let Some(client) = &self.kube_client else {
+            self.open_with_container(purpose, pane_id, pod, namespace, None);
+            return;
+        };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+
+        tokio::spawn(async move {
+            let pods: Api<Pod> = Api::namespaced(kube_client, &namespace);
+            let (workload, containers) = match pods.get(&pod).await {
+                Ok(p) => (pod_workload(&p), pod_containers(&p)),
+                Err(e) => {
+                    tracing::warn!("Could not read containers of {namespace}/{pod}: {e}");
+                    (format!("Pod/{pod}"), Vec::new())
+                }
+            };
+            let _ =
+                app_tx.send(AppEvent::ContainersResolved { purpose, pane_id, pod, namespace, workload, containers });
+        });
+    }
+
+    pub(super) fn handle_containers_resolved(
+        &mut self,
+        purpose: ContainerPurpose,
+        pane_id: Option<PaneId>,
+        pod: String,
+        namespace: String,
+        workload: String,
+        containers: Vec<String>,
+    ) {
+        let context = self.context_resolver.context_name().unwrap_or_default();
+        let memory_key = ContainerMemory::key(context, &namespace, &workload);
+        let remembered =
+            self.container_memory.get(&memory_key).filter(|c| containers.iter().any(|n| n == c)).map(str::to_string);
+
+        if pane_id.is_none() {
+            if containers.len() <= 1 {
+                self.open_with_container(purpose, None, pod, namespace, containers.into_iter().next());
+                return;
+            }
+            if let Some(container) = remembered {
+                self.open_with_container(purpose, None, pod, namespace, Some(container));
+                return;
+            }
+        } else if containers.len() <= 1 {
+            self.toasts.push(ToastMessage::info(format!("{pod} has a single container")));
+            return;
+        }
+
+        let current = pane_id.and_then(|id| self.pane_container(id));
+        let selected = current
+            .as_deref()
+            .or(remembered.as_deref())
+            .and_then(|c| containers.iter().position(|n| n == c))
+            .unwrap_or(0);
+        self.container_picker =
+            Some(ContainerPicker { purpose, pane_id, pod, namespace, memory_key, containers, selected, remembered });
+        self.dispatcher.set_mode(InputMode::ContainerPicker);
+    }
+
+    pub(super) fn container_picker_move(&mut self, forward: bool) {
+        let Some(picker) = &mut self.container_picker else { return };
+        let len = picker.containers.len();
+        if len == 0 {
+            return;
+        }
+        picker.selected = if forward { (picker.selected + 1) % len } else { (picker.selected + len - 1) % len };
+    }
+
+    pub(super) fn confirm_container_picker(&mut self) {
+        let Some(picker) = self.container_picker.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        let Some(container) = picker.containers.get(picker.selected).cloned() else { return };
+
+        if let Err(e) = self.container_memory.remember(picker.memory_key, &container) {
+            tracing::warn!("Failed to save container choice: {e}");
+        }
+        self.open_with_container(picker.purpose, picker.pane_id, picker.pod, picker.namespace, Some(container));
+    }
+
+    pub(super) fn cancel_container_picker(&mut self) {
+        self.container_picker = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    /// Re-picks the container shown in the focused logs or exec pane.
+    pub(super) fn switch_container(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(pane) = self.panes.get(&focused) else { return };
+        let target = if let Some(logs) = pane.as_any().downcast_ref::<LogsPane>() {
+            Some((ContainerPurpose::Logs, logs.pod_name().to_string(), logs.namespace().to_string()))
+        } else {
+            pane.as_any()
+                .downcast_ref::<ExecPane>()
+                .map(|exec| (ContainerPurpose::Exec, exec.pod_name().to_string(), exec.namespace().to_string()))
+        };
+        match target {
+            Some((purpose, pod, namespace)) => self.resolve_container(purpose, Some(focused), pod, namespace),
+            None => self.toasts.push(ToastMessage::info("Switch container works in logs and exec panes")),
+        }
+    }
+
+    fn pane_container(&self, pane_id: PaneId) -> Option<String> {
+        let pane = self.panes.get(&pane_id)?;
+        if let Some(logs) = pane.as_any().downcast_ref::<LogsPane>() {
+            return logs.container().cloned();
+        }
+        pane.as_any().downcast_ref::<ExecPane>().map(|exec| exec.container().to_string())
+    }
+
+    fn open_with_container(
+        &mut self,
+        purpose: ContainerPurpose,
+        pane_id: Option<PaneId>,
+        pod: String,
+        namespace: String,
+        container: Option<String>,
+    ) {
+        match (purpose, pane_id) {
+            (ContainerPurpose::Logs, None) => self.open_logs_pane_for(pod, namespace, container),
+            (ContainerPurpose::Logs, Some(id)) => {
+                let mut pane = LogsPane::new(pod.clone(), namespace.clone());
+                pane.set_highlights(self.log_highlights.clone());
+                self.panes.insert(id, Box::new(pane));
+                self.start_logs_stream_for_pane(id, pod, namespace, container);
+            }
+            (ContainerPurpose::Exec, None) => {
+                let focused = self.tab_manager.active().focused_pane;
+                self.open_exec_pane_for(focused, pod, namespace, container);
+            }
+            (ContainerPurpose::Exec, Some(id)) => {
+                if self.open_exec_pane_for(id, pod, namespace, container) {
+                    self.close_pane(id);
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,9 @@
+use std::time::Duration;
+
 use kubetile_tui::pane::PaneCommand;
+use kubetile_tui::widgets::context_selector::{self, ContextHealth};
+use kubetile_tui::widgets::namespace_selector::selector_entries;
+use kubetile_tui::widgets::toast::ToastMessage;
 
 use crate::command::InputMode;
 use crate::event::AppEvent;
@@ -6,6 +11,8 @@ use crate::panes::ResourceListPane;
 
 use super::App;
 
+const CONTEXT_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
 impl App {
     pub(super) fn handle_namespace_confirm(&mut self) {
         self.select_namespace();
@@ -22,6 +29,18 @@ impl App {
         self.namespace_selected = 0;
     }
 
+    pub(super) fn handle_namespace_toggle(&mut self) {
+        let Some(ns) = self.filtered_namespaces().get(self.namespace_selected).cloned() else { return };
+        if ns == "All Namespaces" {
+            return;
+        }
+        if let Some(i) = self.namespace_marked.iter().position(|m| *m == ns) {
+            self.namespace_marked.remove(i);
+        } else {
+            self.namespace_marked.push(ns);
+        }
+    }
+
     pub(super) fn handle_context_confirm(&mut self) {
         self.select_context();
     }
@@ -67,8 +86,23 @@ impl App {
     }
 
     pub(super) fn select_namespace(&mut self) {
+        if !self.namespace_marked.is_empty() {
+            let marked = std::mem::take(&mut self.namespace_marked);
+            if let Some(ref mut client) = self.kube_client {
+                client.set_namespace(&marked[0]);
+            }
+            self.context_resolver.set_namespaces(&marked);
+            self.remember_namespaces(&marked);
+            self.restart_watchers_for_active_panes();
+            self.sync_active_scope();
+            self.update_active_tab_title();
+            return;
+        }
         let filtered = self.filtered_namespaces();
         if let Some(ns) = filtered.get(self.namespace_selected).cloned() {
+            if ns != "All Namespaces" {
+                self.remember_namespaces(std::slice::from_ref(&ns));
+            }
             let ns = if ns == "All Namespaces" { "default".to_string() } else { ns };
 
             if let Some(ref mut client) = self.kube_client {
@@ -82,29 +116,47 @@ impl App {
     }
 
     pub(super) fn filtered_namespaces(&self) -> Vec<String> {
-        let filter_lower = self.namespace_filter.to_lowercase();
-        let mut result = Vec::new();
+        let context = self.context_resolver.context_name().unwrap_or_default();
+        selector_entries(
+            &self.namespaces,
+            &self.namespace_filter,
+            self.namespace_memory.favorites(context),
+            self.namespace_memory.recent(context),
+        )
+        .into_iter()
+        .map(String::from)
+        .collect()
+    }
 
-        if filter_lower.is_empty() || "all namespaces".contains(&filter_lower) {
-            result.push("All Namespaces".to_string());
+    /// Pins or unpins the highlighted namespace for the current context.
+    pub(super) fn handle_namespace_favorite(&mut self) {
+        let Some(ns) = self.filtered_namespaces().get(self.namespace_selected).cloned() else { return };
+        if ns == "All Namespaces" {
+            return;
         }
+        let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        match self.namespace_memory.toggle_favorite(&context, &ns) {
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to save favorite namespaces: {e}"),
+        }
+        // Keep the highlight on the namespace as it moves.
+        self.namespace_selected =
+            self.filtered_namespaces().iter().position(|n| *n == ns).unwrap_or(self.namespace_selected);
+    }
 
-        for ns in &self.namespaces {
-            if filter_lower.is_empty() || ns.to_lowercase().contains(&filter_lower) {
-                result.push(ns.clone());
+    fn remember_namespaces(&mut self, namespaces: &[String]) {
+        let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        // The first namespace ends up most recent.
+        for ns in namespaces.iter().rev() {
+            if let Err(e) = self.namespace_memory.record_use(&context, ns) {
+                tracing::warn!("Failed to save recent namespaces: {e}");
+                return;
             }
         }
-
-        result
     }
 
     pub(super) fn filtered_contexts(&self) -> Vec<String> {
-        let filter_lower = self.context_filter.to_lowercase();
-        self.contexts
-            .iter()
-            .filter(|ctx| filter_lower.is_empty() || ctx.to_lowercase().contains(&filter_lower))
-            .cloned()
-            .collect()
+        context_selector::selector_entries(&self.contexts, &self.context_filter).into_iter().map(String::from).collect()
     }
 
     pub(super) fn select_context(&mut self) {
@@ -119,22 +171,54 @@ impl App {
             return;
         }
 
+        let ssh_host = self.general_config.ssh_host_for(Some(&context)).map(str::to_string);
         let app_tx = self.app_tx.clone();
         tokio::spawn(async move {
-            match kubetile_core::KubeClient::from_context(&context).await {
+            match kubetile_core::KubeClient::connect_to(Some(&context), ssh_host.as_deref()).await {
                 Ok(client) => {
                     let namespaces = client.list_namespaces().await.unwrap_or_default();
                     let _ = app_tx.send(AppEvent::ContextSwitchReady { client, namespaces });
                 }
                 Err(e) => {
                     let _ =
-                        app_tx.send(AppEvent::ContextSwitchError { context: context.clone(), error: e.to_string() });
+                        app_tx.send(AppEvent::ContextSwitchError { context: context.clone(), error: format!("{e:#}") });
                 }
             }
         });
         self.dispatcher.set_mode(InputMode::Normal);
     }
 
+    /// Probes every kubeconfig context in the background so the selector can show reachability.
+    pub(super) fn probe_contexts(&mut self) {
+        self.context_health.clear();
+        for context in &self.contexts {
+            self.context_health.insert(context.clone(), ContextHealth::Probing);
+            let context = context.clone();
+            let ssh_host = self.general_config.ssh_host_for(Some(&context)).map(str::to_string);
+            let app_tx = self.app_tx.clone();
+            tokio::spawn(async move {
+                let result =
+                    kubetile_core::KubeClient::probe_context(&context, ssh_host.as_deref(), CONTEXT_PROBE_TIMEOUT)
+                        .await
+                        .map_err(|e| e.to_string());
+                let _ = app_tx.send(AppEvent::ContextProbed { context, result });
+            });
+        }
+    }
+
+    pub(super) fn handle_context_probed(&mut self, context: String, result: Result<Duration, String>) {
+        let health = match result {
+            Ok(latency) => ContextHealth::Reachable { latency_ms: latency.as_millis() as u64 },
+            Err(error) => {
+                tracing::debug!("Context {context} is unreachable: {error}");
+                ContextHealth::Unreachable(error)
+            }
+        };
+        if let Some(entry) = self.context_health.get_mut(&context) {
+            *entry = health;
+        }
+    }
+
     pub(super) fn refresh_namespaces(&self) {
         let Some(client) = self.kube_client.clone() else { return };
         let app_tx = self.app_tx.clone();
@@ -151,15 +235,41 @@ impl App {
     pub(super) fn apply_context_switch(&mut self, client: kubetile_core::KubeClient, namespaces: Vec<String>) {
         self.stop_all_port_forwards();
         self.context_resolver.set_context(client.cluster_context());
+        self.apply_context_display();
         self.kube_client = Some(client);
+        self.clear_connection_failure();
+        self.reset_api_health();
+        self.fetch_cache.clear();
         self.namespaces = namespaces;
         self.namespace_filter.clear();
         self.namespace_selected = 0;
+        self.namespace_marked.clear();
         self.restart_watchers_for_active_panes();
         self.sync_active_scope();
         self.update_active_tab_title();
     }
 
+    /// Applies what follows the current context: its accent color and display timezone.
+    pub(super) fn apply_context_display(&mut self) {
+        self.theme = self.base_theme.for_context(self.context_resolver.context_name());
+        self.apply_display_timezone();
+    }
+
+    fn apply_display_timezone(&mut self) {
+        let tz = self.general_config.timezone_for(self.context_resolver.context_name());
+        match kubetile_core::display_timezone(tz) {
+            Ok(zone) => {
+                self.toasts.set_timezone(zone.clone());
+                self.display_timezone = zone;
+                self.refresh_trash_panes();
+            }
+            Err(e) => {
+                tracing::warn!("Invalid display timezone {tz}: {e}");
+                self.toasts.push(ToastMessage::error(format!("Invalid timezone '{tz}': {e}")));
+            }
+        }
+    }
+
     pub(super) fn restart_watchers_for_active_panes(&mut self) {
         let pane_ids: Vec<_> = self.tab_manager.active().pane_tree.leaf_ids();
         for pane_id in &pane_ids {
@@ -181,16 +291,8 @@ impl App {
                 }
             }
 
-            let ns = if kind.is_namespaced() {
-                if all_namespaces {
-                    String::new()
-                } else {
-                    self.context_resolver.namespace().unwrap_or("default").to_string()
-                }
-            } else {
-                String::new()
-            };
-            self.start_watcher_for_pane(pane_id, &kind, &ns);
+            let namespaces = self.watch_namespaces(&kind, all_namespaces);
+            self.start_watcher_for_pane(pane_id, &kind, &namespaces);
         }
     }
 }
@@ -0,0 +1,119 @@
+use std::time::Instant;
+
+use kubetile_core::cluster_overview::cluster_overview;
+use kubetile_core::ClusterOverview;
+use kubetile_tui::pane::{PaneId, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::resource_list::ListScope;
+use crate::panes::DashboardPane;
+
+use super::App;
+
+impl App {
+    pub(super) fn toggle_dashboard_tab(&mut self) {
+        let active_tab_id = self.tab_manager.active().id;
+        if self.is_plugin_tab(active_tab_id, "Dashboard") {
+            self.close_tab();
+            return;
+        }
+
+        if let Some(idx) = self.find_plugin_tab_index("Dashboard") {
+            self.switch_to_tab_index(idx);
+            return;
+        }
+
+        self.sync_active_scope();
+        let tab_id = self.tab_manager.new_tab("Dashboard", ViewType::Plugin("Dashboard".into()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        self.panes.insert(pane_id, Box::new(DashboardPane::new()));
+        self.poll_dashboards();
+        self.sync_active_scope();
+        self.update_active_tab_title();
+    }
+
+    /// Fetches the overview for every dashboard that is due for a refresh.
+    pub(super) fn poll_dashboards(&mut self) {
+        let now = Instant::now();
+        let due: Vec<PaneId> = self
+            .panes
+            .iter_mut()
+            .filter_map(|(&id, pane)| pane.as_any_mut().downcast_mut::<DashboardPane>().map(|d| (id, d)))
+            .filter_map(|(id, dashboard)| dashboard.start_refresh(now).then_some(id))
+            .collect();
+        if due.is_empty() {
+            return;
+        }
+        let Some(client) = &self.kube_client else {
+            for id in due {
+                self.handle_dashboard_ready(id, Err("No cluster connection".into()));
+            }
+            return;
+        };
+        for pane_id in due {
+            let kube_client = client.inner_client();
+            let app_tx = self.app_tx.clone();
+            tokio::spawn(async move {
+                let result = cluster_overview(kube_client).await.map_err(|e| e.to_string());
+                let _ = app_tx.send(AppEvent::DashboardReady { pane_id, result });
+            });
+        }
+    }
+
+    pub(super) fn handle_dashboard_ready(&mut self, pane_id: PaneId, result: Result<ClusterOverview, String>) {
+        let Some(dashboard) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<DashboardPane>())
+        else {
+            return;
+        };
+        match result {
+            Ok(overview) => dashboard.set_overview(&overview),
+            Err(e) => dashboard.set_error(e),
+        }
+    }
+
+    /// Re-reads the cluster for the focused dashboard; `false` when the focused pane is not one.
+    pub(super) fn refresh_focused_dashboard(&mut self) -> bool {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(dashboard) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<DashboardPane>())
+        else {
+            return false;
+        };
+        dashboard.request_refresh();
+        self.poll_dashboards();
+        true
+    }
+
+    /// Opens the list behind the selected tile of the focused dashboard in a new tab; `false` when
+    /// the focused pane is not a dashboard.
+    pub(super) fn open_dashboard_tile(&mut self) -> bool {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(dashboard) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<DashboardPane>()) else {
+            return false;
+        };
+        let Some(target) = dashboard.selected_target().cloned() else {
+            let title = dashboard.selected_title().unwrap_or("This tile");
+            self.toasts.push(ToastMessage::info(format!("{title} has nothing to open")));
+            return true;
+        };
+
+        self.sync_active_scope();
+        let kind = target.kind;
+        let tab_id = self.tab_manager.new_tab(kind.display_name(), ViewType::ResourceList(kind.clone()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        let mut pane = self.new_list_pane(kind.clone(), Vec::new());
+        pane.all_namespaces = target.namespace.is_none() && kind.is_namespaced();
+        pane.scope = target.namespace.map(|namespace| ListScope { namespace, label_selector: None });
+        pane.filter_text = target.filter;
+        pane.field_selector = target.field_selector;
+        pane.problems_only = target.problems_only;
+        let all_namespaces = pane.all_namespaces;
+        self.panes.insert(pane_id, Box::new(pane));
+
+        let namespaces = self.watch_namespaces(&kind, all_namespaces);
+        self.start_watcher_for_pane(pane_id, &kind, &namespaces);
+        self.sync_active_scope();
+        self.update_active_tab_title();
+        true
+    }
+}
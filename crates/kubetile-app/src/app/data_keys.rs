@@ -0,0 +1,295 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use tempfile::TempDir;
+
+use kubetile_core::data_keys::{patch_data_key, read_data};
+use kubetile_core::{DataEntry, DataSnapshot};
+use kubetile_tui::pane::{PaneId, ResourceKind};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::EditorPane;
+
+use super::reconnect::report_auth_expired;
+use super::{App, PendingAction};
+
+/// Keys of a ConfigMap or Secret to pick the one to edit from.
+pub(super) struct DataKeyPicker {
+    kind: ResourceKind,
+    name: String,
+    namespace: String,
+    resource_version: String,
+    entries: Vec<DataEntry>,
+    /// `cm web/settings`
+    pub(super) subject: String,
+    pub(super) labels: Vec<String>,
+    pub(super) selected: usize,
+}
+
+/// A key open in an editor pane, written to `path` with the value it had when it was read.
+pub(super) struct DataKeyEdit {
+    pub(super) kind: ResourceKind,
+    pub(super) name: String,
+    pub(super) namespace: String,
+    pub(super) resource_version: String,
+    pub(super) key: String,
+    pub(super) original: String,
+    pub(super) path: PathBuf,
+    /// The private directory holding `path`; both are removed when the edit is dropped.
+    pub(super) _dir: TempDir,
+}
+
+/// An edited key waiting for confirmation before it is patched.
+#[derive(Debug, Clone)]
+pub struct DataKeyPatch {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub namespace: String,
+    pub resource_version: String,
+    pub key: String,
+    pub value: String,
+}
+
+impl DataKeyEdit {
+    fn subject(&self) -> String {
+        format!("{} {}/{}", self.kind.short_name(), self.namespace, self.name)
+    }
+}
+
+fn core_kind(kind: &ResourceKind) -> kubetile_core::ResourceKind {
+    match kind {
+        ResourceKind::Secrets => kubetile_core::ResourceKind::Secrets,
+        _ => kubetile_core::ResourceKind::ConfigMaps,
+    }
+}
+
+/// `app.conf  14 lines`
+fn entry_label(entry: &DataEntry) -> String {
+    match &entry.value {
+        Some(value) => match value.lines().count() {
+            1 => format!("{}  1 line", entry.key),
+            n => format!("{}  {n} lines", entry.key),
+        },
+        None => format!("{}  binary, not editable", entry.key),
+    }
+}
+
+impl App {
+    /// Reads the keys of the selected ConfigMap or Secret, then asks which one to edit.
+    pub(super) fn initiate_edit_data_key(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        if !matches!(kind, ResourceKind::ConfigMaps | ResourceKind::Secrets) {
+            self.toasts.push(ToastMessage::info("Editing data keys is only available for ConfigMaps and Secrets"));
+            return;
+        }
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        if self.deny_if_forbidden("edit_data") {
+            return;
+        }
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let result = read_data(client, &core_kind(&kind), &name, &namespace)
+                .await
+                .inspect_err(|e| report_auth_expired(&app_tx, &auth_context, e.as_ref()))
+                .map_err(|e| format!("{e:#}"));
+            let _ = app_tx.send(AppEvent::DataKeysReady { kind, name, namespace, result });
+        });
+    }
+
+    pub(super) fn open_data_key_picker(
+        &mut self,
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        result: Result<DataSnapshot, String>,
+    ) {
+        let snapshot = match result {
+            Ok(snapshot) if snapshot.entries.is_empty() => {
+                self.toasts.push(ToastMessage::info(format!("{name} has no data keys")));
+                return;
+            }
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.toasts.push(ToastMessage::error(format!("Could not read the keys of {name}: {e}")));
+                return;
+            }
+        };
+        let subject = format!("{} {namespace}/{name}", kind.short_name());
+        self.data_key_picker = Some(DataKeyPicker {
+            labels: snapshot.entries.iter().map(entry_label).collect(),
+            kind,
+            name,
+            namespace,
+            resource_version: snapshot.resource_version,
+            entries: snapshot.entries,
+            subject,
+            selected: 0,
+        });
+        self.dispatcher.set_mode(InputMode::DataKeyPicker);
+    }
+
+    pub(super) fn data_key_picker_move(&mut self, forward: bool) {
+        let Some(picker) = &mut self.data_key_picker else { return };
+        let len = picker.entries.len();
+        picker.selected = if forward { (picker.selected + 1) % len } else { (picker.selected + len - 1) % len };
+    }
+
+    pub(super) fn cancel_data_key_picker(&mut self) {
+        self.data_key_picker = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    /// Writes the selected key's value to a temporary file and opens it in the editor.
+    pub(super) fn confirm_data_key_picker(&mut self) {
+        let Some(picker) = self.data_key_picker.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        let Some(entry) = picker.entries.into_iter().nth(picker.selected) else { return };
+        let Some(original) = entry.value else {
+            self.toasts.push(ToastMessage::info(format!("{} holds binary data and cannot be edited here", entry.key)));
+            return;
+        };
+
+        let already_open = self.data_key_edits.values().any(|edit| {
+            edit.kind == picker.kind
+                && edit.namespace == picker.namespace
+                && edit.name == picker.name
+                && edit.key == entry.key
+        });
+        if already_open {
+            self.toasts.push(ToastMessage::info(format!("{} is already open in an editor", entry.key)));
+            return;
+        }
+        let (dir, path) = match write_private(&entry.key, &original) {
+            Ok(written) => written,
+            Err(e) => {
+                self.toasts.push(ToastMessage::error(format!("Cannot write {} to a temporary file: {e}", entry.key)));
+                return;
+            }
+        };
+        let edit = DataKeyEdit {
+            kind: picker.kind,
+            name: picker.name,
+            namespace: picker.namespace,
+            resource_version: picker.resource_version,
+            key: entry.key,
+            original,
+            path,
+            _dir: dir,
+        };
+        self.open_editor_pane(edit);
+    }
+
+    fn open_editor_pane(&mut self, edit: DataKeyEdit) {
+        let mut pane = EditorPane::new(format!("{} {}", edit.subject(), edit.key));
+        if let Err(e) = pane.spawn(&self.general_config.editor, &edit.path) {
+            self.toasts.push(ToastMessage::error(format!("Failed to start the editor: {e}")));
+            return;
+        }
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(pane_id) = self.split_auto_opened(
+            focused,
+            kubetile_tui::pane::ViewType::Terminal,
+            self.general_config.splits.exec,
+            0.5,
+        ) else {
+            return;
+        };
+        pane.start_output_forwarding(pane_id, self.app_tx.clone());
+        self.panes.insert(pane_id, Box::new(pane));
+        self.data_key_edits.insert(pane_id, edit);
+        self.set_focus(pane_id);
+        self.dispatcher.set_mode(InputMode::Insert);
+    }
+
+    /// Picks the edited value up once its editor exits, closing the editor pane.
+    pub(super) fn finish_data_key_edit(&mut self, pane_id: PaneId) {
+        let Some(edit) = self.data_key_edits.remove(&pane_id) else { return };
+        let edited = std::fs::read_to_string(&edit.path);
+
+        let was_focused = self.tab_manager.active().focused_pane == pane_id;
+        self.close_pane(pane_id);
+        if was_focused && self.dispatcher.mode() == InputMode::Insert {
+            self.dispatcher.set_mode(InputMode::Normal);
+        }
+        match edited {
+            Ok(edited) => self.review_data_key_edit(edit, edited),
+            Err(e) => self.toasts.push(ToastMessage::error(format!("Could not read the edited {}: {e}", edit.key))),
+        }
+    }
+
+    /// Asks before patching an edited key; an unchanged value is left alone.
+    pub(super) fn review_data_key_edit(&mut self, edit: DataKeyEdit, edited: String) {
+        // Editors add a final newline on save; keep a value that had none without it.
+        let value = match edited.strip_suffix('\n') {
+            Some(stripped) if !edit.original.ends_with('\n') => stripped.to_string(),
+            _ => edited,
+        };
+        if value == edit.original {
+            self.toasts.push(ToastMessage::info(format!("{} unchanged; nothing was applied", edit.key)));
+            return;
+        }
+        let message = format!(
+            "Update key {} of {}?\n\n{} → {} lines; the other keys stay as they are.",
+            edit.key,
+            edit.subject(),
+            edit.original.lines().count(),
+            value.lines().count()
+        );
+        let action = PendingAction::EditDataKey(DataKeyPatch {
+            kind: edit.kind.clone(),
+            name: edit.name.clone(),
+            namespace: edit.namespace.clone(),
+            resource_version: edit.resource_version.clone(),
+            key: edit.key.clone(),
+            value,
+        });
+        self.request_confirmation("edit_data", &edit.name, message, action);
+    }
+
+    pub(super) fn run_data_key_patch(&mut self, patch: DataKeyPatch) {
+        let DataKeyPatch { kind, name, namespace, resource_version, key, value } = patch;
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let result =
+                patch_data_key(client, &core_kind(&kind), &name, &namespace, &resource_version, &key, &value).await;
+            let toast = match result {
+                Ok(()) => ToastMessage::success(format!("Updated {key} of {name}")),
+                Err(e) => {
+                    report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                    ToastMessage::error(format!("Update of {key} failed: {e}"))
+                }
+            };
+            let _ = app_tx.send(AppEvent::Toast(toast));
+        });
+    }
+}
+
+/// Writes `contents` to a new file named `file_name` inside a fresh directory only the user can
+/// open, since it may hold a Secret value. The file is created exclusively, so nothing planted
+/// beforehand is followed or reused. Dropping the directory removes both.
+pub(super) fn write_private(file_name: &str, contents: &str) -> std::io::Result<(TempDir, PathBuf)> {
+    let mut builder = tempfile::Builder::new();
+    builder.prefix("kubetile-edit-");
+    #[cfg(unix)]
+    builder.permissions(std::os::unix::fs::PermissionsExt::from_mode(0o700));
+    let dir = builder.tempdir()?;
+    let mut file = tempfile::Builder::new().prefix(file_name).rand_bytes(0).tempfile_in(dir.path())?;
+    file.write_all(contents.as_bytes())?;
+    let path = file.into_temp_path().keep()?;
+    Ok((dir, path))
+}
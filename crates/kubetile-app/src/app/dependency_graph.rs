@@ -0,0 +1,85 @@
+use kubetile_core::dependency_graph::dependency_graph;
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::GraphPane;
+
+use super::App;
+
+/// The list kind of a graph node's API kind; `None` for kinds kubetile has no view for.
+fn node_kind(kind: &str) -> Option<ResourceKind> {
+    Some(match kind {
+        "Ingress" => ResourceKind::Ingresses,
+        "Service" => ResourceKind::Services,
+        "Deployment" => ResourceKind::Deployments,
+        "StatefulSet" => ResourceKind::StatefulSets,
+        "DaemonSet" => ResourceKind::DaemonSets,
+        "Pod" => ResourceKind::Pods,
+        "PersistentVolumeClaim" => ResourceKind::PersistentVolumeClaims,
+        "ConfigMap" => ResourceKind::ConfigMaps,
+        "Secret" => ResourceKind::Secrets,
+        _ => return None,
+    })
+}
+
+impl App {
+    /// Opens what the focused Ingress, Service, workload or pod leads to as a tree in a graph pane.
+    pub(super) fn open_dependency_graph(&mut self) {
+        let Some((kind, name, namespace)) = self.related_subject() else { return };
+        let core_kind = match kind {
+            ResourceKind::Ingresses => kubetile_core::ResourceKind::Ingresses,
+            ResourceKind::Services => kubetile_core::ResourceKind::Services,
+            ResourceKind::Deployments => kubetile_core::ResourceKind::Deployments,
+            ResourceKind::StatefulSets => kubetile_core::ResourceKind::StatefulSets,
+            ResourceKind::DaemonSets => kubetile_core::ResourceKind::DaemonSets,
+            ResourceKind::Pods => kubetile_core::ResourceKind::Pods,
+            _ => {
+                self.toasts.push(ToastMessage::info(
+                    "Dependency graphs are drawn for Ingresses, Services, workloads and Pods",
+                ));
+                return;
+            }
+        };
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+
+        let pane = GraphPane::new(format!("{} {namespace}/{name}", kind.short_name()), namespace.clone());
+        let focused = self.tab_manager.active().focused_pane;
+        let view = ViewType::Plugin("Graph".into());
+        let Some(pane_id) = self.split_auto_opened(focused, view, self.general_config.splits.detail, 0.5) else {
+            return;
+        };
+        self.panes.insert(pane_id, Box::new(pane));
+        self.set_focus(pane_id);
+
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result =
+                dependency_graph(kube_client, &core_kind, &name, &namespace).await.map_err(|e| format!("{e:#}"));
+            let _ = app_tx.send(AppEvent::DependencyGraphReady { pane_id, result });
+        });
+    }
+
+    /// Opens the detail of the selected node when a graph pane is focused.
+    pub(super) fn open_graph_node(&mut self) -> bool {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(graph) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<GraphPane>()) else {
+            return false;
+        };
+        let Some(line) = graph.selected_line() else { return true };
+        let (name, namespace) = (line.name.clone(), graph.namespace().to_string());
+        if line.note.as_deref() == Some("not found") {
+            self.toasts.push(ToastMessage::info(format!("{} {name} does not exist", line.kind)));
+            return true;
+        }
+        match node_kind(&line.kind) {
+            Some(kind) => self.open_detail_pane(kind, name, namespace),
+            None => self.toasts.push(ToastMessage::info(format!("{} {name} has no view in kubetile", line.kind))),
+        }
+        true
+    }
+}
@@ -0,0 +1,146 @@
+use std::time::Instant;
+
+use kubetile_core::resource::format_duration;
+use kubetile_tui::pane::{Pane, PaneId};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::panes::ExecPane;
+
+use super::App;
+
+/// An exec session whose pane was closed while its shell still ran.
+pub(super) struct DetachedExec {
+    /// Pane id the PTY output is still addressed to until the session is reattached.
+    pub(super) route: PaneId,
+    pub(super) pane: Box<dyn Pane>,
+    /// `db-0/postgres @ prod`
+    pub(super) label: String,
+    pub(super) detached_at: Instant,
+}
+
+pub(super) struct ExecSessionPicker {
+    pub(super) selected: usize,
+    pub(super) labels: Vec<String>,
+}
+
+impl App {
+    /// Keeps a closed pane's exec session running in the background when
+    /// `general.detach_exec_on_close` is set; anything else is dropped as before.
+    pub(super) fn park_closed_pane(&mut self, id: PaneId, pane: Box<dyn Pane>) {
+        if !self.general_config.detach_exec_on_close {
+            return;
+        }
+        let Some(exec) = pane.as_any().downcast_ref::<ExecPane>().filter(|e| e.is_running()) else {
+            return;
+        };
+        let label = format!("{}/{} @ {}", exec.pod_name(), exec.container(), exec.namespace());
+        let session = DetachedExec { route: id, pane, label, detached_at: Instant::now() };
+        let message = match self.dispatcher.key_for("exec_sessions") {
+            Some(key) => format!("Exec session {} detached; {key} to reattach", session.label),
+            None => format!("Exec session {} detached", session.label),
+        };
+        self.toasts.push(ToastMessage::info(message));
+        self.detached_execs.push(session);
+        self.refresh_exec_session_picker();
+    }
+
+    pub(super) fn detached_exec_mut(&mut self, route: PaneId) -> Option<&mut ExecPane> {
+        self.detached_execs
+            .iter_mut()
+            .find(|s| s.route == route)
+            .and_then(|s| s.pane.as_any_mut().downcast_mut::<ExecPane>())
+    }
+
+    /// Forgets a detached session whose shell exited; returns whether `route` was one.
+    pub(super) fn end_detached_exec(&mut self, route: PaneId) -> bool {
+        let Some(index) = self.detached_execs.iter().position(|s| s.route == route) else {
+            return false;
+        };
+        let session = self.detached_execs.remove(index);
+        self.toasts.push(ToastMessage::info(format!("Detached exec session {} exited", session.label)));
+        self.refresh_exec_session_picker();
+        true
+    }
+
+    pub(super) fn open_exec_sessions(&mut self) {
+        if self.detached_execs.is_empty() {
+            self.toasts.push(ToastMessage::info("No detached exec sessions"));
+            return;
+        }
+        self.exec_session_picker = Some(ExecSessionPicker { selected: 0, labels: Vec::new() });
+        self.refresh_exec_session_picker();
+        self.dispatcher.set_mode(InputMode::ExecSessions);
+    }
+
+    /// Recomputes the labels and ages of the open picker; closes it once no session is left.
+    pub(super) fn refresh_exec_session_picker(&mut self) {
+        if self.exec_session_picker.is_none() {
+            return;
+        }
+        if self.detached_execs.is_empty() {
+            self.close_exec_sessions();
+            return;
+        }
+        let now = Instant::now();
+        let labels: Vec<String> = self
+            .detached_execs
+            .iter()
+            .map(|s| {
+                let age = now.saturating_duration_since(s.detached_at);
+                format!("{}  detached {} ago", s.label, format_duration(age))
+            })
+            .collect();
+        if let Some(picker) = self.exec_session_picker.as_mut() {
+            picker.selected = picker.selected.min(labels.len() - 1);
+            picker.labels = labels;
+        }
+    }
+
+    pub(super) fn exec_sessions_move(&mut self, forward: bool) {
+        let Some(picker) = &mut self.exec_session_picker else { return };
+        let len = picker.labels.len();
+        if len == 0 {
+            return;
+        }
+        picker.selected = if forward { (picker.selected + 1) % len } else { (picker.selected + len - 1) % len };
+    }
+
+    /// Reopens the selected session in a new split next to the focused pane.
+    pub(super) fn attach_exec_session(&mut self) {
+        let Some(picker) = self.exec_session_picker.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        if picker.selected >= self.detached_execs.len() {
+            return;
+        }
+        let anchor = self.tab_manager.active().focused_pane;
+        let view = self.detached_execs[picker.selected].pane.view_type().clone();
+        let ratio = self.calc_logs_split_ratio(anchor);
+        let Some(new_id) = self.split_auto_opened(anchor, view, self.general_config.splits.exec, ratio) else {
+            return;
+        };
+        let mut session = self.detached_execs.remove(picker.selected);
+        if let Some(exec) = session.pane.as_any_mut().downcast_mut::<ExecPane>() {
+            exec.retarget(new_id);
+            exec.set_synchronized(self.tab_manager.active().sync_input);
+        }
+        self.panes.insert(new_id, session.pane);
+        self.set_focus(new_id);
+        self.dispatcher.set_mode(InputMode::Insert);
+    }
+
+    /// Ends the selected session; dropping the pane kills its shell.
+    pub(super) fn kill_exec_session(&mut self) {
+        let Some(selected) = self.exec_session_picker.as_ref().map(|p| p.selected) else { return };
+        if selected < self.detached_execs.len() {
+            let session = self.detached_execs.remove(selected);
+            self.toasts.push(ToastMessage::info(format!("Ended exec session {}", session.label)));
+        }
+        self.refresh_exec_session_picker();
+    }
+
+    pub(super) fn close_exec_sessions(&mut self) {
+        self.exec_session_picker = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+}
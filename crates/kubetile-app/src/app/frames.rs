@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+use super::App;
+
+/// Ages and elapsed times on screen are refreshed at least this often, even when idle.
+const IDLE_REDRAW: Duration = Duration::from_secs(1);
+
+/// Decides when the event loop draws: only after something changed, and no more than
+/// `general.max_fps` times a second. Changes arriving in between share the next frame.
+pub(super) struct FramePacer {
+    min_interval: Duration,
+    dirty: bool,
+    last_draw: Option<Instant>,
+}
+
+impl FramePacer {
+    pub(super) fn new(max_fps: u32) -> Self {
+        let min_interval = if max_fps == 0 { Duration::ZERO } else { Duration::from_secs(1) / max_fps };
+        Self { min_interval, dirty: true, last_draw: None }
+    }
+
+    pub(super) fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// A tick with nothing new still redraws once the last frame is old enough to show stale ages.
+    pub(super) fn mark_tick(&mut self) {
+        if self.last_draw.is_none_or(|last| last.elapsed() >= IDLE_REDRAW) {
+            self.dirty = true;
+        }
+    }
+
+    /// Time left before the pending frame may be drawn, or `None` when nothing needs drawing.
+    pub(super) fn wait(&self) -> Option<Duration> {
+        if !self.dirty {
+            return None;
+        }
+        Some(self.last_draw.map_or(Duration::ZERO, |last| self.min_interval.saturating_sub(last.elapsed())))
+    }
+
+    pub(super) fn drawn(&mut self) {
+        self.dirty = false;
+        self.last_draw = Some(Instant::now());
+    }
+}
+
+impl App {
+    /// Redraws after a tick only when a pane produced output or the overlays changed, e.g. a toast
+    /// expired or the warning ticker scrolled.
+    pub(super) fn mark_tick_redraw(&mut self, overlays_changed: bool) {
+        // Every pane's flag is consumed, so `count` rather than a short-circuiting `any`.
+        let panes_changed =
+            self.panes.values_mut().map(|pane| pane.take_redraw()).filter(|&changed| changed).count() > 0;
+        if panes_changed || overlays_changed {
+            self.frames.mark_dirty();
+        } else {
+            self.frames.mark_tick();
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::time::{Duration, Instant};
+
+use kubetile_core::resource::format_duration;
+use kubetile_tui::layout::ConnectionHealthView;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
+
+use super::App;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The last API server round trip and when the next one is due.
+#[derive(Default)]
+pub(super) struct ApiHealth {
+    pub(super) latency: Option<Result<Duration, String>>,
+    last_probe: Option<Instant>,
+    in_flight: bool,
+}
+
+impl App {
+    /// Measures the API server round trip every `general.api_probe_secs`.
+    pub(super) fn probe_api_latency(&mut self) {
+        let interval = Duration::from_secs(self.general_config.api_probe_secs);
+        if interval.is_zero() || self.api_health.in_flight || self.offline.is_some() {
+            return;
+        }
+        if self.api_health.last_probe.is_some_and(|last| last.elapsed() < interval) {
+            return;
+        }
+        let Some(client) = self.kube_client.clone() else { return };
+        self.api_health.in_flight = true;
+        self.api_health.last_probe = Some(Instant::now());
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = client.api_latency(PROBE_TIMEOUT).await.map_err(|e| e.to_string());
+            let _ = app_tx.send(AppEvent::ApiLatencyMeasured { result });
+        });
+    }
+
+    pub(super) fn handle_api_latency(&mut self, result: Result<Duration, String>) {
+        if let Err(e) = &result {
+            tracing::debug!("API latency probe failed: {e}");
+        }
+        self.api_health.in_flight = false;
+        self.api_health.latency = Some(result);
+    }
+
+    /// Forgets the measurement of the previous cluster and probes the new one right away.
+    pub(super) fn reset_api_health(&mut self) {
+        self.api_health = ApiHealth::default();
+        self.probe_api_latency();
+    }
+
+    /// Shown once the first round trip is measured, with the focused list's last event age.
+    pub(super) fn connection_health_view(&self) -> Option<ConnectionHealthView> {
+        let latency = self.api_health.latency.as_ref()?;
+        let focused = self.tab_manager.active().focused_pane;
+        let list = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<ResourceListPane>());
+        Some(ConnectionHealthView {
+            latency_ms: latency.as_ref().ok().map(|d| d.as_millis() as u64),
+            last_event: list.and_then(|l| l.last_event).map(|at| format_duration(at.elapsed())),
+            watch_stale: list.is_some_and(|l| l.state.stale.is_some()),
+        })
+    }
+}
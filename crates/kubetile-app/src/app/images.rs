@@ -0,0 +1,102 @@
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{Api, Client};
+
+use kubetile_core::images::{images_section, pod_images, template_images, updates_section};
+use kubetile_core::resource::DetailSection;
+use kubetile_core::ContainerImage;
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceDetailPane;
+
+use super::App;
+
+impl App {
+    /// Opens the images of the focused pod or workload's containers in a detail pane, followed by
+    /// newer tags from their registries when `features.image_updates` is on.
+    pub(super) fn inspect_images(&mut self) {
+        let Some((kind, name, namespace)) = self.related_subject() else { return };
+        if !matches!(
+            kind,
+            ResourceKind::Pods
+                | ResourceKind::Deployments
+                | ResourceKind::StatefulSets
+                | ResourceKind::DaemonSets
+                | ResourceKind::Jobs
+                | ResourceKind::CronJobs
+        ) {
+            self.toasts.push(ToastMessage::info("Images are listed for Pods and workloads"));
+            return;
+        }
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+
+        let loading = DetailSection { title: "Images".into(), fields: vec![("Loading".into(), "…".into())] };
+        let detail = ResourceDetailPane::new(kind.clone(), name.clone(), Some(namespace.clone()), vec![loading]);
+        let focused = self.tab_manager.active().focused_pane;
+        let view = ViewType::Detail(kind.clone(), name.clone());
+        let Some(pane_id) = self.split_auto_opened(focused, view, self.general_config.splits.detail, 0.5) else {
+            return;
+        };
+        self.panes.insert(pane_id, Box::new(detail));
+        self.set_focus(pane_id);
+
+        let app_tx = self.app_tx.clone();
+        let look_up_updates = self.features.image_updates;
+        tokio::spawn(async move {
+            let images = match resource_images(kube_client, &kind, &name, &namespace).await {
+                Ok(images) => images,
+                Err(e) => {
+                    let toast = ToastMessage::error(format!("Could not read images of {name}: {e}"));
+                    let _ = app_tx.send(AppEvent::Toast(toast));
+                    return;
+                }
+            };
+            let mut sections = vec![images_section(&images)];
+            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id, sections: sections.clone() });
+            if look_up_updates {
+                sections.push(updates_section(&images).await);
+                let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id, sections });
+            }
+        });
+    }
+}
+
+pub(super) async fn resource_images(
+    client: Client,
+    kind: &ResourceKind,
+    name: &str,
+    namespace: &str,
+) -> kube::Result<Vec<ContainerImage>> {
+    Ok(match kind {
+        ResourceKind::Pods => pod_images(&Api::<Pod>::namespaced(client, namespace).get(name).await?),
+        ResourceKind::Deployments => {
+            let deployment = Api::<Deployment>::namespaced(client, namespace).get(name).await?;
+            template_images(deployment.spec.and_then(|s| s.template.spec).as_ref())
+        }
+        ResourceKind::StatefulSets => {
+            let stateful_set = Api::<StatefulSet>::namespaced(client, namespace).get(name).await?;
+            template_images(stateful_set.spec.and_then(|s| s.template.spec).as_ref())
+        }
+        ResourceKind::DaemonSets => {
+            let daemon_set = Api::<DaemonSet>::namespaced(client, namespace).get(name).await?;
+            template_images(daemon_set.spec.and_then(|s| s.template.spec).as_ref())
+        }
+        ResourceKind::Jobs => {
+            let job = Api::<Job>::namespaced(client, namespace).get(name).await?;
+            template_images(job.spec.and_then(|s| s.template.spec).as_ref())
+        }
+        ResourceKind::CronJobs => {
+            let cron_job = Api::<CronJob>::namespaced(client, namespace).get(name).await?;
+            let template = cron_job.spec.and_then(|s| s.job_template.spec).and_then(|s| s.template.spec);
+            template_images(template.as_ref())
+        }
+        _ => Vec::new(),
+    })
+}
@@ -0,0 +1,41 @@
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+
+use super::App;
+
+impl App {
+    /// Resolves each host/path of the selected ingress, probes it through a temporary port-forward
+    /// and opens the report in a text pane.
+    pub(super) fn test_selected_ingress(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else {
+            return;
+        };
+        if kind != ResourceKind::Ingresses {
+            self.toasts.push(ToastMessage::info("Route testing is only available for Ingresses"));
+            return;
+        }
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let focused = self.tab_manager.active().focused_pane;
+        self.toasts.push(ToastMessage::info(format!("Testing routes of {name}...")));
+
+        tokio::spawn(async move {
+            let event = match kubetile_core::ingress_routes::test_ingress(kube_client, &name, &namespace).await {
+                Ok(report) => AppEvent::TextReady {
+                    pane_id: focused,
+                    title: format!("Routes: {name}"),
+                    view: ViewType::Yaml(kind, name),
+                    content: report,
+                },
+                Err(e) => AppEvent::Toast(ToastMessage::error(format!("Route test failed: {e}"))),
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+}
@@ -1,33 +1,73 @@
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
 };
-use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 
 use crossterm::event::{KeyEvent, KeyEventKind};
 use kubetile_tui::pane::{PaneCommand, ResourceKind, SplitDirection, ViewType};
 use kubetile_tui::widgets::toast::{ToastLevel, ToastMessage};
+use ratatui::layout::Rect;
 
 use crate::command::{Command, InputMode};
 use crate::event::AppEvent;
-use crate::panes::{LogsPane, ResourceListPane};
+use crate::panes::{GraphPane, HelpPane, LogsPane, ResourceDetailPane, ResourceListPane, YamlPane};
 use crate::resource_switcher::ResourceSwitcher;
 
+use super::reconnect::report_auth_expired;
 use super::App;
 
+const MOUSE_SCROLL_LINES: usize = 3;
+
 impl App {
+    /// The wheel scrolls the focused pane, also in insert mode, but not behind a dialog.
+    fn handle_mouse_scroll(&mut self, up: bool) {
+        if !matches!(self.dispatcher.mode(), InputMode::Normal | InputMode::Insert) {
+            return;
+        }
+        let cmd = if up { PaneCommand::ScrollUp } else { PaneCommand::ScrollDown };
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get_mut(&focused) {
+            for _ in 0..MOUSE_SCROLL_LINES {
+                pane.handle_command(&cmd);
+            }
+        }
+    }
+
     pub(super) fn handle_event(&mut self, event: AppEvent) {
+        if !matches!(event, AppEvent::Tick) {
+            self.frames.mark_dirty();
+        }
         match event {
             AppEvent::Key(key) => self.handle_key(key),
+            AppEvent::MouseScroll { up } => self.handle_mouse_scroll(up),
             AppEvent::Tick => {
                 self.poll_runtime_panes();
-                self.toasts.retain(|t| !t.is_expired());
+                self.poll_dashboards();
+                let ticker_changed = self.tick_warning_ticker();
+                self.expire_confirmation();
+                self.refresh_undo_list();
+                self.refresh_exec_session_picker();
+                self.refresh_rollouts();
+                self.probe_api_latency();
+                self.pause_background_tabs();
+                self.enforce_memory_budget();
+                self.resume_visible_streams();
+                if !self.active_forwards.is_empty() {
+                    // Keeps the traffic columns live.
+                    self.refresh_port_forwards_panes();
+                }
+                let toasts_expired = self.toasts.expire();
+                self.mark_tick_redraw(ticker_changed || toasts_expired);
             }
-            AppEvent::Resize(_, _) => {}
-            AppEvent::ResourceUpdate { pane_id, watcher_seq, headers, rows } => {
+            AppEvent::Resize(width, height) => self.fit_panes(Rect::new(0, 0, width, height)),
+            AppEvent::ResourceUpdate { pane_id, watcher_seq, headers, rows, partial, expected } => {
                 if self.watcher_seq_by_pane.get(&pane_id).copied() == Some(watcher_seq) {
-                    self.handle_resource_update(pane_id, headers, rows);
+                    self.handle_resource_update(pane_id, headers, rows, partial, expected);
+                    self.mark_stream_resumed();
                 }
             }
             AppEvent::ResourceError { pane_id, watcher_seq, error } => {
@@ -35,6 +75,24 @@ impl App {
                     self.handle_resource_error(pane_id, error);
                 }
             }
+            AppEvent::ResourceRowRefreshed { pane_id, watcher_seq, name, namespace, headers, row } => {
+                if self.watcher_seq_by_pane.get(&pane_id).copied() == Some(watcher_seq) {
+                    self.handle_row_refresh(pane_id, &name, &namespace, headers, row);
+                }
+            }
+            AppEvent::PluginColumnsReady { pane_id, watcher_seq, name, namespace, values } => {
+                if self.watcher_seq_by_pane.get(&pane_id).copied() == Some(watcher_seq) {
+                    self.handle_plugin_columns_ready(pane_id, name, namespace, values);
+                }
+            }
+            AppEvent::VolumeUsageReady { pane_id, watcher_seq, result } => {
+                if self.watcher_seq_by_pane.get(&pane_id).copied() == Some(watcher_seq) {
+                    self.handle_volume_usage_ready(pane_id, result);
+                }
+            }
+            AppEvent::AccessReviewed { context, check, result } => {
+                self.handle_access_reviewed(context, check, result);
+            }
             AppEvent::Toast(toast) => {
                 match toast.level {
                     ToastLevel::Success => tracing::info!("{}", toast.text),
@@ -46,6 +104,23 @@ impl App {
             AppEvent::YamlReady { pane_id, kind, name, content } => {
                 self.open_yaml_pane(pane_id, kind, name, content);
             }
+            AppEvent::TextReady { pane_id, view, title, content } => {
+                self.open_text_pane(pane_id, view, title, content);
+            }
+            AppEvent::DetailSectionsReady { pane_id, sections } => {
+                if let Some(detail) =
+                    self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<ResourceDetailPane>())
+                {
+                    detail.set_sections(sections);
+                }
+            }
+            AppEvent::DependencyGraphReady { pane_id, result } => {
+                if let Some(graph) =
+                    self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<GraphPane>())
+                {
+                    graph.set_graph(result);
+                }
+            }
             AppEvent::LogsStreamReady { pane_id, stream } => {
                 self.attach_logs_stream(pane_id, stream);
             }
@@ -59,12 +134,42 @@ impl App {
                     }
                 }
             }
+            AppEvent::WorkloadPodsChanged { pane_id, pods } => self.handle_workload_pods_changed(pane_id, pods),
+            AppEvent::WorkloadLogStreamReady { pane_id, pod, stream } => {
+                self.attach_workload_log_stream(pane_id, pod, stream);
+            }
             AppEvent::LogsStreamError { pane_id, error } => {
                 self.attach_logs_error(pane_id, error);
             }
             AppEvent::PortForwardReady { forward } => {
                 self.attach_port_forward(forward);
             }
+            AppEvent::ContainersResolved { purpose, pane_id, pod, namespace, workload, containers } => {
+                self.handle_containers_resolved(purpose, pane_id, pod, namespace, workload, containers);
+            }
+            AppEvent::DashboardReady { pane_id, result } => self.handle_dashboard_ready(pane_id, result),
+            AppEvent::WarningEvent { seq, notice } => self.handle_warning_event(seq, notice),
+            AppEvent::ApplyPlanReady { path, context, result } => self.handle_apply_plan(path, context, result),
+            AppEvent::ManifestApplied { pane_id, index, result } => {
+                self.handle_manifest_applied(pane_id, index, result);
+            }
+            AppEvent::DrainPlanned(plan) => self.handle_drain_planned(plan),
+            AppEvent::ResourceDeleted { kind, name, namespace, context, manifest } => {
+                self.handle_resource_deleted(kind, name, namespace, context, manifest)
+            }
+            AppEvent::ResourceRestored { id, result } => self.handle_resource_restored(id, result),
+            AppEvent::FollowRollout { kind, name, namespace } => self.follow_rollout(kind, name, namespace),
+            AppEvent::DataKeysReady { kind, name, namespace, result } => {
+                self.open_data_key_picker(kind, name, namespace, result);
+            }
+            AppEvent::SetImageTargetsReady { kind, name, namespace, result } => {
+                self.open_set_image_dialog(kind, name, namespace, result);
+            }
+            AppEvent::RolloutProgress { id, result } => self.handle_rollout_progress(id, result),
+            AppEvent::RelatedResolved { pane_id, subject, namespace, related } => {
+                self.handle_related_resolved(pane_id, subject, namespace, related);
+            }
+            AppEvent::SearchListed { seq, hits, failed } => self.handle_search_listed(seq, hits, failed),
             AppEvent::PortForwardPromptReady { pod, namespace, suggested_remote } => {
                 self.open_port_forward_prompt(pod, namespace, suggested_remote);
             }
@@ -77,6 +182,24 @@ impl App {
             AppEvent::QueryError { pane_id, error } => {
                 self.handle_query_error(pane_id, error);
             }
+            AppEvent::PlanReady { pane_id, plan } => {
+                self.handle_plan_ready(pane_id, plan);
+            }
+            AppEvent::QueryPageReady { pane_id, offset, result } => {
+                self.handle_query_page_ready(pane_id, offset, result);
+            }
+            AppEvent::QueryRowEstimate { pane_id, rows } => {
+                self.handle_query_row_estimate(pane_id, rows);
+            }
+            AppEvent::TransactionStarted { pane_id, result } => {
+                self.handle_transaction_started(pane_id, result);
+            }
+            AppEvent::TransactionLost { pane_id, error } => {
+                self.handle_transaction_lost(pane_id, error);
+            }
+            AppEvent::TransactionEnded { pane_id, result } => {
+                self.handle_transaction_ended(pane_id, result);
+            }
             AppEvent::SchemaReady { pane_id, rows } => {
                 self.handle_schema_ready(pane_id, rows);
             }
@@ -84,19 +207,60 @@ impl App {
                 self.apply_context_switch(client, namespaces);
             }
             AppEvent::ContextSwitchError { context, error } => {
-                self.toasts.push(ToastMessage::error(format!("Failed to switch context {context}: {error}")));
+                tracing::warn!("Failed to switch context {context}: {error}");
+                let summary = self.record_connection_failure(Some(&context), error);
+                self.toasts.push(ToastMessage::error(format!("Failed to switch context {context}: {summary}")));
             }
+            AppEvent::ReconnectReady { seq, client, namespaces } => {
+                self.handle_reconnect_ready(seq, client, namespaces);
+            }
+            AppEvent::ReconnectFailed { seq, error } => self.handle_reconnect_failed(seq, error),
+            AppEvent::AuthExpired { context, error } => self.handle_auth_expired(context, error),
             AppEvent::NamespacesUpdated { namespaces } => {
                 self.namespaces = namespaces;
             }
+            AppEvent::ContextProbed { context, result } => self.handle_context_probed(context, result),
+            AppEvent::ApiLatencyMeasured { result } => self.handle_api_latency(result),
+            AppEvent::ExecConnected { pane_id, result } => self.handle_exec_connected(pane_id, result),
+            AppEvent::PtyOutput { pane_id, data } if !self.panes.contains_key(&pane_id) => {
+                if let Some(exec) = self.detached_exec_mut(pane_id) {
+                    exec.process_output(&data);
+                }
+            }
             AppEvent::PtyOutput { pane_id, data } => {
                 if let Some(pane) = self.panes.get_mut(&pane_id) {
                     if let Some(exec) = pane.as_any_mut().downcast_mut::<crate::panes::ExecPane>() {
                         exec.process_output(&data);
+                        if let Some(text) = exec.take_copied() {
+                            self.copy_from_exec(text);
+                        }
+                    } else if let Some(editor) = pane.as_any_mut().downcast_mut::<crate::panes::EditorPane>() {
+                        editor.process_output(&data);
+                    } else if let Some(plugin) = pane.as_any_mut().downcast_mut::<crate::panes::PluginPane>() {
+                        plugin.process_output(&data);
                     }
                 }
             }
+            AppEvent::ExecExited { pane_id } if self.data_key_edits.contains_key(&pane_id) => {
+                self.finish_data_key_edit(pane_id);
+            }
+            AppEvent::ExecExited { pane_id } if self.end_detached_exec(pane_id) => {}
             AppEvent::ExecExited { pane_id } => {
+                if let Some(exec) = self
+                    .panes
+                    .get_mut(&pane_id)
+                    .and_then(|pane| pane.as_any_mut().downcast_mut::<crate::panes::ExecPane>())
+                {
+                    exec.mark_exited();
+                }
+                if let Some(plugin) = self
+                    .panes
+                    .get_mut(&pane_id)
+                    .and_then(|pane| pane.as_any_mut().downcast_mut::<crate::panes::PluginPane>())
+                {
+                    plugin.mark_exited();
+                    return;
+                }
                 let was_focused = self.tab_manager.active().focused_pane == pane_id;
                 self.close_pane(pane_id);
                 if was_focused && self.dispatcher.mode() == InputMode::Insert {
@@ -111,14 +275,38 @@ impl App {
             return;
         }
 
+        let prompt_open = self.dispatcher.mode() == InputMode::ConfirmDialog;
         if let Some((cmd, requires_confirm)) = self.dispatcher.dispatch(key) {
-            if requires_confirm || matches!(cmd, Command::Quit) {
-                self.pending_confirmation = Some(super::PendingConfirmation::from_command(cmd));
-                self.dispatcher.set_mode(InputMode::ConfirmDialog);
+            // Mutate commands build their own confirmation according to `[general.confirm]`.
+            let confirms_itself = matches!(
+                cmd,
+                Command::DeleteResource
+                    | Command::EvictPod
+                    | Command::ForceDeletePod
+                    | Command::ScaleResource
+                    | Command::RestartRollout
+                    | Command::SetImage
+                    | Command::EditDataKey
+                    | Command::DrainNode
+                    | Command::ApplyManifests
+                    | Command::UndoDelete
+                    | Command::ToggleDebugMode
+                    | Command::ToggleRootDebugMode
+            );
+            if (requires_confirm && !confirms_itself) || matches!(cmd, Command::Quit) {
+                self.open_confirmation(super::PendingConfirmation::from_command(cmd), InputMode::ConfirmDialog);
             } else {
                 self.handle_command(cmd);
             }
         }
+        // A y/n prompt stays up while the user browses, but does not hide behind another dialog.
+        if prompt_open && self.pending_confirmation.is_some() {
+            match self.dispatcher.mode() {
+                InputMode::ConfirmDialog => {}
+                InputMode::Normal => self.dispatcher.set_mode(InputMode::ConfirmDialog),
+                _ => self.pending_confirmation = None,
+            }
+        }
     }
 
     pub(super) fn handle_command(&mut self, cmd: Command) {
@@ -130,8 +318,14 @@ impl App {
             Command::ShowHelp => self.toggle_help(),
             Command::ShowPaneHelp => self.show_pane_help(),
             Command::ClosePaneHelp => self.close_pane_help(),
+            Command::CloseWelcome => self.close_welcome(),
+            Command::WriteDefaultConfig => self.write_default_config(),
             Command::ToggleAppLogsTab => self.toggle_app_logs_tab(),
             Command::TogglePortForwardsTab => self.toggle_port_forwards_tab(),
+            Command::ToggleAuditTab => self.toggle_audit_tab(),
+            Command::ToggleConnectionTab => self.toggle_connection_tab(),
+            Command::ToggleTrashTab => self.toggle_trash_tab(),
+            Command::ToggleDashboardTab => self.toggle_dashboard_tab(),
             Command::FocusNextPane => self.focus_next(),
             Command::FocusPrevPane => self.focus_prev(),
             Command::SplitVertical => self.split_focused(SplitDirection::Vertical),
@@ -152,12 +346,17 @@ impl App {
                 if mode == InputMode::NamespaceSelector {
                     self.namespace_filter.clear();
                     self.namespace_selected = 0;
+                    self.namespace_marked = self.context_resolver.namespaces().into_iter().map(String::from).collect();
+                    if self.namespace_marked.len() < 2 {
+                        self.namespace_marked.clear();
+                    }
                     self.refresh_namespaces();
                 }
                 if mode == InputMode::ContextSelector {
                     self.context_filter.clear();
                     self.context_selected = 0;
                     self.contexts = kubetile_core::KubeClient::list_contexts().unwrap_or_default();
+                    self.probe_contexts();
                 }
                 if mode == InputMode::FilterInput {
                     self.filter_input_buffer.clear();
@@ -165,6 +364,8 @@ impl App {
                     if let Some(pane) = self.panes.get(&focused) {
                         if let Some(rp) = pane.as_any().downcast_ref::<ResourceListPane>() {
                             self.filter_input_buffer = rp.filter_text.clone();
+                        } else if let Some(help) = pane.as_any().downcast_ref::<HelpPane>() {
+                            self.filter_input_buffer = help.filter_text().to_string();
                         }
                     }
                 }
@@ -173,12 +374,18 @@ impl App {
             Command::NamespaceConfirm => self.handle_namespace_confirm(),
             Command::NamespaceInput(c) => self.handle_namespace_input(c),
             Command::NamespaceBackspace => self.handle_namespace_backspace(),
+            Command::NamespaceToggle => self.handle_namespace_toggle(),
+            Command::NamespaceFavorite => self.handle_namespace_favorite(),
             Command::ContextConfirm => self.handle_context_confirm(),
             Command::ContextInput(c) => self.handle_context_input(c),
             Command::ContextBackspace => self.handle_context_backspace(),
             Command::FocusDirection(dir) => self.focus_direction(dir),
+            Command::SwapPane(dir) => self.swap_pane(dir),
+            Command::RotatePanes => self.rotate_panes(),
             Command::NewTab => self.new_tab(),
             Command::CloseTab => self.close_tab(),
+            Command::MovePaneToNewTab => self.move_pane_to_new_tab(),
+            Command::MergeTabIntoPrevious => self.merge_tab_into_previous(),
             Command::NextTab => self.switch_to_next_tab(),
             Command::PrevTab => self.switch_to_prev_tab(),
             Command::GoToTab(n) => {
@@ -187,6 +394,8 @@ impl App {
                 }
             }
             Command::ToggleFullscreen => self.toggle_fullscreen(),
+            Command::ToggleZoom => self.toggle_zoom(),
+            Command::ToggleSyncInput => self.toggle_sync_input(),
             Command::ResizeGrow => {
                 let focused = self.tab_manager.active().focused_pane;
                 self.tab_manager.active_mut().pane_tree.resize(focused, 0.05, true);
@@ -214,6 +423,9 @@ impl App {
                 let focused = self.tab_manager.active().focused_pane;
                 match &pane_cmd {
                     PaneCommand::Select => {
+                        if self.open_dashboard_tile() || self.open_graph_node() {
+                            return;
+                        }
                         if let Some((kind, name, ns)) = self.selected_resource_info() {
                             self.open_detail_pane(kind, name, ns);
                             return;
@@ -221,14 +433,24 @@ impl App {
                     }
                     PaneCommand::Back => {
                         if let Some(pane) = self.panes.get(&focused) {
-                            let is_detail_or_yaml =
-                                matches!(pane.view_type(), ViewType::Detail(..) | ViewType::Yaml(..));
-                            if is_detail_or_yaml {
+                            // Text panes share the YAML pane, whatever their view.
+                            let is_detail_or_text =
+                                matches!(pane.view_type(), ViewType::Detail(..) | ViewType::Yaml(..))
+                                    || pane.as_any().is::<YamlPane>();
+                            if is_detail_or_text {
                                 self.close_pane(focused);
                                 return;
                             }
                         }
                     }
+                    PaneCommand::SendInput(_) if self.broadcasts_input(focused) => {
+                        for id in self.exec_pane_ids() {
+                            if let Some(pane) = self.panes.get_mut(&id) {
+                                pane.handle_command(&pane_cmd);
+                            }
+                        }
+                        return;
+                    }
                     _ => {}
                 }
                 if let Some(pane) = self.panes.get_mut(&focused) {
@@ -371,12 +593,45 @@ impl App {
             Command::QueryEditorExecute => {
                 self.execute_current_query();
             }
+            Command::QueryEditorExplain => {
+                self.explain_current_query();
+            }
+            Command::QueryBeginTransaction => {
+                self.begin_query_transaction();
+            }
+            Command::QueryCommit => {
+                self.end_query_transaction("COMMIT");
+            }
+            Command::QueryRollback => {
+                self.end_query_transaction("ROLLBACK");
+            }
             Command::EnterQueryBrowse => {
                 self.enter_query_browse();
             }
             Command::QueryBrowseNext => {
                 self.query_browse_next();
             }
+            Command::QueryBrowseNextPage => {
+                self.fetch_next_query_page(true);
+            }
+            Command::QueryBrowseSortAsc => {
+                self.with_query_pane(|qp| qp.sort_by_focused_column(false));
+            }
+            Command::QueryBrowseSortDesc => {
+                self.with_query_pane(|qp| qp.sort_by_focused_column(true));
+            }
+            Command::QueryBrowseHideColumn => {
+                self.with_query_pane(|qp| qp.hide_focused_column());
+            }
+            Command::QueryBrowseShowColumns => {
+                self.with_query_pane(|qp| qp.show_all_columns());
+            }
+            Command::QueryBrowsePinColumns => {
+                self.with_query_pane(|qp| qp.pin_through_focused_column());
+            }
+            Command::QueryBrowseUnpinColumns => {
+                self.with_query_pane(|qp| qp.unpin_columns());
+            }
             Command::QueryBrowsePrev => {
                 self.query_browse_prev();
             }
@@ -410,6 +665,24 @@ impl App {
             Command::CloseQueryHistory => {
                 self.close_query_history();
             }
+            Command::QueryHistoryStartSearch => {
+                self.with_query_pane(|qp| qp.history_start_search());
+                self.dispatcher.set_mode(InputMode::QueryHistorySearch);
+            }
+            Command::QueryHistorySearchInput(c) => {
+                self.with_query_pane(|qp| qp.history_search_input(c));
+            }
+            Command::QueryHistorySearchBackspace => {
+                self.with_query_pane(|qp| qp.history_search_backspace());
+            }
+            Command::QueryHistorySearchConfirm => {
+                self.with_query_pane(|qp| qp.history_search_confirm());
+                self.dispatcher.set_mode(InputMode::QueryHistory);
+            }
+            Command::QueryHistorySearchCancel => {
+                self.with_query_pane(|qp| qp.history_search_cancel());
+                self.dispatcher.set_mode(InputMode::QueryHistory);
+            }
             Command::OpenSaveQueryDialog => {
                 self.open_save_query_dialog();
             }
@@ -419,6 +692,9 @@ impl App {
             Command::SaveQueryNameBackspace => {
                 self.save_query_name_backspace();
             }
+            Command::SaveQueryNameCycleScope => {
+                self.with_query_pane(|qp| qp.cycle_save_scope());
+            }
             Command::SaveQueryNameConfirm => {
                 self.confirm_save_query();
             }
@@ -491,6 +767,43 @@ impl App {
             Command::ExportDialogCancel => {
                 self.cancel_export();
             }
+            Command::OpenCellInspector => {
+                self.open_cell_inspector();
+            }
+            Command::CellInspectorPrevColumn => {
+                self.with_query_pane(|qp| qp.cell_inspector_move(-1));
+            }
+            Command::CellInspectorNextColumn => {
+                self.with_query_pane(|qp| qp.cell_inspector_move(1));
+            }
+            Command::CellInspectorScrollUp => {
+                self.with_query_pane(|qp| qp.cell_inspector_scroll(-1));
+            }
+            Command::CellInspectorScrollDown => {
+                self.with_query_pane(|qp| qp.cell_inspector_scroll(1));
+            }
+            Command::CellInspectorCopy => {
+                self.copy_inspected_cell();
+            }
+            Command::CellInspectorClose => {
+                self.with_query_pane(|qp| qp.close_cell_inspector());
+                self.dispatcher.set_mode(InputMode::QueryBrowse);
+            }
+            Command::QueryParamsInput(c) => {
+                self.query_params_input(c);
+            }
+            Command::QueryParamsBackspace => {
+                self.query_params_backspace();
+            }
+            Command::QueryParamsNextField => {
+                self.query_params_next_field();
+            }
+            Command::QueryParamsConfirm => {
+                self.confirm_query_params();
+            }
+            Command::QueryParamsCancel => {
+                self.cancel_query_params();
+            }
             Command::SortByColumn => {
                 let focused = self.tab_manager.active().focused_pane;
                 if let Some(pane) = self.panes.get_mut(&focused) {
@@ -510,22 +823,54 @@ impl App {
                     }
                 }
             }
+            Command::ToggleOwnerGrouping => {
+                let focused = self.tab_manager.active().focused_pane;
+                let Some(rp) =
+                    self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<ResourceListPane>())
+                else {
+                    return;
+                };
+                if rp.kind() == Some(&ResourceKind::Pods) {
+                    rp.toggle_grouping();
+                } else {
+                    self.toasts.push(ToastMessage::info("Grouping by owner is available in the Pods list"));
+                }
+            }
+            Command::ToggleGroupCollapse => {
+                let focused = self.tab_manager.active().focused_pane;
+                let Some(pane) = self.panes.get_mut(&focused) else { return };
+                if let Some(rp) = pane.as_any_mut().downcast_mut::<ResourceListPane>() {
+                    rp.toggle_selected_group();
+                } else if let Some(yp) = pane.as_any_mut().downcast_mut::<YamlPane>() {
+                    yp.toggle_fold();
+                }
+            }
+            Command::FoldAll | Command::UnfoldAll => {
+                let focused = self.tab_manager.active().focused_pane;
+                if let Some(yp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<YamlPane>()) {
+                    if cmd == Command::FoldAll {
+                        yp.fold_all();
+                    } else {
+                        yp.unfold_all();
+                    }
+                }
+            }
+            Command::ToggleCleanYaml => self.toggle_clean_yaml(),
+            Command::CopyYaml => self.copy_yaml(),
             Command::ToggleAllNamespaces => {
                 let focused = self.tab_manager.active().focused_pane;
                 if let Some(pane) = self.panes.get_mut(&focused) {
                     if let Some(rp) = pane.as_any_mut().downcast_mut::<ResourceListPane>() {
                         rp.all_namespaces = !rp.all_namespaces;
+                        rp.scope = None;
+                        rp.field_selector = None;
                         let kind = rp.kind().cloned();
                         let is_all = rp.all_namespaces;
 
                         if let Some(kind) = kind {
                             if kind.is_namespaced() {
-                                if is_all {
-                                    self.start_watcher_for_pane(focused, &kind, "");
-                                } else {
-                                    let ns = self.context_resolver.namespace().unwrap_or("default").to_string();
-                                    self.start_watcher_for_pane(focused, &kind, &ns);
-                                }
+                                let namespaces = self.watch_namespaces(&kind, is_all);
+                                self.start_watcher_for_pane(focused, &kind, &namespaces);
                                 if let Some(pane) = self.panes.get_mut(&focused) {
                                     if let Some(rp) = pane.as_any_mut().downcast_mut::<ResourceListPane>() {
                                         let headers = rp.state.headers.clone();
@@ -541,7 +886,7 @@ impl App {
             }
 
             Command::EnterResourceSwitcher => {
-                self.resource_switcher = Some(ResourceSwitcher::new());
+                self.resource_switcher = Some(ResourceSwitcher::with_usage(&self.resource_usage));
                 self.dispatcher.set_mode(InputMode::ResourceSwitcher);
             }
             Command::ResourceSwitcherInput(ch) => {
@@ -557,6 +902,9 @@ impl App {
             Command::ResourceSwitcherConfirm => {
                 let kind = self.resource_switcher.as_ref().and_then(|sw| sw.confirm());
                 if let Some(kind) = kind {
+                    if let Err(e) = self.resource_usage.record(kind.short_name()) {
+                        tracing::warn!("Failed to save resource usage: {e}");
+                    }
                     self.switch_resource(kind);
                 }
                 self.resource_switcher = None;
@@ -577,6 +925,8 @@ impl App {
                     .is_some_and(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == "PortForwards"));
                 if is_port_forwards {
                     self.stop_selected_port_forward();
+                } else if self.trash_focused() {
+                    self.purge_selected_trash();
                 } else {
                     self.initiate_delete();
                 }
@@ -584,7 +934,78 @@ impl App {
             Command::ConfirmAction => {
                 self.execute_confirmed_action();
             }
+            Command::ConfirmSubmit => self.submit_confirmation(),
+            Command::ConfirmToggleFocus => self.toggle_confirm_focus(),
+            Command::ConfirmInput(c) => {
+                if let Some(pc) = &mut self.pending_confirmation {
+                    pc.input.push(c);
+                }
+            }
+            Command::ConfirmBackspace => {
+                if let Some(pc) = &mut self.pending_confirmation {
+                    pc.input.pop();
+                }
+            }
+            Command::Reconnect => self.reconnect_now(),
+            Command::OpenNotificationCenter => self.open_notification_center(),
+            Command::NotificationCenterOlder => self.scroll_notification_center(1),
+            Command::NotificationCenterNewer => self.scroll_notification_center(-1),
+            Command::NotificationCenterClear => self.clear_notification_center(),
+            Command::CloseNotificationCenter => self.close_notification_center(),
+            Command::OpenCanIDialog => self.open_can_i_dialog(),
+            Command::CanIInput(c) => self.can_i_input(c),
+            Command::CanIBackspace => self.can_i_backspace(),
+            Command::CanIConfirm => self.submit_can_i(),
+            Command::CanICancel => self.close_can_i_dialog(),
+            Command::ApplyPathInput(c) => self.apply_path_input(c),
+            Command::ApplyPathBackspace => self.apply_path_backspace(),
+            Command::ApplyPathNextNamespace => self.apply_next_namespace(),
+            Command::ApplyPathToggleForce => self.apply_toggle_force(),
+            Command::ApplyPathConfirm => self.plan_manifests(),
+            Command::ApplyPathCancel => self.close_apply_dialog(),
+            Command::DataKeyPickerPrev => self.data_key_picker_move(false),
+            Command::DataKeyPickerNext => self.data_key_picker_move(true),
+            Command::DataKeyPickerConfirm => self.confirm_data_key_picker(),
+            Command::DataKeyPickerCancel => self.cancel_data_key_picker(),
+            Command::SetImageInput(c) => self.set_image_input(c),
+            Command::SetImageBackspace => self.set_image_backspace(),
+            Command::SetImagePrev => self.set_image_move(false),
+            Command::SetImageNext => self.set_image_move(true),
+            Command::SetImageConfirm => self.confirm_set_image(),
+            Command::SetImageCancel => self.close_set_image_dialog(),
+            Command::ContainerPickerPrev => self.container_picker_move(false),
+            Command::ContainerPickerNext => self.container_picker_move(true),
+            Command::ContainerPickerConfirm => self.confirm_container_picker(),
+            Command::ContainerPickerCancel => self.cancel_container_picker(),
+            Command::RelatedPickerPrev => self.related_picker_move(false),
+            Command::RelatedPickerNext => self.related_picker_move(true),
+            Command::RelatedPickerConfirm => self.confirm_related_picker(),
+            Command::RelatedPickerCancel => self.cancel_related_picker(),
+            Command::OpenUndoList => self.open_undo_list(),
+            Command::UndoListPrev => self.undo_list_move(false),
+            Command::UndoListNext => self.undo_list_move(true),
+            Command::UndoListConfirm => self.confirm_undo_list(),
+            Command::CloseUndoList => self.close_undo_list(),
+            Command::OpenExecSessions => self.open_exec_sessions(),
+            Command::ExecSessionsPrev => self.exec_sessions_move(false),
+            Command::ExecSessionsNext => self.exec_sessions_move(true),
+            Command::ExecSessionsAttach => self.attach_exec_session(),
+            Command::ExecSessionsKill => self.kill_exec_session(),
+            Command::CloseExecSessions => self.close_exec_sessions(),
+            Command::OpenGlobalSearch => self.open_global_search(),
+            Command::GlobalSearchInput(c) => self.search_input(c),
+            Command::GlobalSearchBackspace => self.search_backspace(),
+            Command::GlobalSearchPrev => self.search_move(false),
+            Command::GlobalSearchNext => self.search_move(true),
+            Command::GlobalSearchToggleScope => self.search_toggle_scope(),
+            Command::GlobalSearchConfirm => self.confirm_global_search(),
+            Command::CloseGlobalSearch => self.close_global_search(),
+            Command::OpenRelated => self.open_related(),
+            Command::InspectImages => self.inspect_images(),
+            Command::FindUsages => self.find_usages(),
+            Command::OpenDependencyGraph => self.open_dependency_graph(),
 
+            Command::ViewYaml if self.trash_focused() => self.view_trashed_manifest(),
             Command::ViewYaml => {
                 if let Some((kind, name, ns)) = self.selected_resource_info() {
                     let Some(client) = &self.kube_client else {
@@ -593,12 +1014,14 @@ impl App {
                     };
                     let kube_client = client.inner_client();
                     let app_tx = self.app_tx.clone();
+                    let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
                     let focused = self.tab_manager.active().focused_pane;
                     let kind_clone = kind.clone();
                     let name_clone = name.clone();
+                    let fetch_cache = self.fetch_cache.clone();
 
                     tokio::spawn(async move {
-                        let executor = kubetile_core::ActionExecutor::new(kube_client);
+                        let executor = kubetile_core::ActionExecutor::new(kube_client).with_fetch_cache(fetch_cache);
                         let result = dispatch_get_yaml(&executor, &kind, &name, &ns).await;
                         let event = match result {
                             Ok(yaml) => AppEvent::YamlReady {
@@ -607,7 +1030,10 @@ impl App {
                                 name: name_clone,
                                 content: yaml,
                             },
-                            Err(e) => AppEvent::Toast(ToastMessage::error(format!("YAML fetch failed: {e}"))),
+                            Err(e) => {
+                                report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                                AppEvent::Toast(ToastMessage::error(format!("YAML fetch failed: {e}")))
+                            }
                         };
                         let _ = app_tx.send(event);
                     });
@@ -622,13 +1048,16 @@ impl App {
                     };
                     let kube_client = client.inner_client();
                     let app_tx = self.app_tx.clone();
+                    let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
                     let focused = self.tab_manager.active().focused_pane;
                     let kind_clone = kind.clone();
                     let name_clone = name.clone();
+                    let fetch_cache = self.fetch_cache.clone();
+                    let tz = self.display_timezone.clone();
 
                     tokio::spawn(async move {
-                        let executor = kubetile_core::ActionExecutor::new(kube_client);
-                        let result = dispatch_describe(&executor, &kind, &name, &ns).await;
+                        let executor = kubetile_core::ActionExecutor::new(kube_client).with_fetch_cache(fetch_cache);
+                        let result = dispatch_describe(&executor, &kind, &name, &ns, &tz).await;
                         let event = match result {
                             Ok(text) => AppEvent::YamlReady {
                                 pane_id: focused,
@@ -636,43 +1065,47 @@ impl App {
                                 name: name_clone,
                                 content: text,
                             },
-                            Err(e) => AppEvent::Toast(ToastMessage::error(format!("Describe failed: {e}"))),
+                            Err(e) => {
+                                report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                                AppEvent::Toast(ToastMessage::error(format!("Describe failed: {e}")))
+                            }
                         };
                         let _ = app_tx.send(event);
                     });
                 }
             }
+            Command::RefreshRow => {
+                if !self.refresh_focused_dashboard() {
+                    self.refresh_selected_row();
+                }
+            }
             Command::SaveLogsToFile => {
                 self.initiate_save_logs();
             }
+            Command::ToggleLogTee => self.toggle_log_tee(),
+            Command::LogTeePathInput(c) => self.with_tee_prompt(|logs| logs.tee_prompt_input(c)),
+            Command::LogTeePathBackspace => self.with_tee_prompt(LogsPane::tee_prompt_backspace),
+            Command::LogTeePathConfirm => self.confirm_log_tee_path(),
+            Command::LogTeePathCancel => self.cancel_log_tee_path(),
             Command::DownloadFullLogs => {
                 self.initiate_download_full_logs();
             }
 
             Command::RestartRollout => {
-                if let Some((kind, name, ns)) = self.selected_resource_info() {
-                    if kind == ResourceKind::Deployments {
-                        let Some(client) = &self.kube_client else {
-                            self.toasts.push(ToastMessage::error("No cluster connection"));
-                            return;
-                        };
-                        let kube_client = client.inner_client();
-                        let app_tx = self.app_tx.clone();
-
-                        tokio::spawn(async move {
-                            let executor = kubetile_core::ActionExecutor::new(kube_client);
-                            let toast = match executor.restart_rollout(&name, &ns).await {
-                                Ok(()) => ToastMessage::success(format!("Restarted {name}")),
-                                Err(e) => ToastMessage::error(format!("Restart failed: {e}")),
-                            };
-                            let _ = app_tx.send(AppEvent::Toast(toast));
-                        });
-                    } else {
-                        self.toasts.push(ToastMessage::info("Restart rollout is only available for Deployments"));
-                    }
-                }
+                self.initiate_restart_rollout();
             }
+            Command::SetImage => self.initiate_set_image(),
+            Command::EditDataKey => self.initiate_edit_data_key(),
+
+            Command::EvictPod => self.initiate_evict(),
+            Command::DrainNode => self.initiate_drain(),
+            Command::ApplyManifests => self.apply_manifests(),
+            Command::UndoDelete if self.trash_focused() => self.restore_selected_trash(),
+            Command::UndoDelete => self.undo_last_delete(),
+            Command::ForceDeletePod => self.initiate_force_delete(),
 
+            Command::ScaleResource if self.is_read_only_context() => self.notify_read_only(),
+            Command::ScaleResource if self.deny_if_forbidden("scale") => {}
             Command::ScaleResource => {
                 self.toasts.push(ToastMessage::info("Scale not yet implemented"));
             }
@@ -692,9 +1125,13 @@ impl App {
             Command::ExecInto => {
                 self.open_exec_pane();
             }
+            Command::SwitchContainer => self.switch_container(),
             Command::PortForward => {
                 self.toggle_port_forward_for_selected();
             }
+            Command::TestIngress => self.test_selected_ingress(),
+            Command::RunPlugin(name) => self.run_plugin(&name),
+            Command::RunScript(name) => self.run_script(&name),
 
             Command::TerminalSpawn
             | Command::TerminalClose { .. }
@@ -728,9 +1165,14 @@ async fn dispatch_get_yaml(
         ResourceKind::Secrets => executor.get_yaml::<Secret>(name, ns).await,
         ResourceKind::Ingresses => executor.get_yaml::<Ingress>(name, ns).await,
         ResourceKind::PersistentVolumeClaims => executor.get_yaml::<PersistentVolumeClaim>(name, ns).await,
+        ResourceKind::NetworkPolicies => executor.get_yaml::<NetworkPolicy>(name, ns).await,
+        ResourceKind::ResourceQuotas => executor.get_yaml::<ResourceQuota>(name, ns).await,
+        ResourceKind::LimitRanges => executor.get_yaml::<LimitRange>(name, ns).await,
+        ResourceKind::PodDisruptionBudgets => executor.get_yaml::<PodDisruptionBudget>(name, ns).await,
         ResourceKind::Nodes => executor.get_yaml_cluster::<Node>(name).await,
         ResourceKind::Namespaces => executor.get_yaml_cluster::<Namespace>(name).await,
         ResourceKind::PersistentVolumes => executor.get_yaml_cluster::<PersistentVolume>(name).await,
+        ResourceKind::Workloads => Err(anyhow::anyhow!("YAML view needs a single resource kind")),
         ResourceKind::Custom(_) => Err(anyhow::anyhow!("YAML view not supported for custom resources")),
     }
 }
@@ -740,19 +1182,24 @@ async fn dispatch_describe(
     kind: &ResourceKind,
     name: &str,
     ns: &str,
+    tz: &jiff::tz::TimeZone,
 ) -> anyhow::Result<String> {
     match kind {
-        ResourceKind::Pods => executor.describe::<Pod>(name, ns).await,
-        ResourceKind::Deployments => executor.describe::<Deployment>(name, ns).await,
-        ResourceKind::Services => executor.describe::<Service>(name, ns).await,
-        ResourceKind::StatefulSets => executor.describe::<StatefulSet>(name, ns).await,
-        ResourceKind::DaemonSets => executor.describe::<DaemonSet>(name, ns).await,
-        ResourceKind::Jobs => executor.describe::<Job>(name, ns).await,
-        ResourceKind::CronJobs => executor.describe::<CronJob>(name, ns).await,
-        ResourceKind::ConfigMaps => executor.describe::<ConfigMap>(name, ns).await,
-        ResourceKind::Secrets => executor.describe::<Secret>(name, ns).await,
-        ResourceKind::Ingresses => executor.describe::<Ingress>(name, ns).await,
-        ResourceKind::PersistentVolumeClaims => executor.describe::<PersistentVolumeClaim>(name, ns).await,
+        ResourceKind::Pods => executor.describe::<Pod>(name, ns, tz).await,
+        ResourceKind::Deployments => executor.describe::<Deployment>(name, ns, tz).await,
+        ResourceKind::Services => executor.describe::<Service>(name, ns, tz).await,
+        ResourceKind::StatefulSets => executor.describe::<StatefulSet>(name, ns, tz).await,
+        ResourceKind::DaemonSets => executor.describe::<DaemonSet>(name, ns, tz).await,
+        ResourceKind::Jobs => executor.describe::<Job>(name, ns, tz).await,
+        ResourceKind::CronJobs => executor.describe::<CronJob>(name, ns, tz).await,
+        ResourceKind::ConfigMaps => executor.describe::<ConfigMap>(name, ns, tz).await,
+        ResourceKind::Secrets => executor.describe::<Secret>(name, ns, tz).await,
+        ResourceKind::Ingresses => executor.describe::<Ingress>(name, ns, tz).await,
+        ResourceKind::PersistentVolumeClaims => executor.describe::<PersistentVolumeClaim>(name, ns, tz).await,
+        ResourceKind::NetworkPolicies => executor.describe::<NetworkPolicy>(name, ns, tz).await,
+        ResourceKind::ResourceQuotas => executor.describe::<ResourceQuota>(name, ns, tz).await,
+        ResourceKind::LimitRanges => executor.describe::<LimitRange>(name, ns, tz).await,
+        ResourceKind::PodDisruptionBudgets => executor.describe::<PodDisruptionBudget>(name, ns, tz).await,
         _ => Err(anyhow::anyhow!("Describe not supported for this resource type")),
     }
 }
@@ -1,19 +1,30 @@
-use k8s_openapi::api::core::v1::Pod;
+use std::io::Write;
+
+use k8s_openapi::api::core::v1::{LimitRange, Pod, ResourceQuota};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 use kube::Api;
 
-use kubetile_core::resource::DetailSection;
-use kubetile_tui::pane::{PaneId, ResourceKind, SplitDirection, ViewType};
+use kubetile_config::{ExecBackend, LogBackend, SplitConfig, SplitOrientation};
+use kubetile_core::namespace_overview::namespace_overview_sections;
+use kubetile_core::network_policy::network_policy_detail_sections;
+use kubetile_core::resource::{DetailSection, ResourceSummary};
+use kubetile_core::service_endpoints::service_detail_sections;
+use kubetile_core::{
+    clean_yaml, ExecSession, LimitRangeSummary, LogSource, PodDisruptionBudgetSummary, PodSummary, ResourceQuotaSummary,
+};
+use kubetile_tui::pane::{Pane, PaneId, ResourceKind, SplitDirection, ViewType};
 use kubetile_tui::widgets::toast::ToastMessage;
 
-use crate::event::AppEvent;
+use crate::event::{AppEvent, ContainerPurpose};
 use crate::panes::logs_pane::HistoryRequest;
-use crate::panes::{AppLogsPane, ExecPane, LogsPane, ResourceDetailPane, ResourceListPane, YamlPane};
+use crate::panes::{AppLogsPane, AuditPane, ExecPane, LogsPane, ResourceDetailPane, ResourceListPane, YamlPane};
 
+use super::reconnect::report_auth_expired;
 use super::App;
 
 impl App {
     pub(super) fn open_detail_pane(&mut self, kind: ResourceKind, name: String, namespace: String) {
-        let sections = vec![DetailSection {
+        let mut sections = vec![DetailSection {
             title: "Metadata".into(),
             fields: vec![
                 ("Name".into(), name.clone()),
@@ -21,31 +32,185 @@ impl App {
                 ("Kind".into(), kind.display_name().into()),
             ],
         }];
+        sections.extend(self.volume_usage_section(&kind, &name, &namespace));
 
-        let detail = ResourceDetailPane::new(kind.clone(), name.clone(), Some(namespace), sections);
+        let detail = ResourceDetailPane::new(kind.clone(), name.clone(), Some(namespace.clone()), sections);
         let focused = self.tab_manager.active().focused_pane;
-        let view = ViewType::Detail(kind, name);
-        if let Some(new_id) = self.tab_manager.split_pane(focused, SplitDirection::Horizontal, view) {
-            self.panes.insert(new_id, Box::new(detail));
-            self.set_focus(new_id);
+        let view = ViewType::Detail(kind.clone(), name.clone());
+        let split = self.general_config.splits.detail;
+        let Some(new_id) = self.split_auto_opened(focused, view, split, 0.5) else { return };
+        self.panes.insert(new_id, Box::new(detail));
+        self.set_focus(new_id);
+
+        let Some(client) = &self.kube_client else { return };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let tz = self.display_timezone.clone();
+        match kind {
+            ResourceKind::Pods => {
+                let pods: Api<Pod> = Api::namespaced(kube_client, &namespace);
+                tokio::spawn(async move {
+                    match pods.get(&name).await {
+                        Ok(pod) => {
+                            let sections = PodSummary::from(&pod).detail_sections(&tz);
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not read pod {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::ResourceQuotas => {
+                let quotas: Api<ResourceQuota> = Api::namespaced(kube_client, &namespace);
+                tokio::spawn(async move {
+                    match quotas.get(&name).await {
+                        Ok(quota) => {
+                            let sections = ResourceQuotaSummary::from(&quota).detail_sections(&tz);
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not read resource quota {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::LimitRanges => {
+                let limit_ranges: Api<LimitRange> = Api::namespaced(kube_client, &namespace);
+                tokio::spawn(async move {
+                    match limit_ranges.get(&name).await {
+                        Ok(lr) => {
+                            let sections = LimitRangeSummary::from(&lr).detail_sections(&tz);
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not read limit range {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::PodDisruptionBudgets => {
+                let budgets: Api<PodDisruptionBudget> = Api::namespaced(kube_client, &namespace);
+                tokio::spawn(async move {
+                    match budgets.get(&name).await {
+                        Ok(pdb) => {
+                            let sections = PodDisruptionBudgetSummary::from(&pdb).detail_sections(&tz);
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not read disruption budget {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::Services => {
+                tokio::spawn(async move {
+                    match service_detail_sections(kube_client, &name, &namespace, &tz).await {
+                        Ok(sections) => {
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not read endpoints of service {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::NetworkPolicies => {
+                tokio::spawn(async move {
+                    match network_policy_detail_sections(kube_client, &name, &namespace, &tz).await {
+                        Ok(sections) => {
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not evaluate network policy {namespace}/{name}: {e}"),
+                    }
+                });
+            }
+            ResourceKind::Namespaces => {
+                tokio::spawn(async move {
+                    match namespace_overview_sections(kube_client, &name, &tz).await {
+                        Ok(sections) => {
+                            let _ = app_tx.send(AppEvent::DetailSectionsReady { pane_id: new_id, sections });
+                        }
+                        Err(e) => tracing::warn!("Could not summarize namespace {name}: {e}"),
+                    }
+                });
+            }
+            _ => {}
         }
     }
 
     pub(super) fn open_yaml_pane(&mut self, pane_id: PaneId, kind: ResourceKind, name: String, content: String) {
-        let yaml_pane = YamlPane::new(kind.clone(), name.clone(), content, &self.theme);
+        let mut yaml_pane = YamlPane::new(kind.clone(), name.clone(), content, &self.theme);
+        if !self.general_config.show_managed_fields {
+            yaml_pane.fold_managed_fields();
+        }
         let view = ViewType::Yaml(kind, name);
-        if let Some(new_id) = self.tab_manager.split_pane(pane_id, SplitDirection::Horizontal, view) {
+        let split = self.general_config.splits.yaml;
+        if let Some(new_id) = self.split_auto_opened(pane_id, view, split, 0.5) {
             self.panes.insert(new_id, Box::new(yaml_pane));
             self.set_focus(new_id);
         }
     }
 
+    pub(super) fn open_text_pane(&mut self, pane_id: PaneId, view: ViewType, title: String, content: String) {
+        let text_pane = YamlPane::plain_text(view.clone(), title, content, &self.theme);
+        let split = self.general_config.splits.yaml;
+        if let Some(new_id) = self.split_auto_opened(pane_id, view, split, 0.5) {
+            self.panes.insert(new_id, Box::new(text_pane));
+            self.set_focus(new_id);
+        }
+    }
+
+    /// Switches the focused YAML pane between the fetched object and a cleaned, re-appliable form.
+    pub(super) fn toggle_clean_yaml(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(yp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<YamlPane>()) else {
+            return;
+        };
+        if yp.is_cleaned() {
+            yp.show_fetched(&self.theme);
+            if !self.general_config.show_managed_fields {
+                yp.fold_managed_fields();
+            }
+            return;
+        }
+        match clean_yaml(yp.content()) {
+            Ok(cleaned) => yp.show_cleaned(cleaned, &self.theme),
+            Err(_) => self.toasts.push(ToastMessage::info("Only Kubernetes objects can be cleaned")),
+        }
+    }
+
+    /// Copies the focused YAML pane as shown, so a cleaned view copies the cleaned YAML.
+    pub(super) fn copy_yaml(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(yp) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<YamlPane>()) else { return };
+        let (content, what) = (yp.content().to_string(), if yp.is_cleaned() { "cleaned YAML" } else { "YAML" });
+        match self.clipboard.as_mut() {
+            None => self.toasts.push(ToastMessage::error("Clipboard unavailable")),
+            Some(cb) => match cb.set_text(content) {
+                Ok(_) => self.toasts.push(ToastMessage::info(format!("Copied {what}"))),
+                Err(e) => self.toasts.push(ToastMessage::error(format!("Clipboard error: {e}"))),
+            },
+        }
+    }
+
+    /// Puts what a program in an exec pane copied with OSC 52 on the host clipboard. Without a
+    /// local clipboard, as when kubetile itself runs over SSH, the sequence is passed on to the
+    /// terminal kubetile runs in.
+    pub(super) fn copy_from_exec(&mut self, text: String) {
+        if let Some(cb) = self.clipboard.as_mut() {
+            match cb.set_text(text.as_str()) {
+                Ok(()) => return,
+                Err(e) => tracing::warn!("exec: clipboard error, passing OSC 52 on to the terminal: {e}"),
+            }
+        }
+        let mut stdout = std::io::stdout();
+        let sequence = kubetile_terminal::osc52_copy_sequence(&text);
+        if let Err(e) = stdout.write_all(sequence.as_bytes()).and_then(|()| stdout.flush()) {
+            tracing::warn!("exec: cannot pass OSC 52 on to the terminal: {e}");
+        }
+    }
+
     pub(super) fn open_logs_pane(&mut self) {
         let Some((kind, name, namespace)) = self.selected_resource_info() else {
             return;
         };
+        if matches!(kind, ResourceKind::Deployments | ResourceKind::StatefulSets | ResourceKind::DaemonSets) {
+            self.open_workload_logs_pane(kind, name, namespace);
+            return;
+        }
         if kind != ResourceKind::Pods {
-            self.toasts.push(ToastMessage::info("Logs are only available for Pods"));
+            self.toasts.push(ToastMessage::info("Logs are only available for Pods and workloads"));
             return;
         }
 
@@ -54,25 +219,29 @@ impl App {
             return;
         }
 
-        let pane_id = if let Some(existing_id) = self.find_any_logs_pane_in_active_tab() {
-            self.panes.insert(existing_id, Box::new(LogsPane::new(name.clone(), namespace.clone())));
-            self.set_focus(existing_id);
-            existing_id
-        } else {
-            let focused = self.tab_manager.active().focused_pane;
-            let pane = LogsPane::new(name.clone(), namespace.clone());
-            let view = ViewType::Logs(name.clone());
-            let ratio = self.calc_logs_split_ratio(focused);
-            let Some(new_id) = self.tab_manager.split_pane_with_ratio(focused, SplitDirection::Horizontal, view, ratio)
-            else {
-                return;
-            };
-            self.panes.insert(new_id, Box::new(pane));
-            self.set_focus(new_id);
-            new_id
-        };
+        self.resolve_container(ContainerPurpose::Logs, None, name, namespace);
+    }
+
+    pub(super) fn open_logs_pane_for(&mut self, name: String, namespace: String, container: Option<String>) {
+        let Some(pane_id) = self.place_logs_pane(LogsPane::new(name.clone(), namespace.clone())) else { return };
+        self.start_logs_stream_for_pane(pane_id, name, namespace, container);
+    }
 
-        self.start_logs_stream_for_pane(pane_id, name, namespace);
+    /// Puts `pane` in place of the tab's logs pane, or splits one off the focused pane.
+    pub(super) fn place_logs_pane(&mut self, mut pane: LogsPane) -> Option<PaneId> {
+        pane.set_highlights(self.log_highlights.clone());
+        if let Some(existing_id) = self.find_any_logs_pane_in_active_tab() {
+            self.panes.insert(existing_id, Box::new(pane));
+            self.set_focus(existing_id);
+            return Some(existing_id);
+        }
+        let focused = self.tab_manager.active().focused_pane;
+        let view = pane.view_type().clone();
+        let ratio = self.calc_logs_split_ratio(focused);
+        let new_id = self.split_auto_opened(focused, view, self.general_config.splits.logs, ratio)?;
+        self.panes.insert(new_id, Box::new(pane));
+        self.set_focus(new_id);
+        Some(new_id)
     }
 
     fn find_logs_pane_in_active_tab(&self, pod_name: &str, namespace: &str) -> Option<PaneId> {
@@ -90,7 +259,28 @@ impl App {
         })
     }
 
-    fn calc_logs_split_ratio(&self, focused_pane: PaneId) -> f32 {
+    /// Splits `anchor` for an auto-opened pane as `split` configures. Horizontal splits without
+    /// a configured ratio give `auto_ratio` of the area to the anchor.
+    pub(super) fn split_auto_opened(
+        &mut self,
+        anchor: PaneId,
+        view: ViewType,
+        split: SplitConfig,
+        auto_ratio: f32,
+    ) -> Option<PaneId> {
+        let direction = match split.direction {
+            SplitOrientation::Horizontal => SplitDirection::Horizontal,
+            SplitOrientation::Vertical => SplitDirection::Vertical,
+        };
+        let ratio = match (split.ratio, split.direction) {
+            (Some(share), _) => 1.0 - share.clamp(0.1, 0.9),
+            (None, SplitOrientation::Horizontal) => auto_ratio,
+            (None, SplitOrientation::Vertical) => 0.5,
+        };
+        self.tab_manager.split_pane_with_ratio(anchor, direction, view, ratio)
+    }
+
+    pub(super) fn calc_logs_split_ratio(&self, focused_pane: PaneId) -> f32 {
         let Ok((_, rows)) = crossterm::terminal::size() else {
             return 0.5;
         };
@@ -113,7 +303,22 @@ impl App {
         }
     }
 
-    pub(super) fn start_logs_stream_for_pane(&mut self, pane_id: PaneId, name: String, namespace: String) {
+    /// Log streams go through the API server unless `general.log_backend` asks for kubectl or the
+    /// context runs kubectl on an SSH host.
+    pub(super) fn log_source(&self, ssh_host: Option<&str>) -> LogSource {
+        match (&self.kube_client, self.general_config.log_backend, ssh_host) {
+            (Some(client), LogBackend::Native, None) => LogSource::Api(client.inner_client()),
+            _ => LogSource::Kubectl,
+        }
+    }
+
+    pub(super) fn start_logs_stream_for_pane(
+        &mut self,
+        pane_id: PaneId,
+        name: String,
+        namespace: String,
+        container: Option<String>,
+    ) {
         let Some(client) = &self.kube_client else {
             self.attach_logs_error(pane_id, "No cluster connection".into());
             self.toasts.push(ToastMessage::error("No cluster connection"));
@@ -121,14 +326,18 @@ impl App {
         };
         let kube_client = client.inner_client();
         let context = client.context().to_string();
+        let ssh_host = self.general_config.ssh_host_for(Some(&context)).map(str::to_string);
+        let source = self.log_source(ssh_host.as_deref());
         let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
 
         tokio::spawn(async move {
             let mut request = kubetile_core::LogRequest {
                 context: Some(context),
+                ssh_host,
                 pod_name: name.clone(),
                 namespace: namespace.clone(),
-                container: None,
+                container,
                 follow: true,
                 tail_lines: Some(0),
                 since_seconds: None,
@@ -164,11 +373,12 @@ impl App {
                 let _ =
                     app_tx.send(AppEvent::LogsSnapshotReady { pane_id, lines, container: request.container.clone() });
             } else if let Err(e) = snapshot_result {
+                report_auth_expired(&app_tx, &auth_context, &e);
                 let _ = app_tx.send(AppEvent::LogsStreamError { pane_id, error: format!("snapshot failed: {e}") });
                 return;
             }
 
-            if let Ok(stream) = kubetile_core::LogStream::start(request).await {
+            if let Ok(stream) = kubetile_core::LogStream::start(source, request).await {
                 let _ = app_tx.send(AppEvent::LogsStreamReady { pane_id, stream });
             }
         });
@@ -183,31 +393,98 @@ impl App {
             return;
         }
 
-        let context = self.kube_client.as_ref().map(|c| c.context().to_string());
+        self.resolve_container(ContainerPurpose::Exec, None, name, namespace);
+    }
 
-        let focused = self.tab_manager.active().focused_pane;
-        let mut pane = ExecPane::new(name.clone(), "auto".into(), namespace.clone());
+    /// Starts an exec session in a new pane split off `anchor`; returns whether it opened.
+    pub(super) fn open_exec_pane_for(
+        &mut self,
+        anchor: PaneId,
+        name: String,
+        namespace: String,
+        container: Option<String>,
+    ) -> bool {
+        let context = self.kube_client.as_ref().map(|c| c.context().to_string());
+        let mut pane = ExecPane::new(name.clone(), container.unwrap_or_else(|| "auto".into()), namespace)
+            .with_scrollback(self.terminal_config.scrollback_lines as usize);
 
-        match pane.spawn_kubectl(context.as_deref()) {
+        let ssh_host = self.general_config.ssh_host_for(context.as_deref());
+        if ssh_host.is_none() && self.general_config.exec_backend == ExecBackend::Native {
+            if let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) {
+                return self.open_native_exec_pane(anchor, pane, client);
+            }
+        }
+        match pane.spawn_kubectl(context.as_deref(), ssh_host) {
             Ok(()) => {
                 let view = ViewType::Exec(name);
-                let ratio = self.calc_logs_split_ratio(focused);
-                let Some(new_id) =
-                    self.tab_manager.split_pane_with_ratio(focused, SplitDirection::Horizontal, view, ratio)
-                else {
-                    return;
+                let ratio = self.calc_logs_split_ratio(anchor);
+                let Some(new_id) = self.split_auto_opened(anchor, view, self.general_config.splits.exec, ratio) else {
+                    return false;
                 };
                 pane.start_output_forwarding(new_id, self.app_tx.clone());
+                pane.set_synchronized(self.tab_manager.active().sync_input);
                 self.panes.insert(new_id, Box::new(pane));
                 self.set_focus(new_id);
                 self.dispatcher.set_mode(crate::command::InputMode::Insert);
+                true
             }
             Err(e) => {
                 self.toasts.push(ToastMessage::error(format!("Failed to start exec: {e}")));
+                false
             }
         }
     }
 
+    /// Opens the pane right away and connects over the exec WebSocket in the background; output
+    /// starts once `AppEvent::ExecConnected` arrives.
+    fn open_native_exec_pane(&mut self, anchor: PaneId, mut pane: ExecPane, client: kube::Client) -> bool {
+        let view = ViewType::Exec(pane.pod_name().to_string());
+        let ratio = self.calc_logs_split_ratio(anchor);
+        let Some(new_id) = self.split_auto_opened(anchor, view, self.general_config.splits.exec, ratio) else {
+            return false;
+        };
+        let pod = pane.pod_name().to_string();
+        let namespace = pane.namespace().to_string();
+        let container = Some(pane.container().to_string()).filter(|c| c != "auto");
+        tracing::info!("exec: connecting to {namespace}/{pod} (container: {container:?})");
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let result =
+                ExecSession::start(client, &pod, &namespace, container.as_deref(), ExecPane::shell_command()).await;
+            if let Err(e) = &result {
+                report_auth_expired(&app_tx, &auth_context, e.as_ref());
+            }
+            let _ = app_tx.send(AppEvent::ExecConnected { pane_id: new_id, result: result.map_err(|e| e.to_string()) });
+        });
+        pane.set_synchronized(self.tab_manager.active().sync_input);
+        self.panes.insert(new_id, Box::new(pane));
+        self.set_focus(new_id);
+        self.dispatcher.set_mode(crate::command::InputMode::Insert);
+        true
+    }
+
+    /// Hands a connected session to its pane, or closes the pane when connecting failed. A pane
+    /// closed while connecting drops the session, which ends it.
+    pub(super) fn handle_exec_connected(&mut self, pane_id: PaneId, result: Result<ExecSession, String>) {
+        let exec = self.panes.get_mut(&pane_id).and_then(|pane| pane.as_any_mut().downcast_mut::<ExecPane>());
+        match (exec, result) {
+            (Some(exec), Ok(session)) => {
+                exec.attach_session(session);
+                exec.start_output_forwarding(pane_id, self.app_tx.clone());
+            }
+            (Some(_), Err(e)) => {
+                self.toasts.push(ToastMessage::error(format!("Failed to start exec: {e}")));
+                let was_focused = self.tab_manager.active().focused_pane == pane_id;
+                self.close_pane(pane_id);
+                if was_focused && self.dispatcher.mode() == crate::command::InputMode::Insert {
+                    self.dispatcher.set_mode(crate::command::InputMode::Normal);
+                }
+            }
+            (None, _) => {}
+        }
+    }
+
     pub(super) fn attach_logs_stream(&mut self, pane_id: PaneId, stream: kubetile_core::LogStream) {
         if let Some(pane) = self.panes.get_mut(&pane_id) {
             if let Some(logs_pane) = pane.as_any_mut().downcast_mut::<LogsPane>() {
@@ -273,10 +550,16 @@ impl App {
                 if let Some(req) = logs_pane.take_history_request() {
                     history_requests.push((pane_id, req));
                 }
+                if let Some(error) = logs_pane.take_tee_error() {
+                    self.toasts.push(ToastMessage::error(error));
+                }
             }
             if let Some(app_logs_pane) = pane.as_any_mut().downcast_mut::<AppLogsPane>() {
                 app_logs_pane.poll();
             }
+            if let Some(audit_pane) = pane.as_any_mut().downcast_mut::<AuditPane>() {
+                audit_pane.poll(&self.display_timezone);
+            }
         }
         for (pane_id, req) in history_requests {
             self.fetch_logs_history(pane_id, req);
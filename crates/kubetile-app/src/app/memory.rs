@@ -0,0 +1,57 @@
+use std::time::{Duration, Instant};
+
+use kubetile_tui::layout::MemoryBudgetView;
+use kubetile_tui::pane::PaneId;
+
+use super::suspension::SuspendReason;
+use super::App;
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+const MIB: usize = 1024 * 1024;
+
+/// What the panes held at the last check against `general.memory_budget_mb`.
+#[derive(Default)]
+pub(super) struct MemoryBudget {
+    used: usize,
+    last_check: Option<Instant>,
+}
+
+impl App {
+    /// Every few seconds, sums what the panes hold and, over budget, suspends the streams of
+    /// panes that are not on screen.
+    pub(super) fn enforce_memory_budget(&mut self) {
+        let budget = self.general_config.memory_budget_mb as usize * MIB;
+        if budget == 0 || self.memory_budget.last_check.is_some_and(|last| last.elapsed() < CHECK_INTERVAL) {
+            return;
+        }
+        self.memory_budget.last_check = Some(Instant::now());
+        self.memory_budget.used = self.panes.values().map(|pane| pane.memory_usage()).sum();
+        if self.memory_budget.used <= budget {
+            return;
+        }
+
+        let visible = self.tab_manager.active().pane_tree.leaf_ids();
+        let hidden: Vec<PaneId> = self.panes.keys().filter(|id| !visible.contains(id)).copied().collect();
+        let suspended = self.suspend_streams(hidden, SuspendReason::MemoryBudget);
+        if suspended > 0 {
+            tracing::info!(
+                "Pane memory {}MiB is over the {}MiB budget; suspended {suspended} background streams",
+                self.memory_budget.used / MIB,
+                self.general_config.memory_budget_mb,
+            );
+        }
+    }
+
+    /// Shown while any stream is suspended to stay within the budget.
+    pub(super) fn memory_budget_view(&self) -> Option<MemoryBudgetView> {
+        let suspended = self.suspended_streams.values().filter(|&&r| r == SuspendReason::MemoryBudget).count();
+        if suspended == 0 {
+            return None;
+        }
+        Some(MemoryBudgetView {
+            used_mb: (self.memory_budget.used / MIB) as u64,
+            budget_mb: self.general_config.memory_budget_mb,
+            suspended,
+        })
+    }
+}
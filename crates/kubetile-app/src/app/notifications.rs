@@ -0,0 +1,84 @@
+use std::ops::Deref;
+
+use jiff::tz::TimeZone;
+use kubetile_tui::widgets::toast::{ToastMessage, ToastRecord};
+
+use crate::command::InputMode;
+
+use super::App;
+
+/// Toasts kept for the notification center.
+const TOAST_HISTORY: usize = 100;
+
+/// The toasts on screen plus a history of every toast shown, so expired ones can be reviewed.
+pub(super) struct Toasts {
+    active: Vec<ToastMessage>,
+    history: Vec<ToastRecord>,
+    /// Zone the history's times are stamped in, following the current context.
+    timezone: TimeZone,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self { active: Vec::new(), history: Vec::new(), timezone: TimeZone::system() }
+    }
+}
+
+impl Toasts {
+    pub(super) fn set_timezone(&mut self, timezone: TimeZone) {
+        self.timezone = timezone;
+    }
+
+    pub(super) fn push(&mut self, toast: ToastMessage) {
+        let time = jiff::Timestamp::now().to_zoned(self.timezone.clone()).strftime("%H:%M:%S").to_string();
+        self.history.push(ToastRecord { time, level: toast.level.clone(), text: toast.text.clone() });
+        if self.history.len() > TOAST_HISTORY {
+            self.history.remove(0);
+        }
+        self.active.push(toast);
+    }
+
+    /// Drops expired toasts, returning whether any went away.
+    pub(super) fn expire(&mut self) -> bool {
+        let before = self.active.len();
+        self.active.retain(|t| !t.is_expired());
+        self.active.len() != before
+    }
+
+    pub(super) fn history(&self) -> &[ToastRecord] {
+        &self.history
+    }
+}
+
+impl Deref for Toasts {
+    type Target = [ToastMessage];
+
+    fn deref(&self) -> &[ToastMessage] {
+        &self.active
+    }
+}
+
+impl App {
+    pub(super) fn open_notification_center(&mut self) {
+        self.notification_scroll = Some(0);
+        self.dispatcher.set_mode(InputMode::NotificationCenter);
+    }
+
+    pub(super) fn close_notification_center(&mut self) {
+        self.notification_scroll = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    /// Scrolls towards older (`1`) or newer (`-1`) notifications.
+    pub(super) fn scroll_notification_center(&mut self, delta: isize) {
+        let max = self.toasts.history().len().saturating_sub(1);
+        if let Some(ref mut scroll) = self.notification_scroll {
+            *scroll = scroll.saturating_add_signed(delta).min(max);
+        }
+    }
+
+    pub(super) fn clear_notification_center(&mut self) {
+        self.toasts.history.clear();
+        self.notification_scroll = Some(0);
+    }
+}
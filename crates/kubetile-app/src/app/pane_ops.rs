@@ -1,102 +1,153 @@
+use std::time::Instant;
+
+use kubetile_tui::layout::{body_area, pane_areas};
 use kubetile_tui::pane::{find_pane_in_direction, Direction, PaneId, ResourceKind, SplitDirection, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+use ratatui::layout::Rect;
 
 use crate::command::InputMode;
-use crate::panes::ResourceListPane;
+use crate::panes::{ExecPane, HelpSection, ResourceListPane};
 
+use super::access::{mutate_access_checks, MUTATE_ACTIONS};
 use super::App;
 
+const RESOURCE_LIST_HELP: &[&str] = &[
+    "scroll_up",
+    "scroll_down",
+    "select",
+    "go_to_top",
+    "go_to_bottom",
+    "page_up",
+    "page_down",
+    "view_yaml",
+    "view_logs",
+    "exec",
+    "port_forward",
+    "test_ingress",
+    "view_describe",
+    "refresh_row",
+    "related",
+    "images",
+    "usages",
+    "dependency_graph",
+    "filter",
+    "resource_switcher",
+    "toggle_all_namespaces",
+    "group_by_owner",
+    "toggle_group",
+    "open_query",
+    "sort_column",
+    "toggle_sort_order",
+];
+const LOGS_HELP: &[&str] = &[
+    "scroll_up",
+    "scroll_down",
+    "page_up",
+    "page_down",
+    "go_to_top",
+    "go_to_bottom",
+    "toggle_follow",
+    "toggle_wrap",
+    "collapse_repeats",
+    "filter",
+    "save_logs",
+    "download_logs",
+    "tee_logs",
+    "switch_container",
+];
+const YAML_HELP: &[&str] = &[
+    "scroll_up",
+    "scroll_down",
+    "page_up",
+    "page_down",
+    "go_to_top",
+    "go_to_bottom",
+    "filter",
+    "toggle_group",
+    "fold_all",
+    "unfold_all",
+    "clean_yaml",
+    "copy_yaml",
+];
+const DETAIL_HELP: &[&str] = &[
+    "select_next",
+    "select_prev",
+    "scroll_up",
+    "scroll_down",
+    "page_up",
+    "page_down",
+    "related",
+    "images",
+    "usages",
+    "dependency_graph",
+];
+const APP_LOGS_HELP: &[&str] = &["scroll_up", "scroll_down", "page_up", "page_down", "toggle_follow"];
+const DASHBOARD_HELP: &[&str] = &["scroll_up", "scroll_down", "select", "refresh_row"];
+const TRASH_HELP: &[&str] = &["select_next", "select_prev", "view_yaml", "undo_delete", "delete"];
+const GRAPH_HELP: &[&str] =
+    &["select_next", "select_prev", "go_to_top", "go_to_bottom", "page_up", "page_down", "select"];
+const APPLY_HELP: &[&str] = &["select_next", "select_prev", "select", "page_up", "page_down", "apply"];
+
 impl App {
     pub(super) fn show_pane_help(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
         let view_type = self.panes.get(&focused).map(|p| p.view_type().clone());
-        let entries = view_type.as_ref().map(|vt| self.build_pane_help(vt)).unwrap_or_default();
         self.pane_help_prev_mode = self.dispatcher.mode();
+        let entries =
+            view_type.as_ref().map(|vt| self.build_pane_help(vt, self.pane_help_prev_mode)).unwrap_or_default();
+        self.pane_help_disabled = self.forbidden_help_entries(&entries);
         self.pane_help_overlay = Some(entries);
         self.dispatcher.set_mode(InputMode::PaneHelp);
     }
 
-    fn build_pane_help(&self, view_type: &ViewType) -> Vec<(String, String)> {
+    /// Cheat sheet for the focused pane, built from the dispatcher's tables so it follows the keymap.
+    fn build_pane_help(&self, view_type: &ViewType, mode: InputMode) -> Vec<(String, String)> {
         let d = &self.dispatcher;
-        let k = |name: &str| d.key_for(name).unwrap_or_default();
 
         match view_type {
-            ViewType::ResourceList(_) => vec![
-                (k("scroll_up"), "Up".into()),
-                (k("scroll_down"), "Down".into()),
-                (k("select"), "Open".into()),
-                (k("go_to_top"), "Top".into()),
-                (k("go_to_bottom"), "Bottom".into()),
-                (k("page_up"), "Page up".into()),
-                (k("page_down"), "Page down".into()),
-                (k("view_yaml"), "YAML".into()),
-                (k("view_logs"), "Logs".into()),
-                (k("exec"), "Exec into".into()),
-                (k("port_forward"), "Port forward".into()),
-                (k("view_describe"), "Describe".into()),
-                (k("filter"), "Filter".into()),
-                (k("resource_switcher"), "Switch resource".into()),
-                (k("toggle_all_namespaces"), "All namespaces".into()),
-                (k("open_query"), "Query DB".into()),
-                (k("sort_column"), "Sort column".into()),
-                (k("toggle_sort_order"), "Toggle sort order".into()),
-            ],
-            ViewType::Logs(_) => vec![
-                (k("scroll_up"), "Scroll up".into()),
-                (k("scroll_down"), "Scroll down".into()),
-                (k("page_up"), "Page up".into()),
-                (k("page_down"), "Page down".into()),
-                (k("go_to_top"), "Top".into()),
-                (k("go_to_bottom"), "Bottom".into()),
-                (k("toggle_follow"), "Follow mode".into()),
-                (k("toggle_wrap"), "Wrap text".into()),
-                (k("filter"), "Filter".into()),
-                (k("save_logs"), "Save visible logs to file".into()),
-                (k("download_logs"), "Download full log history".into()),
-            ],
-            ViewType::Yaml(_, _) => vec![
-                (k("scroll_up"), "Scroll up".into()),
-                (k("scroll_down"), "Scroll down".into()),
-                (k("page_up"), "Page up".into()),
-                (k("page_down"), "Page down".into()),
-                (k("go_to_top"), "Top".into()),
-                (k("go_to_bottom"), "Bottom".into()),
-                (k("filter"), "Search".into()),
-            ],
-            ViewType::Detail(_, _) => vec![
-                (k("select_next"), "Next section".into()),
-                (k("select_prev"), "Previous section".into()),
-                (k("scroll_up"), "Scroll up".into()),
-                (k("scroll_down"), "Scroll down".into()),
-                (k("page_up"), "Page up".into()),
-                (k("page_down"), "Page down".into()),
-            ],
-            ViewType::Query(_) => {
-                let mut entries = d.query_editor_shortcuts();
-                entries.push(("──────".into(), "Results (browse mode)".into()));
-                entries.extend(d.query_browse_shortcuts());
+            ViewType::ResourceList(kind) => {
+                let mut entries = d.shortcuts_named(RESOURCE_LIST_HELP);
+                let mutate: Vec<&str> = MUTATE_ACTIONS
+                    .iter()
+                    .filter(|(action, _)| !mutate_access_checks(action, kind, None).is_empty())
+                    .map(|(_, binding)| *binding)
+                    .collect();
+                entries.extend(d.shortcuts_named(&mutate));
+                entries.extend(d.shortcuts_named(&["undo_delete"]));
+                entries.extend(d.plugin_shortcuts());
                 entries
             }
+            ViewType::Logs(_) => d.shortcuts_named(LOGS_HELP),
+            ViewType::Yaml(_, _) => d.shortcuts_named(YAML_HELP),
+            ViewType::Detail(_, _) => d.shortcuts_named(DETAIL_HELP),
+            ViewType::Query(_) => match mode {
+                InputMode::QueryEditor => d.query_editor_shortcuts(),
+                InputMode::QueryBrowse => d.query_browse_shortcuts(),
+                _ => {
+                    let mut entries = d.query_editor_shortcuts();
+                    entries.push(("──────".into(), "Results (browse mode)".into()));
+                    entries.extend(d.query_browse_shortcuts());
+                    entries
+                }
+            },
             ViewType::Exec(_) | ViewType::Terminal => {
-                vec![("(all keys)".into(), "Forwarded to shell".into()), (k("back"), "Normal mode".into())]
-            }
-            ViewType::Plugin(name) if name == "AppLogs" => vec![
-                (k("scroll_up"), "Scroll up".into()),
-                (k("scroll_down"), "Scroll down".into()),
-                (k("page_up"), "Page up".into()),
-                (k("page_down"), "Page down".into()),
-                (k("toggle_follow"), "Follow mode".into()),
-            ],
-            ViewType::Plugin(name) if name == "PortForwards" => {
-                vec![(k("scroll_up"), "Previous".into()), (k("scroll_down"), "Next".into())]
-            }
-            ViewType::Help | ViewType::Plugin(_) | ViewType::Empty => {
-                vec![(k("scroll_up"), "Scroll up".into()), (k("scroll_down"), "Scroll down".into())]
+                let mut entries = vec![("(all keys)".into(), "Forwarded to shell".into())];
+                entries.extend(d.shortcuts_named(&["back", "switch_container", "toggle_sync_input"]));
+                entries
             }
+            ViewType::Plugin(name) if name == "AppLogs" => d.shortcuts_named(APP_LOGS_HELP),
+            ViewType::Plugin(name) if name == "Dashboard" => d.shortcuts_named(DASHBOARD_HELP),
+            ViewType::Plugin(name) if name == "Apply" => d.shortcuts_named(APPLY_HELP),
+            ViewType::Plugin(name) if name == "Trash" => d.shortcuts_named(TRASH_HELP),
+            ViewType::Plugin(name) if name == "Graph" => d.shortcuts_named(GRAPH_HELP),
+            ViewType::Help => d.shortcuts_named(&["scroll_up", "scroll_down", "filter"]),
+            ViewType::Plugin(_) | ViewType::Empty => d.shortcuts_named(&["scroll_up", "scroll_down"]),
         }
     }
 
     pub(super) fn close_pane_help(&mut self) {
         self.pane_help_overlay = None;
+        self.pane_help_disabled.clear();
         self.dispatcher.set_mode(self.pane_help_prev_mode);
     }
 
@@ -112,20 +163,46 @@ impl App {
         } else {
             let focused = self.tab_manager.active().focused_pane;
             if let Some(new_id) = self.tab_manager.split_pane(focused, SplitDirection::Vertical, ViewType::Help) {
-                let help = crate::panes::HelpPane::new(
-                    self.dispatcher.global_shortcuts(),
-                    self.dispatcher.navigation_shortcuts(),
-                    self.dispatcher.browse_shortcuts(),
-                    self.dispatcher.tui_shortcuts(),
-                    self.dispatcher.interact_shortcuts(),
-                    self.dispatcher.mutate_shortcuts(),
-                );
+                let help = crate::panes::HelpPane::new(self.help_sections(self.dispatcher.mode()));
                 self.panes.insert(new_id, Box::new(help));
                 self.set_focus(new_id);
             }
         }
     }
 
+    /// Every shortcut group of the keymap, plugins included, with the groups of `mode` marked current.
+    pub(super) fn help_sections(&self, mode: InputMode) -> Vec<HelpSection> {
+        let d = &self.dispatcher;
+        let normal = !matches!(
+            mode,
+            InputMode::QueryEditor
+                | InputMode::QueryBrowse
+                | InputMode::QueryHistory
+                | InputMode::QueryHistorySearch
+                | InputMode::SavedQueries
+                | InputMode::Completion
+        );
+        let section = |title: &str, shortcuts, current| HelpSection { title: title.into(), shortcuts, current };
+        vec![
+            section("Global (Ctrl+)", d.global_shortcuts(), normal),
+            section("Browse", d.browse_shortcuts(), normal),
+            section("Interact", d.interact_shortcuts(), normal),
+            section("Mutate (Ctrl+Alt+)", d.mutate_shortcuts(), normal),
+            section("TUI (Alt+)", d.tui_shortcuts(), normal),
+            section("Navigation", d.navigation_shortcuts(), normal),
+            section("Plugin", d.plugin_shortcuts(), normal),
+            section("Query Editor", d.query_editor_shortcuts(), mode == InputMode::QueryEditor),
+            section("Query Results", d.query_browse_shortcuts(), mode == InputMode::QueryBrowse),
+            section(
+                "Query History",
+                d.query_history_shortcuts(),
+                matches!(mode, InputMode::QueryHistory | InputMode::QueryHistorySearch),
+            ),
+            section("Saved Queries", d.saved_queries_shortcuts(), mode == InputMode::SavedQueries),
+            section("Completion", d.completion_shortcuts(), mode == InputMode::Completion),
+        ]
+    }
+
     pub(super) fn focus_next(&mut self) {
         let ids = self.tab_manager.active().pane_tree.leaf_ids();
         if ids.is_empty() {
@@ -151,7 +228,11 @@ impl App {
     pub(super) fn set_focus(&mut self, new_id: PaneId) {
         let focused = self.tab_manager.active().focused_pane;
         let prev_view = self.panes.get(&focused).map(|p| p.view_type().clone());
-        self.tab_manager.active_mut().focused_pane = new_id;
+        let tab = self.tab_manager.active_mut();
+        tab.focused_pane = new_id;
+        if tab.fullscreen_pane.is_some() && tab.zoom_follows_focus {
+            tab.fullscreen_pane = Some(new_id);
+        }
         if let Some(pane) = self.panes.get_mut(&new_id) {
             pane.on_focus_change(prev_view.as_ref());
         }
@@ -165,9 +246,12 @@ impl App {
                 InputMode::QueryEditor
                 | InputMode::QueryBrowse
                 | InputMode::QueryHistory
+                | InputMode::QueryHistorySearch
                 | InputMode::SaveQueryName
                 | InputMode::SavedQueries
                 | InputMode::ExportDialog
+                | InputMode::QueryParams
+                | InputMode::CellInspector
                 | InputMode::Completion,
             ) => self.dispatcher.set_mode(InputMode::Normal),
             _ => {}
@@ -178,7 +262,7 @@ impl App {
         let focused = self.tab_manager.active().focused_pane;
         let view = ViewType::Empty;
         if let Some(new_id) = self.tab_manager.split_pane(focused, direction, view.clone()) {
-            self.panes.insert(new_id, Box::new(super::EmptyPane(view)));
+            self.panes.insert(new_id, Box::new(super::EmptyPane(view, self.empty_hints.pane.clone())));
             self.set_focus(new_id);
         }
     }
@@ -202,9 +286,15 @@ impl App {
         let focused = tab.focused_pane;
         let was_focused = target == focused;
         if self.tab_manager.active_mut().pane_tree.close(target) {
-            self.panes.remove(&target);
+            if let Some(pane) = self.panes.remove(&target) {
+                self.park_closed_pane(target, pane);
+            }
+            self.query_sessions.remove(&target);
             self.active_watchers.remove(&target);
+            self.volume_stats_pollers.remove(&target);
             self.watcher_seq_by_pane.remove(&target);
+            self.plugin_column_cache.remove(&target);
+            self.list_view_states.retain(|(pane_id, _), _| *pane_id != target);
             if let Some(ref mut fs) = self.tab_manager.active_mut().fullscreen_pane {
                 if *fs == target {
                     self.tab_manager.active_mut().fullscreen_pane = None;
@@ -220,62 +310,203 @@ impl App {
     }
 
     pub(super) fn focus_direction(&mut self, dir: Direction) {
+        let tab = self.tab_manager.active();
+        if tab.fullscreen_pane.is_some() && !tab.zoom_follows_focus {
+            return;
+        }
+        if let Some(target) = self.neighbor_in_direction(dir) {
+            self.set_focus(target);
+        }
+    }
+
+    /// Swaps the focused pane with its neighbor in `dir`. Focus follows the moved pane, and both
+    /// panes keep their watchers and streams since only their positions change.
+    pub(super) fn swap_pane(&mut self, dir: Direction) {
+        if self.tab_manager.active().fullscreen_pane.is_some() {
+            return;
+        }
+        let Some(target) = self.neighbor_in_direction(dir) else { return };
+        let focused = self.tab_manager.active().focused_pane;
+        self.tab_manager.active_mut().pane_tree.swap(focused, target);
+    }
+
+    /// Rotates the panes of the split containing the focused pane.
+    pub(super) fn rotate_panes(&mut self) {
         if self.tab_manager.active().fullscreen_pane.is_some() {
             return;
         }
+        let focused = self.tab_manager.active().focused_pane;
+        self.tab_manager.active_mut().pane_tree.rotate(focused);
+    }
+
+    /// Fits the panes of the active tab to a terminal of `area`, so PTY panes resize their program
+    /// as soon as the terminal does rather than on the next frame.
+    pub(super) fn fit_panes(&mut self, area: Rect) {
+        let tab = self.tab_manager.active();
+        let body = body_area(area, self.offline.is_some());
+        for (pane_id, pane_area) in pane_areas(body, &tab.pane_tree, tab.fullscreen_pane) {
+            if let Some(pane) = self.panes.get_mut(&pane_id) {
+                pane.fit(pane_area);
+            }
+        }
+    }
 
-        let area = ratatui::prelude::Rect::new(0, 0, 200, 50);
+    fn neighbor_in_direction(&self, dir: Direction) -> Option<PaneId> {
+        let area = Rect::new(0, 0, 200, 50);
         let layout = self.tab_manager.active().pane_tree.layout(area);
         let focused = self.tab_manager.active().focused_pane;
 
-        let current = layout.iter().find(|(id, _)| *id == focused).map(|(id, r)| (*id, *r));
-        let Some(current) = current else { return };
+        let current = layout.iter().find(|(id, _)| *id == focused).map(|(id, r)| (*id, *r))?;
+        find_pane_in_direction(current, &layout, dir)
+    }
 
-        if let Some(target) = find_pane_in_direction(current, &layout, dir) {
-            self.set_focus(target);
+    pub(super) fn toggle_fullscreen(&mut self) {
+        let tab = self.tab_manager.active_mut();
+        if tab.fullscreen_pane.is_some() {
+            tab.fullscreen_pane = None;
+        } else {
+            tab.fullscreen_pane = Some(tab.focused_pane);
+            tab.zoom_follows_focus = false;
         }
     }
 
-    pub(super) fn toggle_fullscreen(&mut self) {
+    /// Like `toggle_fullscreen`, but moving focus while zoomed fullscreens the newly focused pane,
+    /// the way tmux's zoom behaves.
+    pub(super) fn toggle_zoom(&mut self) {
         let tab = self.tab_manager.active_mut();
         if tab.fullscreen_pane.is_some() {
             tab.fullscreen_pane = None;
         } else {
             tab.fullscreen_pane = Some(tab.focused_pane);
+            tab.zoom_follows_focus = true;
+        }
+    }
+
+    /// Toggles synchronized input for the active tab, like tmux's synchronize-panes: while it is on,
+    /// keys typed into one exec pane go to every exec pane in the tab.
+    pub(super) fn toggle_sync_input(&mut self) {
+        let tab = self.tab_manager.active_mut();
+        tab.sync_input = !tab.sync_input;
+        let on = tab.sync_input;
+        let exec_panes = self.exec_pane_ids();
+        for id in &exec_panes {
+            if let Some(ep) = self.panes.get_mut(id).and_then(|p| p.as_any_mut().downcast_mut::<ExecPane>()) {
+                ep.set_synchronized(on);
+            }
         }
+        let message = if on {
+            format!("Synchronized input on ({} exec panes)", exec_panes.len())
+        } else {
+            "Synchronized input off".to_string()
+        };
+        self.toasts.push(ToastMessage::info(message));
+    }
+
+    /// Exec panes in the active tab.
+    pub(super) fn exec_pane_ids(&self) -> Vec<PaneId> {
+        self.tab_manager
+            .active()
+            .pane_tree
+            .leaf_ids()
+            .into_iter()
+            .filter(|id| self.panes.get(id).is_some_and(|p| p.as_any().is::<ExecPane>()))
+            .collect()
+    }
+
+    /// Whether input typed into `pane_id` is broadcast to every exec pane of the active tab.
+    pub(super) fn broadcasts_input(&self, pane_id: PaneId) -> bool {
+        self.tab_manager.active().sync_input && self.panes.get(&pane_id).is_some_and(|p| p.as_any().is::<ExecPane>())
     }
 
     pub(super) fn switch_resource(&mut self, kind: ResourceKind) {
         let focused = self.tab_manager.active().focused_pane;
+        let previous = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<ResourceListPane>());
+        if let Some((previous_kind, view_state)) = previous.and_then(|rp| Some((rp.kind()?.clone(), rp.view_state()))) {
+            self.list_view_states.insert((focused, previous_kind), view_state);
+        }
+
         let headers: Vec<String> = Vec::new();
-        let new_pane = ResourceListPane::new(kind.clone(), headers);
+        let mut new_pane = self.new_list_pane(kind.clone(), headers);
+        if let Some(view_state) = self.list_view_states.get(&(focused, kind.clone())) {
+            new_pane.restore_view_state(view_state.clone());
+        }
+        let all_namespaces = new_pane.all_namespaces;
         self.panes.insert(focused, Box::new(new_pane));
 
-        let ns = if kind.is_namespaced() {
-            self.context_resolver.namespace().unwrap_or("default").to_string()
-        } else {
-            String::new()
-        };
-        self.start_watcher_for_pane(focused, &kind, &ns);
+        let namespaces = self.watch_namespaces(&kind, all_namespaces);
+        self.start_watcher_for_pane(focused, &kind, &namespaces);
         self.update_active_tab_title();
     }
 
-    pub(super) fn handle_resource_update(&mut self, pane_id: PaneId, headers: Vec<String>, rows: Vec<Vec<String>>) {
+    /// Namespaces the rows of a list can come from; empty when they can come from any.
+    pub(super) fn list_namespaces(&self, rp: &ResourceListPane) -> Vec<String> {
+        let Some(kind) = rp.kind() else { return Vec::new() };
+        match &rp.scope {
+            Some(scope) if kind.is_namespaced() => vec![scope.namespace.clone()],
+            _ => self.watch_namespaces(kind, rp.all_namespaces),
+        }
+    }
+
+    /// The namespace of a row: its NAMESPACE cell, or the one namespace its list watches.
+    pub(super) fn row_namespace(&self, rp: &ResourceListPane, row: &[String]) -> String {
+        super::header_value(&rp.state.headers, row, "NAMESPACE", usize::MAX).unwrap_or_else(|| {
+            self.list_namespaces(rp)
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| self.context_resolver.namespace().unwrap_or("default").to_string())
+        })
+    }
+
+    /// The configured columns of a list, with `namespace` added after `name` while its rows can
+    /// come from more than one namespace.
+    fn shown_columns(&self, pane_id: PaneId) -> Vec<String> {
+        let Some(rp) = self.panes.get(&pane_id).and_then(|p| p.as_any().downcast_ref::<ResourceListPane>()) else {
+            return Vec::new();
+        };
+        let Some(kind) = rp.kind() else { return Vec::new() };
+        let mut columns = self.views_config.columns_for(super::resource_kind_config_key(kind)).to_vec();
+        let spans_namespaces = kind.is_namespaced() && self.list_namespaces(rp).len() != 1;
+        if spans_namespaces && !columns.is_empty() && !columns.iter().any(|c| c == "namespace") {
+            let at = columns.iter().position(|c| c == "name").map_or(0, |i| i + 1);
+            columns.insert(at, "namespace".into());
+        }
+        columns
+    }
+
+    pub(super) fn handle_resource_update(
+        &mut self,
+        pane_id: PaneId,
+        mut headers: Vec<String>,
+        mut rows: Vec<Vec<String>>,
+        partial: bool,
+        expected: Option<usize>,
+    ) {
+        let shown_columns = self.shown_columns(pane_id);
         if let Some(pane) = self.panes.get_mut(&pane_id) {
             if let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() {
                 let previous_selected_resource = selected_resource_identity(resource_pane);
-                let configured_columns = resource_pane
-                    .kind()
-                    .map(|k| self.views_config.columns_for(super::resource_kind_config_key(k)))
-                    .unwrap_or(&[]);
+                if self.features.volume_stats && resource_pane.kind() == Some(&ResourceKind::PersistentVolumeClaims) {
+                    super::volume_stats::append_volume_usage(&self.volume_usage, &mut headers, &mut rows);
+                }
 
                 let (effective_headers, effective_rows) =
-                    kubetile_config::views::filter_columns(configured_columns, &headers, &rows);
+                    kubetile_config::views::filter_columns(&shown_columns, &headers, &rows);
+                resource_pane.item_owners = owner_cells(&headers, &rows);
 
                 if !effective_headers.is_empty() {
                     resource_pane.state.headers = effective_headers;
                 }
-                resource_pane.state.set_items(effective_rows);
+                let default_sort =
+                    resource_pane.kind().and_then(|k| self.views_config.sort_for(super::resource_kind_config_key(k)));
+                if let Some((column, order)) = default_sort {
+                    resource_pane.apply_default_sort(column, order == kubetile_config::SortOrder::Asc);
+                }
+                if partial {
+                    resource_pane.state.set_partial_items(effective_rows, expected);
+                } else {
+                    resource_pane.state.set_items(effective_rows);
+                }
+                resource_pane.last_event = Some(Instant::now());
                 resource_pane.refresh_filter_and_sort();
                 if let Some((name, namespace)) = previous_selected_resource {
                     if let Some(item_idx) = find_item_index_by_identity(
@@ -289,12 +520,72 @@ impl App {
                 }
             }
         }
+        self.apply_plugin_columns(pane_id);
+    }
+
+    pub(super) fn handle_row_refresh(
+        &mut self,
+        pane_id: PaneId,
+        name: &str,
+        namespace: &str,
+        headers: Vec<String>,
+        row: Option<Vec<String>>,
+    ) {
+        let shown_columns = self.shown_columns(pane_id);
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return };
+        let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() else { return };
+        let Some(item_idx) =
+            find_item_index_by_identity(&resource_pane.state.headers, &resource_pane.state.items, name, namespace)
+        else {
+            return;
+        };
+        let previous_selected_resource = selected_resource_identity(resource_pane);
+
+        match row {
+            Some(row) => {
+                let mut headers = headers;
+                let mut rows = vec![row];
+                if self.features.volume_stats && resource_pane.kind() == Some(&ResourceKind::PersistentVolumeClaims) {
+                    super::volume_stats::append_volume_usage(&self.volume_usage, &mut headers, &mut rows);
+                }
+                let owner = owner_cells(&headers, &rows).pop();
+                let (_, mut rows) = kubetile_config::views::filter_columns(&shown_columns, &headers, &rows);
+                if let Some(row) = rows.pop() {
+                    resource_pane.state.items[item_idx] = row;
+                }
+                if let (Some(owner), Some(slot)) = (owner, resource_pane.item_owners.get_mut(item_idx)) {
+                    *slot = owner;
+                }
+            }
+            None => {
+                resource_pane.state.items.remove(item_idx);
+                if item_idx < resource_pane.item_owners.len() {
+                    resource_pane.item_owners.remove(item_idx);
+                }
+                self.toasts.push(ToastMessage::info(format!("{name} no longer exists")));
+            }
+        }
+        if let Some(cache) = self.plugin_column_cache.get_mut(&pane_id) {
+            cache.remove(&(name.to_string(), namespace.to_string()));
+        }
+
+        let items = std::mem::take(&mut resource_pane.state.items);
+        resource_pane.state.set_items(items);
+        resource_pane.refresh_filter_and_sort();
+        if let Some((name, namespace)) = previous_selected_resource {
+            if let Some(item_idx) =
+                find_item_index_by_identity(&resource_pane.state.headers, &resource_pane.state.items, &name, &namespace)
+            {
+                let _ = resource_pane.select_item_index(item_idx);
+            }
+        }
+        self.apply_plugin_columns(pane_id);
     }
 
     pub(super) fn handle_resource_error(&mut self, pane_id: PaneId, error: String) {
         if let Some(pane) = self.panes.get_mut(&pane_id) {
             if let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() {
-                resource_pane.state.set_error(error);
+                resource_pane.state.set_watch_error(error);
             }
         }
     }
@@ -308,17 +599,14 @@ impl App {
     }
 }
 
+/// The OWNER cell of each row, read before column filtering so grouping works with the column hidden.
+fn owner_cells(headers: &[String], rows: &[Vec<String>]) -> Vec<String> {
+    let Some(col) = headers.iter().position(|h| h == "OWNER") else { return Vec::new() };
+    rows.iter().map(|row| row.get(col).cloned().unwrap_or_default()).collect()
+}
+
 pub(super) fn selected_resource_identity(resource_pane: &ResourceListPane) -> Option<(String, String)> {
-    let selected_idx = match resource_pane.state.selected {
-        Some(s) => {
-            if resource_pane.filtered_indices.is_empty() {
-                s
-            } else {
-                *resource_pane.filtered_indices.get(s)?
-            }
-        }
-        None => return None,
-    };
+    let selected_idx = resource_pane.selected_item_index()?;
     let row = resource_pane.state.items.get(selected_idx)?;
     let name = super::header_value(&resource_pane.state.headers, row, "NAME", 0)?;
     let namespace = super::header_value(&resource_pane.state.headers, row, "NAMESPACE", usize::MAX).unwrap_or_default();
@@ -0,0 +1,164 @@
+use kubetile_core::{run_column_command, ColumnTarget, ScriptResource};
+use kubetile_tui::pane::PaneId;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
+
+use super::pane_ops::{find_item_index_by_identity, selected_resource_identity};
+use super::scripts::with_scripts;
+use super::App;
+
+const LOADING_PLACEHOLDER: &str = "…";
+const ERROR_PLACEHOLDER: &str = "<error>";
+
+impl App {
+    /// Appends the configured plugin columns, then the script columns, to a resource list. Cached
+    /// values are used where available; rows without one get a loading placeholder and a
+    /// background fetch.
+    pub(super) fn apply_plugin_columns(&mut self, pane_id: PaneId) {
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return };
+        let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() else { return };
+        let Some(kind) = resource_pane.kind().cloned() else { return };
+        let kind_key = super::resource_kind_config_key(&kind);
+        let columns = self.views_config.plugin_columns_for(kind_key);
+        let script_headers = self.scripts.column_headers(kind_key);
+        if columns.is_empty() && script_headers.is_empty() {
+            return;
+        }
+
+        let plugin_headers: Vec<String> =
+            columns.iter().map(|c| c.header.clone()).chain(script_headers.iter().cloned()).collect();
+        if !resource_pane.state.headers.ends_with(&plugin_headers) {
+            resource_pane.state.headers.extend(plugin_headers.iter().cloned());
+        }
+        let headers = resource_pane.state.headers.clone();
+        let base_len = headers.len() - plugin_headers.len();
+
+        let watcher_seq = self.watcher_seq_by_pane.get(&pane_id).copied().unwrap_or(0);
+        let cache = self.plugin_column_cache.entry(pane_id).or_default();
+        let mut missing = Vec::new();
+        for row in &mut resource_pane.state.items {
+            if row.len() == headers.len() {
+                continue;
+            }
+            row.resize(base_len, String::new());
+            let name = super::header_value(&headers, row, "NAME", 0).unwrap_or_default();
+            let namespace = super::header_value(&headers, row, "NAMESPACE", usize::MAX).unwrap_or_default();
+            let identity = (name, namespace);
+            match cache.get(&identity) {
+                Some(values) => row.extend(values.iter().cloned()),
+                None => {
+                    let cells = if script_headers.is_empty() {
+                        Default::default()
+                    } else {
+                        super::scripts::row_cells(&headers[..base_len], row)
+                    };
+                    let placeholders = vec![LOADING_PLACEHOLDER.to_string(); plugin_headers.len()];
+                    row.extend(placeholders.iter().cloned());
+                    cache.insert(identity.clone(), placeholders);
+                    missing.push((identity, cells));
+                }
+            }
+        }
+
+        let previous_selected_resource = selected_resource_identity(resource_pane);
+        resource_pane.refresh_filter_and_sort();
+        if let Some((name, namespace)) = previous_selected_resource {
+            if let Some(item_idx) =
+                find_item_index_by_identity(&resource_pane.state.headers, &resource_pane.state.items, &name, &namespace)
+            {
+                let _ = resource_pane.select_item_index(item_idx);
+            }
+        }
+
+        let commands: Vec<String> = columns.iter().map(|c| c.command.clone()).collect();
+        let context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        let current_namespace = self.context_resolver.namespace().unwrap_or("default").to_string();
+        for ((name, namespace), cells) in missing {
+            let target_namespace = if namespace.is_empty() && kind.is_namespaced() {
+                current_namespace.clone()
+            } else {
+                namespace.clone()
+            };
+            let target = ColumnTarget {
+                kind: kind_key.to_string(),
+                name: name.clone(),
+                namespace: target_namespace,
+                context: context.clone(),
+            };
+            let commands = commands.clone();
+            let limiter = self.plugin_column_limiter.clone();
+            let app_tx = self.app_tx.clone();
+            let scripts = (!script_headers.is_empty()).then(|| self.scripts.clone());
+
+            tokio::spawn(async move {
+                let Ok(_permit) = limiter.acquire_owned().await else { return };
+                let mut values = Vec::with_capacity(commands.len());
+                for command in &commands {
+                    match run_column_command(command, &target).await {
+                        Ok(value) => values.push(value),
+                        Err(e) => {
+                            tracing::warn!("Plugin column `{command}` failed for {}: {e}", target.name);
+                            values.push(ERROR_PLACEHOLDER.to_string());
+                        }
+                    }
+                }
+                if let Some(scripts) = scripts {
+                    let resource = ScriptResource {
+                        kind: target.kind.clone(),
+                        name: target.name.clone(),
+                        namespace: target.namespace.clone(),
+                        context: target.context.clone(),
+                        cells,
+                    };
+                    let Some(script_values) = with_scripts(scripts, move |host| host.column_values(&resource)).await
+                    else {
+                        return;
+                    };
+                    for value in script_values {
+                        values.push(value.unwrap_or_else(|e| {
+                            tracing::warn!("Script column failed for {}: {e}", target.name);
+                            ERROR_PLACEHOLDER.to_string()
+                        }));
+                    }
+                }
+                let _ = app_tx.send(AppEvent::PluginColumnsReady { pane_id, watcher_seq, name, namespace, values });
+            });
+        }
+    }
+
+    pub(super) fn handle_plugin_columns_ready(
+        &mut self,
+        pane_id: PaneId,
+        name: String,
+        namespace: String,
+        values: Vec<String>,
+    ) {
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return };
+        let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() else { return };
+        let base_len = resource_pane.state.headers.len().saturating_sub(values.len());
+
+        if let Some(item_idx) =
+            find_item_index_by_identity(&resource_pane.state.headers, &resource_pane.state.items, &name, &namespace)
+        {
+            let row = &mut resource_pane.state.items[item_idx];
+            row.truncate(base_len);
+            row.extend(values.iter().cloned());
+
+            let previous_selected_resource = selected_resource_identity(resource_pane);
+            resource_pane.refresh_filter_and_sort();
+            if let Some((name, namespace)) = previous_selected_resource {
+                if let Some(item_idx) = find_item_index_by_identity(
+                    &resource_pane.state.headers,
+                    &resource_pane.state.items,
+                    &name,
+                    &namespace,
+                ) {
+                    let _ = resource_pane.select_item_index(item_idx);
+                }
+            }
+        }
+
+        self.plugin_column_cache.entry(pane_id).or_default().insert((name, namespace), values);
+    }
+}
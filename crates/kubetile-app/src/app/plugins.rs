@@ -0,0 +1,51 @@
+use kubetile_core::{expand_placeholders, ColumnTarget};
+use kubetile_tui::pane::Pane;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::panes::PluginPane;
+
+use super::App;
+
+impl App {
+    /// Runs the `[plugins]` command `name` on the selected resource, streaming its output into a
+    /// pane split off the resource list. Plugins may change the cluster, so read-only contexts
+    /// refuse them.
+    pub(super) fn run_plugin(&mut self, name: &str) {
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        let Some(plugin) = self.plugins.get(name) else { return };
+        let Some((kind, resource, namespace)) = self.selected_resource_info() else {
+            return;
+        };
+        let kind_key = super::access::api_resource(&kind).map_or(kind.short_name(), |(_, plural)| plural);
+        if !plugin.applies_to(kind_key) {
+            self.toasts.push(ToastMessage::info(format!("Plugin {name} does not apply to {}", kind.display_name())));
+            return;
+        }
+
+        let target = ColumnTarget {
+            kind: kind_key.to_string(),
+            name: resource,
+            namespace: if kind.is_namespaced() { namespace } else { String::new() },
+            context: self.kube_client.as_ref().map(|c| c.context().to_string()).unwrap_or_default(),
+        };
+        let command = expand_placeholders(&plugin.command, &target);
+        let mut pane = PluginPane::new(name.to_string(), target, command);
+        if let Err(e) = pane.spawn() {
+            self.toasts.push(ToastMessage::error(format!("Failed to start plugin {name}: {e}")));
+            return;
+        }
+
+        let anchor = self.tab_manager.active().focused_pane;
+        let ratio = self.calc_logs_split_ratio(anchor);
+        let view = pane.view_type().clone();
+        let Some(new_id) = self.split_auto_opened(anchor, view, self.general_config.splits.plugin, ratio) else {
+            return;
+        };
+        pane.start_output_forwarding(new_id, self.app_tx.clone());
+        self.panes.insert(new_id, Box::new(pane));
+        self.set_focus(new_id);
+    }
+}
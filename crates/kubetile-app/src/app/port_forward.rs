@@ -1,15 +1,12 @@
-use std::time::Duration;
-
 use k8s_openapi::api::core::v1::Pod;
 use kube::Api;
 
-use kubetile_core::ForwardId;
 use kubetile_tui::pane::ResourceKind;
 use kubetile_tui::widgets::toast::ToastMessage;
 
 use crate::command::InputMode;
 use crate::event::AppEvent;
-use crate::panes::PortForwardsPane;
+use crate::panes::{PortForwardRow, PortForwardsPane};
 
 use super::{App, PendingPortForward, PortForwardField};
 
@@ -140,14 +137,20 @@ impl App {
     }
 
     pub(super) fn refresh_port_forwards_panes(&mut self) {
-        let mut rows: Vec<(ForwardId, String, String, u16, u16, Duration)> = self
+        let mut rows: Vec<PortForwardRow> = self
             .active_forwards
             .values()
-            .map(|f| {
-                (f.id(), f.pod_name().to_string(), f.namespace().to_string(), f.local_port(), f.remote_port(), f.age())
+            .map(|f| PortForwardRow {
+                id: f.id(),
+                pod: f.pod_name().to_string(),
+                namespace: f.namespace().to_string(),
+                local: f.local_port(),
+                remote: f.remote_port(),
+                age: f.age(),
+                stats: f.stats(),
             })
             .collect();
-        rows.sort_by(|a, b| a.5.cmp(&b.5).reverse());
+        rows.sort_by(|a, b| a.age.cmp(&b.age).reverse());
 
         for pane in self.panes.values_mut() {
             if let Some(pf) = pane.as_any_mut().downcast_mut::<PortForwardsPane>() {
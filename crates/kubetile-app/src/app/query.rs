@@ -1,12 +1,14 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use kubetile_core::{QueryConfig, QueryResult};
+use kubetile_core::query_plan::QueryPlan;
+use kubetile_core::{QueryConfig, QueryEngine, QueryResult, QuerySession, SessionLost};
 use kubetile_tui::pane::{PaneId, ResourceKind, SplitDirection, ViewType};
 use kubetile_tui::widgets::toast::ToastMessage;
 
 use crate::command::InputMode;
 use crate::event::AppEvent;
-use crate::panes::QueryPane;
+use crate::panes::{QueryPane, TransactionState};
 
 use super::{App, PendingQueryDialog, QueryDialogField};
 
@@ -28,7 +30,7 @@ impl App {
         let app_tx = self.app_tx.clone();
 
         tokio::spawn(async move {
-            let config = kubetile_core::query::read_postgres_env(&kube_client, &pod, &namespace).await;
+            let config = kubetile_core::query::read_database_env(&kube_client, &pod, &namespace).await;
             let _ = app_tx.send(AppEvent::QueryPromptReady { config });
         });
     }
@@ -38,9 +40,11 @@ impl App {
             pod: config.pod,
             namespace: config.namespace,
             container: config.container,
+            engine: config.engine,
             db_input: config.database,
             user_input: config.user,
             password_input: config.password,
+            auth_db_input: config.auth_database,
             port_input: config.port,
             active_field: QueryDialogField::Database,
         });
@@ -62,14 +66,16 @@ impl App {
             pod: pending.pod,
             namespace: pending.namespace,
             container: pending.container,
+            engine: pending.engine,
             database: pending.db_input,
             user: pending.user_input,
             password: pending.password_input,
             port: pending.port_input,
+            auth_database: pending.auth_db_input,
         };
 
         let pane = QueryPane::new(&config);
-        let new_id = if self.query_open_new_tab {
+        let new_id = if self.general_config.query_open_new_tab {
             let tab_name = format!("query:{}", config.pod);
             self.tab_manager.new_tab(&tab_name, ViewType::Query(config.pod.clone()));
             self.tab_manager.active().focused_pane
@@ -85,32 +91,41 @@ impl App {
         self.panes.insert(new_id, Box::new(pane));
         self.set_focus(new_id);
 
-        self.execute_query_for_pane(new_id, config, "SELECT version()".to_string());
+        let version_query = config.engine.version_query().to_string();
+        self.execute_query_for_pane(new_id, config, version_query);
     }
 
     fn execute_query_for_pane(&self, pane_id: PaneId, config: QueryConfig, sql: String) {
-        let Some(client) = &self.kube_client else {
+        let Some(runner) = self.query_runner(pane_id) else {
             return;
         };
-        let kube_client = client.inner_client();
         let app_tx = self.app_tx.clone();
 
         tokio::spawn(async move {
-            let event = match kubetile_core::query::execute_query(&kube_client, &config, &sql).await {
+            let event = match runner.run(&config, &sql).await {
                 Ok(result) => AppEvent::QueryReady { pane_id, result },
+                Err(e) if e.is::<SessionLost>() => AppEvent::TransactionLost { pane_id, error: e.to_string() },
                 Err(e) => AppEvent::QueryError { pane_id, error: e.to_string() },
             };
             let _ = app_tx.send(event);
         });
     }
 
+    /// Where a pane's statements run: its open transaction, or a fresh exec per statement.
+    fn query_runner(&self, pane_id: PaneId) -> Option<QueryRunner> {
+        if let Some(session) = self.query_sessions.get(&pane_id) {
+            return Some(QueryRunner::Session(session.clone()));
+        }
+        self.kube_client.as_ref().map(|client| QueryRunner::OneShot(client.inner_client()))
+    }
+
     pub(super) fn handle_query_ready(&mut self, pane_id: PaneId, result: QueryResult) {
         let mut schema_config: Option<QueryConfig> = None;
         if let Some(pane) = self.panes.get_mut(&pane_id) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
                 if qp.is_connecting() {
-                    if let Some(version_str) = result.rows.first().and_then(|row| row.first()) {
-                        qp.set_connected(extract_pg_version(version_str));
+                    if let Some(label) = qp.config.engine.connected_label(&result) {
+                        qp.set_connected(label);
                         schema_config = Some(qp.config.clone());
                     } else {
                         qp.set_error("Connection test returned no data".to_string());
@@ -326,12 +341,13 @@ impl App {
             Some(qp) => (qp.history_selected_index(), qp.config.clone()),
             None => return,
         };
+        let Some(idx) = idx else { return };
         let mut history = kubetile_core::QueryHistory::load(&config.namespace, &config.pod, &config.database);
         let _ = history.delete(idx);
         let entries: Vec<String> = history.entries.iter().map(|e| e.sql.clone()).collect();
         if let Some(pane) = self.panes.get_mut(&focused) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
-                qp.open_history(entries);
+                qp.refresh_history(entries);
             }
         }
     }
@@ -341,6 +357,56 @@ impl App {
         if let Some(pane) = self.panes.get_mut(&focused) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
                 qp.scroll_up();
+                if qp.wants_next_page() {
+                    self.fetch_next_query_page(false);
+                }
+            }
+        }
+    }
+
+    pub(super) fn handle_query_row_estimate(&mut self, pane_id: PaneId, rows: u64) {
+        if let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.set_row_estimate(rows);
+        }
+    }
+
+    /// Requests the next page of the focused result. `explicit` reports a complete result.
+    pub(super) fn fetch_next_query_page(&mut self, explicit: bool) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(runner) = self.query_runner(focused) else {
+            return;
+        };
+        let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) else {
+            return;
+        };
+        let Some((offset, sql)) = qp.next_page_request() else {
+            if explicit {
+                self.toasts.push(ToastMessage::info("All rows fetched"));
+            }
+            return;
+        };
+        let config = qp.config.clone();
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let result = runner.run(&config, &sql).await.map_err(|e| e.to_string());
+            let _ = app_tx.send(AppEvent::QueryPageReady { pane_id: focused, offset, result });
+        });
+    }
+
+    pub(super) fn handle_query_page_ready(
+        &mut self,
+        pane_id: PaneId,
+        offset: usize,
+        result: Result<QueryResult, String>,
+    ) {
+        let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) else {
+            return;
+        };
+        match result {
+            Ok(result) => qp.append_page(offset, result.rows),
+            Err(e) => {
+                qp.page_failed();
+                self.toasts.push(ToastMessage::error(format!("Fetching more rows failed: {e}")));
             }
         }
     }
@@ -391,6 +457,43 @@ impl App {
         }
     }
 
+    // --- Cell inspector ---
+
+    pub(super) fn with_query_pane(&mut self, f: impl FnOnce(&mut QueryPane)) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            f(qp);
+        }
+    }
+
+    pub(super) fn open_cell_inspector(&mut self) {
+        let mut opened = false;
+        self.with_query_pane(|qp| opened = qp.open_cell_inspector());
+        if opened {
+            self.dispatcher.set_mode(InputMode::CellInspector);
+        } else {
+            self.toasts.push(ToastMessage::info("No row selected"));
+        }
+    }
+
+    pub(super) fn copy_inspected_cell(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let value = self
+            .panes
+            .get(&focused)
+            .and_then(|p| p.as_any().downcast_ref::<QueryPane>())
+            .and_then(|qp| qp.inspected_cell())
+            .map(|(_, value)| value.to_string());
+        let Some(value) = value else { return };
+        match self.clipboard.as_mut() {
+            None => self.toasts.push(ToastMessage::error("Clipboard unavailable")),
+            Some(cb) => match cb.set_text(value) {
+                Ok(_) => self.toasts.push(ToastMessage::info("Copied cell")),
+                Err(e) => self.toasts.push(ToastMessage::error(format!("Clipboard error: {e}"))),
+            },
+        }
+    }
+
     pub(super) fn query_copy_all(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
         let (csv, n) = match self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<QueryPane>()) {
@@ -411,23 +514,267 @@ impl App {
     }
 
     pub(super) fn execute_current_query(&mut self) {
+        self.run_editor_query(false);
+    }
+
+    /// Runs the editor content under `EXPLAIN (ANALYZE, FORMAT JSON)` and shows the plan tree.
+    pub(super) fn explain_current_query(&mut self) {
+        self.run_editor_query(true);
+    }
+
+    fn run_editor_query(&mut self, explain: bool) {
         let focused = self.tab_manager.active().focused_pane;
-        let (sql, config) = {
-            let Some(pane) = self.panes.get_mut(&focused) else {
+        let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) else {
+            return;
+        };
+        if explain && qp.config.engine != QueryEngine::Postgres {
+            self.toasts.push(ToastMessage::info("The plan view needs PostgreSQL"));
+            return;
+        }
+        let sql = qp.editor_content();
+        let sql = sql.trim().to_string();
+        if sql.is_empty() {
+            return;
+        }
+        let names = kubetile_core::query_params::parameter_names(&sql, qp.config.engine);
+        if !names.is_empty() {
+            qp.open_param_prompt(sql, names, explain);
+            self.dispatcher.set_mode(InputMode::QueryParams);
+            return;
+        }
+        qp.set_executing(&sql);
+        let config = qp.config.clone();
+        self.start_query(focused, config, sql, explain);
+    }
+
+    fn start_query(&mut self, pane_id: PaneId, config: QueryConfig, sql: String, explain: bool) {
+        if explain {
+            self.execute_explain_for_pane(pane_id, config, sql);
+            return;
+        }
+        let page_size = self.general_config.query_page_size as usize;
+        let first_page = (page_size > 0 && config.engine.is_sql())
+            .then(|| kubetile_core::query_paging::page_sql(&sql, page_size + 1, 0, config.engine))
+            .flatten();
+        let Some(first_page) = first_page else {
+            self.execute_query_for_pane(pane_id, config, sql);
+            return;
+        };
+        if let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.begin_paging(sql.clone(), page_size);
+        }
+        if config.engine == QueryEngine::Postgres {
+            self.execute_row_estimate_for_pane(pane_id, config.clone(), sql);
+        }
+        self.execute_query_for_pane(pane_id, config, first_page);
+    }
+
+    /// Asks the planner how many rows the whole query returns, for the "fetched N of ~M" status.
+    fn execute_row_estimate_for_pane(&self, pane_id: PaneId, config: QueryConfig, sql: String) {
+        let Some(runner) = self.query_runner(pane_id) else {
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+
+        tokio::spawn(async move {
+            let estimate = kubetile_core::query_plan::estimate_sql(&sql);
+            let Ok(result) = runner.run(&config, &estimate).await else {
                 return;
             };
-            let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() else {
-                return;
+            let json = result.rows.first().and_then(|row| row.first()).map(String::as_str).unwrap_or_default();
+            if let Ok(plan) = kubetile_core::query_plan::parse_plan(json) {
+                let _ = app_tx.send(AppEvent::QueryRowEstimate { pane_id, rows: plan.root.plan_rows as u64 });
+            }
+        });
+    }
+
+    fn execute_explain_for_pane(&self, pane_id: PaneId, config: QueryConfig, sql: String) {
+        let Some(runner) = self.query_runner(pane_id) else {
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+
+        tokio::spawn(async move {
+            let explain = kubetile_core::query_plan::explain_sql(&sql);
+            let plan = runner.run(&config, &explain).await.and_then(|result| {
+                let json = result.rows.first().and_then(|row| row.first()).map(String::as_str).unwrap_or_default();
+                kubetile_core::query_plan::parse_plan(json)
+            });
+            let event = match plan {
+                Ok(plan) => AppEvent::PlanReady { pane_id, plan },
+                Err(e) => AppEvent::QueryError { pane_id, error: e.to_string() },
             };
-            let sql = qp.editor_content();
-            let sql = sql.trim().to_string();
-            if sql.is_empty() {
+            let _ = app_tx.send(event);
+        });
+    }
+
+    // --- Transactions ---
+
+    /// Opens a database session for the focused query pane and runs `BEGIN` on it. Later queries
+    /// of the pane run on that session until the transaction is committed or rolled back.
+    pub(super) fn begin_query_transaction(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(qp) = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<QueryPane>()) else {
+            return;
+        };
+        if !qp.config.engine.is_sql() {
+            self.toasts.push(ToastMessage::info(format!("{} has no transactions", qp.config.engine.label())));
+            return;
+        }
+        match qp.transaction() {
+            TransactionState::None if !self.query_sessions.contains_key(&focused) => {}
+            TransactionState::Starting => {
+                self.toasts.push(ToastMessage::info("A transaction is already starting"));
                 return;
             }
-            qp.set_executing(&sql);
-            (sql, qp.config.clone())
+            _ => {
+                self.toasts.push(ToastMessage::info("A transaction is already open; commit or roll it back first"));
+                return;
+            }
+        }
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
         };
-        self.execute_query_for_pane(focused, config, sql);
+        let kube_client = client.inner_client();
+        let config = qp.config.clone();
+        let app_tx = self.app_tx.clone();
+        if let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.set_transaction(TransactionState::Starting);
+        }
+
+        tokio::spawn(async move {
+            let session = async {
+                let mut session = QuerySession::open(&kube_client, &config).await?;
+                session.execute("BEGIN").await?;
+                anyhow::Ok(session)
+            };
+            let result = session.await.map_err(|e| e.to_string());
+            let _ = app_tx.send(AppEvent::TransactionStarted { pane_id: focused, result });
+        });
+    }
+
+    pub(super) fn handle_transaction_started(&mut self, pane_id: PaneId, result: Result<QuerySession, String>) {
+        let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) else {
+            // The pane closed while the session opened; dropping it ends the transaction.
+            return;
+        };
+        match result {
+            Ok(session) => {
+                qp.set_transaction(TransactionState::Open);
+                self.query_sessions.insert(pane_id, Arc::new(tokio::sync::Mutex::new(session)));
+                self.toasts.push(ToastMessage::info("Transaction started"));
+            }
+            Err(e) => {
+                qp.set_transaction(TransactionState::None);
+                qp.set_error(format!("BEGIN failed: {e}"));
+            }
+        }
+    }
+
+    /// Drops the pane's dead session and says its transaction is gone; later statements run
+    /// on their own connections again.
+    pub(super) fn handle_transaction_lost(&mut self, pane_id: PaneId, error: String) {
+        self.query_sessions.remove(&pane_id);
+        if let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.set_transaction(TransactionState::None);
+            qp.set_error(error);
+        }
+        self.toasts.push(ToastMessage::error("The database session ended; the open transaction was rolled back"));
+    }
+
+    /// Sends `COMMIT` or `ROLLBACK` on the focused pane's session and closes it.
+    pub(super) fn end_query_transaction(&mut self, statement: &'static str) {
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(session) = self.query_sessions.remove(&focused) else {
+            self.toasts.push(ToastMessage::info("No open transaction"));
+            return;
+        };
+        if let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.set_transaction(TransactionState::None);
+        }
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let event = match session.lock().await.execute(statement).await {
+                Ok(_) => AppEvent::TransactionEnded { pane_id: focused, result: Ok(statement) },
+                Err(e) if e.is::<SessionLost>() => AppEvent::TransactionLost { pane_id: focused, error: e.to_string() },
+                Err(e) => AppEvent::TransactionEnded { pane_id: focused, result: Err(e.to_string()) },
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+
+    pub(super) fn handle_transaction_ended(&mut self, pane_id: PaneId, result: Result<&'static str, String>) {
+        match result {
+            Ok("COMMIT") => self.toasts.push(ToastMessage::info("Transaction committed")),
+            Ok(_) => self.toasts.push(ToastMessage::info("Transaction rolled back")),
+            Err(error) => self.handle_query_error(pane_id, error),
+        }
+    }
+
+    pub(super) fn handle_plan_ready(&mut self, pane_id: PaneId, plan: QueryPlan) {
+        if let Some(qp) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) {
+            qp.set_plan(plan);
+        }
+    }
+
+    // --- Parameter prompt ---
+
+    pub(super) fn query_params_input(&mut self, c: char) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get_mut(&focused) {
+            if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
+                qp.param_input(c);
+            }
+        }
+    }
+
+    pub(super) fn query_params_backspace(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get_mut(&focused) {
+            if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
+                qp.param_backspace();
+            }
+        }
+    }
+
+    pub(super) fn query_params_next_field(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get_mut(&focused) {
+            if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
+                qp.param_next_field();
+            }
+        }
+    }
+
+    /// Binds the collected values and runs the query. History keeps the SQL with its placeholders.
+    pub(super) fn confirm_query_params(&mut self) {
+        self.dispatcher.set_mode(InputMode::QueryEditor);
+        let focused = self.tab_manager.active().focused_pane;
+        let Some(qp) = self.panes.get_mut(&focused).and_then(|p| p.as_any_mut().downcast_mut::<QueryPane>()) else {
+            return;
+        };
+        let Some((sql, values, explain)) = qp.take_param_prompt() else {
+            return;
+        };
+        let config = qp.config.clone();
+        match kubetile_core::query_params::bind_parameters(&sql, &values, config.engine) {
+            Ok(bound) => {
+                qp.set_executing(&sql);
+                self.start_query(focused, config, bound, explain);
+            }
+            Err(e) => qp.set_error(e.to_string()),
+        }
+    }
+
+    pub(super) fn cancel_query_params(&mut self) {
+        let focused = self.tab_manager.active().focused_pane;
+        if let Some(pane) = self.panes.get_mut(&focused) {
+            if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
+                qp.close_param_prompt();
+            }
+        }
+        self.dispatcher.set_mode(InputMode::QueryEditor);
     }
 
     pub(super) fn handle_query_error(&mut self, pane_id: PaneId, error: String) {
@@ -446,7 +793,10 @@ impl App {
             QueryDialogField::Database => pending.db_input.push(c),
             QueryDialogField::User => pending.user_input.push(c),
             QueryDialogField::Password => pending.password_input.push(c),
+            QueryDialogField::AuthDatabase => pending.auth_db_input.push(c),
             QueryDialogField::Port => pending.port_input.push(c),
+            QueryDialogField::Engine if c == ' ' => pending.switch_engine(),
+            QueryDialogField::Engine => {}
         }
     }
 
@@ -464,15 +814,19 @@ impl App {
             QueryDialogField::Password => {
                 pending.password_input.pop();
             }
+            QueryDialogField::AuthDatabase => {
+                pending.auth_db_input.pop();
+            }
             QueryDialogField::Port => {
                 pending.port_input.pop();
             }
+            QueryDialogField::Engine => {}
         }
     }
 
     pub(super) fn query_dialog_next_field(&mut self) {
         if let Some(ref mut pending) = self.pending_query_dialog {
-            pending.active_field = pending.active_field.next();
+            pending.active_field = pending.active_field.next(pending.engine);
         }
     }
 
@@ -508,11 +862,18 @@ impl App {
 
     pub(super) fn confirm_save_query(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
-        let (name, sql) = match self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<QueryPane>()) {
+        let context = self.saved_queries_context();
+        let (name, sql, scope) = match self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<QueryPane>()) {
             Some(qp) => {
                 let name = qp.current_save_name().unwrap_or("").trim().to_string();
                 let sql = qp.editor_content();
-                (name, sql)
+                let scope = kubetile_core::QueryScope::at(
+                    qp.current_save_scope(),
+                    &context,
+                    &qp.config.namespace,
+                    &qp.config.database,
+                );
+                (name, sql, scope)
             }
             None => return,
         };
@@ -520,7 +881,7 @@ impl App {
             return;
         }
         let mut saved = kubetile_core::SavedQueries::load();
-        let _ = saved.add(&name, &sql);
+        let _ = saved.add(&name, &sql, scope);
         if let Some(pane) = self.panes.get_mut(&focused) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
                 qp.close_save_name();
@@ -542,12 +903,18 @@ impl App {
 
     // --- Saved-queries popup ---
 
+    /// The kubeconfig context saved queries are scoped to.
+    fn saved_queries_context(&self) -> String {
+        self.context_resolver.context_name().unwrap_or_default().to_string()
+    }
+
     pub(super) fn open_saved_queries(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
+        let context = self.saved_queries_context();
         let saved = kubetile_core::SavedQueries::load();
         if let Some(pane) = self.panes.get_mut(&focused) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
-                qp.open_saved_queries(saved.entries);
+                qp.open_saved_queries(saved.entries, &context);
             }
         }
         self.dispatcher.set_mode(InputMode::SavedQueries);
@@ -574,6 +941,7 @@ impl App {
     /// Handles both "load into editor" (normal mode) and "confirm rename" (rename mode).
     pub(super) fn saved_queries_select(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
+        let context = self.saved_queries_context();
 
         // Check if we're in rename mode first
         let is_renaming = self
@@ -597,7 +965,7 @@ impl App {
                     // Reload popup with updated entries
                     if let Some(pane) = self.panes.get_mut(&focused) {
                         if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
-                            qp.open_saved_queries(saved.entries);
+                            qp.open_saved_queries(saved.entries, &context);
                         }
                     }
                     self.toasts.push(ToastMessage::info(format!("Renamed to \"{new_name}\"")));
@@ -625,6 +993,7 @@ impl App {
 
     pub(super) fn saved_queries_delete(&mut self) {
         let focused = self.tab_manager.active().focused_pane;
+        let context = self.saved_queries_context();
         let real_idx = self
             .panes
             .get(&focused)
@@ -636,7 +1005,7 @@ impl App {
         let _ = saved.delete(real_idx);
         if let Some(pane) = self.panes.get_mut(&focused) {
             if let Some(qp) = pane.as_any_mut().downcast_mut::<QueryPane>() {
-                qp.open_saved_queries(saved.entries);
+                qp.open_saved_queries(saved.entries, &context);
             }
         }
     }
@@ -775,19 +1144,13 @@ impl App {
     // --- Schema fetch ---
 
     fn execute_schema_for_pane(&self, pane_id: PaneId, config: QueryConfig) {
-        let Some(client) = &self.kube_client else {
+        let (Some(client), Some(schema_sql)) = (&self.kube_client, config.engine.schema_sql()) else {
             return;
         };
         let kube_client = client.inner_client();
         let app_tx = self.app_tx.clone();
-        const SCHEMA_SQL: &str = "\
-            SELECT table_schema, table_name, column_name, data_type \
-            FROM information_schema.columns \
-            WHERE table_schema NOT IN ('pg_catalog','information_schema') \
-            ORDER BY table_name, ordinal_position";
-
         tokio::spawn(async move {
-            if let Ok(result) = kubetile_core::query::execute_query(&kube_client, &config, SCHEMA_SQL).await {
+            if let Ok(result) = kubetile_core::query::execute_query(&kube_client, &config, schema_sql).await {
                 let _ = app_tx.send(crate::event::AppEvent::SchemaReady { pane_id, rows: result.rows });
             }
         });
@@ -897,7 +1260,21 @@ impl App {
     }
 }
 
-fn expand_tilde(path: &str) -> std::path::PathBuf {
+enum QueryRunner {
+    OneShot(kube::Client),
+    Session(Arc<tokio::sync::Mutex<QuerySession>>),
+}
+
+impl QueryRunner {
+    async fn run(&self, config: &QueryConfig, sql: &str) -> anyhow::Result<QueryResult> {
+        match self {
+            Self::OneShot(client) => kubetile_core::query::execute_query(client, config, sql).await,
+            Self::Session(session) => session.lock().await.execute(sql).await,
+        }
+    }
+}
+
+pub(super) fn expand_tilde(path: &str) -> std::path::PathBuf {
     if let Some(rest) = path.strip_prefix("~/") {
         dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from(".")).join(rest)
     } else if path == "~" {
@@ -907,27 +1284,41 @@ fn expand_tilde(path: &str) -> std::path::PathBuf {
     }
 }
 
-fn extract_pg_version(version_str: &str) -> String {
-    // "PostgreSQL 15.2 on x86_64-pc-linux-gnu..." → "PostgreSQL 15.2"
-    let mut parts = version_str.splitn(3, ' ');
-    match (parts.next(), parts.next()) {
-        (Some(name), Some(ver)) => format!("{name} {ver}"),
-        _ => version_str.to_string(),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn extract_version_parses_pg_banner() {
-        let banner = "PostgreSQL 15.2 on x86_64-pc-linux-gnu, compiled by gcc (Debian 12.2.0-14) 12.2.0, 64-bit";
-        assert_eq!(extract_pg_version(banner), "PostgreSQL 15.2");
+    fn switching_engine_moves_default_port_but_keeps_edited_one() {
+        let mut dialog = PendingQueryDialog {
+            pod: "db-0".into(),
+            namespace: "data".into(),
+            container: None,
+            engine: QueryEngine::Postgres,
+            db_input: String::new(),
+            user_input: String::new(),
+            password_input: String::new(),
+            auth_db_input: String::new(),
+            port_input: "5432".into(),
+            active_field: QueryDialogField::Engine,
+        };
+        dialog.switch_engine();
+        assert_eq!((dialog.engine, dialog.port_input.as_str()), (QueryEngine::MySql, "3306"));
+
+        dialog.port_input = "13306".into();
+        dialog.switch_engine();
+        assert_eq!((dialog.engine, dialog.port_input.as_str()), (QueryEngine::Redis, "13306"));
+
+        dialog.port_input = "6379".into();
+        dialog.switch_engine();
+        assert_eq!((dialog.engine, dialog.port_input.as_str()), (QueryEngine::Mongo, "27017"));
+        assert_eq!(dialog.auth_db_input, "admin");
     }
 
     #[test]
-    fn extract_version_handles_short_string() {
-        assert_eq!(extract_pg_version("PostgreSQL"), "PostgreSQL");
+    fn auth_database_field_is_only_visited_for_mongo() {
+        assert_eq!(QueryDialogField::Password.next(QueryEngine::Postgres), QueryDialogField::Port);
+        assert_eq!(QueryDialogField::Password.next(QueryEngine::Mongo), QueryDialogField::AuthDatabase);
+        assert_eq!(QueryDialogField::AuthDatabase.next(QueryEngine::Mongo), QueryDialogField::Port);
     }
 }
@@ -0,0 +1,221 @@
+use std::time::{Duration, Instant};
+
+use kubetile_core::connection::is_auth_expired;
+use kubetile_core::informer::backoff_duration;
+use kubetile_core::KubeClient;
+use kubetile_tui::widgets::toast::ToastMessage;
+use tokio::sync::mpsc;
+
+use crate::event::AppEvent;
+
+use super::suspension::SuspendReason;
+use super::App;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(super) enum ReconnectPhase {
+    /// The next automatic attempt is due at this instant.
+    Waiting(Instant),
+    Connecting,
+    /// Connected again; the banner stays until a watcher delivers data.
+    Resuming,
+    /// Refreshing expired credentials failed; nothing is retried until the user reconnects.
+    AwaitingLogin,
+}
+
+pub(super) struct OfflineState {
+    pub(super) reason: String,
+    pub(super) attempt: u32,
+    pub(super) phase: ReconnectPhase,
+    /// Went offline because the cluster rejected the session's credentials.
+    pub(super) auth_expired: bool,
+}
+
+impl OfflineState {
+    pub(super) fn new(reason: String, phase: ReconnectPhase) -> Self {
+        Self { reason, attempt: 0, phase, auth_expired: false }
+    }
+}
+
+impl App {
+    /// Schedules the next automatic reconnect attempt, backing off with each failure.
+    pub(super) fn schedule_reconnect(&mut self) {
+        let Some(offline) = &mut self.offline else { return };
+        let delay = backoff_duration(offline.attempt);
+        offline.phase = ReconnectPhase::Waiting(Instant::now() + delay);
+        self.spawn_reconnect(delay);
+    }
+
+    /// Reconnects immediately, superseding any scheduled attempt. When online this rebuilds the
+    /// client and restarts the watchers of the active tab.
+    pub(super) fn reconnect_now(&mut self) {
+        match &mut self.offline {
+            Some(offline) => {
+                offline.attempt = 0;
+                offline.phase = ReconnectPhase::Connecting;
+            }
+            None => self.toasts.push(ToastMessage::info("Reconnecting…")),
+        }
+        self.spawn_reconnect(Duration::ZERO);
+    }
+
+    fn spawn_reconnect(&mut self, delay: Duration) {
+        self.reconnect_seq = self.reconnect_seq.wrapping_add(1);
+        let seq = self.reconnect_seq;
+        let context = self.context_resolver.context_name().map(str::to_string);
+        let ssh_host = self.general_config.ssh_host_for(context.as_deref()).map(str::to_string);
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let connect = async {
+                let client = KubeClient::connect_to(context.as_deref(), ssh_host.as_deref()).await?;
+                let namespaces = client.list_namespaces().await?;
+                anyhow::Ok((client, namespaces))
+            };
+            let event = match tokio::time::timeout(CONNECT_TIMEOUT, connect).await {
+                Ok(Ok((client, namespaces))) => AppEvent::ReconnectReady { seq, client, namespaces },
+                Ok(Err(e)) => AppEvent::ReconnectFailed { seq, error: format!("{e:#}") },
+                Err(_) => {
+                    AppEvent::ReconnectFailed { seq, error: format!("timed out after {}s", CONNECT_TIMEOUT.as_secs()) }
+                }
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+
+    /// Pauses the watchers of every pane on `context` when the cluster stops accepting its
+    /// credentials. When it is the active tab's context the client is rebuilt once, which runs the
+    /// kubeconfig's credential plugin again; panes of other tabs resume when their tab is opened.
+    pub(super) fn handle_auth_expired(&mut self, context: String, error: String) {
+        let active_context = self.context_resolver.context_name() == Some(context.as_str());
+        if active_context && self.offline.is_some() {
+            return;
+        }
+        let active_tab = self.tab_manager.active().id;
+        let mut background = Vec::new();
+        for tab in self.tab_manager.tabs() {
+            if tab.id == active_tab {
+                if active_context {
+                    for pane_id in tab.pane_tree.leaf_ids() {
+                        self.active_watchers.remove(&pane_id);
+                    }
+                }
+            } else if self.tab_scopes.get(&tab.id).and_then(|s| s.context_resolver.context_name()) == Some(&context) {
+                background.extend(tab.pane_tree.leaf_ids());
+            }
+        }
+        self.suspend_streams(background, SuspendReason::AuthExpired);
+
+        if !active_context {
+            tracing::warn!("Credentials for background context {context} rejected: {error}");
+            let message = format!("Credentials for {context} expired; its tabs refresh them when opened");
+            self.toasts.push(ToastMessage::info(message));
+            return;
+        }
+        tracing::warn!("Credentials rejected, refreshing: {error}");
+        self.record_connection_failure(Some(&context), error.clone());
+        let mut offline = OfflineState::new(error, ReconnectPhase::Connecting);
+        offline.auth_expired = true;
+        self.offline = Some(offline);
+        self.toasts.push(ToastMessage::info(format!("Credentials for {context} expired; refreshing…")));
+        self.spawn_reconnect(Duration::ZERO);
+    }
+
+    pub(super) fn handle_reconnect_ready(&mut self, seq: u64, mut client: KubeClient, namespaces: Vec<String>) {
+        if seq != self.reconnect_seq {
+            return;
+        }
+        if let Some(namespace) = self.context_resolver.namespace() {
+            client.set_namespace(namespace);
+        }
+        let selected: Vec<String> = self.context_resolver.namespaces().into_iter().map(String::from).collect();
+        self.context_resolver.set_context(client.cluster_context());
+        self.context_resolver.set_namespaces(&selected);
+        self.apply_context_display();
+        let active_tab = self.tab_manager.active().id;
+        for (tab_id, scope) in &mut self.tab_scopes {
+            if *tab_id != active_tab && scope.context_resolver.context_name() == Some(client.context()) {
+                let mut tab_client = client.clone();
+                if let Some(namespace) = scope.context_resolver.namespace() {
+                    tab_client.set_namespace(namespace);
+                }
+                scope.kube_client = Some(tab_client);
+            }
+        }
+        self.kube_client = Some(client);
+        self.clear_connection_failure();
+        self.reset_api_health();
+        self.fetch_cache.clear();
+        self.namespaces = namespaces;
+        if self.contexts.is_empty() {
+            self.contexts = KubeClient::list_contexts().unwrap_or_default();
+        }
+
+        if let Some(offline) = &mut self.offline {
+            offline.phase = ReconnectPhase::Resuming;
+        }
+        self.restart_watchers_for_active_panes();
+        if self.active_watchers.is_empty() {
+            self.offline = None;
+        }
+        self.sync_active_scope();
+        self.update_active_tab_title();
+    }
+
+    pub(super) fn handle_reconnect_failed(&mut self, seq: u64, error: String) {
+        if seq != self.reconnect_seq {
+            return;
+        }
+        tracing::warn!("Reconnect failed: {error}");
+        let context = self.context_resolver.context_name().map(str::to_string);
+        let summary = self.record_connection_failure(context.as_deref(), error.clone());
+        let Some(offline) = &mut self.offline else {
+            self.toasts.push(ToastMessage::error(format!("Reconnect failed: {summary}")));
+            return;
+        };
+        offline.reason = error;
+        offline.attempt = offline.attempt.saturating_add(1);
+        if offline.auth_expired {
+            // Retrying would only run the credential plugin again, which may open a browser each time.
+            offline.phase = ReconnectPhase::AwaitingLogin;
+            let prompt = match self.dispatcher.key_for("reconnect") {
+                Some(key) => format!("Could not refresh credentials: {summary}. Log in, then press {key}"),
+                None => format!("Could not refresh credentials: {summary}. Log in, then reconnect"),
+            };
+            self.toasts.push(ToastMessage::error(prompt));
+            return;
+        }
+        self.schedule_reconnect();
+    }
+
+    /// Clears the offline banner once a watcher delivers data after reconnecting.
+    pub(super) fn mark_stream_resumed(&mut self) {
+        if !matches!(self.offline, Some(OfflineState { phase: ReconnectPhase::Resuming, .. })) {
+            return;
+        }
+        let auth_expired = self.offline.take().is_some_and(|o| o.auth_expired);
+        if self.namespaces.is_empty() {
+            self.refresh_namespaces();
+        }
+        let context = self.context_resolver.context_name().unwrap_or("cluster");
+        let message = if auth_expired {
+            format!("Credentials for {context} refreshed")
+        } else {
+            format!("Reconnected to {context}")
+        };
+        self.toasts.push(ToastMessage::success(message));
+    }
+}
+
+/// Sends [`AppEvent::AuthExpired`] when `error` is the API server of `context` answering 401, so
+/// a failed action, fetch or exec session refreshes the credentials the same way a failing watcher
+/// does.
+pub(super) fn report_auth_expired(
+    app_tx: &mpsc::UnboundedSender<AppEvent>,
+    context: &str,
+    error: &(dyn std::error::Error + 'static),
+) {
+    if is_auth_expired(error) {
+        let _ = app_tx.send(AppEvent::AuthExpired { context: context.to_string(), error: error.to_string() });
+    }
+}
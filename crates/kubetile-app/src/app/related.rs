@@ -0,0 +1,182 @@
+use k8s_openapi::api::apps::v1::ReplicaSet;
+use k8s_openapi::api::core::v1::ServiceAccount;
+
+use kubetile_core::related::pod_related;
+use kubetile_core::Related;
+use kubetile_tui::pane::{PaneId, ResourceKind};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::resource_list::ListScope;
+use crate::panes::{ResourceDetailPane, ResourceListPane};
+
+use super::workload_logs::workload_selector;
+use super::App;
+
+pub(super) struct RelatedPicker {
+    pub(super) pane_id: PaneId,
+    pub(super) subject: String,
+    pub(super) namespace: String,
+    pub(super) related: Vec<Related>,
+    pub(super) labels: Vec<String>,
+    pub(super) selected: usize,
+}
+
+impl App {
+    /// The selected row of a list, or the resource shown in a detail pane.
+    pub(super) fn related_subject(&self) -> Option<(ResourceKind, String, String)> {
+        if let Some(info) = self.selected_resource_info() {
+            return Some(info);
+        }
+        let focused = self.tab_manager.active().focused_pane;
+        let detail = self.panes.get(&focused)?.as_any().downcast_ref::<ResourceDetailPane>()?;
+        let (kind, name, namespace) = detail.subject();
+        let namespace = namespace.or(self.context_resolver.namespace()).unwrap_or("default");
+        Some((kind.clone(), name.to_string(), namespace.to_string()))
+    }
+
+    /// Resolves what the focused pod or workload relates to and jumps there, asking first when
+    /// there is more than one place to go.
+    pub(super) fn open_related(&mut self) {
+        let Some((kind, name, namespace)) = self.related_subject() else { return };
+        if !matches!(
+            kind,
+            ResourceKind::Pods | ResourceKind::Deployments | ResourceKind::StatefulSets | ResourceKind::DaemonSets
+        ) {
+            self.toasts.push(ToastMessage::info("Related resources are available for Pods and workloads"));
+            return;
+        }
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let pane_id = self.tab_manager.active().focused_pane;
+        let subject = format!("{}/{name}", kind.short_name());
+
+        tokio::spawn(async move {
+            let result = match kind {
+                ResourceKind::Pods => pod_related(kube_client, &name, &namespace).await.map_err(|e| e.to_string()),
+                _ => workload_selector(kube_client, &kind, &name, &namespace).await.map(|s| vec![Related::Pods(s)]),
+            };
+            let event = match result {
+                Ok(related) => AppEvent::RelatedResolved { pane_id, subject, namespace, related },
+                Err(e) => AppEvent::Toast(ToastMessage::error(format!("Could not resolve related resources: {e}"))),
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+
+    pub(super) fn handle_related_resolved(
+        &mut self,
+        pane_id: PaneId,
+        subject: String,
+        namespace: String,
+        mut related: Vec<Related>,
+    ) {
+        match related.len() {
+            0 => self.toasts.push(ToastMessage::info(format!("{subject} has no related resources"))),
+            1 => self.jump_to_related(pane_id, &namespace, related.remove(0)),
+            _ => {
+                let labels = related.iter().map(Related::label).collect();
+                self.related_picker = Some(RelatedPicker { pane_id, subject, namespace, related, labels, selected: 0 });
+                self.dispatcher.set_mode(InputMode::RelatedPicker);
+            }
+        }
+    }
+
+    pub(super) fn related_picker_move(&mut self, forward: bool) {
+        let Some(picker) = &mut self.related_picker else { return };
+        let len = picker.related.len();
+        if len == 0 {
+            return;
+        }
+        picker.selected = if forward { (picker.selected + 1) % len } else { (picker.selected + len - 1) % len };
+    }
+
+    pub(super) fn confirm_related_picker(&mut self) {
+        let Some(mut picker) = self.related_picker.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        if picker.selected < picker.related.len() {
+            let related = picker.related.swap_remove(picker.selected);
+            self.jump_to_related(picker.pane_id, &picker.namespace, related);
+        }
+    }
+
+    pub(super) fn cancel_related_picker(&mut self) {
+        self.related_picker = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    /// Lists open in place of the pane the jump started from; kinds without a list view open as YAML.
+    fn jump_to_related(&mut self, pane_id: PaneId, namespace: &str, related: Related) {
+        if self.panes.contains_key(&pane_id) {
+            self.set_focus(pane_id);
+        }
+        let scope = || Some(ListScope { namespace: namespace.to_string(), label_selector: None });
+        match related {
+            Related::Owner { kind, name } => match kind.as_str() {
+                "Deployment" => self.open_related_list(ResourceKind::Deployments, scope(), name),
+                "StatefulSet" => self.open_related_list(ResourceKind::StatefulSets, scope(), name),
+                "DaemonSet" => self.open_related_list(ResourceKind::DaemonSets, scope(), name),
+                "Job" => self.open_related_list(ResourceKind::Jobs, scope(), name),
+                "ReplicaSet" => self.open_related_yaml(kind, name, namespace.to_string()),
+                _ => self.toasts.push(ToastMessage::info(format!("{kind} {name} has no view in kubetile"))),
+            },
+            Related::Node(name) => self.open_related_list(ResourceKind::Nodes, None, name),
+            Related::PersistentVolumeClaim(name) => {
+                self.open_related_list(ResourceKind::PersistentVolumeClaims, scope(), name)
+            }
+            Related::Pods(selector) => {
+                let scope = ListScope { namespace: namespace.to_string(), label_selector: Some(selector) };
+                self.open_related_list(ResourceKind::Pods, Some(scope), String::new())
+            }
+            Related::ServiceAccount(name) => {
+                self.open_related_yaml("ServiceAccount".into(), name, namespace.to_string())
+            }
+        }
+    }
+
+    /// Replaces the focused pane with a `kind` list narrowed to `scope` and filtered by `filter`.
+    fn open_related_list(&mut self, kind: ResourceKind, scope: Option<ListScope>, filter: String) {
+        let focused = self.tab_manager.active().focused_pane;
+        let previous = self.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<ResourceListPane>());
+        if let Some((previous_kind, view_state)) = previous.and_then(|rp| Some((rp.kind()?.clone(), rp.view_state()))) {
+            self.list_view_states.insert((focused, previous_kind), view_state);
+        }
+
+        let mut pane = self.new_list_pane(kind.clone(), Vec::new());
+        pane.scope = scope;
+        pane.filter_text = filter;
+        self.panes.insert(focused, Box::new(pane));
+
+        let namespaces = self.watch_namespaces(&kind, false);
+        self.start_watcher_for_pane(focused, &kind, &namespaces);
+        self.update_active_tab_title();
+    }
+
+    fn open_related_yaml(&mut self, kind: String, name: String, namespace: String) {
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let pane_id = self.tab_manager.active().focused_pane;
+
+        tokio::spawn(async move {
+            let executor = kubetile_core::ActionExecutor::new(kube_client);
+            let result = match kind.as_str() {
+                "ReplicaSet" => executor.get_yaml::<ReplicaSet>(&name, &namespace).await,
+                _ => executor.get_yaml::<ServiceAccount>(&name, &namespace).await,
+            };
+            let event = match result {
+                Ok(content) => AppEvent::YamlReady { pane_id, kind: ResourceKind::Custom(kind), name, content },
+                Err(e) => AppEvent::Toast(ToastMessage::error(format!("YAML fetch failed: {e}"))),
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+}
@@ -1,12 +1,21 @@
+use std::time::Instant;
+
 use kubetile_tui::layout::{
-    ConfirmDialogView, ContextSelectorView, NamespaceSelectorView, PaneHelpView, PortForwardDialogView,
-    PortForwardFieldView, QueryDialogFieldView, QueryDialogView, RenderContext, ResourceSwitcherView,
+    ApplyDialogView, CanIDialogView, CanIStatusView, ConfirmDialogView, ContainerPickerView, ContextSelectorView,
+    DataKeyPickerView, ExecSessionsView, GlobalSearchView, NamespaceSelectorView, NotificationCenterView,
+    OfflineBannerView, OfflineStatusView, PaneHelpView, PortForwardDialogView, PortForwardFieldView,
+    QueryDialogFieldView, QueryDialogView, RelatedPickerView, RenderContext, ResourceSwitcherView, RolloutPanelView,
+    SearchHitView, SetImageDialogView, UndoListView, WarningTickerView, WelcomeView,
 };
 use kubetile_tui::pane::{ResourceKind, ViewType};
 
 use crate::command::InputMode;
+use crate::event::ContainerPurpose;
 use crate::panes::ResourceListPane;
 
+use super::access::CanIStatus;
+use super::reconnect::ReconnectPhase;
+use super::search::MAX_SHOWN_HITS;
 use super::{App, PortForwardField, QueryDialogField};
 
 impl App {
@@ -21,27 +30,46 @@ impl App {
             InputMode::Command => "Command",
             InputMode::Insert => "Insert",
             InputMode::ResourceSwitcher => "Resource",
-            InputMode::ConfirmDialog => "Confirm",
+            InputMode::ConfirmDialog | InputMode::ConfirmTypeName => "Confirm",
+            InputMode::CanIDialog => "CanI",
+            InputMode::ApplyPathInput => "Apply",
+            InputMode::SetImageDialog => "SetImage",
+            InputMode::NotificationCenter => "Notifications",
+            InputMode::ContainerPicker => "Container",
+            InputMode::RelatedPicker => "Related",
+            InputMode::DataKeyPicker => "DataKey",
+            InputMode::UndoList => "Undo",
+            InputMode::ExecSessions => "Sessions",
+            InputMode::GlobalSearch => "GlobalSearch",
             InputMode::FilterInput => "Filter",
             InputMode::PortForwardInput => "PortForward",
+            InputMode::LogTeePath => "TeePath",
             InputMode::QueryDialog => "QueryDialog",
             InputMode::QueryEditor => "QueryEditor",
             InputMode::QueryBrowse => "QueryBrowse",
             InputMode::QueryHistory => "QueryHistory",
+            InputMode::QueryHistorySearch => "QueryHistorySearch",
             InputMode::SaveQueryName => "SaveQueryName",
             InputMode::SavedQueries => "SavedQueries",
             InputMode::ExportDialog => "ExportDialog",
+            InputMode::QueryParams => "QueryParams",
+            InputMode::CellInspector => "CellInspector",
             InputMode::Completion => "Completion",
             InputMode::PaneHelp => "Help",
+            InputMode::Welcome => "Welcome",
         }
     }
 
-    pub(super) fn build_render_context(&self) -> (RenderContext<'_>, Vec<String>, [Option<String>; 7]) {
+    pub(super) fn build_render_context(&self) -> (RenderContext<'_>, Vec<String>, [Option<String>; 9]) {
         let namespace_selector = if self.dispatcher.mode() == InputMode::NamespaceSelector {
+            let context = self.context_resolver.context_name().unwrap_or_default();
             Some(NamespaceSelectorView {
                 namespaces: &self.namespaces,
                 filter: &self.namespace_filter,
                 selected: self.namespace_selected,
+                marked: &self.namespace_marked,
+                favorites: self.namespace_memory.favorites(context),
+                recent: self.namespace_memory.recent(context),
             })
         } else {
             None
@@ -51,6 +79,7 @@ impl App {
                 contexts: &self.contexts,
                 filter: &self.context_filter,
                 selected: self.context_selected,
+                health: &self.context_health,
             })
         } else {
             None
@@ -62,19 +91,29 @@ impl App {
             selected: sw.selected(),
         });
 
-        let confirm_dialog = self.pending_confirmation.as_ref().map(|pc| ConfirmDialogView { message: &pc.message });
+        let confirm_dialog = self.pending_confirmation.as_ref().map(|pc| ConfirmDialogView {
+            message: &pc.message,
+            type_to_confirm: pc.type_to_confirm.as_deref(),
+            input: &pc.input,
+            confirm_focused: pc.confirm_focused,
+            countdown: pc.deadline.map(|d| d.saturating_duration_since(Instant::now()).as_secs_f64().ceil() as u64),
+        });
         let query_dialog = self.pending_query_dialog.as_ref().map(|qd| QueryDialogView {
             pod: &qd.pod,
             namespace: &qd.namespace,
+            engine: qd.engine.label(),
             database: &qd.db_input,
             user: &qd.user_input,
             password: &qd.password_input,
+            auth_database: (qd.engine == kubetile_core::QueryEngine::Mongo).then_some(qd.auth_db_input.as_str()),
             port: &qd.port_input,
             active_field: match qd.active_field {
                 QueryDialogField::Database => QueryDialogFieldView::Database,
                 QueryDialogField::User => QueryDialogFieldView::User,
                 QueryDialogField::Password => QueryDialogFieldView::Password,
+                QueryDialogField::AuthDatabase => QueryDialogFieldView::AuthDatabase,
                 QueryDialogField::Port => QueryDialogFieldView::Port,
+                QueryDialogField::Engine => QueryDialogFieldView::Engine,
             },
         });
         let pane_help = self.pane_help_overlay.as_deref().map(|entries| PaneHelpView {
@@ -84,6 +123,59 @@ impl App {
                 .map(|p| pane_help_title(p.view_type()))
                 .unwrap_or("Help"),
             entries,
+            disabled: &self.pane_help_disabled,
+        });
+        let can_i_dialog = self.can_i_dialog.as_ref().map(|ci| CanIDialogView {
+            input: &ci.input,
+            namespace: self.context_resolver.namespace().unwrap_or("default"),
+            query: ci.check.as_ref().map(|_| ci.query.as_str()),
+            status: match &ci.status {
+                CanIStatus::Idle => CanIStatusView::Idle,
+                CanIStatus::Checking => CanIStatusView::Checking,
+                CanIStatus::Decided(decision) if decision.allowed => CanIStatusView::Allowed(&decision.reason),
+                CanIStatus::Decided(decision) => CanIStatusView::Denied(&decision.reason),
+                CanIStatus::Failed(error) => CanIStatusView::Error(error),
+            },
+        });
+        let apply_dialog = self.apply_dialog.as_ref().map(|ad| ApplyDialogView {
+            input: &ad.input,
+            namespace: &ad.namespace,
+            namespace_count: self.context_resolver.namespaces().len(),
+            force: ad.force,
+            planning: ad.planning,
+            error: ad.error.as_deref(),
+        });
+
+        let container_picker = self.container_picker.as_ref().map(|cp| ContainerPickerView {
+            purpose: match cp.purpose {
+                ContainerPurpose::Logs => "Logs",
+                ContainerPurpose::Exec => "Exec",
+            },
+            pod: &cp.pod,
+            containers: &cp.containers,
+            selected: cp.selected,
+            remembered: cp.remembered.as_deref(),
+        });
+
+        let related_picker = self.related_picker.as_ref().map(|rp| RelatedPickerView {
+            subject: &rp.subject,
+            items: &rp.labels,
+            selected: rp.selected,
+        });
+
+        let offline = self.offline.as_ref().map(|offline| OfflineBannerView {
+            reason: self.connection_failure.as_ref().map_or(&offline.reason, |f| &f.cause),
+            status: match offline.phase {
+                ReconnectPhase::Waiting(at) => match at.saturating_duration_since(Instant::now()) {
+                    remaining if remaining.is_zero() => OfflineStatusView::Connecting,
+                    remaining => OfflineStatusView::RetryIn(remaining.as_secs_f64().ceil() as u64),
+                },
+                ReconnectPhase::Connecting => OfflineStatusView::Connecting,
+                ReconnectPhase::Resuming => OfflineStatusView::Resuming,
+                ReconnectPhase::AwaitingLogin => OfflineStatusView::AwaitingLogin,
+            },
+            reconnect_key: None,
+            diagnostics_key: None,
         });
 
         let port_forward_dialog = self.pending_port_forward.as_ref().map(|pf| PortForwardDialogView {
@@ -97,7 +189,13 @@ impl App {
             },
         });
 
-        let tab_names = self.tab_manager.tab_names();
+        let tab_names: Vec<String> = self
+            .tab_manager
+            .tab_names()
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| if self.tab_has_suspended_streams(i) { format!("{name} ⏸") } else { name })
+            .collect();
         let keys = [
             self.dispatcher.key_for("help"),
             self.dispatcher.key_for("show_pane_help"),
@@ -106,6 +204,8 @@ impl App {
             self.dispatcher.key_for("close_pane"),
             self.dispatcher.key_for("new_tab"),
             self.dispatcher.key_for("quit"),
+            self.dispatcher.key_for("reconnect"),
+            self.dispatcher.key_for("connection_diagnostics"),
         ];
 
         let tab = self.tab_manager.active();
@@ -114,13 +214,61 @@ impl App {
         let ctx = RenderContext {
             cluster_name: self.context_resolver.context_name(),
             namespace: self.context_resolver.namespace(),
+            read_only: self.is_read_only_context(),
+            offline,
             namespace_selector,
             context_selector,
             resource_switcher,
             confirm_dialog,
             port_forward_dialog,
             query_dialog,
+            can_i_dialog,
+            apply_dialog,
+            set_image_dialog: self.set_image_dialog.as_ref().map(|sd| SetImageDialogView {
+                target: &sd.target,
+                containers: &sd.rows,
+                selected: sd.selected,
+                error: sd.error.as_deref(),
+            }),
+            container_picker,
+            related_picker,
+            data_key_picker: self.data_key_picker.as_ref().map(|dp| DataKeyPickerView {
+                subject: &dp.subject,
+                items: &dp.labels,
+                selected: dp.selected,
+            }),
+            undo_list: self.undo_list.as_ref().map(|ul| UndoListView { items: &ul.labels, selected: ul.selected }),
+            exec_sessions: self
+                .exec_session_picker
+                .as_ref()
+                .map(|p| ExecSessionsView { items: &p.labels, selected: p.selected }),
+            global_search: self.global_search.as_ref().map(|gs| {
+                let matches = gs.matches();
+                GlobalSearchView {
+                    input: &gs.input,
+                    query: gs.query().name,
+                    scope: gs.scope_label(),
+                    hits: matches
+                        .iter()
+                        .take(MAX_SHOWN_HITS)
+                        .map(|hit| SearchHitView {
+                            kind: hit.kind.short_name(),
+                            namespace: hit.namespace.as_deref(),
+                            name: &hit.name,
+                        })
+                        .collect(),
+                    total: matches.len(),
+                    selected: gs.selected,
+                    loading: gs.loading,
+                    notice: gs.notice(),
+                }
+            }),
+            rollouts: (!self.rollouts.lines.is_empty()).then(|| RolloutPanelView { entries: &self.rollouts.lines }),
             pane_help,
+            notification_center: self
+                .notification_scroll
+                .map(|scroll| NotificationCenterView { entries: self.toasts.history(), scroll }),
+            welcome: self.welcome.as_ref().map(|w| WelcomeView { entries: &w.entries, config_path: &w.display_path }),
             toasts: &self.toasts,
             pane_tree,
             focused_pane: Some(focused_pane),
@@ -136,6 +284,12 @@ impl App {
             close_pane_key: None,
             new_tab_key: None,
             quit_key: None,
+            warning_ticker: self
+                .warning_ticker
+                .text()
+                .map(|text| WarningTickerView { text, offset: self.warning_ticker.offset }),
+            connection_health: self.connection_health_view(),
+            memory_budget: self.memory_budget_view(),
             theme: &self.theme,
         };
 
@@ -159,7 +313,8 @@ impl App {
                 }
             }
         }
-        let ns = self.context_resolver.namespace().unwrap_or("n/a");
+        let ns = self.context_resolver.namespaces().join(",");
+        let ns = if ns.is_empty() { "n/a" } else { ns.as_str() };
         if ns.len() > 25 {
             format!("{}…", &ns[..24])
         } else {
@@ -180,6 +335,11 @@ impl App {
             ViewType::Help => "HLP".into(),
             ViewType::Empty => "EMP".into(),
             ViewType::Plugin(name) if name == "AppLogs" => "ALG".into(),
+            ViewType::Plugin(name) if name == "Audit" => "AUD".into(),
+            ViewType::Plugin(name) if name == "Trash" => "TRS".into(),
+            ViewType::Plugin(name) if name == "Dashboard" => "DSH".into(),
+            ViewType::Plugin(name) if name == "Apply" => "APL".into(),
+            ViewType::Plugin(name) if name == "Graph" => "GRF".into(),
             ViewType::Plugin(_) => "PLG".into(),
             ViewType::Query(_) => "SQL".into(),
         }
@@ -198,6 +358,11 @@ fn pane_help_title(view_type: &ViewType) -> &'static str {
         ViewType::Empty => "Help",
         ViewType::Plugin(name) if name == "AppLogs" => "Help — App Logs",
         ViewType::Plugin(name) if name == "PortForwards" => "Help — Port Forwards",
+        ViewType::Plugin(name) if name == "Audit" => "Help — Audit Log",
+        ViewType::Plugin(name) if name == "Trash" => "Help — Trash",
+        ViewType::Plugin(name) if name == "Dashboard" => "Help — Dashboard",
+        ViewType::Plugin(name) if name == "Apply" => "Help — Apply Manifests",
+        ViewType::Plugin(name) if name == "Graph" => "Help — Dependency Graph",
         ViewType::Plugin(_) => "Help — Plugin",
         ViewType::Query(_) => "Help — Query",
     }
@@ -219,6 +384,11 @@ fn resource_alias(kind: &ResourceKind) -> String {
         ResourceKind::Namespaces => "NSP".into(),
         ResourceKind::PersistentVolumes => "PVS".into(),
         ResourceKind::PersistentVolumeClaims => "PVC".into(),
+        ResourceKind::NetworkPolicies => "NPL".into(),
+        ResourceKind::ResourceQuotas => "RQT".into(),
+        ResourceKind::LimitRanges => "LMR".into(),
+        ResourceKind::PodDisruptionBudgets => "PDB".into(),
+        ResourceKind::Workloads => "ALL".into(),
         ResourceKind::Custom(name) => {
             let up = name.to_uppercase();
             up.chars().take(3).collect()
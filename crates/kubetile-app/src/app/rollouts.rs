@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+use kubetile_core::resource::format_duration;
+use kubetile_core::rollout::fetch_rollout_progress;
+use kubetile_core::{RolloutProgress, RolloutState};
+use kubetile_tui::pane::ResourceKind;
+use kubetile_tui::widgets::toast::ToastMessage;
+use tokio_util::sync::{CancellationToken, DropGuard};
+
+use crate::event::AppEvent;
+
+use super::reconnect::report_auth_expired;
+use super::set_image::workload_kind;
+use super::App;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A restarted or re-imaged workload whose rollout is polled until it completes, fails or times out.
+/// Dropping it stops the poller.
+struct FollowedRollout {
+    id: u64,
+    kind: ResourceKind,
+    name: String,
+    namespace: String,
+    started: Instant,
+    /// Latest progress; `None` until the first poll answers.
+    progress: Option<RolloutProgress>,
+    _poller: DropGuard,
+}
+
+impl FollowedRollout {
+    fn label(&self) -> String {
+        format!("{} {}/{}", self.kind.short_name(), self.namespace, self.name)
+    }
+}
+
+/// Rollouts followed after a restart or image change, oldest first, with the panel lines refreshed every tick so
+/// the elapsed times stay current.
+#[derive(Default)]
+pub(super) struct Rollouts {
+    entries: Vec<FollowedRollout>,
+    next_id: u64,
+    pub(super) lines: Vec<(String, String)>,
+}
+
+impl Rollouts {
+    /// Tracks a rollout polled by the task `poller` cancels, replacing any earlier follower of it.
+    pub(super) fn push(&mut self, id: u64, kind: ResourceKind, name: String, namespace: String, poller: DropGuard) {
+        self.entries.retain(|r| r.kind != kind || r.name != name || r.namespace != namespace);
+        let started = Instant::now();
+        self.entries.push(FollowedRollout { id, kind, name, namespace, started, progress: None, _poller: poller });
+    }
+
+    fn remove(&mut self, id: u64) -> Option<FollowedRollout> {
+        let index = self.entries.iter().position(|r| r.id == id)?;
+        Some(self.entries.remove(index))
+    }
+}
+
+impl App {
+    /// Starts polling the rollout of a workload whose pod template just changed.
+    pub(super) fn follow_rollout(&mut self, kind: ResourceKind, name: String, namespace: String) {
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else { return };
+        self.rollouts.next_id += 1;
+        let id = self.rollouts.next_id;
+
+        let cancel = CancellationToken::new();
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        let (task_name, task_namespace) = (name.clone(), namespace.clone());
+        let poller = cancel.clone();
+        let core_kind = workload_kind(&kind);
+        tokio::spawn(async move {
+            loop {
+                let result = fetch_rollout_progress(client.clone(), &core_kind, &task_name, &task_namespace)
+                    .await
+                    .inspect_err(|e| report_auth_expired(&app_tx, &auth_context, e.as_ref()))
+                    .map_err(|e| format!("{e:#}"));
+                let done = !matches!(
+                    result,
+                    Ok(RolloutProgress { state: RolloutState::Pending | RolloutState::Progressing, .. })
+                );
+                if app_tx.send(AppEvent::RolloutProgress { id, result }).is_err() || done {
+                    return;
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                    _ = poller.cancelled() => return,
+                }
+            }
+        });
+
+        self.rollouts.push(id, kind, name, namespace, cancel.drop_guard());
+        self.refresh_rollouts();
+    }
+
+    pub(super) fn handle_rollout_progress(&mut self, id: u64, result: Result<RolloutProgress, String>) {
+        let Some(rollout) = self.rollouts.entries.iter_mut().find(|r| r.id == id) else { return };
+        let label = rollout.label();
+        let toast = match result {
+            Ok(progress) if progress.state == RolloutState::Complete => {
+                ToastMessage::success(format!("Rolled out {label}: {}", progress.describe()))
+            }
+            Ok(progress) if progress.state == RolloutState::Failed => ToastMessage::error(format!(
+                "Rollout of {label} exceeded its progress deadline: {}",
+                progress.describe()
+            )),
+            Ok(progress) => {
+                rollout.progress = Some(progress);
+                self.refresh_rollouts();
+                return;
+            }
+            Err(e) => ToastMessage::error(format!("Stopped following the rollout of {label}: {e}")),
+        };
+        self.rollouts.remove(id);
+        self.toasts.push(toast);
+        self.refresh_rollouts();
+    }
+
+    /// Gives up on rollouts older than `general.rollout_timeout_secs` and recomputes the panel lines.
+    pub(super) fn refresh_rollouts(&mut self) {
+        let timeout = Duration::from_secs(self.general_config.rollout_timeout_secs);
+        let now = Instant::now();
+        let timed_out: Vec<u64> = self
+            .rollouts
+            .entries
+            .iter()
+            .filter(|r| now.saturating_duration_since(r.started) >= timeout)
+            .map(|r| r.id)
+            .collect();
+        for id in timed_out {
+            let Some(rollout) = self.rollouts.remove(id) else { continue };
+            let progress = rollout.progress.map(|p| format!(": {}", p.describe())).unwrap_or_default();
+            self.toasts.push(ToastMessage::error(format!(
+                "Rollout of {} not finished after {}{progress}",
+                rollout.label(),
+                format_duration(timeout)
+            )));
+        }
+
+        self.rollouts.lines = self
+            .rollouts
+            .entries
+            .iter()
+            .map(|r| {
+                let status = match &r.progress {
+                    Some(p) if p.state == RolloutState::Progressing => p.describe(),
+                    _ => "waiting for the controller".to_string(),
+                };
+                (r.label(), format!("{status}  {}", format_duration(now.saturating_duration_since(r.started))))
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use kubetile_core::{ScriptHost, ScriptOutput, ScriptResource};
+use kubetile_tui::pane::ViewType;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
+
+use super::App;
+
+/// The row's cells by header, as scripts see them in `resource.cells`.
+pub(super) fn row_cells(headers: &[String], row: &[String]) -> BTreeMap<String, String> {
+    headers.iter().cloned().zip(row.iter().cloned()).collect()
+}
+
+/// Runs `f` on the script host off the event loop, so a slow script cannot stall drawing.
+pub(super) async fn with_scripts<T: Send + 'static>(
+    scripts: Arc<ScriptHost>,
+    f: impl FnOnce(&ScriptHost) -> T + Send + 'static,
+) -> Option<T> {
+    tokio::task::spawn_blocking(move || f(&scripts)).await.ok()
+}
+
+impl App {
+    /// Runs the script action or opens the script pane `name` for the selected resource. Scripts
+    /// may change the cluster, so read-only contexts refuse them.
+    pub(super) fn run_script(&mut self, name: &str) {
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        let Some((kind, resource, namespace)) = self.selected_resource_info() else {
+            return;
+        };
+        let kind_key = super::access::api_resource(&kind).map_or(kind.short_name(), |(_, plural)| plural);
+        if !self.scripts.applies_to(name, kind_key) {
+            self.toasts.push(ToastMessage::info(format!("Script {name} does not apply to {}", kind.display_name())));
+            return;
+        }
+
+        let focused = self.tab_manager.active().focused_pane;
+        let cells = self
+            .panes
+            .get(&focused)
+            .and_then(|p| p.as_any().downcast_ref::<ResourceListPane>())
+            .and_then(|rp| Some(row_cells(&rp.state.headers, rp.state.items.get(rp.selected_item_index()?)?)))
+            .unwrap_or_default();
+        let target = ScriptResource {
+            kind: kind_key.to_string(),
+            name: resource,
+            namespace: if kind.is_namespaced() { namespace } else { String::new() },
+            context: self.kube_client.as_ref().map(|c| c.context().to_string()).unwrap_or_default(),
+            cells,
+        };
+        let (scripts, app_tx, name) = (self.scripts.clone(), self.app_tx.clone(), name.to_string());
+
+        tokio::spawn(async move {
+            let title = format!("{name}: {}", target.name);
+            let run_name = name.clone();
+            let Some(result) = with_scripts(scripts, move |host| host.run(&run_name, &target)).await else { return };
+            let event = match result {
+                Ok(ScriptOutput::Message(Some(message))) => AppEvent::Toast(ToastMessage::success(message)),
+                Ok(ScriptOutput::Message(None)) => AppEvent::Toast(ToastMessage::success(format!("Ran {name}"))),
+                Ok(ScriptOutput::Pane(content)) => {
+                    let view = ViewType::Plugin(format!("script:{name}"));
+                    AppEvent::TextReady { pane_id: focused, view, title, content }
+                }
+                Err(e) => AppEvent::Toast(ToastMessage::error(format!("Script {name} failed: {e}"))),
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+}
@@ -0,0 +1,174 @@
+use kubetile_core::listing::BUILTIN_KINDS;
+use kubetile_core::search::list_names;
+use kubetile_core::{SearchHit, SearchQuery};
+use kubetile_tui::fuzzy::fuzzy_filter;
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::resource_list::ListScope;
+
+use super::App;
+
+/// Hits listed at once; the rest are only counted.
+pub(super) const MAX_SHOWN_HITS: usize = 200;
+
+/// The cluster-wide search popup. Every object in scope is listed once when it opens, then
+/// filtered locally as the user types.
+pub(super) struct GlobalSearch {
+    pub(super) input: String,
+    pub(super) cluster_wide: bool,
+    /// The namespace searched when not cluster-wide.
+    pub(super) namespace: String,
+    pub(super) hits: Vec<SearchHit>,
+    /// Short names of the kinds that could not be listed.
+    pub(super) failed: Vec<String>,
+    pub(super) loading: bool,
+    pub(super) selected: usize,
+    /// Bumped with every listing, so a slower listing of the previous scope is dropped.
+    pub(super) seq: u64,
+}
+
+impl GlobalSearch {
+    pub(super) fn query(&self) -> SearchQuery {
+        SearchQuery::parse(&self.input)
+    }
+
+    /// Hits matching the input, best first.
+    pub(super) fn matches(&self) -> Vec<&SearchHit> {
+        let query = self.query();
+        let in_kinds = self.hits.iter().filter(|hit| query.includes(&hit.kind));
+        fuzzy_filter(&query.name, in_kinds, |hit| hit.name.as_str())
+    }
+
+    /// `namespace prod` or `cluster`.
+    pub(super) fn scope_label(&self) -> String {
+        if self.cluster_wide {
+            "cluster".into()
+        } else {
+            format!("namespace {}", self.namespace)
+        }
+    }
+
+    /// Why the results may be incomplete: kinds that name nothing, or that could not be listed.
+    pub(super) fn notice(&self) -> Option<String> {
+        let query = self.query();
+        if !query.unknown_kinds.is_empty() {
+            return Some(format!("Unknown kind: {}", query.unknown_kinds.join(", ")));
+        }
+        (!self.failed.is_empty()).then(|| format!("Could not list: {}", self.failed.join(", ")))
+    }
+}
+
+impl App {
+    pub(super) fn open_global_search(&mut self) {
+        if self.kube_client.is_none() {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        }
+        let namespace = self.context_resolver.namespace().unwrap_or("default").to_string();
+        self.global_search = Some(GlobalSearch {
+            input: String::new(),
+            cluster_wide: false,
+            namespace,
+            hits: Vec::new(),
+            failed: Vec::new(),
+            loading: true,
+            selected: 0,
+            seq: 0,
+        });
+        self.dispatcher.set_mode(InputMode::GlobalSearch);
+        self.spawn_search_listing();
+    }
+
+    pub(super) fn close_global_search(&mut self) {
+        self.global_search = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    fn spawn_search_listing(&mut self) {
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else { return };
+        let Some(search) = &mut self.global_search else { return };
+        search.seq += 1;
+        search.loading = true;
+        search.hits.clear();
+        search.failed.clear();
+        search.selected = 0;
+
+        let seq = search.seq;
+        let namespace = (!search.cluster_wide).then(|| search.namespace.clone());
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let (hits, failed) = list_names(client, &BUILTIN_KINDS, namespace.as_deref()).await;
+            let _ = app_tx.send(AppEvent::SearchListed { seq, hits, failed });
+        });
+    }
+
+    pub(super) fn handle_search_listed(
+        &mut self,
+        seq: u64,
+        hits: Vec<SearchHit>,
+        failed: Vec<(kubetile_core::ResourceKind, String)>,
+    ) {
+        let Some(search) = self.global_search.as_mut().filter(|s| s.seq == seq) else { return };
+        for (kind, error) in &failed {
+            tracing::warn!("Search could not list {}: {error}", kind.display_name());
+        }
+        search.hits = hits;
+        search.failed = failed.iter().map(|(kind, _)| kind.short_name().to_string()).collect();
+        search.loading = false;
+    }
+
+    pub(super) fn search_input(&mut self, c: char) {
+        if let Some(search) = &mut self.global_search {
+            search.input.push(c);
+            search.selected = 0;
+        }
+    }
+
+    pub(super) fn search_backspace(&mut self) {
+        if let Some(search) = &mut self.global_search {
+            search.input.pop();
+            search.selected = 0;
+        }
+    }
+
+    pub(super) fn search_move(&mut self, forward: bool) {
+        let Some(search) = &mut self.global_search else { return };
+        let len = search.matches().len().min(MAX_SHOWN_HITS);
+        if len == 0 {
+            return;
+        }
+        search.selected = if forward { (search.selected + 1) % len } else { (search.selected + len - 1) % len };
+    }
+
+    /// Switches between the current namespace and the whole cluster.
+    pub(super) fn search_toggle_scope(&mut self) {
+        let Some(search) = &mut self.global_search else { return };
+        search.cluster_wide = !search.cluster_wide;
+        self.spawn_search_listing();
+    }
+
+    /// Opens the selected hit in a new tab: a list of its kind, in its namespace, filtered by its
+    /// name.
+    pub(super) fn confirm_global_search(&mut self) {
+        let Some(search) = self.global_search.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        let Some(hit) = search.matches().get(search.selected).map(|hit| (*hit).clone()) else { return };
+        let Some(kind) = ResourceKind::from_short_name(hit.kind.short_name()) else { return };
+
+        self.sync_active_scope();
+        let tab_id = self.tab_manager.new_tab(kind.display_name(), ViewType::ResourceList(kind.clone()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        let mut pane = self.new_list_pane(kind.clone(), Vec::new());
+        pane.scope = hit.namespace.map(|namespace| ListScope { namespace, label_selector: None });
+        pane.filter_text = hit.name;
+        self.panes.insert(pane_id, Box::new(pane));
+
+        let namespaces = self.watch_namespaces(&kind, false);
+        self.start_watcher_for_pane(pane_id, &kind, &namespaces);
+        self.sync_active_scope();
+        self.update_active_tab_title();
+    }
+}
@@ -0,0 +1,192 @@
+use kubetile_core::ContainerImage;
+use kubetile_tui::pane::ResourceKind;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+
+use super::images::resource_images;
+use super::reconnect::report_auth_expired;
+use super::{App, PendingAction};
+
+/// Image editor for the containers of a Deployment, StatefulSet or DaemonSet, prefilled with their images.
+pub(super) struct SetImageDialog {
+    kind: ResourceKind,
+    name: String,
+    namespace: String,
+    containers: Vec<ContainerImage>,
+    /// `deploy web/api`
+    pub(super) target: String,
+    /// `(container, current image, edited image)` per entry of `containers`.
+    pub(super) rows: Vec<(String, String, String)>,
+    pub(super) selected: usize,
+    pub(super) error: Option<String>,
+}
+
+impl App {
+    /// Reads the containers of the selected Deployment, StatefulSet or DaemonSet, then opens the image editor.
+    pub(super) fn initiate_set_image(&mut self) {
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        if !matches!(kind, ResourceKind::Deployments | ResourceKind::StatefulSets | ResourceKind::DaemonSets) {
+            self.toasts
+                .push(ToastMessage::info("Set image is only available for Deployments, StatefulSets and DaemonSets"));
+            return;
+        }
+        if self.is_read_only_context() {
+            self.notify_read_only();
+            return;
+        }
+        if self.deny_if_forbidden("set_image") {
+            return;
+        }
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let result = resource_images(client, &kind, &name, &namespace)
+                .await
+                .inspect_err(|e| report_auth_expired(&app_tx, &auth_context, e))
+                .map_err(|e| e.to_string());
+            let _ = app_tx.send(AppEvent::SetImageTargetsReady { kind, name, namespace, result });
+        });
+    }
+
+    pub(super) fn open_set_image_dialog(
+        &mut self,
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        result: Result<Vec<ContainerImage>, String>,
+    ) {
+        let containers = match result {
+            Ok(containers) if containers.is_empty() => {
+                self.toasts.push(ToastMessage::info(format!("{name} has no containers")));
+                return;
+            }
+            Ok(containers) => containers,
+            Err(e) => {
+                self.toasts.push(ToastMessage::error(format!("Could not read images of {name}: {e}")));
+                return;
+            }
+        };
+        let rows = containers
+            .iter()
+            .map(|c| {
+                let label = if c.init { format!("{} (init)", c.container) } else { c.container.clone() };
+                (label, c.image.clone(), c.image.clone())
+            })
+            .collect();
+        let target = format!("{} {namespace}/{name}", kind.short_name());
+        // Start on the first regular container rather than an init container.
+        let selected = containers.iter().position(|c| !c.init).unwrap_or(0);
+        self.set_image_dialog =
+            Some(SetImageDialog { kind, name, namespace, containers, target, rows, selected, error: None });
+        self.dispatcher.set_mode(InputMode::SetImageDialog);
+    }
+
+    pub(super) fn close_set_image_dialog(&mut self) {
+        self.set_image_dialog = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    pub(super) fn set_image_move(&mut self, forward: bool) {
+        let Some(dialog) = &mut self.set_image_dialog else { return };
+        let len = dialog.rows.len();
+        dialog.selected = if forward { (dialog.selected + 1) % len } else { (dialog.selected + len - 1) % len };
+    }
+
+    pub(super) fn set_image_input(&mut self, c: char) {
+        let Some(dialog) = &mut self.set_image_dialog else { return };
+        if let Some(row) = dialog.rows.get_mut(dialog.selected) {
+            row.2.push(c);
+            dialog.error = None;
+        }
+    }
+
+    pub(super) fn set_image_backspace(&mut self) {
+        let Some(dialog) = &mut self.set_image_dialog else { return };
+        if let Some(row) = dialog.rows.get_mut(dialog.selected) {
+            row.2.pop();
+            dialog.error = None;
+        }
+    }
+
+    /// Asks before patching the containers whose image was edited.
+    pub(super) fn confirm_set_image(&mut self) {
+        let Some(dialog) = &mut self.set_image_dialog else { return };
+        if let Some((label, _, _)) = dialog.rows.iter().find(|(_, _, edited)| edited.trim().is_empty()) {
+            dialog.error = Some(format!("the image of {label} is empty"));
+            return;
+        }
+        let (images, lines): (Vec<ContainerImage>, Vec<String>) = dialog
+            .containers
+            .iter()
+            .zip(&dialog.rows)
+            .filter(|(_, (_, current, edited))| edited.trim() != current)
+            .map(|(container, (label, current, edited))| {
+                let image = edited.trim().to_string();
+                let line = format!("{label}: {current} → {image}");
+                (ContainerImage { image, ..container.clone() }, line)
+            })
+            .unzip();
+        if images.is_empty() {
+            dialog.error = Some("no image was changed".into());
+            return;
+        }
+
+        let message = format!("Set images of {}?\n\n{}", dialog.target, lines.join("\n"));
+        let Some(dialog) = self.set_image_dialog.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        let action = PendingAction::SetImage {
+            kind: dialog.kind,
+            name: dialog.name.clone(),
+            namespace: dialog.namespace,
+            images,
+        };
+        self.request_confirmation("set_image", &dialog.name, message, action);
+    }
+
+    /// Patches the images, then follows the rollout they start.
+    pub(super) fn run_set_image(
+        &mut self,
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        images: Vec<ContainerImage>,
+    ) {
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let executor = kubetile_core::ActionExecutor::new(client);
+            match executor.set_images(&workload_kind(&kind), &name, &namespace, &images).await {
+                Ok(()) => {
+                    let summary: Vec<String> =
+                        images.iter().map(|i| format!("{} → {}", i.container, i.image)).collect();
+                    let toast = ToastMessage::success(format!("Set image of {name}: {}", summary.join(", ")));
+                    let _ = app_tx.send(AppEvent::Toast(toast));
+                    let _ = app_tx.send(AppEvent::FollowRollout { kind, name, namespace });
+                }
+                Err(e) => {
+                    report_auth_expired(&app_tx, &auth_context, e.as_ref());
+                    let _ = app_tx.send(AppEvent::Toast(ToastMessage::error(format!("Set image failed: {e}"))));
+                }
+            }
+        });
+    }
+}
+
+/// The core kind of a workload whose pod template can be patched and rolled out.
+pub(super) fn workload_kind(kind: &ResourceKind) -> kubetile_core::ResourceKind {
+    match kind {
+        ResourceKind::StatefulSets => kubetile_core::ResourceKind::StatefulSets,
+        ResourceKind::DaemonSets => kubetile_core::ResourceKind::DaemonSets,
+        _ => kubetile_core::ResourceKind::Deployments,
+    }
+}
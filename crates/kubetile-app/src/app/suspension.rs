@@ -0,0 +1,139 @@
+use std::time::{Duration, Instant};
+
+use kubetile_tui::pane::PaneId;
+
+use crate::event::AppEvent;
+use crate::panes::logs_pane::ResumeRequest;
+use crate::panes::{LogsPane, ResourceListPane};
+
+use super::App;
+
+/// Why the watcher or log streams of a pane are stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SuspendReason {
+    /// Pane memory went over `general.memory_budget_mb`.
+    MemoryBudget,
+    /// The pane's tab stayed in the background for `general.pause_background_tabs_secs`.
+    BackgroundTab,
+    /// The cluster rejected the credentials of the pane's context while its tab was in the
+    /// background.
+    AuthExpired,
+}
+
+impl App {
+    /// Suspends the streams of every tab that has not been active for
+    /// `general.pause_background_tabs_secs`.
+    pub(super) fn pause_background_tabs(&mut self) {
+        let active = self.tab_manager.active().id;
+        let now = Instant::now();
+        self.tab_last_active.retain(|id, _| self.tab_manager.tabs().iter().any(|tab| tab.id == *id));
+        self.tab_last_active.insert(active, now);
+        let after = Duration::from_secs(self.general_config.pause_background_tabs_secs);
+        if after.is_zero() {
+            return;
+        }
+        let mut idle = Vec::new();
+        for tab in self.tab_manager.tabs() {
+            let since = *self.tab_last_active.entry(tab.id).or_insert(now);
+            if since.elapsed() >= after {
+                idle.extend(tab.pane_tree.leaf_ids().into_iter().filter(|id| !self.suspended_streams.contains_key(id)));
+            }
+        }
+        self.suspend_streams(idle, SuspendReason::BackgroundTab);
+    }
+
+    /// Suspends the streams of `pane_ids`; returns how many panes had one to stop.
+    pub(super) fn suspend_streams(&mut self, pane_ids: Vec<PaneId>, reason: SuspendReason) -> usize {
+        let mut suspended = 0;
+        for pane_id in pane_ids {
+            if self.suspend_pane_streams(pane_id) {
+                self.suspended_streams.insert(pane_id, reason);
+                suspended += 1;
+            }
+        }
+        suspended
+    }
+
+    /// Whether any pane of the tab has its streams suspended, for the tab bar.
+    pub(super) fn tab_has_suspended_streams(&self, tab_index: usize) -> bool {
+        self.tab_manager
+            .tabs()
+            .get(tab_index)
+            .is_some_and(|tab| tab.pane_tree.leaf_ids().iter().any(|id| self.suspended_streams.contains_key(id)))
+    }
+
+    /// Restarts the streams of suspended panes that are on screen again.
+    pub(super) fn resume_visible_streams(&mut self) {
+        self.suspended_streams.retain(|id, _| self.panes.contains_key(id));
+        if self.suspended_streams.is_empty() {
+            return;
+        }
+        for pane_id in self.tab_manager.active().pane_tree.leaf_ids() {
+            if self.suspended_streams.remove(&pane_id).is_some() {
+                self.resume_pane_streams(pane_id);
+            }
+        }
+    }
+
+    /// Stops the watcher of a list pane or the log streams of a logs pane; returns whether there
+    /// was one to stop.
+    fn suspend_pane_streams(&mut self, pane_id: PaneId) -> bool {
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return false };
+        if let Some(logs) = pane.as_any_mut().downcast_mut::<LogsPane>() {
+            return logs.suspend();
+        }
+        if pane.as_any().downcast_ref::<ResourceListPane>().is_some() {
+            self.volume_stats_pollers.remove(&pane_id);
+            return self.active_watchers.remove(&pane_id).is_some();
+        }
+        false
+    }
+
+    /// Restarts what [`App::suspend_pane_streams`] stopped: a list re-lists, logs pick up from
+    /// when they were suspended.
+    fn resume_pane_streams(&mut self, pane_id: PaneId) {
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return };
+        if let Some(logs) = pane.as_any_mut().downcast_mut::<LogsPane>() {
+            let workload = logs.is_workload();
+            if let Some(request) = logs.resume() {
+                self.restart_log_streams(pane_id, workload, request);
+            }
+            return;
+        }
+        let Some(list) = pane.as_any().downcast_ref::<ResourceListPane>() else { return };
+        let Some(kind) = list.kind().cloned() else { return };
+        let all_namespaces = list.all_namespaces;
+        let namespaces = self.watch_namespaces(&kind, all_namespaces);
+        self.start_watcher_for_pane(pane_id, &kind, &namespaces);
+    }
+
+    fn restart_log_streams(&mut self, pane_id: PaneId, workload: bool, request: ResumeRequest) {
+        let context = self.kube_client.as_ref().map(|c| c.context().to_string());
+        let ssh_host = self.general_config.ssh_host_for(context.as_deref()).map(str::to_string);
+        let source = self.log_source(ssh_host.as_deref());
+        for pod in request.pods {
+            let log_request = kubetile_core::LogRequest {
+                context: context.clone(),
+                ssh_host: ssh_host.clone(),
+                pod_name: pod.clone(),
+                namespace: request.namespace.clone(),
+                container: request.container.clone(),
+                tail_lines: None,
+                since_seconds: Some(request.since_seconds),
+                ..Default::default()
+            };
+            let app_tx = self.app_tx.clone();
+            let source = source.clone();
+            tokio::spawn(async move {
+                if let Ok(stream) = kubetile_core::LogStream::start(source, log_request).await {
+                    let event = if workload {
+                        AppEvent::WorkloadLogStreamReady { pane_id, pod, stream }
+                    } else {
+                        AppEvent::LogsStreamReady { pane_id, stream }
+                    };
+                    let _ = app_tx.send(event);
+                }
+            });
+        }
+    }
+}
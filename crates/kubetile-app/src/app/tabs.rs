@@ -1,6 +1,7 @@
-use kubetile_tui::pane::{PaneId, ResourceKind, ViewType};
+use kubetile_tui::pane::{PaneId, ResourceKind, SplitDirection, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
 
-use crate::panes::{AppLogsPane, PortForwardsPane, ResourceListPane};
+use crate::panes::{AppLogsPane, AuditPane, ExecPane, PortForwardsPane, TrashPane};
 
 use super::{App, TabScope};
 
@@ -11,9 +12,9 @@ impl App {
         let name = format!("Tab {}", tab_count + 1);
         let tab_id = self.tab_manager.new_tab(&name, ViewType::ResourceList(ResourceKind::Pods));
         let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
-        self.panes.insert(pane_id, Box::new(ResourceListPane::new(ResourceKind::Pods, super::pods_headers())));
-        let ns = self.context_resolver.namespace().unwrap_or("default").to_string();
-        self.start_watcher_for_pane(pane_id, &ResourceKind::Pods, &ns);
+        self.panes.insert(pane_id, Box::new(self.new_list_pane(ResourceKind::Pods, super::pods_headers())));
+        let namespaces = self.watch_namespaces(&ResourceKind::Pods, false);
+        self.start_watcher_for_pane(pane_id, &ResourceKind::Pods, &namespaces);
         self.sync_active_scope();
         self.update_active_tab_title();
     }
@@ -32,23 +33,65 @@ impl App {
         if self.tab_manager.close_tab(tab_id) {
             self.tab_scopes.remove(&tab_id);
             for id in pane_ids {
-                self.panes.remove(&id);
+                if let Some(pane) = self.panes.remove(&id) {
+                    self.park_closed_pane(id, pane);
+                }
+                self.query_sessions.remove(&id);
                 self.active_watchers.remove(&id);
+                self.volume_stats_pollers.remove(&id);
                 self.watcher_seq_by_pane.remove(&id);
+                self.plugin_column_cache.remove(&id);
+                self.list_view_states.retain(|(pane_id, _), _| *pane_id != id);
             }
             self.load_active_scope();
             self.update_active_tab_title();
         }
     }
 
+    /// Moves the focused pane into a new tab. The pane keeps its ID, so its watcher, log stream or
+    /// PTY session carries on untouched.
+    pub(super) fn move_pane_to_new_tab(&mut self) {
+        self.sync_active_scope();
+        let focused = self.tab_manager.active().focused_pane;
+        let name = format!("Tab {}", self.tab_manager.tabs().len() + 1);
+        if self.tab_manager.move_pane_to_new_tab(focused, &name).is_none() {
+            self.toasts.push(ToastMessage::info("Pane is already alone in its tab"));
+            return;
+        }
+        self.sync_exec_pane_input(focused);
+        self.sync_active_scope();
+        self.update_active_tab_title();
+    }
+
+    /// Merges the active single-pane tab into the previous tab, split next to its focused pane.
+    pub(super) fn merge_tab_into_previous(&mut self) {
+        let tab_id = self.tab_manager.active().id;
+        let Some(pane_id) = self.tab_manager.merge_into_previous_tab(SplitDirection::Vertical) else {
+            self.toasts.push(ToastMessage::info("Only a single-pane tab after the first can be merged"));
+            return;
+        };
+        self.tab_scopes.remove(&tab_id);
+        self.sync_exec_pane_input(pane_id);
+        self.load_active_scope();
+        self.update_active_tab_title();
+    }
+
+    /// Matches a moved exec pane's synchronized input to the tab it landed in.
+    fn sync_exec_pane_input(&mut self, pane_id: PaneId) {
+        let on = self.tab_manager.active().sync_input;
+        if let Some(ep) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<ExecPane>()) {
+            ep.set_synchronized(on);
+        }
+    }
+
     pub(super) fn toggle_app_logs_tab(&mut self) {
         let active_tab_id = self.tab_manager.active().id;
-        if self.is_app_logs_tab(active_tab_id) {
+        if self.is_plugin_tab(active_tab_id, "AppLogs") {
             self.close_tab();
             return;
         }
 
-        if let Some(idx) = self.find_app_logs_tab_index() {
+        if let Some(idx) = self.find_plugin_tab_index("AppLogs") {
             self.switch_to_tab_index(idx);
             return;
         }
@@ -63,12 +106,12 @@ impl App {
 
     pub(super) fn toggle_port_forwards_tab(&mut self) {
         let active_tab_id = self.tab_manager.active().id;
-        if self.is_port_forwards_tab(active_tab_id) {
+        if self.is_plugin_tab(active_tab_id, "PortForwards") {
             self.close_tab();
             return;
         }
 
-        if let Some(idx) = self.find_port_forwards_tab_index() {
+        if let Some(idx) = self.find_plugin_tab_index("PortForwards") {
             self.switch_to_tab_index(idx);
             return;
         }
@@ -82,50 +125,82 @@ impl App {
         self.update_active_tab_title();
     }
 
-    fn is_app_logs_tab(&self, tab_id: u32) -> bool {
-        let Some(tab) = self.tab_manager.tabs().iter().find(|t| t.id == tab_id) else {
-            return false;
-        };
-        tab.pane_tree.leaf_ids().iter().all(|pane_id| {
-            self.panes
-                .get(pane_id)
-                .is_some_and(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == "AppLogs"))
-        })
+    pub(super) fn toggle_audit_tab(&mut self) {
+        let active_tab_id = self.tab_manager.active().id;
+        if self.is_plugin_tab(active_tab_id, "Audit") {
+            self.close_tab();
+            return;
+        }
+
+        if let Some(idx) = self.find_plugin_tab_index("Audit") {
+            self.switch_to_tab_index(idx);
+            return;
+        }
+
+        self.sync_active_scope();
+        let tab_id = self.tab_manager.new_tab("Audit Log", ViewType::Plugin("Audit".into()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        let mut pane = AuditPane::new();
+        pane.poll(&self.display_timezone);
+        self.panes.insert(pane_id, Box::new(pane));
+        self.sync_active_scope();
+        self.update_active_tab_title();
     }
 
-    fn find_app_logs_tab_index(&self) -> Option<usize> {
-        self.tab_manager.tabs().iter().position(|tab| self.is_app_logs_tab(tab.id))
+    pub(super) fn toggle_trash_tab(&mut self) {
+        let active_tab_id = self.tab_manager.active().id;
+        if self.is_plugin_tab(active_tab_id, "Trash") {
+            self.close_tab();
+            return;
+        }
+
+        if let Some(idx) = self.find_plugin_tab_index("Trash") {
+            self.switch_to_tab_index(idx);
+            return;
+        }
+
+        self.sync_active_scope();
+        let tab_id = self.tab_manager.new_tab("Trash", ViewType::Plugin("Trash".into()));
+        let pane_id = self.tab_manager.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
+        self.panes.insert(pane_id, Box::new(TrashPane::new()));
+        self.refresh_trash_panes();
+        self.sync_active_scope();
+        self.update_active_tab_title();
     }
 
-    fn is_port_forwards_tab(&self, tab_id: u32) -> bool {
+    pub(super) fn is_plugin_tab(&self, tab_id: u32, plugin: &str) -> bool {
         let Some(tab) = self.tab_manager.tabs().iter().find(|t| t.id == tab_id) else {
             return false;
         };
         tab.pane_tree.leaf_ids().iter().all(|pane_id| {
-            self.panes
-                .get(pane_id)
-                .is_some_and(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == "PortForwards"))
+            self.panes.get(pane_id).is_some_and(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == plugin))
         })
     }
 
-    fn find_port_forwards_tab_index(&self) -> Option<usize> {
-        self.tab_manager.tabs().iter().position(|tab| self.is_port_forwards_tab(tab.id))
+    pub(super) fn find_plugin_tab_index(&self, plugin: &str) -> Option<usize> {
+        self.tab_manager.tabs().iter().position(|tab| self.is_plugin_tab(tab.id, plugin))
     }
 
     fn reset_last_tab_to_pods(&mut self, old_tab_id: u32, old_pane_ids: Vec<PaneId>) {
-        let ns = self.context_resolver.namespace().unwrap_or("default").to_string();
+        let namespaces = self.watch_namespaces(&ResourceKind::Pods, false);
         let old_scope = self.tab_scopes.get(&old_tab_id).cloned();
 
         let new_tab_id = self.tab_manager.new_tab("Main", ViewType::ResourceList(ResourceKind::Pods));
         let new_pane_id = self.tab_manager.tabs().iter().find(|t| t.id == new_tab_id).unwrap().focused_pane;
-        self.panes.insert(new_pane_id, Box::new(ResourceListPane::new(ResourceKind::Pods, super::pods_headers())));
-        self.start_watcher_for_pane(new_pane_id, &ResourceKind::Pods, &ns);
+        self.panes.insert(new_pane_id, Box::new(self.new_list_pane(ResourceKind::Pods, super::pods_headers())));
+        self.start_watcher_for_pane(new_pane_id, &ResourceKind::Pods, &namespaces);
 
         let _ = self.tab_manager.close_tab(old_tab_id);
         for id in old_pane_ids {
-            self.panes.remove(&id);
+            if let Some(pane) = self.panes.remove(&id) {
+                self.park_closed_pane(id, pane);
+            }
+            self.query_sessions.remove(&id);
             self.active_watchers.remove(&id);
+            self.volume_stats_pollers.remove(&id);
             self.watcher_seq_by_pane.remove(&id);
+            self.plugin_column_cache.remove(&id);
+            self.list_view_states.retain(|(pane_id, _), _| *pane_id != id);
         }
 
         self.tab_scopes.remove(&old_tab_id);
@@ -168,6 +243,7 @@ impl App {
                 namespaces: self.namespaces.clone(),
                 namespace_filter: self.namespace_filter.clone(),
                 namespace_selected: self.namespace_selected,
+                namespace_marked: self.namespace_marked.clone(),
                 context_filter: self.context_filter.clone(),
                 context_selected: self.context_selected,
             },
@@ -183,10 +259,13 @@ impl App {
             self.namespaces = scope.namespaces;
             self.namespace_filter = scope.namespace_filter;
             self.namespace_selected = scope.namespace_selected;
+            self.namespace_marked = scope.namespace_marked;
             self.context_filter = scope.context_filter;
             self.context_selected = scope.context_selected;
+            self.apply_context_display();
         } else {
             self.sync_active_scope();
         }
+        self.resume_visible_streams();
     }
 }
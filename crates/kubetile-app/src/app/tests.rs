@@ -1,13 +1,14 @@
 use super::*;
 use crossterm::event::{KeyCode, KeyEvent};
 use kubetile_core::resource::DetailSection;
-use kubetile_tui::pane::{PaneCommand, PaneTree, SplitDirection};
+use kubetile_tui::pane::{Direction, PaneCommand, PaneTree, SplitDirection};
 use tokio_util::sync::CancellationToken;
 
 use crate::{
     command::InputMode,
+    event::ContainerPurpose,
     keybindings::KeybindingDispatcher,
-    panes::{HelpPane, ResourceDetailPane, YamlPane},
+    panes::{HelpPane, HelpSection, ResourceDetailPane, YamlPane},
 };
 
 fn test_dispatcher() -> KeybindingDispatcher {
@@ -117,14 +118,8 @@ fn focus_cycling_wraps_around() {
 #[test]
 fn help_pane_view_type_is_help() {
     let d = test_dispatcher();
-    let help = HelpPane::new(
-        d.global_shortcuts(),
-        d.navigation_shortcuts(),
-        d.browse_shortcuts(),
-        d.tui_shortcuts(),
-        d.interact_shortcuts(),
-        d.mutate_shortcuts(),
-    );
+    let help =
+        HelpPane::new(vec![HelpSection { title: "Global".into(), shortcuts: d.global_shortcuts(), current: true }]);
 
     let help_ref = help.as_any().downcast_ref::<HelpPane>().unwrap();
     assert_eq!(help_ref.view_type(), &ViewType::Help);
@@ -139,7 +134,7 @@ fn tab_manager_new_tab_creates_pane() {
 
     let tab_id = tm.new_tab("Second", ViewType::Empty);
     let new_pane_id = tm.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
-    panes.insert(new_pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(new_pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     assert_eq!(tm.tabs().len(), 2);
     assert_eq!(tm.active_index(), 1);
@@ -153,7 +148,7 @@ fn tab_manager_close_tab_cleans_up_panes() {
 
     let tab_id = tm.new_tab("Temp", ViewType::Empty);
     let pane_id = tm.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
-    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     let pane_ids: Vec<PaneId> = tm.active().pane_tree.leaf_ids();
     assert!(tm.close_tab(tab_id));
@@ -171,7 +166,7 @@ fn tab_manager_split_uses_global_ids() {
     let (mut panes, mut tm) = make_test_tab_manager();
 
     let new_id = tm.split_pane(1, SplitDirection::Vertical, ViewType::Empty).unwrap();
-    panes.insert(new_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(new_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     assert_eq!(tm.active().pane_tree.leaf_ids().len(), 2);
     assert!(panes.contains_key(&new_id));
@@ -183,12 +178,12 @@ fn tab_switch_preserves_focus() {
     let (mut panes, mut tm) = make_test_tab_manager();
 
     let new_id = tm.split_pane(1, SplitDirection::Vertical, ViewType::Empty).unwrap();
-    panes.insert(new_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(new_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
     tm.active_mut().focused_pane = new_id;
 
     let tab_id = tm.new_tab("Second", ViewType::Empty);
     let pane_id = tm.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
-    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     tm.switch_tab(0);
     assert_eq!(tm.active().focused_pane, new_id);
@@ -205,7 +200,7 @@ fn fullscreen_is_per_tab() {
 
     let tab_id = tm.new_tab("Second", ViewType::Empty);
     let pane_id = tm.tabs().iter().find(|t| t.id == tab_id).unwrap().focused_pane;
-    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     assert!(tm.active().fullscreen_pane.is_none());
 
@@ -213,6 +208,35 @@ fn fullscreen_is_per_tab() {
     assert_eq!(tm.active().fullscreen_pane, Some(1));
 }
 
+#[tokio::test]
+async fn zoom_follows_focus_but_fullscreen_stays_pinned() {
+    let mut app = App::new(
+        test_dispatcher(),
+        kubetile_tui::theme::Theme::default(),
+        kubetile_config::ViewsConfig::default(),
+        kubetile_config::GeneralConfig::default(),
+        kubetile_config::FeatureFlags::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .await;
+    let first = app.tab_manager.active().focused_pane;
+    let second = app.tab_manager.split_pane(first, SplitDirection::Vertical, ViewType::Empty).unwrap();
+    app.panes.insert(second, Box::new(EmptyPane(ViewType::Empty, String::new())));
+
+    app.handle_command(Command::ToggleZoom);
+    app.handle_command(Command::FocusDirection(Direction::Right));
+    assert_eq!(app.tab_manager.active().focused_pane, second);
+    assert_eq!(app.tab_manager.active().fullscreen_pane, Some(second));
+    app.handle_command(Command::ToggleZoom);
+    assert!(app.tab_manager.active().fullscreen_pane.is_none());
+
+    app.handle_command(Command::ToggleFullscreen);
+    app.handle_command(Command::FocusNextPane);
+    assert_eq!(app.tab_manager.active().focused_pane, first);
+    assert_eq!(app.tab_manager.active().fullscreen_pane, Some(second));
+}
+
 #[test]
 fn mode_switch_updates_dispatcher() {
     let mut d = KeybindingDispatcher::from_config(&kubetile_config::Config::load().keybindings);
@@ -229,7 +253,7 @@ fn render_context_reflects_active_tab() {
 
     tm.new_tab("Second", ViewType::Empty);
     let pane_id = tm.active().focused_pane;
-    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     let tab_names = tm.tab_names();
     assert_eq!(tab_names, vec!["Main", "Second"]);
@@ -242,7 +266,7 @@ fn goto_tab_uses_one_based_index() {
 
     tm.new_tab("Second", ViewType::Empty);
     let pane_id = tm.active().focused_pane;
-    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty)));
+    panes.insert(pane_id, Box::new(EmptyPane(ViewType::Empty, String::new())));
 
     // GoToTab(1) should switch to index 0 (first tab)
     let n: usize = 1;
@@ -415,10 +439,10 @@ fn back_on_yaml_pane_closes_it() {
 
 #[test]
 fn deny_action_clears_confirmation() {
-    let confirmation = Some(PendingConfirmation {
-        message: "Delete pod pod-a?".into(),
-        action: PendingAction::Delete { kind: ResourceKind::Pods, name: "pod-a".into(), namespace: "default".into() },
-    });
+    let confirmation = Some(PendingConfirmation::new(
+        "Delete pod pod-a?".into(),
+        PendingAction::Delete { kind: ResourceKind::Pods, name: "pod-a".into(), namespace: "default".into() },
+    ));
     let switcher: Option<ResourceSwitcher> = Some(ResourceSwitcher::new());
     let mut dispatcher = test_dispatcher();
     dispatcher.set_mode(InputMode::ConfirmDialog);
@@ -614,9 +638,16 @@ fn insert_mode_hints_contain_esc() {
 #[tokio::test]
 async fn enter_insert_mode_is_gated_by_focused_pane_type() {
     let dispatcher = test_dispatcher();
-    let mut app =
-        App::new(50, dispatcher, kubetile_tui::theme::Theme::default(), kubetile_config::ViewsConfig::default(), true)
-            .await;
+    let mut app = App::new(
+        dispatcher,
+        kubetile_tui::theme::Theme::default(),
+        kubetile_config::ViewsConfig::default(),
+        kubetile_config::GeneralConfig::default(),
+        kubetile_config::FeatureFlags::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .await;
     app.dispatcher.set_mode(InputMode::Normal);
 
     app.handle_command(Command::EnterMode(InputMode::Insert));
@@ -635,9 +666,16 @@ async fn enter_insert_mode_is_gated_by_focused_pane_type() {
 #[tokio::test]
 async fn exec_spawns_kubectl_and_enters_insert_mode() {
     let dispatcher = test_dispatcher();
-    let mut app =
-        App::new(50, dispatcher, kubetile_tui::theme::Theme::default(), kubetile_config::ViewsConfig::default(), true)
-            .await;
+    let mut app = App::new(
+        dispatcher,
+        kubetile_tui::theme::Theme::default(),
+        kubetile_config::ViewsConfig::default(),
+        kubetile_config::GeneralConfig::default(),
+        kubetile_config::FeatureFlags::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .await;
     app.dispatcher.set_mode(InputMode::Normal);
 
     app.with_pods_pane(|pane| {
@@ -650,3 +688,1457 @@ async fn exec_spawns_kubectl_and_enters_insert_mode() {
 
     assert_eq!(app.dispatcher.mode(), InputMode::Insert);
 }
+
+async fn app_with_selected_pod(general_config: kubetile_config::GeneralConfig) -> App {
+    let dispatcher = test_dispatcher();
+    let mut app = App::new(
+        dispatcher,
+        kubetile_tui::theme::Theme::default(),
+        kubetile_config::ViewsConfig::default(),
+        general_config,
+        kubetile_config::FeatureFlags::default(),
+        Default::default(),
+        Default::default(),
+    )
+    .await;
+    app.namespace_memory = kubetile_core::NamespaceMemory::default();
+    app.container_memory = kubetile_core::ContainerMemory::default();
+    app.resource_usage = kubetile_core::ResourceUsage::default();
+    app.dispatcher.set_mode(InputMode::Normal);
+    app.with_pods_pane(|pane| {
+        pane.state.headers = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()];
+        pane.state.set_items(vec![vec!["pod-a".into(), "default".into(), "Running".into()]]);
+        pane.refresh_filter_and_sort();
+    });
+    app
+}
+
+#[tokio::test]
+async fn log_tee_writes_to_the_path_typed_into_its_prompt() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes.insert(focused, Box::new(crate::panes::LogsPane::new("pod-a".into(), "default".into())));
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("pod-a.log");
+
+    app.handle_command(Command::ToggleLogTee);
+    assert_eq!(app.dispatcher.mode(), InputMode::LogTeePath);
+    app.with_tee_prompt(|logs| {
+        logs.close_tee_prompt();
+        logs.open_tee_prompt(String::new());
+    });
+    for c in path.display().to_string().chars() {
+        app.handle_command(Command::LogTeePathInput(c));
+    }
+    app.handle_command(Command::LogTeePathConfirm);
+
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    let logs = app.panes[&focused].as_any().downcast_ref::<crate::panes::LogsPane>().unwrap();
+    assert_eq!(logs.tee_path(), Some(path.as_path()));
+    assert!(path.exists());
+
+    app.handle_command(Command::ToggleLogTee);
+    let logs = app.panes[&focused].as_any().downcast_ref::<crate::panes::LogsPane>().unwrap();
+    assert_eq!(logs.tee_path(), None);
+}
+
+#[tokio::test]
+async fn delete_prompts_by_default() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+
+    app.handle_command(Command::DeleteResource);
+
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| pc.type_to_confirm.is_none()));
+}
+
+#[tokio::test]
+async fn enter_cancels_unless_confirm_is_focused() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.handle_command(Command::DeleteResource);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| !pc.confirm_focused));
+    app.handle_command(Command::ConfirmSubmit);
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+
+    let mut general = kubetile_config::GeneralConfig::default();
+    general.confirm.default_button = kubetile_config::ConfirmButton::Confirm;
+    let mut app = app_with_selected_pod(general).await;
+    app.handle_command(Command::DeleteResource);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| pc.confirm_focused));
+    app.handle_command(Command::ConfirmToggleFocus);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| !pc.confirm_focused));
+}
+
+#[tokio::test]
+async fn destructive_prompts_cancel_after_the_countdown() {
+    let mut general = kubetile_config::GeneralConfig::default();
+    general.confirm.auto_cancel_secs = 5;
+    let mut app = app_with_selected_pod(general).await;
+
+    app.handle_command(Command::DeleteResource);
+    let deadline = app.pending_confirmation.as_ref().and_then(|pc| pc.deadline);
+    assert!(deadline.is_some_and(|d| d > Instant::now() + Duration::from_secs(4)));
+    app.handle_event(AppEvent::Tick);
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+
+    app.pending_confirmation.as_mut().unwrap().deadline = Some(Instant::now());
+    app.handle_event(AppEvent::Tick);
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert!(app.toasts.iter().any(|t| t.text.contains("timed out")));
+
+    let save = PendingAction::SaveLogs { path: "pod-a.log".into(), content: String::new() };
+    app.open_confirmation(PendingConfirmation::new("Save logs?".into(), save), InputMode::ConfirmDialog);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| pc.deadline.is_none()));
+}
+
+#[tokio::test]
+async fn prompts_do_not_block_browsing_but_refuse_a_moved_selection() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.with_pods_pane(|pane| {
+        pane.state.set_items(vec![
+            vec!["pod-a".into(), "default".into(), "Running".into()],
+            vec!["pod-b".into(), "default".into(), "Running".into()],
+        ]);
+        pane.refresh_filter_and_sort();
+    });
+    let press = |code| AppEvent::Key(KeyEvent::new(code, crossterm::event::KeyModifiers::NONE));
+    app.open_confirmation(PendingConfirmation::from_command(Command::RestartRollout), InputMode::ConfirmDialog);
+
+    app.handle_event(press(KeyCode::Char('j')));
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    assert_eq!(app.selected_resource_info().map(|(_, name, _)| name).as_deref(), Some("pod-b"));
+
+    app.handle_event(press(KeyCode::Char('y')));
+    assert!(app.pending_confirmation.is_none());
+    assert!(app.toasts.iter().any(|t| t.text.contains("selection changed")));
+}
+
+#[tokio::test]
+async fn delete_without_confirmation_runs_immediately() {
+    let mut general = kubetile_config::GeneralConfig::default();
+    general.confirm.delete = kubetile_config::ConfirmMode::Off;
+    let mut app = app_with_selected_pod(general).await;
+
+    app.handle_command(Command::DeleteResource);
+
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert!(app.pending_confirmation.is_none());
+}
+
+#[tokio::test]
+async fn evict_prompts_and_force_delete_requires_typed_name() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+
+    app.handle_command(Command::EvictPod);
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    assert!(matches!(
+        app.pending_confirmation.as_ref().map(|pc| &pc.action),
+        Some(PendingAction::EvictPod { name, .. }) if name == "pod-a"
+    ));
+
+    app.handle_command(Command::DenyAction);
+    app.handle_command(Command::ForceDeletePod);
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmTypeName);
+    assert!(matches!(
+        app.pending_confirmation.as_ref().map(|pc| &pc.action),
+        Some(PendingAction::ForceDeletePod { name, .. }) if name == "pod-a"
+    ));
+}
+
+#[tokio::test]
+async fn single_container_pod_opens_logs_without_picker() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+
+    app.handle_containers_resolved(
+        ContainerPurpose::Logs,
+        None,
+        "pod-a".into(),
+        "default".into(),
+        "Pod/pod-a".into(),
+        vec!["app".into()],
+    );
+
+    assert!(app.container_picker.is_none());
+    let focused = app.tab_manager.active().focused_pane;
+    assert!(matches!(app.panes[&focused].view_type(), ViewType::Logs(name) if name == "pod-a"));
+}
+
+#[tokio::test]
+async fn multi_container_pod_opens_picker() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+
+    app.handle_containers_resolved(
+        ContainerPurpose::Exec,
+        None,
+        "pod-a".into(),
+        "default".into(),
+        "Deployment/kubetile-picker-test".into(),
+        vec!["app".into(), "sidecar".into()],
+    );
+    assert_eq!(app.dispatcher.mode(), InputMode::ContainerPicker);
+    assert_eq!(app.container_picker.as_ref().map(|p| p.selected), Some(0));
+
+    app.handle_command(Command::ContainerPickerPrev);
+    assert_eq!(app.container_picker.as_ref().map(|p| p.selected), Some(1));
+
+    app.handle_command(Command::ContainerPickerCancel);
+    assert!(app.container_picker.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+}
+
+#[tokio::test]
+async fn related_jump_scopes_the_list_or_asks_first() {
+    use kubetile_core::Related;
+
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let focused = app.tab_manager.active().focused_pane;
+    let list = |app: &App| {
+        let rp = app.panes[&focused].as_any().downcast_ref::<ResourceListPane>().unwrap();
+        (rp.kind().cloned(), rp.scope.clone(), rp.filter_text.clone())
+    };
+
+    app.handle_related_resolved(focused, "deploy/api".into(), "web".into(), vec![Related::Pods("app=api".into())]);
+    let scope =
+        crate::panes::resource_list::ListScope { namespace: "web".into(), label_selector: Some("app=api".into()) };
+    assert_eq!(list(&app), (Some(ResourceKind::Pods), Some(scope), String::new()));
+
+    let related =
+        vec![Related::Owner { kind: "Deployment".into(), name: "api".into() }, Related::Node("worker-1".into())];
+    app.handle_related_resolved(focused, "po/api-1".into(), "web".into(), related);
+    assert_eq!(app.dispatcher.mode(), InputMode::RelatedPicker);
+    app.handle_command(Command::RelatedPickerNext);
+    app.handle_command(Command::RelatedPickerConfirm);
+    assert!(app.related_picker.is_none());
+    assert_eq!(list(&app), (Some(ResourceKind::Nodes), None, "worker-1".into()));
+}
+
+#[tokio::test]
+async fn undo_lists_recent_deletes_and_asks_before_recreating() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let context = app.context_resolver.context_name().unwrap_or_default().to_string();
+    app.handle_command(Command::OpenUndoList);
+    assert!(app.undo_list.is_none());
+
+    let deleted = |app: &mut App, kind, name: &str| {
+        app.handle_resource_deleted(kind, name.into(), "web".into(), context.clone(), Some("kind: Whatever".into()))
+    };
+    deleted(&mut app, ResourceKind::ConfigMaps, "settings");
+    deleted(&mut app, ResourceKind::Deployments, "api");
+    app.handle_command(Command::OpenUndoList);
+    assert_eq!(app.dispatcher.mode(), InputMode::UndoList);
+    let labels = &app.undo_list.as_ref().unwrap().labels;
+    assert_eq!(labels[0], "deploy web/api  deleted 0s ago, 10m left");
+    assert!(labels[1].starts_with("cm web/settings"));
+
+    app.handle_command(Command::UndoListNext);
+    app.handle_command(Command::UndoListConfirm);
+    assert!(app.undo_list.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    let pc = app.pending_confirmation.as_ref().unwrap();
+    assert!(pc.message.starts_with("Recreate cm web/settings"));
+    assert!(matches!(pc.action, PendingAction::UndoDelete { id: 1 }));
+}
+
+#[tokio::test]
+async fn detached_exec_sessions_keep_their_output_and_reattach() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.handle_command(Command::OpenExecSessions);
+    assert_eq!(app.toasts.history().last().unwrap().text, "No detached exec sessions");
+
+    let exec = |pod: &str| Box::new(crate::panes::ExecPane::new(pod.into(), "auto".into(), "web".into()));
+    for (route, pod) in [(90, "api-0"), (91, "db-0")] {
+        app.detached_execs.push(exec_sessions::DetachedExec {
+            route,
+            pane: exec(pod),
+            label: format!("{pod}/auto @ web"),
+            detached_at: Instant::now(),
+        });
+    }
+    app.handle_event(AppEvent::PtyOutput { pane_id: 91, data: b"line\r\n".repeat(100) });
+    app.handle_event(AppEvent::ExecExited { pane_id: 90 });
+    assert_eq!(app.toasts.history().last().unwrap().text, "Detached exec session api-0/auto @ web exited");
+
+    app.handle_command(Command::OpenExecSessions);
+    assert_eq!(app.dispatcher.mode(), InputMode::ExecSessions);
+    assert_eq!(app.exec_session_picker.as_ref().unwrap().labels, vec!["db-0/auto @ web  detached 0s ago"]);
+    app.handle_command(Command::ExecSessionsAttach);
+
+    assert!(app.detached_execs.is_empty());
+    assert_eq!(app.dispatcher.mode(), InputMode::Insert);
+    let focused = app.tab_manager.active().focused_pane;
+    let pane = app.panes.get(&focused).unwrap();
+    assert_eq!(pane.view_type(), &ViewType::Exec("db-0".into()));
+    assert!(pane.memory_usage() > exec("db-0").memory_usage(), "output sent while detached was kept");
+
+    // A pane with no running shell is closed as before.
+    app.close_pane(focused);
+    assert!(app.detached_execs.is_empty());
+}
+
+#[tokio::test]
+async fn failed_native_exec_closes_its_pane() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let anchor = app.tab_manager.active().focused_pane;
+    let id = app.split_auto_opened(anchor, ViewType::Exec("pod-a".into()), Default::default(), 0.5).unwrap();
+    app.panes.insert(id, Box::new(crate::panes::ExecPane::new("pod-a".into(), "auto".into(), "default".into())));
+    app.set_focus(id);
+    app.dispatcher.set_mode(InputMode::Insert);
+
+    app.handle_event(AppEvent::ExecConnected { pane_id: id, result: Err("pods \"pod-a\" is forbidden".into()) });
+    assert!(!app.panes.contains_key(&id));
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert_eq!(app.toasts.history().last().unwrap().text, "Failed to start exec: pods \"pod-a\" is forbidden");
+}
+
+#[tokio::test]
+async fn undo_is_off_with_a_zero_window_and_stays_in_its_context() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.handle_resource_deleted(
+        ResourceKind::Pods,
+        "api-1".into(),
+        "web".into(),
+        "staging".into(),
+        Some(String::new()),
+    );
+    app.handle_command(Command::UndoDelete);
+    assert!(app.pending_confirmation.is_none());
+    assert!(app.toasts.history().last().unwrap().text.contains("deleted in context staging"));
+
+    let general = kubetile_config::GeneralConfig { undo_window_secs: 0, ..Default::default() };
+    let mut app = app_with_selected_pod(general).await;
+    app.handle_resource_deleted(ResourceKind::Pods, "api-1".into(), "web".into(), String::new(), Some(String::new()));
+    app.handle_command(Command::UndoDelete);
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.toasts.history().last().unwrap().text, "Nothing to undo");
+}
+
+#[tokio::test]
+async fn trash_pane_lists_session_deletes_and_purges_them() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.handle_resource_deleted(
+        ResourceKind::ConfigMaps,
+        "settings".into(),
+        "web".into(),
+        String::new(),
+        Some("kind: ConfigMap".into()),
+    );
+    app.handle_command(Command::ToggleTrashTab);
+    assert!(app.trash_focused());
+
+    app.handle_command(Command::ViewYaml);
+    let focused = app.tab_manager.active().focused_pane;
+    assert!(
+        matches!(app.panes[&focused].view_type(), ViewType::Yaml(ResourceKind::ConfigMaps, name) if name == "settings")
+    );
+    app.close_focused();
+    assert!(app.trash_focused());
+
+    app.handle_command(Command::DeleteResource);
+    assert!(app.trash.get(1).is_none());
+    assert!(app.pending_confirmation.is_none());
+}
+
+#[tokio::test]
+async fn deletes_without_a_manifest_are_listed_but_not_undoable() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let context = app.context_resolver.context_name().unwrap_or_default().to_string();
+    app.handle_resource_deleted(ResourceKind::PersistentVolumes, "data-1".into(), String::new(), context, None);
+    app.handle_command(Command::UndoDelete);
+    assert_eq!(app.toasts.history().last().unwrap().text, "Nothing to undo");
+
+    app.handle_command(Command::ToggleTrashTab);
+    app.restore_selected_trash();
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.toasts.history().last().unwrap().text, "No manifest was kept for pv data-1");
+    assert!(app.trash.get(1).is_some());
+}
+
+#[tokio::test]
+async fn type_name_confirmation_requires_exact_name() {
+    let mut general = kubetile_config::GeneralConfig::default();
+    general.confirm.delete = kubetile_config::ConfirmMode::TypeName;
+    let mut app = app_with_selected_pod(general).await;
+
+    app.handle_command(Command::DeleteResource);
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmTypeName);
+
+    for c in "pod-b".chars() {
+        app.handle_command(Command::ConfirmInput(c));
+    }
+    app.handle_command(Command::ConfirmAction);
+    assert!(app.pending_confirmation.is_some(), "mismatched name must not confirm");
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmTypeName);
+
+    app.handle_command(Command::ConfirmBackspace);
+    app.handle_command(Command::ConfirmInput('a'));
+    app.handle_command(Command::ConfirmAction);
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+}
+
+#[tokio::test]
+async fn row_refresh_patches_only_the_matching_row() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.views_config.pods.columns.clear();
+    let pods_pane_id = app.pods_pane_id;
+    app.with_pods_pane(|pane| {
+        pane.state.set_items(vec![
+            vec!["pod-a".into(), "default".into(), "Pending".into()],
+            vec!["pod-b".into(), "default".into(), "Running".into()],
+        ]);
+        pane.refresh_filter_and_sort();
+    });
+
+    let headers = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()];
+    let row = vec!["pod-a".into(), "default".into(), "Running".into()];
+    app.handle_row_refresh(pods_pane_id, "pod-a", "default", headers, Some(row));
+
+    app.with_pods_pane(|pane| {
+        assert_eq!(pane.state.items[0][2], "Running");
+        assert_eq!(pane.state.items[1][0], "pod-b");
+    });
+}
+
+#[tokio::test]
+async fn row_refresh_drops_deleted_object() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let pods_pane_id = app.pods_pane_id;
+
+    app.handle_row_refresh(pods_pane_id, "pod-a", "default", Vec::new(), None);
+
+    app.with_pods_pane(|pane| assert!(pane.state.items.is_empty()));
+}
+
+#[tokio::test]
+async fn read_only_context_blocks_mutations() {
+    let general = kubetile_config::GeneralConfig { read_only_contexts: vec!["prod-*".into()], ..Default::default() };
+    let mut app = app_with_selected_pod(general).await;
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "prod-eu".into(), namespace: "default".into() });
+    let toasts_before = app.toasts.len();
+
+    app.handle_command(Command::DeleteResource);
+
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert_eq!(app.toasts.len(), toasts_before + 1);
+    assert!(app.build_render_context().0.read_only);
+
+    let panes_before = app.panes.len();
+    app.handle_command(Command::RunPlugin("stern".into()));
+    app.handle_command(Command::RunScript("restart-all".into()));
+    assert_eq!(app.panes.len(), panes_before);
+    assert_eq!(app.toasts.len(), toasts_before + 3);
+}
+
+#[tokio::test]
+async fn display_timezone_follows_the_context() {
+    let mut general = kubetile_config::GeneralConfig { timezone: "UTC".into(), ..Default::default() };
+    general.context_timezones.insert("prod-*".into(), "Asia/Tokyo".into());
+    let mut app = app_with_selected_pod(general).await;
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "prod-eu".into(), namespace: "default".into() });
+
+    app.apply_context_display();
+    assert_eq!(app.display_timezone, jiff::tz::TimeZone::get("Asia/Tokyo").unwrap());
+
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "staging".into(), namespace: "default".into() });
+    app.apply_context_display();
+    assert_eq!(app.display_timezone, jiff::tz::TimeZone::UTC);
+}
+
+#[tokio::test]
+async fn plugin_columns_show_placeholder_then_value() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.views_config.pods.columns.clear();
+    app.views_config.pods.plugin_columns =
+        vec![kubetile_config::PluginColumnConfig { header: "TEAM".into(), command: "echo payments".into() }];
+    let pods_pane_id = app.pods_pane_id;
+    let headers: Vec<String> = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()];
+
+    app.handle_resource_update(
+        pods_pane_id,
+        headers.clone(),
+        vec![vec!["pod-a".into(), "default".into(), "Running".into()]],
+        false,
+        None,
+    );
+    app.with_pods_pane(|pane| {
+        assert_eq!(pane.state.headers.last().map(String::as_str), Some("TEAM"));
+        assert_eq!(pane.state.items[0][3], "…");
+    });
+
+    app.handle_plugin_columns_ready(pods_pane_id, "pod-a".into(), "default".into(), vec!["payments".into()]);
+    app.with_pods_pane(|pane| assert_eq!(pane.state.items[0], vec!["pod-a", "default", "Running", "payments"]));
+
+    app.handle_resource_update(
+        pods_pane_id,
+        headers,
+        vec![vec!["pod-a".into(), "default".into(), "Pending".into()]],
+        false,
+        None,
+    );
+    app.with_pods_pane(|pane| {
+        assert_eq!(pane.state.headers.len(), 4);
+        assert_eq!(pane.state.items[0], vec!["pod-a", "default", "Pending", "payments"]);
+    });
+}
+
+#[tokio::test]
+async fn script_columns_and_panes_run_on_the_selected_pod() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    app.app_tx = tx;
+    app.views_config.pods.columns.clear();
+    let mut scripts = kubetile_core::ScriptHost::new();
+    scripts
+        .load_source(
+            "status.lua",
+            r#"
+            kubetile.column { header = "SHOUT", kinds = { "pods" }, value = function(r) return r.cells.STATUS:upper() end }
+            kubetile.pane { name = "about", key = "ctrl+alt+y", render = function(r) return { r.kind, r.name } end }
+            "#,
+        )
+        .unwrap();
+    app.set_scripts(scripts);
+    let pods_pane_id = app.pods_pane_id;
+
+    app.handle_resource_update(
+        pods_pane_id,
+        vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()],
+        vec![vec!["pod-a".into(), "default".into(), "Running".into()]],
+        false,
+        None,
+    );
+    app.with_pods_pane(|pane| assert_eq!(pane.state.headers.last().map(String::as_str), Some("SHOUT")));
+    let Some(AppEvent::PluginColumnsReady { values, .. }) = rx.recv().await else { panic!("expected column values") };
+    assert_eq!(values, ["RUNNING"]);
+
+    app.handle_command(Command::RunScript("about".into()));
+    let event = rx.recv().await.unwrap();
+    assert!(matches!(&event, AppEvent::TextReady { content, .. } if content == "pods\npod-a"));
+    app.handle_event(event);
+    let focused = app.tab_manager.active().focused_pane;
+    assert!(matches!(app.panes[&focused].view_type(), ViewType::Plugin(name) if name == "script:about"));
+}
+
+#[tokio::test]
+async fn pvc_list_shows_volume_usage_when_enabled() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.features.volume_stats = true;
+    let pane_id = app.pods_pane_id;
+    app.panes.insert(pane_id, Box::new(ResourceListPane::new(ResourceKind::PersistentVolumeClaims, vec![])));
+    let headers: Vec<String> = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()];
+    let rows = vec![vec!["data".to_string(), "db".to_string(), "Bound".to_string()]];
+
+    app.handle_resource_update(pane_id, headers.clone(), rows.clone(), false, None);
+    let pvc_row = |app: &App| {
+        let pane = app.panes[&pane_id].as_any().downcast_ref::<ResourceListPane>().unwrap();
+        (pane.state.headers.clone(), pane.state.items[0].clone())
+    };
+    let (shown_headers, row) = pvc_row(&app);
+    assert_eq!(shown_headers, vec!["NAME", "NAMESPACE", "STATUS", "USED", "USE%"]);
+    assert_eq!(row, vec!["data", "db", "Bound", "", ""]);
+
+    let usage = kubetile_core::VolumeUsage { capacity_bytes: 4 << 30, used_bytes: 1 << 30 };
+    app.handle_volume_usage_ready(pane_id, Ok([(("db".to_string(), "data".to_string()), usage)].into()));
+    assert_eq!(pvc_row(&app).1, vec!["data", "db", "Bound", "1.0Gi", "25%"]);
+
+    app.handle_resource_update(pane_id, headers, rows, false, None);
+    assert_eq!(pvc_row(&app).1, vec!["data", "db", "Bound", "1.0Gi", "25%"]);
+    let section = app.volume_usage_section(&ResourceKind::PersistentVolumeClaims, "data", "db").unwrap();
+    assert_eq!(section.fields[2], ("Use%".into(), "25%".into()));
+}
+
+#[tokio::test]
+async fn partial_pages_fill_list_until_complete() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.views_config.pods.columns.clear();
+    let pods_pane_id = app.pods_pane_id;
+    let headers: Vec<String> = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into()];
+    let row = |name: &str| vec![name.to_string(), "default".to_string(), "Running".to_string()];
+
+    app.handle_resource_update(pods_pane_id, headers.clone(), vec![row("pod-a")], true, Some(2));
+    app.with_pods_pane(|pane| {
+        assert!(pane.state.partial);
+        assert_eq!(pane.state.expected, Some(2));
+        assert!(!pane.state.loading);
+        assert_eq!(pane.state.items.len(), 1);
+    });
+
+    app.handle_resource_update(pods_pane_id, headers, vec![row("pod-a"), row("pod-b")], false, None);
+    app.with_pods_pane(|pane| {
+        assert!(!pane.state.partial);
+        assert_eq!(pane.state.items.len(), 2);
+    });
+}
+
+fn app_in_context(app: &mut App, context: &str) {
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: context.into(), namespace: "default".into() });
+}
+
+#[tokio::test]
+async fn forbidden_delete_is_refused() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app_in_context(&mut app, "dev");
+    let check = kubetile_core::AccessCheck::new("delete", "", "pods", Some("default"));
+    app.handle_access_reviewed(
+        "dev".into(),
+        check,
+        Ok(kubetile_core::AccessDecision { allowed: false, reason: String::new() }),
+    );
+    let toasts_before = app.toasts.len();
+
+    app.handle_command(Command::DeleteResource);
+
+    assert!(app.pending_confirmation.is_none());
+    assert_eq!(app.toasts.len(), toasts_before + 1);
+    assert!(app.toasts.last().unwrap().text.contains("delete pods -n default"));
+}
+
+#[tokio::test]
+async fn pane_help_greys_out_forbidden_actions() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app_in_context(&mut app, "dev");
+    let denied = kubetile_core::AccessDecision { allowed: false, reason: String::new() };
+    app.handle_access_reviewed(
+        "dev".into(),
+        kubetile_core::AccessCheck::new("delete", "", "pods", Some("default")),
+        Ok(denied),
+    );
+
+    app.show_pane_help();
+
+    let entries = app.pane_help_overlay.clone().unwrap();
+    let disabled: Vec<&str> = app.pane_help_disabled.iter().map(|&i| entries[i].1.as_str()).collect();
+    assert_eq!(disabled, vec!["Delete", "Force delete"]);
+    assert!(entries.iter().any(|(_, desc)| desc == "Debug mode"));
+    assert!(!entries.iter().any(|(_, desc)| desc == "Restart rollout"));
+}
+
+#[tokio::test]
+async fn can_i_dialog_shows_review_answer() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app_in_context(&mut app, "dev");
+
+    app.handle_command(Command::OpenCanIDialog);
+    assert_eq!(app.dispatcher.mode(), InputMode::CanIDialog);
+    for c in "list secrets -n prod".chars() {
+        app.handle_command(Command::CanIInput(c));
+    }
+    app.handle_command(Command::CanIConfirm);
+
+    let check = app.can_i_dialog.as_ref().and_then(|d| d.check.clone()).unwrap();
+    assert_eq!(check, kubetile_core::AccessCheck::new("list", "", "secrets", Some("prod")));
+    app.handle_access_reviewed(
+        "dev".into(),
+        check,
+        Ok(kubetile_core::AccessDecision { allowed: true, reason: "RBAC: allowed".into() }),
+    );
+    assert!(matches!(
+        app.can_i_dialog.as_ref().map(|d| &d.status),
+        Some(access::CanIStatus::Decided(decision)) if decision.allowed
+    ));
+
+    app.handle_command(Command::CanICancel);
+    assert!(app.can_i_dialog.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+}
+
+#[tokio::test]
+async fn apply_dialog_reports_errors_then_opens_the_diff_pane() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app_in_context(&mut app, "dev");
+    app.context_resolver.set_namespaces(&["web".into(), "api".into()]);
+
+    app.handle_command(Command::ApplyManifests);
+    assert_eq!(app.dispatcher.mode(), InputMode::ApplyPathInput);
+    assert_eq!(app.apply_dialog.as_ref().unwrap().namespace, "web");
+    app.handle_command(Command::ApplyPathNextNamespace);
+    app.handle_command(Command::ApplyPathToggleForce);
+    let dialog = app.apply_dialog.as_ref().unwrap();
+    assert_eq!((dialog.namespace.as_str(), dialog.force), ("api", true));
+    app.handle_command(Command::ApplyPathNextNamespace);
+    assert_eq!(app.apply_dialog.as_ref().unwrap().namespace, "web");
+    for c in "./deploy".chars() {
+        app.handle_command(Command::ApplyPathInput(c));
+    }
+    app.apply_dialog.as_mut().unwrap().planning = true;
+    app.handle_apply_plan("./deploy".into(), "dev".into(), Err("./deploy does not exist".into()));
+    let dialog = app.apply_dialog.as_ref().unwrap();
+    assert_eq!((dialog.planning, dialog.error.as_deref()), (false, Some("./deploy does not exist")));
+
+    app.handle_apply_plan("./deploy".into(), "dev".into(), Ok(Vec::new()));
+    assert!(app.apply_dialog.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert_eq!(app.last_apply_path, "./deploy");
+    let focused = app.tab_manager.active().focused_pane;
+    assert_eq!(app.panes[&focused].view_type(), &ViewType::Plugin("Apply".into()));
+
+    let toasts_before = app.toasts.len();
+    app.handle_command(Command::ApplyManifests);
+    assert!(app.pending_confirmation.is_none(), "nothing accepted, nothing to confirm");
+    assert_eq!(app.toasts.len(), toasts_before + 1);
+
+    app_in_context(&mut app, "prod");
+    app.handle_command(Command::ApplyManifests);
+    assert!(app.pending_confirmation.is_none());
+    assert!(app.toasts.history().last().unwrap().text.contains("planned against context dev"));
+}
+
+#[tokio::test]
+async fn context_probes_annotate_selector_entries() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.contexts = vec!["dev".into(), "prod".into()];
+
+    app.probe_contexts();
+    assert_eq!(app.context_health.get("dev"), Some(&ContextHealth::Probing));
+
+    app.handle_context_probed("dev".into(), Ok(Duration::from_millis(42)));
+    app.handle_context_probed("prod".into(), Err("timed out after 3000ms".into()));
+    app.handle_context_probed("gone".into(), Ok(Duration::from_millis(1)));
+
+    assert_eq!(app.context_health.get("dev"), Some(&ContextHealth::Reachable { latency_ms: 42 }));
+    assert!(matches!(app.context_health.get("prod"), Some(ContextHealth::Unreachable(_))));
+    assert!(!app.context_health.contains_key("gone"));
+}
+
+fn focused_list(app: &App) -> &ResourceListPane {
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes[&focused].as_any().downcast_ref::<ResourceListPane>().unwrap()
+}
+
+#[tokio::test]
+async fn switching_resources_keeps_view_state_per_kind() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.with_pods_pane(|pane| {
+        pane.filter_text = "pod-a".into();
+        pane.sort_by_column(2);
+        pane.all_namespaces = true;
+    });
+    let pods_state = focused_list(&app).view_state();
+
+    app.switch_resource(ResourceKind::Deployments);
+    let deployments = focused_list(&app);
+    assert_eq!(deployments.kind(), Some(&ResourceKind::Deployments));
+    assert!(deployments.filter_text.is_empty());
+    assert!(!deployments.all_namespaces);
+    app.with_pods_pane(|pane| pane.filter_text = "api".into());
+
+    app.switch_resource(ResourceKind::Pods);
+    assert_eq!(focused_list(&app).view_state(), pods_state);
+
+    app.switch_resource(ResourceKind::Deployments);
+    assert_eq!(focused_list(&app).filter_text, "api");
+}
+
+#[tokio::test]
+async fn failed_reconnect_backs_off_and_ignores_superseded_attempts() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.offline = Some(reconnect::OfflineState::new("no kubeconfig".into(), reconnect::ReconnectPhase::Connecting));
+    app.schedule_reconnect();
+    let first = app.reconnect_seq;
+
+    app.handle_reconnect_failed(first, "connection refused".into());
+    let offline = app.offline.as_ref().unwrap();
+    assert_eq!(offline.attempt, 1);
+    assert_eq!(offline.reason, "connection refused");
+    assert!(matches!(offline.phase, reconnect::ReconnectPhase::Waiting(_)));
+
+    app.handle_reconnect_failed(first, "stale".into());
+    assert_eq!(app.offline.as_ref().unwrap().reason, "connection refused");
+
+    app.handle_command(Command::Reconnect);
+    let offline = app.offline.as_ref().unwrap();
+    assert_eq!(offline.attempt, 0);
+    assert!(matches!(offline.phase, reconnect::ReconnectPhase::Connecting));
+}
+
+#[tokio::test]
+async fn connection_failures_are_diagnosed_in_their_own_tab() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let summary = app.record_connection_failure(None, "tcp connect error: Connection refused (os error 111)".into());
+    assert_eq!(summary, "The API server could not be reached (Ctrl+Shift+Y: details)");
+
+    app.handle_command(Command::ToggleConnectionTab);
+    let tab = app.tab_manager.active();
+    assert!(app.panes[&tab.focused_pane].as_any().is::<crate::panes::ConnectionPane>());
+    assert!(app.is_plugin_tab(tab.id, "Connection"));
+
+    app.clear_connection_failure();
+    assert!(app.connection_failure.is_none());
+    app.handle_command(Command::ToggleConnectionTab);
+    assert!(app.find_plugin_tab_index("Connection").is_none());
+}
+
+#[tokio::test]
+async fn global_search_filters_listed_names_and_opens_the_hit() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.handle_command(Command::OpenGlobalSearch);
+    assert!(app.global_search.is_none());
+    assert_eq!(app.toasts.history().last().unwrap().text, "No cluster connection");
+
+    app.global_search = Some(search::GlobalSearch {
+        input: String::new(),
+        cluster_wide: false,
+        namespace: "prod".into(),
+        hits: Vec::new(),
+        failed: Vec::new(),
+        loading: true,
+        selected: 0,
+        seq: 2,
+    });
+    app.dispatcher.set_mode(InputMode::GlobalSearch);
+    let hit = |kind, name: &str| kubetile_core::SearchHit { kind, namespace: Some("prod".into()), name: name.into() };
+    let listed = |seq| AppEvent::SearchListed {
+        seq,
+        hits: vec![
+            hit(kubetile_core::ResourceKind::Pods, "api-7d9f-x2x"),
+            hit(kubetile_core::ResourceKind::Services, "api"),
+            hit(kubetile_core::ResourceKind::Deployments, "api"),
+            hit(kubetile_core::ResourceKind::ConfigMaps, "web-config"),
+        ],
+        failed: vec![(kubetile_core::ResourceKind::Secrets, "forbidden".into())],
+    };
+    app.handle_event(listed(1));
+    assert!(app.global_search.as_ref().unwrap().loading, "listings of an earlier scope are dropped");
+    app.handle_event(listed(2));
+
+    for c in "api".chars() {
+        app.handle_command(Command::GlobalSearchInput(c));
+    }
+    let search = app.global_search.as_ref().unwrap();
+    let names: Vec<_> = search.matches().iter().map(|h| (h.kind.short_name(), h.name.as_str())).collect();
+    assert_eq!(names, [("po", "api-7d9f-x2x"), ("svc", "api"), ("deploy", "api")]);
+    assert_eq!(search.notice().as_deref(), Some("Could not list: secret"));
+
+    app.global_search.as_mut().unwrap().input = "deploy,svc/api".into();
+    app.handle_command(Command::GlobalSearchNext);
+    app.handle_command(Command::GlobalSearchConfirm);
+    assert!(app.global_search.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    let pane = app.panes[&app.tab_manager.active().focused_pane].as_any().downcast_ref::<ResourceListPane>().unwrap();
+    assert_eq!(pane.kind(), Some(&ResourceKind::Deployments));
+    assert_eq!(pane.scope.as_ref().map(|s| s.namespace.as_str()), Some("prod"));
+    assert_eq!(pane.filter_text, "api");
+}
+
+#[tokio::test]
+async fn offline_banner_clears_when_watcher_stream_resumes() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let pane_id = app.pods_pane_id;
+    app.watcher_seq_by_pane.insert(pane_id, 7);
+    app.offline = Some(reconnect::OfflineState::new("timed out".into(), reconnect::ReconnectPhase::Connecting));
+
+    let update = |seq| AppEvent::ResourceUpdate {
+        pane_id,
+        watcher_seq: seq,
+        headers: vec![],
+        rows: vec![vec!["pod-a".into(), "default".into(), "Running".into()]],
+        partial: false,
+        expected: None,
+    };
+    app.handle_event(update(7));
+    assert!(app.offline.is_some(), "banner stays until the client is back");
+
+    app.offline.as_mut().unwrap().phase = reconnect::ReconnectPhase::Resuming;
+    app.handle_event(update(6));
+    assert!(app.offline.is_some(), "stale watcher updates do not count");
+
+    app.handle_event(update(7));
+    assert!(app.offline.is_none());
+}
+
+#[tokio::test]
+async fn expired_credentials_pause_watchers_until_the_user_logs_in() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let pane_id = app.pods_pane_id;
+    app_in_context(&mut app, "dev");
+    app.offline = None;
+    app.watcher_seq_by_pane.insert(pane_id, 3);
+    let error = "ApiError: Unauthorized: Unauthorized (ErrorResponse { code: 401 })".to_string();
+    app.handle_event(AppEvent::ResourceError { pane_id, watcher_seq: 3, error: error.clone() });
+    assert!(app.offline.is_none(), "the text of a watch error alone does not count as a 401");
+    app.handle_event(AppEvent::AuthExpired { context: "dev".into(), error });
+
+    let offline = app.offline.as_ref().unwrap();
+    assert!(offline.auth_expired);
+    assert!(matches!(offline.phase, reconnect::ReconnectPhase::Connecting), "refreshes right away");
+    assert!(app.active_watchers.is_empty());
+
+    app.handle_reconnect_failed(app.reconnect_seq, "auth exec command 'aws' failed with status 255".into());
+    assert!(matches!(app.offline.as_ref().unwrap().phase, reconnect::ReconnectPhase::AwaitingLogin));
+    assert!(app.toasts.history().last().unwrap().text.ends_with("Log in, then press Ctrl+Shift+R"));
+
+    app.handle_command(Command::Reconnect);
+    assert!(matches!(app.offline.as_ref().unwrap().phase, reconnect::ReconnectPhase::Connecting));
+}
+
+/// A watcher that watches nothing, standing in for one started against a cluster.
+fn idle_watcher() -> ResourceWatcher {
+    ResourceWatcher::merge::<String>(Vec::new(), tokio::sync::mpsc::channel(1).0)
+}
+
+#[tokio::test]
+async fn expired_credentials_only_pause_the_tabs_on_that_context() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.offline = None;
+    app_in_context(&mut app, "dev");
+    let dev_pane = app.pods_pane_id;
+    app.active_watchers.insert(dev_pane, idle_watcher());
+    app.new_tab();
+    app_in_context(&mut app, "prod");
+    let prod_pane = app.tab_manager.active().focused_pane;
+    app.active_watchers.insert(prod_pane, idle_watcher());
+
+    let error = "ApiError: Unauthorized (ErrorResponse { code: 401 })".to_string();
+    app.handle_event(AppEvent::AuthExpired { context: "dev".into(), error: error.clone() });
+    assert!(app.offline.is_none(), "the active context prod is not refreshed");
+    assert!(app.active_watchers.contains_key(&prod_pane));
+    assert!(!app.active_watchers.contains_key(&dev_pane));
+    assert_eq!(app.suspended_streams.get(&dev_pane), Some(&suspension::SuspendReason::AuthExpired));
+    assert!(app.toasts.history().last().unwrap().text.starts_with("Credentials for dev expired"));
+
+    app.handle_event(AppEvent::AuthExpired { context: "prod".into(), error });
+    assert!(app.offline.as_ref().is_some_and(|o| o.auth_expired));
+    assert!(app.active_watchers.is_empty());
+    assert!(!app.suspended_streams.contains_key(&prod_pane), "the refresh restarts the active tab");
+
+    let seq = app.watcher_seq_by_pane.get(&dev_pane).copied();
+    app.switch_to_tab_index(0);
+    assert!(app.suspended_streams.is_empty());
+    assert_ne!(app.watcher_seq_by_pane.get(&dev_pane).copied(), seq, "the dev list is watched again");
+}
+
+#[tokio::test]
+async fn namespace_selector_space_toggles_a_multi_namespace_selection() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "test".into(), namespace: "default".into() });
+    app.namespaces = vec!["api".into(), "default".into(), "web".into()];
+
+    app.handle_command(Command::EnterMode(InputMode::NamespaceSelector));
+    // Entries are "All Namespaces", then the namespaces in order.
+    app.handle_command(Command::NamespaceToggle);
+    assert!(app.namespace_marked.is_empty());
+    app.handle_command(Command::Pane(PaneCommand::SelectNext));
+    app.handle_command(Command::NamespaceToggle);
+    app.handle_command(Command::Pane(PaneCommand::SelectNext));
+    app.handle_command(Command::Pane(PaneCommand::SelectNext));
+    app.handle_command(Command::NamespaceToggle);
+    assert_eq!(app.namespace_marked, vec!["api".to_string(), "web".to_string()]);
+    app.handle_command(Command::NamespaceConfirm);
+
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert_eq!(app.context_resolver.namespaces(), vec!["api", "web"]);
+    assert_eq!(app.watch_namespaces(&ResourceKind::Pods, false), vec!["api".to_string(), "web".to_string()]);
+    assert!(app.watch_namespaces(&ResourceKind::Nodes, false).is_empty());
+    assert!(app.tab_manager.active().name.starts_with("api,web|"));
+
+    app.handle_command(Command::EnterMode(InputMode::NamespaceSelector));
+    assert_eq!(app.namespace_marked, vec!["api".to_string(), "web".to_string()]);
+}
+
+#[tokio::test]
+async fn multi_namespace_lists_show_and_act_on_each_rows_namespace() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "test".into(), namespace: "default".into() });
+    let pods_pane_id = app.pods_pane_id;
+    let headers: Vec<String> = vec!["NAME".into(), "NAMESPACE".into(), "STATUS".into(), "AGE".into()];
+    let rows = vec![vec!["api-0".into(), "web".into(), "Running".into(), "1m".into()]];
+
+    app.handle_resource_update(pods_pane_id, headers.clone(), rows.clone(), false, None);
+    app.with_pods_pane(|pane| assert!(!pane.state.headers.contains(&"NAMESPACE".to_string())));
+    assert_eq!(app.selected_resource_info().map(|(_, _, ns)| ns).as_deref(), Some("default"));
+
+    app.context_resolver.set_namespaces(&["api".into(), "web".into()]);
+    app.handle_resource_update(pods_pane_id, headers, rows, false, None);
+    app.with_pods_pane(|pane| assert_eq!(pane.state.headers[..2], ["NAME", "NAMESPACE"]));
+    assert_eq!(app.selected_resource_info().map(|(_, _, ns)| ns).as_deref(), Some("web"));
+
+    app.handle_command(Command::DeleteResource);
+    assert!(app.pending_confirmation.as_ref().is_some_and(|pc| pc.message.contains("in namespace web")));
+}
+
+#[tokio::test]
+async fn namespace_selector_lists_favorites_and_recent_namespaces_first() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.context_resolver
+        .set_context(kubetile_core::ClusterContext { name: "test".into(), namespace: "default".into() });
+    app.namespaces = vec!["api".into(), "billing".into(), "default".into(), "web".into()];
+
+    app.handle_command(Command::EnterMode(InputMode::NamespaceSelector));
+    app.namespace_filter = "bil".into();
+    app.handle_command(Command::NamespaceConfirm);
+    assert_eq!(app.context_resolver.namespace(), Some("billing"));
+
+    app.handle_command(Command::EnterMode(InputMode::NamespaceSelector));
+    app.namespace_selected = 4;
+    app.handle_command(Command::NamespaceFavorite);
+    assert_eq!(app.filtered_namespaces(), ["All Namespaces", "web", "billing", "api", "default"]);
+    assert_eq!(app.namespace_selected, 1, "the highlight follows the pinned namespace");
+
+    app.handle_command(Command::NamespaceFavorite);
+    assert_eq!(app.filtered_namespaces(), ["All Namespaces", "billing", "api", "default", "web"]);
+}
+
+#[tokio::test]
+async fn sync_input_broadcasts_from_exec_panes_of_the_active_tab() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let list_id = app.tab_manager.active().focused_pane;
+    let mut exec_ids = Vec::new();
+    for pod in ["pod-a", "pod-b"] {
+        let id = app.tab_manager.split_pane(list_id, SplitDirection::Horizontal, ViewType::Exec(pod.into())).unwrap();
+        app.panes.insert(id, Box::new(crate::panes::ExecPane::new(pod.into(), "auto".into(), "default".into())));
+        exec_ids.push(id);
+    }
+    assert_eq!(app.exec_pane_ids().len(), 2);
+    assert!(!app.broadcasts_input(exec_ids[0]));
+
+    app.handle_command(Command::ToggleSyncInput);
+    assert!(app.tab_manager.active().sync_input);
+    assert!(app.broadcasts_input(exec_ids[0]));
+    assert!(!app.broadcasts_input(list_id));
+
+    app.new_tab();
+    assert!(!app.tab_manager.active().sync_input);
+    assert!(app.exec_pane_ids().is_empty());
+}
+
+#[tokio::test]
+async fn parameterized_query_prompts_for_values_before_running() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let mut pane = crate::panes::query_pane::test_pane();
+    pane.set_editor_content("SELECT * FROM orgs WHERE id = :org_id");
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes.insert(focused, Box::new(pane));
+    app.dispatcher.set_mode(InputMode::QueryEditor);
+
+    app.handle_command(Command::QueryEditorExecute);
+    assert_eq!(app.dispatcher.mode(), InputMode::QueryParams);
+
+    for c in "42".chars() {
+        app.handle_command(Command::QueryParamsInput(c));
+    }
+    app.handle_command(Command::QueryParamsConfirm);
+
+    assert_eq!(app.dispatcher.mode(), InputMode::QueryEditor);
+    let qp = app.panes[&focused].as_any().downcast_ref::<crate::panes::QueryPane>().unwrap();
+    assert_eq!(qp.last_executed_sql(), Some("SELECT * FROM orgs WHERE id = :org_id"));
+}
+
+#[tokio::test]
+async fn commit_without_open_transaction_is_refused() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+
+    app.handle_command(Command::QueryCommit);
+
+    assert_eq!(app.toasts.last().map(|t| t.text.as_str()), Some("No open transaction"));
+}
+
+#[tokio::test]
+async fn a_second_begin_waits_for_the_first_and_a_failed_begin_resets_the_pane() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    // BEGIN is in flight on the pane's new session.
+    let mut pane = crate::panes::query_pane::test_pane();
+    pane.set_transaction(crate::panes::TransactionState::Starting);
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes.insert(focused, Box::new(pane));
+    let transaction =
+        |app: &App| app.panes[&focused].as_any().downcast_ref::<crate::panes::QueryPane>().unwrap().transaction();
+
+    app.handle_command(Command::QueryBeginTransaction);
+    assert_eq!(app.toasts.last().map(|t| t.text.as_str()), Some("A transaction is already starting"));
+
+    app.handle_event(AppEvent::TransactionStarted { pane_id: focused, result: Err("connection refused".into()) });
+    assert_eq!(transaction(&app), crate::panes::TransactionState::None);
+    assert!(app.query_sessions.is_empty());
+
+    app.handle_event(AppEvent::TransactionLost { pane_id: focused, error: "database session lost".into() });
+    assert_eq!(
+        app.toasts.last().map(|t| t.text.as_str()),
+        Some("The database session ended; the open transaction was rolled back")
+    );
+}
+
+#[tokio::test]
+async fn cell_inspector_walks_columns_of_selected_row() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let mut pane = crate::panes::query_pane::test_pane();
+    pane.set_result(kubetile_core::QueryResult {
+        headers: vec!["id".into(), "doc".into()],
+        rows: vec![vec!["1".into(), r#"{"a":1}"#.into()]],
+    });
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes.insert(focused, Box::new(pane));
+    app.dispatcher.set_mode(InputMode::QueryBrowse);
+
+    app.handle_command(Command::OpenCellInspector);
+    assert_eq!(app.dispatcher.mode(), InputMode::CellInspector);
+    app.handle_command(Command::CellInspectorNextColumn);
+    app.handle_command(Command::CellInspectorNextColumn);
+    let qp = app.panes[&focused].as_any().downcast_ref::<crate::panes::QueryPane>().unwrap();
+    assert_eq!(qp.inspected_cell(), Some(("doc", r#"{"a":1}"#)));
+
+    app.handle_command(Command::CellInspectorClose);
+    assert_eq!(app.dispatcher.mode(), InputMode::QueryBrowse);
+}
+
+#[tokio::test]
+async fn notification_center_keeps_expired_toasts() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let before = app.toasts.history().len();
+    let mut stale = ToastMessage::error("Rollout failed");
+    stale.ttl = Duration::ZERO;
+    app.toasts.push(stale);
+    app.handle_event(AppEvent::Tick);
+    assert!(!app.toasts.iter().any(|t| t.text == "Rollout failed"));
+
+    app.handle_command(Command::OpenNotificationCenter);
+    assert_eq!(app.dispatcher.mode(), InputMode::NotificationCenter);
+    assert_eq!(app.toasts.history().len(), before + 1);
+    assert_eq!(app.toasts.history().last().map(|r| r.text.as_str()), Some("Rollout failed"));
+
+    app.handle_command(Command::NotificationCenterClear);
+    assert!(app.toasts.history().is_empty());
+    app.handle_command(Command::CloseNotificationCenter);
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+}
+
+#[tokio::test]
+async fn warning_events_reach_the_ticker_or_toasts_by_reason() {
+    let mut general = kubetile_config::GeneralConfig::default();
+    general.warning_events.exclude_reasons = vec!["FailedMount".into()];
+    let mut app = app_with_selected_pod(general).await;
+    let notice = |reason: &str| kubetile_core::WarningNotice {
+        namespace: "web".into(),
+        object: "Pod/api-0".into(),
+        reason: reason.into(),
+        message: "Back-off restarting failed container".into(),
+    };
+
+    app.handle_warning_event(app.warning_watch_seq, notice("FailedMount"));
+    app.handle_warning_event(app.warning_watch_seq + 1, notice("BackOff"));
+    assert!(app.build_render_context().0.warning_ticker.is_none());
+
+    app.handle_warning_event(app.warning_watch_seq, notice("BackOff"));
+    let ticker = app.build_render_context().0.warning_ticker.map(|t| t.text.to_string());
+    assert_eq!(ticker.as_deref(), Some("BackOff Pod/api-0: Back-off restarting failed container"));
+
+    app.general_config.warning_events.display = kubetile_config::WarningEventsDisplay::Toast;
+    let toasts_before = app.toasts.len();
+    app.handle_warning_event(app.warning_watch_seq, notice("Unhealthy"));
+    assert_eq!(app.toasts.len(), toasts_before + 1);
+    assert!(app.toasts.last().unwrap().text.starts_with("Warning in web: Unhealthy"));
+}
+
+#[tokio::test]
+async fn welcome_overlay_writes_the_default_config_once() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let dir = std::env::temp_dir().join(format!("kubetile-welcome-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let path = dir.join("config.toml");
+    app.show_welcome(path.clone());
+    assert_eq!(app.dispatcher.mode(), InputMode::Welcome);
+    assert!(app.welcome.as_ref().is_some_and(|w| w.entries.iter().any(|(_, desc)| desc == "Quit")));
+
+    app.handle_command(Command::WriteDefaultConfig);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), kubetile_config::DEFAULT_CONFIG);
+    assert!(app.welcome.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+
+    app.show_welcome(path.clone());
+    app.handle_command(Command::WriteDefaultConfig);
+    assert!(app.toasts.iter().any(|t| t.text.starts_with("Cannot write the config")));
+    assert!(app.welcome.is_some());
+    app.handle_command(Command::CloseWelcome);
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn empty_panes_carry_key_hints() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let list = app.new_list_pane(ResourceKind::Services, Vec::new());
+    assert_eq!(list.empty_hint, "Press : to switch resource, / to filter, A for all namespaces");
+    app.split_focused(SplitDirection::Vertical);
+    let focused = app.tab_manager.active().focused_pane;
+    let empty = app.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<EmptyPane>()).unwrap();
+    assert!(empty.1.starts_with("Press : to open a resource list here"));
+}
+
+#[tokio::test]
+async fn help_sections_follow_the_input_mode_and_include_plugins() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let current = |sections: &[HelpSection]| -> Vec<String> {
+        sections.iter().filter(|s| s.current).map(|s| s.title.clone()).collect()
+    };
+    let sections = app.help_sections(InputMode::QueryEditor);
+    assert_eq!(current(&sections), ["Query Editor"]);
+    assert!(sections.iter().any(|s| s.title == "Plugin"));
+    assert!(current(&app.help_sections(InputMode::Normal)).contains(&"Global (Ctrl+)".to_string()));
+
+    app.toggle_help();
+    app.handle_command(Command::EnterMode(InputMode::FilterInput));
+    app.handle_command(Command::FilterInput('q'));
+    let focused = app.tab_manager.active().focused_pane;
+    let help = app.panes.get(&focused).and_then(|p| p.as_any().downcast_ref::<HelpPane>()).unwrap();
+    assert_eq!(help.filter_text(), "q");
+}
+
+#[tokio::test]
+async fn followed_rollouts_show_progress_until_they_finish() {
+    use kubetile_core::{RolloutProgress, RolloutState};
+
+    let progress = |updated, state| RolloutProgress { desired: 3, updated, available: 3, old: 3 - updated, state };
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let api_poller = CancellationToken::new();
+    app.rollouts.push(1, ResourceKind::Deployments, "api".into(), "web".into(), api_poller.clone().drop_guard());
+    let worker_poller = CancellationToken::new().drop_guard();
+    app.rollouts.push(2, ResourceKind::StatefulSets, "worker".into(), "web".into(), worker_poller);
+
+    app.handle_rollout_progress(1, Ok(progress(2, RolloutState::Progressing)));
+    assert_eq!(app.rollouts.lines[0].0, "deploy web/api");
+    assert!(app.rollouts.lines[0].1.starts_with("2 of 3 updated, 3 available, 1 old terminating  "));
+    assert_eq!(app.rollouts.lines[1].0, "sts web/worker");
+    assert!(app.rollouts.lines[1].1.starts_with("waiting for the controller  "));
+
+    app.handle_rollout_progress(1, Ok(progress(3, RolloutState::Complete)));
+    assert!(api_poller.is_cancelled());
+    assert_eq!(app.rollouts.lines.len(), 1);
+    assert_eq!(app.toasts.history().last().unwrap().text, "Rolled out deploy web/api: 3 of 3 updated, 3 available");
+
+    app.handle_rollout_progress(2, Ok(progress(1, RolloutState::Failed)));
+    assert!(app.rollouts.lines.is_empty());
+    assert!(app.toasts.history().last().unwrap().text.contains("exceeded its progress deadline"));
+}
+
+#[tokio::test]
+async fn followed_rollouts_time_out() {
+    let general = kubetile_config::GeneralConfig { rollout_timeout_secs: 0, ..Default::default() };
+    let mut app = app_with_selected_pod(general).await;
+    let poller = CancellationToken::new();
+    app.rollouts.push(1, ResourceKind::Deployments, "api".into(), "web".into(), poller.clone().drop_guard());
+
+    app.refresh_rollouts();
+    assert!(poller.is_cancelled());
+    assert!(app.rollouts.lines.is_empty());
+    assert_eq!(app.toasts.history().last().unwrap().text, "Rollout of deploy web/api not finished after 0s");
+}
+
+#[tokio::test]
+async fn set_image_dialog_edits_images_and_asks_first() {
+    let image = |container: &str, init| ContainerImage {
+        container: container.into(),
+        init,
+        image: "web/api:1.4".into(),
+        pull_policy: "IfNotPresent".into(),
+        digest: None,
+    };
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let containers = vec![image("migrate", true), image("api", false)];
+    app.open_set_image_dialog(ResourceKind::Deployments, "api".into(), "web".into(), Ok(containers));
+    assert_eq!(app.dispatcher.mode(), InputMode::SetImageDialog);
+    assert_eq!(app.set_image_dialog.as_ref().unwrap().selected, 1);
+
+    app.handle_command(Command::SetImageConfirm);
+    assert_eq!(app.set_image_dialog.as_ref().unwrap().error.as_deref(), Some("no image was changed"));
+
+    app.handle_command(Command::SetImageBackspace);
+    app.handle_command(Command::SetImageInput('5'));
+    app.handle_command(Command::SetImageConfirm);
+    assert!(app.set_image_dialog.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    let pending = app.pending_confirmation.as_ref().unwrap();
+    assert_eq!(pending.message, "Set images of deploy web/api?\n\napi: web/api:1.4 → web/api:1.5");
+    assert!(matches!(
+        &pending.action,
+        PendingAction::SetImage { images, .. } if images.len() == 1 && images[0].image == "web/api:1.5" && !images[0].init
+    ));
+}
+
+#[tokio::test]
+async fn data_key_edits_pick_a_text_key_and_ask_before_patching() {
+    use kubetile_core::{DataEntry, DataSnapshot};
+
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let entries = vec![
+        DataEntry { key: "app.conf".into(), value: Some("port = 80\nworkers = 4".into()) },
+        DataEntry { key: "logo.png".into(), value: None },
+    ];
+    let snapshot = DataSnapshot { resource_version: "41".into(), entries };
+    app.open_data_key_picker(ResourceKind::ConfigMaps, "settings".into(), "web".into(), Ok(snapshot));
+    assert_eq!(app.dispatcher.mode(), InputMode::DataKeyPicker);
+    let picker = app.data_key_picker.as_ref().unwrap();
+    assert_eq!(picker.subject, "cm web/settings");
+    assert_eq!(picker.labels, vec!["app.conf  2 lines", "logo.png  binary, not editable"]);
+
+    app.handle_command(Command::DataKeyPickerNext);
+    app.handle_command(Command::DataKeyPickerConfirm);
+    assert!(app.data_key_picker.is_none());
+    assert_eq!(app.dispatcher.mode(), InputMode::Normal);
+    assert_eq!(app.toasts.history().last().unwrap().text, "logo.png holds binary data and cannot be edited here");
+
+    let edit = |original: &str| super::data_keys::DataKeyEdit {
+        kind: ResourceKind::ConfigMaps,
+        name: "settings".into(),
+        namespace: "web".into(),
+        resource_version: "41".into(),
+        key: "app.conf".into(),
+        original: original.into(),
+        path: "app.conf".into(),
+        _dir: tempfile::tempdir().unwrap(),
+    };
+    // The newline an editor adds on save does not count as a change.
+    app.review_data_key_edit(edit("port = 80\nworkers = 4"), "port = 80\nworkers = 4\n".into());
+    assert_eq!(app.toasts.history().last().unwrap().text, "app.conf unchanged; nothing was applied");
+    assert!(app.pending_confirmation.is_none());
+
+    app.review_data_key_edit(edit("port = 80\nworkers = 4"), "port = 8080\nworkers = 4\nlog = debug\n".into());
+    assert_eq!(app.dispatcher.mode(), InputMode::ConfirmDialog);
+    let pending = app.pending_confirmation.as_ref().unwrap();
+    assert_eq!(
+        pending.message,
+        "Update key app.conf of cm web/settings?\n\n2 → 3 lines; the other keys stay as they are."
+    );
+    assert!(matches!(
+        &pending.action,
+        PendingAction::EditDataKey(patch)
+            if patch.key == "app.conf" && patch.value == "port = 8080\nworkers = 4\nlog = debug" && patch.resource_version == "41"
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn data_key_values_are_written_to_a_private_directory() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (dir, path) = super::data_keys::write_private("token", "s3cret").unwrap();
+    assert_eq!(path.file_name().unwrap(), "token");
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "s3cret");
+    assert_eq!(std::fs::metadata(dir.path()).unwrap().permissions().mode() & 0o777, 0o700);
+    assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+    drop(dir);
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn graph_nodes_open_their_detail() {
+    use kubetile_core::GraphNode;
+
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let node = |kind: &str, name: &str, note: Option<&str>, children| GraphNode {
+        kind: kind.into(),
+        name: name.into(),
+        note: note.map(Into::into),
+        children,
+    };
+    let slice = node(
+        "EndpointSlice",
+        "api-x7k2p",
+        Some("1/2 ready"),
+        vec![node("Pod", "api-0", Some("Running"), vec![]), node("Pod", "api-1", Some("not found"), vec![])],
+    );
+    let mut graph = crate::panes::GraphPane::new("svc web/api".into(), "web".into());
+    graph.set_graph(Ok(node("Service", "api", None, vec![slice])));
+    let focused = app.tab_manager.active().focused_pane;
+    app.panes.insert(focused, Box::new(graph));
+
+    app.handle_command(Command::Pane(PaneCommand::SelectNext));
+    app.handle_command(Command::Pane(PaneCommand::Select));
+    assert_eq!(app.toasts.history().last().unwrap().text, "EndpointSlice api-x7k2p has no view in kubetile");
+
+    app.handle_command(Command::Pane(PaneCommand::GoToBottom));
+    app.handle_command(Command::Pane(PaneCommand::Select));
+    assert_eq!(app.toasts.history().last().unwrap().text, "Pod api-1 does not exist");
+
+    app.handle_command(Command::Pane(PaneCommand::SelectPrev));
+    app.handle_command(Command::Pane(PaneCommand::Select));
+    let detail = app.tab_manager.active().focused_pane;
+    assert_ne!(detail, focused);
+    assert_eq!(app.panes[&detail].view_type(), &ViewType::Detail(ResourceKind::Pods, "api-0".into()));
+}
+
+#[tokio::test]
+async fn status_bar_health_shows_latency_and_watch_age() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    assert!(app.connection_health_view().is_none());
+
+    app.handle_event(AppEvent::ApiLatencyMeasured { result: Ok(Duration::from_millis(42)) });
+    let health = app.connection_health_view().unwrap();
+    assert_eq!((health.latency_ms, health.last_event), (Some(42), None));
+
+    let pane_id = app.pods_pane_id;
+    let headers = vec!["NAME".to_string(), "NAMESPACE".into(), "STATUS".into()];
+    app.handle_resource_update(
+        pane_id,
+        headers,
+        vec![vec!["pod-a".into(), "default".into(), "Running".into()]],
+        false,
+        None,
+    );
+    app.with_pods_pane(|pane| pane.state.stale = Some("watch stream ended".into()));
+    app.handle_event(AppEvent::ApiLatencyMeasured { result: Err("connection refused".into()) });
+    let health = app.connection_health_view().unwrap();
+    assert_eq!(health.latency_ms, None);
+    assert_eq!(health.last_event.as_deref(), Some("0s"));
+    assert!(health.watch_stale);
+}
+
+#[tokio::test]
+async fn suspended_panes_resume_when_their_tab_is_opened() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    let pane_id = app.pods_pane_id;
+    app.new_tab();
+    app.suspended_streams.insert(pane_id, suspension::SuspendReason::BackgroundTab);
+    assert!(app.tab_has_suspended_streams(0));
+    assert!(app.memory_budget_view().is_none(), "only memory suspensions show in the status bar");
+
+    app.resume_visible_streams();
+    assert!(app.suspended_streams.contains_key(&pane_id), "the tab is still in the background");
+
+    let seq = app.watcher_seq_by_pane.get(&pane_id).copied();
+    app.switch_to_tab_index(0);
+    assert!(!app.tab_has_suspended_streams(0));
+    assert_ne!(app.watcher_seq_by_pane.get(&pane_id).copied(), seq, "the list is watched again");
+}
+
+#[tokio::test]
+async fn idle_ticks_do_not_redraw_until_something_changes() {
+    let general = kubetile_config::GeneralConfig { max_fps: 10, ..Default::default() };
+    let mut app = app_with_selected_pod(general).await;
+    app.toasts.expire();
+    app.frames.drawn();
+
+    app.handle_event(AppEvent::Tick);
+    assert_eq!(app.frames.wait(), None, "nothing changed since the last frame");
+
+    app.handle_event(AppEvent::Resize(80, 24));
+    let wait = app.frames.wait().expect("a frame is pending");
+    assert!(wait > Duration::ZERO && wait <= Duration::from_millis(100), "frames are capped at max_fps");
+
+    app.frames = frames::FramePacer::new(0);
+    assert_eq!(app.frames.wait(), Some(Duration::ZERO), "no cap draws right away");
+}
+
+#[tokio::test]
+async fn a_visible_toast_redraws_only_when_it_expires() {
+    let mut app = app_with_selected_pod(kubetile_config::GeneralConfig::default()).await;
+    app.toasts.expire();
+    app.toasts.push(ToastMessage::info("Saved"));
+    app.frames.drawn();
+
+    app.handle_event(AppEvent::Tick);
+    assert_eq!(app.frames.wait(), None, "a toast on screen alone does not redraw");
+
+    app.toasts.push(ToastMessage { ttl: Duration::ZERO, ..ToastMessage::info("Old") });
+    app.frames.drawn();
+    app.handle_event(AppEvent::Tick);
+    assert!(app.frames.wait().is_some(), "an expired toast is cleared from the screen");
+}
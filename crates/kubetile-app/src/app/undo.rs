@@ -0,0 +1,290 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use kubetile_core::manifests::recreate;
+use kubetile_core::resource::format_duration;
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::event::AppEvent;
+use crate::panes::{TrashPane, TrashRow};
+
+use super::reconnect::report_auth_expired;
+use super::{App, PendingAction};
+
+/// Deletes the trash remembers at most; the oldest is forgotten first.
+const MAX_TRASH_ENTRIES: usize = 200;
+
+/// A deleted resource with the manifest it had, captured just before the delete.
+pub(super) struct TrashEntry {
+    pub(super) id: u64,
+    pub(super) kind: ResourceKind,
+    pub(super) name: String,
+    pub(super) namespace: String,
+    /// Context the resource was deleted in; it is only ever recreated there.
+    pub(super) context: String,
+    /// `None` when the object could not be read before the delete; such a delete is listed but
+    /// cannot be undone.
+    pub(super) manifest: Option<String>,
+    /// Wall-clock time of the delete.
+    pub(super) time: jiff::Timestamp,
+    pub(super) deleted_at: Instant,
+}
+
+impl TrashEntry {
+    /// `deploy web/api`, or `pv data-1` for cluster-scoped kinds.
+    pub(super) fn label(&self) -> String {
+        if self.namespace.is_empty() {
+            format!("{} {}", self.kind.short_name(), self.name)
+        } else {
+            format!("{} {}/{}", self.kind.short_name(), self.namespace, self.name)
+        }
+    }
+}
+
+/// Resources deleted through kubetile this session, newest first.
+#[derive(Default)]
+pub(super) struct Trash {
+    entries: VecDeque<TrashEntry>,
+    next_id: u64,
+}
+
+impl Trash {
+    pub(super) fn push(
+        &mut self,
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        context: String,
+        manifest: Option<String>,
+    ) {
+        self.next_id += 1;
+        let time = jiff::Timestamp::now();
+        let entry =
+            TrashEntry { id: self.next_id, kind, name, namespace, context, manifest, time, deleted_at: Instant::now() };
+        self.entries.push_front(entry);
+        self.entries.truncate(MAX_TRASH_ENTRIES);
+    }
+
+    pub(super) fn get(&self, id: u64) -> Option<&TrashEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    /// Deletes younger than `window` that kept their manifest, newest first.
+    pub(super) fn within(&self, window: Duration, now: Instant) -> impl Iterator<Item = &TrashEntry> {
+        self.entries
+            .iter()
+            .filter(move |e| e.manifest.is_some() && now.saturating_duration_since(e.deleted_at) < window)
+    }
+
+    pub(super) fn remove(&mut self, id: u64) -> Option<TrashEntry> {
+        let index = self.entries.iter().position(|e| e.id == id)?;
+        self.entries.remove(index)
+    }
+}
+
+/// The undo list overlay: undoable deletes by id, with labels refreshed every tick so the ages
+/// stay current.
+pub(super) struct UndoList {
+    pub(super) selected: usize,
+    pub(super) ids: Vec<u64>,
+    pub(super) labels: Vec<String>,
+}
+
+impl App {
+    fn undo_window(&self) -> Duration {
+        Duration::from_secs(self.general_config.undo_window_secs)
+    }
+
+    /// Puts a deleted resource in the trash.
+    pub(super) fn handle_resource_deleted(
+        &mut self,
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        context: String,
+        manifest: Option<String>,
+    ) {
+        self.trash.push(kind, name, namespace, context, manifest);
+        self.refresh_trash_panes();
+    }
+
+    /// Asks to recreate the most recently deleted resource, if it is still within the undo window.
+    pub(super) fn undo_last_delete(&mut self) {
+        let latest = self.trash.within(self.undo_window(), Instant::now()).next().map(|e| e.id);
+        match latest {
+            Some(id) => self.request_restore(id),
+            None => self.toasts.push(ToastMessage::info("Nothing to undo")),
+        }
+    }
+
+    pub(super) fn open_undo_list(&mut self) {
+        self.undo_list = Some(UndoList { selected: 0, ids: Vec::new(), labels: Vec::new() });
+        self.refresh_undo_list();
+        if self.undo_list.is_none() {
+            self.toasts.push(ToastMessage::info("Nothing to undo"));
+            return;
+        }
+        self.dispatcher.set_mode(InputMode::UndoList);
+    }
+
+    /// Drops deletes past the undo window from the open list and recomputes the ages; closes
+    /// the list once nothing is left.
+    pub(super) fn refresh_undo_list(&mut self) {
+        if self.undo_list.is_none() {
+            return;
+        }
+        let now = Instant::now();
+        let window = self.undo_window();
+        let (ids, labels): (Vec<u64>, Vec<String>) = self
+            .trash
+            .within(window, now)
+            .map(|e| {
+                let age = now.saturating_duration_since(e.deleted_at);
+                let left = Duration::from_secs(window.saturating_sub(age).as_secs_f64().ceil() as u64);
+                (e.id, format!("{}  deleted {} ago, {} left", e.label(), format_duration(age), format_duration(left)))
+            })
+            .unzip();
+        if ids.is_empty() {
+            self.close_undo_list();
+            return;
+        }
+        if let Some(list) = self.undo_list.as_mut() {
+            list.selected = list.selected.min(ids.len() - 1);
+            list.ids = ids;
+            list.labels = labels;
+        }
+    }
+
+    pub(super) fn undo_list_move(&mut self, forward: bool) {
+        let Some(list) = &mut self.undo_list else { return };
+        let len = list.ids.len();
+        if len == 0 {
+            return;
+        }
+        list.selected = if forward { (list.selected + 1) % len } else { (list.selected + len - 1) % len };
+    }
+
+    pub(super) fn confirm_undo_list(&mut self) {
+        let Some(list) = self.undo_list.take() else { return };
+        self.dispatcher.set_mode(InputMode::Normal);
+        if let Some(&id) = list.ids.get(list.selected) {
+            self.request_restore(id);
+        }
+    }
+
+    pub(super) fn close_undo_list(&mut self) {
+        self.undo_list = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    fn request_restore(&mut self, id: u64) {
+        let Some(entry) = self.trash.get(id) else { return };
+        if entry.manifest.is_none() {
+            self.toasts.push(ToastMessage::error(format!("No manifest was kept for {}", entry.label())));
+            return;
+        }
+        let current = self.context_resolver.context_name().unwrap_or_default();
+        if entry.context != current {
+            let message =
+                format!("{} was deleted in context {}; switch to it to restore it", entry.label(), entry.context);
+            self.toasts.push(ToastMessage::error(message));
+            return;
+        }
+        let (label, name) = (entry.label(), entry.name.clone());
+        let message = format!("Recreate {label}\nfrom the manifest it had before the delete?");
+        self.request_confirmation("undo", &name, message, PendingAction::UndoDelete { id });
+    }
+
+    /// Creates the deleted resource again; it leaves the trash once that worked.
+    pub(super) fn run_restore(&mut self, id: u64) {
+        let Some(client) = self.kube_client.as_ref().map(|c| c.inner_client()) else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let Some(manifest) = self.trash.get(id).and_then(|e| e.manifest.clone()) else {
+            self.toasts.push(ToastMessage::info("That delete is no longer in the trash"));
+            return;
+        };
+        let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+        tokio::spawn(async move {
+            let result = recreate(&client, &manifest)
+                .await
+                .inspect_err(|e| report_auth_expired(&app_tx, &auth_context, e.as_ref()))
+                .map_err(|e| format!("{e:#}"));
+            let _ = app_tx.send(AppEvent::ResourceRestored { id, result });
+        });
+    }
+
+    pub(super) fn handle_resource_restored(&mut self, id: u64, result: Result<(), String>) {
+        let Some(label) = self.trash.get(id).map(TrashEntry::label) else { return };
+        match result {
+            Ok(()) => {
+                self.trash.remove(id);
+                self.refresh_trash_panes();
+                self.toasts.push(ToastMessage::success(format!("Recreated {label}")));
+            }
+            Err(e) => self.toasts.push(ToastMessage::error(format!("Failed to recreate {label}: {e}"))),
+        }
+    }
+
+    /// The trash entry selected in the focused pane, when that is the trash pane.
+    fn selected_trash_id(&self) -> Option<u64> {
+        let focused = self.tab_manager.active().focused_pane;
+        self.panes.get(&focused)?.as_any().downcast_ref::<TrashPane>()?.selected_id()
+    }
+
+    pub(super) fn trash_focused(&self) -> bool {
+        let focused = self.tab_manager.active().focused_pane;
+        self.panes.get(&focused).is_some_and(|p| matches!(p.view_type(), ViewType::Plugin(name) if name == "Trash"))
+    }
+
+    pub(super) fn restore_selected_trash(&mut self) {
+        if let Some(id) = self.selected_trash_id() {
+            self.request_restore(id);
+        }
+    }
+
+    /// Forgets the selected delete for good.
+    pub(super) fn purge_selected_trash(&mut self) {
+        let Some(entry) = self.selected_trash_id().and_then(|id| self.trash.remove(id)) else { return };
+        self.refresh_trash_panes();
+        self.toasts.push(ToastMessage::info(format!("Purged {} from the trash", entry.label())));
+    }
+
+    /// Opens the manifest kept for the selected delete.
+    pub(super) fn view_trashed_manifest(&mut self) {
+        let Some(entry) = self.selected_trash_id().and_then(|id| self.trash.get(id)) else { return };
+        let Some(manifest) = entry.manifest.clone() else {
+            self.toasts.push(ToastMessage::info(format!("No manifest was kept for {}", entry.label())));
+            return;
+        };
+        let (kind, name) = (entry.kind.clone(), entry.name.clone());
+        let focused = self.tab_manager.active().focused_pane;
+        self.open_yaml_pane(focused, kind, name, manifest);
+    }
+
+    pub(super) fn refresh_trash_panes(&mut self) {
+        let rows: Vec<TrashRow> = self
+            .trash
+            .entries
+            .iter()
+            .map(|e| TrashRow {
+                id: e.id,
+                time: e.time.to_zoned(self.display_timezone.clone()).strftime("%H:%M:%S").to_string(),
+                kind: e.kind.short_name().to_string(),
+                namespace: e.namespace.clone(),
+                name: e.name.clone(),
+                context: e.context.clone(),
+                restorable: e.manifest.is_some(),
+            })
+            .collect();
+        for pane in self.panes.values_mut() {
+            if let Some(trash) = pane.as_any_mut().downcast_mut::<TrashPane>() {
+                trash.set_items(rows.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use kubetile_core::resource::DetailSection;
+use kubetile_core::usages::{find_usages, usage_sections};
+use kubetile_tui::pane::{ResourceKind, ViewType};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceDetailPane;
+
+use super::App;
+
+impl App {
+    /// Opens the pods and workloads referencing the focused ConfigMap or Secret in a detail pane,
+    /// through volumes, `env`, `envFrom` or image pull secrets.
+    pub(super) fn find_usages(&mut self) {
+        let Some((kind, name, namespace)) = self.related_subject() else { return };
+        let core_kind = match kind {
+            ResourceKind::ConfigMaps => kubetile_core::ResourceKind::ConfigMaps,
+            ResourceKind::Secrets => kubetile_core::ResourceKind::Secrets,
+            _ => {
+                self.toasts.push(ToastMessage::info("Usages are listed for ConfigMaps and Secrets"));
+                return;
+            }
+        };
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+
+        let loading = DetailSection { title: "Usages".into(), fields: vec![("Loading".into(), "…".into())] };
+        let detail = ResourceDetailPane::new(kind.clone(), name.clone(), Some(namespace.clone()), vec![loading]);
+        let focused = self.tab_manager.active().focused_pane;
+        let view = ViewType::Detail(kind, name.clone());
+        let Some(pane_id) = self.split_auto_opened(focused, view, self.general_config.splits.detail, 0.5) else {
+            return;
+        };
+        self.panes.insert(pane_id, Box::new(detail));
+        self.set_focus(pane_id);
+
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let event = match find_usages(kube_client, &core_kind, &name, &namespace).await {
+                Ok(usages) => AppEvent::DetailSectionsReady { pane_id, sections: usage_sections(&usages) },
+                Err(e) => AppEvent::Toast(ToastMessage::error(format!("Could not find usages of {name}: {e}"))),
+            };
+            let _ = app_tx.send(event);
+        });
+    }
+}
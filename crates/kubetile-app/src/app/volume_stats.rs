@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use kube::Client;
+use tokio_util::sync::CancellationToken;
+
+use kubetile_core::resource::DetailSection;
+use kubetile_core::volume_stats::{fetch_pvc_usage, format_bytes};
+use kubetile_core::{VolumeUsage, VolumeUsageMap};
+use kubetile_tui::pane::{PaneId, ResourceKind};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
+
+use super::pane_ops::{find_item_index_by_identity, selected_resource_identity};
+use super::App;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+const USED_HEADER: &str = "USED";
+const PERCENT_HEADER: &str = "USE%";
+
+impl App {
+    /// Polls kubelet volume stats for as long as the PVC list in `pane_id` keeps its watcher.
+    pub(super) fn start_volume_stats_poller(&mut self, pane_id: PaneId, watcher_seq: u64, client: Client) {
+        let cancel = CancellationToken::new();
+        self.volume_stats_pollers.insert(pane_id, cancel.clone().drop_guard());
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            loop {
+                let result = fetch_pvc_usage(client.clone()).await.map_err(|e| e.to_string());
+                let failed = result.is_err();
+                if app_tx.send(AppEvent::VolumeUsageReady { pane_id, watcher_seq, result }).is_err() || failed {
+                    return;
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {}
+                    _ = cancel.cancelled() => return,
+                }
+            }
+        });
+    }
+
+    pub(super) fn handle_volume_usage_ready(&mut self, pane_id: PaneId, result: Result<VolumeUsageMap, String>) {
+        let usage = match result {
+            Ok(usage) => usage,
+            Err(e) => {
+                self.volume_stats_pollers.remove(&pane_id);
+                self.toasts.push(ToastMessage::error(format!("Volume stats unavailable: {e}")));
+                return;
+            }
+        };
+        self.volume_usage = usage;
+
+        let Some(pane) = self.panes.get_mut(&pane_id) else { return };
+        let Some(resource_pane) = pane.as_any_mut().downcast_mut::<ResourceListPane>() else { return };
+        let headers = &resource_pane.state.headers;
+        let used_idx = headers.iter().position(|h| h == USED_HEADER);
+        let percent_idx = headers.iter().position(|h| h == PERCENT_HEADER);
+        if used_idx.is_none() && percent_idx.is_none() {
+            return;
+        }
+
+        for row in &mut resource_pane.state.items {
+            let [used, percent] = usage_cells(&self.volume_usage, &resource_pane.state.headers, row);
+            for (idx, value) in [(used_idx, used), (percent_idx, percent)] {
+                if let Some(cell) = idx.and_then(|i| row.get_mut(i)) {
+                    *cell = value;
+                }
+            }
+        }
+
+        let previous_selected_resource = selected_resource_identity(resource_pane);
+        resource_pane.refresh_filter_and_sort();
+        if let Some((name, namespace)) = previous_selected_resource {
+            if let Some(item_idx) =
+                find_item_index_by_identity(&resource_pane.state.headers, &resource_pane.state.items, &name, &namespace)
+            {
+                let _ = resource_pane.select_item_index(item_idx);
+            }
+        }
+    }
+
+    pub(super) fn volume_usage_section(
+        &self,
+        kind: &ResourceKind,
+        name: &str,
+        namespace: &str,
+    ) -> Option<DetailSection> {
+        if *kind != ResourceKind::PersistentVolumeClaims || !self.features.volume_stats {
+            return None;
+        }
+        let usage = self.volume_usage.get(&(namespace.to_string(), name.to_string()))?;
+        Some(DetailSection {
+            title: "Usage".into(),
+            fields: vec![
+                ("Capacity".into(), format_bytes(usage.capacity_bytes)),
+                ("Used".into(), format_bytes(usage.used_bytes)),
+                ("Use%".into(), format_percent(usage)),
+            ],
+        })
+    }
+}
+
+/// Appends USED and USE% to PVC rows fresh from the watcher, before view columns are applied.
+pub(super) fn append_volume_usage(usage: &VolumeUsageMap, headers: &mut Vec<String>, rows: &mut [Vec<String>]) {
+    if headers.is_empty() || headers.iter().any(|h| h == USED_HEADER) {
+        return;
+    }
+    for row in rows.iter_mut() {
+        let cells = usage_cells(usage, headers, row);
+        row.extend(cells);
+    }
+    headers.extend([USED_HEADER.to_string(), PERCENT_HEADER.to_string()]);
+}
+
+fn usage_cells(usage: &VolumeUsageMap, headers: &[String], row: &[String]) -> [String; 2] {
+    let name = super::header_value(headers, row, "NAME", 0).unwrap_or_default();
+    let namespace = super::header_value(headers, row, "NAMESPACE", usize::MAX).unwrap_or_default();
+    match usage.get(&(namespace, name)) {
+        Some(u) => [format_bytes(u.used_bytes), format_percent(u)],
+        None => [String::new(), String::new()],
+    }
+}
+
+fn format_percent(usage: &VolumeUsage) -> String {
+    usage.percent().map(|p| format!("{p}%")).unwrap_or_default()
+}
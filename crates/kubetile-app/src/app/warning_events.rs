@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use k8s_openapi::api::core::v1::Event;
+use kube::Api;
+use tokio::sync::mpsc;
+
+use kubetile_config::WarningEventsDisplay;
+use kubetile_core::{WarningNotice, WarningWatcher};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+
+use super::App;
+
+/// How long a warning stays on the ticker.
+const TICKER_TTL: Duration = Duration::from_secs(300);
+/// Warnings the ticker shows at once; older ones drop off first.
+const TICKER_MAX: usize = 5;
+/// How often the ticker scrolls one character, independent of the tick rate.
+const TICKER_SCROLL: Duration = Duration::from_millis(500);
+
+/// Warning event watchers for the scope they were started for.
+pub(super) struct WarningWatch {
+    /// Context followed by the watched namespaces.
+    scope: Vec<String>,
+    _watchers: Vec<WarningWatcher>,
+}
+
+/// Recent warnings scrolling through the status bar, newest first.
+#[derive(Default)]
+pub(super) struct WarningTicker {
+    entries: VecDeque<(Instant, String)>,
+    text: String,
+    pub(super) offset: usize,
+    scrolled_at: Option<Instant>,
+}
+
+impl WarningTicker {
+    fn push(&mut self, line: String) {
+        self.entries.push_front((Instant::now(), line));
+        self.entries.truncate(TICKER_MAX);
+        self.rebuild();
+    }
+
+    /// Drops expired warnings and scrolls the ticker one character once its scroll step is due.
+    /// Returns whether what the ticker shows changed.
+    fn tick(&mut self) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|(at, _)| at.elapsed() < TICKER_TTL);
+        let expired = self.entries.len() != before;
+        if expired {
+            self.rebuild();
+        }
+        if self.entries.is_empty() {
+            self.offset = 0;
+            self.scrolled_at = None;
+            return expired;
+        }
+        if self.scrolled_at.is_some_and(|at| at.elapsed() < TICKER_SCROLL) {
+            return expired;
+        }
+        self.scrolled_at = Some(Instant::now());
+        self.offset = self.offset.wrapping_add(1);
+        true
+    }
+
+    fn rebuild(&mut self) {
+        self.text = self.entries.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>().join("  •  ");
+    }
+
+    pub(super) fn text(&self) -> Option<&str> {
+        Some(self.text.as_str()).filter(|t| !t.is_empty())
+    }
+}
+
+impl App {
+    /// Keeps the Warning event watch on the active tab's context and namespaces, starting,
+    /// restarting or stopping it as they change.
+    pub(super) fn sync_warning_watch(&mut self) {
+        let config = &self.general_config.warning_events;
+        let Some(client) = self.kube_client.as_ref().filter(|_| config.enabled) else {
+            if self.warning_watch.take().is_some() {
+                self.warning_watch_seq += 1;
+            }
+            return;
+        };
+        let namespaces: Vec<String> = self.context_resolver.namespaces().into_iter().map(String::from).collect();
+        let mut scope = vec![self.context_resolver.context_name().unwrap_or_default().to_string()];
+        scope.extend(namespaces.iter().cloned());
+        if self.warning_watch.as_ref().is_some_and(|w| w.scope == scope) {
+            return;
+        }
+
+        self.warning_watch_seq += 1;
+        let seq = self.warning_watch_seq;
+        self.warning_ticker = WarningTicker::default();
+        let (tx, mut rx) = mpsc::channel(16);
+        let watchers = if namespaces.is_empty() {
+            vec![WarningWatcher::watch(Api::<Event>::all(client.inner_client()), tx)]
+        } else {
+            namespaces
+                .iter()
+                .map(|ns| WarningWatcher::watch(Api::<Event>::namespaced(client.inner_client(), ns), tx.clone()))
+                .collect()
+        };
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            while let Some(notice) = rx.recv().await {
+                if app_tx.send(AppEvent::WarningEvent { seq, notice }).is_err() {
+                    break;
+                }
+            }
+        });
+        self.warning_watch = Some(WarningWatch { scope, _watchers: watchers });
+    }
+
+    pub(super) fn handle_warning_event(&mut self, seq: u64, notice: WarningNotice) {
+        if seq != self.warning_watch_seq {
+            return;
+        }
+        let config = &self.general_config.warning_events;
+        if !config.shows_reason(&notice.reason) {
+            return;
+        }
+        match config.display {
+            WarningEventsDisplay::Ticker => self.warning_ticker.push(notice.describe()),
+            WarningEventsDisplay::Toast => {
+                self.toasts.push(ToastMessage::error(format!(
+                    "Warning in {}: {}",
+                    notice.namespace,
+                    notice.describe()
+                )));
+            }
+        }
+    }
+
+    /// Returns whether the ticker needs a redraw.
+    pub(super) fn tick_warning_ticker(&mut self) -> bool {
+        self.sync_warning_watch();
+        self.warning_ticker.tick()
+    }
+}
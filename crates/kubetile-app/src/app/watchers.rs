@@ -1,9 +1,14 @@
+use std::fmt::Debug;
+
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
 };
-use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::serde::de::DeserializeOwned;
 use kube::Api;
 use tokio::sync::mpsc;
 
@@ -11,14 +16,37 @@ use kubetile_core::informer::{ResourceEvent, ResourceWatcher};
 use kubetile_core::resource::ResourceSummary;
 use kubetile_core::*;
 use kubetile_tui::pane::{PaneId, ResourceKind};
+use kubetile_tui::widgets::toast::ToastMessage;
 
 use crate::event::AppEvent;
+use crate::panes::ResourceListPane;
 
+use super::pane_ops::selected_resource_identity;
 use super::App;
 
 impl App {
-    pub(super) fn start_watcher_for_pane(&mut self, pane_id: PaneId, kind: &ResourceKind, namespace: &str) {
+    /// Namespaces a `kind` list watches: none, meaning every namespace, for cluster-scoped kinds and
+    /// all-namespaces panes, otherwise the namespaces picked in the namespace selector.
+    pub(super) fn watch_namespaces(&self, kind: &ResourceKind, all_namespaces: bool) -> Vec<String> {
+        if !kind.is_namespaced() || all_namespaces {
+            return Vec::new();
+        }
+        let selected = self.context_resolver.namespaces();
+        if selected.is_empty() {
+            vec!["default".into()]
+        } else {
+            selected.into_iter().map(String::from).collect()
+        }
+    }
+
+    /// Starts the watcher behind a list pane. An empty `namespaces` watches every namespace; several
+    /// namespaces get one watch each, merged into a single list. A scoped pane ignores `namespaces`
+    /// and watches its own namespace and label selector. A pane's field selector applies to every
+    /// namespace or a single one, not to merged watches.
+    pub(super) fn start_watcher_for_pane(&mut self, pane_id: PaneId, kind: &ResourceKind, namespaces: &[String]) {
         self.active_watchers.remove(&pane_id);
+        self.volume_stats_pollers.remove(&pane_id);
+        self.plugin_column_cache.remove(&pane_id);
         let watcher_seq = self.watcher_seq_by_pane.get(&pane_id).copied().unwrap_or(0).wrapping_add(1);
         self.watcher_seq_by_pane.insert(pane_id, watcher_seq);
 
@@ -28,29 +56,63 @@ impl App {
 
         let kube_client = client.inner_client();
         let app_tx = self.app_tx.clone();
+        let auth_context = self.context_resolver.context_name().unwrap_or_default().to_string();
+
+        let scope = self
+            .panes
+            .get(&pane_id)
+            .and_then(|p| p.as_any().downcast_ref::<ResourceListPane>())
+            .and_then(|rp| rp.scope.clone());
+        let scoped_namespace: Vec<String>;
+        let namespaces = match &scope {
+            Some(scope) if kind.is_namespaced() => {
+                scoped_namespace = vec![scope.namespace.clone()];
+                &scoped_namespace[..]
+            }
+            _ => namespaces,
+        };
+        let label_selector = scope.and_then(|s| s.label_selector);
+        let field_selector = self
+            .panes
+            .get(&pane_id)
+            .and_then(|p| p.as_any().downcast_ref::<ResourceListPane>())
+            .and_then(|rp| rp.field_selector.clone());
 
         fn spawn_bridge<S>(
             pane_id: PaneId,
             watcher_seq: u64,
             mut rx: mpsc::Receiver<ResourceEvent<S>>,
             app_tx: mpsc::UnboundedSender<AppEvent>,
+            context: String,
         ) where
             S: ResourceSummary + 'static,
         {
             tokio::spawn(async move {
                 while let Some(event) = rx.recv().await {
-                    let app_event = match event {
-                        ResourceEvent::Updated(items) => {
-                            let headers = if items.is_empty() {
-                                vec![]
-                            } else {
-                                items[0].columns().into_iter().map(|(h, _)| h.to_string()).collect()
-                            };
-                            let rows = items.iter().map(|item| item.row()).collect();
-                            AppEvent::ResourceUpdate { pane_id, watcher_seq, headers, rows }
+                    let (items, partial, expected) = match event {
+                        ResourceEvent::Updated(items) => (items, false, None),
+                        ResourceEvent::Loading { items, expected } => (items, true, expected),
+                        ResourceEvent::Error(error) => {
+                            if app_tx.send(AppEvent::ResourceError { pane_id, watcher_seq, error }).is_err() {
+                                break;
+                            }
+                            continue;
+                        }
+                        ResourceEvent::Unauthorized(error) => {
+                            let _ = app_tx.send(AppEvent::ResourceError { pane_id, watcher_seq, error: error.clone() });
+                            if app_tx.send(AppEvent::AuthExpired { context: context.clone(), error }).is_err() {
+                                break;
+                            }
+                            continue;
                         }
-                        ResourceEvent::Error(error) => AppEvent::ResourceError { pane_id, watcher_seq, error },
                     };
+                    let headers = if items.is_empty() {
+                        vec![]
+                    } else {
+                        items[0].columns().into_iter().map(|(h, _)| h.to_string()).collect()
+                    };
+                    let rows = items.iter().map(|item| item.row()).collect();
+                    let app_event = AppEvent::ResourceUpdate { pane_id, watcher_seq, headers, rows, partial, expected };
                     if app_tx.send(app_event).is_err() {
                         break;
                     }
@@ -58,29 +120,54 @@ impl App {
             });
         }
 
-        let all_ns = namespace.is_empty();
+        let page_size = self.general_config.list_page_size;
 
         macro_rules! spawn_watcher {
             ($k8s_type:ty, $summary_type:ty) => {{
-                let api: Api<$k8s_type> = if all_ns {
-                    Api::all(kube_client.clone())
-                } else {
-                    Api::namespaced(kube_client.clone(), namespace)
-                };
                 let (tx, rx) = mpsc::channel(16);
-                let watcher = ResourceWatcher::watch::<$k8s_type, $summary_type>(api, tx);
+                let watcher = match namespaces {
+                    [] => ResourceWatcher::watch_with_selectors::<$k8s_type, $summary_type>(
+                        Api::all(kube_client.clone()),
+                        tx,
+                        page_size,
+                        None,
+                        field_selector.as_deref(),
+                    ),
+                    [namespace] => ResourceWatcher::watch_with_selectors::<$k8s_type, $summary_type>(
+                        Api::namespaced(kube_client.clone(), namespace),
+                        tx,
+                        page_size,
+                        label_selector.as_deref(),
+                        field_selector.as_deref(),
+                    ),
+                    _ => ResourceWatcher::watch_merged::<$k8s_type, $summary_type>(
+                        namespaces.iter().map(|ns| (ns.clone(), Api::namespaced(kube_client.clone(), ns))).collect(),
+                        tx,
+                        page_size,
+                    ),
+                };
                 self.active_watchers.insert(pane_id, watcher);
-                spawn_bridge(pane_id, watcher_seq, rx, app_tx);
+                spawn_bridge(pane_id, watcher_seq, rx, app_tx, auth_context);
             }};
             (cluster $k8s_type:ty, $summary_type:ty) => {{
                 let api: Api<$k8s_type> = Api::all(kube_client.clone());
                 let (tx, rx) = mpsc::channel(16);
-                let watcher = ResourceWatcher::watch::<$k8s_type, $summary_type>(api, tx);
+                let watcher = ResourceWatcher::watch_with_page_size::<$k8s_type, $summary_type>(api, tx, page_size);
                 self.active_watchers.insert(pane_id, watcher);
-                spawn_bridge(pane_id, watcher_seq, rx, app_tx);
+                spawn_bridge(pane_id, watcher_seq, rx, app_tx, auth_context);
             }};
         }
 
+        let access_kinds = if *kind == ResourceKind::Workloads { workload_kinds() } else { vec![kind.clone()] };
+        for kind in &access_kinds {
+            if namespaces.is_empty() {
+                self.prefetch_mutate_access(kind, "");
+            }
+            for namespace in namespaces {
+                self.prefetch_mutate_access(kind, namespace);
+            }
+        }
+
         match kind {
             ResourceKind::Pods => spawn_watcher!(Pod, PodSummary),
             ResourceKind::Deployments => spawn_watcher!(Deployment, DeploymentSummary),
@@ -96,11 +183,158 @@ impl App {
             ResourceKind::Namespaces => spawn_watcher!(cluster Namespace, NamespaceSummary),
             ResourceKind::PersistentVolumes => spawn_watcher!(cluster PersistentVolume, PersistentVolumeSummary),
             ResourceKind::PersistentVolumeClaims => {
+                if self.features.volume_stats {
+                    self.start_volume_stats_poller(pane_id, watcher_seq, kube_client.clone());
+                }
                 spawn_watcher!(PersistentVolumeClaim, PersistentVolumeClaimSummary)
             }
+            ResourceKind::NetworkPolicies => spawn_watcher!(NetworkPolicy, NetworkPolicySummary),
+            ResourceKind::ResourceQuotas => spawn_watcher!(ResourceQuota, ResourceQuotaSummary),
+            ResourceKind::LimitRanges => spawn_watcher!(LimitRange, LimitRangeSummary),
+            ResourceKind::PodDisruptionBudgets => spawn_watcher!(PodDisruptionBudget, PodDisruptionBudgetSummary),
+            ResourceKind::Workloads => {
+                // One watch per workload kind and namespace, merged into a single list. A kind that
+                // cannot be listed is reported by name while the others still show.
+                let mut parts = Vec::new();
+                macro_rules! watch_part {
+                    ($k8s_type:ty, $kind:expr) => {{
+                        let kind: ResourceKind = $kind;
+                        let label = kind.display_name();
+                        let apis: Vec<(String, Api<$k8s_type>)> = match namespaces {
+                            [] => vec![(label.to_string(), Api::all(kube_client.clone()))],
+                            [namespace] => vec![(label.to_string(), Api::namespaced(kube_client.clone(), namespace))],
+                            _ => namespaces
+                                .iter()
+                                .map(|ns| (format!("{label} in {ns}"), Api::namespaced(kube_client.clone(), ns)))
+                                .collect(),
+                        };
+                        for (label, api) in apis {
+                            let (part_tx, part_rx) = mpsc::channel(16);
+                            let watcher = ResourceWatcher::watch_with_page_size::<$k8s_type, WorkloadSummary>(
+                                api, part_tx, page_size,
+                            );
+                            parts.push((label, watcher, part_rx));
+                        }
+                    }};
+                }
+                watch_part!(Deployment, ResourceKind::Deployments);
+                watch_part!(StatefulSet, ResourceKind::StatefulSets);
+                watch_part!(DaemonSet, ResourceKind::DaemonSets);
+                watch_part!(Job, ResourceKind::Jobs);
+                watch_part!(CronJob, ResourceKind::CronJobs);
+
+                let (tx, rx) = mpsc::channel(16);
+                self.active_watchers.insert(pane_id, ResourceWatcher::merge(parts, tx));
+                spawn_bridge(pane_id, watcher_seq, rx, app_tx, auth_context);
+            }
             ResourceKind::Custom(_) => {
                 tracing::warn!("Custom resource kinds are not yet supported");
             }
         }
     }
+
+    /// Re-fetches the selected object with a GET and patches its row in place, without touching the watcher.
+    pub(super) fn refresh_selected_row(&mut self) {
+        let pane_id = self.tab_manager.active().focused_pane;
+        let Some(list) = self.panes.get(&pane_id).and_then(|pane| pane.as_any().downcast_ref::<ResourceListPane>())
+        else {
+            return;
+        };
+        let Some(identity) = selected_resource_identity(list) else { return };
+        // Rows of the workloads list are refreshed in its own row format.
+        let workloads = list.kind() == Some(&ResourceKind::Workloads);
+        let Some((kind, name, namespace)) = self.selected_resource_info() else { return };
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+
+        let kube_client = client.inner_client();
+        let app_tx = self.app_tx.clone();
+        let watcher_seq = self.watcher_seq_by_pane.get(&pane_id).copied().unwrap_or(0);
+
+        macro_rules! spawn_refresh {
+            ($k8s_type:ty, $summary_type:ty) => {{
+                let api: Api<$k8s_type> = Api::namespaced(kube_client, &namespace);
+                spawn_row_refresh::<$k8s_type, $summary_type>(api, pane_id, watcher_seq, name, identity, app_tx);
+            }};
+            (cluster $k8s_type:ty, $summary_type:ty) => {{
+                let api: Api<$k8s_type> = Api::all(kube_client);
+                spawn_row_refresh::<$k8s_type, $summary_type>(api, pane_id, watcher_seq, name, identity, app_tx);
+            }};
+        }
+
+        match kind {
+            ResourceKind::Deployments if workloads => spawn_refresh!(Deployment, WorkloadSummary),
+            ResourceKind::StatefulSets if workloads => spawn_refresh!(StatefulSet, WorkloadSummary),
+            ResourceKind::DaemonSets if workloads => spawn_refresh!(DaemonSet, WorkloadSummary),
+            ResourceKind::Jobs if workloads => spawn_refresh!(Job, WorkloadSummary),
+            ResourceKind::CronJobs if workloads => spawn_refresh!(CronJob, WorkloadSummary),
+            ResourceKind::Pods => spawn_refresh!(Pod, PodSummary),
+            ResourceKind::Deployments => spawn_refresh!(Deployment, DeploymentSummary),
+            ResourceKind::Services => spawn_refresh!(Service, ServiceSummary),
+            ResourceKind::StatefulSets => spawn_refresh!(StatefulSet, StatefulSetSummary),
+            ResourceKind::DaemonSets => spawn_refresh!(DaemonSet, DaemonSetSummary),
+            ResourceKind::Jobs => spawn_refresh!(Job, JobSummary),
+            ResourceKind::CronJobs => spawn_refresh!(CronJob, CronJobSummary),
+            ResourceKind::ConfigMaps => spawn_refresh!(ConfigMap, ConfigMapSummary),
+            ResourceKind::Secrets => spawn_refresh!(Secret, SecretSummary),
+            ResourceKind::Ingresses => spawn_refresh!(Ingress, IngressSummary),
+            ResourceKind::Nodes => spawn_refresh!(cluster Node, NodeSummary),
+            ResourceKind::Namespaces => spawn_refresh!(cluster Namespace, NamespaceSummary),
+            ResourceKind::PersistentVolumes => spawn_refresh!(cluster PersistentVolume, PersistentVolumeSummary),
+            ResourceKind::PersistentVolumeClaims => {
+                spawn_refresh!(PersistentVolumeClaim, PersistentVolumeClaimSummary)
+            }
+            ResourceKind::NetworkPolicies => spawn_refresh!(NetworkPolicy, NetworkPolicySummary),
+            ResourceKind::ResourceQuotas => spawn_refresh!(ResourceQuota, ResourceQuotaSummary),
+            ResourceKind::LimitRanges => spawn_refresh!(LimitRange, LimitRangeSummary),
+            ResourceKind::PodDisruptionBudgets => spawn_refresh!(PodDisruptionBudget, PodDisruptionBudgetSummary),
+            // The selected row resolves to its own kind.
+            ResourceKind::Workloads => {}
+            ResourceKind::Custom(_) => {
+                self.toasts.push(ToastMessage::info("Refresh is not supported for custom resources"));
+            }
+        }
+    }
+}
+
+fn spawn_row_refresh<K, S>(
+    api: Api<K>,
+    pane_id: PaneId,
+    watcher_seq: u64,
+    name: String,
+    identity: (String, String),
+    app_tx: mpsc::UnboundedSender<AppEvent>,
+) where
+    K: kube::Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    S: ResourceSummary + From<K> + 'static,
+{
+    tokio::spawn(async move {
+        let event = match api.get_opt(&name).await {
+            Ok(obj) => {
+                let summary = obj.map(S::from);
+                let headers = summary
+                    .as_ref()
+                    .map(|s| s.columns().into_iter().map(|(h, _)| h.to_string()).collect())
+                    .unwrap_or_default();
+                let (name, namespace) = identity;
+                AppEvent::ResourceRowRefreshed {
+                    pane_id,
+                    watcher_seq,
+                    name,
+                    namespace,
+                    headers,
+                    row: summary.map(|s| s.row()),
+                }
+            }
+            Err(e) => AppEvent::Toast(ToastMessage::error(format!("Refresh of {name} failed: {e}"))),
+        };
+        let _ = app_tx.send(event);
+    });
+}
+
+/// The kinds the workloads list watches.
+fn workload_kinds() -> Vec<ResourceKind> {
+    WORKLOAD_KINDS.iter().filter_map(|kind| ResourceKind::from_short_name(kind.short_name())).collect()
 }
@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use kubetile_config::AppConfig;
+use kubetile_tui::pane::ResourceKind;
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::command::InputMode;
+use crate::keybindings::KeybindingDispatcher;
+use crate::panes::ResourceListPane;
+
+use super::App;
+
+/// Shortcuts the first-run overlay lists, in order.
+const WELCOME_HELP: &[&str] = &[
+    "help",
+    "show_pane_help",
+    "resource_switcher",
+    "filter",
+    "toggle_all_namespaces",
+    "namespace_selector",
+    "context_selector",
+    "split_vertical",
+    "focus_next",
+    "close_pane",
+    "quit",
+];
+/// Shown under an empty resource list.
+const EMPTY_LIST_HINT: &[(&str, &str)] = &[
+    ("resource_switcher", "to switch resource"),
+    ("filter", "to filter"),
+    ("toggle_all_namespaces", "for all namespaces"),
+];
+/// Shown in a freshly split pane.
+const EMPTY_PANE_HINT: &[(&str, &str)] =
+    &[("resource_switcher", "to open a resource list here"), ("close_pane", "to close it")];
+
+/// First-run overlay: the core shortcuts and where the default config would be written.
+pub(super) struct Welcome {
+    pub(super) entries: Vec<(String, String)>,
+    pub(super) config_path: PathBuf,
+    pub(super) display_path: String,
+}
+
+/// Key hints for empty panes, built from the keymap so they name the keys actually bound.
+pub(super) struct EmptyHints {
+    pub(super) list: String,
+    pub(super) pane: String,
+}
+
+impl EmptyHints {
+    pub(super) fn new(dispatcher: &KeybindingDispatcher) -> Self {
+        Self { list: key_hint(dispatcher, EMPTY_LIST_HINT), pane: key_hint(dispatcher, EMPTY_PANE_HINT) }
+    }
+}
+
+/// "Press : to switch resource, / to filter", skipping unbound actions; empty when none is bound.
+fn key_hint(dispatcher: &KeybindingDispatcher, parts: &[(&str, &str)]) -> String {
+    let parts: Vec<String> = parts
+        .iter()
+        .filter_map(|(action, what)| dispatcher.key_for(action).map(|key| format!("{key} {what}")))
+        .collect();
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!("Press {}", parts.join(", "))
+    }
+}
+
+impl App {
+    /// Shows the first-run overlay, offering to write the default config to `config_path`.
+    pub fn show_welcome(&mut self, config_path: PathBuf) {
+        self.welcome = Some(Welcome {
+            entries: self.dispatcher.shortcuts_named(WELCOME_HELP),
+            display_path: config_path.display().to_string(),
+            config_path,
+        });
+        self.dispatcher.set_mode(InputMode::Welcome);
+    }
+
+    pub(super) fn close_welcome(&mut self) {
+        self.welcome = None;
+        self.dispatcher.set_mode(InputMode::Normal);
+    }
+
+    pub(super) fn write_default_config(&mut self) {
+        let Some(welcome) = self.welcome.as_ref() else { return };
+        match AppConfig::write_default(&welcome.config_path) {
+            Ok(()) => {
+                self.toasts
+                    .push(ToastMessage::success(format!("Wrote the default config to {}", welcome.display_path)));
+                self.close_welcome();
+            }
+            Err(e) => self.toasts.push(ToastMessage::error(format!("Cannot write the config: {e}"))),
+        }
+    }
+
+    /// A resource list pane carrying the empty-list key hint.
+    pub(super) fn new_list_pane(&self, kind: ResourceKind, headers: Vec<String>) -> ResourceListPane {
+        let mut pane = ResourceListPane::new(kind, headers);
+        pane.empty_hint = self.empty_hints.list.clone();
+        pane
+    }
+}
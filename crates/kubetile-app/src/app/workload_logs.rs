@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use tokio_util::sync::CancellationToken;
+
+use kubetile_core::related::label_selector_string;
+use kubetile_tui::pane::{PaneId, ResourceKind};
+use kubetile_tui::widgets::toast::ToastMessage;
+
+use crate::event::AppEvent;
+use crate::panes::LogsPane;
+
+use super::App;
+
+const POD_POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Lines fetched from each pod found when the pane opens; pods that appear later stream from their start.
+const INITIAL_TAIL_LINES: i64 = 100;
+
+impl App {
+    pub(super) fn open_workload_logs_pane(&mut self, kind: ResourceKind, name: String, namespace: String) {
+        let Some(client) = &self.kube_client else {
+            self.toasts.push(ToastMessage::error("No cluster connection"));
+            return;
+        };
+        let kube_client = client.inner_client();
+        let alias = match kind {
+            ResourceKind::StatefulSets => "sts",
+            ResourceKind::DaemonSets => "ds",
+            _ => "deploy",
+        };
+
+        let cancel = CancellationToken::new();
+        let pane = LogsPane::for_workload(format!("{alias}/{name}"), namespace.clone(), cancel.clone().drop_guard());
+        let Some(pane_id) = self.place_logs_pane(pane) else { return };
+
+        let app_tx = self.app_tx.clone();
+        tokio::spawn(async move {
+            let selector = match workload_selector(kube_client.clone(), &kind, &name, &namespace).await {
+                Ok(selector) => selector,
+                Err(error) => {
+                    let _ = app_tx.send(AppEvent::LogsStreamError { pane_id, error });
+                    return;
+                }
+            };
+            let pods: Api<Pod> = Api::namespaced(kube_client, &namespace);
+            let params = ListParams::default().labels(&selector);
+            let mut last: Option<Vec<String>> = None;
+            loop {
+                match pods.list(&params).await {
+                    Ok(list) => {
+                        let mut running: Vec<String> = list
+                            .items
+                            .iter()
+                            .filter(|p| p.metadata.deletion_timestamp.is_none())
+                            .filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running"))
+                            .filter_map(|p| p.metadata.name.clone())
+                            .collect();
+                        running.sort();
+                        if last.as_ref() != Some(&running) {
+                            last = Some(running.clone());
+                            if app_tx.send(AppEvent::WorkloadPodsChanged { pane_id, pods: running }).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Listing pods of {name} failed: {e}"),
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(POD_POLL_INTERVAL) => {}
+                    _ = cancel.cancelled() => return,
+                }
+            }
+        });
+    }
+
+    pub(super) fn handle_workload_pods_changed(&mut self, pane_id: PaneId, pods: Vec<String>) {
+        let Some(logs) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<LogsPane>()) else {
+            return;
+        };
+        let from_start = logs.has_followed_pods();
+        let started = logs.follow_pods(&pods);
+        if logs.is_suspended() {
+            // Resuming starts a stream for every followed pod, new ones included.
+            return;
+        }
+        let namespace = logs.namespace().to_string();
+
+        let context = self.kube_client.as_ref().map(|c| c.context().to_string());
+        let ssh_host = self.general_config.ssh_host_for(context.as_deref()).map(str::to_string);
+        let source = self.log_source(ssh_host.as_deref());
+        for pod in started {
+            let request = kubetile_core::LogRequest {
+                context: context.clone(),
+                ssh_host: ssh_host.clone(),
+                pod_name: pod.clone(),
+                namespace: namespace.clone(),
+                tail_lines: Some(if from_start { -1 } else { INITIAL_TAIL_LINES }),
+                ..Default::default()
+            };
+            let app_tx = self.app_tx.clone();
+            let source = source.clone();
+            tokio::spawn(async move {
+                if let Ok(stream) = kubetile_core::LogStream::start(source, request).await {
+                    let _ = app_tx.send(AppEvent::WorkloadLogStreamReady { pane_id, pod, stream });
+                }
+            });
+        }
+    }
+
+    pub(super) fn attach_workload_log_stream(
+        &mut self,
+        pane_id: PaneId,
+        pod: String,
+        stream: kubetile_core::LogStream,
+    ) {
+        if let Some(logs) = self.panes.get_mut(&pane_id).and_then(|p| p.as_any_mut().downcast_mut::<LogsPane>()) {
+            logs.attach_pod_stream(&pod, stream);
+        }
+    }
+}
+
+pub(super) async fn workload_selector(
+    client: Client,
+    kind: &ResourceKind,
+    name: &str,
+    namespace: &str,
+) -> Result<String, String> {
+    let selector = match kind {
+        ResourceKind::Deployments => {
+            Api::<Deployment>::namespaced(client, namespace).get(name).await.map(|d| d.spec.map(|s| s.selector))
+        }
+        ResourceKind::StatefulSets => {
+            Api::<StatefulSet>::namespaced(client, namespace).get(name).await.map(|s| s.spec.map(|s| s.selector))
+        }
+        ResourceKind::DaemonSets => {
+            Api::<DaemonSet>::namespaced(client, namespace).get(name).await.map(|d| d.spec.map(|s| s.selector))
+        }
+        _ => return Err(format!("{} has no pods to follow", kind.display_name())),
+    }
+    .map_err(|e| e.to_string())?;
+    selector.as_ref().and_then(label_selector_string).ok_or_else(|| format!("{name} has no matchLabels selector"))
+}
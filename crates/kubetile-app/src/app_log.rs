@@ -1,11 +1,18 @@
 use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 
 use tracing_subscriber::fmt::MakeWriter;
 
+/// Tracing filter when `RUST_LOG` is unset: kubetile's own crates (`kubetile`, `kubetile_core`, …)
+/// log at debug so the app log's debug level has lines to show; dependencies stay at info.
+pub const DEFAULT_FILTER: &str = "info,kubetile=debug";
 const MAX_LOG_LINES: usize = 2000;
+/// Rotated files kept next to the app log file (`kubetile.log.1` … `.3`).
+const ROTATED_FILES: usize = 3;
 static LINE_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 #[derive(Clone)]
@@ -15,6 +22,74 @@ struct LogEntry {
 }
 
 static LOG_BUFFER: OnceLock<Arc<Mutex<VecDeque<LogEntry>>>> = OnceLock::new();
+static LOG_FILE: OnceLock<Mutex<LogFile>> = OnceLock::new();
+
+/// A log written line by line and rotated by size: the optional on-disk copy of the app log, or
+/// a logs pane teed to a file.
+pub(crate) struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+}
+
+impl LogFile {
+    pub(crate) fn open(path: PathBuf, max_bytes: u64) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self { path, file, size, max_bytes })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(crate) fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 + 1 > self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.size += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        for n in (1..ROTATED_FILES).rev() {
+            let from = rotated_path(&self.path, n);
+            if from.exists() {
+                fs::rename(&from, rotated_path(&self.path, n + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    PathBuf::from(name)
+}
+
+pub fn default_file_path() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("kubetile")
+        .join("kubetile.log")
+}
+
+/// Also writes every app log line to `path`, rotating it once it grows past `max_bytes`.
+/// Lines logged before this are only kept in memory.
+pub fn enable_file(path: PathBuf, max_bytes: u64) -> io::Result<()> {
+    let file = LogFile::open(path, max_bytes.max(1))?;
+    LOG_FILE.set(Mutex::new(file)).map_err(|_| io::Error::other("app log file already enabled"))
+}
 
 fn buffer() -> Arc<Mutex<VecDeque<LogEntry>>> {
     LOG_BUFFER.get_or_init(|| Arc::new(Mutex::new(VecDeque::with_capacity(MAX_LOG_LINES)))).clone()
@@ -33,6 +108,9 @@ fn commit_line(line: &str) {
     if line.is_empty() || is_suppressed(line) {
         return;
     }
+    if let Some(Ok(mut file)) = LOG_FILE.get().map(|f| f.lock()) {
+        let _ = file.write_line(line);
+    }
     let buf = buffer();
     let Ok(mut guard) = buf.lock() else { return };
     let counter = LINE_COUNTER.fetch_add(1, Ordering::Relaxed) + 1;
@@ -53,6 +131,14 @@ pub fn recent_lines_with_cursor(limit: usize) -> (Vec<String>, usize) {
     (lines, latest_counter(&guard))
 }
 
+/// Like [`recent_lines_with_cursor`], but gives up instead of waiting for the lock.
+pub fn try_recent_lines(limit: usize) -> Vec<String> {
+    let buf = buffer();
+    let Ok(guard) = buf.try_lock() else { return Vec::new() };
+    let skip = guard.len().saturating_sub(limit);
+    guard.iter().skip(skip).map(|entry| entry.text.clone()).collect()
+}
+
 pub fn fetch_since(cursor: usize) -> (Vec<String>, usize) {
     let buf = buffer();
     let guard = match buf.lock() {
@@ -103,3 +189,24 @@ impl Write for AppLogWriter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_file_rotates_and_keeps_three_old_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kubetile.log");
+        let mut file = LogFile::open(path.clone(), 10).unwrap();
+        for line in ["one", "two", "three", "four", "five", "six"] {
+            file.write_line(&format!("{line} line")).unwrap();
+        }
+
+        let read = |p: PathBuf| fs::read_to_string(p).unwrap();
+        assert_eq!(read(path.clone()), "six line\n");
+        assert_eq!(read(rotated_path(&path, 1)), "five line\n");
+        assert_eq!(read(rotated_path(&path, 3)), "three line\n");
+        assert!(!rotated_path(&path, 4).exists());
+    }
+}
@@ -0,0 +1,127 @@
+use kubetile_config::{check_collisions, validate_keybindings, AppConfig};
+
+use crate::keybindings::{parse_key_string, unknown_actions};
+use crate::panes::LogHighlights;
+
+/// Problems found in a loaded config, split by whether they stop kubetile from behaving as configured.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+pub fn check(config: &AppConfig) -> Report {
+    let mut report = Report::default();
+    let keybindings = &config.keybindings;
+
+    for (group, name, err) in validate_keybindings(keybindings) {
+        report.errors.push(format!("keybindings.{group}.{name}: {err}"));
+    }
+    for (key, first, second) in check_collisions(keybindings) {
+        if first == second {
+            report.errors.push(format!("keybindings.{first}: `{key}` is bound twice"));
+        } else {
+            report.errors.push(format!("keybindings: `{key}` is bound in both {first} and {second}"));
+        }
+    }
+    for (group, name) in unknown_actions(keybindings) {
+        report.errors.push(format!("keybindings.{group}.{name}: unknown action"));
+    }
+    for (field, value) in config.theme.colors() {
+        if let Err(e) = kubetile_tui::theme::parse_color(value) {
+            report.errors.push(format!("theme.{field}: {e}"));
+        }
+    }
+    for (pattern, value) in &config.theme.context_accents {
+        if let Err(e) = kubetile_tui::theme::parse_color(value) {
+            report.errors.push(format!("theme.context_accents.\"{pattern}\": {e}"));
+        }
+    }
+
+    for (pattern, modes) in &config.general.context_confirm {
+        for action in modes.keys() {
+            if config.general.confirm.action_mode(&action.replace('-', "_")).is_none() {
+                report.warnings.push(format!("general.context_confirm.\"{pattern}\".{action}: unknown action"));
+            }
+        }
+    }
+
+    for error in LogHighlights::compile(&config.logs).1 {
+        report.errors.push(format!("logs.highlights: {error}"));
+    }
+
+    // Plugin keys compete with the Normal-mode groups.
+    let builtin_keys: Vec<_> = keybindings
+        .group_entries()
+        .into_iter()
+        .flat_map(|(_, map)| map.values().filter_map(|k| parse_key_string(k)))
+        .collect();
+    for (name, plugin) in &config.plugins {
+        match parse_key_string(&plugin.key) {
+            None => report.errors.push(format!("plugins.{name}.key: invalid key `{}`", plugin.key)),
+            Some(key) if builtin_keys.contains(&key) => {
+                report.warnings.push(format!("plugins.{name}.key: `{}` is shadowed by a built-in binding", plugin.key))
+            }
+            Some(_) => {}
+        }
+    }
+    report
+}
+
+/// Checks the user config file and prints what it finds. Returns `false` when there are errors.
+pub fn run() -> bool {
+    let path = AppConfig::default_path();
+    let config = if path.exists() {
+        match AppConfig::load_from(&path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{}: {e}", path.display());
+                return false;
+            }
+        }
+    } else {
+        println!("No config at {}; checking the defaults", path.display());
+        AppConfig::default()
+    };
+
+    let report = check(&config);
+    for warning in &report.warnings {
+        println!("warning: {warning}");
+    }
+    for error in &report.errors {
+        println!("error: {error}");
+    }
+    if report.errors.is_empty() {
+        println!("{}: OK", path.display());
+    }
+    report.errors.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_pass() {
+        let report = check(&AppConfig::default());
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+    }
+
+    #[test]
+    fn reports_collisions_unknown_actions_and_bad_colors() {
+        let mut config = AppConfig::default();
+        config.keybindings.global.insert("no_such_action".into(), "ctrl+alt+z".into());
+        config.keybindings.mutate.insert("delete".into(), config.keybindings.global["quit"].clone());
+        config.theme.accent = "not-a-color".into();
+        config.logs.highlights.insert("(".into(), "red".into());
+        config.theme.context_accents.insert("prod-*".into(), "crimson".into());
+
+        let errors = check(&config).errors;
+        assert!(errors.iter().any(|e| e.contains("is bound in both global and mutate")), "{errors:?}");
+        assert!(errors.contains(&"keybindings.global.no_such_action: unknown action".to_string()));
+        assert!(errors.iter().any(|e| e.starts_with("theme.accent:")), "{errors:?}");
+        assert!(errors.iter().any(|e| e.starts_with("logs.highlights: `(`")), "{errors:?}");
+        assert!(errors.iter().any(|e| e.starts_with("theme.context_accents.\"prod-*\":")), "{errors:?}");
+    }
+}
@@ -21,20 +21,33 @@ pub enum Command {
     ShowHelp,
     ShowPaneHelp,
     ClosePaneHelp,
+    CloseWelcome,
+    WriteDefaultConfig,
     ToggleAppLogsTab,
     TogglePortForwardsTab,
+    ToggleAuditTab,
+    ToggleConnectionTab,
+    ToggleTrashTab,
+    ToggleDashboardTab,
+    Reconnect,
     FocusNextPane,
     FocusPrevPane,
     FocusDirection(Direction),
+    SwapPane(Direction),
+    RotatePanes,
     SplitVertical,
     SplitHorizontal,
     ClosePane,
     NewTab,
     CloseTab,
+    MovePaneToNewTab,
+    MergeTabIntoPrevious,
     NextTab,
     PrevTab,
     GoToTab(usize),
     ToggleFullscreen,
+    ToggleZoom,
+    ToggleSyncInput,
     ResizeGrow,
     ResizeShrink,
     EnterMode(InputMode),
@@ -42,6 +55,8 @@ pub enum Command {
     NamespaceConfirm,
     NamespaceInput(char),
     NamespaceBackspace,
+    NamespaceToggle,
+    NamespaceFavorite,
     ContextConfirm,
     ContextInput(char),
     ContextBackspace,
@@ -68,6 +83,10 @@ pub enum Command {
     QueryEditorScrollUp,
     QueryEditorScrollDown,
     QueryEditorExecute,
+    QueryEditorExplain,
+    QueryBeginTransaction,
+    QueryCommit,
+    QueryRollback,
     QueryEditorIndent,
     QueryEditorDeIndent,
     EnterQueryBrowse,
@@ -77,6 +96,13 @@ pub enum Command {
     QueryBrowsePrev,
     QueryBrowseScrollLeft,
     QueryBrowseScrollRight,
+    QueryBrowseNextPage,
+    QueryBrowseSortAsc,
+    QueryBrowseSortDesc,
+    QueryBrowseHideColumn,
+    QueryBrowseShowColumns,
+    QueryBrowsePinColumns,
+    QueryBrowseUnpinColumns,
     QueryCopyRow,
     QueryCopyAll,
 
@@ -87,6 +113,11 @@ pub enum Command {
     QueryHistorySelect,
     QueryHistoryDelete,
     CloseQueryHistory,
+    QueryHistoryStartSearch,
+    QueryHistorySearchInput(char),
+    QueryHistorySearchBackspace,
+    QueryHistorySearchConfirm,
+    QueryHistorySearchCancel,
 
     // Export to file dialog
     OpenExportDialog,
@@ -95,10 +126,33 @@ pub enum Command {
     ExportDialogConfirm,
     ExportDialogCancel,
 
+    // Tee logs path prompt
+    LogTeePathInput(char),
+    LogTeePathBackspace,
+    LogTeePathConfirm,
+    LogTeePathCancel,
+
+    // Result cell inspector
+    OpenCellInspector,
+    CellInspectorPrevColumn,
+    CellInspectorNextColumn,
+    CellInspectorScrollUp,
+    CellInspectorScrollDown,
+    CellInspectorCopy,
+    CellInspectorClose,
+
+    // Query parameter prompt
+    QueryParamsInput(char),
+    QueryParamsBackspace,
+    QueryParamsNextField,
+    QueryParamsConfirm,
+    QueryParamsCancel,
+
     // Save query name dialog
     OpenSaveQueryDialog,
     SaveQueryNameInput(char),
     SaveQueryNameBackspace,
+    SaveQueryNameCycleScope,
     SaveQueryNameConfirm,
     SaveQueryNameCancel,
 
@@ -126,17 +180,40 @@ pub enum Command {
     // Resource actions
     ViewYaml,
     ViewDescribe,
+    RefreshRow,
+    OpenRelated,
+    InspectImages,
+    FindUsages,
+    OpenDependencyGraph,
     SaveLogsToFile,
     DownloadFullLogs,
+    ToggleLogTee,
     DeleteResource,
+    EvictPod,
+    ForceDeletePod,
     ScaleResource,
     RestartRollout,
+    SetImage,
+    EditDataKey,
+    DrainNode,
+    ApplyManifests,
+    UndoDelete,
     ToggleDebugMode,
     ToggleRootDebugMode,
     ViewLogs,
     ExecInto,
+    SwitchContainer,
     PortForward,
+    TestIngress,
+    RunPlugin(String),
+    RunScript(String),
     ToggleAllNamespaces,
+    ToggleOwnerGrouping,
+    ToggleGroupCollapse,
+    FoldAll,
+    UnfoldAll,
+    ToggleCleanYaml,
+    CopyYaml,
 
     // Resource switcher
     EnterResourceSwitcher,
@@ -146,7 +223,82 @@ pub enum Command {
 
     // Confirmation dialog
     ConfirmAction,
+    ConfirmSubmit,
+    ConfirmToggleFocus,
     DenyAction,
+    ConfirmInput(char),
+    ConfirmBackspace,
+
+    // Notification center
+    OpenNotificationCenter,
+    NotificationCenterOlder,
+    NotificationCenterNewer,
+    NotificationCenterClear,
+    CloseNotificationCenter,
+
+    // RBAC "can I?" dialog
+    OpenCanIDialog,
+    CanIInput(char),
+    CanIBackspace,
+    CanIConfirm,
+    CanICancel,
+
+    // Apply manifests dialog
+    ApplyPathInput(char),
+    ApplyPathBackspace,
+    ApplyPathNextNamespace,
+    ApplyPathToggleForce,
+    ApplyPathConfirm,
+    ApplyPathCancel,
+
+    // Set image dialog
+    SetImageInput(char),
+    SetImageBackspace,
+    SetImagePrev,
+    SetImageNext,
+    SetImageConfirm,
+    SetImageCancel,
+
+    // Container picker
+    ContainerPickerPrev,
+    ContainerPickerNext,
+    ContainerPickerConfirm,
+    ContainerPickerCancel,
+
+    // Data key picker
+    DataKeyPickerPrev,
+    DataKeyPickerNext,
+    DataKeyPickerConfirm,
+    DataKeyPickerCancel,
+
+    // Related picker
+    RelatedPickerPrev,
+    RelatedPickerNext,
+    RelatedPickerConfirm,
+    RelatedPickerCancel,
+    OpenUndoList,
+    UndoListPrev,
+    UndoListNext,
+    UndoListConfirm,
+    CloseUndoList,
+
+    // Detached exec sessions
+    OpenExecSessions,
+    ExecSessionsPrev,
+    ExecSessionsNext,
+    ExecSessionsAttach,
+    ExecSessionsKill,
+    CloseExecSessions,
+
+    // Global search
+    OpenGlobalSearch,
+    GlobalSearchInput(char),
+    GlobalSearchBackspace,
+    GlobalSearchPrev,
+    GlobalSearchNext,
+    GlobalSearchToggleScope,
+    GlobalSearchConfirm,
+    CloseGlobalSearch,
 
     // Sort
     SortByColumn,
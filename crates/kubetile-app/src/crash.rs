@@ -0,0 +1,141 @@
+use std::fmt::Write as _;
+use std::panic::PanicHookInfo;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const REPORT_LOG_LINES: usize = 200;
+/// Config keys containing any of these are redacted from the report.
+const SECRET_KEY_PARTS: [&str; 6] = ["password", "secret", "token", "credential", "auth", "key"];
+
+/// What the app was doing, kept up to date so a crash report can include it.
+struct CrashContext {
+    views: Vec<String>,
+    config_summary: String,
+}
+
+static CONTEXT: Mutex<CrashContext> = Mutex::new(CrashContext { views: Vec::new(), config_summary: String::new() });
+
+/// Records the settings that differ from the defaults, minus anything that looks like a secret.
+pub fn set_config(config: &kubetile_config::Config) {
+    let summary = match config.diff_from(&kubetile_config::Config::default()) {
+        Ok(mut diff) => {
+            redact(&mut diff);
+            toml::to_string_pretty(&diff).unwrap_or_default()
+        }
+        Err(e) => format!("(unavailable: {e})"),
+    };
+    if let Ok(mut ctx) = CONTEXT.lock() {
+        ctx.config_summary = summary;
+    }
+}
+
+/// Records the view types of the open panes.
+pub fn set_views(views: impl Iterator<Item = String>) {
+    if let Ok(mut ctx) = CONTEXT.try_lock() {
+        ctx.views.clear();
+        ctx.views.extend(views);
+    }
+}
+
+fn redact(table: &mut toml::Table) {
+    for (key, value) in table.iter_mut() {
+        let lower = key.to_lowercase();
+        if SECRET_KEY_PARTS.iter().any(|part| lower.contains(part)) && !value.is_table() {
+            *value = toml::Value::String("<redacted>".into());
+        } else if let Some(inner) = value.as_table_mut() {
+            redact(inner);
+        }
+    }
+}
+
+pub fn default_dir() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("kubetile")
+        .join("crashes")
+}
+
+/// Writes a crash report for `info` and returns its path. Locks are only tried, so a panic
+/// raised while one is held still produces a report.
+pub fn write_report(info: &PanicHookInfo<'_>) -> std::io::Result<PathBuf> {
+    let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+    let (views, config_summary) = match CONTEXT.try_lock() {
+        Ok(ctx) => (ctx.views.clone(), ctx.config_summary.clone()),
+        Err(_) => (Vec::new(), "(unavailable)".into()),
+    };
+    let log_lines = crate::app_log::try_recent_lines(REPORT_LOG_LINES);
+    let report = build_report(&info.to_string(), &backtrace, &views, &log_lines, &config_summary);
+
+    let dir = default_dir();
+    std::fs::create_dir_all(&dir)?;
+    let stamp: String = jiff::Timestamp::now().to_string().chars().filter(char::is_ascii_alphanumeric).collect();
+    let path = dir.join(format!("crash-{stamp}.txt"));
+    std::fs::write(&path, report)?;
+    Ok(path)
+}
+
+fn build_report(panic: &str, backtrace: &str, views: &[String], log_lines: &[String], config: &str) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "kubetile {} crash report", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(out, "time: {}", jiff::Timestamp::now());
+    let _ = writeln!(out, "os: {} {}", std::env::consts::OS, std::env::consts::ARCH);
+    let _ = writeln!(out, "\n## Panic\n{panic}");
+    let _ = writeln!(out, "\n## Backtrace\n{}", backtrace.trim_end());
+    let _ = writeln!(out, "\n## Open views");
+    for view in views {
+        let _ = writeln!(out, "- {view}");
+    }
+    let _ = writeln!(out, "\n## App log (last {} lines)", log_lines.len());
+    for line in log_lines {
+        let _ = writeln!(out, "{line}");
+    }
+    let config = if config.trim().is_empty() { "(defaults)" } else { config.trim_end() };
+    let _ = writeln!(out, "\n## Config (changes from defaults)\n{config}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_secret_looking_keys_at_any_depth() {
+        let mut table: toml::Table = toml::from_str(
+            r#"
+            editor = "vim"
+            [plugins.db]
+            api_token = "abc"
+            command = "run"
+            [plugins.db.env]
+            PGPASSWORD = "hunter2"
+            "#,
+        )
+        .unwrap();
+        redact(&mut table);
+        let text = toml::to_string(&table).unwrap();
+        assert!(!text.contains("abc") && !text.contains("hunter2"));
+        assert!(text.contains("vim") && text.contains("run"));
+    }
+
+    #[test]
+    fn report_lists_every_section() {
+        let report = build_report(
+            "panicked at src/app.rs:1:1:\nboom",
+            "0: main",
+            &["ResourceList(Pods)".into()],
+            &["2026-10-16T10:00:00Z  INFO kubetile::app: started".into()],
+            "",
+        );
+        for needle in [
+            "## Panic\npanicked",
+            "boom",
+            "## Backtrace\n0: main",
+            "- ResourceList(Pods)",
+            "last 1 lines",
+            "(defaults)",
+        ] {
+            assert!(report.contains(needle), "missing {needle:?} in\n{report}");
+        }
+    }
+}
@@ -1,31 +1,79 @@
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyEvent};
-use kubetile_core::{KubeClient, LogLine, LogStream, PortForward, QueryConfig, QueryResult};
-use kubetile_tui::pane::{PaneId, ResourceKind};
+use crossterm::event::{self, Event, KeyEvent, MouseEventKind};
+use kubetile_core::query_plan::QueryPlan;
+use kubetile_core::{
+    AccessCheck, AccessDecision, ClusterOverview, ContainerImage, DataSnapshot, DetailSection, DrainPlan, ExecSession,
+    GraphNode, KubeClient, LogLine, LogStream, PlannedApply, PortForward, QueryConfig, QueryResult, QuerySession,
+    Related, RolloutProgress, SearchHit, VolumeUsageMap, WarningNotice,
+};
+use kubetile_tui::pane::{PaneId, ResourceKind, ViewType};
 use kubetile_tui::widgets::toast::ToastMessage;
 use tokio::sync::mpsc;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerPurpose {
+    Logs,
+    Exec,
+}
+
 pub enum AppEvent {
     Key(KeyEvent),
+    /// A mouse wheel notch, when `terminal.mouse_scroll` captures the mouse.
+    MouseScroll {
+        up: bool,
+    },
     Tick,
     #[allow(dead_code)]
     Resize(u16, u16),
     /// Resource update for a specific pane.
     /// The Vec<Vec<String>> is pre-rendered rows (via ResourceSummary::row()).
     /// This erases the generic S type so AppEvent doesn't need type params.
+    /// `partial` is set while the initial list is still being paged in, and `expected` then holds
+    /// the size of the whole list if the server reported it.
     ResourceUpdate {
         pane_id: PaneId,
         watcher_seq: u64,
         #[allow(dead_code)]
         headers: Vec<String>,
         rows: Vec<Vec<String>>,
+        partial: bool,
+        expected: Option<usize>,
     },
     ResourceError {
         pane_id: PaneId,
         watcher_seq: u64,
         error: String,
     },
+    /// Single object re-fetched via GET; `row` is `None` when it no longer exists.
+    ResourceRowRefreshed {
+        pane_id: PaneId,
+        watcher_seq: u64,
+        name: String,
+        namespace: String,
+        headers: Vec<String>,
+        row: Option<Vec<String>>,
+    },
+    /// Values for the configured plugin columns of one row.
+    PluginColumnsReady {
+        pane_id: PaneId,
+        watcher_seq: u64,
+        name: String,
+        namespace: String,
+        values: Vec<String>,
+    },
+    /// Kubelet volume stats for the PVC list in `pane_id`; an error stops the poller.
+    VolumeUsageReady {
+        pane_id: PaneId,
+        watcher_seq: u64,
+        result: Result<VolumeUsageMap, String>,
+    },
+    /// Answer to a SelfSubjectAccessReview issued in `context`.
+    AccessReviewed {
+        context: String,
+        check: AccessCheck,
+        result: Result<AccessDecision, String>,
+    },
     Toast(ToastMessage),
     YamlReady {
         pane_id: PaneId,
@@ -33,6 +81,23 @@ pub enum AppEvent {
         name: String,
         content: String,
     },
+    /// Plain text for a pane split off `pane_id`, e.g. a report or script output.
+    TextReady {
+        pane_id: PaneId,
+        view: ViewType,
+        title: String,
+        content: String,
+    },
+    /// Full sections for a detail pane that opened with placeholder metadata.
+    DetailSectionsReady {
+        pane_id: PaneId,
+        sections: Vec<DetailSection>,
+    },
+    /// The dependency graph of a graph pane.
+    DependencyGraphReady {
+        pane_id: PaneId,
+        result: Result<GraphNode, String>,
+    },
     LogsStreamReady {
         pane_id: PaneId,
         stream: LogStream,
@@ -47,6 +112,16 @@ pub enum AppEvent {
         lines: Vec<LogLine>,
         tail_lines: usize,
     },
+    /// Running pods of the workload followed by a logs pane; sent whenever the set changes.
+    WorkloadPodsChanged {
+        pane_id: PaneId,
+        pods: Vec<String>,
+    },
+    WorkloadLogStreamReady {
+        pane_id: PaneId,
+        pod: String,
+        stream: LogStream,
+    },
     LogsStreamError {
         pane_id: PaneId,
         error: String,
@@ -59,6 +134,92 @@ pub enum AppEvent {
         namespace: String,
         suggested_remote: u16,
     },
+    /// Containers of a pod about to be opened for logs/exec; `pane_id` is set when switching
+    /// the container of an existing pane. `containers` is empty when the pod could not be read.
+    ContainersResolved {
+        purpose: ContainerPurpose,
+        pane_id: Option<PaneId>,
+        pod: String,
+        namespace: String,
+        workload: String,
+        containers: Vec<String>,
+    },
+    /// Resources related to `subject`, resolved for a "go to related" jump from `pane_id`.
+    RelatedResolved {
+        pane_id: PaneId,
+        subject: String,
+        namespace: String,
+        related: Vec<Related>,
+    },
+    /// Every object in scope of the global search listing started as `seq`, and the kinds that
+    /// could not be listed.
+    SearchListed {
+        seq: u64,
+        hits: Vec<SearchHit>,
+        failed: Vec<(kubetile_core::ResourceKind, String)>,
+    },
+    /// A cluster overview fetched for the dashboard in `pane_id`.
+    DashboardReady {
+        pane_id: PaneId,
+        result: Result<ClusterOverview, String>,
+    },
+    /// A Warning event from the background watch started as `seq`.
+    WarningEvent {
+        seq: u64,
+        notice: WarningNotice,
+    },
+    /// Dry-run results for the manifests at `path` against `context`, or why they could not be read.
+    ApplyPlanReady {
+        path: String,
+        context: String,
+        result: Result<Vec<PlannedApply>, String>,
+    },
+    /// Outcome of applying document `index` of an apply pane.
+    ManifestApplied {
+        pane_id: PaneId,
+        index: usize,
+        result: Result<(), String>,
+    },
+    /// A resource deleted in `context`, with the manifest it had, for the trash.
+    ResourceDeleted {
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        context: String,
+        manifest: Option<String>,
+    },
+    /// Outcome of recreating trash entry `id`.
+    ResourceRestored {
+        id: u64,
+        result: Result<(), String>,
+    },
+    /// A workload whose pod template just changed, so its rollout is followed from here on.
+    FollowRollout {
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+    },
+    /// Data keys of the ConfigMap or Secret to pick one to edit from.
+    DataKeysReady {
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        result: Result<DataSnapshot, String>,
+    },
+    /// Containers of the workload the set image dialog edits.
+    SetImageTargetsReady {
+        kind: ResourceKind,
+        name: String,
+        namespace: String,
+        result: Result<Vec<ContainerImage>, String>,
+    },
+    /// Latest poll of followed rollout `id`.
+    RolloutProgress {
+        id: u64,
+        result: Result<RolloutProgress, String>,
+    },
+    /// A node drain planned by `Ctrl+Alt+N`, awaiting confirmation.
+    DrainPlanned(DrainPlan),
     QueryPromptReady {
         config: QueryConfig,
     },
@@ -70,6 +231,33 @@ pub enum AppEvent {
         pane_id: PaneId,
         error: String,
     },
+    PlanReady {
+        pane_id: PaneId,
+        plan: QueryPlan,
+    },
+    QueryPageReady {
+        pane_id: PaneId,
+        offset: usize,
+        result: Result<QueryResult, String>,
+    },
+    QueryRowEstimate {
+        pane_id: PaneId,
+        rows: u64,
+    },
+    TransactionStarted {
+        pane_id: PaneId,
+        result: Result<QuerySession, String>,
+    },
+    /// The session of `pane_id`'s open transaction died, rolling the transaction back.
+    TransactionLost {
+        pane_id: PaneId,
+        error: String,
+    },
+    /// `Ok` carries the statement that ended the transaction.
+    TransactionEnded {
+        pane_id: PaneId,
+        result: Result<&'static str, String>,
+    },
     SchemaReady {
         pane_id: PaneId,
         rows: Vec<Vec<String>>,
@@ -82,9 +270,38 @@ pub enum AppEvent {
         context: String,
         error: String,
     },
+    /// A reconnect attempt succeeded; `seq` identifies the attempt so superseded ones are ignored.
+    ReconnectReady {
+        seq: u64,
+        client: KubeClient,
+        namespaces: Vec<String>,
+    },
+    ReconnectFailed {
+        seq: u64,
+        error: String,
+    },
+    /// An API call to `context` was answered 401: its credentials expired and need refreshing.
+    AuthExpired {
+        context: String,
+        error: String,
+    },
     NamespacesUpdated {
         namespaces: Vec<String>,
     },
+    /// Outcome of a context selector health probe: latency or error.
+    ContextProbed {
+        context: String,
+        result: Result<Duration, String>,
+    },
+    /// Round trip to the current context's API server, for the status bar.
+    ApiLatencyMeasured {
+        result: Result<Duration, String>,
+    },
+    /// A native exec session finished connecting for the exec pane `pane_id`.
+    ExecConnected {
+        pane_id: PaneId,
+        result: Result<ExecSession, String>,
+    },
     PtyOutput {
         pane_id: PaneId,
         data: Vec<u8>,
@@ -111,6 +328,16 @@ impl EventHandler {
                         break;
                     }
                 }
+                Ok(Event::Mouse(mouse)) => {
+                    let up = match mouse.kind {
+                        MouseEventKind::ScrollUp => true,
+                        MouseEventKind::ScrollDown => false,
+                        _ => continue,
+                    };
+                    if input_tx.send(AppEvent::MouseScroll { up }).is_err() {
+                        break;
+                    }
+                }
                 Ok(Event::Resize(w, h)) => {
                     if input_tx.send(AppEvent::Resize(w, h)).is_err() {
                         break;
@@ -0,0 +1,64 @@
+use anyhow::{anyhow, Result};
+use clap::{Args, ValueEnum};
+
+use kubetile_core::listing::{kind_from_name, list_resources};
+use kubetile_core::KubeClient;
+
+#[derive(Args)]
+pub struct GetArgs {
+    /// Resource kind: short name (po), plural (pods) or singular (pod)
+    kind: String,
+
+    /// Namespace to list; defaults to the context's namespace
+    #[arg(short, long)]
+    namespace: Option<String>,
+
+    /// List across all namespaces
+    #[arg(short = 'A', long, conflicts_with = "namespace")]
+    all_namespaces: bool,
+
+    /// Kubeconfig context to use instead of the current one
+    #[arg(long)]
+    context: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+}
+
+/// Prints the columns the resource list shows for a kind, honouring `[views]` column choices.
+pub async fn run(args: GetArgs) -> Result<()> {
+    let kind = kind_from_name(&args.kind).ok_or_else(|| anyhow!("unknown resource kind `{}`", args.kind))?;
+    let config = kubetile_config::AppConfig::load();
+    let context = args.context.or_else(KubeClient::current_context_name);
+    let ssh_host = config.general.ssh_host_for(context.as_deref());
+    let client = KubeClient::connect_to(context.as_deref(), ssh_host).await?;
+    let namespace = match (args.all_namespaces, args.namespace) {
+        (true, _) => None,
+        (false, Some(ns)) => Some(ns),
+        (false, None) => Some(client.namespace().to_string()),
+    };
+
+    let mut listing = list_resources(client.inner_client(), &kind, namespace.as_deref()).await?;
+    let configured = config.views.columns_for(&kind.display_name().to_lowercase()).to_vec();
+    (listing.headers, listing.rows) =
+        kubetile_config::views::filter_columns(&configured, &listing.headers, &listing.rows);
+
+    match args.output {
+        OutputFormat::Table if listing.rows.is_empty() => match namespace {
+            Some(ns) => eprintln!("No resources found in {ns} namespace."),
+            None => eprintln!("No resources found."),
+        },
+        OutputFormat::Table => println!("{}", listing.render_table()),
+        OutputFormat::Json => println!("{}", listing.to_json()?),
+        OutputFormat::Yaml => print!("{}", listing.to_yaml()?),
+    }
+    Ok(())
+}
@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use kubetile_config::KeybindingsConfig;
+use kubetile_config::{KeybindingsConfig, PluginConfig};
+use kubetile_core::ScriptBinding;
 use kubetile_tui::pane::PaneCommand;
 
 use crate::command::Command;
@@ -35,17 +36,33 @@ pub enum InputMode {
     ContextSelector,
     ResourceSwitcher,
     ConfirmDialog,
+    ConfirmTypeName,
+    CanIDialog,
+    ApplyPathInput,
+    SetImageDialog,
+    NotificationCenter,
+    ContainerPicker,
+    RelatedPicker,
+    DataKeyPicker,
+    UndoList,
+    ExecSessions,
+    GlobalSearch,
     FilterInput,
     PortForwardInput,
+    LogTeePath,
     QueryDialog,
     QueryEditor,
     QueryBrowse,
     QueryHistory,
+    QueryHistorySearch,
     SaveQueryName,
     SavedQueries,
     ExportDialog,
+    QueryParams,
+    CellInspector,
     Completion,
     PaneHelp,
+    Welcome,
 }
 
 #[allow(dead_code)]
@@ -62,6 +79,7 @@ pub struct KeybindingDispatcher {
     query_history_bindings: HashMap<KeyEvent, Command>,
     saved_queries_bindings: HashMap<KeyEvent, Command>,
     completion_bindings: HashMap<KeyEvent, Command>,
+    plugin_bindings: HashMap<KeyEvent, Command>,
     reverse_global: Vec<(String, String, String)>,
     reverse_mutate: Vec<(String, String, String)>,
     reverse_interact: Vec<(String, String, String)>,
@@ -73,6 +91,7 @@ pub struct KeybindingDispatcher {
     reverse_query_history: Vec<(String, String, String)>,
     reverse_saved_queries: Vec<(String, String, String)>,
     reverse_completion: Vec<(String, String, String)>,
+    reverse_plugins: Vec<(String, String, String)>,
 }
 
 type GroupResult = (HashMap<KeyEvent, Command>, Vec<(String, String, String)>);
@@ -140,6 +159,7 @@ impl KeybindingDispatcher {
             query_history_bindings,
             saved_queries_bindings,
             completion_bindings,
+            plugin_bindings: HashMap::new(),
             reverse_global,
             reverse_mutate,
             reverse_interact,
@@ -151,9 +171,43 @@ impl KeybindingDispatcher {
             reverse_query_history,
             reverse_saved_queries,
             reverse_completion,
+            reverse_plugins: Vec::new(),
         }
     }
 
+    /// Binds each `[plugins]` entry to its key in Normal mode. Built-in bindings win over plugin keys.
+    pub fn with_plugins(mut self, plugins: &BTreeMap<String, PluginConfig>) -> Self {
+        for (name, plugin) in plugins {
+            let Some(key) = parse_key_string(&plugin.key) else {
+                tracing::warn!("Plugin `{name}` has an invalid key `{}`", plugin.key);
+                continue;
+            };
+            let description =
+                if plugin.description.is_empty() { format!("Plugin: {name}") } else { plugin.description.clone() };
+            self.plugin_bindings.insert(key, Command::RunPlugin(name.clone()));
+            self.reverse_plugins.push((name.clone(), plugin.key.clone(), description));
+        }
+        self
+    }
+
+    /// Binds the actions and panes registered by Lua scripts, after the `[plugins]` entries.
+    /// A key already taken by a plugin keeps the plugin.
+    pub fn with_scripts(mut self, scripts: &[ScriptBinding]) -> Self {
+        for script in scripts {
+            let Some(key) = parse_key_string(&script.key) else {
+                tracing::warn!("Script `{}` has an invalid key `{}`", script.name, script.key);
+                continue;
+            };
+            if self.plugin_bindings.contains_key(&key) {
+                tracing::warn!("Script `{}` key `{}` is already bound to a plugin", script.name, script.key);
+                continue;
+            }
+            self.plugin_bindings.insert(key, Command::RunScript(script.name.clone()));
+            self.reverse_plugins.push((script.name.clone(), script.key.clone(), script.description.clone()));
+        }
+        self
+    }
+
     pub fn dispatch(&self, key: KeyEvent) -> Option<(Command, bool)> {
         let key = normalize_key_event(key);
 
@@ -162,6 +216,12 @@ impl KeybindingDispatcher {
                 if key.code == KeyCode::Esc {
                     return Some((Command::ExitMode, false));
                 }
+                // Shifted page keys scroll the pane's scrollback, as in most terminal emulators.
+                match (key.code, key.modifiers.contains(KeyModifiers::SHIFT)) {
+                    (KeyCode::PageUp, true) => return Some((Command::Pane(PaneCommand::PageUp), false)),
+                    (KeyCode::PageDown, true) => return Some((Command::Pane(PaneCommand::PageDown), false)),
+                    _ => {}
+                }
                 let s = key_to_input_string(key);
                 if s.is_empty() {
                     return None;
@@ -180,6 +240,99 @@ impl KeybindingDispatcher {
             InputMode::ConfirmDialog => match key.code {
                 KeyCode::Char('y') => return Some((Command::ConfirmAction, false)),
                 KeyCode::Char('n') | KeyCode::Esc => return Some((Command::DenyAction, false)),
+                KeyCode::Enter => return Some((Command::ConfirmSubmit, false)),
+                KeyCode::Left | KeyCode::Right => return Some((Command::ConfirmToggleFocus, false)),
+                // Browsing keys keep working behind a y/n prompt.
+                _ => {}
+            },
+            InputMode::ConfirmTypeName => match key.code {
+                KeyCode::Enter => return Some((Command::ConfirmAction, false)),
+                KeyCode::Esc => return Some((Command::DenyAction, false)),
+                KeyCode::Char(c) => return Some((Command::ConfirmInput(c), false)),
+                KeyCode::Backspace => return Some((Command::ConfirmBackspace, false)),
+                _ => return None,
+            },
+            InputMode::Welcome => match key.code {
+                KeyCode::Char('w') => return Some((Command::WriteDefaultConfig, false)),
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => return Some((Command::CloseWelcome, false)),
+                _ => return None,
+            },
+            InputMode::NotificationCenter => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => return Some((Command::CloseNotificationCenter, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::NotificationCenterOlder, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::NotificationCenterNewer, false)),
+                KeyCode::Char('c') => return Some((Command::NotificationCenterClear, false)),
+                _ => return None,
+            },
+            InputMode::CanIDialog => match key.code {
+                KeyCode::Enter => return Some((Command::CanIConfirm, false)),
+                KeyCode::Esc => return Some((Command::CanICancel, false)),
+                KeyCode::Char(c) => return Some((Command::CanIInput(c), false)),
+                KeyCode::Backspace => return Some((Command::CanIBackspace, false)),
+                _ => return None,
+            },
+            InputMode::ApplyPathInput => match (key.code, key.modifiers) {
+                (KeyCode::Enter, _) => return Some((Command::ApplyPathConfirm, false)),
+                (KeyCode::Esc, _) => return Some((Command::ApplyPathCancel, false)),
+                (KeyCode::Tab, _) => return Some((Command::ApplyPathNextNamespace, false)),
+                (KeyCode::Char('f'), KeyModifiers::CONTROL) => return Some((Command::ApplyPathToggleForce, false)),
+                (KeyCode::Char(c), _) => return Some((Command::ApplyPathInput(c), false)),
+                (KeyCode::Backspace, _) => return Some((Command::ApplyPathBackspace, false)),
+                _ => return None,
+            },
+            InputMode::SetImageDialog => match key.code {
+                KeyCode::Enter => return Some((Command::SetImageConfirm, false)),
+                KeyCode::Esc => return Some((Command::SetImageCancel, false)),
+                KeyCode::Up | KeyCode::BackTab => return Some((Command::SetImagePrev, false)),
+                KeyCode::Down | KeyCode::Tab => return Some((Command::SetImageNext, false)),
+                KeyCode::Char(c) => return Some((Command::SetImageInput(c), false)),
+                KeyCode::Backspace => return Some((Command::SetImageBackspace, false)),
+                _ => return None,
+            },
+            InputMode::ContainerPicker => match key.code {
+                KeyCode::Enter => return Some((Command::ContainerPickerConfirm, false)),
+                KeyCode::Esc => return Some((Command::ContainerPickerCancel, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::ContainerPickerPrev, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::ContainerPickerNext, false)),
+                _ => return None,
+            },
+            InputMode::DataKeyPicker => match key.code {
+                KeyCode::Enter => return Some((Command::DataKeyPickerConfirm, false)),
+                KeyCode::Esc => return Some((Command::DataKeyPickerCancel, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::DataKeyPickerPrev, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::DataKeyPickerNext, false)),
+                _ => return None,
+            },
+            InputMode::RelatedPicker => match key.code {
+                KeyCode::Enter => return Some((Command::RelatedPickerConfirm, false)),
+                KeyCode::Esc => return Some((Command::RelatedPickerCancel, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::RelatedPickerPrev, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::RelatedPickerNext, false)),
+                _ => return None,
+            },
+            InputMode::UndoList => match key.code {
+                KeyCode::Enter => return Some((Command::UndoListConfirm, false)),
+                KeyCode::Esc | KeyCode::Char('q') => return Some((Command::CloseUndoList, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::UndoListPrev, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::UndoListNext, false)),
+                _ => return None,
+            },
+            InputMode::ExecSessions => match key.code {
+                KeyCode::Enter => return Some((Command::ExecSessionsAttach, false)),
+                KeyCode::Char('x') => return Some((Command::ExecSessionsKill, false)),
+                KeyCode::Esc | KeyCode::Char('q') => return Some((Command::CloseExecSessions, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::ExecSessionsPrev, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::ExecSessionsNext, false)),
+                _ => return None,
+            },
+            InputMode::GlobalSearch => match key.code {
+                KeyCode::Enter => return Some((Command::GlobalSearchConfirm, false)),
+                KeyCode::Esc => return Some((Command::CloseGlobalSearch, false)),
+                KeyCode::Up => return Some((Command::GlobalSearchPrev, false)),
+                KeyCode::Down => return Some((Command::GlobalSearchNext, false)),
+                KeyCode::Tab => return Some((Command::GlobalSearchToggleScope, false)),
+                KeyCode::Char(c) => return Some((Command::GlobalSearchInput(c), false)),
+                KeyCode::Backspace => return Some((Command::GlobalSearchBackspace, false)),
                 _ => return None,
             },
             InputMode::FilterInput => match key.code {
@@ -260,6 +413,22 @@ impl KeybindingDispatcher {
                     _ => return None,
                 }
             }
+            InputMode::QueryHistorySearch => match key.code {
+                KeyCode::Esc => return Some((Command::QueryHistorySearchCancel, false)),
+                KeyCode::Enter => return Some((Command::QueryHistorySearchConfirm, false)),
+                KeyCode::Down => return Some((Command::QueryHistoryNext, false)),
+                KeyCode::Up => return Some((Command::QueryHistoryPrev, false)),
+                KeyCode::Char(c) => return Some((Command::QueryHistorySearchInput(c), false)),
+                KeyCode::Backspace => return Some((Command::QueryHistorySearchBackspace, false)),
+                _ => return None,
+            },
+            InputMode::LogTeePath => match key.code {
+                KeyCode::Esc => return Some((Command::LogTeePathCancel, false)),
+                KeyCode::Enter => return Some((Command::LogTeePathConfirm, false)),
+                KeyCode::Char(c) => return Some((Command::LogTeePathInput(c), false)),
+                KeyCode::Backspace => return Some((Command::LogTeePathBackspace, false)),
+                _ => return None,
+            },
             InputMode::ExportDialog => match (key.code, key.modifiers) {
                 (KeyCode::Esc, _) => return Some((Command::ExportDialogCancel, false)),
                 (KeyCode::Enter, _) => return Some((Command::ExportDialogConfirm, false)),
@@ -267,9 +436,29 @@ impl KeybindingDispatcher {
                 (KeyCode::Backspace, _) => return Some((Command::ExportDialogBackspace, false)),
                 _ => return None,
             },
+            InputMode::CellInspector => match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => return Some((Command::CellInspectorClose, false)),
+                KeyCode::Left | KeyCode::Char('h') => return Some((Command::CellInspectorPrevColumn, false)),
+                KeyCode::Right | KeyCode::Char('l') => return Some((Command::CellInspectorNextColumn, false)),
+                KeyCode::Up | KeyCode::Char('k') => return Some((Command::CellInspectorScrollUp, false)),
+                KeyCode::Down | KeyCode::Char('j') => return Some((Command::CellInspectorScrollDown, false)),
+                KeyCode::Char('y') => return Some((Command::CellInspectorCopy, false)),
+                _ => return None,
+            },
+            InputMode::QueryParams => match key.code {
+                KeyCode::Esc => return Some((Command::QueryParamsCancel, false)),
+                KeyCode::Enter => return Some((Command::QueryParamsConfirm, false)),
+                KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => {
+                    return Some((Command::QueryParamsNextField, false));
+                }
+                KeyCode::Char(c) => return Some((Command::QueryParamsInput(c), false)),
+                KeyCode::Backspace => return Some((Command::QueryParamsBackspace, false)),
+                _ => return None,
+            },
             InputMode::SaveQueryName => match (key.code, key.modifiers) {
                 (KeyCode::Esc, _) => return Some((Command::SaveQueryNameCancel, false)),
                 (KeyCode::Enter, _) => return Some((Command::SaveQueryNameConfirm, false)),
+                (KeyCode::Tab, _) => return Some((Command::SaveQueryNameCycleScope, false)),
                 (KeyCode::Char(c), _) => return Some((Command::SaveQueryNameInput(c), false)),
                 (KeyCode::Backspace, _) => return Some((Command::SaveQueryNameBackspace, false)),
                 _ => return None,
@@ -301,10 +490,15 @@ impl KeybindingDispatcher {
                     _ => return None,
                 }
             }
-            InputMode::PaneHelp => match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => return Some((Command::ClosePaneHelp, false)),
-                _ => return None,
-            },
+            InputMode::PaneHelp => {
+                if self.global_bindings.get(&key) == Some(&Command::ShowPaneHelp) {
+                    return Some((Command::ClosePaneHelp, false));
+                }
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('q') => return Some((Command::ClosePaneHelp, false)),
+                    _ => return None,
+                }
+            }
             InputMode::QueryDialog => match key.code {
                 KeyCode::Esc => return Some((Command::QueryDialogCancel, false)),
                 KeyCode::Enter => return Some((Command::QueryDialogConfirm, false)),
@@ -333,6 +527,7 @@ impl KeybindingDispatcher {
                     .or_else(|| self.browse_bindings.get(&key))
                     .or_else(|| self.navigation_bindings.get(&key))
                     .or_else(|| self.tui_bindings.get(&key))
+                    .or_else(|| self.plugin_bindings.get(&key))
                     .cloned()
                     .map(|cmd| (cmd, false))
             }
@@ -341,6 +536,9 @@ impl KeybindingDispatcher {
                 KeyCode::Esc => Some((Command::ExitMode, false)),
                 KeyCode::Up => Some((Command::Pane(PaneCommand::SelectPrev), false)),
                 KeyCode::Down => Some((Command::Pane(PaneCommand::SelectNext), false)),
+                KeyCode::Char(' ') => Some((Command::NamespaceToggle, false)),
+                // Namespace names cannot contain `*`, so it never filters.
+                KeyCode::Char('*') => Some((Command::NamespaceFavorite, false)),
                 KeyCode::Char(c) => Some((Command::NamespaceInput(c), false)),
                 KeyCode::Backspace => Some((Command::NamespaceBackspace, false)),
                 _ => None,
@@ -354,21 +552,43 @@ impl KeybindingDispatcher {
                 KeyCode::Backspace => Some((Command::ContextBackspace, false)),
                 _ => None,
             },
+            InputMode::ConfirmDialog => self
+                .browse_bindings
+                .get(&key)
+                .or_else(|| self.navigation_bindings.get(&key))
+                .or_else(|| self.tui_bindings.get(&key))
+                .cloned()
+                .map(|cmd| (cmd, false)),
             InputMode::Search | InputMode::Command => None,
             InputMode::Pane | InputMode::Tab => None,
             InputMode::ResourceSwitcher
-            | InputMode::ConfirmDialog
+            | InputMode::ConfirmTypeName
+            | InputMode::CanIDialog
+            | InputMode::ApplyPathInput
+            | InputMode::SetImageDialog
+            | InputMode::NotificationCenter
+            | InputMode::ContainerPicker
+            | InputMode::RelatedPicker
+            | InputMode::DataKeyPicker
+            | InputMode::UndoList
+            | InputMode::ExecSessions
+            | InputMode::GlobalSearch
             | InputMode::FilterInput
             | InputMode::PortForwardInput
+            | InputMode::LogTeePath
             | InputMode::QueryDialog
             | InputMode::QueryEditor
             | InputMode::QueryBrowse
             | InputMode::QueryHistory
+            | InputMode::QueryHistorySearch
             | InputMode::SaveQueryName
             | InputMode::SavedQueries
             | InputMode::ExportDialog
+            | InputMode::QueryParams
+            | InputMode::CellInspector
             | InputMode::Completion
-            | InputMode::PaneHelp => {
+            | InputMode::PaneHelp
+            | InputMode::Welcome => {
                 unreachable!("handled above")
             }
         }
@@ -388,6 +608,7 @@ impl KeybindingDispatcher {
         collect(&mut result, "Browse", &self.reverse_browse);
         collect(&mut result, "Navigation", &self.reverse_navigation);
         collect(&mut result, "TUI", &self.reverse_tui);
+        collect(&mut result, "Plugins", &self.reverse_plugins);
         result
     }
 
@@ -412,6 +633,24 @@ impl KeybindingDispatcher {
         all.iter().find(|(n, _, _)| n == name).map(|(_, key_str, _)| format_key_display(key_str))
     }
 
+    /// Key and description of each bound command in `names`, in the given order. Unbound names are
+    /// skipped, so the result always reflects the user's keymap.
+    pub fn shortcuts_named(&self, names: &[&str]) -> Vec<(String, String)> {
+        let tables = [
+            &self.reverse_global,
+            &self.reverse_tui,
+            &self.reverse_browse,
+            &self.reverse_interact,
+            &self.reverse_navigation,
+            &self.reverse_mutate,
+        ];
+        names
+            .iter()
+            .filter_map(|name| tables.iter().flat_map(|t| t.iter()).find(|(n, _, _)| n == name))
+            .map(|(_, key_str, desc)| (format_key_display(key_str), desc.clone()))
+            .collect()
+    }
+
     #[allow(dead_code)]
     pub fn key_for_mode(&self, group: &str, name: &str) -> Option<String> {
         let reverse: &[(String, String, String)] = match group {
@@ -425,6 +664,10 @@ impl KeybindingDispatcher {
         reverse.iter().find(|(n, _, _)| n == name).map(|(_, key_str, _)| format_key_display(key_str))
     }
 
+    pub fn plugin_shortcuts(&self) -> Vec<(String, String)> {
+        self.reverse_plugins.iter().map(|(_, key_str, desc)| (format_key_display(key_str), desc.clone())).collect()
+    }
+
     pub fn global_shortcuts(&self) -> Vec<(String, String)> {
         self.reverse_global.iter().map(|(_, key_str, desc)| (format_key_display(key_str), desc.clone())).collect()
     }
@@ -479,5 +722,31 @@ impl KeybindingDispatcher {
     }
 }
 
+type CommandFromName = fn(&str) -> Option<Command>;
+
+/// Action names in `config` that no command answers to, as `(group, name)`.
+pub fn unknown_actions(config: &KeybindingsConfig) -> Vec<(String, String)> {
+    let lookups: [(&str, CommandFromName); 11] = [
+        ("global", global_command_from_name),
+        ("mutate", mutate_command_from_name),
+        ("interact", interact_command_from_name),
+        ("browse", browse_command_from_name),
+        ("navigation", navigation_command_from_name),
+        ("tui", tui_command_from_name),
+        ("query_editor", query_editor_command_from_name),
+        ("query_browse", query_browse_command_from_name),
+        ("query_history", query_history_command_from_name),
+        ("saved_queries", saved_queries_command_from_name),
+        ("completion", completion_command_from_name),
+    ];
+    lookups
+        .into_iter()
+        .filter_map(|(group, from_name)| Some((group, config.group(group)?, from_name)))
+        .flat_map(|(group, map, from_name)| {
+            map.keys().filter(move |name| from_name(name).is_none()).map(move |name| (group.to_string(), name.clone()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests;
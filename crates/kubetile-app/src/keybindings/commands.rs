@@ -10,9 +10,19 @@ pub(super) fn global_command_from_name(name: &str) -> Option<Command> {
         "show_pane_help" => Some(Command::ShowPaneHelp),
         "app_logs" => Some(Command::ToggleAppLogsTab),
         "port_forwards" => Some(Command::TogglePortForwardsTab),
+        "audit_log" => Some(Command::ToggleAuditTab),
         "enter_insert" => Some(Command::EnterMode(InputMode::Insert)),
         "namespace_selector" => Some(Command::EnterMode(InputMode::NamespaceSelector)),
         "context_selector" => Some(Command::EnterMode(InputMode::ContextSelector)),
+        "can_i" => Some(Command::OpenCanIDialog),
+        "notifications" => Some(Command::OpenNotificationCenter),
+        "reconnect" => Some(Command::Reconnect),
+        "dashboard" => Some(Command::ToggleDashboardTab),
+        "undo_list" => Some(Command::OpenUndoList),
+        "trash" => Some(Command::ToggleTrashTab),
+        "exec_sessions" => Some(Command::OpenExecSessions),
+        "connection_diagnostics" => Some(Command::ToggleConnectionTab),
+        "global_search" => Some(Command::OpenGlobalSearch),
         _ => None,
     }
 }
@@ -24,9 +34,19 @@ pub(super) fn global_command_description(name: &str) -> String {
         "show_pane_help" => "Pane help",
         "app_logs" => "App logs",
         "port_forwards" => "Port forwards",
+        "audit_log" => "Audit log",
         "enter_insert" => "Insert mode",
         "namespace_selector" => "Namespace",
         "context_selector" => "Context",
+        "can_i" => "Can I? (RBAC check)",
+        "notifications" => "Notification history",
+        "reconnect" => "Reconnect",
+        "dashboard" => "Cluster dashboard",
+        "undo_list" => "Undoable deletes",
+        "trash" => "Trash (deleted this session)",
+        "exec_sessions" => "Detached exec sessions",
+        "connection_diagnostics" => "Connection diagnostics",
+        "global_search" => "Search all resources",
         _ => "Unknown",
     }
     .into()
@@ -35,10 +55,17 @@ pub(super) fn global_command_description(name: &str) -> String {
 pub(super) fn mutate_command_from_name(name: &str) -> Option<Command> {
     match name {
         "delete" => Some(Command::DeleteResource),
+        "evict" => Some(Command::EvictPod),
+        "force_delete" => Some(Command::ForceDeletePod),
         "scale" => Some(Command::ScaleResource),
         "restart_rollout" => Some(Command::RestartRollout),
+        "set_image" => Some(Command::SetImage),
+        "edit_data" => Some(Command::EditDataKey),
         "debug_mode" => Some(Command::ToggleDebugMode),
         "root_debug_mode" => Some(Command::ToggleRootDebugMode),
+        "drain" => Some(Command::DrainNode),
+        "apply" => Some(Command::ApplyManifests),
+        "undo_delete" => Some(Command::UndoDelete),
         _ => None,
     }
 }
@@ -46,10 +73,17 @@ pub(super) fn mutate_command_from_name(name: &str) -> Option<Command> {
 pub(super) fn mutate_command_description(name: &str) -> String {
     match name {
         "delete" => "Delete",
+        "evict" => "Evict",
+        "force_delete" => "Force delete",
         "scale" => "Scale",
         "restart_rollout" => "Restart",
+        "set_image" => "Set image",
+        "edit_data" => "Edit data key",
         "debug_mode" => "Debug mode",
         "root_debug_mode" => "Root debug mode",
+        "drain" => "Drain node",
+        "apply" => "Apply manifests",
+        "undo_delete" => "Undo last delete",
         _ => "Unknown",
     }
     .into()
@@ -61,6 +95,8 @@ pub(super) fn interact_command_from_name(name: &str) -> Option<Command> {
         "open_query" => Some(Command::OpenQueryPane),
         "port_forward" => Some(Command::PortForward),
         "view_logs" => Some(Command::ViewLogs),
+        "switch_container" => Some(Command::SwitchContainer),
+        "test_ingress" => Some(Command::TestIngress),
         _ => None,
     }
 }
@@ -71,6 +107,8 @@ pub(super) fn interact_command_description(name: &str) -> String {
         "open_query" => "Query DB",
         "port_forward" => "Port Forward",
         "view_logs" => "Logs",
+        "switch_container" => "Switch container",
+        "test_ingress" => "Test routes",
         _ => "Unknown",
     }
     .into()
@@ -80,16 +118,30 @@ pub(super) fn browse_command_from_name(name: &str) -> Option<Command> {
     match name {
         "view_yaml" => Some(Command::ViewYaml),
         "view_describe" => Some(Command::ViewDescribe),
+        "refresh_row" => Some(Command::RefreshRow),
+        "related" => Some(Command::OpenRelated),
+        "images" => Some(Command::InspectImages),
+        "usages" => Some(Command::FindUsages),
+        "dependency_graph" => Some(Command::OpenDependencyGraph),
         "view_logs" => Some(Command::ViewLogs),
         "save_logs" => Some(Command::SaveLogsToFile),
         "download_logs" => Some(Command::DownloadFullLogs),
+        "tee_logs" => Some(Command::ToggleLogTee),
         "filter" => Some(Command::EnterMode(InputMode::FilterInput)),
         "resource_switcher" => Some(Command::EnterResourceSwitcher),
         "sort_column" => Some(Command::SortByColumn),
         "toggle_sort_order" => Some(Command::Pane(PaneCommand::ToggleSortOrder)),
         "toggle_all_namespaces" => Some(Command::ToggleAllNamespaces),
+        "group_by_owner" => Some(Command::ToggleOwnerGrouping),
+        "toggle_group" => Some(Command::ToggleGroupCollapse),
+        "fold_all" => Some(Command::FoldAll),
+        "unfold_all" => Some(Command::UnfoldAll),
+        "clean_yaml" => Some(Command::ToggleCleanYaml),
+        "copy_yaml" => Some(Command::CopyYaml),
         "toggle_follow" => Some(Command::Pane(PaneCommand::ToggleFollow)),
         "toggle_wrap" => Some(Command::Pane(PaneCommand::ToggleWrap)),
+        "collapse_repeats" => Some(Command::Pane(PaneCommand::ToggleCollapse)),
+        "log_level" => Some(Command::Pane(PaneCommand::CycleLogLevel)),
         _ => None,
     }
 }
@@ -98,16 +150,30 @@ pub(super) fn browse_command_description(name: &str) -> String {
     match name {
         "view_yaml" => "View YAML",
         "view_describe" => "Describe",
+        "refresh_row" => "Refresh",
+        "related" => "Related",
+        "images" => "Images",
+        "usages" => "Usages",
+        "dependency_graph" => "Dependencies",
         "view_logs" => "Logs",
         "save_logs" => "Save Logs",
         "download_logs" => "Download All Logs",
+        "tee_logs" => "Tee Logs to File",
         "filter" => "Filter",
         "resource_switcher" => "Resources",
         "sort_column" => "Sort",
         "toggle_sort_order" => "Sort Order",
         "toggle_all_namespaces" => "All NS",
+        "group_by_owner" => "Group by owner",
+        "toggle_group" => "Fold",
+        "fold_all" => "Fold all",
+        "unfold_all" => "Unfold all",
+        "clean_yaml" => "Clean YAML",
+        "copy_yaml" => "Copy YAML",
         "toggle_follow" => "Follow",
         "toggle_wrap" => "Wrap",
+        "collapse_repeats" => "Collapse repeats",
+        "log_level" => "Log level",
         _ => "Unknown",
     }
     .into()
@@ -152,14 +218,23 @@ pub(super) fn tui_command_from_name(name: &str) -> Option<Command> {
         "split_horizontal" => Some(Command::SplitHorizontal),
         "close_pane" => Some(Command::ClosePane),
         "toggle_fullscreen" => Some(Command::ToggleFullscreen),
+        "toggle_zoom" => Some(Command::ToggleZoom),
+        "toggle_sync_input" => Some(Command::ToggleSyncInput),
         "focus_up" => Some(Command::FocusDirection(Direction::Up)),
         "focus_down" => Some(Command::FocusDirection(Direction::Down)),
         "focus_left" => Some(Command::FocusDirection(Direction::Left)),
         "focus_right" => Some(Command::FocusDirection(Direction::Right)),
+        "swap_up" => Some(Command::SwapPane(Direction::Up)),
+        "swap_down" => Some(Command::SwapPane(Direction::Down)),
+        "swap_left" => Some(Command::SwapPane(Direction::Left)),
+        "swap_right" => Some(Command::SwapPane(Direction::Right)),
+        "rotate_panes" => Some(Command::RotatePanes),
         "resize_grow" => Some(Command::ResizeGrow),
         "resize_shrink" => Some(Command::ResizeShrink),
         "new_tab" => Some(Command::NewTab),
         "close_tab" => Some(Command::CloseTab),
+        "move_pane_to_tab" => Some(Command::MovePaneToNewTab),
+        "merge_tab" => Some(Command::MergeTabIntoPrevious),
         "open_terminal" => Some(Command::TerminalSpawn),
         "focus_next" => Some(Command::FocusNextPane),
         "focus_prev" => Some(Command::FocusPrevPane),
@@ -174,14 +249,23 @@ pub(super) fn tui_command_description(name: &str) -> String {
         "split_horizontal" => "Split H",
         "close_pane" => "Close pane",
         "toggle_fullscreen" => "Fullscreen",
+        "toggle_zoom" => "Zoom",
+        "toggle_sync_input" => "Sync input",
         "focus_up" => "Focus up",
         "focus_down" => "Focus down",
         "focus_left" => "Focus left",
         "focus_right" => "Focus right",
+        "swap_up" => "Swap up",
+        "swap_down" => "Swap down",
+        "swap_left" => "Swap left",
+        "swap_right" => "Swap right",
+        "rotate_panes" => "Rotate panes",
         "resize_grow" => "Grow",
         "resize_shrink" => "Shrink",
         "new_tab" => "New tab",
         "close_tab" => "Close tab",
+        "move_pane_to_tab" => "Pane to tab",
+        "merge_tab" => "Merge tab",
         "open_terminal" => "Terminal",
         "focus_next" => "Focus next",
         "focus_prev" => "Focus prev",
@@ -195,6 +279,10 @@ pub(super) fn query_editor_command_from_name(name: &str) -> Option<Command> {
     match name {
         "exit" => Some(Command::ExitMode),
         "execute" => Some(Command::QueryEditorExecute),
+        "explain" => Some(Command::QueryEditorExplain),
+        "begin_transaction" => Some(Command::QueryBeginTransaction),
+        "commit" => Some(Command::QueryCommit),
+        "rollback" => Some(Command::QueryRollback),
         "indent" => Some(Command::QueryEditorIndent),
         "deindent" => Some(Command::QueryEditorDeIndent),
         "history" => Some(Command::OpenQueryHistory),
@@ -210,6 +298,10 @@ pub(super) fn query_editor_command_description(name: &str) -> String {
     match name {
         "exit" => "Exit editor",
         "execute" => "Execute query",
+        "explain" => "Explain plan",
+        "begin_transaction" => "Begin transaction",
+        "commit" => "Commit transaction",
+        "rollback" => "Roll back transaction",
         "indent" => "Indent",
         "deindent" => "De-indent",
         "history" => "Query history",
@@ -230,9 +322,17 @@ pub(super) fn query_browse_command_from_name(name: &str) -> Option<Command> {
         "prev_row" => Some(Command::QueryBrowsePrev),
         "scroll_left" => Some(Command::QueryBrowseScrollLeft),
         "scroll_right" => Some(Command::QueryBrowseScrollRight),
+        "next_page" => Some(Command::QueryBrowseNextPage),
+        "sort_asc" => Some(Command::QueryBrowseSortAsc),
+        "sort_desc" => Some(Command::QueryBrowseSortDesc),
+        "hide_column" => Some(Command::QueryBrowseHideColumn),
+        "show_columns" => Some(Command::QueryBrowseShowColumns),
+        "pin_columns" => Some(Command::QueryBrowsePinColumns),
+        "unpin_columns" => Some(Command::QueryBrowseUnpinColumns),
         "copy_row" => Some(Command::QueryCopyRow),
         "copy_all" => Some(Command::QueryCopyAll),
         "export" => Some(Command::OpenExportDialog),
+        "inspect_cell" => Some(Command::OpenCellInspector),
         _ => None,
     }
 }
@@ -245,9 +345,17 @@ pub(super) fn query_browse_command_description(name: &str) -> String {
         "prev_row" => "Previous row",
         "scroll_left" => "Scroll left",
         "scroll_right" => "Scroll right",
+        "next_page" => "Fetch next page",
         "copy_row" => "Copy row as CSV",
         "copy_all" => "Copy all rows as CSV",
         "export" => "Export to file",
+        "inspect_cell" => "Inspect cell",
+        "sort_asc" => "Sort by column ascending",
+        "sort_desc" => "Sort by column descending",
+        "hide_column" => "Hide column",
+        "show_columns" => "Show hidden columns",
+        "pin_columns" => "Pin columns through this one",
+        "unpin_columns" => "Unpin columns",
         _ => "Unknown",
     }
     .into()
@@ -260,6 +368,7 @@ pub(super) fn query_history_command_from_name(name: &str) -> Option<Command> {
         "next" => Some(Command::QueryHistoryNext),
         "prev" => Some(Command::QueryHistoryPrev),
         "delete" => Some(Command::QueryHistoryDelete),
+        "search" => Some(Command::QueryHistoryStartSearch),
         _ => None,
     }
 }
@@ -271,6 +380,7 @@ pub(super) fn query_history_command_description(name: &str) -> String {
         "next" => "Next entry",
         "prev" => "Previous entry",
         "delete" => "Delete entry",
+        "search" => "Search history",
         _ => "Unknown",
     }
     .into()
@@ -41,6 +41,18 @@ fn dispatch_global_keys() {
     assert_eq!(d.dispatch(press(KeyCode::Char('i'))), Some((Command::EnterMode(InputMode::Insert), false)));
     assert_eq!(d.dispatch(ctrl(KeyCode::Char('l'))), Some((Command::ToggleAppLogsTab, false)));
     assert_eq!(d.dispatch(ctrl(KeyCode::Char('p'))), Some((Command::TogglePortForwardsTab, false)));
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('u'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::ToggleAuditTab, false))
+    );
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('r'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::Reconnect, false))
+    );
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('d'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::ToggleDashboardTab, false))
+    );
 }
 
 #[test]
@@ -86,6 +98,10 @@ fn dispatch_browse_keys() {
     assert_eq!(d.dispatch(press(KeyCode::Char('a'))), Some((Command::ToggleAllNamespaces, false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('f'))), Some((Command::Pane(PaneCommand::ToggleFollow), false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('w'))), Some((Command::Pane(PaneCommand::ToggleWrap), false)));
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('C'), KeyModifiers::SHIFT)),
+        Some((Command::Pane(PaneCommand::ToggleCollapse), false))
+    );
 }
 
 #[test]
@@ -95,6 +111,7 @@ fn dispatch_tui_keys() {
     assert_eq!(d.dispatch(alt(KeyCode::Char('h'))), Some((Command::SplitHorizontal, false)));
     assert_eq!(d.dispatch(alt(KeyCode::Char('x'))), Some((Command::ClosePane, false)));
     assert_eq!(d.dispatch(alt(KeyCode::Char('f'))), Some((Command::ToggleFullscreen, false)));
+    assert_eq!(d.dispatch(alt(KeyCode::Char('s'))), Some((Command::ToggleSyncInput, false)));
     assert_eq!(d.dispatch(ctrl(KeyCode::Char('t'))), Some((Command::NewTab, false)));
     assert_eq!(d.dispatch(ctrl(KeyCode::Char('w'))), Some((Command::CloseTab, false)));
     assert_eq!(d.dispatch(press(KeyCode::Tab)), Some((Command::FocusNextPane, false)));
@@ -109,6 +126,9 @@ fn dispatch_mutate_keys_require_confirmation() {
     assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('x'))), Some((Command::DeleteResource, true)));
     assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('s'))), Some((Command::ScaleResource, true)));
     assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('r'))), Some((Command::RestartRollout, true)));
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('e'))), Some((Command::EvictPod, true)));
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('f'))), Some((Command::ForceDeletePod, true)));
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('n'))), Some((Command::DrainNode, true)));
 }
 
 #[test]
@@ -116,6 +136,44 @@ fn dispatch_interact_keys_no_confirmation() {
     let d = default_dispatcher();
     assert_eq!(d.dispatch(press(KeyCode::Char('e'))), Some((Command::ExecInto, false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('p'))), Some((Command::PortForward, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('t'))), Some((Command::TestIngress, false)));
+}
+
+#[test]
+fn plugin_keys_dispatch_after_builtin_bindings() {
+    let plugin = |key: &str| kubetile_config::PluginConfig {
+        key: key.into(),
+        command: "stern {name}".into(),
+        description: String::new(),
+        kinds: Vec::new(),
+    };
+    let plugins = [("shadowed".to_string(), plugin("e")), ("stern".to_string(), plugin("ctrl+alt+l"))].into();
+    let d = default_dispatcher().with_plugins(&plugins);
+
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('l'))), Some((Command::RunPlugin("stern".into()), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('e'))), Some((Command::ExecInto, false)));
+    assert!(d.plugin_shortcuts().contains(&("Ctrl+Alt+L".to_string(), "Plugin: stern".to_string())));
+}
+
+#[test]
+fn script_keys_dispatch_after_plugins() {
+    let plugin = kubetile_config::PluginConfig {
+        key: "ctrl+alt+l".into(),
+        command: "stern {name}".into(),
+        ..Default::default()
+    };
+    let script = |name: &str, key: &str| kubetile_core::ScriptBinding {
+        name: name.into(),
+        key: key.into(),
+        description: format!("Script: {name}"),
+    };
+    let d = default_dispatcher()
+        .with_plugins(&[("stern".to_string(), plugin)].into())
+        .with_scripts(&[script("clash", "ctrl+alt+l"), script("summary", "ctrl+alt+y")]);
+
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('l'))), Some((Command::RunPlugin("stern".into()), false)));
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('y'))), Some((Command::RunScript("summary".into()), false)));
+    assert!(d.plugin_shortcuts().contains(&("Ctrl+Alt+Y".to_string(), "Script: summary".to_string())));
 }
 
 #[test]
@@ -257,6 +315,10 @@ fn insert_mode_special_keys() {
         d.dispatch(press(KeyCode::PageDown)),
         Some((Command::Pane(PaneCommand::SendInput("\x1b[6~".into())), false))
     );
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::PageUp, KeyModifiers::SHIFT)),
+        Some((Command::Pane(PaneCommand::PageUp), false))
+    );
     assert_eq!(
         d.dispatch(press(KeyCode::Delete)),
         Some((Command::Pane(PaneCommand::SendInput("\x1b[3~".into())), false))
@@ -407,6 +469,16 @@ fn resize_dispatch() {
     );
 }
 
+#[test]
+fn swap_and_rotate_dispatch() {
+    let d = default_dispatcher();
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Left, KeyModifiers::CONTROL | KeyModifiers::ALT)),
+        Some((Command::SwapPane(Direction::Left), false))
+    );
+    assert_eq!(d.dispatch(alt(KeyCode::Char('o'))), Some((Command::RotatePanes, false)));
+}
+
 #[test]
 fn fullscreen_dispatch() {
     let d = default_dispatcher();
@@ -423,6 +495,8 @@ fn namespace_mode_dispatches_nav_and_input() {
     assert_eq!(d.dispatch(press(KeyCode::Up)), Some((Command::Pane(PaneCommand::SelectPrev), false)));
     assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::Pane(PaneCommand::SelectNext), false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('a'))), Some((Command::NamespaceInput('a'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char(' '))), Some((Command::NamespaceToggle, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('*'))), Some((Command::NamespaceFavorite, false)));
     assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::NamespaceBackspace, false)));
 }
 
@@ -479,15 +553,200 @@ fn confirm_dialog_mode_accepts_y_n_esc() {
     assert_eq!(d.dispatch(press(KeyCode::Char('y'))), Some((Command::ConfirmAction, false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('n'))), Some((Command::DenyAction, false)));
     assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::DenyAction, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::ConfirmSubmit, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Left)), Some((Command::ConfirmToggleFocus, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Right)), Some((Command::ConfirmToggleFocus, false)));
+    assert_ne!(d.dispatch(press(KeyCode::Char('l'))), Some((Command::ConfirmToggleFocus, false)));
 }
 
 #[test]
-fn confirm_dialog_mode_ignores_other_keys() {
+fn confirm_dialog_mode_keeps_browsing_keys_but_not_mutations() {
     let mut d = default_dispatcher();
     d.set_mode(InputMode::ConfirmDialog);
+    assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::Pane(PaneCommand::SelectNext), false)));
+    assert_eq!(d.dispatch(ctrl(KeyCode::Char('q'))), Some((Command::Quit, false)));
+    let delete = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT);
+    assert_eq!(d.dispatch(delete), None);
+}
+
+#[test]
+fn confirm_type_name_mode_forwards_chars() {
+    let mut d = default_dispatcher();
+    d.set_mode(InputMode::ConfirmTypeName);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('y'))), Some((Command::ConfirmInput('y'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::ConfirmBackspace, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::ConfirmAction, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::DenyAction, false)));
+}
+
+#[test]
+fn can_i_dialog_mode_forwards_chars() {
+    let mut d = default_dispatcher();
+    d.set_mode(InputMode::CanIDialog);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('q'))), Some((Command::CanIInput('q'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::CanIBackspace, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::CanIConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::CanICancel, false)));
+}
+
+#[test]
+fn apply_path_mode_edits_the_path() {
+    let mut d = default_dispatcher();
+    let ctrl_alt = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('a'), ctrl_alt)), Some((Command::ApplyManifests, true)));
+    d.set_mode(InputMode::ApplyPathInput);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('k'))), Some((Command::ApplyPathInput('k'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::ApplyPathBackspace, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Tab)), Some((Command::ApplyPathNextNamespace, false)));
+    let ctrl = KeyModifiers::CONTROL;
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('f'), ctrl)), Some((Command::ApplyPathToggleForce, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::ApplyPathConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::ApplyPathCancel, false)));
+}
+
+#[test]
+fn container_picker_mode_navigates_and_confirms() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(press(KeyCode::Char('c'))), Some((Command::SwitchContainer, false)));
+    d.set_mode(InputMode::ContainerPicker);
+
+    assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::ContainerPickerNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('k'))), Some((Command::ContainerPickerPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::ContainerPickerConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::ContainerPickerCancel, false)));
     assert_eq!(d.dispatch(press(KeyCode::Char('q'))), None);
-    assert_eq!(d.dispatch(press(KeyCode::Char('a'))), None);
-    assert_eq!(d.dispatch(press(KeyCode::Tab)), None);
+}
+
+#[test]
+fn shift_i_inspects_images() {
+    let d = default_dispatcher();
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('I'), KeyModifiers::SHIFT)), Some((Command::InspectImages, false)));
+}
+
+#[test]
+fn shift_d_opens_the_dependency_graph() {
+    let d = default_dispatcher();
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('D'), KeyModifiers::SHIFT)),
+        Some((Command::OpenDependencyGraph, false))
+    );
+}
+
+#[test]
+fn u_finds_usages() {
+    let d = default_dispatcher();
+    assert_eq!(d.dispatch(press(KeyCode::Char('u'))), Some((Command::FindUsages, false)));
+}
+
+#[test]
+fn yaml_fold_keys_follow_vim() {
+    let d = default_dispatcher();
+    assert_eq!(d.dispatch(press(KeyCode::Char('z'))), Some((Command::ToggleGroupCollapse, false)));
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('M'), KeyModifiers::SHIFT)), Some((Command::FoldAll, false)));
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('R'), KeyModifiers::SHIFT)), Some((Command::UnfoldAll, false)));
+}
+
+#[test]
+fn shift_t_tees_logs_to_a_file() {
+    let d = default_dispatcher();
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('T'), KeyModifiers::SHIFT)), Some((Command::ToggleLogTee, false)));
+}
+
+#[test]
+fn yaml_clean_and_copy_keys() {
+    let d = default_dispatcher();
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('N'), KeyModifiers::SHIFT)), Some((Command::ToggleCleanYaml, false)));
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('Y'), KeyModifiers::SHIFT)), Some((Command::CopyYaml, false)));
+}
+
+#[test]
+fn related_picker_mode_navigates_and_confirms() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(press(KeyCode::Char('o'))), Some((Command::OpenRelated, false)));
+    d.set_mode(InputMode::RelatedPicker);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('j'))), Some((Command::RelatedPickerNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Up)), Some((Command::RelatedPickerPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::RelatedPickerConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::RelatedPickerCancel, false)));
+}
+
+#[test]
+fn undo_keys_and_list_mode() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('z'))), Some((Command::UndoDelete, true)));
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('z'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::OpenUndoList, false))
+    );
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('g'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::ToggleTrashTab, false))
+    );
+    d.set_mode(InputMode::UndoList);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('j'))), Some((Command::UndoListNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Up)), Some((Command::UndoListPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::UndoListConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('q'))), Some((Command::CloseUndoList, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('x'))), None);
+}
+
+#[test]
+fn exec_sessions_key_and_mode() {
+    let mut d = default_dispatcher();
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('e'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::OpenExecSessions, false))
+    );
+    d.set_mode(InputMode::ExecSessions);
+
+    assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::ExecSessionsNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('k'))), Some((Command::ExecSessionsPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::ExecSessionsAttach, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('x'))), Some((Command::ExecSessionsKill, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::CloseExecSessions, false)));
+}
+
+#[test]
+fn connection_diagnostics_key() {
+    let d = default_dispatcher();
+    assert_eq!(
+        d.dispatch(press_mod(KeyCode::Char('y'), KeyModifiers::CONTROL | KeyModifiers::SHIFT)),
+        Some((Command::ToggleConnectionTab, false))
+    );
+}
+
+#[test]
+fn global_search_key_and_mode() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(press_mod(KeyCode::Char('/'), KeyModifiers::ALT)), Some((Command::OpenGlobalSearch, false)));
+    d.set_mode(InputMode::GlobalSearch);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('q'))), Some((Command::GlobalSearchInput('q'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::GlobalSearchBackspace, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::GlobalSearchNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Up)), Some((Command::GlobalSearchPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Tab)), Some((Command::GlobalSearchToggleScope, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::GlobalSearchConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::CloseGlobalSearch, false)));
+}
+
+#[test]
+fn set_image_key_and_dialog_mode() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('i'))), Some((Command::SetImage, true)));
+    d.set_mode(InputMode::SetImageDialog);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('j'))), Some((Command::SetImageInput('j'), false)));
+    assert_eq!(d.dispatch(press(KeyCode::Backspace)), Some((Command::SetImageBackspace, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Down)), Some((Command::SetImageNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::BackTab)), Some((Command::SetImagePrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::SetImageConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::SetImageCancel, false)));
 }
 
 #[test]
@@ -602,3 +861,46 @@ fn from_config_builds_all_five_maps() {
     assert!(!d.navigation_shortcuts().is_empty());
     assert!(!d.tui_shortcuts().is_empty());
 }
+
+#[test]
+fn pane_help_key_toggles_overlay_closed() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(press(KeyCode::F(2))), Some((Command::ShowPaneHelp, false)));
+    d.set_mode(InputMode::PaneHelp);
+    assert_eq!(d.dispatch(press(KeyCode::F(2))), Some((Command::ClosePaneHelp, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char('j'))), None);
+}
+
+#[test]
+fn welcome_overlay_writes_config_or_closes() {
+    let mut d = default_dispatcher();
+    d.set_mode(InputMode::Welcome);
+    assert_eq!(d.dispatch(press(KeyCode::Char('w'))), Some((Command::WriteDefaultConfig, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::CloseWelcome, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::CloseWelcome, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Char(':'))), None);
+}
+
+#[test]
+fn shortcuts_named_follows_keymap_and_skips_unbound() {
+    let mut config = kubetile_config::Config::default();
+    config.keybindings.browse.insert("view_yaml".into(), "shift+y".into());
+    let d = KeybindingDispatcher::from_config(&config.keybindings);
+    let entries = d.shortcuts_named(&["view_yaml", "not_a_binding", "delete"]);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].1, "View YAML");
+    assert_eq!(entries[0].0, format_key_display("shift+y"));
+    assert_eq!(entries[1].1, "Delete");
+}
+
+#[test]
+fn edit_data_key_and_picker_mode() {
+    let mut d = default_dispatcher();
+    assert_eq!(d.dispatch(ctrl_alt(KeyCode::Char('k'))), Some((Command::EditDataKey, true)));
+    d.set_mode(InputMode::DataKeyPicker);
+
+    assert_eq!(d.dispatch(press(KeyCode::Char('j'))), Some((Command::DataKeyPickerNext, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Up)), Some((Command::DataKeyPickerPrev, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Enter)), Some((Command::DataKeyPickerConfirm, false)));
+    assert_eq!(d.dispatch(press(KeyCode::Esc)), Some((Command::DataKeyPickerCancel, false)));
+}
@@ -1,23 +1,32 @@
 mod app;
 mod app_log;
+mod check_config;
 mod command;
+mod crash;
 mod event;
+mod get;
 mod keybindings;
 mod panes;
+mod queries;
 mod resource_switcher;
 mod state;
 
 use std::io;
 
-use clap::Parser;
-use crossterm::event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags};
+use clap::{Parser, Subcommand};
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+    PushKeyboardEnhancementFlags,
+};
 use crossterm::execute;
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use kubetile_tui::pane::ResourceKind;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
-use crate::app::App;
+use crate::app::{App, StartupOptions};
 use crate::keybindings::KeybindingDispatcher;
+use crate::panes::LogHighlights;
 
 #[derive(Parser)]
 #[command(name = "kubetile", about = "Keyboard-driven Kubernetes TUI IDE")]
@@ -29,6 +38,44 @@ struct Cli {
     /// Print effective config (defaults + user overrides) and exit
     #[arg(long)]
     print_config: bool,
+
+    /// With --print-config, only print values that differ from the defaults
+    #[arg(long, requires = "print_config")]
+    diff: bool,
+
+    /// Print a JSON schema of the config file and exit
+    #[arg(long)]
+    schema: bool,
+
+    /// Validate the config file (key collisions, unknown actions, theme colors, log highlights) and exit
+    #[arg(long)]
+    check_config: bool,
+
+    /// Kubeconfig context to open instead of the current context
+    #[arg(long)]
+    context: Option<String>,
+
+    /// Namespace to open instead of the context's default namespace
+    #[arg(short, long)]
+    namespace: Option<String>,
+
+    /// Resource list to open, as KIND (pods, deploy, ...) or KIND/NAME to also open its detail pane
+    target: Option<String>,
+
+    /// Initial filter for the resource list
+    #[arg(long)]
+    filter: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    /// Print a resource list without starting the TUI
+    Get(get::GetArgs),
+    /// Import or export the query pane's saved queries
+    Queries(queries::QueriesArgs),
 }
 
 #[tokio::main]
@@ -43,12 +90,37 @@ async fn main() -> anyhow::Result<()> {
 
     if cli.print_config {
         let config = kubetile_config::AppConfig::load();
-        println!("{}", toml::to_string_pretty(&config)?);
+        if cli.diff {
+            let diff = config.diff_from(&kubetile_config::AppConfig::default())?;
+            println!("{}", toml::to_string_pretty(&diff)?);
+        } else {
+            println!("{}", toml::to_string_pretty(&config)?);
+        }
         return Ok(());
     }
 
+    if cli.schema {
+        println!("{}", serde_json::to_string_pretty(&kubetile_config::AppConfig::schema())?);
+        return Ok(());
+    }
+
+    if cli.check_config {
+        std::process::exit(if check_config::run() { 0 } else { 1 });
+    }
+
+    match cli.command {
+        Some(CliCommand::Get(args)) => return get::run(args).await,
+        Some(CliCommand::Queries(args)) => return queries::run(args),
+        None => {}
+    }
+
+    let (kind, name) = match cli.target.as_deref() {
+        Some(target) => parse_target(target)?,
+        None => (None, None),
+    };
+
     let env_filter = tracing_subscriber::EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new(app_log::DEFAULT_FILTER));
 
     tracing_subscriber::fmt()
         .with_env_filter(env_filter)
@@ -57,6 +129,7 @@ async fn main() -> anyhow::Result<()> {
         .init();
 
     install_panic_hook();
+    kubetile_core::audit::init(kubetile_core::audit::default_path());
 
     terminal::enable_raw_mode()?;
     execute!(
@@ -69,23 +142,69 @@ async fn main() -> anyhow::Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let config = kubetile_config::Config::load();
-    let dispatcher = KeybindingDispatcher::from_config(&config.keybindings);
+    crash::set_config(&config);
+    if config.general.app_log_file {
+        let max_bytes = u64::from(config.general.app_log_max_mb) * 1024 * 1024;
+        if let Err(e) = app_log::enable_file(app_log::default_file_path(), max_bytes) {
+            tracing::warn!("Cannot write the app log file: {e}");
+        }
+    }
+    if config.terminal.mouse_scroll {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
+    let scripts = kubetile_core::ScriptHost::load_dir(&kubetile_config::AppConfig::plugins_dir());
+    for error in scripts.errors() {
+        tracing::warn!("Skipping script {error}");
+    }
+    let dispatcher = KeybindingDispatcher::from_config(&config.keybindings)
+        .with_plugins(&config.plugins)
+        .with_scripts(&scripts.bindings());
     let theme = kubetile_tui::theme::Theme::from_config(&config.theme);
+    let scope = StartupOptions { context: cli.context, namespace: cli.namespace, kind, name, filter: cli.filter };
+    let (log_highlights, highlight_errors) = LogHighlights::compile(&config.logs);
+    for error in highlight_errors {
+        tracing::warn!("Skipping log highlight {error}");
+    }
     let mut app =
-        App::new(config.tick_rate_ms(), dispatcher, theme, config.views, config.general.query_open_new_tab).await;
+        App::new(dispatcher, theme, config.views, config.general, config.features, config.plugins, scope).await;
+    app.set_log_highlights(log_highlights);
+    app.set_scripts(scripts);
+    app.set_terminal_config(config.terminal);
+    let config_path = kubetile_config::AppConfig::default_path();
+    if !config_path.exists() {
+        app.show_welcome(config_path);
+    }
     let result = app.run(&mut terminal).await;
 
     terminal::disable_raw_mode()?;
-    execute!(io::stdout(), PopKeyboardEnhancementFlags, LeaveAlternateScreen)?;
+    execute!(io::stdout(), DisableMouseCapture, PopKeyboardEnhancementFlags, LeaveAlternateScreen)?;
 
     result
 }
 
+/// Splits a `KIND[/NAME]` launch target.
+fn parse_target(target: &str) -> anyhow::Result<(Option<ResourceKind>, Option<String>)> {
+    let (kind, name) = match target.split_once('/') {
+        Some((kind, name)) if !name.is_empty() => (kind, Some(name.to_string())),
+        _ => (target.trim_end_matches('/'), None),
+    };
+    let kind = kubetile_core::listing::kind_from_name(kind)
+        .and_then(|k| ResourceKind::from_short_name(k.short_name()))
+        .ok_or_else(|| anyhow::anyhow!("unknown resource kind `{kind}`"))?;
+    Ok((Some(kind), name))
+}
+
 fn install_panic_hook() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
         let _ = terminal::disable_raw_mode();
-        let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags, LeaveAlternateScreen);
+        let _ = execute!(io::stdout(), DisableMouseCapture, PopKeyboardEnhancementFlags, LeaveAlternateScreen);
         original_hook(panic_info);
+        match crash::write_report(panic_info) {
+            Ok(path) => {
+                eprintln!("\nCrash report written to {}\nPlease attach it when reporting this bug.", path.display())
+            }
+            Err(e) => eprintln!("\nCould not write a crash report: {e}"),
+        }
     }));
 }
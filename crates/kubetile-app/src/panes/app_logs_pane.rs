@@ -9,14 +9,31 @@ use crate::app_log;
 use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
 
 const LOG_LINE_LIMIT: usize = 1500;
+const LEVELS: [&str; 5] = ["ERROR", "WARN", "INFO", "DEBUG", "TRACE"];
+
+/// Level and target of a formatted tracing line (`<time>  WARN kubetile::app: …`). Lines that
+/// continue a multi-line message have neither.
+fn parse_line(line: &str) -> Option<(usize, &str)> {
+    let mut words = line.split_whitespace();
+    words.next()?;
+    let level = words.next()?;
+    let level = LEVELS.iter().position(|l| *l == level)?;
+    let target = words.next()?.strip_suffix(':')?;
+    Some((level, target))
+}
 
 pub struct AppLogsPane {
     view_type: ViewType,
     lines: VecDeque<String>,
     scroll: usize,
     follow: bool,
+    /// Most verbose level shown, as an index into `LEVELS`; `None` shows everything.
+    max_level: Option<usize>,
+    /// Case-insensitive substring the target module must contain.
+    module_filter: Option<String>,
     last_cursor: usize,
     visible_height: Cell<u16>,
+    redraw: bool,
 }
 
 impl AppLogsPane {
@@ -26,8 +43,11 @@ impl AppLogsPane {
             lines: VecDeque::new(),
             scroll: 0,
             follow: true,
+            max_level: None,
+            module_filter: None,
             last_cursor: 0,
             visible_height: Cell::new(0),
+            redraw: false,
         }
     }
 
@@ -36,10 +56,12 @@ impl AppLogsPane {
             let (lines, cursor) = app_log::recent_lines_with_cursor(LOG_LINE_LIMIT);
             self.lines = VecDeque::from(lines);
             self.last_cursor = cursor;
+            self.redraw = !self.lines.is_empty();
         } else {
             let (new_lines, cursor) = app_log::fetch_since(self.last_cursor);
             self.last_cursor = cursor;
             if !new_lines.is_empty() {
+                self.redraw = true;
                 self.lines.extend(new_lines);
                 while self.lines.len() > LOG_LINE_LIMIT {
                     self.lines.pop_front();
@@ -54,7 +76,30 @@ impl AppLogsPane {
     }
 
     fn max_scroll(&self) -> usize {
-        self.lines.len().saturating_sub(1)
+        self.visible_lines().len().saturating_sub(1)
+    }
+
+    /// Lines passing the level and module filters. Continuation lines follow their event.
+    fn visible_lines(&self) -> Vec<&str> {
+        if self.max_level.is_none() && self.module_filter.is_none() {
+            return self.lines.iter().map(String::as_str).collect();
+        }
+        let mut keep = false;
+        self.lines
+            .iter()
+            .filter(|line| {
+                if let Some((level, target)) = parse_line(line) {
+                    keep = self.max_level.is_none_or(|max| level <= max)
+                        && self.module_filter.as_ref().is_none_or(|m| target.to_lowercase().contains(m));
+                }
+                keep
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn refilter(&mut self) {
+        self.scroll = if self.follow { self.max_scroll() } else { self.scroll.min(self.max_scroll()) };
     }
 }
 
@@ -62,19 +107,30 @@ impl Pane for AppLogsPane {
     fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &kubetile_tui::theme::Theme) {
         let border_style = if focused { theme.border_active } else { theme.border };
         let mode = if self.follow { "follow" } else { "paused" };
+        let mut title = format!(" App Logs ({mode})");
+        if let Some(max) = self.max_level {
+            title.push_str(&format!(" [{}+]", LEVELS[max].to_lowercase()));
+        }
+        if let Some(ref module) = self.module_filter {
+            title.push_str(&format!(" [/{module}]"));
+        }
+        title.push(' ');
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(format!(" App Logs ({mode}) "))
+            .title(title)
             .title_style(Style::default().fg(theme.accent).bold());
         let inner = block.inner(area);
         self.visible_height.set(inner.height);
         frame.render_widget(block, area);
 
+        let visible = self.visible_lines();
         let text = if self.lines.is_empty() {
             "No app logs yet".to_string()
+        } else if visible.is_empty() {
+            "No app logs match the filter".to_string()
         } else {
-            self.lines.iter().cloned().collect::<Vec<_>>().join("\n")
+            visible.join("\n")
         };
         let paragraph = Paragraph::new(text).scroll((self.scroll as u16, 0));
         frame.render_widget(paragraph, inner);
@@ -100,6 +156,23 @@ impl Pane for AppLogsPane {
                 let page = self.visible_height.get().max(1) as usize;
                 self.scroll = self.scroll.saturating_add(page).min(self.max_scroll());
             }
+            PaneCommand::CycleLogLevel => {
+                // All → error → warn → info → debug → all.
+                self.max_level = match self.max_level {
+                    None => Some(0),
+                    Some(level) if level + 2 < LEVELS.len() => Some(level + 1),
+                    Some(_) => None,
+                };
+                self.refilter();
+            }
+            PaneCommand::Filter(text) => {
+                self.module_filter = Some(text.to_lowercase());
+                self.refilter();
+            }
+            PaneCommand::ClearFilter => {
+                self.module_filter = None;
+                self.refilter();
+            }
             PaneCommand::ToggleFollow => {
                 self.follow = !self.follow;
                 if self.follow {
@@ -110,6 +183,10 @@ impl Pane for AppLogsPane {
         }
     }
 
+    fn take_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.redraw)
+    }
+
     fn view_type(&self) -> &ViewType {
         &self.view_type
     }
@@ -122,3 +199,43 @@ impl Pane for AppLogsPane {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pane(lines: &[&str]) -> AppLogsPane {
+        let mut pane = AppLogsPane::new();
+        pane.lines = lines.iter().map(|l| l.to_string()).collect();
+        pane
+    }
+
+    #[test]
+    fn filters_by_level_and_module_keeping_continuation_lines() {
+        let mut pane = pane(&[
+            "2026-10-16T10:00:00Z ERROR kubetile::app::query: query failed",
+            "  caused by: timeout",
+            "2026-10-16T10:00:01Z  WARN kubetile::app: slow watch",
+            "2026-10-16T10:00:02Z  INFO kubetile_core::client: connected",
+            "2026-10-16T10:00:03Z DEBUG kubetile::app::query: sent",
+        ]);
+        assert_eq!(pane.visible_lines().len(), 5);
+
+        pane.handle_command(&PaneCommand::CycleLogLevel);
+        assert_eq!(pane.visible_lines().len(), 2);
+        pane.handle_command(&PaneCommand::CycleLogLevel);
+        pane.handle_command(&PaneCommand::CycleLogLevel);
+        assert_eq!(pane.visible_lines().len(), 4);
+
+        pane.handle_command(&PaneCommand::Filter("QUERY".into()));
+        assert_eq!(
+            pane.visible_lines(),
+            ["2026-10-16T10:00:00Z ERROR kubetile::app::query: query failed", "  caused by: timeout"]
+        );
+
+        pane.handle_command(&PaneCommand::CycleLogLevel);
+        pane.handle_command(&PaneCommand::CycleLogLevel);
+        assert_eq!(pane.max_level, None);
+        assert_eq!(pane.visible_lines().len(), 3);
+    }
+}
@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::cell::Cell;
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Frame, Line, Rect, Span, Style, Stylize};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use kubetile_core::{ApplyChange, DiffLine, PlannedApply};
+use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
+use kubetile_tui::theme::Theme;
+
+/// Rows the document list takes at most before the diff gets the rest.
+const MAX_LIST_ROWS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DocumentState {
+    Accepted,
+    Skipped,
+    Applying,
+    Applied,
+    Failed(String),
+}
+
+struct Document {
+    plan: PlannedApply,
+    state: DocumentState,
+}
+
+/// Documents of local manifests with the diff a server-side dry run gave for each; the accepted
+/// ones are applied together.
+pub struct ApplyPane {
+    view_type: ViewType,
+    path: String,
+    /// Context the dry run ran against; the documents are only applied there.
+    context: String,
+    documents: Vec<Document>,
+    selected: usize,
+    diff_scroll: usize,
+    diff_height: Cell<u16>,
+}
+
+impl ApplyPane {
+    /// Documents that would change something start accepted; unchanged and failed ones skipped.
+    pub fn new(path: String, context: String, plans: Vec<PlannedApply>) -> Self {
+        let documents = plans
+            .into_iter()
+            .map(|plan| {
+                let state = match &plan.outcome {
+                    Ok(planned) if planned.change != ApplyChange::Unchanged => DocumentState::Accepted,
+                    _ => DocumentState::Skipped,
+                };
+                Document { plan, state }
+            })
+            .collect();
+        Self {
+            view_type: ViewType::Plugin("Apply".into()),
+            path,
+            context,
+            documents,
+            selected: 0,
+            diff_scroll: 0,
+            diff_height: Cell::new(0),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    pub fn accepted_count(&self) -> usize {
+        self.documents.iter().filter(|d| d.state == DocumentState::Accepted).count()
+    }
+
+    /// Flips the selected document between accepted and skipped. Documents the dry run rejected
+    /// stay skipped; a failed apply can be accepted again to retry it.
+    pub fn toggle_selected(&mut self) {
+        let Some(doc) = self.documents.get_mut(self.selected) else { return };
+        if doc.plan.outcome.is_err() {
+            return;
+        }
+        doc.state = match doc.state {
+            DocumentState::Skipped | DocumentState::Failed(_) => DocumentState::Accepted,
+            DocumentState::Accepted => DocumentState::Skipped,
+            ref other => other.clone(),
+        };
+    }
+
+    /// Marks the accepted documents as being applied and hands them out with their positions.
+    pub fn start_apply(&mut self) -> Vec<(usize, PlannedApply)> {
+        self.documents
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, d)| d.state == DocumentState::Accepted)
+            .map(|(i, d)| {
+                d.state = DocumentState::Applying;
+                (i, d.plan.clone())
+            })
+            .collect()
+    }
+
+    pub fn set_result(&mut self, index: usize, result: Result<(), String>) {
+        if let Some(doc) = self.documents.get_mut(index) {
+            doc.state = match result {
+                Ok(()) => DocumentState::Applied,
+                Err(e) => DocumentState::Failed(e),
+            };
+        }
+    }
+
+    fn select_by(&mut self, step: isize) {
+        let count = self.documents.len() as isize;
+        if count > 0 {
+            self.selected = (self.selected as isize + step).clamp(0, count - 1) as usize;
+            self.diff_scroll = 0;
+        }
+    }
+
+    fn document_line(doc: &Document, theme: &Theme) -> Line<'static> {
+        let (mark, mark_style) = match &doc.state {
+            DocumentState::Accepted => ("[x]", theme.accent.into()),
+            DocumentState::Skipped => ("[ ]", theme.text_dim),
+            DocumentState::Applying => (" … ", theme.text_dim),
+            DocumentState::Applied => (" ✓ ", theme.status_running),
+            DocumentState::Failed(_) => (" ✗ ", theme.status_failed),
+        };
+        let (change, change_style) = match &doc.plan.outcome {
+            Ok(planned) => match planned.change {
+                ApplyChange::Create => ("create", theme.status_running),
+                ApplyChange::Update => ("update", theme.status_pending),
+                ApplyChange::Unchanged => ("unchanged", theme.text_dim),
+            },
+            Err(_) => ("error", theme.status_failed),
+        };
+        Line::from(vec![
+            Span::styled(format!(" {mark} "), mark_style),
+            Span::styled(format!("{change:<10}"), change_style),
+            Span::raw(doc.plan.target()),
+            Span::styled(format!("  {}", doc.plan.source), theme.text_dim),
+        ])
+    }
+
+    fn diff_len(doc: &Document) -> usize {
+        let failed = usize::from(matches!(doc.state, DocumentState::Failed(_)));
+        failed + doc.plan.outcome.as_ref().map_or(1, |planned| planned.diff.len().max(1))
+    }
+
+    fn diff_lines(doc: &Document, theme: &Theme) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        if let DocumentState::Failed(e) = &doc.state {
+            lines.push(Line::styled(format!("Apply failed: {e}"), theme.status_failed));
+        }
+        match &doc.plan.outcome {
+            Err(e) => lines.push(Line::styled(format!("Dry run failed: {e}"), theme.status_failed)),
+            Ok(planned) if planned.diff.is_empty() => lines.push(Line::styled("No changes", theme.text_dim)),
+            Ok(planned) => lines.extend(planned.diff.iter().map(|line| match line {
+                DiffLine::Context(text) => Line::raw(format!("  {text}")),
+                DiffLine::Added(text) => Line::styled(format!("+ {text}"), theme.status_running),
+                DiffLine::Removed(text) => Line::styled(format!("- {text}"), theme.status_failed),
+                DiffLine::Skipped(n) => Line::styled(format!("  ⋯ {n} unchanged lines"), theme.text_dim),
+            })),
+        }
+        lines
+    }
+}
+
+impl Pane for ApplyPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border = if focused { theme.border_active } else { theme.border };
+        let list_rows = self.documents.len().clamp(1, MAX_LIST_ROWS) as u16 + 2;
+        let [list_area, diff_area] = Layout::vertical([Constraint::Length(list_rows), Constraint::Min(3)]).areas(area);
+
+        let title = format!(" Apply: {} — {} of {} accepted ", self.path, self.accepted_count(), self.documents.len());
+        let list_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border)
+            .title(title)
+            .title_style(Style::default().fg(theme.accent).bold());
+        let visible = list_rows as usize - 2;
+        let first = self.selected.saturating_sub(visible.saturating_sub(1));
+        let rows: Vec<Line> = self
+            .documents
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .map(|(i, doc)| {
+                let line = Self::document_line(doc, theme);
+                if i == self.selected && focused {
+                    line.style(theme.selection)
+                } else {
+                    line
+                }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(rows).block(list_block), list_area);
+
+        let Some(doc) = self.documents.get(self.selected) else { return };
+        let diff_block =
+            Block::default().borders(Borders::ALL).border_style(border).title(format!(" Diff: {} ", doc.plan.source));
+        self.diff_height.set(diff_block.inner(diff_area).height);
+        let lines = Self::diff_lines(doc, theme);
+        let scroll = self.diff_scroll.min(lines.len().saturating_sub(1));
+        frame.render_widget(Paragraph::new(lines).block(diff_block).scroll((scroll as u16, 0)), diff_area);
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        match cmd {
+            PaneCommand::SelectNext | PaneCommand::ScrollDown => self.select_by(1),
+            PaneCommand::SelectPrev | PaneCommand::ScrollUp => self.select_by(-1),
+            PaneCommand::GoToTop => self.select_by(-(self.documents.len() as isize)),
+            PaneCommand::GoToBottom => self.select_by(self.documents.len() as isize),
+            PaneCommand::Select => self.toggle_selected(),
+            PaneCommand::PageDown => {
+                let last = self.documents.get(self.selected).map_or(0, |d| Self::diff_len(d).saturating_sub(1));
+                self.diff_scroll = (self.diff_scroll + self.diff_height.get().max(1) as usize).min(last);
+            }
+            PaneCommand::PageUp => {
+                self.diff_scroll = self.diff_scroll.saturating_sub(self.diff_height.get().max(1) as usize);
+            }
+            _ => {}
+        }
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,137 @@
+use std::any::Any;
+use std::cell::Cell;
+
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+
+use kubetile_core::resource::DetailSection;
+use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
+use kubetile_tui::theme::Theme;
+
+/// Why the last connection to the cluster failed, what to try, and how the context connects.
+pub struct ConnectionPane {
+    view_type: ViewType,
+    sections: Vec<DetailSection>,
+    scroll: usize,
+    visible_height: Cell<u16>,
+    redraw: bool,
+}
+
+impl ConnectionPane {
+    pub fn new(sections: Vec<DetailSection>) -> Self {
+        Self {
+            view_type: ViewType::Plugin("Connection".into()),
+            sections,
+            scroll: 0,
+            visible_height: Cell::new(0),
+            redraw: false,
+        }
+    }
+
+    pub fn set_sections(&mut self, sections: Vec<DetailSection>) {
+        self.sections = sections;
+        self.scroll = self.scroll.min(self.max_scroll());
+        self.redraw = true;
+    }
+
+    fn lines(&self, theme: &Theme) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        for section in &self.sections {
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::styled(section.title.as_str(), Style::default().fg(theme.accent).bold()));
+            let width = section.fields.iter().map(|(key, _)| key.chars().count()).max().unwrap_or(0);
+            for (key, value) in &section.fields {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {key:<width$}  "), theme.text_dim),
+                    Span::raw(value.as_str()),
+                ]));
+            }
+        }
+        lines
+    }
+
+    fn line_count(&self) -> usize {
+        self.sections.iter().map(|s| s.fields.len() + 2).sum::<usize>().saturating_sub(1)
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.line_count().saturating_sub(self.visible_height.get().max(1) as usize)
+    }
+}
+
+impl Pane for ConnectionPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border_style = if focused { theme.border_active } else { theme.border };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(" Connection ")
+            .title_style(Style::default().fg(theme.accent).bold());
+        let inner = block.inner(area);
+        self.visible_height.set(inner.height);
+        frame.render_widget(block, area);
+
+        let paragraph = Paragraph::new(self.lines(theme)).wrap(Wrap { trim: false }).scroll((self.scroll as u16, 0));
+        frame.render_widget(paragraph, inner);
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        let page = self.visible_height.get().max(1) as usize;
+        match cmd {
+            PaneCommand::SelectNext | PaneCommand::ScrollDown => {
+                self.scroll = self.scroll.saturating_add(1).min(self.max_scroll())
+            }
+            PaneCommand::SelectPrev | PaneCommand::ScrollUp => self.scroll = self.scroll.saturating_sub(1),
+            PaneCommand::PageDown => self.scroll = self.scroll.saturating_add(page).min(self.max_scroll()),
+            PaneCommand::PageUp => self.scroll = self.scroll.saturating_sub(page),
+            PaneCommand::GoToTop => self.scroll = 0,
+            PaneCommand::GoToBottom => self.scroll = self.max_scroll(),
+            _ => {}
+        }
+    }
+
+    fn take_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.redraw)
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    use super::*;
+
+    #[test]
+    fn renders_sections_with_aligned_fields() {
+        let pane = ConnectionPane::new(vec![
+            DetailSection {
+                title: "Problem".into(),
+                fields: vec![("Cause".into(), "The API server could not be reached".into())],
+            },
+            DetailSection { title: "What to try".into(), fields: vec![("1.".into(), "Connect the VPN".into())] },
+        ]);
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal.draw(|frame| pane.render(frame, frame.area(), true, &Theme::default())).unwrap();
+        let rows: Vec<String> =
+            (0..8).map(|y| (0..60).map(|x| terminal.backend().buffer()[(x, y)].symbol()).collect::<String>()).collect();
+        assert!(rows[0].contains(" Connection "));
+        assert!(rows[1].contains("Problem"));
+        assert!(rows[2].contains("  Cause  The API server could not be reached"));
+        assert!(rows[5].contains("  1.  Connect the VPN"));
+    }
+}
@@ -0,0 +1,362 @@
+use std::any::Any;
+use std::time::{Duration, Instant};
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Frame, Line, Rect, Style};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use kubetile_core::cluster_overview::{ClusterOverview, OverviewSection};
+use kubetile_core::listing::kind_from_name;
+use kubetile_core::resource::format_duration;
+use kubetile_tui::pane::{Pane, PaneCommand, ResourceKind, ViewType};
+use kubetile_tui::theme::Theme;
+
+/// How often an open dashboard re-reads the cluster.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+/// Lines listed under a tile's headline before the rest is summarised as `… N more`.
+const MAX_TILE_ITEMS: usize = 6;
+const TILES_PER_ROW: usize = 3;
+
+/// The list a dashboard tile opens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TileTarget {
+    pub kind: ResourceKind,
+    /// Namespace to narrow the list to; `None` lists every namespace.
+    pub namespace: Option<String>,
+    pub filter: String,
+    /// Server-side field selector for the list, e.g. `status.phase=Pending`.
+    pub field_selector: Option<String>,
+    /// Keep only the rows that are not healthy.
+    pub problems_only: bool,
+}
+
+impl TileTarget {
+    fn all(kind: ResourceKind) -> Self {
+        Self { kind, namespace: None, filter: String::new(), field_selector: None, problems_only: false }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tone {
+    Plain,
+    Warn,
+    Bad,
+}
+
+struct Tile {
+    title: &'static str,
+    lines: Vec<(String, Tone)>,
+    target: Option<TileTarget>,
+}
+
+/// Cluster-wide aggregates as a grid of tiles; `Enter` on a tile opens the matching list.
+pub struct DashboardPane {
+    view_type: ViewType,
+    tiles: Vec<Tile>,
+    selected: usize,
+    loading: bool,
+    error: Option<String>,
+    fetched_at: Option<Instant>,
+}
+
+impl DashboardPane {
+    pub fn new() -> Self {
+        Self {
+            view_type: ViewType::Plugin("Dashboard".into()),
+            tiles: Vec::new(),
+            selected: 0,
+            loading: false,
+            error: None,
+            fetched_at: None,
+        }
+    }
+
+    /// Whether the overview should be (re)fetched now; marks the pane as loading when it should.
+    pub fn start_refresh(&mut self, now: Instant) -> bool {
+        let due = self.fetched_at.is_none_or(|at| now.duration_since(at) >= REFRESH_INTERVAL);
+        if self.loading || !due {
+            return false;
+        }
+        self.loading = true;
+        true
+    }
+
+    /// Makes the next tick fetch the overview again.
+    pub fn request_refresh(&mut self) {
+        self.fetched_at = None;
+    }
+
+    pub fn set_overview(&mut self, overview: &ClusterOverview) {
+        self.tiles = tiles(overview);
+        self.selected = self.selected.min(self.tiles.len().saturating_sub(1));
+        self.error = None;
+        self.loading = false;
+        self.fetched_at = Some(Instant::now());
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+        self.loading = false;
+        self.fetched_at = Some(Instant::now());
+    }
+
+    pub fn selected_target(&self) -> Option<&TileTarget> {
+        self.tiles.get(self.selected)?.target.as_ref()
+    }
+
+    pub fn selected_title(&self) -> Option<&str> {
+        self.tiles.get(self.selected).map(|t| t.title)
+    }
+
+    fn select_by(&mut self, step: isize) {
+        let count = self.tiles.len() as isize;
+        if count > 0 {
+            self.selected = (self.selected as isize + step).rem_euclid(count) as usize;
+        }
+    }
+
+    fn tone_style(tone: Tone, theme: &Theme) -> Style {
+        match tone {
+            Tone::Plain => Style::default(),
+            Tone::Warn => theme.status_pending,
+            Tone::Bad => theme.status_failed,
+        }
+    }
+}
+
+fn tiles(overview: &ClusterOverview) -> Vec<Tile> {
+    let not_ready = overview.nodes_not_ready.len();
+    let mut nodes = vec![(
+        format!("{}/{} ready", overview.nodes_total - not_ready, overview.nodes_total),
+        if not_ready > 0 { Tone::Bad } else { Tone::Plain },
+    )];
+    nodes.extend(listed(&overview.nodes_not_ready, Tone::Bad));
+
+    let phases = overview
+        .pod_phases
+        .iter()
+        .map(|(phase, count)| {
+            let tone = match phase.as_str() {
+                "Pending" => Tone::Warn,
+                "Failed" | "Unknown" => Tone::Bad,
+                _ => Tone::Plain,
+            };
+            (format!("{phase}: {count}"), tone)
+        })
+        .collect();
+
+    let mut pending = vec![headline(overview.pending_pods.len(), "pending", Tone::Warn)];
+    pending.extend(listed(&overview.pending_pods, Tone::Warn));
+
+    let failing: Vec<String> = overview
+        .failing_deployments
+        .iter()
+        .map(|d| format!("{}/{} {}/{}", d.namespace, d.name, d.ready, d.desired))
+        .collect();
+    let mut deployments = vec![headline(failing.len(), "failing", Tone::Bad)];
+    deployments.extend(listed(&failing, Tone::Bad));
+
+    let events: Vec<String> = overview
+        .warnings
+        .iter()
+        .map(|w| format!("{} {} {}/{}: {}", format_duration(w.age), w.reason, w.kind, w.name, w.message))
+        .collect();
+    let mut warnings = vec![headline(events.len(), "warnings", Tone::Warn)];
+    warnings.extend(listed(&events, Tone::Warn));
+    // Opens the object of the newest warning.
+    let warning_target = overview.warnings.first().and_then(|w| {
+        let kind = kind_from_name(&w.kind).and_then(|k| ResourceKind::from_short_name(k.short_name()))?;
+        let namespace = Some(w.namespace.clone()).filter(|ns| kind.is_namespaced() && !ns.is_empty());
+        Some(TileTarget { namespace, filter: w.name.clone(), ..TileTarget::all(kind) })
+    });
+
+    // A tile whose list could not be read shows why instead of its (empty) aggregates.
+    let or_error = |section: OverviewSection, lines: Vec<(String, Tone)>| match overview.error(section) {
+        Some(error) => vec![(error.to_string(), Tone::Bad)],
+        None => lines,
+    };
+    let nodes = or_error(OverviewSection::Nodes, nodes);
+    let phases = or_error(OverviewSection::Pods, phases);
+    let pending = or_error(OverviewSection::Pods, pending);
+    let deployments = or_error(OverviewSection::Deployments, deployments);
+    let warnings = or_error(OverviewSection::Warnings, warnings);
+
+    vec![
+        Tile {
+            title: "Nodes",
+            lines: nodes,
+            target: Some(TileTarget { problems_only: not_ready > 0, ..TileTarget::all(ResourceKind::Nodes) }),
+        },
+        Tile { title: "Pod Phases", lines: phases, target: Some(TileTarget::all(ResourceKind::Pods)) },
+        Tile {
+            title: "Pending Pods",
+            lines: pending,
+            target: Some(TileTarget {
+                field_selector: Some("status.phase=Pending".into()),
+                ..TileTarget::all(ResourceKind::Pods)
+            }),
+        },
+        Tile {
+            title: "Failing Deployments",
+            lines: deployments,
+            target: Some(TileTarget { problems_only: true, ..TileTarget::all(ResourceKind::Deployments) }),
+        },
+        Tile { title: "Warning Events (last hour)", lines: warnings, target: warning_target },
+    ]
+}
+
+fn headline(count: usize, what: &str, tone: Tone) -> (String, Tone) {
+    (format!("{count} {what}"), if count > 0 { tone } else { Tone::Plain })
+}
+
+fn listed(items: &[String], tone: Tone) -> Vec<(String, Tone)> {
+    let mut lines: Vec<(String, Tone)> = items.iter().take(MAX_TILE_ITEMS).map(|i| (format!("  {i}"), tone)).collect();
+    if items.len() > MAX_TILE_ITEMS {
+        lines.push((format!("  … {} more", items.len() - MAX_TILE_ITEMS), Tone::Plain));
+    }
+    lines
+}
+
+impl Pane for DashboardPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let status = match (&self.error, self.loading) {
+            (Some(e), _) => format!(" Cluster Overview — {e} "),
+            (None, true) => " Cluster Overview — refreshing… ".to_string(),
+            (None, false) => " Cluster Overview ".to_string(),
+        };
+        let border = if focused { theme.border_active } else { theme.border };
+        let outer = Block::default().borders(Borders::ALL).border_style(border).title(status);
+        let inner = outer.inner(area);
+        frame.render_widget(outer, area);
+
+        if self.tiles.is_empty() {
+            frame.render_widget(Paragraph::new("Loading…").style(theme.text_dim), inner);
+            return;
+        }
+
+        let rows = self.tiles.len().div_ceil(TILES_PER_ROW);
+        let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows as u32); rows]).split(inner);
+        for (row, row_area) in row_areas.iter().enumerate() {
+            let cells =
+                Layout::horizontal(vec![Constraint::Ratio(1, TILES_PER_ROW as u32); TILES_PER_ROW]).split(*row_area);
+            for (col, cell) in cells.iter().enumerate() {
+                let index = row * TILES_PER_ROW + col;
+                let Some(tile) = self.tiles.get(index) else { break };
+                let style = if index == self.selected && focused { theme.border_active } else { theme.border };
+                let block =
+                    Block::default().borders(Borders::ALL).border_style(style).title(format!(" {} ", tile.title));
+                let lines: Vec<Line> = tile
+                    .lines
+                    .iter()
+                    .map(|(text, tone)| Line::styled(text.clone(), Self::tone_style(*tone, theme)))
+                    .collect();
+                frame.render_widget(Paragraph::new(lines).block(block), *cell);
+            }
+        }
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        match cmd {
+            PaneCommand::SelectNext | PaneCommand::ScrollDown | PaneCommand::ScrollRight => self.select_by(1),
+            PaneCommand::SelectPrev | PaneCommand::ScrollUp | PaneCommand::ScrollLeft => self.select_by(-1),
+            PaneCommand::GoToTop => self.selected = 0,
+            PaneCommand::GoToBottom => self.selected = self.tiles.len().saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kubetile_core::cluster_overview::{FailingDeployment, WarningEvent};
+
+    use super::*;
+
+    fn overview() -> ClusterOverview {
+        ClusterOverview {
+            nodes_total: 3,
+            nodes_not_ready: vec!["node-2".into()],
+            pod_phases: vec![("Running".into(), 12), ("Pending".into(), 1)],
+            pending_pods: vec!["web/db-0".into()],
+            failing_deployments: vec![FailingDeployment {
+                namespace: "web".into(),
+                name: "db".into(),
+                ready: 1,
+                desired: 3,
+            }],
+            warnings: vec![WarningEvent {
+                namespace: "web".into(),
+                kind: "Pod".into(),
+                name: "db-0".into(),
+                reason: "FailedScheduling".into(),
+                message: "0/3 nodes are available".into(),
+                age: Duration::from_secs(120),
+            }],
+            errors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn tiles_lead_to_filtered_lists() {
+        let mut pane = DashboardPane::new();
+        pane.set_overview(&overview());
+        assert_eq!(pane.tiles[0].lines[0], ("2/3 ready".to_string(), Tone::Bad));
+        assert_eq!(
+            pane.selected_target(),
+            Some(&TileTarget { problems_only: true, ..TileTarget::all(ResourceKind::Nodes) })
+        );
+
+        pane.handle_command(&PaneCommand::SelectNext);
+        pane.handle_command(&PaneCommand::SelectNext);
+        assert_eq!(pane.selected_target().and_then(|t| t.field_selector.as_deref()), Some("status.phase=Pending"));
+
+        pane.handle_command(&PaneCommand::GoToBottom);
+        assert_eq!(
+            pane.selected_target(),
+            Some(&TileTarget {
+                kind: ResourceKind::Pods,
+                namespace: Some("web".into()),
+                filter: "db-0".into(),
+                field_selector: None,
+                problems_only: false,
+            })
+        );
+        pane.handle_command(&PaneCommand::SelectNext);
+        assert_eq!(pane.selected_title(), Some("Nodes"));
+    }
+
+    #[test]
+    fn a_failed_list_shows_its_error_in_its_tiles_only() {
+        let mut pane = DashboardPane::new();
+        let errors = vec![(OverviewSection::Pods, "pods is forbidden".to_string())];
+        pane.set_overview(&ClusterOverview { errors, ..overview() });
+        assert_eq!(pane.tiles[0].lines[0], ("2/3 ready".to_string(), Tone::Bad));
+        assert_eq!(pane.tiles[1].lines, vec![("pods is forbidden".to_string(), Tone::Bad)]);
+        assert_eq!(pane.tiles[2].lines, vec![("pods is forbidden".to_string(), Tone::Bad)]);
+        assert_eq!(pane.tiles[3].lines[0], ("1 failing".to_string(), Tone::Bad));
+    }
+
+    #[test]
+    fn refresh_waits_for_the_interval_and_the_pending_fetch() {
+        let mut pane = DashboardPane::new();
+        let now = Instant::now();
+        assert!(pane.start_refresh(now));
+        assert!(!pane.start_refresh(now), "a fetch is already running");
+        pane.set_overview(&ClusterOverview::default());
+        assert!(!pane.start_refresh(Instant::now()));
+        pane.request_refresh();
+        assert!(pane.start_refresh(Instant::now()));
+    }
+}
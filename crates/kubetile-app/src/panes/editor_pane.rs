@@ -0,0 +1,119 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::path::Path;
+
+use portable_pty::CommandBuilder;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use tokio::sync::mpsc as tokio_mpsc;
+
+use kubetile_terminal::render_terminal_screen;
+use kubetile_tui::pane::{Pane, PaneCommand, PaneId, ViewType};
+use kubetile_tui::theme::Theme;
+
+use super::pty::{content_area, fit_to_area, PtyProcess};
+use crate::event::AppEvent;
+
+/// `general.editor` running on a temporary file in a PTY. The app picks the file up again once
+/// the editor exits.
+pub struct EditorPane {
+    view_type: ViewType,
+    /// What is being edited, e.g. `cm web/settings app.conf`.
+    title: String,
+    process: Option<PtyProcess>,
+    vt: RefCell<vt100::Parser>,
+}
+
+impl EditorPane {
+    pub fn new(title: String) -> Self {
+        Self {
+            view_type: ViewType::Terminal,
+            title,
+            process: None,
+            vt: RefCell::new(vt100::Parser::new(48, 160, 10_000)),
+        }
+    }
+
+    /// Starts `editor` on `path` through `sh -c`, so values like `$EDITOR` or `code --wait`
+    /// work; `$EDITOR` falls back to `vi` when it is unset.
+    pub fn spawn(&mut self, editor: &str, path: &Path) -> anyhow::Result<()> {
+        let mut cmd = CommandBuilder::new("sh");
+        cmd.args(["-c", &format!("exec {editor} \"$1\""), "kubetile-editor"]);
+        cmd.arg(path);
+        if std::env::var_os("EDITOR").is_none() {
+            cmd.env("EDITOR", "vi");
+        }
+        if let Ok(cwd) = std::env::current_dir() {
+            cmd.cwd(cwd);
+        }
+
+        tracing::info!("editor: spawning `{editor}` on {}", path.display());
+        self.process = Some(PtyProcess::spawn_sized(cmd, &self.vt.borrow())?);
+        Ok(())
+    }
+
+    pub fn start_output_forwarding(&mut self, pane_id: PaneId, app_tx: tokio_mpsc::UnboundedSender<AppEvent>) {
+        if let Some(process) = self.process.as_mut() {
+            process.start_output_forwarding(pane_id, app_tx);
+        }
+    }
+
+    pub fn process_output(&mut self, data: &[u8]) {
+        self.vt.borrow_mut().process(data);
+    }
+}
+
+impl Pane for EditorPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border_style = if focused { theme.border_active } else { theme.border };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!(" [edit:{}] ", self.title))
+            .title_style(Style::default().fg(theme.accent).bold());
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.height == 0 {
+            return;
+        }
+
+        let content_area = content_area(area);
+        let mut vt = self.vt.borrow_mut();
+        fit_to_area(&mut vt, self.process.as_ref(), content_area);
+
+        render_terminal_screen(vt.screen(), content_area, frame.buffer_mut());
+
+        let footer_area =
+            Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
+        frame.render_widget(
+            Paragraph::new("Save and quit the editor to apply | Insert mode to type").style(theme.status_bar),
+            footer_area,
+        );
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        if let PaneCommand::SendInput(input) = cmd {
+            if let Some(process) = self.process.as_mut() {
+                process.write(input);
+            }
+        }
+    }
+
+    fn fit(&mut self, area: Rect) {
+        fit_to_area(self.vt.get_mut(), self.process.as_ref(), content_area(area));
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -1,30 +1,35 @@
 use std::any::Any;
 use std::cell::RefCell;
-use std::io::Write;
-use std::sync::mpsc as std_mpsc;
 
-use portable_pty::{native_pty_system, Child, CommandBuilder, MasterPty, PtySize};
+use portable_pty::CommandBuilder;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 use tokio::sync::mpsc as tokio_mpsc;
 
-use kubetile_terminal::render_terminal_screen;
+use kubetile_terminal::{render_terminal_screen, EscapeFilter, Osc52Scanner};
 use kubetile_tui::pane::{Pane, PaneCommand, PaneId, ViewType};
 use kubetile_tui::theme::Theme;
 
+use super::pty::{content_area, fit_to_area, PtyProcess};
 use crate::event::AppEvent;
 
+/// Starts the best shell the container has.
+const SHELL_PROBE: &str = r#"if command -v zsh >/dev/null 2>&1; then exec zsh -i; fi; if command -v bash >/dev/null 2>&1; then exec bash -i; fi; exec sh -i"#;
+
 pub struct ExecPane {
     view_type: ViewType,
     pod_name: String,
     container: String,
     namespace: String,
-    pty_master: Option<Box<dyn MasterPty + Send>>,
-    child: Option<Box<dyn Child + Send + Sync>>,
-    output_rx: Option<std_mpsc::Receiver<Vec<u8>>>,
-    writer: Option<Box<dyn Write + Send>>,
+    process: Option<PtyProcess>,
     vt: RefCell<vt100::Parser>,
     status: String,
+    synchronized: bool,
+    osc52: Osc52Scanner,
+    copied: Option<String>,
+    escapes: EscapeFilter,
+    hidden_images: usize,
+    exited: bool,
 }
 
 impl ExecPane {
@@ -34,95 +39,135 @@ impl ExecPane {
             pod_name,
             container,
             namespace,
-            pty_master: None,
-            child: None,
-            output_rx: None,
-            writer: None,
+            process: None,
             vt: RefCell::new(vt100::Parser::new(48, 160, 10_000)),
             status: "Connecting...".into(),
+            synchronized: false,
+            osc52: Osc52Scanner::default(),
+            copied: None,
+            escapes: EscapeFilter::default(),
+            hidden_images: 0,
+            exited: false,
+        }
+    }
+
+    pub fn pod_name(&self) -> &str {
+        &self.pod_name
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Container name, or `auto` when kubectl picks the default container.
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Keeps up to `lines` lines that scrolled off the top, per `terminal.scrollback_lines`.
+    pub fn with_scrollback(mut self, lines: usize) -> Self {
+        let (rows, cols) = self.vt.get_mut().screen().size();
+        self.vt = RefCell::new(vt100::Parser::new(rows, cols, lines));
+        self
+    }
+
+    /// vt100 clamps the offset to the scrollback it holds, and keeps a scrolled view in place as
+    /// new output arrives.
+    fn scroll_by(&mut self, delta: isize) {
+        let vt = self.vt.get_mut();
+        let offset = vt.screen().scrollback().saturating_add_signed(delta);
+        vt.set_scrollback(offset);
+    }
+
+    fn page(&self) -> isize {
+        self.vt.borrow().screen().size().0.max(1) as isize
+    }
+
+    /// Moves the session's output to `pane_id`, where it is reattached.
+    pub fn retarget(&self, pane_id: PaneId) {
+        if let Some(process) = &self.process {
+            process.retarget(pane_id);
         }
     }
 
-    pub fn spawn_kubectl(&mut self, context: Option<&str>) -> anyhow::Result<()> {
-        let pty_system = native_pty_system();
-        let pty_size = PtySize { cols: 160, rows: 48, pixel_width: 0, pixel_height: 0 };
-        let pair = pty_system.openpty(pty_size)?;
+    pub fn mark_exited(&mut self) {
+        self.exited = true;
+    }
+
+    /// Whether a process is still running in the pane, so closing it would end its work.
+    pub fn is_running(&self) -> bool {
+        self.process.is_some() && !self.exited
+    }
+
+    /// Marks the pane as receiving the input typed into any exec pane of its tab.
+    pub fn set_synchronized(&mut self, synchronized: bool) {
+        self.synchronized = synchronized;
+    }
 
-        let mut cmd = CommandBuilder::new("kubectl");
-        cmd.arg("exec");
-        cmd.arg("-it");
-        cmd.arg("-n");
-        cmd.arg(&self.namespace);
+    /// Spawns `kubectl exec` in a PTY, on `ssh_host` when the context is configured to run kubectl remotely.
+    pub fn spawn_kubectl(&mut self, context: Option<&str>, ssh_host: Option<&str>) -> anyhow::Result<()> {
+        let mut args: Vec<String> = vec!["exec".into(), "-it".into(), "-n".into(), self.namespace.clone()];
         if let Some(ctx) = context {
-            cmd.arg("--context");
-            cmd.arg(ctx);
+            args.push("--context".into());
+            args.push(ctx.into());
         }
-        cmd.arg(&self.pod_name);
+        args.push(self.pod_name.clone());
         if self.container != "auto" {
-            cmd.arg("-c");
-            cmd.arg(&self.container);
+            args.push("-c".into());
+            args.push(self.container.clone());
         }
-        cmd.arg("--");
-        cmd.arg("sh");
-        cmd.arg("-c");
-        cmd.arg(
-            r#"if command -v zsh >/dev/null 2>&1; then exec zsh -i; fi; if command -v bash >/dev/null 2>&1; then exec bash -i; fi; exec sh -i"#,
-        );
+        args.push("--".into());
+        args.extend(Self::shell_command());
+
+        let argv = kubetile_core::kubectl_argv(ssh_host, true, &args);
+        let mut cmd = CommandBuilder::new(&argv[0]);
+        cmd.args(&argv[1..]);
 
         tracing::info!(
-            "exec: spawning kubectl exec -it -n {} {} (context: {:?}, container: {})",
+            "exec: spawning kubectl exec -it -n {} {} (context: {:?}, container: {}, ssh host: {:?})",
             self.namespace,
             self.pod_name,
             context,
             self.container,
+            ssh_host,
         );
-        let child = pair.slave.spawn_command(cmd)?;
-        let mut reader = pair.master.try_clone_reader()?;
-        let writer = pair.master.take_writer()?;
-
-        let (tx, rx) = std_mpsc::channel::<Vec<u8>>();
-        std::thread::spawn(move || {
-            use std::io::Read;
-            let mut buf = [0u8; 4096];
-            loop {
-                match reader.read(&mut buf) {
-                    Ok(0) => break,
-                    Ok(n) => {
-                        if tx.send(buf[..n].to_vec()).is_err() {
-                            break;
-                        }
-                    }
-                    Err(_) => break,
-                }
-            }
-        });
-
-        self.pty_master = Some(pair.master);
-        self.child = Some(child);
-        self.output_rx = Some(rx);
-        self.writer = Some(writer);
+        self.process = Some(PtyProcess::spawn_sized(cmd, &self.vt.borrow())?);
         self.status = "Connected".into();
 
         Ok(())
     }
 
-    /// Takes the PTY output receiver and spawns a thread that forwards all output
-    /// into the app event channel as `AppEvent::PtyOutput`. When the PTY reader
-    /// closes, sends `AppEvent::ExecExited`.
+    /// Command run in the container: a login shell, picked by what the image has.
+    pub fn shell_command() -> Vec<String> {
+        vec!["sh".into(), "-c".into(), SHELL_PROBE.into()]
+    }
+
+    /// Takes over an exec session opened through the API server, in place of `kubectl exec`.
+    pub fn attach_session(&mut self, session: kubetile_core::ExecSession) {
+        self.process = Some(PtyProcess::from_exec(session, &self.vt.borrow()));
+        self.status = "Connected".into();
+    }
+
+    /// Forwards PTY output to the app as `AppEvent::PtyOutput`, then `AppEvent::ExecExited`.
     pub fn start_output_forwarding(&mut self, pane_id: PaneId, app_tx: tokio_mpsc::UnboundedSender<AppEvent>) {
-        let Some(rx) = self.output_rx.take() else { return };
-        std::thread::spawn(move || {
-            while let Ok(data) = rx.recv() {
-                if app_tx.send(AppEvent::PtyOutput { pane_id, data }).is_err() {
-                    return;
-                }
-            }
-            let _ = app_tx.send(AppEvent::ExecExited { pane_id });
-        });
+        if let Some(process) = self.process.as_mut() {
+            process.start_output_forwarding(pane_id, app_tx);
+        }
     }
 
+    /// Feeds program output to the screen, minus images and other sequences vt100 would garble.
     pub fn process_output(&mut self, data: &[u8]) {
-        self.vt.borrow_mut().process(data);
+        let filtered = self.escapes.filter(data);
+        self.hidden_images += filtered.images;
+        if let Some(text) = self.osc52.scan(&filtered.bytes).pop() {
+            self.copied = Some(text);
+        }
+        self.vt.borrow_mut().process(&filtered.bytes);
+    }
+
+    /// Text the program last copied with OSC 52, for the app to put on the host clipboard.
+    pub fn take_copied(&mut self) -> Option<String> {
+        self.copied.take()
     }
 
     fn render_title(&self) -> String {
@@ -146,15 +191,9 @@ impl Pane for ExecPane {
             return;
         }
 
-        let content_area = Rect { x: inner.x, y: inner.y, width: inner.width, height: inner.height.saturating_sub(1) };
+        let content_area = content_area(area);
         let mut vt = self.vt.borrow_mut();
-        let rows = content_area.height.max(1);
-        let cols = content_area.width.max(1);
-        vt.set_size(rows, cols);
-
-        if let Some(pty_master) = &self.pty_master {
-            let _ = pty_master.resize(PtySize { cols, rows, pixel_width: 0, pixel_height: 0 });
-        }
+        fit_to_area(&mut vt, self.process.as_ref(), content_area);
 
         render_terminal_screen(vt.screen(), content_area, frame.buffer_mut());
         if self.status == "Connecting..." {
@@ -163,21 +202,57 @@ impl Pane for ExecPane {
 
         let footer_area =
             Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
+        let sync = if self.synchronized { " | SYNC" } else { "" };
+        let images = match self.hidden_images {
+            0 => String::new(),
+            1 => " | 1 image hidden".into(),
+            n => format!(" | {n} images hidden"),
+        };
+        let scrolled = match vt.screen().scrollback() {
+            0 => String::new(),
+            offset => format!(" | SCROLLBACK +{offset} | G for live output"),
+        };
         frame.render_widget(
-            Paragraph::new(format!("{} | Insert mode to type", self.status)).style(theme.status_bar),
+            Paragraph::new(format!("{}{sync}{images}{scrolled} | Insert mode to type", self.status))
+                .style(theme.status_bar),
             footer_area,
         );
     }
 
     fn handle_command(&mut self, cmd: &PaneCommand) {
-        if let PaneCommand::SendInput(input) = cmd {
-            if let Some(writer) = self.writer.as_mut() {
-                let _ = writer.write_all(input.as_bytes());
-                let _ = writer.flush();
+        match cmd {
+            PaneCommand::SendInput(input) => {
+                self.vt.get_mut().set_scrollback(0);
+                if let Some(process) = self.process.as_mut() {
+                    process.write(input);
+                }
             }
+            PaneCommand::ScrollUp | PaneCommand::SelectPrev => self.scroll_by(1),
+            PaneCommand::ScrollDown | PaneCommand::SelectNext => self.scroll_by(-1),
+            PaneCommand::PageUp => self.scroll_by(self.page()),
+            PaneCommand::PageDown => self.scroll_by(-self.page()),
+            PaneCommand::GoToTop => self.vt.get_mut().set_scrollback(usize::MAX),
+            PaneCommand::GoToBottom => self.vt.get_mut().set_scrollback(0),
+            _ => {}
         }
     }
 
+    /// The screen plus its scrollback; vt100 only reports how far back it can scroll, so the
+    /// offset is pushed to the end and restored to read that.
+    fn memory_usage(&self) -> usize {
+        let mut vt = self.vt.borrow_mut();
+        let offset = vt.screen().scrollback();
+        vt.set_scrollback(usize::MAX);
+        let scrollback = vt.screen().scrollback();
+        vt.set_scrollback(offset);
+        let (rows, cols) = vt.screen().size();
+        (scrollback + rows as usize) * cols as usize * std::mem::size_of::<vt100::Cell>()
+    }
+
+    fn fit(&mut self, area: Rect) {
+        fit_to_area(self.vt.get_mut(), self.process.as_ref(), content_area(area));
+    }
+
     fn view_type(&self) -> &ViewType {
         &self.view_type
     }
@@ -191,11 +266,51 @@ impl Pane for ExecPane {
     }
 }
 
-impl Drop for ExecPane {
-    fn drop(&mut self) {
-        if let Some(child) = self.child.as_mut() {
-            let _ = child.kill();
-            let _ = child.wait();
+#[cfg(test)]
+mod tests {
+    use super::ExecPane;
+    use kubetile_tui::pane::{Pane, PaneCommand};
+
+    fn offset(pane: &ExecPane) -> usize {
+        pane.vt.borrow().screen().scrollback()
+    }
+
+    fn pane_with_lines(lines: usize) -> ExecPane {
+        let mut pane = ExecPane::new("pod-a".into(), "auto".into(), "default".into()).with_scrollback(100);
+        for i in 0..lines {
+            pane.process_output(format!("line {i}\r\n").as_bytes());
         }
+        pane
+    }
+
+    #[test]
+    fn scrollback_is_capped_and_stays_put_while_output_arrives() {
+        let mut pane = pane_with_lines(300);
+        pane.handle_command(&PaneCommand::GoToTop);
+        assert_eq!(offset(&pane), 100, "only terminal.scrollback_lines are kept");
+
+        pane.handle_command(&PaneCommand::GoToBottom);
+        pane.handle_command(&PaneCommand::PageUp);
+        assert_eq!(offset(&pane), 48);
+        pane.process_output(b"new\r\n");
+        assert_eq!(offset(&pane), 49, "the view does not move under the reader");
+    }
+
+    #[test]
+    fn inline_images_are_hidden_instead_of_drawn() {
+        let mut pane = pane_with_lines(0);
+        pane.process_output(b"before\x1bPq#0;2;0;0;0#0~~@@");
+        pane.process_output(b"vv\x1b\\after");
+        assert_eq!(pane.hidden_images, 1);
+        assert!(pane.vt.borrow().screen().contents().starts_with("beforeafter"));
+    }
+
+    #[test]
+    fn typing_snaps_back_to_live_output() {
+        let mut pane = pane_with_lines(60);
+        pane.handle_command(&PaneCommand::ScrollUp);
+        assert_eq!(offset(&pane), 1);
+        pane.handle_command(&PaneCommand::SendInput("ls\r".into()));
+        assert_eq!(offset(&pane), 0);
     }
 }
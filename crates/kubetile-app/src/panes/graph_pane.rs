@@ -0,0 +1,176 @@
+use std::any::Any;
+use std::cell::Cell;
+
+use ratatui::prelude::{Frame, Line, Rect, Span, Style, Stylize};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use kubetile_core::dependency_graph::graph_lines;
+use kubetile_core::{GraphLine, GraphNode};
+use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
+use kubetile_tui::theme::Theme;
+
+enum GraphState {
+    Loading,
+    Ready(Vec<GraphLine>),
+    Failed(String),
+}
+
+/// What an object leads to, drawn as an ASCII tree; each line can be opened.
+pub struct GraphPane {
+    view_type: ViewType,
+    /// `ing web/shop`
+    subject: String,
+    namespace: String,
+    state: GraphState,
+    selected: usize,
+    height: Cell<u16>,
+}
+
+impl GraphPane {
+    pub fn new(subject: String, namespace: String) -> Self {
+        Self {
+            view_type: ViewType::Plugin("Graph".into()),
+            subject,
+            namespace,
+            state: GraphState::Loading,
+            selected: 0,
+            height: Cell::new(0),
+        }
+    }
+
+    pub fn set_graph(&mut self, result: Result<GraphNode, String>) {
+        self.state = match result {
+            Ok(root) => GraphState::Ready(graph_lines(&root)),
+            Err(e) => GraphState::Failed(e),
+        };
+        self.selected = 0;
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn selected_line(&self) -> Option<&GraphLine> {
+        match &self.state {
+            GraphState::Ready(lines) => lines.get(self.selected),
+            _ => None,
+        }
+    }
+
+    fn line_count(&self) -> usize {
+        match &self.state {
+            GraphState::Ready(lines) => lines.len(),
+            _ => 0,
+        }
+    }
+
+    fn select_by(&mut self, step: isize) {
+        let count = self.line_count() as isize;
+        if count > 0 {
+            self.selected = (self.selected as isize + step).clamp(0, count - 1) as usize;
+        }
+    }
+
+    fn graph_line(line: &GraphLine, theme: &Theme) -> Line<'static> {
+        let mut spans = vec![
+            Span::styled(format!(" {}", line.prefix), theme.text_dim),
+            Span::styled(format!("{} ", line.kind), Style::default().fg(theme.accent)),
+            Span::styled(line.name.clone(), Style::default().fg(theme.fg)),
+        ];
+        if let Some(note) = &line.note {
+            let style = if note == "not found" { theme.status_failed } else { theme.text_dim };
+            spans.push(Span::styled(format!("  {note}"), style));
+        }
+        Line::from(spans)
+    }
+}
+
+impl Pane for GraphPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border = if focused { theme.border_active } else { theme.border };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border)
+            .title(format!(" Graph: {} ", self.subject))
+            .title_style(Style::default().fg(theme.accent).bold());
+        let visible = block.inner(area).height as usize;
+        self.height.set(visible as u16);
+
+        let rows: Vec<Line> = match &self.state {
+            GraphState::Loading => vec![Line::styled(" Loading…", theme.text_dim)],
+            GraphState::Failed(e) => vec![Line::styled(format!(" {e}"), theme.status_failed)],
+            GraphState::Ready(lines) => {
+                let first = self.selected.saturating_sub(visible.saturating_sub(1));
+                lines
+                    .iter()
+                    .enumerate()
+                    .skip(first)
+                    .take(visible)
+                    .map(|(i, line)| {
+                        let line = Self::graph_line(line, theme);
+                        if i == self.selected && focused {
+                            line.style(theme.selection)
+                        } else {
+                            line
+                        }
+                    })
+                    .collect()
+            }
+        };
+        frame.render_widget(Paragraph::new(rows).block(block), area);
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        let page = self.height.get().max(1) as isize;
+        match cmd {
+            PaneCommand::SelectNext | PaneCommand::ScrollDown => self.select_by(1),
+            PaneCommand::SelectPrev | PaneCommand::ScrollUp => self.select_by(-1),
+            PaneCommand::PageDown => self.select_by(page),
+            PaneCommand::PageUp => self.select_by(-page),
+            PaneCommand::GoToTop => self.select_by(-(self.line_count() as isize)),
+            PaneCommand::GoToBottom => self.select_by(self.line_count() as isize),
+            _ => {}
+        }
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(kind: &str, name: &str, children: Vec<GraphNode>) -> GraphNode {
+        GraphNode { kind: kind.into(), name: name.into(), note: None, children }
+    }
+
+    #[test]
+    fn selection_walks_the_tree_lines() {
+        let mut pane = GraphPane::new("svc web/api".into(), "web".into());
+        assert!(pane.selected_line().is_none());
+
+        let pod = node("Pod", "api-0", vec![node("ConfigMap", "settings", vec![])]);
+        pane.set_graph(Ok(node("Service", "api", vec![pod])));
+        pane.handle_command(&PaneCommand::GoToBottom);
+        let line = pane.selected_line().unwrap();
+        assert_eq!(
+            (line.prefix.as_str(), line.kind.as_str(), line.name.as_str()),
+            ("    └── ", "ConfigMap", "settings")
+        );
+
+        pane.handle_command(&PaneCommand::SelectPrev);
+        assert_eq!(pane.selected_line().unwrap().name, "api-0");
+        pane.handle_command(&PaneCommand::GoToTop);
+        assert_eq!(pane.selected_line().unwrap().kind, "Service");
+    }
+}
@@ -6,34 +6,40 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
 use kubetile_tui::theme::Theme;
 
+/// A group of shortcuts, such as the global ones or those of the query editor.
+pub struct HelpSection {
+    pub title: String,
+    pub shortcuts: Vec<(String, String)>,
+    /// The keys of the input mode help was opened from; listed first and marked.
+    pub current: bool,
+}
+
 pub struct HelpPane {
     scroll_offset: u16,
-    global_shortcuts: Vec<(String, String)>,
-    navigation_shortcuts: Vec<(String, String)>,
-    browse_shortcuts: Vec<(String, String)>,
-    tui_shortcuts: Vec<(String, String)>,
-    interact_shortcuts: Vec<(String, String)>,
-    mutate_shortcuts: Vec<(String, String)>,
+    sections: Vec<HelpSection>,
+    /// Case-insensitive text a shortcut's key or description must contain to be listed.
+    filter: String,
 }
 
 impl HelpPane {
-    pub fn new(
-        global_shortcuts: Vec<(String, String)>,
-        navigation_shortcuts: Vec<(String, String)>,
-        browse_shortcuts: Vec<(String, String)>,
-        tui_shortcuts: Vec<(String, String)>,
-        interact_shortcuts: Vec<(String, String)>,
-        mutate_shortcuts: Vec<(String, String)>,
-    ) -> Self {
-        Self {
-            scroll_offset: 0,
-            global_shortcuts,
-            navigation_shortcuts,
-            browse_shortcuts,
-            tui_shortcuts,
-            interact_shortcuts,
-            mutate_shortcuts,
-        }
+    /// Sections of the current input mode move to the top; the rest keep their order.
+    pub fn new(mut sections: Vec<HelpSection>) -> Self {
+        sections.sort_by_key(|section| !section.current);
+        Self { scroll_offset: 0, sections, filter: String::new() }
+    }
+
+    pub fn filter_text(&self) -> &str {
+        &self.filter
+    }
+
+    fn matching(&self, shortcuts: &[(String, String)]) -> Vec<(String, String)> {
+        let query = self.filter.to_lowercase();
+        Self::normalize_shortcuts(shortcuts)
+            .into_iter()
+            .filter(|(key, desc)| {
+                query.is_empty() || key.to_lowercase().contains(&query) || desc.to_lowercase().contains(&query)
+            })
+            .collect()
     }
 
     fn normalize_shortcuts(entries: &[(String, String)]) -> Vec<(String, String)> {
@@ -127,40 +133,38 @@ impl Pane for HelpPane {
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(border_style)
-            .title(" Help ")
+            .title(if self.filter.is_empty() { " Help ".to_string() } else { format!(" Help /{} ", self.filter) })
             .title_style(Style::default().fg(theme.accent).bold());
 
         let inner = block.inner(area);
         frame.render_widget(block, area);
 
         let mut lines: Vec<Line> = Vec::new();
-
-        let sections: &[(&str, &[(String, String)])] = &[
-            ("Global (Ctrl+)", &self.global_shortcuts),
-            ("Browse", &self.browse_shortcuts),
-            ("Interact", &self.interact_shortcuts),
-            ("Mutate (Ctrl+Alt+)", &self.mutate_shortcuts),
-            ("TUI (Alt+)", &self.tui_shortcuts),
-            ("Navigation", &self.navigation_shortcuts),
-        ];
-
-        for (i, (title, shortcuts)) in sections.iter().enumerate() {
+        for section in &self.sections {
+            let shortcuts = self.matching(&section.shortcuts);
             if shortcuts.is_empty() {
                 continue;
             }
-            if i > 0 {
+            if !lines.is_empty() {
                 lines.push(Line::from(""));
             }
-            lines
-                .push(Line::from(Span::styled(format!("{title} Shortcuts"), Style::default().fg(theme.accent).bold())));
+            let mut heading =
+                vec![Span::styled(format!("{} Shortcuts", section.title), Style::default().fg(theme.accent).bold())];
+            if section.current {
+                heading.push(Span::styled("  (current mode)", theme.text_dim));
+            }
+            lines.push(Line::from(heading));
             lines.push(Line::from(""));
-            for (key, desc) in Self::normalize_shortcuts(shortcuts) {
+            for (key, desc) in shortcuts {
                 lines.push(Line::from(vec![
                     Span::styled(format!("  {:<16}", Self::format_key(&key)), Style::default().fg(theme.fg).bold()),
                     Span::styled(desc, theme.text_dim),
                 ]));
             }
         }
+        if lines.is_empty() {
+            lines.push(Line::styled(format!("No shortcuts match \"{}\"", self.filter), theme.text_dim));
+        }
 
         let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).scroll((self.scroll_offset, 0));
         frame.render_widget(paragraph, inner);
@@ -174,6 +178,14 @@ impl Pane for HelpPane {
             PaneCommand::ScrollDown | PaneCommand::SelectNext => {
                 self.scroll_offset += 1;
             }
+            PaneCommand::Filter(text) => {
+                self.filter = text.clone();
+                self.scroll_offset = 0;
+            }
+            PaneCommand::ClearFilter => {
+                self.filter.clear();
+                self.scroll_offset = 0;
+            }
             _ => {}
         }
     }
@@ -195,19 +207,21 @@ impl Pane for HelpPane {
 mod tests {
     use super::*;
 
+    fn section(title: &str, shortcuts: &[(&str, &str)], current: bool) -> HelpSection {
+        HelpSection {
+            title: title.into(),
+            shortcuts: shortcuts.iter().map(|(k, d)| (k.to_string(), d.to_string())).collect(),
+            current,
+        }
+    }
+
     fn make_help() -> HelpPane {
-        let global = vec![("Ctrl+Q".into(), "Quit".into()), ("?".into(), "Help".into())];
-        let navigation = vec![("J".into(), "Down".into()), ("K".into(), "Up".into())];
-        let browse =
-            vec![("Y".into(), "View YAML".into()), ("D".into(), "Describe".into()), ("L".into(), "Logs".into())];
-        let tui = vec![("Alt+V".into(), "Split V".into())];
-        let interact = vec![("E".into(), "Exec".into()), ("P".into(), "Port Forward".into())];
-        let mutate = vec![
-            ("Ctrl+Alt+D".into(), "Delete".into()),
-            ("Ctrl+Alt+S".into(), "Scale".into()),
-            ("Ctrl+Alt+R".into(), "Restart".into()),
-        ];
-        HelpPane::new(global, navigation, browse, tui, interact, mutate)
+        HelpPane::new(vec![
+            section("Global (Ctrl+)", &[("Ctrl+Q", "Quit"), ("?", "Help")], false),
+            section("Browse", &[("Y", "View YAML"), ("D", "Describe"), ("L", "Logs")], false),
+            section("Mutate (Ctrl+Alt+)", &[("Ctrl+Alt+D", "Delete"), ("Ctrl+Alt+S", "Scale")], false),
+            section("Query editor", &[("Ctrl+Enter", "Execute query")], true),
+        ])
     }
 
     #[test]
@@ -217,11 +231,24 @@ mod tests {
     }
 
     #[test]
-    fn help_pane_shortcuts_from_dispatcher() {
+    fn current_mode_sections_come_first() {
         let help = make_help();
-        assert!(help.browse_shortcuts.iter().any(|(_, d)| d == "View YAML"));
-        assert!(help.browse_shortcuts.iter().any(|(_, d)| d == "Describe"));
-        assert!(help.mutate_shortcuts.iter().any(|(_, d)| d == "Delete"));
+        let titles: Vec<&str> = help.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["Query editor", "Global (Ctrl+)", "Browse", "Mutate (Ctrl+Alt+)"]);
+    }
+
+    #[test]
+    fn filter_matches_keys_and_descriptions() {
+        let mut help = make_help();
+        help.handle_command(&PaneCommand::Filter("yaml".into()));
+        assert_eq!(help.matching(&help.sections[2].shortcuts), [("Y".to_string(), "View YAML".to_string())]);
+        assert!(help.matching(&help.sections[1].shortcuts).is_empty());
+
+        help.handle_command(&PaneCommand::Filter("ctrl+alt".into()));
+        assert_eq!(help.matching(&help.sections[3].shortcuts).len(), 2);
+        help.handle_command(&PaneCommand::ClearFilter);
+        assert_eq!(help.filter_text(), "");
+        assert_eq!(help.matching(&help.sections[2].shortcuts).len(), 3);
     }
 
     #[test]
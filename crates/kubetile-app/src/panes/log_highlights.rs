@@ -0,0 +1,55 @@
+use ratatui::style::Style;
+use regex::Regex;
+
+use kubetile_config::LogsConfig;
+use kubetile_tui::theme::parse_style;
+
+/// The compiled `[logs.highlights]` rules, in config order.
+#[derive(Debug, Default)]
+pub struct LogHighlights {
+    rules: Vec<(Regex, Style)>,
+}
+
+impl LogHighlights {
+    /// Compiles every rule, returning the ones that fail as `pattern: reason` next to the rest.
+    pub fn compile(config: &LogsConfig) -> (Self, Vec<String>) {
+        let mut rules = Vec::new();
+        let mut errors = Vec::new();
+        for (pattern, style) in &config.highlights {
+            match (Regex::new(pattern), parse_style(style)) {
+                (Ok(regex), Ok(style)) => rules.push((regex, style)),
+                (Err(e), _) => errors.push(format!("`{pattern}`: {e}")),
+                (_, Err(e)) => errors.push(format!("`{pattern}`: {e}")),
+            }
+        }
+        (Self { rules }, errors)
+    }
+
+    /// Style of the first rule matching `line`.
+    pub fn style_for(&self, line: &str) -> Option<Style> {
+        self.rules.iter().find(|(regex, _)| regex.is_match(line)).map(|(_, style)| *style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use super::*;
+
+    #[test]
+    fn first_matching_rule_styles_the_line() {
+        let mut config = LogsConfig::default();
+        config.highlights.insert("ERROR|panic".into(), "red bold".into());
+        config.highlights.insert("(?i)error".into(), "yellow".into());
+        config.highlights.insert("(unclosed".into(), "red".into());
+        config.highlights.insert("WARN".into(), "sparkly".into());
+
+        let (highlights, errors) = LogHighlights::compile(&config);
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        let red_bold = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        assert_eq!(highlights.style_for("thread main panicked"), Some(red_bold));
+        assert_eq!(highlights.style_for("an error occurred"), Some(Style::default().fg(Color::Yellow)));
+        assert_eq!(highlights.style_for("WARN disk"), None);
+    }
+}
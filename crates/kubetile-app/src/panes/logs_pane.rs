@@ -1,5 +1,10 @@
 use std::any::Any;
 use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
@@ -7,6 +12,10 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use kubetile_core::{LogLine, LogStream, StreamStatus};
 use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
 use kubetile_tui::theme::Theme;
+use tokio_util::sync::DropGuard;
+
+use super::LogHighlights;
+use crate::app_log::LogFile;
 
 const MAX_LOG_LINES: usize = 5000;
 const HISTORY_MAX_LINES: usize = 3000;
@@ -18,6 +27,27 @@ pub struct HistoryRequest {
     pub tail_lines: usize,
 }
 
+/// Streams to restart when a suspended pane resumes.
+pub struct ResumeRequest {
+    /// The pane's pod, or every followed pod of a workload pane.
+    pub pods: Vec<String>,
+    pub namespace: String,
+    pub container: Option<String>,
+    /// Reaches back to when the pane was suspended, so no lines are skipped.
+    pub since_seconds: i64,
+}
+
+/// Pods of a Deployment/StatefulSet/DaemonSet followed by one pane; streams come and go as the
+/// workload rolls.
+struct WorkloadFollow {
+    /// Pod name prefix stripped from line tags, e.g. `api-` for `deploy/api`.
+    pod_prefix: String,
+    streams: BTreeMap<String, Option<LogStream>>,
+    initialized: bool,
+    /// Stops the pod follower task when the pane goes away.
+    _follower: DropGuard,
+}
+
 #[derive(Clone)]
 struct LogEntry {
     rendered: String,
@@ -36,9 +66,12 @@ pub struct LogsPane {
     horizontal_offset: usize,
     follow: bool,
     wrap: bool,
+    /// Shows a run of identical lines once, with a `×N` count.
+    collapse: bool,
     filter_text: String,
     status: String,
     stream: Option<LogStream>,
+    workload: Option<WorkloadFollow>,
     max_scroll_offset: Cell<usize>,
     max_horizontal_offset: Cell<usize>,
     visible_height: Cell<usize>,
@@ -46,6 +79,16 @@ pub struct LogsPane {
     history_fetch_in_progress: bool,
     needs_more_history: bool,
     history_limit_notice: bool,
+    /// File the live stream is appended to while teeing.
+    tee: Option<LogFile>,
+    tee_error: Option<String>,
+    /// Path being typed into the tee prompt while it is open.
+    tee_prompt: Option<String>,
+    highlights: Arc<LogHighlights>,
+    /// Set while the streams are dropped to save memory; lines already read are kept.
+    suspended_at: Option<Instant>,
+    /// Lines or status changed while polling since the last redraw.
+    redraw: bool,
 }
 
 impl LogsPane {
@@ -61,9 +104,11 @@ impl LogsPane {
             horizontal_offset: 0,
             follow: true,
             wrap: true,
+            collapse: false,
             filter_text: String::new(),
             status: "Connecting...".into(),
             stream: None,
+            workload: None,
             max_scroll_offset: Cell::new(0),
             max_horizontal_offset: Cell::new(0),
             visible_height: Cell::new(0),
@@ -71,10 +116,62 @@ impl LogsPane {
             history_fetch_in_progress: false,
             needs_more_history: false,
             history_limit_notice: false,
+            tee: None,
+            tee_error: None,
+            tee_prompt: None,
+            highlights: Arc::default(),
+            suspended_at: None,
+            redraw: false,
+        }
+    }
+
+    /// A pane that follows every running pod of a workload; `label` reads like `deploy/api`.
+    pub fn for_workload(label: String, namespace: String, follower: DropGuard) -> Self {
+        let name = label.split_once('/').map_or(label.as_str(), |(_, name)| name);
+        let pod_prefix = format!("{name}-");
+        let mut pane = Self::new(label, namespace);
+        pane.workload =
+            Some(WorkloadFollow { pod_prefix, streams: BTreeMap::new(), initialized: false, _follower: follower });
+        pane.status = "Looking for pods...".into();
+        pane
+    }
+
+    pub fn is_workload(&self) -> bool {
+        self.workload.is_some()
+    }
+
+    /// Whether the first pod list of a workload pane has been applied.
+    pub fn has_followed_pods(&self) -> bool {
+        self.workload.as_ref().is_some_and(|w| w.initialized)
+    }
+
+    /// Tracks the workload's current pods: streams of pods that are gone are dropped, and the
+    /// names of pods not followed yet are returned so the caller can start their streams.
+    pub fn follow_pods(&mut self, pods: &[String]) -> Vec<String> {
+        let Some(workload) = &mut self.workload else { return Vec::new() };
+        workload.initialized = true;
+        workload.streams.retain(|pod, _| pods.contains(pod));
+        let new: Vec<String> = pods.iter().filter(|p| !workload.streams.contains_key(*p)).cloned().collect();
+        for pod in &new {
+            workload.streams.insert(pod.clone(), None);
+        }
+        self.status = format!("Following {} pods", workload.streams.len());
+        new
+    }
+
+    pub fn attach_pod_stream(&mut self, pod: &str, stream: LogStream) {
+        if self.is_suspended() {
+            return;
+        }
+        if let Some(slot) = self.workload.as_mut().and_then(|w| w.streams.get_mut(pod)) {
+            *slot = Some(stream);
         }
     }
 
     pub fn attach_stream(&mut self, stream: LogStream) {
+        if self.is_suspended() {
+            return;
+        }
         self.stream = Some(stream);
         self.status = "Streaming".into();
     }
@@ -86,6 +183,42 @@ impl LogsPane {
         }
     }
 
+    /// Drops the live streams but keeps the lines read so far. Returns whether anything was
+    /// streaming.
+    pub fn suspend(&mut self) -> bool {
+        let suspended = match &mut self.workload {
+            Some(workload) => {
+                workload.streams.values_mut().filter_map(|stream| stream.take_if(|s| s.is_active())).count() > 0
+            }
+            None => self.stream.take_if(|s| s.is_active()).is_some(),
+        };
+        if suspended {
+            self.suspended_at = Some(Instant::now());
+            self.status = "Suspended".into();
+        }
+        suspended
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    /// Ends a suspension; the caller starts the returned streams and attaches them.
+    pub fn resume(&mut self) -> Option<ResumeRequest> {
+        let since = self.suspended_at.take()?.elapsed().as_secs() as i64 + 1;
+        let pods = match &self.workload {
+            Some(workload) => workload.streams.keys().cloned().collect(),
+            None => vec![self.pod_name.clone()],
+        };
+        self.status = "Resuming...".into();
+        Some(ResumeRequest {
+            pods,
+            namespace: self.namespace.clone(),
+            container: self.container.clone(),
+            since_seconds: since,
+        })
+    }
+
     pub fn set_error(&mut self, error: String) {
         self.stream = None;
         self.status = format!("Error: {error}");
@@ -101,7 +234,56 @@ impl LogsPane {
         v
     }
 
+    pub fn set_highlights(&mut self, highlights: Arc<LogHighlights>) {
+        self.highlights = highlights;
+    }
+
+    /// Appends every line that arrives from now on to `path` as well, rotating it at `max_bytes`.
+    pub fn start_tee(&mut self, path: PathBuf, max_bytes: u64) -> io::Result<()> {
+        self.tee = Some(LogFile::open(path, max_bytes.max(1))?);
+        Ok(())
+    }
+
+    /// Stops teeing, returning the file that was written.
+    pub fn stop_tee(&mut self) -> Option<PathBuf> {
+        self.tee.take().map(|tee| tee.path().to_path_buf())
+    }
+
+    pub fn tee_path(&self) -> Option<&Path> {
+        self.tee.as_ref().map(LogFile::path)
+    }
+
+    /// Opens the prompt for the file to tee into, pre-filled with `path`.
+    pub fn open_tee_prompt(&mut self, path: String) {
+        self.tee_prompt = Some(path);
+    }
+
+    pub fn tee_prompt_input(&mut self, c: char) {
+        if let Some(path) = &mut self.tee_prompt {
+            path.push(c);
+        }
+    }
+
+    pub fn tee_prompt_backspace(&mut self) {
+        if let Some(path) = &mut self.tee_prompt {
+            path.pop();
+        }
+    }
+
+    /// Closes the tee prompt, returning the path typed into it.
+    pub fn close_tee_prompt(&mut self) -> Option<String> {
+        self.tee_prompt.take()
+    }
+
+    /// Why teeing stopped on its own, once.
+    pub fn take_tee_error(&mut self) -> Option<String> {
+        self.tee_error.take()
+    }
+
     pub fn take_history_request(&mut self) -> Option<HistoryRequest> {
+        if self.workload.is_some() {
+            return None;
+        }
         if !self.needs_more_history || self.history_fetch_in_progress || self.history_lines_loaded >= HISTORY_MAX_LINES
         {
             return None;
@@ -171,6 +353,20 @@ impl LogsPane {
     }
 
     pub fn poll(&mut self) {
+        if let Some(workload) = &mut self.workload {
+            let mut new_lines = Vec::new();
+            for (pod, stream) in workload.streams.iter_mut() {
+                let Some(stream) = stream else { continue };
+                let tag = pod.strip_prefix(workload.pod_prefix.as_str()).unwrap_or(pod);
+                new_lines.extend(stream.next_lines().into_iter().map(|mut line| {
+                    line.content = format!("[{tag}] {}", line.content);
+                    line
+                }));
+            }
+            self.push_lines(new_lines);
+            return;
+        }
+
         let (new_lines, stream_status) = {
             let Some(stream) = self.stream.as_mut() else {
                 return;
@@ -184,12 +380,16 @@ impl LogsPane {
             self.push_lines(new_lines);
         }
 
-        self.status = match stream_status {
+        let status = match stream_status {
             StreamStatus::Streaming => "Streaming".into(),
             StreamStatus::Reconnecting { attempt } => format!("Reconnecting ({attempt})"),
             StreamStatus::Stopped => "Stopped".into(),
             StreamStatus::Error => "Error".into(),
         };
+        if status != self.status {
+            self.status = status;
+            self.redraw = true;
+        }
     }
 
     fn render_title(&self) -> String {
@@ -200,12 +400,20 @@ impl LogsPane {
         if lines.is_empty() {
             return;
         }
+        self.redraw = true;
 
         for line in lines {
             let sequence = self.next_sequence;
             self.next_sequence = self.next_sequence.saturating_add(1);
+            let rendered = format_log_line(&line);
+            if let Some(tee) = &mut self.tee {
+                if let Err(e) = tee.write_line(&rendered) {
+                    self.tee_error = Some(format!("Stopped writing logs to {}: {e}", tee.path().display()));
+                    self.tee = None;
+                }
+            }
             self.lines.push(LogEntry {
-                rendered: format_log_line(&line),
+                rendered,
                 sort_ts: line.timestamp.unwrap_or_else(jiff::Timestamp::now),
                 sequence,
             });
@@ -227,6 +435,37 @@ impl LogsPane {
         let query = self.filter_text.to_lowercase();
         self.lines.iter().filter(|line| line.rendered.to_lowercase().contains(&query)).collect()
     }
+
+    /// Filtered lines with how many times each repeats in a row; every count is 1 unless
+    /// collapsing.
+    fn display_rows<'a>(&self, filtered: &[&'a LogEntry]) -> Vec<(&'a LogEntry, usize)> {
+        let mut rows: Vec<(&LogEntry, usize)> = Vec::with_capacity(filtered.len());
+        for &entry in filtered {
+            match rows.last_mut() {
+                Some((last, count)) if self.collapse && last.rendered == entry.rendered => *count += 1,
+                _ => rows.push((entry, 1)),
+            }
+        }
+        rows
+    }
+}
+
+/// Index of the first line to show so the wrapped tail of `lines` fills `height` rows at `width`.
+fn wrapped_start<'a>(
+    lines: impl DoubleEndedIterator<Item = &'a str> + ExactSizeIterator,
+    width: u16,
+    height: usize,
+) -> usize {
+    let mut start = lines.len();
+    let mut used = 0;
+    for line in lines.rev() {
+        if used >= height {
+            break;
+        }
+        used += Paragraph::new(line).wrap(Wrap { trim: false }).line_count(width).max(1);
+        start -= 1;
+    }
+    start
 }
 
 impl Pane for LogsPane {
@@ -246,37 +485,65 @@ impl Pane for LogsPane {
         }
 
         let filtered = self.filtered_lines();
+        let rows = self.display_rows(&filtered);
         let visible_height = inner.height.saturating_sub(1) as usize;
         self.visible_height.set(visible_height);
         let total = self.lines.len();
         let filtered_total = filtered.len();
-        let max_offset = filtered_total.saturating_sub(visible_height);
+        let max_offset = rows.len().saturating_sub(visible_height);
         self.max_scroll_offset.set(max_offset);
         let offset = if self.follow { 0 } else { self.scroll_offset.min(max_offset) };
-        let end = filtered_total.saturating_sub(offset);
-        let start = end.saturating_sub(visible_height);
-        let visible = &filtered[start..end];
+        let end = rows.len().saturating_sub(offset);
+        let start = if self.wrap {
+            wrapped_start(rows[..end].iter().map(|(line, _)| line.rendered.as_str()), inner.width, visible_height)
+        } else {
+            end.saturating_sub(visible_height)
+        };
+        let visible = &rows[start..end];
         let viewport_width = inner.width as usize;
-        let max_horizontal =
-            visible.iter().map(|line| line.rendered.chars().count().saturating_sub(viewport_width)).max().unwrap_or(0);
+        let max_horizontal = visible
+            .iter()
+            .map(|(line, _)| line.rendered.chars().count().saturating_sub(viewport_width))
+            .max()
+            .unwrap_or(0);
         self.max_horizontal_offset.set(max_horizontal);
         let horizontal_offset = if self.wrap { 0 } else { self.horizontal_offset.min(max_horizontal) };
 
         let content = if visible.is_empty() {
             vec![Line::from(format!("Waiting for log lines... ({})", self.status))]
         } else {
-            visible.iter().map(|l| Line::from(l.rendered.as_str())).collect()
+            visible
+                .iter()
+                .map(|&(l, count)| {
+                    let mut line = Line::from(l.rendered.as_str());
+                    if count > 1 {
+                        line.push_span(Span::styled(format!(" ×{count}"), theme.text_dim));
+                    }
+                    match self.highlights.style_for(&l.rendered) {
+                        Some(style) => line.style(style),
+                        None => line,
+                    }
+                })
+                .collect()
         };
         let content_area = Rect { x: inner.x, y: inner.y, width: inner.width, height: inner.height.saturating_sub(1) };
         let paragraph = if self.wrap {
-            Paragraph::new(content).wrap(Wrap { trim: false })
+            // The oldest line shown may only partly fit; its top rows are scrolled off.
+            let paragraph = Paragraph::new(content).wrap(Wrap { trim: false });
+            let overflow = paragraph.line_count(content_area.width).saturating_sub(visible_height);
+            paragraph.scroll((overflow as u16, 0))
         } else {
             Paragraph::new(content).scroll((0, horizontal_offset as u16))
         };
         frame.render_widget(paragraph, content_area);
 
         let mode_text = if self.follow { "FOLLOW" } else { "PAUSED" };
-        let wrap_mode = if self.wrap { "WRAP" } else { "NOWRAP" };
+        let wrap_mode = match (self.wrap, self.collapse) {
+            (true, false) => "WRAP",
+            (false, false) => "NOWRAP",
+            (true, true) => "WRAP | COLLAPSE",
+            (false, true) => "NOWRAP | COLLAPSE",
+        };
         let footer = format!("{mode_text} | {wrap_mode} | {} lines | {}", self.lines.len(), self.status);
         let footer = if self.filter_text.is_empty() {
             footer
@@ -286,9 +553,17 @@ impl Pane for LogsPane {
                 self.filter_text, self.status
             )
         };
+        let footer = match self.tee_path() {
+            Some(path) => format!("{footer} | TEE {}", path.display()),
+            None => footer,
+        };
         let footer_area =
             Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
         frame.render_widget(Paragraph::new(footer).style(theme.status_bar), footer_area);
+
+        if let Some(path) = &self.tee_prompt {
+            super::render_path_popup(frame, area, " Tee Logs to File ", path, theme);
+        }
     }
 
     fn handle_command(&mut self, cmd: &PaneCommand) {
@@ -347,6 +622,9 @@ impl Pane for LogsPane {
                     self.horizontal_offset = self.horizontal_offset.min(self.max_horizontal_offset.get());
                 }
             }
+            PaneCommand::ToggleCollapse => {
+                self.collapse = !self.collapse;
+            }
             PaneCommand::Filter(text) => {
                 self.filter_text = text.clone();
                 self.scroll_offset = 0;
@@ -359,6 +637,14 @@ impl Pane for LogsPane {
         }
     }
 
+    fn memory_usage(&self) -> usize {
+        self.lines.iter().map(|line| std::mem::size_of::<LogEntry>() + line.rendered.capacity()).sum()
+    }
+
+    fn take_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.redraw)
+    }
+
     fn view_type(&self) -> &ViewType {
         &self.view_type
     }
@@ -438,7 +724,9 @@ fn sanitize_log_text(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{sanitize_log_text, LogsPane};
+    use std::time::{Duration, Instant};
+
+    use super::{sanitize_log_text, wrapped_start, LogsPane};
     use kubetile_core::LogLine;
     use kubetile_tui::pane::{Pane, PaneCommand};
 
@@ -454,6 +742,14 @@ mod tests {
         assert_eq!(sanitize_log_text(input), "line1 line2 ");
     }
 
+    #[test]
+    fn wrapped_tail_fits_the_pane_height() {
+        let lines = ["old", "a line long enough to wrap onto two rows", "new"];
+        assert_eq!(wrapped_start(lines.iter().copied(), 80, 3), 0);
+        assert_eq!(wrapped_start(lines.iter().copied(), 24, 3), 1, "the long line takes two of the three rows");
+        assert_eq!(wrapped_start(lines.iter().copied(), 24, 1), 2);
+    }
+
     #[test]
     fn append_snapshot_sorts_by_log_timestamp() {
         let mut pane = LogsPane::new("pod-a".into(), "default".into());
@@ -481,6 +777,46 @@ mod tests {
         assert!(pane.lines[1].rendered.contains("second"));
     }
 
+    #[test]
+    fn tee_appends_lines_that_arrive_while_it_runs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("pod-a.log");
+        let line = |content: &str| LogLine {
+            timestamp: None,
+            content: content.into(),
+            container: "main".into(),
+            is_stderr: false,
+        };
+        let mut pane = LogsPane::new("pod-a".into(), "default".into());
+        pane.append_snapshot(vec![line("before")]);
+        pane.start_tee(path.clone(), 1024).unwrap();
+        pane.append_snapshot(vec![line("\u{1b}[31mduring\u{1b}[0m")]);
+        assert_eq!(pane.stop_tee(), Some(path.clone()));
+        pane.append_snapshot(vec![line("after")]);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "during\n");
+        assert!(pane.take_tee_error().is_none());
+    }
+
+    #[test]
+    fn collapse_folds_consecutive_identical_lines() {
+        let line = |content: &str| LogLine {
+            timestamp: None,
+            content: content.into(),
+            container: "main".into(),
+            is_stderr: false,
+        };
+        let mut pane = LogsPane::new("pod-a".into(), "default".into());
+        pane.append_snapshot(["retry", "retry", "retry", "ok", "retry"].map(line).to_vec());
+
+        assert_eq!(pane.display_rows(&pane.filtered_lines()).len(), 5);
+        pane.handle_command(&PaneCommand::ToggleCollapse);
+        let filtered = pane.filtered_lines();
+        let rows: Vec<(&str, usize)> =
+            pane.display_rows(&filtered).into_iter().map(|(l, n)| (l.rendered.as_str(), n)).collect();
+        assert_eq!(rows, [("retry", 3), ("ok", 1), ("retry", 1)]);
+    }
+
     #[test]
     fn filter_matches_log_content_case_insensitive() {
         let mut pane = LogsPane::new("pod-a".into(), "default".into());
@@ -496,6 +832,46 @@ mod tests {
         assert_eq!(filtered[0].rendered, "Error connecting");
     }
 
+    #[test]
+    fn workload_pane_follows_pod_churn() {
+        let guard = tokio_util::sync::CancellationToken::new().drop_guard();
+        let mut pane = LogsPane::for_workload("deploy/api".into(), "default".into(), guard);
+        assert!(!pane.has_followed_pods());
+
+        let started = pane.follow_pods(&["api-5d8f-a".into(), "api-5d8f-b".into()]);
+        assert_eq!(started, vec!["api-5d8f-a".to_string(), "api-5d8f-b".to_string()]);
+        assert!(pane.has_followed_pods());
+
+        let started = pane.follow_pods(&["api-5d8f-b".into(), "api-7c9e-c".into()]);
+        assert_eq!(started, vec!["api-7c9e-c".to_string()]);
+        assert_eq!(pane.status, "Following 2 pods");
+        assert!(pane.take_history_request().is_none());
+    }
+
+    #[test]
+    fn resuming_a_workload_pane_restarts_every_followed_pod() {
+        let guard = tokio_util::sync::CancellationToken::new().drop_guard();
+        let mut pane = LogsPane::for_workload("deploy/api".into(), "default".into(), guard);
+        pane.follow_pods(&["api-5d8f-a".into(), "api-5d8f-b".into()]);
+        assert!(!pane.suspend(), "nothing is streaming yet");
+        assert!(pane.resume().is_none());
+
+        pane.suspended_at = Instant::now().checked_sub(Duration::from_secs(30));
+        let request = pane.resume().unwrap();
+        assert_eq!(request.pods, ["api-5d8f-a", "api-5d8f-b"]);
+        assert_eq!(request.since_seconds, 31);
+        assert!(!pane.is_suspended());
+    }
+
+    #[test]
+    fn memory_usage_grows_with_lines() {
+        let mut pane = LogsPane::new("pod-a".into(), "default".into());
+        assert_eq!(pane.memory_usage(), 0);
+        let line = LogLine { timestamp: None, content: "x".repeat(1000), container: "main".into(), is_stderr: false };
+        pane.append_snapshot(vec![line.clone(), line]);
+        assert!(pane.memory_usage() >= 2000);
+    }
+
     #[test]
     fn clear_filter_restores_all_lines() {
         let mut pane = LogsPane::new("pod-a".into(), "default".into());
@@ -1,19 +1,92 @@
 pub mod app_logs_pane;
+pub mod apply_pane;
+pub mod audit_pane;
+pub mod connection_pane;
+pub mod dashboard_pane;
+pub mod editor_pane;
 pub mod exec_pane;
+pub mod graph_pane;
 pub mod help;
+pub mod log_highlights;
 pub mod logs_pane;
+pub mod plugin_pane;
 pub mod port_forwards_pane;
+mod pty;
 pub mod query_pane;
 pub mod resource_detail;
 pub mod resource_list;
+pub mod trash_pane;
 pub mod yaml_pane;
 
 pub use app_logs_pane::AppLogsPane;
+pub use apply_pane::ApplyPane;
+pub use audit_pane::AuditPane;
+pub use connection_pane::ConnectionPane;
+pub use dashboard_pane::DashboardPane;
+pub use editor_pane::EditorPane;
 pub use exec_pane::ExecPane;
-pub use help::HelpPane;
+pub use graph_pane::GraphPane;
+pub use help::{HelpPane, HelpSection};
+pub use log_highlights::LogHighlights;
 pub use logs_pane::LogsPane;
-pub use port_forwards_pane::PortForwardsPane;
-pub use query_pane::QueryPane;
+pub use plugin_pane::PluginPane;
+pub use port_forwards_pane::{PortForwardRow, PortForwardsPane};
+pub use query_pane::{QueryPane, TransactionState};
 pub use resource_detail::ResourceDetailPane;
 pub use resource_list::ResourceListPane;
+pub use trash_pane::{TrashPane, TrashRow};
 pub use yaml_pane::YamlPane;
+
+use kubetile_tui::theme::Theme;
+use ratatui::prelude::{Frame, Rect, Style, Stylize};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+/// Approximate bytes held by a table of string cells.
+pub(crate) fn rows_memory(rows: &[Vec<String>]) -> usize {
+    rows.iter()
+        .map(|row| {
+            std::mem::size_of::<Vec<String>>()
+                + row.iter().map(|cell| std::mem::size_of::<String>() + cell.capacity()).sum::<usize>()
+        })
+        .sum()
+}
+
+/// A centred one-line path input titled `title`, e.g. for exporting to or writing a file.
+pub(crate) fn render_path_popup(frame: &mut Frame, area: Rect, title: &str, path_buf: &str, theme: &Theme) {
+    let popup_w = (area.width.saturating_sub(4)).clamp(30, 70);
+    let popup_h = 5u16;
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(popup_w)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_h)) / 2,
+        width: popup_w,
+        height: popup_h,
+    };
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(theme.accent).bold())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(theme.overlay);
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    if inner.height < 3 {
+        return;
+    }
+
+    let input_area = Rect { x: inner.x, y: inner.y, width: inner.width, height: 1 };
+    let hint_area = Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
+
+    let prefix = "Path: ";
+    let max_path_w = inner.width.saturating_sub(prefix.len() as u16) as usize;
+    let display = if path_buf.len() > max_path_w {
+        format!("…{}", &path_buf[path_buf.len().saturating_sub(max_path_w.saturating_sub(1))..])
+    } else {
+        path_buf.to_string()
+    };
+    let label = format!("{prefix}{display}");
+    frame.render_widget(Paragraph::new(label).style(Style::default().fg(theme.accent)), input_area);
+    frame.render_widget(Paragraph::new("Enter confirm  Esc cancel").style(theme.text_dim), hint_area);
+}
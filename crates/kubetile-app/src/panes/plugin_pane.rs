@@ -0,0 +1,145 @@
+use std::any::Any;
+use std::cell::RefCell;
+
+use portable_pty::CommandBuilder;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use tokio::sync::mpsc as tokio_mpsc;
+
+use kubetile_core::ColumnTarget;
+use kubetile_terminal::render_terminal_screen;
+use kubetile_tui::pane::{Pane, PaneCommand, PaneId, ViewType};
+use kubetile_tui::theme::Theme;
+
+use super::pty::{content_area, fit_to_area, PtyProcess};
+use crate::event::AppEvent;
+
+/// Output of a `[plugins]` command run against one resource. The pane stays open after the
+/// command exits so its output can still be read.
+pub struct PluginPane {
+    view_type: ViewType,
+    plugin: String,
+    target: ColumnTarget,
+    command: String,
+    process: Option<PtyProcess>,
+    vt: RefCell<vt100::Parser>,
+    status: String,
+}
+
+impl PluginPane {
+    pub fn new(plugin: String, target: ColumnTarget, command: String) -> Self {
+        Self {
+            // Prefixed so a plugin named like a built-in pane, e.g. "Trash", stays apart from it.
+            view_type: ViewType::Plugin(format!("plugin:{plugin}")),
+            plugin,
+            target,
+            command,
+            process: None,
+            vt: RefCell::new(vt100::Parser::new(48, 160, 10_000)),
+            status: "Starting...".into(),
+        }
+    }
+
+    /// Runs the expanded command through `sh -c` in a PTY, with the target also exposed as
+    /// `KUBETILE_*` env vars like plugin columns.
+    pub fn spawn(&mut self) -> anyhow::Result<()> {
+        let mut cmd = CommandBuilder::new("sh");
+        cmd.args(["-c", &self.command]);
+        if let Ok(cwd) = std::env::current_dir() {
+            cmd.cwd(cwd);
+        }
+        cmd.env("KUBETILE_KIND", &self.target.kind);
+        cmd.env("KUBETILE_NAME", &self.target.name);
+        cmd.env("KUBETILE_NAMESPACE", &self.target.namespace);
+        cmd.env("KUBETILE_CONTEXT", &self.target.context);
+
+        tracing::info!("plugin: spawning `{}`", self.command);
+        self.process = Some(PtyProcess::spawn_sized(cmd, &self.vt.borrow())?);
+        self.status = "Running".into();
+        Ok(())
+    }
+
+    pub fn start_output_forwarding(&mut self, pane_id: PaneId, app_tx: tokio_mpsc::UnboundedSender<AppEvent>) {
+        if let Some(process) = self.process.as_mut() {
+            process.start_output_forwarding(pane_id, app_tx);
+        }
+    }
+
+    pub fn process_output(&mut self, data: &[u8]) {
+        self.vt.borrow_mut().process(data);
+    }
+
+    /// Records how the command ended; called once its output closes.
+    pub fn mark_exited(&mut self) {
+        let code = self.process.as_mut().and_then(PtyProcess::exit_status).map(|s| s.exit_code());
+        self.status = match code {
+            Some(0) => "Finished".into(),
+            Some(code) => format!("Exited with code {code}"),
+            None => "Exited".into(),
+        };
+    }
+
+    fn render_title(&self) -> String {
+        let name = &self.plugin;
+        if self.target.namespace.is_empty() {
+            format!("[{name}:{}/{}]", self.target.kind, self.target.name)
+        } else {
+            format!("[{name}:{}/{} @ {}]", self.target.kind, self.target.name, self.target.namespace)
+        }
+    }
+}
+
+impl Pane for PluginPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
+        let border_style = if focused { theme.border_active } else { theme.border };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!(" {} ", self.render_title()))
+            .title_style(Style::default().fg(theme.accent).bold());
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if inner.height == 0 {
+            return;
+        }
+
+        let content_area = content_area(area);
+        let mut vt = self.vt.borrow_mut();
+        fit_to_area(&mut vt, self.process.as_ref(), content_area);
+
+        render_terminal_screen(vt.screen(), content_area, frame.buffer_mut());
+
+        let footer_area =
+            Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
+        frame.render_widget(
+            Paragraph::new(format!("{} | {}", self.status, self.command)).style(theme.status_bar),
+            footer_area,
+        );
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        if let PaneCommand::SendInput(input) = cmd {
+            if let Some(process) = self.process.as_mut() {
+                process.write(input);
+            }
+        }
+    }
+
+    fn fit(&mut self, area: Rect) {
+        fit_to_area(self.vt.get_mut(), self.process.as_ref(), content_area(area));
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
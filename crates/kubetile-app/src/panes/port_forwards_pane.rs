@@ -1,13 +1,26 @@
 use std::any::Any;
 use std::time::Duration;
 
-use kubetile_core::ForwardId;
+use kubetile_core::volume_stats::format_bytes;
+use kubetile_core::{ForwardId, ForwardStats};
 use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
 use kubetile_tui::widgets::resource_list::ResourceListWidget;
 use ratatui::prelude::{Frame, Rect};
 
 use crate::state::ResourceListState;
 
+/// One active forward as listed in the pane.
+#[derive(Clone)]
+pub struct PortForwardRow {
+    pub id: ForwardId,
+    pub pod: String,
+    pub namespace: String,
+    pub local: u16,
+    pub remote: u16,
+    pub age: Duration,
+    pub stats: ForwardStats,
+}
+
 pub struct PortForwardsPane {
     view_type: ViewType,
     state: ResourceListState,
@@ -24,22 +37,34 @@ impl PortForwardsPane {
                 "LOCAL".into(),
                 "REMOTE".into(),
                 "AGE".into(),
+                "CONNS".into(),
+                "↑BYTES".into(),
+                "↓BYTES".into(),
+                "LAST".into(),
             ]),
             ids: Vec::new(),
         }
     }
 
-    pub fn set_items(&mut self, items: Vec<(ForwardId, String, String, u16, u16, Duration)>) {
-        self.ids = items.iter().map(|(id, ..)| *id).collect();
+    pub fn set_items(&mut self, items: Vec<PortForwardRow>) {
+        self.ids = items.iter().map(|row| row.id).collect();
         let rows = items
             .into_iter()
-            .map(|(_, pod, namespace, local, remote, age)| {
+            .map(|row| {
+                let stats = row.stats;
                 vec![
-                    pod,
-                    namespace,
-                    local.to_string(),
-                    remote.to_string(),
-                    kubetile_core::resource::format_duration(age),
+                    row.pod,
+                    row.namespace,
+                    row.local.to_string(),
+                    row.remote.to_string(),
+                    kubetile_core::resource::format_duration(row.age),
+                    format!("{}/{}", stats.active, stats.connections),
+                    format_bytes(stats.bytes_up),
+                    format_bytes(stats.bytes_down),
+                    stats
+                        .idle
+                        .map(|d| format!("{} ago", kubetile_core::resource::format_duration(d)))
+                        .unwrap_or("-".into()),
                 ]
             })
             .collect();
@@ -82,13 +107,19 @@ impl Pane for PortForwardsPane {
             selected: self.state.selected,
             scroll_offset: self.state.scroll_offset,
             loading: self.state.loading,
+            loading_more: false,
+            expected_count: None,
             error: self.state.error.as_deref(),
+            stale: false,
             focused,
             filter_text: None,
             sort_column: None,
             sort_ascending: true,
             total_count: self.state.items.len(),
             all_namespaces: false,
+            status_kind: "",
+            row_styles: &[],
+            empty_hint: None,
             theme,
         };
         widget.render(frame, area);
@@ -0,0 +1,205 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::mpsc as std_mpsc;
+use std::sync::{Arc, Mutex};
+
+use futures::channel::mpsc as futures_mpsc;
+use kube::api::TerminalSize;
+use portable_pty::{native_pty_system, Child, CommandBuilder, ExitStatus, MasterPty, PtySize};
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, Borders};
+use tokio::sync::mpsc as tokio_mpsc;
+
+use kubetile_core::ExecSession;
+use kubetile_tui::pane::PaneId;
+
+use crate::event::AppEvent;
+
+enum Backend {
+    /// A local child, such as `kubectl exec` or an editor.
+    Local { master: Box<dyn MasterPty + Send>, child: Box<dyn Child + Send + Sync> },
+    /// A process in a container, attached over the API server's exec WebSocket.
+    Remote { resize_tx: Mutex<futures_mpsc::Sender<TerminalSize>> },
+}
+
+/// A process attached to a pseudo-terminal, with its output read on a background thread.
+pub struct PtyProcess {
+    backend: Backend,
+    output_rx: Option<std_mpsc::Receiver<Vec<u8>>>,
+    writer: Box<dyn Write + Send>,
+    /// Pane the forwarded output is addressed to; changes when a detached session is reattached.
+    target: Arc<AtomicU32>,
+}
+
+impl PtyProcess {
+    pub fn spawn(cmd: CommandBuilder, rows: u16, cols: u16) -> anyhow::Result<Self> {
+        let pty_system = native_pty_system();
+        let pty_size = PtySize { cols, rows, pixel_width: 0, pixel_height: 0 };
+        let pair = pty_system.openpty(pty_size)?;
+
+        let child = pair.slave.spawn_command(cmd)?;
+        let mut reader = pair.master.try_clone_reader()?;
+        let writer = pair.master.take_writer()?;
+
+        let (tx, rx) = std_mpsc::channel::<Vec<u8>>();
+        std::thread::spawn(move || {
+            use std::io::Read;
+            let mut buf = [0u8; 4096];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        if tx.send(buf[..n].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Ok(Self {
+            backend: Backend::Local { master: pair.master, child },
+            output_rx: Some(rx),
+            writer,
+            target: Arc::default(),
+        })
+    }
+
+    /// Wraps an exec session already running in a container; its TTY is sized to `vt`.
+    pub fn from_exec(session: ExecSession, vt: &vt100::Parser) -> Self {
+        let ExecSession { mut reader, writer, resize_tx, .. } = session;
+        let (tx, rx) = std_mpsc::channel::<Vec<u8>>();
+        std::thread::spawn(move || {
+            while let Some(data) = reader.recv() {
+                if tx.send(data).is_err() {
+                    break;
+                }
+            }
+        });
+        let process = Self {
+            backend: Backend::Remote { resize_tx: Mutex::new(resize_tx) },
+            output_rx: Some(rx),
+            writer: Box::new(writer),
+            target: Arc::default(),
+        };
+        let (rows, cols) = vt.screen().size();
+        process.resize(rows, cols);
+        process
+    }
+
+    /// Spawns at the size the pane's screen was last laid out at.
+    pub fn spawn_sized(cmd: CommandBuilder, vt: &vt100::Parser) -> anyhow::Result<Self> {
+        let (rows, cols) = vt.screen().size();
+        Self::spawn(cmd, rows, cols)
+    }
+
+    /// Takes the PTY output receiver and spawns a thread that forwards all output
+    /// into the app event channel as `AppEvent::PtyOutput`. When the PTY reader
+    /// closes, sends `AppEvent::ExecExited`.
+    pub fn start_output_forwarding(&mut self, pane_id: PaneId, app_tx: tokio_mpsc::UnboundedSender<AppEvent>) {
+        let Some(rx) = self.output_rx.take() else { return };
+        self.target.store(pane_id, Ordering::Relaxed);
+        let target = Arc::clone(&self.target);
+        std::thread::spawn(move || {
+            while let Ok(data) = rx.recv() {
+                let pane_id = target.load(Ordering::Relaxed);
+                if app_tx.send(AppEvent::PtyOutput { pane_id, data }).is_err() {
+                    return;
+                }
+            }
+            let _ = app_tx.send(AppEvent::ExecExited { pane_id: target.load(Ordering::Relaxed) });
+        });
+    }
+
+    /// Sends further output, and the exit, to `pane_id`.
+    pub fn retarget(&self, pane_id: PaneId) {
+        self.target.store(pane_id, Ordering::Relaxed);
+    }
+
+    pub fn write(&mut self, input: &str) {
+        let _ = self.writer.write_all(input.as_bytes());
+        let _ = self.writer.flush();
+    }
+
+    /// Sets the PTY window size; the kernel then sends SIGWINCH so full-screen programs reflow.
+    /// Remote sessions pass the size on to the container's TTY the same way.
+    pub fn resize(&self, rows: u16, cols: u16) {
+        match &self.backend {
+            Backend::Local { master, .. } => {
+                let _ = master.resize(PtySize { cols, rows, pixel_width: 0, pixel_height: 0 });
+            }
+            Backend::Remote { resize_tx } => {
+                if let Ok(mut tx) = resize_tx.lock() {
+                    let _ = tx.try_send(TerminalSize { height: rows, width: cols });
+                }
+            }
+        }
+    }
+
+    /// Exit status once a local process has finished; remote sessions do not report one.
+    pub fn exit_status(&mut self) -> Option<ExitStatus> {
+        match &mut self.backend {
+            Backend::Local { child, .. } => child.try_wait().ok().flatten(),
+            Backend::Remote { .. } => None,
+        }
+    }
+}
+
+/// A remote session ends when its stdin, held by `writer`, closes with it.
+impl Drop for PtyProcess {
+    fn drop(&mut self) {
+        if let Backend::Local { child, .. } = &mut self.backend {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
+
+/// Where a PTY pane drawn in `area` shows its screen: inside the border, above the status line.
+pub fn content_area(area: Rect) -> Rect {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    Rect { height: inner.height.saturating_sub(1), ..inner }
+}
+
+/// Fits a PTY pane's screen, and its process's window, to the area it is drawn in. Only an
+/// actual change is passed on, so a terminal or layout resize reaches the program exactly once.
+pub fn fit_to_area(vt: &mut vt100::Parser, process: Option<&PtyProcess>, area: Rect) {
+    let size = (area.height.max(1), area.width.max(1));
+    if vt.screen().size() == size {
+        return;
+    }
+    vt.set_size(size.0, size.1);
+    if let Some(process) = process {
+        process.resize(size.0, size.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fit_to_area_resizes_the_screen_and_the_program_once_per_change() {
+        let (resize_tx, mut resize_rx) = futures_mpsc::channel(4);
+        let process = PtyProcess {
+            backend: Backend::Remote { resize_tx: Mutex::new(resize_tx) },
+            output_rx: None,
+            writer: Box::new(std::io::sink()),
+            target: Arc::new(AtomicU32::new(0)),
+        };
+        let mut vt = vt100::Parser::new(24, 80, 0);
+        let area = content_area(Rect::new(0, 0, 100, 30));
+        assert_eq!(area, Rect::new(1, 1, 98, 27));
+
+        fit_to_area(&mut vt, Some(&process), area);
+        fit_to_area(&mut vt, Some(&process), area);
+        assert_eq!(vt.screen().size(), (27, 98));
+        let sent = resize_rx.try_next().unwrap().unwrap();
+        assert_eq!((sent.height, sent.width), (27, 98));
+        assert!(resize_rx.try_next().is_err(), "an unchanged area is not passed on again");
+
+        fit_to_area(&mut vt, Some(&process), content_area(Rect::new(0, 0, 2, 2)));
+        assert_eq!(vt.screen().size(), (1, 1));
+    }
+}
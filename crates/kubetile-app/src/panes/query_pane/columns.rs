@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+use super::QueryPane;
+
+/// How the result grid lays out its columns. Only the view changes; copy and export still see
+/// every column.
+#[derive(Default)]
+pub(super) struct ColumnView {
+    /// Column index and whether the order is descending.
+    pub(super) sort: Option<(usize, bool)>,
+    pub(super) hidden: BTreeSet<usize>,
+    /// Columns before this index stay on screen while scrolling horizontally.
+    pub(super) pinned: usize,
+}
+
+impl QueryPane {
+    /// Columns that are pinned and not hidden, left to right.
+    pub(super) fn pinned_columns(&self) -> Vec<usize> {
+        (0..self.col_widths.len().min(self.columns.pinned)).filter(|c| !self.columns.hidden.contains(c)).collect()
+    }
+
+    /// Columns that scroll horizontally; `result_h_col_offset` indexes into this list.
+    pub(super) fn scrollable_columns(&self) -> Vec<usize> {
+        (self.columns.pinned..self.col_widths.len()).filter(|c| !self.columns.hidden.contains(c)).collect()
+    }
+
+    /// The column at the left edge of the scrolled area, which column operations act on.
+    pub fn focused_column(&self) -> Option<usize> {
+        self.scrollable_columns().get(self.result_h_col_offset).copied()
+    }
+
+    /// Sorts the rows by the focused column, numerically when both cells are numbers.
+    pub fn sort_by_focused_column(&mut self, descending: bool) {
+        if self.plan.is_some() {
+            return;
+        }
+        let Some(col) = self.focused_column() else { return };
+        self.columns.sort = Some((col, descending));
+        self.apply_sort();
+    }
+
+    pub(super) fn apply_sort(&mut self) {
+        let (Some((col, descending)), Some(result)) = (self.columns.sort, self.result.as_mut()) else {
+            return;
+        };
+        result.rows.sort_by(|a, b| {
+            let ord = compare_cells(a.get(col).map(String::as_str), b.get(col).map(String::as_str));
+            if descending {
+                ord.reverse()
+            } else {
+                ord
+            }
+        });
+    }
+
+    /// Hides the focused column. The last scrollable column cannot be hidden.
+    pub fn hide_focused_column(&mut self) {
+        let scrollable = self.scrollable_columns();
+        if self.plan.is_some() || scrollable.len() < 2 {
+            return;
+        }
+        let Some(&col) = scrollable.get(self.result_h_col_offset) else { return };
+        self.columns.hidden.insert(col);
+        self.result_h_col_offset = self.result_h_col_offset.min(scrollable.len() - 2);
+    }
+
+    pub fn show_all_columns(&mut self) {
+        self.columns.hidden.clear();
+    }
+
+    /// Pins every column up to and including the focused one, keeping at least one column to
+    /// scroll.
+    pub fn pin_through_focused_column(&mut self) {
+        if self.plan.is_some() || self.result_h_col_offset + 1 >= self.scrollable_columns().len() {
+            return;
+        }
+        let Some(col) = self.focused_column() else { return };
+        self.columns.pinned = col + 1;
+        self.result_h_col_offset = 0;
+    }
+
+    pub fn unpin_columns(&mut self) {
+        let Some(focused) = self.focused_column() else { return };
+        self.columns.pinned = 0;
+        self.result_h_col_offset = self.scrollable_columns().iter().position(|&c| c == focused).unwrap_or(0);
+    }
+}
+
+fn compare_cells(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let (a, b) = (a.unwrap_or(""), b.unwrap_or(""));
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kubetile_core::QueryResult;
+
+    use super::*;
+    use crate::panes::query_pane::test_pane;
+
+    fn pane() -> QueryPane {
+        let mut pane = test_pane();
+        let rows = [["2", "b", "x"], ["10", "a", "y"], ["1", "c", "z"]];
+        pane.set_result(QueryResult {
+            headers: vec!["id".into(), "name".into(), "tag".into()],
+            rows: rows.iter().map(|r| r.iter().map(|c| c.to_string()).collect()).collect(),
+        });
+        pane
+    }
+
+    fn column(pane: &QueryPane, col: usize) -> Vec<&str> {
+        pane.result.as_ref().unwrap().rows.iter().map(|r| r[col].as_str()).collect()
+    }
+
+    #[test]
+    fn sorts_numbers_numerically_in_either_direction() {
+        let mut pane = pane();
+        pane.sort_by_focused_column(false);
+        assert_eq!(column(&pane, 0), ["1", "2", "10"]);
+        pane.sort_by_focused_column(true);
+        assert_eq!(column(&pane, 0), ["10", "2", "1"]);
+        pane.result_h_col_offset = 1;
+        pane.sort_by_focused_column(false);
+        assert_eq!(column(&pane, 1), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn hidden_and_pinned_columns_leave_the_scrolled_area() {
+        let mut pane = pane();
+        pane.pin_through_focused_column();
+        assert_eq!(pane.pinned_columns(), [0]);
+        assert_eq!(pane.focused_column(), Some(1));
+
+        pane.hide_focused_column();
+        assert_eq!(pane.scrollable_columns(), [2]);
+        pane.hide_focused_column();
+        assert_eq!(pane.scrollable_columns(), [2]);
+
+        pane.show_all_columns();
+        pane.result_h_col_offset = 1;
+        pane.unpin_columns();
+        assert_eq!(pane.pinned_columns(), Vec::<usize>::new());
+        assert_eq!(pane.focused_column(), Some(2));
+    }
+}
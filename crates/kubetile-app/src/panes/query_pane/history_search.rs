@@ -0,0 +1,72 @@
+use std::ops::Range;
+
+use regex::{Regex, RegexBuilder};
+
+/// What the history search input matches: a case-insensitive substring, or a regex when the
+/// input is wrapped in slashes (`/join\s+orders/`).
+pub(super) enum HistoryMatcher {
+    Substring(String),
+    Regex(Regex),
+    /// A `/…/` pattern that does not compile; matches nothing.
+    Invalid,
+}
+
+impl HistoryMatcher {
+    pub(super) fn new(input: &str) -> Option<Self> {
+        if input.is_empty() {
+            return None;
+        }
+        let pattern = input.strip_prefix('/').and_then(|rest| rest.strip_suffix('/')).filter(|p| !p.is_empty());
+        Some(match pattern {
+            Some(pattern) => match RegexBuilder::new(pattern).case_insensitive(true).build() {
+                Ok(regex) => Self::Regex(regex),
+                Err(_) => Self::Invalid,
+            },
+            None => Self::Substring(input.to_lowercase()),
+        })
+    }
+
+    /// Byte ranges of the matches in `text`.
+    pub(super) fn find(&self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Substring(needle) => {
+                // Lowercasing can change byte lengths outside ASCII; fall back to whole-text hits.
+                let haystack = text.to_lowercase();
+                if haystack.len() != text.len() {
+                    return haystack.contains(needle.as_str()).then_some(0..text.len()).into_iter().collect();
+                }
+                haystack.match_indices(needle.as_str()).map(|(start, m)| start..start + m.len()).collect()
+            }
+            Self::Regex(regex) => regex.find_iter(text).filter(|m| !m.is_empty()).map(|m| m.range()).collect(),
+            Self::Invalid => Vec::new(),
+        }
+    }
+
+    pub(super) fn is_match(&self, text: &str) -> bool {
+        !self.find(text).is_empty()
+    }
+
+    pub(super) fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substring_matches_anywhere_ignoring_case() {
+        let matcher = HistoryMatcher::new("orders").unwrap();
+        assert_eq!(matcher.find("SELECT *\nFROM Orders o JOIN orders_items"), vec![14..20, 28..34]);
+        assert!(HistoryMatcher::new("").is_none());
+    }
+
+    #[test]
+    fn slashes_make_a_regex() {
+        let matcher = HistoryMatcher::new(r"/join\s+\w+/").unwrap();
+        assert_eq!(matcher.find("a JOIN  users u"), vec![2..13]);
+        assert!(HistoryMatcher::new("/(/").unwrap().is_invalid());
+        assert!(!HistoryMatcher::new("/").unwrap().is_invalid());
+    }
+}
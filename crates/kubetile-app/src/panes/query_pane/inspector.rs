@@ -0,0 +1,121 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use kubetile_tui::theme::Theme;
+
+use super::QueryPane;
+
+/// The cell of the selected row shown in full in a popup.
+pub(super) struct CellInspectorState {
+    pub(super) col: usize,
+    pub(super) scroll: u16,
+}
+
+impl QueryPane {
+    /// Opens the inspector on the focused column of the selected row.
+    pub fn open_cell_inspector(&mut self) -> bool {
+        let has_row = self.result.as_ref().is_some_and(|r| r.rows.get(self.result_selected_row).is_some());
+        match self.focused_column() {
+            Some(col) if has_row => {
+                self.cell_inspector = Some(CellInspectorState { col, scroll: 0 });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn close_cell_inspector(&mut self) {
+        self.cell_inspector = None;
+    }
+
+    /// Moves the inspector to the previous (`-1`) or next (`1`) column.
+    pub fn cell_inspector_move(&mut self, delta: isize) {
+        let cols = self.result.as_ref().map(|r| r.headers.len()).unwrap_or(0);
+        if let Some(ref mut ci) = self.cell_inspector {
+            ci.col = ci.col.saturating_add_signed(delta).min(cols.saturating_sub(1));
+            ci.scroll = 0;
+        }
+    }
+
+    pub fn cell_inspector_scroll(&mut self, delta: i16) {
+        if let Some(ref mut ci) = self.cell_inspector {
+            ci.scroll = ci.scroll.saturating_add_signed(delta);
+        }
+    }
+
+    /// Header and raw value of the inspected cell.
+    pub fn inspected_cell(&self) -> Option<(&str, &str)> {
+        let ci = self.cell_inspector.as_ref()?;
+        let result = self.result.as_ref()?;
+        let header = result.headers.get(ci.col)?;
+        let value = result.rows.get(self.result_selected_row)?.get(ci.col)?;
+        Some((header, value))
+    }
+}
+
+/// Pretty-prints `value` when it is a JSON object or array, as JSON and JSONB columns are.
+pub(super) fn pretty_json(value: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if !(trimmed.starts_with('{') || trimmed.starts_with('[')) {
+        return None;
+    }
+    let parsed: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+    serde_json::to_string_pretty(&parsed).ok()
+}
+
+pub(super) fn render_cell_inspector_popup(frame: &mut Frame, area: Rect, pane: &QueryPane, theme: &Theme) {
+    let (Some(ci), Some((header, value))) = (pane.cell_inspector.as_ref(), pane.inspected_cell()) else {
+        return;
+    };
+    let total_cols = pane.result.as_ref().map(|r| r.headers.len()).unwrap_or(0);
+
+    let popup_w = (area.width * 4 / 5).max(30.min(area.width));
+    let popup_h = (area.height * 4 / 5).max(6.min(area.height));
+    let popup = Rect {
+        x: area.x + (area.width.saturating_sub(popup_w)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_h)) / 2,
+        width: popup_w,
+        height: popup_h,
+    };
+    frame.render_widget(Clear, popup);
+
+    let pretty = pretty_json(value);
+    let kind = if pretty.is_some() { " · JSON" } else { "" };
+    let block = Block::default()
+        .title(format!(" {header} ({}/{total_cols}){kind} ", ci.col + 1))
+        .title_style(Style::default().fg(theme.accent).bold())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.accent))
+        .style(theme.overlay);
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    if inner.height < 2 {
+        return;
+    }
+
+    let body_area = Rect { height: inner.height - 1, ..inner };
+    let hint_area = Rect { y: inner.y + inner.height - 1, height: 1, ..inner };
+    let text = pretty.unwrap_or_else(|| value.to_string());
+    frame.render_widget(
+        Paragraph::new(text).style(Style::default().fg(theme.fg)).wrap(Wrap { trim: false }).scroll((ci.scroll, 0)),
+        body_area,
+    );
+    frame.render_widget(
+        Paragraph::new("h/l column  j/k scroll  y copy cell  Esc close").style(theme.text_dim),
+        hint_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_prints_json_objects_and_arrays_only() {
+        assert_eq!(pretty_json(r#"{"a":1,"b":[true]}"#).unwrap(), "{\n  \"a\": 1,\n  \"b\": [\n    true\n  ]\n}");
+        assert!(pretty_json("[1, 2]").is_some());
+        assert_eq!(pretty_json("42"), None);
+        assert_eq!(pretty_json("{not json"), None);
+    }
+}
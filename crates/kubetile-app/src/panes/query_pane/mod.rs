@@ -5,17 +5,25 @@ use std::collections::HashMap;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState};
 
-use kubetile_core::{QueryConfig, QueryResult};
+use kubetile_core::{QueryConfig, QueryResult, ScopeLevel};
 use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
 use kubetile_tui::theme::Theme;
 
+mod columns;
 mod completion;
 mod editor;
+mod history_search;
+mod inspector;
+mod plan;
 mod popups;
 mod result;
 
+use columns::ColumnView;
 use completion::CompletionState;
-use popups::{QueryHistoryState, SavedQueriesState};
+use inspector::CellInspectorState;
+use plan::PlanView;
+use popups::{ParamPromptState, QueryHistoryState, SavedQueriesState};
+use result::ResultPaging;
 
 pub(super) enum QueryPaneStatus {
     Connecting,
@@ -24,6 +32,16 @@ pub(super) enum QueryPaneStatus {
     Error(String),
 }
 
+/// Where the pane's transaction stands; statements run on its session only while it is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransactionState {
+    #[default]
+    None,
+    /// The session is opening and `BEGIN` has not come back yet.
+    Starting,
+    Open,
+}
+
 pub struct QueryPane {
     view_type: ViewType,
     pod_name: String,
@@ -31,12 +49,16 @@ pub struct QueryPane {
     status: QueryPaneStatus,
     pub config: QueryConfig,
     connected_version: Option<String>,
+    transaction: TransactionState,
     editor_lines: Vec<String>,
     cursor_row: usize,
     cursor_col: usize,
     editor_scroll: usize,
     result: Option<QueryResult>,
+    paging: Option<ResultPaging>,
+    plan: Option<PlanView>,
     col_widths: Vec<usize>,
+    columns: ColumnView,
     result_selected_row: usize,
     result_scroll: usize,
     result_h_col_offset: usize,
@@ -47,8 +69,12 @@ pub struct QueryPane {
     last_executed_sql: Option<String>,
     history: Option<QueryHistoryState>,
     pending_save_name: Option<String>,
+    save_scope: ScopeLevel,
     saved_queries: Option<SavedQueriesState>,
     export_dialog_path: Option<String>,
+    cell_inspector: Option<CellInspectorState>,
+    param_prompt: Option<ParamPromptState>,
+    param_values: HashMap<String, String>,
     completion: Option<CompletionState>,
     schema_tables: Vec<(String, String)>,
     column_cache: HashMap<String, Vec<(String, String)>>,
@@ -63,12 +89,16 @@ impl QueryPane {
             status: QueryPaneStatus::Connecting,
             config: config.clone(),
             connected_version: None,
+            transaction: TransactionState::None,
             editor_lines: vec![String::new()],
             cursor_row: 0,
             cursor_col: 0,
             editor_scroll: 0,
             result: None,
+            paging: None,
+            plan: None,
             col_widths: Vec::new(),
+            columns: ColumnView::default(),
             result_selected_row: 0,
             result_scroll: 0,
             result_h_col_offset: 0,
@@ -79,8 +109,12 @@ impl QueryPane {
             last_executed_sql: None,
             history: None,
             pending_save_name: None,
+            save_scope: ScopeLevel::Global,
             saved_queries: None,
             export_dialog_path: None,
+            cell_inspector: None,
+            param_prompt: None,
+            param_values: HashMap::new(),
             completion: None,
             schema_tables: Vec::new(),
             column_cache: HashMap::new(),
@@ -99,7 +133,10 @@ impl QueryPane {
     pub fn set_executing(&mut self, sql: &str) {
         self.last_executed_sql = Some(sql.to_string());
         self.result = None;
+        self.paging = None;
+        self.plan = None;
         self.col_widths.clear();
+        self.columns = ColumnView::default();
         self.result_selected_row = 0;
         self.result_scroll = 0;
         self.result_h_col_offset = 0;
@@ -118,6 +155,14 @@ impl QueryPane {
     pub fn set_error(&mut self, error: String) {
         self.status = QueryPaneStatus::Error(error);
     }
+
+    pub fn set_transaction(&mut self, transaction: TransactionState) {
+        self.transaction = transaction;
+    }
+
+    pub fn transaction(&self) -> TransactionState {
+        self.transaction
+    }
 }
 
 impl Pane for QueryPane {
@@ -172,7 +217,9 @@ impl Pane for QueryPane {
 
         // Results — also produces col_range for the status line
         let mut col_range: Option<(usize, usize, usize)> = None;
-        if results_height > 0 {
+        if let (true, Some(view)) = (results_height > 0, &self.plan) {
+            plan::render_plan(frame, results_area, self, view, theme);
+        } else if results_height > 0 {
             match &self.result {
                 None => {
                     frame.render_widget(Paragraph::new("No results yet").style(theme.text_dim), results_area);
@@ -200,56 +247,83 @@ impl Pane for QueryPane {
                     let data_end = (scroll + data_visible).min(row_count);
 
                     let h_offset = self.result_h_col_offset;
-                    let total_cols = result.headers.len();
+                    let sort = self.columns.sort;
+                    let col_width = |col_i: usize| {
+                        let w = self.col_widths.get(col_i).copied().unwrap_or(0);
+                        // Room for the sort arrow after the header.
+                        if sort.is_some_and(|(c, _)| c == col_i) {
+                            w.max(result.headers[col_i].len() + 2)
+                        } else {
+                            w
+                        }
+                    };
+                    let pinned = self.pinned_columns();
+                    let scrollable = self.scrollable_columns();
                     let mut visible_cols: Vec<usize> = Vec::new();
                     let mut used = 0usize;
-                    for col_i in h_offset..total_cols {
-                        let w = self.col_widths.get(col_i).copied().unwrap_or(0);
+                    let mut last_visible = h_offset;
+                    for (pos, &col_i) in pinned.iter().chain(scrollable.iter().skip(h_offset)).enumerate() {
+                        let w = col_width(col_i);
                         let needed = if visible_cols.is_empty() { w } else { 2 + w };
                         if used + needed > text_width && !visible_cols.is_empty() {
                             break;
                         }
                         used += needed;
                         visible_cols.push(col_i);
+                        if pos >= pinned.len() {
+                            last_visible = h_offset + pos - pinned.len();
+                        }
                     }
-                    let last_visible = visible_cols.last().copied().unwrap_or(h_offset);
                     self.result_last_visible_col.set(last_visible);
 
+                    // Pinned columns end with a rule so they read as fixed.
+                    let gap = |vi: usize| match vi {
+                        0 => "",
+                        _ if vi == pinned.len() => "│ ",
+                        _ => "  ",
+                    };
+
                     let header_style = Style::default().fg(theme.accent).bold();
+                    let focused_col = scrollable.get(h_offset).copied();
                     let sep_style = theme.text_dim;
 
-                    let header_str: String = visible_cols
-                        .iter()
-                        .enumerate()
-                        .map(|(vi, &col_i)| {
-                            let h = &result.headers[col_i];
-                            let w = self.col_widths.get(col_i).copied().unwrap_or(h.len());
-                            if vi == 0 {
-                                format!("{:<width$}", h, width = w)
-                            } else {
-                                format!("  {:<width$}", h, width = w)
+                    let mut header_spans: Vec<Span> = Vec::new();
+                    let mut header_left = text_width;
+                    for (vi, &col_i) in visible_cols.iter().enumerate() {
+                        let label = match sort {
+                            Some((c, descending)) if c == col_i => {
+                                format!("{} {}", result.headers[col_i], if descending { "↓" } else { "↑" })
                             }
-                        })
-                        .collect();
+                            _ => result.headers[col_i].clone(),
+                        };
+                        let style = if Some(col_i) == focused_col {
+                            header_style.add_modifier(Modifier::UNDERLINED)
+                        } else {
+                            header_style
+                        };
+                        let cell = format!("{:<width$}", label, width = col_width(col_i));
+                        for (text, style) in [(gap(vi).to_string(), sep_style), (cell, style)] {
+                            let clipped: String = text.chars().take(header_left).collect();
+                            header_left -= clipped.chars().count();
+                            header_spans.push(Span::styled(clipped, style));
+                        }
+                    }
 
                     let sep_str: String = visible_cols
                         .iter()
                         .enumerate()
                         .map(|(vi, &col_i)| {
-                            let w = self.col_widths.get(col_i).copied().unwrap_or(0);
-                            if vi == 0 {
-                                "─".repeat(w)
-                            } else {
-                                format!("──{}", "─".repeat(w))
+                            let w = col_width(col_i);
+                            match gap(vi) {
+                                "" => "─".repeat(w),
+                                "│ " => format!("┼─{}", "─".repeat(w)),
+                                _ => format!("──{}", "─".repeat(w)),
                             }
                         })
                         .collect();
 
                     let mut lines: Vec<Line> = Vec::with_capacity(2 + data_visible);
-                    lines.push(Line::from(Span::styled(
-                        header_str.chars().take(text_width).collect::<String>(),
-                        header_style,
-                    )));
+                    lines.push(Line::from(header_spans));
                     lines.push(Line::from(Span::styled(
                         sep_str.chars().take(text_width).collect::<String>(),
                         sep_style,
@@ -262,12 +336,7 @@ impl Pane for QueryPane {
                             .enumerate()
                             .map(|(vi, &col_i)| {
                                 let cell = row.get(col_i).map(|s| s.as_str()).unwrap_or("");
-                                let w = self.col_widths.get(col_i).copied().unwrap_or(cell.len());
-                                if vi == 0 {
-                                    format!("{:<width$}", cell, width = w)
-                                } else {
-                                    format!("  {:<width$}", cell, width = w)
-                                }
+                                format!("{}{:<width$}", gap(vi), cell, width = col_width(col_i))
                             })
                             .collect();
                         let style =
@@ -283,7 +352,8 @@ impl Pane for QueryPane {
                         frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
                     }
 
-                    col_range = Some((h_offset + 1, last_visible + 1, total_cols));
+                    let shown = pinned.len() + scrollable.len();
+                    col_range = Some((pinned.len() + h_offset + 1, pinned.len() + last_visible + 1, shown));
                 }
             }
         }
@@ -300,11 +370,23 @@ impl Pane for QueryPane {
             if total > 1 {
                 status_text.push_str(&format!("  cols {first}–{last} of {total}"));
             }
+            if !self.columns.hidden.is_empty() {
+                status_text.push_str(&format!(" ({} hidden)", self.columns.hidden.len()));
+            }
+        }
+        if let Some(paging) = self.paging_status() {
+            status_text.push_str(&format!("  {paging}"));
         }
         if let Some(hint) = self.export_hint_text() {
             status_text.push_str(&format!("  {hint}"));
         }
-        frame.render_widget(Paragraph::new(status_text).style(status_style), status_area);
+        let mut status_line = vec![Span::styled(status_text, status_style)];
+        match self.transaction {
+            TransactionState::None => {}
+            TransactionState::Starting => status_line.push(Span::styled("  [TX starting]", theme.status_pending)),
+            TransactionState::Open => status_line.push(Span::styled("  [TX open]", theme.status_pending.bold())),
+        }
+        frame.render_widget(Paragraph::new(Line::from(status_line)), status_area);
 
         if let Some(ref c) = self.completion {
             let visible_row = self.cursor_row.saturating_sub(editor_scroll);
@@ -316,18 +398,28 @@ impl Pane for QueryPane {
             popups::render_history_popup(frame, area, h, theme);
         }
         if let Some(ref name_buf) = self.pending_save_name {
-            popups::render_save_name_popup(frame, area, name_buf, theme);
+            popups::render_save_name_popup(frame, area, name_buf, self.save_scope, theme);
         }
         if let Some(ref sq) = self.saved_queries {
             popups::render_saved_queries_popup(frame, area, sq, theme);
         }
         if let Some(ref path_buf) = self.export_dialog_path {
-            popups::render_export_dialog_popup(frame, area, path_buf, theme);
+            crate::panes::render_path_popup(frame, area, " Export to File ", path_buf, theme);
+        }
+        if self.cell_inspector.is_some() {
+            inspector::render_cell_inspector_popup(frame, area, self, theme);
+        }
+        if let Some(ref pp) = self.param_prompt {
+            popups::render_param_prompt_popup(frame, area, pp, theme);
         }
     }
 
     fn handle_command(&mut self, _cmd: &PaneCommand) {}
 
+    fn memory_usage(&self) -> usize {
+        self.result.as_ref().map_or(0, |result| super::rows_memory(&result.rows))
+    }
+
     fn view_type(&self) -> &ViewType {
         &self.view_type
     }
@@ -340,3 +432,19 @@ impl Pane for QueryPane {
         self
     }
 }
+
+/// A pane for Postgres in pod `db-0` of `data`, connected as `app` to database `app`.
+#[cfg(test)]
+pub(crate) fn test_pane() -> QueryPane {
+    QueryPane::new(&QueryConfig {
+        pod: "db-0".into(),
+        namespace: "data".into(),
+        container: None,
+        engine: Default::default(),
+        database: "app".into(),
+        user: "app".into(),
+        password: String::new(),
+        port: "5432".into(),
+        auth_database: String::new(),
+    })
+}
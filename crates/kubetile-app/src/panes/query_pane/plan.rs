@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use kubetile_core::query_plan::{PlanNode, QueryPlan};
+use kubetile_tui::theme::Theme;
+
+use super::columns::ColumnView;
+use super::{QueryPane, QueryPaneStatus};
+
+/// An EXPLAIN plan shown as a tree in place of the result table.
+pub(super) struct PlanView {
+    pub(super) plan: QueryPlan,
+    /// Child-index paths from the root of the nodes whose children are hidden.
+    pub(super) collapsed: HashSet<Vec<usize>>,
+}
+
+/// One visible line of the tree.
+pub(super) struct PlanRow<'a> {
+    pub(super) depth: usize,
+    pub(super) path: Vec<usize>,
+    pub(super) node: &'a PlanNode,
+    pub(super) collapsed: bool,
+}
+
+impl PlanView {
+    pub(super) fn visible_rows(&self) -> Vec<PlanRow<'_>> {
+        let mut rows = Vec::new();
+        self.push_rows(&self.plan.root, Vec::new(), &mut rows);
+        rows
+    }
+
+    fn push_rows<'a>(&'a self, node: &'a PlanNode, path: Vec<usize>, rows: &mut Vec<PlanRow<'a>>) {
+        let collapsed = self.collapsed.contains(&path);
+        rows.push(PlanRow { depth: path.len(), path: path.clone(), node, collapsed });
+        if collapsed {
+            return;
+        }
+        for (i, child) in node.children.iter().enumerate() {
+            let mut child_path = path.clone();
+            child_path.push(i);
+            self.push_rows(child, child_path, rows);
+        }
+    }
+}
+
+impl QueryPane {
+    pub fn set_plan(&mut self, plan: QueryPlan) {
+        self.result = None;
+        self.col_widths.clear();
+        self.columns = ColumnView::default();
+        self.result_selected_row = 0;
+        self.result_scroll = 0;
+        self.result_h_col_offset = 0;
+        let label = self.connected_version.clone().unwrap_or_else(|| "Ready".to_string());
+        self.status = QueryPaneStatus::Connected(label);
+        self.plan = Some(PlanView { plan, collapsed: HashSet::new() });
+    }
+
+    /// Hides (`collapse`) or shows the children of the selected plan node.
+    pub fn set_plan_node_collapsed(&mut self, collapse: bool) {
+        let Some(view) = self.plan.as_mut() else {
+            return;
+        };
+        let Some(path) = view.visible_rows().get(self.result_selected_row).map(|r| r.path.clone()) else {
+            return;
+        };
+        if collapse {
+            view.collapsed.insert(path);
+        } else {
+            view.collapsed.remove(&path);
+        }
+    }
+}
+
+pub(super) fn render_plan(frame: &mut Frame, area: Rect, pane: &QueryPane, view: &PlanView, theme: &Theme) {
+    let rows = view.visible_rows();
+    let summary = match (view.plan.planning_time, view.plan.execution_time) {
+        (Some(planning), Some(execution)) => format!("Planning {planning:.3} ms · Execution {execution:.3} ms"),
+        _ => "Plan".to_string(),
+    };
+
+    // The node that spends the most time itself is the first place to look.
+    let hottest = rows
+        .iter()
+        .filter_map(|r| Some((r.node.self_time()?, &r.path)))
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, path)| path.clone());
+
+    let visible = (area.height as usize).saturating_sub(1);
+    pane.result_visible_rows.set(visible);
+    pane.result_row_count.set(rows.len());
+    let scroll = pane.result_scroll.min(rows.len().saturating_sub(1));
+
+    let mut lines = vec![Line::from(Span::styled(summary, Style::default().fg(theme.accent).bold()))];
+    for (i, row) in rows.iter().enumerate().skip(scroll).take(visible) {
+        let marker = match (row.node.children.is_empty(), row.collapsed) {
+            (true, _) => "  ",
+            (false, true) => "▸ ",
+            (false, false) => "▾ ",
+        };
+        let label_style = if hottest.as_ref() == Some(&row.path) {
+            theme.status_failed.bold()
+        } else {
+            Style::default().fg(theme.fg)
+        };
+        let mut line = Line::from(vec![
+            Span::raw(format!("{}{marker}", "  ".repeat(row.depth))),
+            Span::styled(row.node.label(), label_style),
+            Span::styled(format!("  {}", row.node.metrics()), theme.text_dim),
+        ]);
+        if i == pane.result_selected_row {
+            line = line.style(theme.selection);
+        }
+        lines.push(line);
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panes::query_pane::test_pane;
+
+    fn pane_with_plan() -> QueryPane {
+        let plan = kubetile_core::query_plan::parse_plan(
+            r#"[{ "Plan": { "Node Type": "Nested Loop", "Plans": [
+                { "Node Type": "Hash", "Plans": [ { "Node Type": "Seq Scan", "Relation Name": "a" } ] },
+                { "Node Type": "Index Scan", "Relation Name": "b" }
+            ] } }]"#,
+        )
+        .unwrap();
+        let mut pane = test_pane();
+        pane.set_plan(plan);
+        pane
+    }
+
+    fn labels(pane: &QueryPane) -> Vec<String> {
+        pane.plan.as_ref().unwrap().visible_rows().iter().map(|r| r.node.label()).collect()
+    }
+
+    #[test]
+    fn collapsing_a_node_hides_its_subtree() {
+        let mut pane = pane_with_plan();
+        assert_eq!(labels(&pane), vec!["Nested Loop", "Hash", "Seq Scan on a", "Index Scan on b"]);
+
+        pane.result_selected_row = 1;
+        pane.scroll_h_left();
+        assert_eq!(labels(&pane), vec!["Nested Loop", "Hash", "Index Scan on b"]);
+
+        pane.scroll_h_right();
+        assert_eq!(labels(&pane).len(), 4);
+    }
+}
@@ -1,29 +1,57 @@
+use std::collections::HashMap;
+
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-use kubetile_core::SavedQuery;
+use kubetile_core::{QueryScope, SavedQuery, ScopeLevel};
+use kubetile_tui::fuzzy::{fuzzy_filter, highlight};
 
+use super::history_search::HistoryMatcher;
 use super::QueryPane;
 
 pub(super) struct QueryHistoryState {
     pub(super) entries: Vec<String>,
+    /// Index into the entries that match the search.
     pub(super) selected: usize,
+    pub(super) search: String,
+    /// Whether keys go to the search input.
+    pub(super) searching: bool,
+    pub(super) matcher: Option<HistoryMatcher>,
+}
+
+/// Indices of the history entries matching the search, most recent first.
+fn history_filtered(h: &QueryHistoryState) -> Vec<usize> {
+    match &h.matcher {
+        Some(matcher) => (0..h.entries.len()).filter(|&i| matcher.is_match(&h.entries[i])).collect(),
+        None => (0..h.entries.len()).collect(),
+    }
 }
 
 pub(super) struct SavedQueriesState {
     pub(super) entries: Vec<SavedQuery>,
+    /// Context, namespace and database of the pane; entries scoped elsewhere are hidden.
+    pub(super) here: [String; 3],
     pub(super) selected: usize,
     pub(super) filter_input: Option<String>,
     pub(super) rename_input: Option<String>,
 }
 
+/// Values for the `:name` placeholders of the SQL about to run.
+pub(super) struct ParamPromptState {
+    pub(super) sql: String,
+    /// Whether the bound SQL is explained rather than run.
+    pub(super) explain: bool,
+    pub(super) names: Vec<String>,
+    pub(super) values: Vec<String>,
+    pub(super) active: usize,
+}
+
 fn saved_queries_filtered(sq: &SavedQueriesState) -> Vec<(usize, &SavedQuery)> {
+    let [context, namespace, database] = &sq.here;
+    let in_scope = sq.entries.iter().enumerate().filter(|(_, e)| e.scope.matches(context, namespace, database));
     match &sq.filter_input {
-        Some(f) if !f.is_empty() => {
-            let f = f.to_lowercase();
-            sq.entries.iter().enumerate().filter(|(_, e)| e.name.to_lowercase().contains(&f)).collect()
-        }
-        _ => sq.entries.iter().enumerate().collect(),
+        Some(f) if !f.is_empty() => fuzzy_filter(f, in_scope, |(_, e)| &e.name),
+        _ => in_scope.collect(),
     }
 }
 
@@ -31,7 +59,54 @@ impl QueryPane {
     // --- History ---
 
     pub fn open_history(&mut self, entries: Vec<String>) {
-        self.history = Some(QueryHistoryState { entries, selected: 0 });
+        self.history =
+            Some(QueryHistoryState { entries, selected: 0, search: String::new(), searching: false, matcher: None });
+    }
+
+    /// Replaces the entries after a change, keeping the search and a valid selection.
+    pub fn refresh_history(&mut self, entries: Vec<String>) {
+        if let Some(ref mut h) = self.history {
+            h.entries = entries;
+            h.selected = h.selected.min(history_filtered(h).len().saturating_sub(1));
+        }
+    }
+
+    pub fn history_start_search(&mut self) {
+        if let Some(ref mut h) = self.history {
+            h.searching = true;
+        }
+    }
+
+    pub fn history_search_input(&mut self, c: char) {
+        if let Some(ref mut h) = self.history {
+            h.search.push(c);
+            h.matcher = HistoryMatcher::new(&h.search);
+            h.selected = 0;
+        }
+    }
+
+    pub fn history_search_backspace(&mut self) {
+        if let Some(ref mut h) = self.history {
+            h.search.pop();
+            h.matcher = HistoryMatcher::new(&h.search);
+            h.selected = 0;
+        }
+    }
+
+    /// Leaves the search input, keeping the list filtered.
+    pub fn history_search_confirm(&mut self) {
+        if let Some(ref mut h) = self.history {
+            h.searching = false;
+        }
+    }
+
+    pub fn history_search_cancel(&mut self) {
+        if let Some(ref mut h) = self.history {
+            h.searching = false;
+            h.search.clear();
+            h.matcher = None;
+            h.selected = 0;
+        }
     }
 
     pub fn close_history(&mut self) {
@@ -40,7 +115,7 @@ impl QueryPane {
 
     pub fn history_next(&mut self) {
         if let Some(ref mut h) = self.history {
-            if h.selected + 1 < h.entries.len() {
+            if h.selected + 1 < history_filtered(h).len() {
                 h.selected += 1;
             }
         }
@@ -53,17 +128,29 @@ impl QueryPane {
     }
 
     pub fn history_selected_sql(&self) -> Option<&str> {
-        self.history.as_ref()?.entries.get(self.history.as_ref()?.selected).map(|s| s.as_str())
+        let h = self.history.as_ref()?;
+        history_filtered(h).get(h.selected).map(|&i| h.entries[i].as_str())
     }
 
-    pub fn history_selected_index(&self) -> usize {
-        self.history.as_ref().map(|h| h.selected).unwrap_or(0)
+    /// Index of the selected entry in the full history.
+    pub fn history_selected_index(&self) -> Option<usize> {
+        let h = self.history.as_ref()?;
+        history_filtered(h).get(h.selected).copied()
     }
 
     // --- Save-name dialog ---
 
     pub fn open_save_name(&mut self) {
         self.pending_save_name = Some(String::new());
+        self.save_scope = ScopeLevel::Global;
+    }
+
+    pub fn cycle_save_scope(&mut self) {
+        self.save_scope = self.save_scope.next();
+    }
+
+    pub fn current_save_scope(&self) -> ScopeLevel {
+        self.save_scope
     }
 
     pub fn close_save_name(&mut self) {
@@ -88,8 +175,11 @@ impl QueryPane {
 
     // --- Saved-queries popup ---
 
-    pub fn open_saved_queries(&mut self, entries: Vec<SavedQuery>) {
-        self.saved_queries = Some(SavedQueriesState { entries, selected: 0, filter_input: None, rename_input: None });
+    /// Opens the library as seen from this pane in kubeconfig `context`.
+    pub fn open_saved_queries(&mut self, entries: Vec<SavedQuery>, context: &str) {
+        let here = [context.to_string(), self.config.namespace.clone(), self.config.database.clone()];
+        self.saved_queries =
+            Some(SavedQueriesState { entries, here, selected: 0, filter_input: None, rename_input: None });
     }
 
     pub fn close_saved_queries(&mut self) {
@@ -208,6 +298,45 @@ impl QueryPane {
     pub fn current_export_path(&self) -> Option<&str> {
         self.export_dialog_path.as_deref()
     }
+
+    // --- Parameter prompt ---
+
+    /// Asks for each placeholder of `sql`, pre-filled with the values last used in this pane.
+    pub fn open_param_prompt(&mut self, sql: String, names: Vec<String>, explain: bool) {
+        let values = names.iter().map(|n| self.param_values.get(n).cloned().unwrap_or_default()).collect();
+        self.param_prompt = Some(ParamPromptState { sql, explain, names, values, active: 0 });
+    }
+
+    pub fn close_param_prompt(&mut self) {
+        self.param_prompt = None;
+    }
+
+    pub fn param_input(&mut self, c: char) {
+        if let Some(ref mut pp) = self.param_prompt {
+            pp.values[pp.active].push(c);
+        }
+    }
+
+    pub fn param_backspace(&mut self) {
+        if let Some(ref mut pp) = self.param_prompt {
+            pp.values[pp.active].pop();
+        }
+    }
+
+    pub fn param_next_field(&mut self) {
+        if let Some(ref mut pp) = self.param_prompt {
+            pp.active = (pp.active + 1) % pp.names.len();
+        }
+    }
+
+    /// Closes the prompt, returning the SQL, its values (kept for the next prompt) and whether to
+    /// explain it.
+    pub fn take_param_prompt(&mut self) -> Option<(String, HashMap<String, String>, bool)> {
+        let pp = self.param_prompt.take()?;
+        let values: HashMap<String, String> = pp.names.into_iter().zip(pp.values).collect();
+        self.param_values.extend(values.clone());
+        Some((pp.sql, values, pp.explain))
+    }
 }
 
 pub(super) fn render_history_popup(
@@ -226,8 +355,13 @@ pub(super) fn render_history_popup(
     };
     frame.render_widget(Clear, popup);
 
+    let filtered = history_filtered(h);
     let count = h.entries.len();
-    let title = format!(" Query History ({count}) ");
+    let title = if h.matcher.is_some() {
+        format!(" Query History ({}/{count}) ", filtered.len())
+    } else {
+        format!(" Query History ({count}) ")
+    };
     let block = Block::default()
         .title(title)
         .title_style(Style::default().fg(theme.accent).bold())
@@ -243,12 +377,28 @@ pub(super) fn render_history_popup(
 
     let hint_y = inner.y + inner.height.saturating_sub(1);
     let hint_area = Rect { x: inner.x, y: hint_y, width: inner.width, height: 1 };
-    let list_area = Rect { height: inner.height.saturating_sub(1), ..inner };
+    let show_search = h.searching || !h.search.is_empty();
+    let list_h = inner.height.saturating_sub(if show_search { 2 } else { 1 });
+    let list_area = Rect { height: list_h, ..inner };
 
-    frame.render_widget(
-        Paragraph::new("j/k navigate  Enter select  d delete  Esc cancel").style(theme.text_dim),
-        hint_area,
-    );
+    let hint = if h.searching {
+        "Enter keep filter  Esc clear  /re/ for regex"
+    } else {
+        "j/k navigate  Enter select  d delete  / search  Esc cancel"
+    };
+    frame.render_widget(Paragraph::new(hint).style(theme.text_dim), hint_area);
+
+    if show_search {
+        let search_area = Rect { y: hint_y.saturating_sub(1), ..hint_area };
+        let cursor = if h.searching { "▌" } else { "" };
+        let mut spans = vec![Span::styled(format!("Search: {}{cursor}", h.search), Style::default().fg(theme.accent))];
+        match &h.matcher {
+            Some(m) if m.is_invalid() => spans.push(Span::styled("  invalid regex", theme.status_failed)),
+            Some(HistoryMatcher::Regex(_)) => spans.push(Span::styled("  (regex)", theme.text_dim)),
+            _ => {}
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), search_area);
+    }
 
     let list_w = (list_area.width * 2 / 5).max(10);
     let preview_w = list_area.width.saturating_sub(list_w + 1);
@@ -261,38 +411,50 @@ pub(super) fn render_history_popup(
 
     let visible = list_area.height as usize;
     let scroll = if h.selected >= visible { h.selected + 1 - visible } else { 0 };
-    let list_lines: Vec<Line> = h
-        .entries
+    let list_lines: Vec<Line> = filtered
         .iter()
         .enumerate()
         .skip(scroll)
         .take(visible)
-        .map(|(i, sql)| {
-            let first_line = sql.lines().next().unwrap_or("").chars().take(list_w as usize - 3).collect::<String>();
+        .map(|(i, &entry)| {
+            let sql = &h.entries[entry];
+            // While searching, list the line that matched rather than the first one.
+            let shown = match &h.matcher {
+                Some(m) => sql.lines().find(|l| m.is_match(l)).unwrap_or_else(|| sql.lines().next().unwrap_or("")),
+                None => sql.lines().next().unwrap_or(""),
+            };
+            let shown = shown.trim().chars().take(list_w as usize - 3).collect::<String>();
             let prefix = if i == h.selected { "> " } else { "  " };
-            let text = format!("{prefix}{first_line}");
+            let text = format!("{prefix}{shown}");
             let style = if i == h.selected { Style::default().fg(theme.accent).bold() } else { Style::default() };
             Line::from(Span::styled(text, style))
         })
         .collect();
     frame.render_widget(Paragraph::new(list_lines), left_area);
 
-    if let Some(sql) = h.entries.get(h.selected) {
-        let preview_lines: Vec<Line> = sql
+    if let Some(&entry) = filtered.get(h.selected) {
+        let match_style = Style::default().fg(theme.accent).add_modifier(Modifier::REVERSED);
+        let preview_lines: Vec<Line> = h.entries[entry]
             .lines()
-            .flat_map(|line| {
-                if line.is_empty() {
-                    vec![Line::from("")]
-                } else {
-                    line.chars()
-                        .collect::<Vec<_>>()
-                        .chunks(preview_w as usize)
-                        .map(|chunk| Line::from(chunk.iter().collect::<String>()))
-                        .collect()
+            .map(|line| {
+                let ranges = h.matcher.as_ref().map(|m| m.find(line)).unwrap_or_default();
+                let mut spans = Vec::new();
+                let mut last = 0;
+                for range in ranges {
+                    spans.push(Span::raw(line[last..range.start].to_string()));
+                    spans.push(Span::styled(line[range.clone()].to_string(), match_style));
+                    last = range.end;
                 }
+                spans.push(Span::raw(line[last..].to_string()));
+                Line::from(spans)
             })
             .collect();
-        frame.render_widget(Paragraph::new(preview_lines).style(Style::default().fg(theme.fg)), right_area);
+        frame.render_widget(
+            Paragraph::new(preview_lines).style(Style::default().fg(theme.fg)).wrap(Wrap { trim: false }),
+            right_area,
+        );
+    } else if filtered.is_empty() {
+        frame.render_widget(Paragraph::new("No matches").style(theme.text_dim), left_area);
     }
 }
 
@@ -300,6 +462,7 @@ pub(super) fn render_save_name_popup(
     frame: &mut Frame,
     area: Rect,
     name_buf: &str,
+    scope: ScopeLevel,
     theme: &kubetile_tui::theme::Theme,
 ) {
     let popup_w = (area.width.saturating_sub(4)).clamp(30, 60);
@@ -326,12 +489,17 @@ pub(super) fn render_save_name_popup(
     }
 
     let input_area = Rect { x: inner.x, y: inner.y, width: inner.width, height: 1 };
+    let scope_area = Rect { y: inner.y + 1, ..input_area };
     let hint_area = Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
 
     let display_name: String = name_buf.chars().take(inner.width.saturating_sub(8) as usize).collect();
     let label = format!("Name: {display_name}▌");
     frame.render_widget(Paragraph::new(label).style(Style::default().fg(theme.accent)), input_area);
-    frame.render_widget(Paragraph::new("Enter confirm  Esc cancel").style(theme.text_dim), hint_area);
+    frame.render_widget(
+        Paragraph::new(format!("Show: {}", scope.label())).style(Style::default().fg(theme.fg)),
+        scope_area,
+    );
+    frame.render_widget(Paragraph::new("Tab scope  Enter confirm  Esc cancel").style(theme.text_dim), hint_area);
 }
 
 pub(super) fn render_saved_queries_popup(
@@ -430,9 +598,18 @@ pub(super) fn render_saved_queries_popup(
                 entry.name.chars().take(max_name_w).collect()
             };
             let prefix = if is_selected { "> " } else { "  " };
-            let text = format!("{prefix}{display_name}");
             let style = if is_selected { Style::default().fg(theme.accent).bold() } else { Style::default() };
-            Line::from(Span::styled(text, style))
+            let mut spans = vec![Span::styled(prefix, style)];
+            match &sq.filter_input {
+                Some(f) if !(is_selected && sq.rename_input.is_some()) => {
+                    spans.extend(highlight(&display_name, f, style, theme))
+                }
+                _ => spans.push(Span::styled(display_name, style)),
+            }
+            if entry.scope != QueryScope::default() {
+                spans.push(Span::styled(format!(" [{}]", entry.scope.label()), theme.text_dim));
+            }
+            Line::from(spans)
         })
         .collect();
     frame.render_widget(Paragraph::new(list_lines), left_area);
@@ -459,14 +636,14 @@ pub(super) fn render_saved_queries_popup(
     }
 }
 
-pub(super) fn render_export_dialog_popup(
+pub(super) fn render_param_prompt_popup(
     frame: &mut Frame,
     area: Rect,
-    path_buf: &str,
+    pp: &ParamPromptState,
     theme: &kubetile_tui::theme::Theme,
 ) {
-    let popup_w = (area.width.saturating_sub(4)).clamp(30, 70);
-    let popup_h = 5u16;
+    let popup_w = (area.width.saturating_sub(4)).clamp(30, 60);
+    let popup_h = (pp.names.len() as u16 + 4).min(area.height);
     let popup = Rect {
         x: area.x + (area.width.saturating_sub(popup_w)) / 2,
         y: area.y + (area.height.saturating_sub(popup_h)) / 2,
@@ -476,7 +653,7 @@ pub(super) fn render_export_dialog_popup(
     frame.render_widget(Clear, popup);
 
     let block = Block::default()
-        .title(" Export to File ")
+        .title(" Query Parameters ")
         .title_style(Style::default().fg(theme.accent).bold())
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.accent))
@@ -488,17 +665,23 @@ pub(super) fn render_export_dialog_popup(
         return;
     }
 
-    let input_area = Rect { x: inner.x, y: inner.y, width: inner.width, height: 1 };
+    let label_w = pp.names.iter().map(|n| n.chars().count() + 1).max().unwrap_or(0);
+    let lines: Vec<Line> = pp
+        .names
+        .iter()
+        .zip(&pp.values)
+        .enumerate()
+        .map(|(i, (name, value))| {
+            let label = format!("{:<label_w$} : ", format!(":{name}"));
+            if i == pp.active {
+                Line::from(Span::styled(format!("{label}{value}▌"), Style::default().fg(theme.accent).bold()))
+            } else {
+                Line::from(Span::styled(format!("{label}{value}"), Style::default().fg(theme.fg)))
+            }
+        })
+        .collect();
+    let fields_area = Rect { height: inner.height.saturating_sub(2), ..inner };
     let hint_area = Rect { x: inner.x, y: inner.y + inner.height.saturating_sub(1), width: inner.width, height: 1 };
-
-    let prefix = "Path: ";
-    let max_path_w = inner.width.saturating_sub(prefix.len() as u16) as usize;
-    let display = if path_buf.len() > max_path_w {
-        format!("…{}", &path_buf[path_buf.len().saturating_sub(max_path_w.saturating_sub(1))..])
-    } else {
-        path_buf.to_string()
-    };
-    let label = format!("{prefix}{display}");
-    frame.render_widget(Paragraph::new(label).style(Style::default().fg(theme.accent)), input_area);
-    frame.render_widget(Paragraph::new("Enter confirm  Esc cancel").style(theme.text_dim), hint_area);
+    frame.render_widget(Paragraph::new(lines), fields_area);
+    frame.render_widget(Paragraph::new("Tab next  Enter run  Esc cancel").style(theme.text_dim), hint_area);
 }
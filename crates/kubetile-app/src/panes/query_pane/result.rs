@@ -1,18 +1,99 @@
 use kubetile_core::QueryResult;
 
+use super::columns::ColumnView;
 use super::{QueryPane, QueryPaneStatus};
 
+/// A result fetched page by page with LIMIT/OFFSET.
+pub(super) struct ResultPaging {
+    /// The statement as written, before the paging clause.
+    sql: String,
+    page_size: usize,
+    has_more: bool,
+    loading: bool,
+    /// The planner's row estimate for the whole result.
+    estimate: Option<u64>,
+}
+
 impl QueryPane {
-    pub fn set_result(&mut self, result: QueryResult) {
+    /// Marks the running query as paged. Each page is requested with one extra row, which tells
+    /// whether another page follows.
+    pub fn begin_paging(&mut self, sql: String, page_size: usize) {
+        self.paging = Some(ResultPaging { sql, page_size, has_more: false, loading: true, estimate: None });
+    }
+
+    pub fn set_result(&mut self, mut result: QueryResult) {
+        if let Some(ref mut paging) = self.paging {
+            paging.has_more = result.rows.len() > paging.page_size;
+            paging.loading = false;
+            result.rows.truncate(paging.page_size);
+        }
         self.col_widths = compute_col_widths(&result);
+        self.columns = ColumnView::default();
         self.result_selected_row = 0;
         self.result_scroll = 0;
         self.result_h_col_offset = 0;
         let label = self.connected_version.clone().unwrap_or_else(|| "Ready".to_string());
         self.status = QueryPaneStatus::Connected(label);
+        self.plan = None;
         self.result = Some(result);
     }
 
+    /// Offset and SQL of the next page, marking it as loading. `None` when the result is complete
+    /// or a page is already on its way.
+    pub fn next_page_request(&mut self) -> Option<(usize, String)> {
+        let offset = self.row_count();
+        let paging = self.paging.as_mut().filter(|p| p.has_more && !p.loading)?;
+        let sql = kubetile_core::query_paging::page_sql(&paging.sql, paging.page_size + 1, offset, self.config.engine)?;
+        paging.loading = true;
+        Some((offset, sql))
+    }
+
+    /// Appends the page fetched from `offset`. Pages of an earlier query are dropped.
+    pub fn append_page(&mut self, offset: usize, mut rows: Vec<Vec<String>>) {
+        let row_count = self.row_count();
+        let (Some(paging), Some(result)) = (self.paging.as_mut(), self.result.as_mut()) else {
+            return;
+        };
+        if !paging.loading || offset != row_count {
+            return;
+        }
+        paging.loading = false;
+        paging.has_more = rows.len() > paging.page_size;
+        rows.truncate(paging.page_size);
+        result.rows.append(&mut rows);
+        self.col_widths = compute_col_widths(result);
+        self.apply_sort();
+    }
+
+    pub fn page_failed(&mut self) {
+        if let Some(ref mut paging) = self.paging {
+            paging.loading = false;
+        }
+    }
+
+    pub fn set_row_estimate(&mut self, rows: u64) {
+        if let Some(ref mut paging) = self.paging {
+            paging.estimate = Some(rows);
+        }
+    }
+
+    /// Whether the selection sits on the last fetched row of a result with more pages.
+    pub fn wants_next_page(&self) -> bool {
+        self.paging.as_ref().is_some_and(|p| p.has_more && !p.loading)
+            && self.result_selected_row + 1 >= self.row_count()
+    }
+
+    pub(super) fn paging_status(&self) -> Option<String> {
+        let paging = self.paging.as_ref().filter(|p| p.has_more || p.loading)?;
+        let fetched = self.result.as_ref()?.rows.len();
+        let mut text = match paging.estimate {
+            Some(estimate) if estimate as usize > fetched => format!("fetched {fetched} of ~{estimate}"),
+            _ => format!("fetched {fetched}"),
+        };
+        text.push_str(if paging.loading { " · loading…" } else { " · n for more" });
+        Some(text)
+    }
+
     pub fn size_hint(&self) -> (usize, usize) {
         let row_count = self.result.as_ref().map(|r| r.rows.len()).unwrap_or(0);
         let est_bytes = self.col_widths.iter().sum::<usize>() * row_count.max(1);
@@ -92,13 +173,19 @@ impl QueryPane {
     }
 
     pub fn scroll_h_left(&mut self) {
-        if self.result_h_col_offset > 0 {
+        if self.plan.is_some() {
+            self.set_plan_node_collapsed(true);
+        } else if self.result_h_col_offset > 0 {
             self.result_h_col_offset -= 1;
         }
     }
 
     pub fn scroll_h_right(&mut self) {
-        let total = self.col_widths.len();
+        if self.plan.is_some() {
+            self.set_plan_node_collapsed(false);
+            return;
+        }
+        let total = self.scrollable_columns().len();
         let last_visible = self.result_last_visible_col.get();
         if total > 0 && last_visible + 1 < total {
             self.result_h_col_offset += 1;
@@ -135,3 +222,34 @@ fn csv_escape(s: &str) -> String {
         s.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panes::query_pane::test_pane;
+
+    fn rows(range: std::ops::Range<usize>) -> Vec<Vec<String>> {
+        range.map(|i| vec![i.to_string()]).collect()
+    }
+
+    #[test]
+    fn paged_result_fetches_following_pages_in_order() {
+        let mut pane = test_pane();
+        pane.set_executing("SELECT id FROM events");
+        pane.begin_paging("SELECT id FROM events".into(), 2);
+        pane.set_result(QueryResult { headers: vec!["id".into()], rows: rows(0..3) });
+        assert_eq!(pane.row_count(), 2);
+        assert_eq!(pane.paging_status().as_deref(), Some("fetched 2 · n for more"));
+
+        let (offset, sql) = pane.next_page_request().unwrap();
+        assert_eq!((offset, sql.as_str()), (2, "SELECT id FROM events\nLIMIT 3 OFFSET 2"));
+        assert_eq!(pane.next_page_request(), None);
+
+        pane.append_page(0, rows(0..3));
+        assert_eq!(pane.row_count(), 2);
+        pane.append_page(2, rows(2..4));
+        assert_eq!(pane.row_count(), 4);
+        assert_eq!(pane.paging_status(), None);
+        assert_eq!(pane.next_page_request(), None);
+    }
+}
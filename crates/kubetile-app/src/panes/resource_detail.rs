@@ -35,6 +35,15 @@ impl ResourceDetailPane {
         }
     }
 
+    pub fn subject(&self) -> (&ResourceKind, &str, Option<&str>) {
+        (&self.kind, &self.name, self.namespace.as_deref())
+    }
+
+    pub fn set_sections(&mut self, sections: Vec<DetailSection>) {
+        self.sections = sections;
+        self.selected_section = self.selected_section.min(self.sections.len().saturating_sub(1));
+    }
+
     fn total_content_height(&self) -> usize {
         let mut height = 0;
         for section in &self.sections {
@@ -230,6 +239,15 @@ mod tests {
         assert_eq!(pane.selected_section, 2);
     }
 
+    #[test]
+    fn set_sections_keeps_selection_in_range() {
+        let mut pane = ResourceDetailPane::new(ResourceKind::Pods, "test".into(), None, sample_sections());
+        pane.selected_section = 2;
+        pane.set_sections(sample_sections().into_iter().take(1).collect());
+        assert_eq!(pane.selected_section, 0);
+        assert_eq!(pane.sections.len(), 1);
+    }
+
     #[test]
     fn select_prev_decrements_section() {
         let mut pane = ResourceDetailPane::new(ResourceKind::Pods, "test".into(), None, sample_sections());
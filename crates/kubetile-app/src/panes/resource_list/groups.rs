@@ -0,0 +1,146 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::ResourceListPane;
+
+/// Joins the levels of an owner chain in the OWNER column, e.g. `Deployment/api > ReplicaSet/api-5d8f9c7b6`.
+const OWNER_SEPARATOR: &str = " > ";
+const NO_OWNER: &str = "(no owner)";
+
+/// A line of the grouped list: an owner heading or one of the filtered items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum DisplayRow {
+    /// `path` is the namespace and owner chain down to this heading and identifies it for collapsing.
+    Group {
+        path: String,
+        label: String,
+        depth: usize,
+        count: usize,
+    },
+    Item {
+        index: usize,
+        depth: usize,
+    },
+}
+
+impl ResourceListPane {
+    pub fn toggle_grouping(&mut self) {
+        self.grouped = !self.grouped;
+        self.refresh_filter_and_sort();
+    }
+
+    /// Collapses or expands the selected heading. On an item, collapses the group it sits in and
+    /// selects that group's heading.
+    pub fn toggle_selected_group(&mut self) {
+        let Some(selected) = self.state.selected.filter(|_| self.grouped) else { return };
+        let path = match self.display_rows.get(selected) {
+            Some(DisplayRow::Group { path, .. }) => path.clone(),
+            Some(DisplayRow::Item { index, .. }) => self.group_key(*index).join(OWNER_SEPARATOR),
+            None => return,
+        };
+        if !self.collapsed.remove(&path) {
+            self.collapsed.insert(path.clone());
+        }
+        self.refresh_filter_and_sort();
+        self.state.selected = self
+            .display_rows
+            .iter()
+            .position(|row| matches!(row, DisplayRow::Group { path: p, .. } if *p == path))
+            .or(self.state.selected);
+    }
+
+    pub(super) fn selected_group_path(&self) -> Option<&str> {
+        match self.display_rows.get(self.state.selected?)? {
+            DisplayRow::Group { path, .. } => Some(path),
+            DisplayRow::Item { .. } => None,
+        }
+    }
+
+    /// Cells of each display row, with names indented by depth, and the item behind it.
+    pub(super) fn grouped_rows(&self) -> Vec<(Vec<String>, Option<usize>)> {
+        let width = self.state.headers.len().max(1);
+        self.display_rows
+            .iter()
+            .map(|row| match row {
+                DisplayRow::Group { path, label, depth, count } => {
+                    let marker = if self.collapsed.contains(path) { "▸" } else { "▾" };
+                    let mut cells = vec![String::new(); width];
+                    cells[0] = format!("{}{marker} {label} ({count})", "  ".repeat(*depth));
+                    (cells, None)
+                }
+                DisplayRow::Item { index, depth } => {
+                    let mut cells = self.state.items[*index].clone();
+                    if let Some(name) = cells.first_mut() {
+                        *name = format!("{}{name}", "  ".repeat(*depth));
+                    }
+                    (cells, Some(*index))
+                }
+            })
+            .collect()
+    }
+
+    fn owner_path(&self, index: usize) -> &str {
+        self.item_owners.get(index).map(String::as_str).filter(|o| !o.is_empty()).unwrap_or(NO_OWNER)
+    }
+
+    /// Namespace of an item, empty for cluster-scoped kinds.
+    fn item_namespace(&self, index: usize) -> &str {
+        let Some(column) = self.state.headers.iter().position(|h| h == "NAMESPACE") else { return "" };
+        self.state.items[index].get(column).map(String::as_str).unwrap_or_default()
+    }
+
+    /// The item's namespace followed by its owner chain, so same-named owners in different
+    /// namespaces get their own groups. Items without an owner share one group.
+    fn group_key(&self, index: usize) -> Vec<&str> {
+        let owner = self.owner_path(index);
+        let namespace = if owner == NO_OWNER { "" } else { self.item_namespace(index) };
+        std::iter::once(namespace).chain(owner.split(OWNER_SEPARATOR)).collect()
+    }
+
+    /// Lays the filtered items out under their owners, keeping the sort order within each group.
+    /// Groups are ordered by namespace, then owner name; collapsed groups keep their heading and
+    /// hide the rest. Top-level owners name their namespace when the list spans several.
+    pub(super) fn rebuild_display_rows(&mut self) {
+        self.display_rows.clear();
+        if !self.grouped {
+            return;
+        }
+        let mut groups: BTreeMap<Vec<&str>, Vec<usize>> = BTreeMap::new();
+        for &index in &self.filtered_indices {
+            groups.entry(self.group_key(index)).or_default().push(index);
+        }
+        let namespaces: BTreeSet<&str> = groups.keys().map(|key| key[0]).filter(|ns| !ns.is_empty()).collect();
+        let spans_namespaces = namespaces.len() > 1;
+        let collapsed = |key: &[&str], levels: std::ops::Range<usize>| {
+            levels.into_iter().any(|end| self.collapsed.contains(&key[..end].join(OWNER_SEPARATOR)))
+        };
+
+        let mut rows = Vec::new();
+        let mut open: Vec<&str> = Vec::new();
+        for (key, items) in &groups {
+            let common = open.iter().zip(key).take_while(|(a, b)| a == b).count();
+            open.truncate(common);
+            if open.is_empty() {
+                open.push(key[0]);
+            }
+            for (level, owner) in key.iter().enumerate().skip(open.len()) {
+                open.push(owner);
+                let prefix = &key[..=level];
+                if collapsed(prefix, 2..prefix.len()) {
+                    continue;
+                }
+                let count = groups.iter().filter(|(k, _)| k.starts_with(prefix)).map(|(_, i)| i.len()).sum();
+                let label = match key[0] {
+                    namespace if level == 1 && spans_namespaces && !namespace.is_empty() => {
+                        format!("{owner} in {namespace}")
+                    }
+                    _ => owner.to_string(),
+                };
+                rows.push(DisplayRow::Group { path: prefix.join(OWNER_SEPARATOR), label, depth: level - 1, count });
+            }
+            if !collapsed(key, 2..key.len() + 1) {
+                rows.extend(items.iter().map(|&index| DisplayRow::Item { index, depth: key.len() - 1 }));
+            }
+        }
+        self.display_rows = rows;
+    }
+}
@@ -1,13 +1,39 @@
+mod groups;
+
 use std::any::Any;
 use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::time::Instant;
 
-use ratatui::prelude::{Frame, Rect};
+use ratatui::prelude::{Frame, Modifier, Rect, Style};
 
+use kubetile_core::resource::parse_quantity;
+use kubetile_core::{classify_row, RowStatus, WorkloadSummary};
 use kubetile_tui::pane::{Pane, PaneCommand, ResourceKind, ViewType};
 use kubetile_tui::widgets::resource_list::ResourceListWidget;
 
 use crate::state::ResourceListState;
 
+use groups::DisplayRow;
+
+/// Narrows a list to one namespace and, optionally, a label selector, e.g. the pods of a Deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListScope {
+    pub namespace: String,
+    pub label_selector: Option<String>,
+}
+
+/// Filter, sort, namespace scope and grouping of a resource list, kept per (pane, kind) across
+/// resource switches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceListViewState {
+    pub filter_text: String,
+    pub sort_column: Option<usize>,
+    pub sort_ascending: bool,
+    pub all_namespaces: bool,
+    pub grouped: bool,
+}
+
 pub struct ResourceListPane {
     view_type: ViewType,
     pub state: ResourceListState,
@@ -16,6 +42,25 @@ pub struct ResourceListPane {
     pub sort_column: Option<usize>,
     pub sort_ascending: bool,
     pub all_namespaces: bool,
+    /// Set when the list was opened from a related resource; overrides the namespace selection.
+    pub scope: Option<ListScope>,
+    /// Server-side filter the watch lists with, e.g. `status.phase=Pending`; set by the dashboard.
+    pub field_selector: Option<String>,
+    /// Hides healthy rows; set by the dashboard and cleared with the filter.
+    pub problems_only: bool,
+    /// OWNER cell of each row in `state.items`, kept even when the column is hidden; empty for
+    /// kinds without one.
+    pub item_owners: Vec<String>,
+    /// Key hint shown while the list is empty; set from the keymap when the pane is created.
+    pub empty_hint: String,
+    /// Rows are nested under their owners; `state.selected` then indexes `display_rows`.
+    grouped: bool,
+    collapsed: BTreeSet<String>,
+    display_rows: Vec<DisplayRow>,
+    /// Whether the configured default sort still has to be applied once headers arrive.
+    default_sort_pending: bool,
+    /// When the watcher last delivered rows; `None` until the first list arrives.
+    pub last_event: Option<Instant>,
 }
 
 impl ResourceListPane {
@@ -28,27 +73,43 @@ impl ResourceListPane {
             sort_column: None,
             sort_ascending: true,
             all_namespaces: false,
+            scope: None,
+            field_selector: None,
+            item_owners: Vec::new(),
+            empty_hint: String::new(),
+            grouped: false,
+            collapsed: BTreeSet::new(),
+            display_rows: Vec::new(),
+            problems_only: false,
+            default_sort_pending: true,
+            last_event: None,
         }
     }
 
     pub fn apply_filter(&mut self) {
-        if self.filter_text.is_empty() {
-            self.filtered_indices = (0..self.state.items.len()).collect();
-        } else {
-            let query = self.filter_text.to_lowercase();
-            self.filtered_indices = self
-                .state
-                .items
-                .iter()
-                .enumerate()
-                .filter(|(_, row)| row.iter().any(|cell| cell.to_lowercase().contains(&query)))
-                .map(|(i, _)| i)
-                .collect();
-        }
+        let query = self.filter_text.to_lowercase();
+        let kind_name = self.kind().map(|k| k.display_name()).unwrap_or_default();
+        let headers = &self.state.headers;
+        self.filtered_indices = self
+            .state
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| query.is_empty() || row.iter().any(|cell| cell.to_lowercase().contains(&query)))
+            .filter(|(_, row)| !self.problems_only || classify_row(kind_name, headers, row) != RowStatus::Healthy)
+            .map(|(i, _)| i)
+            .collect();
     }
 
-    fn selected_item_index(&self) -> Option<usize> {
+    /// The item behind the selected row; `None` when nothing or a group heading is selected.
+    pub fn selected_item_index(&self) -> Option<usize> {
         let selected = self.state.selected?;
+        if self.grouped {
+            return match self.display_rows.get(selected)? {
+                DisplayRow::Item { index, .. } => Some(*index),
+                DisplayRow::Group { .. } => None,
+            };
+        }
         if self.filtered_indices.is_empty() {
             Some(selected)
         } else {
@@ -57,7 +118,7 @@ impl ResourceListPane {
     }
 
     pub fn select_item_index(&mut self, item_idx: usize) -> bool {
-        if let Some(selected) = self.filtered_indices.iter().position(|&idx| idx == item_idx) {
+        if let Some(selected) = self.visible_position(item_idx) {
             self.state.selected = Some(selected);
             true
         } else {
@@ -65,22 +126,41 @@ impl ResourceListPane {
         }
     }
 
+    fn visible_position(&self, item_idx: usize) -> Option<usize> {
+        if self.grouped {
+            self.display_rows.iter().position(|row| matches!(row, DisplayRow::Item { index, .. } if *index == item_idx))
+        } else {
+            self.filtered_indices.iter().position(|&idx| idx == item_idx)
+        }
+    }
+
+    /// Number of selectable rows: filtered items, plus group headings when grouped.
+    fn visible_len(&self) -> usize {
+        if self.grouped {
+            self.display_rows.len()
+        } else {
+            self.filtered_indices.len()
+        }
+    }
+
     pub fn apply_sort(&mut self) {
-        let Some(col) = self.sort_column else { return };
-        let asc = self.sort_ascending;
-        let items = &self.state.items;
-        let header = self.state.headers.get(col).map(|s| s.as_str()).unwrap_or("");
-
-        self.filtered_indices.sort_by(|&a, &b| {
-            let va = items[a].get(col).map(|s| s.as_str()).unwrap_or("");
-            let vb = items[b].get(col).map(|s| s.as_str()).unwrap_or("");
-            let ord = compare_cells(header, va, vb);
-            if asc {
-                ord
-            } else {
-                ord.reverse()
-            }
-        });
+        if let Some(col) = self.sort_column {
+            let asc = self.sort_ascending;
+            let items = &self.state.items;
+            let header = self.state.headers.get(col).map(|s| s.as_str()).unwrap_or("");
+
+            self.filtered_indices.sort_by(|&a, &b| {
+                let va = items[a].get(col).map(|s| s.as_str()).unwrap_or("");
+                let vb = items[b].get(col).map(|s| s.as_str()).unwrap_or("");
+                let ord = compare_cells(header, va, vb);
+                if asc {
+                    ord
+                } else {
+                    ord.reverse()
+                }
+            });
+        }
+        self.rebuild_display_rows();
     }
 
     pub fn sort_by_column(&mut self, col: usize) {
@@ -93,18 +173,40 @@ impl ResourceListPane {
         self.apply_sort();
     }
 
+    /// Sorts by the header named `column` (case-insensitive) unless a sort was already chosen.
+    /// Only takes effect once, on the first update that carries headers.
+    pub fn apply_default_sort(&mut self, column: &str, ascending: bool) {
+        if !self.default_sort_pending || self.state.headers.is_empty() {
+            return;
+        }
+        self.default_sort_pending = false;
+        if self.sort_column.is_some() {
+            return;
+        }
+        if let Some(col) = self.state.headers.iter().position(|h| h.eq_ignore_ascii_case(column)) {
+            self.sort_column = Some(col);
+            self.sort_ascending = ascending;
+        }
+    }
+
     pub fn refresh_filter_and_sort(&mut self) {
         let prev_item_idx = self.selected_item_index();
+        let prev_group = self.selected_group_path().map(str::to_string);
         let prev_selected = self.state.selected;
         self.apply_filter();
         self.apply_sort();
-        self.state.selected = if self.filtered_indices.is_empty() {
+        let len = self.visible_len();
+        let prev_position = match (prev_item_idx, &prev_group) {
+            (Some(item_idx), _) => Some(self.visible_position(item_idx)),
+            (None, Some(path)) => Some(
+                self.display_rows.iter().position(|row| matches!(row, DisplayRow::Group { path: p, .. } if p == path)),
+            ),
+            (None, None) => None,
+        };
+        self.state.selected = if len == 0 {
             None
-        } else if let Some(prev_item_idx) = prev_item_idx {
-            self.filtered_indices
-                .iter()
-                .position(|&idx| idx == prev_item_idx)
-                .or_else(|| prev_selected.map(|sel| sel.min(self.filtered_indices.len().saturating_sub(1))))
+        } else if let Some(position) = prev_position {
+            position.or_else(|| prev_selected.map(|sel| sel.min(len - 1)))
         } else {
             Some(0)
         };
@@ -115,21 +217,23 @@ impl ResourceListPane {
     }
 
     fn nav_next(&mut self) {
-        if self.filtered_indices.is_empty() {
+        let len = self.visible_len();
+        if len == 0 {
             return;
         }
         self.state.selected = Some(match self.state.selected {
-            Some(i) => (i + 1) % self.filtered_indices.len(),
+            Some(i) => (i + 1) % len,
             None => 0,
         });
     }
 
     fn nav_prev(&mut self) {
-        if self.filtered_indices.is_empty() {
+        let len = self.visible_len();
+        if len == 0 {
             return;
         }
         self.state.selected = Some(match self.state.selected {
-            Some(0) | None => self.filtered_indices.len().saturating_sub(1),
+            Some(0) | None => len - 1,
             Some(i) => i - 1,
         });
     }
@@ -140,36 +244,81 @@ impl ResourceListPane {
             _ => None,
         }
     }
-}
 
-fn compare_cells(header: &str, a: &str, b: &str) -> Ordering {
-    if header.eq_ignore_ascii_case("age") {
-        return compare_age_cells(a, b);
+    /// Kind of the object in `row`: the list's kind, or the row's KIND cell in the workloads list.
+    pub fn row_kind(&self, row: &[String]) -> Option<ResourceKind> {
+        let kind = self.kind()?;
+        if *kind != ResourceKind::Workloads {
+            return Some(kind.clone());
+        }
+        let idx = self.state.headers.iter().position(|h| h == "KIND")?;
+        let kind = WorkloadSummary::kind_from_label(row.get(idx)?)?;
+        ResourceKind::from_short_name(kind.short_name())
     }
-    if header.eq_ignore_ascii_case("restarts") {
-        return compare_numeric_cells(a, b);
+
+    pub fn view_state(&self) -> ResourceListViewState {
+        ResourceListViewState {
+            filter_text: self.filter_text.clone(),
+            sort_column: self.sort_column,
+            sort_ascending: self.sort_ascending,
+            all_namespaces: self.all_namespaces,
+            grouped: self.grouped,
+        }
+    }
+
+    pub fn restore_view_state(&mut self, view_state: ResourceListViewState) {
+        self.filter_text = view_state.filter_text;
+        self.sort_column = view_state.sort_column;
+        self.sort_ascending = view_state.sort_ascending;
+        self.all_namespaces = view_state.all_namespaces;
+        self.grouped = view_state.grouped;
+        self.default_sort_pending = false;
+        self.refresh_filter_and_sort();
     }
-    a.cmp(b)
 }
 
-fn compare_age_cells(a: &str, b: &str) -> Ordering {
-    match (parse_age_seconds(a), parse_age_seconds(b)) {
-        (Some(va), Some(vb)) => va.cmp(&vb),
-        (Some(_), None) => Ordering::Less,
-        (None, Some(_)) => Ordering::Greater,
-        (None, None) => a.cmp(b),
+fn compare_cells(header: &str, a: &str, b: &str) -> Ordering {
+    match header.to_ascii_lowercase().as_str() {
+        "age" | "duration" | "last schedule" | "last-schedule" => compare_parsed(a, b, parse_age_seconds),
+        "restarts" | "desired" | "current" | "up-to-date" | "available" | "active" | "data" => {
+            compare_parsed(a, b, parse_u64_cell)
+        }
+        "ready" | "completions" => compare_parsed(a, b, parse_ratio_cell),
+        "ports" | "port(s)" => compare_parsed(a, b, parse_port_cell),
+        "capacity" | "used" | "use%" | "cpu" | "memory" | "storage" => compare_parsed(a, b, parse_quantity_cell),
+        _ => a.cmp(b),
     }
 }
 
-fn compare_numeric_cells(a: &str, b: &str) -> Ordering {
-    match (parse_u64_cell(a), parse_u64_cell(b)) {
-        (Some(va), Some(vb)) => va.cmp(&vb),
+/// Orders parseable cells by their parsed value ahead of unparseable ones, which keep text order.
+fn compare_parsed<T: PartialOrd>(a: &str, b: &str, parse: impl Fn(&str) -> Option<T>) -> Ordering {
+    match (parse(a), parse(b)) {
+        (Some(va), Some(vb)) => va.partial_cmp(&vb).unwrap_or(Ordering::Equal),
         (Some(_), None) => Ordering::Less,
         (None, Some(_)) => Ordering::Greater,
         (None, None) => a.cmp(b),
     }
 }
 
+/// `x/y` cells such as READY `1/2`, ordered by `x` then `y`.
+fn parse_ratio_cell(raw: &str) -> Option<(u64, u64)> {
+    let (x, y) = raw.trim().split_once('/')?;
+    Some((x.trim().parse().ok()?, y.trim().parse().ok()?))
+}
+
+/// First port number of a ports cell like `80:30080/TCP,443/TCP` or `80, 443`.
+fn parse_port_cell(raw: &str) -> Option<u64> {
+    let digits: String = raw.trim().chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+/// Kubernetes quantities (`100m`, `1.5Gi`, `2`), byte counts (`900B`) and percentages (`45%`) as
+/// plain numbers.
+fn parse_quantity_cell(raw: &str) -> Option<f64> {
+    let s = raw.trim();
+    parse_quantity(s.strip_suffix(['B', '%']).unwrap_or(s))
+}
+
 fn parse_u64_cell(raw: &str) -> Option<u64> {
     raw.trim().parse::<u64>().ok()
 }
@@ -226,27 +375,62 @@ fn parse_age_seconds(raw: &str) -> Option<u64> {
 
 impl Pane for ResourceListPane {
     fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &kubetile_tui::theme::Theme) {
-        let title = match &self.view_type {
+        let kind_name = match &self.view_type {
             ViewType::ResourceList(kind) => kind.display_name(),
             _ => "Resources",
         };
+        let title = match &self.scope {
+            Some(ListScope { namespace, label_selector: Some(selector) }) => {
+                format!("{kind_name} in {namespace} ({selector})")
+            }
+            Some(ListScope { namespace, label_selector: None }) => format!("{kind_name} in {namespace}"),
+            None => kind_name.to_string(),
+        };
+        let title = match &self.field_selector {
+            Some(selector) => format!("{title} [{selector}]"),
+            None => title,
+        };
+        let title = if self.problems_only { format!("{title} [unhealthy]") } else { title };
 
-        let filtered = self.filtered_items();
+        let row_style = |row: &Vec<String>| match classify_row(kind_name, &self.state.headers, row) {
+            RowStatus::Healthy => Style::default(),
+            RowStatus::Degraded => theme.status_pending,
+            RowStatus::Failed => theme.status_failed,
+            RowStatus::Terminating => theme.text_dim,
+        };
+        let grouped_rows = if self.grouped { self.grouped_rows() } else { Vec::new() };
+        let (filtered, row_styles): (Vec<&Vec<String>>, Vec<Style>) = if self.grouped {
+            grouped_rows
+                .iter()
+                .map(|(cells, item)| match item {
+                    Some(index) => (cells, row_style(&self.state.items[*index])),
+                    None => (cells, Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)),
+                })
+                .unzip()
+        } else {
+            self.filtered_items().into_iter().map(|row| (row, row_style(row))).unzip()
+        };
 
         let widget = ResourceListWidget {
-            title,
+            title: &title,
             headers: &self.state.headers,
             items: &filtered,
             selected: self.state.selected,
             scroll_offset: self.state.scroll_offset,
             loading: self.state.loading,
+            loading_more: self.state.partial,
+            expected_count: self.state.expected,
             error: self.state.error.as_deref(),
+            stale: self.state.stale.is_some(),
             focused,
             filter_text: if self.filter_text.is_empty() { None } else { Some(&self.filter_text) },
             sort_column: self.sort_column,
             sort_ascending: self.sort_ascending,
             total_count: self.state.items.len(),
             all_namespaces: self.all_namespaces,
+            status_kind: kind_name,
+            row_styles: &row_styles,
+            empty_hint: Some(&self.empty_hint),
             theme,
         };
         widget.render(frame, area);
@@ -262,6 +446,7 @@ impl Pane for ResourceListPane {
             }
             PaneCommand::ClearFilter => {
                 self.filter_text.clear();
+                self.problems_only = false;
                 self.refresh_filter_and_sort();
             }
             PaneCommand::SortByColumn(col) => {
@@ -275,6 +460,10 @@ impl Pane for ResourceListPane {
         }
     }
 
+    fn memory_usage(&self) -> usize {
+        super::rows_memory(&self.state.items)
+    }
+
     fn view_type(&self) -> &ViewType {
         &self.view_type
     }
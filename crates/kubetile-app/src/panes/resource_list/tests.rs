@@ -37,6 +37,17 @@ fn empty_filter_shows_all_items() {
     assert_eq!(pane.filtered_indices.len(), 4);
 }
 
+#[test]
+fn problems_only_hides_healthy_rows_until_filter_cleared() {
+    let mut pane = sample_pane();
+    pane.problems_only = true;
+    pane.refresh_filter_and_sort();
+    assert_eq!(pane.filtered_indices, vec![2, 3]);
+    pane.handle_command(&PaneCommand::ClearFilter);
+    assert!(!pane.problems_only);
+    assert_eq!(pane.filtered_indices.len(), 4);
+}
+
 #[test]
 fn sort_by_column_ascending() {
     let mut pane = sample_pane();
@@ -46,6 +57,19 @@ fn sort_by_column_ascending() {
     assert!(pane.sort_ascending);
 }
 
+#[test]
+fn default_sort_applies_once_and_yields_to_user_sort() {
+    let mut pane = sample_pane();
+    pane.apply_default_sort("name", false);
+    pane.refresh_filter_and_sort();
+    let names: Vec<&str> = pane.filtered_indices.iter().map(|&i| pane.state.items[i][0].as_str()).collect();
+    assert_eq!(names, vec!["redis-master-0", "nginx-sidecar-1", "nginx-pod-abc123", "api-gateway-xyz"]);
+
+    pane.sort_by_column(2);
+    pane.apply_default_sort("name", false);
+    assert_eq!(pane.sort_column, Some(2));
+}
+
 #[test]
 fn sort_toggle_flips_direction() {
     let mut pane = sample_pane();
@@ -258,3 +282,173 @@ fn restarts_column_sorts_numerically_descending() {
     let names: Vec<&str> = pane.filtered_indices.iter().map(|&i| pane.state.items[i][0].as_str()).collect();
     assert_eq!(names, vec!["pod-b", "pod-a", "pod-c", "pod-d"]);
 }
+
+#[test]
+fn restore_view_state_reapplies_filter_and_sort() {
+    let mut pane = sample_pane();
+    pane.handle_command(&PaneCommand::Filter("nginx".into()));
+    pane.sort_by_column(0);
+    pane.sort_by_column(0);
+    pane.all_namespaces = true;
+    let saved = pane.view_state();
+
+    let mut restored = sample_pane();
+    restored.restore_view_state(saved.clone());
+
+    assert_eq!(restored.view_state(), saved);
+    assert_eq!(restored.filtered_indices, vec![3, 0]);
+}
+
+fn sorted_cells(headers: [&str; 2], cells: &[&str]) -> Vec<String> {
+    let mut pane = ResourceListPane::new(ResourceKind::Pods, headers.iter().map(|h| h.to_string()).collect());
+    pane.state.set_items(cells.iter().enumerate().map(|(i, c)| vec![format!("row-{i}"), c.to_string()]).collect());
+    pane.refresh_filter_and_sort();
+    pane.sort_by_column(1);
+    pane.filtered_indices.iter().map(|&i| pane.state.items[i][1].clone()).collect()
+}
+
+#[test]
+fn ready_column_sorts_by_ready_then_total() {
+    assert_eq!(sorted_cells(["NAME", "READY"], &["10/10", "2/3", "0/1", "2/2"]), vec!["0/1", "2/2", "2/3", "10/10"]);
+}
+
+#[test]
+fn quantity_columns_sort_by_value() {
+    assert_eq!(sorted_cells(["NAME", "CPU"], &["1", "250m", "1500m", "50m"]), vec!["50m", "250m", "1", "1500m"]);
+    assert_eq!(
+        sorted_cells(["NAME", "CAPACITY"], &["1Gi", "512Mi", "10Gi", "900B", "2Ti"]),
+        vec!["900B", "512Mi", "1Gi", "10Gi", "2Ti"]
+    );
+}
+
+#[test]
+fn other_columns_sort_as_text() {
+    assert_eq!(sorted_cells(["NAME", "STATUS"], &["2m", "10", "Running"]), vec!["10", "2m", "Running"]);
+}
+
+#[test]
+fn percent_and_port_columns_sort_numerically() {
+    assert_eq!(sorted_cells(["NAME", "USE%"], &["9%", "100%", "45%", ""]), vec!["9%", "45%", "100%", ""]);
+    assert_eq!(
+        sorted_cells(["NAME", "PORT(S)"], &["8080/TCP", "443/TCP", "80:30080/TCP,443/TCP", "<none>"]),
+        vec!["80:30080/TCP,443/TCP", "443/TCP", "8080/TCP", "<none>"]
+    );
+}
+
+fn owned_pods_pane() -> ResourceListPane {
+    let mut pane = ResourceListPane::new(ResourceKind::Pods, vec!["NAME".into(), "STATUS".into()]);
+    pane.state.set_items(vec![
+        vec!["web-5d8f-a".into(), "Running".into()],
+        vec!["db-0".into(), "Running".into()],
+        vec!["debug".into(), "Pending".into()],
+        vec!["web-5d8f-b".into(), "Running".into()],
+    ]);
+    pane.item_owners = vec![
+        "Deployment/web > ReplicaSet/web-5d8f".into(),
+        "StatefulSet/db".into(),
+        String::new(),
+        "Deployment/web > ReplicaSet/web-5d8f".into(),
+    ];
+    pane.refresh_filter_and_sort();
+    pane
+}
+
+fn first_cells(pane: &ResourceListPane) -> Vec<String> {
+    pane.grouped_rows().into_iter().map(|(cells, _)| cells[0].clone()).collect()
+}
+
+#[test]
+fn grouping_nests_pods_under_their_owner_chain() {
+    let mut pane = owned_pods_pane();
+    pane.toggle_grouping();
+    assert_eq!(
+        first_cells(&pane),
+        [
+            "▾ (no owner) (1)",
+            "  debug",
+            "▾ Deployment/web (2)",
+            "  ▾ ReplicaSet/web-5d8f (2)",
+            "    web-5d8f-a",
+            "    web-5d8f-b",
+            "▾ StatefulSet/db (1)",
+            "  db-0",
+        ]
+    );
+
+    pane.state.selected = Some(4);
+    assert_eq!(pane.selected_item_index(), Some(0));
+    pane.state.selected = Some(2);
+    assert_eq!(pane.selected_item_index(), None);
+}
+
+#[test]
+fn folding_a_group_hides_its_rows_and_keeps_the_heading_selected() {
+    let mut pane = owned_pods_pane();
+    pane.toggle_grouping();
+    pane.state.selected = Some(4);
+    pane.toggle_selected_group();
+    assert_eq!(pane.state.selected, Some(3));
+    assert_eq!(
+        first_cells(&pane),
+        [
+            "▾ (no owner) (1)",
+            "  debug",
+            "▾ Deployment/web (2)",
+            "  ▸ ReplicaSet/web-5d8f (2)",
+            "▾ StatefulSet/db (1)",
+            "  db-0"
+        ]
+    );
+
+    pane.state.selected = Some(2);
+    pane.toggle_selected_group();
+    assert_eq!(first_cells(&pane)[2..], ["▸ Deployment/web (2)", "▾ StatefulSet/db (1)", "  db-0"]);
+
+    pane.toggle_selected_group();
+    pane.toggle_grouping();
+    assert_eq!(pane.filtered_items().len(), 4);
+}
+
+#[test]
+fn same_named_owners_in_different_namespaces_group_apart() {
+    let mut pane = ResourceListPane::new(ResourceKind::Pods, vec!["NAME".into(), "NAMESPACE".into()]);
+    pane.state.set_items(vec![
+        vec!["web-a".into(), "prod".into()],
+        vec!["web-b".into(), "staging".into()],
+        vec!["debug".into(), "prod".into()],
+    ]);
+    pane.item_owners = vec!["Deployment/web".into(), "Deployment/web".into(), String::new()];
+    pane.refresh_filter_and_sort();
+    pane.toggle_grouping();
+    assert_eq!(
+        first_cells(&pane),
+        [
+            "▾ (no owner) (1)",
+            "  debug",
+            "▾ Deployment/web in prod (1)",
+            "  web-a",
+            "▾ Deployment/web in staging (1)",
+            "  web-b",
+        ]
+    );
+
+    pane.state.selected = Some(3);
+    pane.toggle_selected_group();
+    assert_eq!(first_cells(&pane)[2..], ["▸ Deployment/web in prod (1)", "▾ Deployment/web in staging (1)", "  web-b"]);
+}
+
+#[test]
+fn workloads_rows_resolve_to_their_own_kind() {
+    let headers = vec!["NAME".into(), "NAMESPACE".into(), "KIND".into(), "READY".into(), "AGE".into()];
+    let mut pane = ResourceListPane::new(ResourceKind::Workloads, headers);
+    pane.state.set_items(vec![
+        vec!["api".into(), "prod".into(), "Deployment".into(), "2/2".into(), "3d".into()],
+        vec!["backup".into(), "prod".into(), "CronJob".into(), "Active".into(), "9d".into()],
+    ]);
+    pane.refresh_filter_and_sort();
+
+    assert_eq!(pane.row_kind(&pane.state.items[0]), Some(ResourceKind::Deployments));
+    assert_eq!(pane.row_kind(&pane.state.items[1]), Some(ResourceKind::CronJobs));
+    assert_eq!(pane.row_kind(&["x".into(), "prod".into(), "Pod".into()]), None);
+    assert_eq!(sample_pane().row_kind(&[]), Some(ResourceKind::Pods));
+}
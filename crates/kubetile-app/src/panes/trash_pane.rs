@@ -0,0 +1,157 @@
+use std::any::Any;
+
+use kubetile_tui::pane::{Pane, PaneCommand, ViewType};
+use kubetile_tui::widgets::resource_list::ResourceListWidget;
+use ratatui::prelude::{Frame, Rect};
+
+use crate::state::ResourceListState;
+
+/// One deleted resource as listed in the pane.
+#[derive(Clone)]
+pub struct TrashRow {
+    pub id: u64,
+    pub time: String,
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+    pub context: String,
+    /// Whether the manifest was kept, so the delete can be restored.
+    pub restorable: bool,
+}
+
+/// Resources deleted through kubetile this session, newest first.
+pub struct TrashPane {
+    view_type: ViewType,
+    state: ResourceListState,
+    ids: Vec<u64>,
+}
+
+impl TrashPane {
+    pub fn new() -> Self {
+        Self {
+            view_type: ViewType::Plugin("Trash".into()),
+            state: ResourceListState::new(vec![
+                "TIME".into(),
+                "KIND".into(),
+                "NAMESPACE".into(),
+                "NAME".into(),
+                "CONTEXT".into(),
+                "MANIFEST".into(),
+            ]),
+            ids: Vec::new(),
+        }
+    }
+
+    pub fn set_items(&mut self, items: Vec<TrashRow>) {
+        self.ids = items.iter().map(|row| row.id).collect();
+        let rows = items
+            .into_iter()
+            .map(|row| {
+                let manifest = if row.restorable { "kept" } else { "none" };
+                vec![row.time, row.kind, row.namespace, row.name, row.context, manifest.to_string()]
+            })
+            .collect();
+        self.state.set_items(rows);
+    }
+
+    pub fn selected_id(&self) -> Option<u64> {
+        let selected = self.state.selected?;
+        self.ids.get(selected).copied()
+    }
+
+    fn nav_next(&mut self) {
+        if self.state.items.is_empty() {
+            return;
+        }
+        self.state.selected = Some(match self.state.selected {
+            Some(i) => (i + 1) % self.state.items.len(),
+            None => 0,
+        });
+    }
+
+    fn nav_prev(&mut self) {
+        if self.state.items.is_empty() {
+            return;
+        }
+        self.state.selected = Some(match self.state.selected {
+            Some(0) | None => self.state.items.len().saturating_sub(1),
+            Some(i) => i - 1,
+        });
+    }
+}
+
+impl Pane for TrashPane {
+    fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &kubetile_tui::theme::Theme) {
+        let items: Vec<&Vec<String>> = self.state.items.iter().collect();
+        let widget = ResourceListWidget {
+            title: "Trash",
+            headers: &self.state.headers,
+            items: &items,
+            selected: self.state.selected,
+            scroll_offset: self.state.scroll_offset,
+            loading: false,
+            loading_more: false,
+            expected_count: None,
+            error: None,
+            stale: false,
+            focused,
+            filter_text: None,
+            sort_column: None,
+            sort_ascending: true,
+            total_count: self.state.items.len(),
+            all_namespaces: false,
+            status_kind: "",
+            row_styles: &[],
+            empty_hint: Some("Resources deleted through kubetile this session show up here"),
+            theme,
+        };
+        widget.render(frame, area);
+    }
+
+    fn handle_command(&mut self, cmd: &PaneCommand) {
+        match cmd {
+            PaneCommand::SelectNext | PaneCommand::ScrollDown => self.nav_next(),
+            PaneCommand::SelectPrev | PaneCommand::ScrollUp => self.nav_prev(),
+            _ => {}
+        }
+    }
+
+    fn view_type(&self) -> &ViewType {
+        &self.view_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: u64, name: &str) -> TrashRow {
+        TrashRow {
+            id,
+            time: "12:00:00".into(),
+            kind: "cm".into(),
+            namespace: "web".into(),
+            name: name.into(),
+            context: "prod".into(),
+            restorable: true,
+        }
+    }
+
+    #[test]
+    fn selection_maps_back_to_the_trash_entry() {
+        let mut pane = TrashPane::new();
+        pane.set_items(vec![row(7, "settings"), row(3, "flags")]);
+        assert_eq!(pane.state.items[1], vec!["12:00:00", "cm", "web", "flags", "prod", "kept"]);
+
+        pane.handle_command(&PaneCommand::SelectNext);
+        assert_eq!(pane.selected_id(), Some(3));
+    }
+}
@@ -7,10 +7,21 @@ use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientatio
 use kubetile_tui::pane::{Pane, PaneCommand, ResourceKind, ViewType};
 use kubetile_tui::theme::Theme;
 
+/// Lines under a top-level key or `managedFields` that can be hidden behind their header line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Fold {
+    /// Line of the key the fold hangs off.
+    start: usize,
+    /// Last line of the key's value.
+    end: usize,
+    top_level: bool,
+    folded: bool,
+}
+
 #[allow(dead_code)]
 pub struct YamlPane {
     view_type: ViewType,
-    resource_name: String,
+    title: String,
     content: String,
     styled_lines: Vec<Line<'static>>,
     total_lines: usize,
@@ -19,6 +30,11 @@ pub struct YamlPane {
     search_matches: Vec<usize>,
     current_match: usize,
     visible_height: Cell<u16>,
+    folds: Vec<Fold>,
+    /// Source lines left on screen once folded ones are hidden, in order.
+    visible: Vec<usize>,
+    /// The YAML as fetched, kept while its cleaned form is shown.
+    fetched: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -26,9 +42,10 @@ impl YamlPane {
     pub fn new(kind: ResourceKind, name: String, yaml_content: String, theme: &Theme) -> Self {
         let styled_lines = Self::highlight_yaml(&yaml_content, theme);
         let total_lines = styled_lines.len();
+        let folds = Self::fold_regions(&yaml_content);
         Self {
-            view_type: ViewType::Yaml(kind, name.clone()),
-            resource_name: name,
+            title: format!("YAML: {name}"),
+            view_type: ViewType::Yaml(kind, name),
             content: yaml_content,
             styled_lines,
             total_lines,
@@ -37,9 +54,168 @@ impl YamlPane {
             search_matches: vec![],
             current_match: 0,
             visible_height: Cell::new(0),
+            folds,
+            visible: (0..total_lines).collect(),
+            fetched: None,
+        }
+    }
+
+    /// Text that is not YAML, e.g. a report or script output: shown with line numbers but no
+    /// highlighting or folds, under `title`.
+    pub fn plain_text(view_type: ViewType, title: String, content: String, theme: &Theme) -> Self {
+        let styled_lines: Vec<Line<'static>> = content
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                Line::from(vec![Span::styled(format!("{:>4} │ ", i + 1), theme.text_dim), Span::raw(line.to_string())])
+            })
+            .collect();
+        let total_lines = styled_lines.len();
+        Self {
+            view_type,
+            title,
+            content,
+            styled_lines,
+            total_lines,
+            scroll_offset: 0,
+            search_query: None,
+            search_matches: vec![],
+            current_match: 0,
+            visible_height: Cell::new(0),
+            folds: Vec::new(),
+            visible: (0..total_lines).collect(),
+            fetched: None,
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn is_cleaned(&self) -> bool {
+        self.fetched.is_some()
+    }
+
+    /// Shows `cleaned` in place of the fetched YAML until `show_fetched` is called.
+    pub fn show_cleaned(&mut self, cleaned: String, theme: &Theme) {
+        let fetched = self.set_content(cleaned, theme);
+        self.fetched.get_or_insert(fetched);
+    }
+
+    /// Goes back to the YAML as fetched, with every fold open.
+    pub fn show_fetched(&mut self, theme: &Theme) {
+        if let Some(fetched) = self.fetched.take() {
+            self.set_content(fetched, theme);
+        }
+    }
+
+    /// Swaps in new text, returning the old. Folds reset; a search is rerun on the new text.
+    fn set_content(&mut self, content: String, theme: &Theme) -> String {
+        self.styled_lines = Self::highlight_yaml(&content, theme);
+        self.total_lines = self.styled_lines.len();
+        self.folds = Self::fold_regions(&content);
+        self.visible = (0..self.total_lines).collect();
+        self.scroll_offset = 0;
+        let old = std::mem::replace(&mut self.content, content);
+        self.update_search_matches();
+        old
+    }
+
+    /// Finds the top-level keys and `managedFields` keys whose value spans the lines below them.
+    fn fold_regions(content: &str) -> Vec<Fold> {
+        let lines: Vec<&str> = content.lines().collect();
+        let indent = |line: &str| line.len() - line.trim_start().len();
+        let mut folds = Vec::new();
+        for (start, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            let top_level = indent(line) == 0;
+            if !(top_level || trimmed == "managedFields:") || !trimmed.ends_with(':') || trimmed.starts_with('#') {
+                continue;
+            }
+            // Block sequences may sit at the key's own indent, as serde_yaml writes them.
+            let end = lines[start + 1..]
+                .iter()
+                .take_while(|l| {
+                    l.trim().is_empty()
+                        || indent(l) > indent(line)
+                        || (indent(l) == indent(line) && l.trim_start().starts_with("- "))
+                })
+                .count()
+                + start;
+            let end = (start..=end).rev().find(|&i| !lines[i].trim().is_empty()).unwrap_or(start);
+            if end > start {
+                folds.push(Fold { start, end, top_level, folded: false });
+            }
+        }
+        folds
+    }
+
+    /// Hides `managedFields`, for when `show_managed_fields` is off.
+    pub fn fold_managed_fields(&mut self) {
+        for fold in self.folds.iter_mut().filter(|f| !f.top_level) {
+            fold.folded = true;
+        }
+        self.rebuild_visible();
+    }
+
+    /// Collapses every top-level key, keeping the one in view at the top.
+    pub fn fold_all(&mut self) {
+        let top = self.top_line();
+        let anchor = self.folds.iter().find(|f| f.top_level && f.start <= top && top <= f.end).map_or(top, |f| f.start);
+        for fold in self.folds.iter_mut().filter(|f| f.top_level) {
+            fold.folded = true;
+        }
+        self.rebuild_visible();
+        self.scroll_to_line(anchor);
+    }
+
+    /// Expands every fold, `managedFields` included.
+    pub fn unfold_all(&mut self) {
+        let top = self.top_line();
+        for fold in &mut self.folds {
+            fold.folded = false;
+        }
+        self.rebuild_visible();
+        self.scroll_to_line(top);
+    }
+
+    /// Folds or unfolds the innermost fold holding the line at the top of the view.
+    pub fn toggle_fold(&mut self) {
+        let top = self.top_line();
+        let Some(fold) = self.folds.iter_mut().rev().find(|f| f.start <= top && top <= f.end) else { return };
+        fold.folded = !fold.folded;
+        let start = fold.start;
+        self.rebuild_visible();
+        self.scroll_to_line(start);
+    }
+
+    fn top_line(&self) -> usize {
+        let scroll = self.scroll_offset.min(self.max_scroll());
+        self.visible.get(scroll).copied().unwrap_or(0)
+    }
+
+    /// Puts `line` at the top of the view, or as close as the end of the document allows.
+    fn scroll_to_line(&mut self, line: usize) {
+        let row = self.visible.partition_point(|&l| l < line);
+        self.scroll_offset = row.min(self.max_scroll());
+    }
+
+    fn rebuild_visible(&mut self) {
+        self.visible.clear();
+        let mut line = 0;
+        while line < self.total_lines {
+            self.visible.push(line);
+            line = match self.folds.iter().find(|f| f.folded && f.start == line) {
+                Some(fold) => fold.end + 1,
+                None => line + 1,
+            };
         }
     }
 
+    fn hidden_below(&self, line: usize) -> Option<usize> {
+        self.folds.iter().find(|f| f.folded && f.start == line).map(|f| f.end - f.start)
+    }
+
     pub fn highlight_yaml(content: &str, theme: &Theme) -> Vec<Line<'static>> {
         content
             .lines()
@@ -128,15 +304,24 @@ impl YamlPane {
 
     fn scroll_to_match(&mut self) {
         if let Some(&line_num) = self.search_matches.get(self.current_match) {
+            let mut unfolded = false;
+            for fold in self.folds.iter_mut().filter(|f| f.folded && f.start < line_num && line_num <= f.end) {
+                fold.folded = false;
+                unfolded = true;
+            }
+            if unfolded {
+                self.rebuild_visible();
+            }
+            let row = self.visible.partition_point(|&l| l < line_num);
             let half_visible = self.visible_height.get() as usize / 2;
-            let target = line_num.saturating_sub(half_visible);
+            let target = row.saturating_sub(half_visible);
             self.scroll_offset = target.min(self.max_scroll());
         }
     }
 
     fn max_scroll(&self) -> usize {
         let view_height = self.visible_height.get().max(1) as usize;
-        self.total_lines.saturating_sub(view_height)
+        self.visible.len().saturating_sub(view_height)
     }
 
     fn prev_match(&mut self) {
@@ -155,7 +340,8 @@ impl Pane for YamlPane {
     fn render(&self, frame: &mut Frame, area: Rect, focused: bool, theme: &Theme) {
         let border_style = if focused { theme.border_active } else { theme.border };
 
-        let title = format!(" YAML: {} ", self.resource_name);
+        let cleaned = if self.is_cleaned() { " (cleaned)" } else { "" };
+        let title = format!(" {}{cleaned} ", self.title);
         let line_count = format!(" {} lines ", self.total_lines);
         let block = Block::default()
             .borders(Borders::ALL)
@@ -182,20 +368,23 @@ impl Pane for YamlPane {
         let scroll = self.scroll_offset.min(max_scroll);
 
         let display_lines: Vec<Line> = self
-            .styled_lines
+            .visible
             .iter()
-            .enumerate()
             .skip(scroll)
             .take(content_height as usize)
-            .map(|(line_idx, line)| {
+            .map(|&line_idx| {
+                let mut line = self.styled_lines[line_idx].clone();
+                if let Some(hidden) = self.hidden_below(line_idx) {
+                    line.push_span(Span::styled(format!(" … {hidden} lines"), theme.text_dim));
+                }
                 let is_match = self.search_matches.contains(&line_idx);
                 let is_current = self.search_matches.get(self.current_match).is_some_and(|&m| m == line_idx);
                 if is_current {
-                    line.clone().style(theme.selection)
+                    line.style(theme.selection)
                 } else if is_match {
-                    line.clone().style(Style::default().bg(Color::Rgb(49, 50, 68)))
+                    line.style(Style::default().bg(Color::Rgb(49, 50, 68)))
                 } else {
-                    line.clone()
+                    line
                 }
             })
             .collect();
@@ -204,7 +393,7 @@ impl Pane for YamlPane {
         frame.render_widget(paragraph, content_area);
 
         // Scrollbar
-        if self.total_lines > content_height as usize {
+        if self.visible.len() > content_height as usize {
             let mut scrollbar_state = ScrollbarState::new(max_scroll).position(scroll);
             frame.render_stateful_widget(
                 Scrollbar::new(ScrollbarOrientation::VerticalRight),
@@ -444,6 +633,102 @@ status:
         assert_eq!(*pane.view_type(), ViewType::Yaml(ResourceKind::Pods, "test".into()));
     }
 
+    #[test]
+    fn plain_text_is_not_highlighted_or_folded() {
+        let theme = test_theme();
+        let view = ViewType::Plugin("report".into());
+        let mut pane = YamlPane::plain_text(view.clone(), "Routes: web".into(), "host: a.example\n  ok".into(), &theme);
+        assert_eq!(*pane.view_type(), view);
+        assert!(pane.folds.is_empty());
+        assert!(pane.styled_lines[0].spans[1..].iter().all(|span| span.style == Style::default()));
+        pane.fold_all();
+        assert_eq!(pane.visible, [0, 1]);
+    }
+
+    const MANAGED_YAML: &str = "\
+apiVersion: v1
+kind: Pod
+metadata:
+  name: nginx
+  managedFields:
+  - manager: kubectl
+    operation: Update
+  namespace: default
+spec:
+  containers:
+  - name: nginx
+status:
+  phase: Running";
+
+    #[test]
+    fn fold_regions_cover_top_level_keys_and_managed_fields() {
+        let folds = YamlPane::fold_regions(MANAGED_YAML);
+        let spans: Vec<(usize, usize, bool)> = folds.iter().map(|f| (f.start, f.end, f.top_level)).collect();
+        assert_eq!(spans, [(2, 7, true), (4, 6, false), (8, 10, true), (11, 12, true)]);
+    }
+
+    #[test]
+    fn managed_fields_fold_leaves_the_rest_visible() {
+        let theme = test_theme();
+        let mut pane = YamlPane::new(ResourceKind::Pods, "nginx".into(), MANAGED_YAML.into(), &theme);
+        pane.fold_managed_fields();
+        assert_eq!(pane.visible, [0, 1, 2, 3, 4, 7, 8, 9, 10, 11, 12]);
+        assert_eq!(pane.hidden_below(4), Some(2));
+    }
+
+    #[test]
+    fn fold_all_and_unfold_all_collapse_and_restore_top_level_keys() {
+        let theme = test_theme();
+        let mut pane = YamlPane::new(ResourceKind::Pods, "nginx".into(), MANAGED_YAML.into(), &theme);
+        pane.visible_height.set(2);
+        pane.fold_managed_fields();
+        pane.fold_all();
+        assert_eq!(pane.visible, [0, 1, 2, 8, 11]);
+        pane.unfold_all();
+        assert_eq!(pane.visible.len(), pane.total_lines, "unfold all shows managedFields too");
+    }
+
+    #[test]
+    fn toggle_fold_acts_on_the_key_at_the_top_of_the_view() {
+        let theme = test_theme();
+        let mut pane = YamlPane::new(ResourceKind::Pods, "nginx".into(), MANAGED_YAML.into(), &theme);
+        pane.visible_height.set(2);
+        pane.scroll_offset = 9;
+        pane.toggle_fold();
+        assert_eq!(pane.visible, [0, 1, 2, 3, 4, 5, 6, 7, 8, 11, 12]);
+        assert_eq!(pane.top_line(), 8, "the folded key moves to the top");
+        pane.toggle_fold();
+        assert_eq!(pane.visible.len(), pane.total_lines);
+    }
+
+    #[test]
+    fn search_unfolds_the_fold_holding_a_match() {
+        let theme = test_theme();
+        let mut pane = YamlPane::new(ResourceKind::Pods, "nginx".into(), MANAGED_YAML.into(), &theme);
+        pane.visible_height.set(20);
+        pane.fold_managed_fields();
+        for ch in "kubectl".chars() {
+            pane.handle_command(&PaneCommand::SearchInput(ch));
+        }
+        pane.handle_command(&PaneCommand::SearchConfirm);
+        assert!(pane.visible.contains(&5));
+    }
+
+    #[test]
+    fn cleaned_view_swaps_content_and_restores_the_fetched_yaml() {
+        let theme = test_theme();
+        let mut pane = YamlPane::new(ResourceKind::Pods, "nginx".into(), MANAGED_YAML.into(), &theme);
+        pane.fold_managed_fields();
+        pane.show_cleaned("apiVersion: v1\nkind: Pod\nmetadata:\n  name: nginx\n".into(), &theme);
+        assert!(pane.is_cleaned());
+        assert_eq!(pane.total_lines, 4);
+        assert_eq!(pane.visible, [0, 1, 2, 3]);
+        pane.show_fetched(&theme);
+        assert!(!pane.is_cleaned());
+        assert_eq!(pane.content(), MANAGED_YAML);
+        assert_eq!(pane.visible.len(), pane.total_lines);
+    }
+
     #[test]
     fn list_markers_styled_dim() {
         let theme = test_theme();
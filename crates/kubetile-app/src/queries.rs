@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+
+use kubetile_core::SavedQueries;
+
+#[derive(Args)]
+pub struct QueriesArgs {
+    #[command(subcommand)]
+    action: QueriesAction,
+}
+
+#[derive(Subcommand)]
+enum QueriesAction {
+    /// Write the saved-query library to a file (TOML for `.toml`, otherwise YAML)
+    Export { file: PathBuf },
+    /// Merge a query pack into the library; queries with the same name and scope are replaced
+    Import { file: PathBuf },
+}
+
+/// Shares the query pane's saved queries as a pack a team can check in and distribute.
+pub fn run(args: QueriesArgs) -> Result<()> {
+    let mut library = SavedQueries::load();
+    match args.action {
+        QueriesAction::Export { file } => {
+            let count = library.export(&file)?;
+            println!("Exported {count} queries to {}", file.display());
+        }
+        QueriesAction::Import { file } => {
+            let count = library.import(&file)?;
+            println!("Imported {count} queries from {}", file.display());
+        }
+    }
+    Ok(())
+}
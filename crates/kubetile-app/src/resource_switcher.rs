@@ -1,3 +1,5 @@
+use kubetile_core::ResourceUsage;
+use kubetile_tui::fuzzy::fuzzy_match;
 use kubetile_tui::pane::ResourceKind;
 
 pub struct ResourceSwitcher {
@@ -14,6 +16,14 @@ impl ResourceSwitcher {
         Self { input: String::new(), all_kinds, filtered_kinds, selected: 0 }
     }
 
+    /// Lists the kinds used most often and most recently first; unused kinds keep their order.
+    pub fn with_usage(usage: &ResourceUsage) -> Self {
+        let mut switcher = Self::new();
+        switcher.all_kinds.sort_by(|a, b| usage.score(b.short_name()).total_cmp(&usage.score(a.short_name())));
+        switcher.filtered_kinds = switcher.all_kinds.clone();
+        switcher
+    }
+
     pub fn on_input(&mut self, ch: char) {
         self.input.push(ch);
         self.filter();
@@ -45,14 +55,26 @@ impl ResourceSwitcher {
         if query.is_empty() {
             self.filtered_kinds = self.all_kinds.clone();
         } else {
-            self.filtered_kinds = self
+            let mut scored: Vec<(ResourceKind, i64)> = self
                 .all_kinds
                 .iter()
-                .filter(|k| {
-                    k.short_name().to_lowercase().contains(&query) || k.display_name().to_lowercase().contains(&query)
+                .filter_map(|k| {
+                    let names = k.aliases().iter().copied().chain([k.short_name(), k.display_name()]);
+                    names.filter_map(|name| fuzzy_match(&query, name)).map(|m| m.score).max().map(|s| (k.clone(), s))
                 })
-                .cloned()
                 .collect();
+            // Stable, so equal matches keep the usage order
+            scored.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+            self.filtered_kinds = scored.into_iter().map(|(k, _)| k).collect();
+            // An exact alias such as `dp` or `sec` goes to the top
+            if let Some(pos) = self
+                .filtered_kinds
+                .iter()
+                .position(|k| k.aliases().contains(&query.as_str()) || k.short_name().eq_ignore_ascii_case(&query))
+            {
+                let kind = self.filtered_kinds.remove(pos);
+                self.filtered_kinds.insert(0, kind);
+            }
         }
         if self.selected >= self.filtered_kinds.len() {
             self.selected = self.filtered_kinds.len().saturating_sub(1);
@@ -87,8 +109,26 @@ mod tests {
         let mut sw = ResourceSwitcher::new();
         sw.on_input('p');
         sw.on_input('o');
-        assert_eq!(sw.filtered().len(), 1);
-        assert_eq!(sw.filtered()[0], ResourceKind::Pods);
+        assert_eq!(
+            sw.filtered()[..3],
+            [ResourceKind::Pods, ResourceKind::NetworkPolicies, ResourceKind::PodDisruptionBudgets]
+        );
+        assert_eq!(sw.confirm(), Some(ResourceKind::Pods));
+    }
+
+    #[test]
+    fn filter_matches_fuzzily() {
+        let mut sw = ResourceSwitcher::new();
+        for c in "statset".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.confirm(), Some(ResourceKind::StatefulSets));
+
+        let mut sw = ResourceSwitcher::new();
+        for c in "dset".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.filtered(), &[ResourceKind::DaemonSets, ResourceKind::PodDisruptionBudgets]);
     }
 
     #[test]
@@ -112,6 +152,37 @@ mod tests {
         assert!(names.contains(&"Secrets"));
     }
 
+    #[test]
+    fn exact_alias_ranks_first() {
+        let mut sw = ResourceSwitcher::new();
+        for c in "dp".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.confirm(), Some(ResourceKind::Deployments));
+
+        let mut sw = ResourceSwitcher::new();
+        for c in "sec".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.confirm(), Some(ResourceKind::Secrets));
+
+        let mut sw = ResourceSwitcher::new();
+        for c in "np".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.confirm(), Some(ResourceKind::NetworkPolicies));
+    }
+
+    #[test]
+    fn custom_kinds_match_on_short_name() {
+        let mut sw = ResourceSwitcher::new();
+        sw.all_kinds.push(ResourceKind::Custom("Certificate".into()));
+        for c in "cert".chars() {
+            sw.on_input(c);
+        }
+        assert_eq!(sw.confirm(), Some(ResourceKind::Custom("Certificate".into())));
+    }
+
     #[test]
     fn filter_xyz_matches_none() {
         let mut sw = ResourceSwitcher::new();
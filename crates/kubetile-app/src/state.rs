@@ -4,17 +4,36 @@ pub struct ResourceListState {
     pub selected: Option<usize>,
     pub scroll_offset: usize,
     pub loading: bool,
+    /// Rows shown so far are an incomplete page of the initial list.
+    pub partial: bool,
+    /// Size of the whole initial list while `partial`, once the server has reported it.
+    pub expected: Option<usize>,
     pub error: Option<String>,
+    /// Last watch error while `items` is still the previous snapshot and the watcher retries.
+    pub stale: Option<String>,
 }
 
 impl ResourceListState {
     pub fn new(headers: Vec<String>) -> Self {
-        Self { items: Vec::new(), headers, selected: None, scroll_offset: 0, loading: true, error: None }
+        Self {
+            items: Vec::new(),
+            headers,
+            selected: None,
+            scroll_offset: 0,
+            loading: true,
+            partial: false,
+            expected: None,
+            error: None,
+            stale: None,
+        }
     }
 
     pub fn set_items(&mut self, items: Vec<Vec<String>>) {
         self.loading = false;
+        self.partial = false;
+        self.expected = None;
         self.error = None;
+        self.stale = None;
         self.items = items;
         if self.items.is_empty() {
             self.selected = None;
@@ -27,11 +46,29 @@ impl ResourceListState {
         }
     }
 
+    pub fn set_partial_items(&mut self, items: Vec<Vec<String>>, expected: Option<usize>) {
+        self.set_items(items);
+        self.partial = true;
+        self.expected = expected;
+    }
+
     pub fn set_error(&mut self, err: String) {
         self.loading = false;
+        self.partial = false;
+        self.expected = None;
+        self.stale = None;
         self.error = Some(err);
     }
 
+    /// Keeps already loaded rows and marks them stale; without any rows yet this is a plain error.
+    pub fn set_watch_error(&mut self, err: String) {
+        if self.loading || self.error.is_some() {
+            self.set_error(err);
+        } else {
+            self.stale = Some(err);
+        }
+    }
+
     #[allow(dead_code)]
     pub fn next(&mut self) {
         if self.items.is_empty() {
@@ -61,8 +61,41 @@ fn set_error_clears_loading() {
     assert_eq!(state.error.as_deref(), Some("timeout"));
 }
 
+#[test]
+fn partial_items_stay_partial_until_full_list() {
+    let mut state = ResourceListState::new(vec!["A".into()]);
+    state.set_partial_items(vec![vec!["r0".into()]], Some(2));
+    assert!(!state.loading);
+    assert!(state.partial);
+    assert_eq!(state.expected, Some(2));
+    assert_eq!(state.selected, Some(0));
+    state.set_items(vec![vec!["r0".into()], vec!["r1".into()]]);
+    assert!(!state.partial);
+    assert_eq!(state.expected, None);
+}
+
 #[test]
 fn selected_item_returns_correct_row() {
     let state = sample_state();
     assert_eq!(state.selected_item().unwrap(), &vec!["r0".to_string()]);
 }
+
+#[test]
+fn watch_error_keeps_loaded_rows_as_stale() {
+    let mut state = sample_state();
+    state.set_watch_error("too old resource version".into());
+    assert_eq!(state.items.len(), 3);
+    assert!(state.error.is_none());
+    assert_eq!(state.stale.as_deref(), Some("too old resource version"));
+
+    state.set_items(vec![vec!["r0".into()]]);
+    assert!(state.stale.is_none());
+}
+
+#[test]
+fn watch_error_before_first_list_is_an_error() {
+    let mut state = ResourceListState::new(vec![]);
+    state.set_watch_error("connection refused".into());
+    assert_eq!(state.error.as_deref(), Some("connection refused"));
+    assert!(state.stale.is_none());
+}
@@ -1,10 +1,19 @@
-use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+use indexmap::IndexMap;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
 pub struct GeneralConfig {
     #[serde(alias = "tick-rate-ms")]
     pub tick_rate_ms: u64,
+    /// Most frames drawn per second; redraws asked for sooner are merged into the next frame.
+    /// 0 draws after every batch of events.
+    #[serde(alias = "max-fps")]
+    pub max_fps: u32,
     #[serde(alias = "default-namespace")]
     pub default_namespace: String,
     #[serde(alias = "default-view")]
@@ -19,12 +28,138 @@ pub struct GeneralConfig {
     pub show_managed_fields: bool,
     #[serde(alias = "query-open-new-tab")]
     pub query_open_new_tab: bool,
+    /// Rows fetched per page of a query pane result; 0 fetches the whole result at once.
+    #[serde(alias = "query-page-size")]
+    pub query_page_size: u32,
+    /// Objects fetched per LIST page when a resource view loads; 0 lists everything at once.
+    #[serde(alias = "list-page-size")]
+    pub list_page_size: u32,
+    /// Also write the app log to `~/.local/state/kubetile/kubetile.log`.
+    #[serde(alias = "app-log-file")]
+    pub app_log_file: bool,
+    /// Size in MiB at which the app log file is rotated; three old files are kept.
+    #[serde(alias = "app-log-max-mb")]
+    pub app_log_max_mb: u32,
+    /// Size in MiB at which a file a logs pane is teed to is rotated; three old files are kept.
+    #[serde(alias = "log-tee-max-mb")]
+    pub log_tee_max_mb: u32,
+    /// How long undo can recreate a deleted resource; 0 turns undo off. The trash tab can
+    /// restore deletes of the whole session either way.
+    #[serde(alias = "undo-window-secs")]
+    pub undo_window_secs: u64,
+    /// How long a restarted rollout is followed before it is reported as timed out.
+    #[serde(alias = "rollout-timeout-secs")]
+    pub rollout_timeout_secs: u64,
+    /// How often the API server round trip shown in the status bar is measured; 0 turns it off.
+    #[serde(alias = "api-probe-secs")]
+    pub api_probe_secs: u64,
+    /// How long fetched YAML and describe output is reused before asking the API again; 0 turns
+    /// the cache off.
+    #[serde(alias = "fetch-cache-secs")]
+    pub fetch_cache_secs: u64,
+    /// MiB that log lines, result rows and scrollback of all panes may hold before the streams
+    /// of panes not on screen are suspended; 0 turns the budget off.
+    #[serde(alias = "memory-budget-mb")]
+    pub memory_budget_mb: u64,
+    /// Seconds a tab stays in the background before its watchers and log streams are paused;
+    /// they resume, re-listing, when the tab is opened again. 0 keeps them running.
+    #[serde(alias = "pause-background-tabs-secs")]
+    pub pause_background_tabs_secs: u64,
+    /// Closing an exec pane whose shell is still running detaches the session instead of
+    /// killing it; the exec sessions picker reattaches it.
+    #[serde(alias = "detach-exec-on-close")]
+    pub detach_exec_on_close: bool,
+    /// How exec panes reach the container.
+    #[serde(alias = "exec-backend")]
+    pub exec_backend: ExecBackend,
+    /// How log panes follow pod logs.
+    #[serde(alias = "log-backend")]
+    pub log_backend: LogBackend,
+    /// Display timezone for absolute timestamps: `"local"`, `"UTC"` or an IANA name.
+    pub timezone: String,
+    /// Context name patterns (`*` wildcard) where mutate commands are disabled.
+    #[serde(alias = "read-only-contexts")]
+    pub read_only_contexts: Vec<String>,
+    pub confirm: ConfirmConfig,
+    /// How auto-opened YAML, detail, logs and exec panes split off the pane they open from.
+    pub splits: SplitsConfig,
+    /// Background watch on Warning events of the current namespace.
+    #[serde(alias = "warning-events")]
+    pub warning_events: WarningEventsConfig,
+    /// Which pods a node drain evicts besides those a controller recreates.
+    pub drain: DrainConfig,
+    /// Per-context timezone overrides keyed by context name pattern (`*` wildcard).
+    #[serde(alias = "context-timezones")]
+    pub context_timezones: IndexMap<String, String>,
+    /// SSH hosts (`user@host` or an ssh_config alias) that run kubectl for exec and log streams,
+    /// keyed by context name pattern (`*` wildcard).
+    #[serde(alias = "context-ssh-hosts")]
+    pub context_ssh_hosts: IndexMap<String, String>,
+    /// Per-context confirmation overrides keyed by context name pattern (`*` wildcard), then by
+    /// action as in `confirm`. Actions a matching context leaves out keep the global mode.
+    #[serde(alias = "context-confirm")]
+    pub context_confirm: IndexMap<String, IndexMap<String, ConfirmMode>>,
+}
+
+impl GeneralConfig {
+    /// Effective confirmation mode for a mutate action in `context`. The first context pattern
+    /// that sets the action wins over `confirm`; the legacy `confirm_delete = false` still
+    /// switches delete prompts off.
+    pub fn confirm_mode(&self, action: &str, context: Option<&str>) -> ConfirmMode {
+        let overridden = context.and_then(|ctx| {
+            self.context_confirm
+                .iter()
+                .filter(|(pattern, _)| context_matches(pattern, ctx))
+                .find_map(|(_, modes)| modes.iter().find(|(key, _)| key.replace('-', "_") == action))
+                .map(|(_, mode)| *mode)
+        });
+        if let Some(mode) = overridden {
+            return mode;
+        }
+        if action == "delete" && !self.confirm_delete {
+            return ConfirmMode::Off;
+        }
+        self.confirm.mode_for(action)
+    }
+
+    pub fn is_read_only(&self, context: Option<&str>) -> bool {
+        context.is_some_and(|ctx| self.read_only_contexts.iter().any(|pattern| context_matches(pattern, ctx)))
+    }
+
+    pub fn timezone_for(&self, context: Option<&str>) -> &str {
+        context
+            .and_then(|ctx| self.context_timezones.iter().find(|(pattern, _)| context_matches(pattern, ctx)))
+            .map_or(self.timezone.as_str(), |(_, tz)| tz.as_str())
+    }
+
+    pub fn ssh_host_for(&self, context: Option<&str>) -> Option<&str> {
+        let ctx = context?;
+        self.context_ssh_hosts.iter().find(|(pattern, _)| context_matches(pattern, ctx)).map(|(_, host)| host.as_str())
+    }
+}
+
+/// Matches a context name against a pattern where `*` stands for any run of characters.
+pub fn context_matches(pattern: &str, name: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let [first, middle @ .., last] = parts.as_slice() else { return pattern == name };
+    if name.len() < first.len() + last.len() || !name.starts_with(first) || !name.ends_with(last) {
+        return false;
+    }
+    let mut rest = &name[first.len()..name.len() - last.len()];
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
 }
 
 impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             tick_rate_ms: 250,
+            max_fps: 30,
             default_namespace: "default".into(),
             default_view: "pods".into(),
             editor: "$EDITOR".into(),
@@ -33,26 +168,289 @@ impl Default for GeneralConfig {
             confirm_delete: true,
             show_managed_fields: false,
             query_open_new_tab: true,
+            query_page_size: 1000,
+            list_page_size: 500,
+            app_log_file: false,
+            app_log_max_mb: 10,
+            log_tee_max_mb: 100,
+            undo_window_secs: 600,
+            rollout_timeout_secs: 300,
+            api_probe_secs: 15,
+            fetch_cache_secs: 5,
+            memory_budget_mb: 512,
+            pause_background_tabs_secs: 0,
+            detach_exec_on_close: true,
+            exec_backend: ExecBackend::Native,
+            log_backend: LogBackend::Native,
+            timezone: "local".into(),
+            read_only_contexts: Vec::new(),
+            confirm: ConfirmConfig::default(),
+            splits: SplitsConfig::default(),
+            warning_events: WarningEventsConfig::default(),
+            drain: DrainConfig::default(),
+            context_timezones: IndexMap::new(),
+            context_ssh_hosts: IndexMap::new(),
+            context_confirm: IndexMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// How a mutate action is confirmed: `false`, `true` (y/n prompt) or `"type-name"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfirmMode {
+    Off,
+    #[default]
+    Prompt,
+    TypeName,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawConfirmMode {
+    Flag(bool),
+    Named(String),
+}
+
+impl<'de> Deserialize<'de> for ConfirmMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match RawConfirmMode::deserialize(deserializer)? {
+            RawConfirmMode::Flag(true) => Ok(Self::Prompt),
+            RawConfirmMode::Flag(false) => Ok(Self::Off),
+            RawConfirmMode::Named(name) => match name.as_str() {
+                "type-name" | "type_name" => Ok(Self::TypeName),
+                "prompt" => Ok(Self::Prompt),
+                "off" => Ok(Self::Off),
+                other => Err(D::Error::custom(format!(
+                    "invalid confirm mode '{other}', expected true, false or \"type-name\""
+                ))),
+            },
+        }
+    }
+}
+
+impl Serialize for ConfirmMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Off => serializer.serialize_bool(false),
+            Self::Prompt => serializer.serialize_bool(true),
+            Self::TypeName => serializer.serialize_str("type-name"),
+        }
+    }
+}
+
+impl JsonSchema for ConfirmMode {
+    fn schema_name() -> Cow<'static, str> {
+        "ConfirmMode".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "`false`, `true` (y/n prompt) or `\"type-name\"`",
+            "anyOf": [{ "type": "boolean" }, { "enum": ["type-name", "prompt", "off"] }]
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    pub delete: ConfirmMode,
+    pub evict: ConfirmMode,
+    #[serde(alias = "force-delete")]
+    pub force_delete: ConfirmMode,
+    pub scale: ConfirmMode,
+    #[serde(alias = "restart_rollout", alias = "restart-rollout")]
+    pub restart: ConfirmMode,
+    #[serde(alias = "debug-mode")]
+    pub debug_mode: ConfirmMode,
+    #[serde(alias = "root-debug-mode")]
+    pub root_debug_mode: ConfirmMode,
+    pub drain: ConfirmMode,
+    pub apply: ConfirmMode,
+    /// Recreating a deleted resource with undo.
+    pub undo: ConfirmMode,
+    /// Pointing containers of a Deployment, StatefulSet or DaemonSet at new images.
+    #[serde(alias = "set-image")]
+    pub set_image: ConfirmMode,
+    /// Writing back a ConfigMap or Secret key edited in the editor.
+    #[serde(alias = "edit-data")]
+    pub edit_data: ConfirmMode,
+    /// Button a y/n prompt focuses first, so Enter picks it.
+    #[serde(alias = "default-button")]
+    pub default_button: ConfirmButton,
+    /// Seconds after which prompts for delete, force delete, evict and drain cancel themselves;
+    /// 0 keeps them open.
+    #[serde(alias = "auto-cancel-secs")]
+    pub auto_cancel_secs: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfirmButton {
+    Confirm,
+    #[default]
+    Cancel,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            delete: ConfirmMode::Prompt,
+            evict: ConfirmMode::Prompt,
+            force_delete: ConfirmMode::TypeName,
+            scale: ConfirmMode::Prompt,
+            restart: ConfirmMode::Prompt,
+            debug_mode: ConfirmMode::Prompt,
+            root_debug_mode: ConfirmMode::Prompt,
+            drain: ConfirmMode::TypeName,
+            apply: ConfirmMode::Prompt,
+            undo: ConfirmMode::Prompt,
+            set_image: ConfirmMode::Prompt,
+            edit_data: ConfirmMode::Prompt,
+            default_button: ConfirmButton::Cancel,
+            auto_cancel_secs: 0,
+        }
+    }
+}
+
+impl ConfirmConfig {
+    pub fn mode_for(&self, action: &str) -> ConfirmMode {
+        self.action_mode(action).unwrap_or(ConfirmMode::Prompt)
+    }
+
+    /// Mode configured for `action`, or `None` when no action has that name.
+    pub fn action_mode(&self, action: &str) -> Option<ConfirmMode> {
+        let mode = match action {
+            "delete" => self.delete,
+            "evict" => self.evict,
+            "force_delete" => self.force_delete,
+            "scale" => self.scale,
+            "restart" | "restart_rollout" => self.restart,
+            "debug_mode" => self.debug_mode,
+            "root_debug_mode" => self.root_debug_mode,
+            "drain" => self.drain,
+            "apply" => self.apply,
+            "undo" => self.undo,
+            "set_image" => self.set_image,
+            "edit_data" => self.edit_data,
+            _ => return None,
+        };
+        Some(mode)
+    }
+}
+
+/// Direction of a split: `horizontal` stacks the new pane below, `vertical` puts it to the right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct SplitConfig {
+    pub direction: SplitOrientation,
+    /// Share of the area the new pane takes, 0.1 to 0.9. Unset gives half, except that
+    /// horizontal logs and exec splits shrink the list above them to fit its rows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct SplitsConfig {
+    pub yaml: SplitConfig,
+    pub detail: SplitConfig,
+    pub logs: SplitConfig,
+    pub exec: SplitConfig,
+    /// Output panes of `[plugins]` commands.
+    pub plugin: SplitConfig,
+}
+
+/// `native` opens exec sessions over the API server's WebSocket with the app's own client, so no
+/// kubectl binary is needed; `kubectl` runs `kubectl exec -it` in a local PTY instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExecBackend {
+    #[default]
+    Native,
+    Kubectl,
+}
+
+/// `native` follows logs through the API server's pod log endpoint with the app's own client;
+/// `kubectl` runs `kubectl logs -f` instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LogBackend {
+    #[default]
+    Native,
+    Kubectl,
+}
+
+/// Where surfaced Warning events show: a scrolling segment of the status bar or toasts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WarningEventsDisplay {
+    #[default]
+    Ticker,
+    Toast,
+}
+
+/// Like `kubectl drain`, pods nothing would recreate and pods with `emptyDir` data are left on
+/// the node unless these are set.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct DrainConfig {
+    /// Also evict pods without a controller (`kubectl drain --force`).
+    pub force: bool,
+    /// Also evict pods using `emptyDir` volumes, losing their data (`--delete-emptydir-data`).
+    #[serde(alias = "delete-emptydir-data")]
+    pub delete_emptydir_data: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct WarningEventsConfig {
+    pub enabled: bool,
+    pub display: WarningEventsDisplay,
+    /// Reason patterns (`*` wildcard) to show; empty shows every reason.
+    #[serde(alias = "include-reasons")]
+    pub include_reasons: Vec<String>,
+    /// Reason patterns (`*` wildcard) never shown, even when included.
+    #[serde(alias = "exclude-reasons")]
+    pub exclude_reasons: Vec<String>,
+}
+
+impl WarningEventsConfig {
+    pub fn shows_reason(&self, reason: &str) -> bool {
+        let included =
+            self.include_reasons.is_empty() || self.include_reasons.iter().any(|p| context_matches(p, reason));
+        included && !self.exclude_reasons.iter().any(|p| context_matches(p, reason))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
 pub struct TerminalConfig {
     #[serde(alias = "scrollback-lines")]
     pub scrollback_lines: u32,
     #[serde(alias = "cursor-style")]
     pub cursor_style: String,
+    /// Captures the mouse so the wheel scrolls the focused pane. Terminals then need shift held
+    /// to select text.
+    #[serde(alias = "mouse-scroll")]
+    pub mouse_scroll: bool,
 }
 
 impl Default for TerminalConfig {
     fn default() -> Self {
-        Self { scrollback_lines: 10000, cursor_style: "block".into() }
+        Self { scrollback_lines: 10000, cursor_style: "block".into(), mouse_scroll: true }
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
 pub struct FeatureFlags {
     #[serde(alias = "hot-reload")]
@@ -61,10 +459,16 @@ pub struct FeatureFlags {
     pub command_palette: bool,
     #[serde(alias = "port-forward")]
     pub port_forward: bool,
+    /// PVC used/% columns read from kubelet stats; needs `get` on `nodes/proxy`.
+    #[serde(alias = "volume-stats")]
+    pub volume_stats: bool,
+    /// Newer image tags looked up in the image registries; needs network access to them.
+    #[serde(alias = "image-updates")]
+    pub image_updates: bool,
 }
 
 impl Default for FeatureFlags {
     fn default() -> Self {
-        Self { hot_reload: true, command_palette: true, port_forward: true }
+        Self { hot_reload: true, command_palette: true, port_forward: true, volume_stats: false, image_updates: false }
     }
 }
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 
 use indexmap::IndexMap;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct KeybindingsConfig {
     #[serde(default)]
     pub navigation: IndexMap<String, String>,
@@ -31,7 +32,7 @@ pub struct KeybindingsConfig {
 
 impl KeybindingsConfig {
     /// Normal-mode groups only — used for cross-group collision detection.
-    fn group_entries(&self) -> [(&str, &IndexMap<String, String>); 6] {
+    pub fn group_entries(&self) -> [(&str, &IndexMap<String, String>); 6] {
         [
             ("global", &self.global),
             ("mutate", &self.mutate),
@@ -42,6 +43,17 @@ impl KeybindingsConfig {
         ]
     }
 
+    /// Groups only active in their own input mode — checked for collisions within each group.
+    fn mode_group_entries(&self) -> [(&str, &IndexMap<String, String>); 5] {
+        [
+            ("query_editor", &self.query_editor),
+            ("query_browse", &self.query_browse),
+            ("query_history", &self.query_history),
+            ("saved_queries", &self.saved_queries),
+            ("completion", &self.completion),
+        ]
+    }
+
     /// All groups including mode-specific — used for key-string validation.
     fn all_group_entries(&self) -> [(&str, &IndexMap<String, String>); 11] {
         [
@@ -58,6 +70,11 @@ impl KeybindingsConfig {
             ("completion", &self.completion),
         ]
     }
+
+    /// The group called `name`, e.g. `"browse"`.
+    pub fn group(&self, name: &str) -> Option<&IndexMap<String, String>> {
+        self.all_group_entries().into_iter().find(|(group, _)| *group == name).map(|(_, map)| map)
+    }
 }
 
 pub fn validate_keybindings(config: &KeybindingsConfig) -> Vec<(String, String, String)> {
@@ -72,17 +89,29 @@ pub fn validate_keybindings(config: &KeybindingsConfig) -> Vec<(String, String,
     errors
 }
 
+/// Keys bound twice within one input mode, as `(key, first group, second group)`. Normal-mode
+/// groups share one key space; each mode-specific group is its own.
 pub fn check_collisions(config: &KeybindingsConfig) -> Vec<(String, String, String)> {
-    let mut seen: HashMap<String, String> = HashMap::new();
     let mut collisions = Vec::new();
+    let mut record = |seen: &mut HashMap<String, String>, group: &str, key_str: &String| {
+        let normalized = key_str.trim().to_ascii_lowercase();
+        if let Some(prev_group) = seen.get(&normalized) {
+            collisions.push((key_str.clone(), prev_group.clone(), group.to_string()));
+        } else {
+            seen.insert(normalized, group.to_string());
+        }
+    };
+
+    let mut seen = HashMap::new();
     for (group, map) in config.group_entries() {
         for key_str in map.values() {
-            let normalized = key_str.trim().to_ascii_lowercase();
-            if let Some(prev_group) = seen.get(&normalized) {
-                collisions.push((key_str.clone(), prev_group.clone(), group.to_string()));
-            } else {
-                seen.insert(normalized, group.to_string());
-            }
+            record(&mut seen, group, key_str);
+        }
+    }
+    for (group, map) in config.mode_group_entries() {
+        let mut seen = HashMap::new();
+        for key_str in map.values() {
+            record(&mut seen, group, key_str);
         }
     }
     collisions
@@ -158,6 +187,25 @@ mod tests {
         assert_eq!(collisions[0].0, "q");
     }
 
+    #[test]
+    fn check_collisions_within_a_mode_group_only() {
+        let mut config = KeybindingsConfig::default();
+        config.global.insert("quit".into(), "ctrl+q".into());
+        config.query_editor.insert("execute".into(), "ctrl+q".into());
+        config.query_history.insert("select".into(), "enter".into());
+        config.query_history.insert("delete".into(), "Enter".into());
+
+        let collisions = check_collisions(&config);
+        assert_eq!(collisions, vec![("Enter".to_string(), "query_history".to_string(), "query_history".to_string())]);
+    }
+
+    #[test]
+    fn default_keybindings_are_valid_and_collision_free() {
+        let config = crate::AppConfig::default();
+        assert!(validate_keybindings(&config.keybindings).is_empty());
+        assert!(check_collisions(&config.keybindings).is_empty());
+    }
+
     #[test]
     fn check_collisions_none_when_unique() {
         let mut config = KeybindingsConfig::default();
@@ -167,4 +215,12 @@ mod tests {
         let collisions = check_collisions(&config);
         assert!(collisions.is_empty());
     }
+
+    #[test]
+    fn groups_are_found_by_name() {
+        let mut config = KeybindingsConfig::default();
+        config.completion.insert("accept".into(), "tab".into());
+        assert_eq!(config.group("completion").and_then(|g| g.get("accept")).map(String::as_str), Some("tab"));
+        assert!(config.group("nope").is_none());
+    }
 }
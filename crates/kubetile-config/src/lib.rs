@@ -1,18 +1,27 @@
 pub mod general;
 pub mod keybindings;
+pub mod logs;
+pub mod plugins;
 pub mod theme;
 pub mod views;
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-pub use general::{FeatureFlags, GeneralConfig, TerminalConfig};
+pub use general::{
+    context_matches, ConfirmButton, ConfirmConfig, ConfirmMode, DrainConfig, ExecBackend, FeatureFlags, GeneralConfig,
+    LogBackend, SplitConfig, SplitOrientation, SplitsConfig, TerminalConfig, WarningEventsConfig, WarningEventsDisplay,
+};
 pub use keybindings::{check_collisions, validate_keybindings, KeybindingsConfig};
-pub use theme::ThemeConfig;
-pub use views::{ResourceViewConfig, ViewsConfig};
+pub use logs::LogsConfig;
+pub use plugins::{ManifestColumn, PluginConfig, PluginManifest};
+pub use theme::{StatusRules, ThemeConfig};
+pub use views::{PluginColumnConfig, ResourceViewConfig, SortOrder, ViewsConfig};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AppConfig {
     #[serde(default)]
     pub general: GeneralConfig,
@@ -26,6 +35,10 @@ pub struct AppConfig {
     pub theme: ThemeConfig,
     #[serde(default)]
     pub views: ViewsConfig,
+    #[serde(default)]
+    pub logs: LogsConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub plugins: BTreeMap<String, PluginConfig>,
 }
 
 pub const DEFAULT_CONFIG: &str = include_str!("defaults.toml");
@@ -52,9 +65,55 @@ impl AppConfig {
             }
         }
 
+        config.load_plugin_dir(&Self::plugins_dir());
         config
     }
 
+    /// Directory of plugin manifests, `<config dir>/kubetile/plugins`.
+    pub fn plugins_dir() -> PathBuf {
+        dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("plugins")
+    }
+
+    /// Adds the columns and actions of every `*.toml` manifest in `dir`, in file-name order.
+    /// Actions already defined under `[plugins]` keep their `config.toml` definition.
+    pub fn load_plugin_dir(&mut self, dir: &Path) {
+        let Ok(entries) = std::fs::read_dir(dir) else { return };
+        let mut paths: Vec<PathBuf> =
+            entries.flatten().map(|e| e.path()).filter(|p| p.extension().is_some_and(|ext| ext == "toml")).collect();
+        paths.sort();
+
+        for path in paths {
+            let manifest = match std::fs::read_to_string(&path) {
+                Ok(contents) => match toml::from_str::<PluginManifest>(&contents) {
+                    Ok(manifest) => manifest,
+                    Err(e) => {
+                        eprintln!("Warning: invalid plugin manifest at {}: {e}", path.display());
+                        continue;
+                    }
+                },
+                Err(e) => {
+                    eprintln!("Warning: could not read {}: {e}", path.display());
+                    continue;
+                }
+            };
+            self.add_plugin_manifest(manifest, &path);
+        }
+    }
+
+    fn add_plugin_manifest(&mut self, manifest: PluginManifest, source: &Path) {
+        for column in &manifest.columns {
+            for kind in &column.kinds {
+                match self.views.view_for_mut(kind) {
+                    Some(view) => view.plugin_columns.push(column.to_column()),
+                    None => eprintln!("Warning: {} adds a column to unknown kind `{kind}`", source.display()),
+                }
+            }
+        }
+        for (name, action) in manifest.actions {
+            self.plugins.entry(name).or_insert(action);
+        }
+    }
+
     pub fn load_from(path: &Path) -> anyhow::Result<Self> {
         let mut config = Self::default();
         let contents = std::fs::read_to_string(path)?;
@@ -78,14 +137,33 @@ impl AppConfig {
 
     pub fn init_default() -> anyhow::Result<PathBuf> {
         let path = Self::default_path();
+        Self::write_default(&path)?;
+        Ok(path)
+    }
+
+    /// Writes the commented default config to `path`, refusing to overwrite an existing file.
+    pub fn write_default(path: &Path) -> anyhow::Result<()> {
         if path.exists() {
             anyhow::bail!("Config already exists at {}", path.display());
         }
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        std::fs::write(&path, DEFAULT_CONFIG)?;
-        Ok(path)
+        std::fs::write(path, DEFAULT_CONFIG)?;
+        Ok(())
+    }
+
+    /// The settings in `self` whose values differ from `base`, as a TOML table. Arrays are
+    /// compared whole; tables are compared key by key.
+    pub fn diff_from(&self, base: &AppConfig) -> anyhow::Result<toml::Table> {
+        let ours = toml::Table::try_from(self)?;
+        let theirs = toml::Table::try_from(base)?;
+        Ok(diff_tables(ours, &theirs))
+    }
+
+    /// JSON schema of the config file, for editor completion and validation.
+    pub fn schema() -> schemars::Schema {
+        schemars::schema_for!(AppConfig)
     }
 
     fn user_config_path() -> Option<PathBuf> {
@@ -96,8 +174,15 @@ impl AppConfig {
         self.general = user.general;
         self.terminal = user.terminal;
         self.features = user.features;
-        self.theme = user.theme;
+        // Status rules: merge per kind so adding one kind keeps the built-in `default` rules
+        let mut theme = user.theme;
+        for (kind, rules) in std::mem::take(&mut self.theme.status_rules) {
+            theme.status_rules.entry(kind).or_insert(rules);
+        }
+        self.theme = theme;
         self.views = user.views;
+        self.logs = user.logs;
+        self.plugins.extend(user.plugins);
 
         // Keybindings: merge per-key (user overrides, defaults preserved)
         for (k, v) in user.keybindings.navigation {
@@ -142,5 +227,24 @@ impl AppConfig {
 
 pub type Config = AppConfig;
 
+fn diff_tables(ours: toml::Table, theirs: &toml::Table) -> toml::Table {
+    let mut diff = toml::Table::new();
+    for (key, value) in ours {
+        match (value, theirs.get(&key)) {
+            (toml::Value::Table(table), Some(toml::Value::Table(base))) => {
+                let nested = diff_tables(table, base);
+                if !nested.is_empty() {
+                    diff.insert(key, toml::Value::Table(nested));
+                }
+            }
+            (value, Some(base)) if &value == base => {}
+            (value, _) => {
+                diff.insert(key, value);
+            }
+        }
+    }
+    diff
+}
+
 #[cfg(test)]
 mod tests;
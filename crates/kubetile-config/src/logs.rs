@@ -0,0 +1,12 @@
+use indexmap::IndexMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct LogsConfig {
+    /// Regex patterns mapped to the style of the log lines they match, like `"ERROR|panic" =
+    /// "red bold"`. The first matching pattern wins.
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub highlights: IndexMap<String, String>,
+}
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::views::PluginColumnConfig;
+
+/// External command run on the selected resource, e.g. `stern {name} -n {namespace}`.
+///
+/// `{name}`, `{namespace}`, `{kind}` and `{context}` in `command` are replaced with shell-quoted
+/// values before it runs through `sh -c`; its output streams into a new pane.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct PluginConfig {
+    pub key: String,
+    pub command: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// Plural resource names the plugin applies to, such as `pods`; empty means all kinds.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<String>,
+}
+
+impl PluginConfig {
+    pub fn applies_to(&self, kind: &str) -> bool {
+        self.kinds.is_empty() || self.kinds.iter().any(|k| k == kind)
+    }
+}
+
+/// One file in the plugins directory, bundling plugin columns and actions so they can be shared
+/// and installed by dropping the file in place.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct PluginManifest {
+    #[serde(default)]
+    pub columns: Vec<ManifestColumn>,
+    /// Same shape as `[plugins.<name>]` in `config.toml`.
+    #[serde(default)]
+    pub actions: BTreeMap<String, PluginConfig>,
+}
+
+/// A plugin column added to each view in `kinds`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ManifestColumn {
+    pub kinds: Vec<String>,
+    pub header: String,
+    pub command: String,
+}
+
+impl ManifestColumn {
+    pub fn to_column(&self) -> PluginColumnConfig {
+        PluginColumnConfig { header: self.header.clone(), command: self.command.clone() }
+    }
+}
@@ -32,6 +32,13 @@ fn feature_flags_default_to_true() {
     assert!(config.features.port_forward);
 }
 
+#[test]
+fn volume_stats_is_opt_in() {
+    assert!(!AppConfig::default().features.volume_stats);
+    let user: AppConfig = toml::from_str("[features]\nvolume-stats = true\n").unwrap();
+    assert!(user.features.volume_stats);
+}
+
 #[test]
 fn parse_general_from_toml() {
     let raw = r#"
@@ -45,6 +52,217 @@ default_view = "deployments"
     assert_eq!(config.general.default_namespace, "default");
 }
 
+#[test]
+fn confirm_policy_defaults_to_prompt() {
+    let config = AppConfig::default();
+    assert_eq!(config.general.confirm.delete, ConfirmMode::Prompt);
+    assert_eq!(config.general.confirm_mode("restart_rollout", None), ConfirmMode::Prompt);
+}
+
+#[test]
+fn confirm_policy_parses_bools_and_type_name() {
+    let raw = r#"
+[general.confirm]
+delete = "type-name"
+scale = false
+restart = true
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.confirm_mode("delete", None), ConfirmMode::TypeName);
+    assert_eq!(config.general.confirm_mode("scale", None), ConfirmMode::Off);
+    assert_eq!(config.general.confirm_mode("restart_rollout", None), ConfirmMode::Prompt);
+    assert_eq!(config.general.confirm_mode("debug_mode", None), ConfirmMode::Prompt);
+}
+
+#[test]
+fn force_delete_requires_typed_name_by_default() {
+    assert_eq!(AppConfig::default().general.confirm_mode("force_delete", None), ConfirmMode::TypeName);
+    assert_eq!(AppConfig::default().general.confirm_mode("evict", None), ConfirmMode::Prompt);
+    assert_eq!(AppConfig::default().general.confirm_mode("drain", None), ConfirmMode::TypeName);
+
+    let raw = r#"
+[general.confirm]
+delete = false
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.confirm_mode("delete", None), ConfirmMode::Off);
+    assert_eq!(config.general.confirm_mode("force_delete", None), ConfirmMode::TypeName);
+}
+
+#[test]
+fn confirm_dialog_focuses_cancel_and_never_auto_cancels_by_default() {
+    let config = AppConfig::default();
+    assert_eq!(config.general.confirm.default_button, ConfirmButton::Cancel);
+    assert_eq!(config.general.confirm.auto_cancel_secs, 0);
+
+    let raw = r#"
+[general.confirm]
+default_button = "confirm"
+auto_cancel_secs = 10
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.confirm.default_button, ConfirmButton::Confirm);
+    assert_eq!(config.general.confirm.auto_cancel_secs, 10);
+    assert_eq!(config.general.confirm_mode("drain", None), ConfirmMode::TypeName);
+}
+
+#[test]
+fn splits_default_to_horizontal_and_parse_per_pane_type() {
+    let config = AppConfig::default();
+    assert_eq!(config.general.splits.logs, SplitConfig::default());
+
+    let raw = r#"
+[general.splits]
+logs = { direction = "vertical", ratio = 0.4 }
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.splits.logs.direction, SplitOrientation::Vertical);
+    assert_eq!(config.general.splits.logs.ratio, Some(0.4));
+    assert_eq!(config.general.splits.yaml.direction, SplitOrientation::Horizontal);
+}
+
+#[test]
+fn warning_events_filter_reasons_with_wildcards() {
+    let config = AppConfig::default();
+    assert!(!config.general.warning_events.enabled);
+    assert!(config.general.warning_events.shows_reason("BackOff"));
+
+    let raw = r#"
+[general.warning_events]
+enabled = true
+display = "toast"
+include_reasons = ["Failed*", "BackOff"]
+exclude_reasons = ["FailedMount"]
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    let events = &config.general.warning_events;
+    assert_eq!(events.display, WarningEventsDisplay::Toast);
+    assert!(events.shows_reason("FailedScheduling"));
+    assert!(events.shows_reason("BackOff"));
+    assert!(!events.shows_reason("FailedMount"));
+    assert!(!events.shows_reason("Unhealthy"));
+}
+
+#[test]
+fn confirm_policy_rejects_unknown_mode() {
+    let raw = r#"
+[general.confirm]
+delete = "maybe"
+"#;
+    assert!(toml::from_str::<AppConfig>(raw).is_err());
+}
+
+#[test]
+fn legacy_confirm_delete_false_disables_delete_prompt() {
+    let raw = r#"
+[general]
+confirm_delete = false
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.confirm_mode("delete", None), ConfirmMode::Off);
+    assert_eq!(config.general.confirm_mode("restart_rollout", None), ConfirmMode::Prompt);
+}
+
+#[test]
+fn confirm_policy_roundtrips_through_toml() {
+    let mut config = AppConfig::default();
+    config.general.confirm.delete = ConfirmMode::TypeName;
+    config.general.confirm.evict = ConfirmMode::Off;
+    let raw = toml::to_string_pretty(&config).unwrap();
+    let parsed: AppConfig = toml::from_str(&raw).unwrap();
+    assert_eq!(parsed.general.confirm.delete, ConfirmMode::TypeName);
+    assert_eq!(parsed.general.confirm.evict, ConfirmMode::Off);
+}
+
+#[test]
+fn timezone_defaults_to_local() {
+    let config = AppConfig::default();
+    assert_eq!(config.general.timezone, "local");
+    assert_eq!(config.general.timezone_for(Some("minikube")), "local");
+}
+
+#[test]
+fn context_timezone_override_matches_pattern() {
+    let raw = r#"
+[general]
+timezone = "Europe/Berlin"
+
+[general.context_timezones]
+"prod-*" = "UTC"
+"staging" = "America/New_York"
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.timezone_for(Some("prod-eu-1")), "UTC");
+    assert_eq!(config.general.timezone_for(Some("staging")), "America/New_York");
+    assert_eq!(config.general.timezone_for(Some("staging-2")), "Europe/Berlin");
+    assert_eq!(config.general.timezone_for(None), "Europe/Berlin");
+}
+
+#[test]
+fn context_confirm_overrides_actions_per_context() {
+    let raw = r#"
+[general]
+confirm_delete = false
+
+[general.confirm]
+restart = false
+
+[general.context_confirm."prod-*"]
+delete = "type-name"
+set-image = "type-name"
+
+[general.context_confirm."*"]
+restart = true
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    let general = &config.general;
+    assert_eq!(general.confirm_mode("delete", Some("prod-eu-1")), ConfirmMode::TypeName);
+    assert_eq!(general.confirm_mode("set_image", Some("prod-eu-1")), ConfirmMode::TypeName);
+    assert_eq!(general.confirm_mode("restart", Some("prod-eu-1")), ConfirmMode::Prompt);
+    assert_eq!(general.confirm_mode("evict", Some("prod-eu-1")), ConfirmMode::Prompt);
+    assert_eq!(general.confirm_mode("delete", Some("minikube")), ConfirmMode::Off);
+    assert_eq!(general.confirm_mode("restart", None), ConfirmMode::Off);
+    assert_eq!(general.confirm.action_mode("set_image"), Some(ConfirmMode::Prompt));
+    assert_eq!(general.confirm.action_mode("restrat"), None);
+}
+
+#[test]
+fn context_ssh_host_matches_pattern() {
+    let raw = r#"
+[general.context_ssh_hosts]
+"prod-*" = "ops@bastion.internal"
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert_eq!(config.general.ssh_host_for(Some("prod-eu-1")), Some("ops@bastion.internal"));
+    assert_eq!(config.general.ssh_host_for(Some("minikube")), None);
+    assert_eq!(config.general.ssh_host_for(None), None);
+}
+
+#[test]
+fn read_only_contexts_match_patterns() {
+    let raw = r#"
+[general]
+read_only_contexts = ["prod-*", "admin@live"]
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    assert!(config.general.is_read_only(Some("prod-eu")));
+    assert!(config.general.is_read_only(Some("admin@live")));
+    assert!(!config.general.is_read_only(Some("staging")));
+    assert!(!config.general.is_read_only(None));
+    assert!(!AppConfig::default().general.is_read_only(Some("prod-eu")));
+}
+
+#[test]
+fn context_matches_wildcards() {
+    assert!(context_matches("prod-*", "prod-eu"));
+    assert!(context_matches("*-prod", "eu-prod"));
+    assert!(context_matches("gke_*_prod*", "gke_acme_prod-1"));
+    assert!(context_matches("*", "anything"));
+    assert!(context_matches("kind", "kind"));
+    assert!(!context_matches("prod-*", "staging-prod"));
+    assert!(!context_matches("a*a", "a"));
+}
+
 #[test]
 fn partial_toml_only_general_merges_with_defaults() {
     let mut base = AppConfig::default();
@@ -228,3 +446,180 @@ fn columns_for_returns_correct_resource() {
     assert_eq!(views.columns_for("nodes"), &["name", "status", "roles", "age", "version"]);
     assert!(views.columns_for("unknown").is_empty());
 }
+
+#[test]
+fn plugin_columns_parse_from_toml() {
+    let raw = r#"
+[views.pods]
+columns = ["name", "status"]
+
+[[views.pods.plugin_columns]]
+header = "TEAM"
+command = "team-lookup $KUBETILE_NAMESPACE"
+"#;
+    let config: AppConfig = toml::from_str(raw).unwrap();
+    let plugin = config.views.plugin_columns_for("pods");
+    assert_eq!(plugin.len(), 1);
+    assert_eq!(plugin[0].header, "TEAM");
+    assert!(config.views.plugin_columns_for("deployments").is_empty());
+    assert!(config.views.plugin_columns_for("unknown").is_empty());
+}
+
+#[test]
+fn default_status_rules_match_theme_default() {
+    let config = AppConfig::default();
+    assert_eq!(config.theme.status_rules, ThemeConfig::default().status_rules);
+    assert!(config.theme.status_rules["default"].error.contains(&"CrashLoopBackOff".to_string()));
+}
+
+#[test]
+fn user_status_rules_merge_per_kind() {
+    let mut base = AppConfig::default();
+    let user_toml = r#"
+[theme.status_rules.certificates]
+ok = ["True"]
+error = ["False"]
+"#;
+    let user: AppConfig = toml::from_str(user_toml).unwrap();
+    base.merge(user);
+
+    assert_eq!(base.theme.status_rules["certificates"].ok, vec!["True".to_string()]);
+    assert!(base.theme.status_rules["default"].ok.contains(&"Running".to_string()));
+}
+
+#[test]
+fn log_highlights_keep_their_order() {
+    let config: AppConfig = toml::from_str(
+        r#"
+[logs.highlights]
+"ERROR|panic" = "red bold"
+"WARN" = "yellow"
+"#,
+    )
+    .unwrap();
+    let patterns: Vec<&str> = config.logs.highlights.keys().map(String::as_str).collect();
+    assert_eq!(patterns, ["ERROR|panic", "WARN"]);
+    assert!(AppConfig::default().logs.highlights.is_empty());
+}
+
+#[test]
+fn plugins_parse_from_toml() {
+    let raw = r#"
+[plugins.stern]
+key = "ctrl+alt+l"
+command = "stern {name} -n {namespace} --context {context}"
+kinds = ["pods", "deployments"]
+
+[plugins.neat]
+key = "ctrl+alt+n"
+command = "kubectl get {kind} {name} -n {namespace} -o yaml | kubectl neat"
+description = "Clean YAML"
+"#;
+    let mut config = AppConfig::default();
+    assert!(config.plugins.is_empty());
+    config.merge(toml::from_str(raw).unwrap());
+
+    let names: Vec<&str> = config.plugins.keys().map(String::as_str).collect();
+    assert_eq!(names, vec!["neat", "stern"]);
+    let stern = &config.plugins["stern"];
+    assert_eq!(stern.key, "ctrl+alt+l");
+    assert!(stern.description.is_empty());
+    assert!(stern.applies_to("pods"));
+    assert!(!stern.applies_to("services"));
+    assert!(config.plugins["neat"].applies_to("services"));
+}
+
+#[test]
+fn plugin_dir_manifests_add_columns_and_actions() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = tmp.path();
+    std::fs::write(
+        dir.join("team.toml"),
+        r#"
+[[columns]]
+kinds = ["pods", "deployments", "widgets"]
+header = "TEAM"
+command = "team-lookup $KUBETILE_NAME"
+
+[actions.stern]
+key = "ctrl+alt+l"
+command = "stern {name}"
+
+[actions.owner]
+key = "ctrl+alt+o"
+command = "owner {kind} {name}"
+"#,
+    )
+    .unwrap();
+    std::fs::write(dir.join("broken.toml"), "columns = 3").unwrap();
+    std::fs::write(dir.join("README.md"), "not a manifest").unwrap();
+
+    let mut config = AppConfig::default();
+    config.plugins.insert(
+        "stern".into(),
+        PluginConfig { key: "f5".into(), command: "stern -n {namespace} {name}".into(), ..Default::default() },
+    );
+    config.load_plugin_dir(dir);
+
+    assert_eq!(config.views.plugin_columns_for("pods")[0].header, "TEAM");
+    assert_eq!(config.views.plugin_columns_for("deployments").len(), 1);
+    assert!(config.views.plugin_columns_for("services").is_empty());
+    assert_eq!(config.plugins["stern"].key, "f5");
+    assert_eq!(config.plugins["owner"].command, "owner {kind} {name}");
+}
+
+#[test]
+fn diff_from_defaults_keeps_only_changed_values() {
+    let mut config = AppConfig::default();
+    assert!(config.diff_from(&AppConfig::default()).unwrap().is_empty());
+
+    config.general.tick_rate_ms = 500;
+    config.keybindings.global.insert("quit".into(), "ctrl+x".into());
+    config.views.pods.columns = vec!["name".into()];
+
+    let diff = toml::to_string(&config.diff_from(&AppConfig::default()).unwrap()).unwrap();
+    let parsed: toml::Table = toml::from_str(&diff).unwrap();
+    let expected: toml::Table = toml::from_str(
+        r#"
+[general]
+tick_rate_ms = 500
+
+[keybindings.global]
+quit = "ctrl+x"
+
+[views.pods]
+columns = ["name"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, expected);
+}
+
+#[test]
+fn schema_describes_every_section() {
+    let schema = AppConfig::schema();
+    let properties = schema.get("properties").and_then(|p| p.as_object()).unwrap();
+    for section in ["general", "keybindings", "terminal", "features", "theme", "views", "logs", "plugins"] {
+        assert!(properties.contains_key(section), "missing {section}");
+    }
+}
+
+#[test]
+fn view_sort_parses_from_toml() {
+    let config: AppConfig = toml::from_str(
+        r#"
+[views.pods]
+columns = ["name", "age"]
+sort_by = "age"
+order = "desc"
+
+[views.nodes]
+columns = ["name"]
+sort-by = "name"
+"#,
+    )
+    .unwrap();
+    assert_eq!(config.views.sort_for("pods"), Some(("age", SortOrder::Desc)));
+    assert_eq!(config.views.sort_for("nodes"), Some(("name", SortOrder::Asc)));
+    assert_eq!(config.views.sort_for("services"), None);
+}
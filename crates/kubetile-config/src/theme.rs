@@ -1,6 +1,19 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// STATUS column keywords per severity, matched case-insensitively against the whole value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(default)]
+pub struct StatusRules {
+    pub ok: Vec<String>,
+    pub warn: Vec<String>,
+    pub error: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
 pub struct ThemeConfig {
     pub accent: String,
@@ -46,6 +59,16 @@ pub struct ThemeConfig {
     pub insert_mode_bg: String,
     #[serde(alias = "insert-mode-fg")]
     pub insert_mode_fg: String,
+
+    /// Keyed by resource kind (e.g. `pods`, or a custom resource name); `default` applies to every
+    /// kind and is consulted after the kind's own rules.
+    #[serde(alias = "status-rules")]
+    pub status_rules: HashMap<String, StatusRules>,
+    /// Accent colors keyed by context name pattern (`*` wildcard). The first match recolors the
+    /// active tab, the focused pane's border and the status bar, so production never looks like
+    /// staging.
+    #[serde(alias = "context-accents")]
+    pub context_accents: IndexMap<String, String>,
 }
 
 impl Default for ThemeConfig {
@@ -73,6 +96,45 @@ impl Default for ThemeConfig {
             yaml_null: "#585b70".into(),
             insert_mode_bg: "#a6e3a1".into(),
             insert_mode_fg: "#1e1e2e".into(),
+            status_rules: HashMap::from([(
+                "default".into(),
+                StatusRules {
+                    ok: vec!["Running".into(), "Succeeded".into()],
+                    warn: vec!["Pending".into(), "ContainerCreating".into()],
+                    error: vec!["Failed".into(), "Error".into(), "CrashLoopBackOff".into(), "ImagePullBackOff".into()],
+                },
+            )]),
+            context_accents: IndexMap::new(),
         }
     }
 }
+
+impl ThemeConfig {
+    /// Every color setting with its config key, for validation.
+    pub fn colors(&self) -> [(&'static str, &str); 22] {
+        [
+            ("accent", &self.accent),
+            ("bg", &self.bg),
+            ("fg", &self.fg),
+            ("header_bg", &self.header_bg),
+            ("header_fg", &self.header_fg),
+            ("selection_bg", &self.selection_bg),
+            ("selection_fg", &self.selection_fg),
+            ("border", &self.border),
+            ("border_active", &self.border_active),
+            ("text_dim", &self.text_dim),
+            ("overlay_bg", &self.overlay_bg),
+            ("status_running", &self.status_running),
+            ("status_pending", &self.status_pending),
+            ("status_failed", &self.status_failed),
+            ("status_unknown", &self.status_unknown),
+            ("yaml_key", &self.yaml_key),
+            ("yaml_string", &self.yaml_string),
+            ("yaml_number", &self.yaml_number),
+            ("yaml_boolean", &self.yaml_boolean),
+            ("yaml_null", &self.yaml_null),
+            ("insert_mode_bg", &self.insert_mode_bg),
+            ("insert_mode_fg", &self.insert_mode_fg),
+        ]
+    }
+}
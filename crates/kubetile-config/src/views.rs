@@ -1,11 +1,51 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ResourceViewConfig {
     pub columns: Vec<String>,
+    #[serde(default, alias = "plugin-columns", skip_serializing_if = "Vec::is_empty")]
+    pub plugin_columns: Vec<PluginColumnConfig>,
+    /// Column the list is sorted by when it first loads; unset keeps API order.
+    #[serde(default, alias = "sort-by", skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+    #[serde(default, skip_serializing_if = "SortOrder::is_asc")]
+    pub order: SortOrder,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn is_asc(&self) -> bool {
+        *self == SortOrder::Asc
+    }
+}
+
+impl ResourceViewConfig {
+    fn with_columns(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            plugin_columns: Vec::new(),
+            sort_by: None,
+            order: SortOrder::Asc,
+        }
+    }
+}
+
+/// Extra column filled by running `command` once per row; the first line of stdout becomes the cell value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct PluginColumnConfig {
+    pub header: String,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(default)]
 pub struct ViewsConfig {
     pub pods: ResourceViewConfig,
@@ -25,75 +65,71 @@ pub struct ViewsConfig {
 impl Default for ViewsConfig {
     fn default() -> Self {
         Self {
-            pods: ResourceViewConfig {
-                columns: vec!["name", "ready", "status", "restarts", "age", "node"]
-                    .into_iter()
-                    .map(Into::into)
-                    .collect(),
-            },
-            deployments: ResourceViewConfig {
-                columns: vec!["name", "ready", "up-to-date", "available", "age"].into_iter().map(Into::into).collect(),
-            },
-            services: ResourceViewConfig {
-                columns: vec!["name", "type", "cluster-ip", "external-ip", "ports", "age"]
-                    .into_iter()
-                    .map(Into::into)
-                    .collect(),
-            },
-            statefulsets: ResourceViewConfig {
-                columns: vec!["name", "ready", "age"].into_iter().map(Into::into).collect(),
-            },
-            daemonsets: ResourceViewConfig {
-                columns: vec!["name", "desired", "current", "ready", "age"].into_iter().map(Into::into).collect(),
-            },
-            jobs: ResourceViewConfig {
-                columns: vec!["name", "completions", "duration", "age"].into_iter().map(Into::into).collect(),
-            },
-            cronjobs: ResourceViewConfig {
-                columns: vec!["name", "schedule", "suspend", "active", "last-schedule", "age"]
-                    .into_iter()
-                    .map(Into::into)
-                    .collect(),
-            },
-            configmaps: ResourceViewConfig {
-                columns: vec!["name", "data", "age"].into_iter().map(Into::into).collect(),
-            },
-            secrets: ResourceViewConfig {
-                columns: vec!["name", "type", "data", "age"].into_iter().map(Into::into).collect(),
-            },
-            ingresses: ResourceViewConfig {
-                columns: vec!["name", "class", "hosts", "address", "ports", "age"]
-                    .into_iter()
-                    .map(Into::into)
-                    .collect(),
-            },
-            nodes: ResourceViewConfig {
-                columns: vec!["name", "status", "roles", "age", "version"].into_iter().map(Into::into).collect(),
-            },
-            namespaces: ResourceViewConfig {
-                columns: vec!["name", "status", "age"].into_iter().map(Into::into).collect(),
-            },
+            pods: ResourceViewConfig::with_columns(&["name", "ready", "status", "restarts", "age", "node"]),
+            deployments: ResourceViewConfig::with_columns(&["name", "ready", "up-to-date", "available", "age"]),
+            services: ResourceViewConfig::with_columns(&["name", "type", "cluster-ip", "external-ip", "ports", "age"]),
+            statefulsets: ResourceViewConfig::with_columns(&["name", "ready", "age"]),
+            daemonsets: ResourceViewConfig::with_columns(&["name", "desired", "current", "ready", "age"]),
+            jobs: ResourceViewConfig::with_columns(&["name", "completions", "duration", "age"]),
+            cronjobs: ResourceViewConfig::with_columns(&[
+                "name",
+                "schedule",
+                "suspend",
+                "active",
+                "last-schedule",
+                "age",
+            ]),
+            configmaps: ResourceViewConfig::with_columns(&["name", "data", "age"]),
+            secrets: ResourceViewConfig::with_columns(&["name", "type", "data", "age"]),
+            ingresses: ResourceViewConfig::with_columns(&["name", "class", "hosts", "address", "ports", "age"]),
+            nodes: ResourceViewConfig::with_columns(&["name", "status", "roles", "age", "version"]),
+            namespaces: ResourceViewConfig::with_columns(&["name", "status", "age"]),
         }
     }
 }
 
+/// The view named `$kind`, borrowed with `$borrow` (`&` or `&mut`).
+macro_rules! view_field {
+    ($views:ident, $kind:expr, $($borrow:tt)+) => {
+        match $kind {
+            "pods" => Some($($borrow)+ $views.pods),
+            "deployments" => Some($($borrow)+ $views.deployments),
+            "services" => Some($($borrow)+ $views.services),
+            "statefulsets" => Some($($borrow)+ $views.statefulsets),
+            "daemonsets" => Some($($borrow)+ $views.daemonsets),
+            "jobs" => Some($($borrow)+ $views.jobs),
+            "cronjobs" => Some($($borrow)+ $views.cronjobs),
+            "configmaps" => Some($($borrow)+ $views.configmaps),
+            "secrets" => Some($($borrow)+ $views.secrets),
+            "ingresses" => Some($($borrow)+ $views.ingresses),
+            "nodes" => Some($($borrow)+ $views.nodes),
+            "namespaces" => Some($($borrow)+ $views.namespaces),
+            _ => None,
+        }
+    };
+}
+
 impl ViewsConfig {
+    pub fn view_for(&self, resource_kind: &str) -> Option<&ResourceViewConfig> {
+        view_field!(self, resource_kind, &)
+    }
+
+    pub fn view_for_mut(&mut self, resource_kind: &str) -> Option<&mut ResourceViewConfig> {
+        view_field!(self, resource_kind, &mut)
+    }
+
     pub fn columns_for(&self, resource_kind: &str) -> &[String] {
-        match resource_kind {
-            "pods" => &self.pods.columns,
-            "deployments" => &self.deployments.columns,
-            "services" => &self.services.columns,
-            "statefulsets" => &self.statefulsets.columns,
-            "daemonsets" => &self.daemonsets.columns,
-            "jobs" => &self.jobs.columns,
-            "cronjobs" => &self.cronjobs.columns,
-            "configmaps" => &self.configmaps.columns,
-            "secrets" => &self.secrets.columns,
-            "ingresses" => &self.ingresses.columns,
-            "nodes" => &self.nodes.columns,
-            "namespaces" => &self.namespaces.columns,
-            _ => &[],
-        }
+        self.view_for(resource_kind).map_or(&[], |v| &v.columns)
+    }
+
+    pub fn plugin_columns_for(&self, resource_kind: &str) -> &[PluginColumnConfig] {
+        self.view_for(resource_kind).map_or(&[], |v| &v.plugin_columns)
+    }
+
+    /// Initial sort column and order for a kind, if one is configured.
+    pub fn sort_for(&self, resource_kind: &str) -> Option<(&str, SortOrder)> {
+        let view = self.view_for(resource_kind)?;
+        Some((view.sort_by.as_deref()?, view.order))
     }
 }
 
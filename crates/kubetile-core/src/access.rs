@@ -0,0 +1,142 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use k8s_openapi::api::authorization::v1::{ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec};
+use kube::api::{Api, PostParams};
+use kube::Client;
+
+/// A `kubectl auth can-i` style question about the current user.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccessCheck {
+    pub verb: String,
+    pub group: String,
+    pub resource: String,
+    pub subresource: String,
+    /// `None` asks about all namespaces (or a cluster-scoped resource).
+    pub namespace: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessDecision {
+    pub allowed: bool,
+    pub reason: String,
+}
+
+impl AccessCheck {
+    pub fn new(verb: &str, group: &str, resource: &str, namespace: Option<&str>) -> Self {
+        Self {
+            verb: verb.into(),
+            group: group.into(),
+            resource: resource.into(),
+            subresource: String::new(),
+            namespace: namespace.filter(|ns| !ns.is_empty()).map(str::to_string),
+        }
+    }
+
+    pub fn with_subresource(mut self, subresource: &str) -> Self {
+        self.subresource = subresource.into();
+        self
+    }
+
+    /// Parses `VERB RESOURCE[.GROUP][/SUBRESOURCE] [-n NAMESPACE | -A]`, as accepted by
+    /// `kubectl auth can-i`. Without a namespace flag the check targets `default_namespace`.
+    pub fn parse(input: &str, default_namespace: &str) -> Result<Self> {
+        let mut tokens = input.split_whitespace();
+        let verb = tokens.next().ok_or_else(|| anyhow!("expected: VERB RESOURCE [-n NAMESPACE | -A]"))?;
+        let target = tokens.next().ok_or_else(|| anyhow!("missing resource after '{verb}'"))?;
+
+        let mut namespace = Some(default_namespace.to_string());
+        while let Some(flag) = tokens.next() {
+            match flag {
+                "-A" | "--all-namespaces" => namespace = None,
+                "-n" | "--namespace" => {
+                    let ns = tokens.next().ok_or_else(|| anyhow!("{flag} needs a namespace"))?;
+                    namespace = Some(ns.to_string());
+                }
+                _ => match flag.strip_prefix("--namespace=") {
+                    Some(ns) => namespace = Some(ns.to_string()),
+                    None => return Err(anyhow!("unexpected argument '{flag}'")),
+                },
+            }
+        }
+
+        let (resource, subresource) = target.split_once('/').unwrap_or((target, ""));
+        let (resource, group) = resource.split_once('.').unwrap_or((resource, ""));
+        Ok(Self::new(verb, group, resource, namespace.as_deref()).with_subresource(subresource))
+    }
+
+    /// Asks the API server whether the current user may perform this check.
+    pub async fn review(&self, client: Client) -> Result<AccessDecision> {
+        let review = SelfSubjectAccessReview {
+            spec: SelfSubjectAccessReviewSpec {
+                resource_attributes: Some(ResourceAttributes {
+                    verb: Some(self.verb.clone()),
+                    group: Some(self.group.clone()),
+                    resource: Some(self.resource.clone()),
+                    subresource: (!self.subresource.is_empty()).then(|| self.subresource.clone()),
+                    namespace: self.namespace.clone(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let api: Api<SelfSubjectAccessReview> = Api::all(client);
+        let status = api.create(&PostParams::default(), &review).await?.status.unwrap_or_default();
+        let reason = status.reason.or(status.evaluation_error).unwrap_or_default();
+        Ok(AccessDecision { allowed: status.allowed && !status.denied.unwrap_or(false), reason })
+    }
+}
+
+impl fmt::Display for AccessCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.verb, self.resource)?;
+        if !self.group.is_empty() {
+            write!(f, ".{}", self.group)?;
+        }
+        if !self.subresource.is_empty() {
+            write!(f, "/{}", self.subresource)?;
+        }
+        match &self.namespace {
+            Some(ns) => write!(f, " -n {ns}"),
+            None => write!(f, " -A"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_uses_default_namespace() {
+        let check = AccessCheck::parse("delete pods", "web").unwrap();
+        assert_eq!(check, AccessCheck::new("delete", "", "pods", Some("web")));
+    }
+
+    #[test]
+    fn parse_group_subresource_and_namespace() {
+        let check = AccessCheck::parse("patch deployments.apps/scale -n prod", "default").unwrap();
+        assert_eq!(check.group, "apps");
+        assert_eq!(check.resource, "deployments");
+        assert_eq!(check.subresource, "scale");
+        assert_eq!(check.namespace.as_deref(), Some("prod"));
+        assert_eq!(check.to_string(), "patch deployments.apps/scale -n prod");
+    }
+
+    #[test]
+    fn parse_all_namespaces() {
+        let check = AccessCheck::parse("list secrets --all-namespaces", "default").unwrap();
+        assert_eq!(check.namespace, None);
+        assert_eq!(check.to_string(), "list secrets -A");
+    }
+
+    #[test]
+    fn parse_rejects_incomplete_input() {
+        assert!(AccessCheck::parse("", "default").is_err());
+        assert!(AccessCheck::parse("delete", "default").is_err());
+        assert!(AccessCheck::parse("delete pods -n", "default").is_err());
+        assert!(AccessCheck::parse("delete pods extra", "default").is_err());
+    }
+}
@@ -1,14 +1,22 @@
 use std::fmt::Debug;
 
 use anyhow::Result;
-use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet, StatefulSet};
-use k8s_openapi::api::core::v1::{Container, Event};
+use jiff::tz::TimeZone;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::core::v1::{Container, Event, Node, Pod};
 use k8s_openapi::NamespaceResourceScope;
-use kube::api::{Api, DeleteParams, ListParams, Patch, PatchParams};
+use kube::api::{Api, DeleteParams, EvictParams, ListParams, Patch, PatchParams};
 use kube::{Client, Resource};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use crate::audit;
+use crate::fetch_cache::{FetchCache, FetchKey, FetchView, Fetched};
+use crate::images::ContainerImage;
+use crate::manifests::clean_yaml;
+use crate::namespace_overview::last_seen;
+use crate::resource::format_time;
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ResourceKind {
     Pods,
@@ -25,6 +33,10 @@ pub enum ResourceKind {
     Namespaces,
     PersistentVolumes,
     PersistentVolumeClaims,
+    NetworkPolicies,
+    ResourceQuotas,
+    LimitRanges,
+    PodDisruptionBudgets,
     Custom(String),
 }
 
@@ -45,6 +57,10 @@ impl ResourceKind {
             Self::Namespaces => "ns",
             Self::PersistentVolumes => "pv",
             Self::PersistentVolumeClaims => "pvc",
+            Self::NetworkPolicies => "netpol",
+            Self::ResourceQuotas => "quota",
+            Self::LimitRanges => "limits",
+            Self::PodDisruptionBudgets => "pdb",
             Self::Custom(s) => s.as_str(),
         }
     }
@@ -65,6 +81,10 @@ impl ResourceKind {
             Self::Namespaces => "Namespaces",
             Self::PersistentVolumes => "PersistentVolumes",
             Self::PersistentVolumeClaims => "PersistentVolumeClaims",
+            Self::NetworkPolicies => "NetworkPolicies",
+            Self::ResourceQuotas => "ResourceQuotas",
+            Self::LimitRanges => "LimitRanges",
+            Self::PodDisruptionBudgets => "PodDisruptionBudgets",
             Self::Custom(s) => s.as_str(),
         }
     }
@@ -73,12 +93,17 @@ impl ResourceKind {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ResourceAction {
     Delete,
+    /// Pod eviction through the policy/v1 Eviction API, which honours PodDisruptionBudgets.
+    Evict,
+    /// Pod delete with a zero grace period.
+    ForceDelete,
     ViewYaml,
     Describe,
     ViewLogs,
     Exec,
     Scale(i32),
     RestartRollout,
+    SetImage,
 }
 
 impl ResourceAction {
@@ -86,15 +111,19 @@ impl ResourceAction {
         let mut actions = vec![ResourceAction::Delete, ResourceAction::ViewYaml, ResourceAction::Describe];
         match kind {
             ResourceKind::Pods => {
+                actions.push(ResourceAction::Evict);
+                actions.push(ResourceAction::ForceDelete);
                 actions.push(ResourceAction::ViewLogs);
                 actions.push(ResourceAction::Exec);
             }
             ResourceKind::Deployments => {
                 actions.push(ResourceAction::Scale(0));
                 actions.push(ResourceAction::RestartRollout);
+                actions.push(ResourceAction::SetImage);
             }
             ResourceKind::StatefulSets => {
                 actions.push(ResourceAction::Scale(0));
+                actions.push(ResourceAction::SetImage);
             }
             _ => {}
         }
@@ -102,33 +131,104 @@ impl ResourceAction {
     }
 }
 
+/// Pod template patch setting each container's `image`, init containers in their own list.
+fn set_image_patch(images: &[ContainerImage]) -> serde_json::Value {
+    let entries = |init: bool| -> Vec<serde_json::Value> {
+        images
+            .iter()
+            .filter(|i| i.init == init)
+            .map(|i| serde_json::json!({ "name": i.container, "image": i.image }))
+            .collect()
+    };
+    let mut spec = serde_json::Map::new();
+    for (field, init) in [("containers", false), ("initContainers", true)] {
+        let list = entries(init);
+        if !list.is_empty() {
+            spec.insert(field.into(), list.into());
+        }
+    }
+    serde_json::json!({ "spec": { "template": { "spec": spec } } })
+}
+
+/// Records a mutation's outcome in the audit log and converts it for the caller.
+fn audited<T>(verb: &str, resource: &str, name: &str, ns: &str, result: kube::Result<T>) -> Result<()> {
+    let result = result.map(|_| ()).map_err(anyhow::Error::from);
+    audit::record(verb, resource, name, ns, &result);
+    result
+}
+
+/// The object's manifest cleaned of server fields, read just before deleting it; `None` when it
+/// cannot be read.
+async fn manifest_before_delete<K>(api: &Api<K>, name: &str) -> Option<String>
+where
+    K: Clone + DeserializeOwned + Serialize + Debug,
+{
+    let object = api.get(name).await.ok()?;
+    serde_yaml::to_string(&object).ok().and_then(|yaml| clean_yaml(&yaml).ok())
+}
+
 pub struct ActionExecutor {
     client: Client,
+    fetch_cache: FetchCache,
 }
 
 impl ActionExecutor {
     pub fn new(client: Client) -> Self {
-        Self { client }
+        Self { client, fetch_cache: FetchCache::default() }
+    }
+
+    /// Serves YAML and describe output through `cache`.
+    pub fn with_fetch_cache(mut self, cache: FetchCache) -> Self {
+        self.fetch_cache = cache;
+        self
     }
 
-    pub async fn delete<K>(&self, name: &str, ns: &str) -> Result<()>
+    /// Deletes the object and hands back its manifest as it was before, cleaned of server fields,
+    /// so the delete can be undone. The manifest is `None` when the object could not be read first.
+    pub async fn delete<K>(&self, name: &str, ns: &str) -> Result<Option<String>>
     where
-        K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+        K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Serialize + Debug,
     {
         let api: Api<K> = Api::namespaced(self.client.clone(), ns);
+        let manifest = manifest_before_delete(&api, name).await;
         let dp = DeleteParams::default();
-        api.delete(name, &dp).await?;
-        Ok(())
+        audited("delete", &K::plural(&()), name, ns, api.delete(name, &dp).await)?;
+        Ok(manifest)
     }
 
-    pub async fn delete_cluster<K>(&self, name: &str) -> Result<()>
+    /// Deletes a cluster-scoped object, handing back its manifest as [`ActionExecutor::delete`] does.
+    pub async fn delete_cluster<K>(&self, name: &str) -> Result<Option<String>>
     where
-        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug,
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Serialize + Debug,
     {
         let api: Api<K> = Api::all(self.client.clone());
+        let manifest = manifest_before_delete(&api, name).await;
         let dp = DeleteParams::default();
-        api.delete(name, &dp).await?;
-        Ok(())
+        audited("delete", &K::plural(&()), name, "", api.delete(name, &dp).await)?;
+        Ok(manifest)
+    }
+
+    /// Evicts a pod; the API server refuses with 429 when a PodDisruptionBudget would be violated.
+    pub async fn evict_pod(&self, name: &str, ns: &str) -> Result<()> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), ns);
+        audited("evict", "pods", name, ns, api.evict(name, &EvictParams::default()).await)
+    }
+
+    /// Marks a node unschedulable so nothing new lands on it while it is drained.
+    pub async fn cordon_node(&self, name: &str) -> Result<()> {
+        let api: Api<Node> = Api::all(self.client.clone());
+        let patch = serde_json::json!({ "spec": { "unschedulable": true } });
+        audited("cordon", "nodes", name, "", api.patch(name, &PatchParams::default(), &Patch::Merge(&patch)).await)
+    }
+
+    /// Deletes a pod immediately, without waiting for graceful termination, handing back its
+    /// manifest as [`ActionExecutor::delete`] does.
+    pub async fn force_delete_pod(&self, name: &str, ns: &str) -> Result<Option<String>> {
+        let api: Api<Pod> = Api::namespaced(self.client.clone(), ns);
+        let manifest = manifest_before_delete(&api, name).await;
+        let dp = DeleteParams { grace_period_seconds: Some(0), ..DeleteParams::default() };
+        audited("force-delete", "pods", name, ns, api.delete(name, &dp).await)?;
+        Ok(manifest)
     }
 
     pub async fn scale(&self, kind: &ResourceKind, name: &str, ns: &str, replicas: i32) -> Result<()> {
@@ -137,18 +237,18 @@ impl ActionExecutor {
         });
         let pp = PatchParams::apply("kubetile");
 
+        let verb = format!("scale to {replicas}");
         match kind {
             ResourceKind::Deployments => {
                 let api: Api<Deployment> = Api::namespaced(self.client.clone(), ns);
-                api.patch(name, &pp, &Patch::Merge(&patch)).await?;
+                audited(&verb, "deployments", name, ns, api.patch(name, &pp, &Patch::Merge(&patch)).await)
             }
             ResourceKind::StatefulSets => {
                 let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), ns);
-                api.patch(name, &pp, &Patch::Merge(&patch)).await?;
+                audited(&verb, "statefulsets", name, ns, api.patch(name, &pp, &Patch::Merge(&patch)).await)
             }
             _ => anyhow::bail!("Scale not supported for {:?}", kind),
         }
-        Ok(())
     }
 
     pub async fn resolve_owner_deployment(&self, pod_name: &str, ns: &str) -> Result<String> {
@@ -239,8 +339,8 @@ impl ActionExecutor {
             }
         });
 
-        api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await?;
-        Ok(())
+        let result = api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await;
+        audited("debug on", "deployments", name, ns, result)
     }
 
     pub async fn exit_debug_mode(&self, name: &str, ns: &str) -> Result<()> {
@@ -282,8 +382,8 @@ impl ActionExecutor {
             }
         });
 
-        api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await?;
-        Ok(())
+        let result = api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await;
+        audited("debug off", "deployments", name, ns, result)
     }
 
     pub async fn is_in_debug_mode(&self, name: &str, ns: &str) -> Result<bool> {
@@ -343,8 +443,8 @@ impl ActionExecutor {
             }
         });
 
-        api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await?;
-        Ok(())
+        let result = api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await;
+        audited("root debug on", "deployments", name, ns, result)
     }
 
     pub async fn exit_root_debug_mode(&self, name: &str, ns: &str) -> Result<()> {
@@ -393,8 +493,8 @@ impl ActionExecutor {
             }
         });
 
-        api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await?;
-        Ok(())
+        let result = api.patch(name, &PatchParams::default(), &Patch::Strategic(&patch)).await;
+        audited("root debug off", "deployments", name, ns, result)
     }
 
     pub async fn is_in_root_debug_mode(&self, name: &str, ns: &str) -> Result<bool> {
@@ -419,31 +519,71 @@ impl ActionExecutor {
         });
         let pp = PatchParams::apply("kubetile");
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), ns);
-        api.patch(name, &pp, &Patch::Merge(&patch)).await?;
-        Ok(())
+        audited("rollout restart", "deployments", name, ns, api.patch(name, &pp, &Patch::Merge(&patch)).await)
+    }
+
+    /// Points containers of a Deployment, StatefulSet or DaemonSet at new images with a strategic merge
+    /// patch, which merges the container lists by name and leaves other containers alone.
+    pub async fn set_images(&self, kind: &ResourceKind, name: &str, ns: &str, images: &[ContainerImage]) -> Result<()> {
+        let patch = set_image_patch(images);
+        let pp = PatchParams::apply("kubetile");
+        match kind {
+            ResourceKind::Deployments => {
+                let api: Api<Deployment> = Api::namespaced(self.client.clone(), ns);
+                audited("set image", "deployments", name, ns, api.patch(name, &pp, &Patch::Strategic(&patch)).await)
+            }
+            ResourceKind::StatefulSets => {
+                let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), ns);
+                audited("set image", "statefulsets", name, ns, api.patch(name, &pp, &Patch::Strategic(&patch)).await)
+            }
+            ResourceKind::DaemonSets => {
+                let api: Api<DaemonSet> = Api::namespaced(self.client.clone(), ns);
+                audited("set image", "daemonsets", name, ns, api.patch(name, &pp, &Patch::Strategic(&patch)).await)
+            }
+            _ => anyhow::bail!("Set image not supported for {:?}", kind),
+        }
     }
 
     pub async fn get_yaml<K>(&self, name: &str, ns: &str) -> Result<String>
     where
         K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Serialize + Debug,
     {
-        let api: Api<K> = Api::namespaced(self.client.clone(), ns);
-        let obj = api.get(name).await?;
-        let yaml = serde_yaml::to_string(&obj)?;
-        Ok(yaml)
+        self.cached_yaml::<K>(Api::namespaced(self.client.clone(), ns), name, ns).await
     }
 
     pub async fn get_yaml_cluster<K>(&self, name: &str) -> Result<String>
     where
         K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Serialize + Debug,
     {
-        let api: Api<K> = Api::all(self.client.clone());
-        let obj = api.get(name).await?;
-        let yaml = serde_yaml::to_string(&obj)?;
-        Ok(yaml)
+        self.cached_yaml::<K>(Api::all(self.client.clone()), name, "").await
     }
 
-    pub async fn describe<K>(&self, name: &str, ns: &str) -> Result<String>
+    async fn cached_yaml<K>(&self, api: Api<K>, name: &str, ns: &str) -> Result<String>
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Serialize + Debug,
+    {
+        let key = FetchKey::of::<K>(ns, name, FetchView::Yaml);
+        let current_version = async { Ok(api.get_metadata(name).await?.metadata.resource_version) };
+        let fetch = async {
+            let obj = api.get(name).await?;
+            Ok(Fetched { resource_version: obj.meta().resource_version.clone(), text: serde_yaml::to_string(&obj)? })
+        };
+        self.fetch_cache.get(key, current_version, fetch).await
+    }
+
+    /// Events change without the object's `resourceVersion` moving, so cached describe output is
+    /// only reused within the cache TTL.
+    pub async fn describe<K>(&self, name: &str, ns: &str, tz: &TimeZone) -> Result<String>
+    where
+        K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    {
+        let key = FetchKey::of::<K>(ns, name, FetchView::Describe);
+        let fetch =
+            async { Ok(Fetched { resource_version: None, text: self.describe_uncached::<K>(name, ns, tz).await? }) };
+        self.fetch_cache.get(key, std::future::ready(Ok(None)), fetch).await
+    }
+
+    async fn describe_uncached<K>(&self, name: &str, ns: &str, tz: &TimeZone) -> Result<String>
     where
         K: Resource<DynamicType = (), Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
     {
@@ -461,17 +601,14 @@ impl ActionExecutor {
         output.push_str("\n--- Events ---\n");
 
         let mut event_list: Vec<_> = events.items.into_iter().collect();
-        event_list.sort_by(|a, b| {
-            let a_time = a.last_timestamp.as_ref().map(|t| &t.0);
-            let b_time = b.last_timestamp.as_ref().map(|t| &t.0);
-            a_time.cmp(&b_time)
-        });
+        event_list.sort_by_key(last_seen);
 
         for event in &event_list {
             let kind = event.type_.as_deref().unwrap_or("Unknown");
             let reason = event.reason.as_deref().unwrap_or("");
             let message = event.message.as_deref().unwrap_or("");
-            output.push_str(&format!("  {:<10} {:<20} {}\n", kind, reason, message));
+            let last_seen = format_time(last_seen(event), tz);
+            output.push_str(&format!("  {:<27} {:<10} {:<20} {}\n", last_seen, kind, reason, message));
         }
 
         if event_list.is_empty() {
@@ -492,6 +629,8 @@ mod tests {
         assert!(actions.contains(&ResourceAction::ViewLogs));
         assert!(actions.contains(&ResourceAction::Exec));
         assert!(actions.contains(&ResourceAction::Delete));
+        assert!(actions.contains(&ResourceAction::Evict));
+        assert!(actions.contains(&ResourceAction::ForceDelete));
         assert!(actions.contains(&ResourceAction::ViewYaml));
         assert!(actions.contains(&ResourceAction::Describe));
     }
@@ -501,15 +640,39 @@ mod tests {
         let actions = ResourceAction::available_for(&ResourceKind::Deployments);
         assert!(actions.contains(&ResourceAction::Scale(0)));
         assert!(actions.contains(&ResourceAction::RestartRollout));
+        assert!(actions.contains(&ResourceAction::SetImage));
     }
 
     #[test]
     fn available_for_statefulsets_includes_scale() {
         let actions = ResourceAction::available_for(&ResourceKind::StatefulSets);
         assert!(actions.contains(&ResourceAction::Scale(0)));
+        assert!(actions.contains(&ResourceAction::SetImage));
         assert!(!actions.contains(&ResourceAction::RestartRollout));
     }
 
+    #[test]
+    fn set_image_patch_merges_containers_by_name() {
+        let image = |container: &str, init, image: &str| ContainerImage {
+            container: container.into(),
+            init,
+            image: image.into(),
+            pull_policy: "IfNotPresent".into(),
+            digest: None,
+        };
+        let patch = set_image_patch(&[image("api", false, "web/api:1.4"), image("migrate", true, "web/api:1.4")]);
+        assert_eq!(
+            patch,
+            serde_json::json!({ "spec": { "template": { "spec": {
+                "containers": [{ "name": "api", "image": "web/api:1.4" }],
+                "initContainers": [{ "name": "migrate", "image": "web/api:1.4" }]
+            } } } })
+        );
+
+        let patch = set_image_patch(&[image("api", false, "web/api:1.5")]);
+        assert_eq!(patch["spec"]["template"]["spec"].as_object().unwrap().len(), 1);
+    }
+
     #[test]
     fn available_for_configmaps_excludes_scale_logs_exec() {
         let actions = ResourceAction::available_for(&ResourceKind::ConfigMaps);
@@ -517,6 +680,7 @@ mod tests {
         assert!(!actions.contains(&ResourceAction::ViewLogs));
         assert!(!actions.contains(&ResourceAction::Exec));
         assert!(!actions.contains(&ResourceAction::RestartRollout));
+        assert!(!actions.contains(&ResourceAction::Evict));
     }
 
     #[test]
@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+/// Entries kept in memory; the file on disk keeps everything.
+const MAX_ENTRIES: usize = 1000;
+
+static AUDIT_LOG: OnceLock<Mutex<AuditLog>> = OnceLock::new();
+
+/// One API mutation performed by kubetile.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub verb: String,
+    pub resource: String,
+    pub name: String,
+    pub namespace: String,
+    /// `ok`, or the error the API server returned.
+    pub result: String,
+}
+
+impl AuditEntry {
+    pub fn succeeded(&self) -> bool {
+        self.result == "ok"
+    }
+}
+
+/// Ring buffer of recent mutations, mirrored line by line to a JSONL file when a path is set.
+#[derive(Debug)]
+pub struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    capacity: usize,
+    path: Option<PathBuf>,
+    version: u64,
+}
+
+impl AuditLog {
+    pub fn in_memory(capacity: usize) -> Self {
+        Self { entries: VecDeque::new(), capacity, path: None, version: 0 }
+    }
+
+    /// Opens the log at `path`, loading its last `capacity` entries. Unparseable lines are skipped.
+    pub fn open(path: impl Into<PathBuf>, capacity: usize) -> Self {
+        let path = path.into();
+        let mut entries: VecDeque<AuditEntry> = std::fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        while entries.len() > capacity {
+            entries.pop_front();
+        }
+        Self { entries, capacity, path: Some(path), version: 0 }
+    }
+
+    pub fn push(&mut self, entry: AuditEntry) -> io::Result<()> {
+        let written = match &self.path {
+            Some(path) => append_line(path, &entry),
+            None => Ok(()),
+        };
+        self.entries.push_back(entry);
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+        self.version += 1;
+        written
+    }
+
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &AuditEntry> {
+        self.entries.iter()
+    }
+
+    /// Bumped on every push so viewers can tell when to refresh.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+fn append_line(path: &Path, entry: &AuditEntry) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(entry).map_err(io::Error::other)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")
+}
+
+fn global() -> &'static Mutex<AuditLog> {
+    AUDIT_LOG.get_or_init(|| Mutex::new(AuditLog::in_memory(MAX_ENTRIES)))
+}
+
+/// Default location of the persistent audit file.
+pub fn default_path() -> PathBuf {
+    dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("audit.jsonl")
+}
+
+/// Starts persisting to `path` and loads its recent history. Call once at startup; entries
+/// recorded before this stay in memory only.
+pub fn init(path: impl Into<PathBuf>) {
+    let mut log = AuditLog::open(path, MAX_ENTRIES);
+    let mut guard = global().lock().unwrap_or_else(|e| e.into_inner());
+    log.version = guard.version + 1;
+    *guard = log;
+}
+
+/// Records the outcome of a mutation.
+pub fn record<T>(verb: &str, resource: &str, name: &str, namespace: &str, result: &anyhow::Result<T>) {
+    let entry = AuditEntry {
+        timestamp: jiff::Timestamp::now().to_string(),
+        verb: verb.to_string(),
+        resource: resource.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+        result: match result {
+            Ok(_) => "ok".into(),
+            Err(e) => e.to_string(),
+        },
+    };
+    let mut guard = global().lock().unwrap_or_else(|e| e.into_inner());
+    if let Err(e) = guard.push(entry) {
+        tracing::warn!("Failed to write audit log: {e}");
+    }
+}
+
+/// Recent entries, newest first, with the log version they were read at.
+pub fn snapshot() -> (Vec<AuditEntry>, u64) {
+    let guard = global().lock().unwrap_or_else(|e| e.into_inner());
+    (guard.entries().rev().cloned().collect(), guard.version())
+}
+
+/// The log version, cheap enough to check on every tick.
+pub fn version() -> u64 {
+    global().lock().unwrap_or_else(|e| e.into_inner()).version()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, result: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: "2024-03-01T12:00:00Z".into(),
+            verb: "delete".into(),
+            resource: "pods".into(),
+            name: name.into(),
+            namespace: "web".into(),
+            result: result.into(),
+        }
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest_and_bumps_version() {
+        let mut log = AuditLog::in_memory(2);
+        for name in ["a", "b", "c"] {
+            log.push(entry(name, "ok")).unwrap();
+        }
+        let names: Vec<&str> = log.entries().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "c"]);
+        assert_eq!(log.version(), 3);
+    }
+
+    #[test]
+    fn entries_persist_as_json_lines_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("audit.jsonl");
+
+        let mut log = AuditLog::open(&path, 10);
+        log.push(entry("api-0", "ok")).unwrap();
+        log.push(entry("api-1", "pods \"api-1\" is forbidden")).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.starts_with("{\"timestamp\":\"2024-03-01T12:00:00Z\",\"verb\":\"delete\""));
+
+        let reloaded = AuditLog::open(&path, 1);
+        let entries: Vec<&AuditEntry> = reloaded.entries().collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "api-1");
+        assert!(!entries[0].succeeded());
+    }
+}
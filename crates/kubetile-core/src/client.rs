@@ -1,12 +1,16 @@
 use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use k8s_openapi::api::core::v1::{Namespace, Pod};
 use kube::api::ListParams;
 use kube::config::{KubeConfigOptions, Kubeconfig};
 use kube::{Api, Client, Config};
 
+use crate::connection::{env_proxy_bypassed, ProxyEnv};
 use crate::context::ClusterContext;
+use crate::remote::ApiTunnel;
 use crate::resources::PodSummary;
 
 #[derive(Clone)]
@@ -14,10 +18,12 @@ pub struct KubeClient {
     client: Client,
     current_namespace: String,
     current_context: String,
+    /// SSH session carrying the API traffic of a context reached through a bastion.
+    tunnel: Option<Arc<ApiTunnel>>,
 }
 
 impl KubeClient {
-    fn read_kubeconfig_with_fallback() -> Result<Kubeconfig> {
+    pub(crate) fn read_kubeconfig_with_fallback() -> Result<Kubeconfig> {
         if let Some(kubeconfig) = Self::load_kubeconfig_from_env()? {
             return Ok(kubeconfig);
         }
@@ -51,29 +57,93 @@ impl KubeClient {
         let current_context = kubeconfig.current_context.clone().unwrap_or_else(|| "unknown".into());
 
         let config = Config::from_custom_kubeconfig(kubeconfig, &KubeConfigOptions::default()).await?;
-        let default_ns = config.default_namespace.clone();
-        let client = Client::try_from(config)?;
-
-        Ok(Self { client, current_namespace: default_ns, current_context })
+        Self::connect(config, current_context)
     }
 
     pub async fn from_config(path: &Path, context: &str) -> Result<Self> {
         let kubeconfig = Kubeconfig::read_from(path)?;
         let opts = KubeConfigOptions { context: Some(context.to_string()), ..Default::default() };
         let config = Config::from_custom_kubeconfig(kubeconfig, &opts).await?;
-        let default_ns = config.default_namespace.clone();
-        let client = Client::try_from(config)?;
-
-        Ok(Self { client, current_namespace: default_ns, current_context: context.to_string() })
+        Self::connect(config, context.to_string())
     }
 
     pub async fn from_context(context: &str) -> Result<Self> {
         let kubeconfig = Self::read_kubeconfig_with_fallback()?;
         let opts = KubeConfigOptions { context: Some(context.to_string()), ..Default::default() };
         let config = Config::from_custom_kubeconfig(kubeconfig, &opts).await?;
+        Self::connect(config, context.to_string())
+    }
+
+    /// Connects to `context` through `kubectl proxy` on `ssh_host`, which holds the kubeconfig and
+    /// credentials. The local kubeconfig only supplies the context's default namespace, if any.
+    pub async fn from_context_over_ssh(context: &str, ssh_host: &str) -> Result<Self> {
+        let tunnel = ApiTunnel::open(ssh_host, context).await?;
+        let mut config = Config::new(tunnel.url().parse()?);
+        if let Some(namespace) = Self::context_namespace(context) {
+            config.default_namespace = namespace;
+        }
+        let mut client = Self::connect(config, context.to_string())?;
+        client.tunnel = Some(Arc::new(tunnel));
+        Ok(client)
+    }
+
+    /// Connects to `context`, or to the kubeconfig's current context, through `ssh_host` when one
+    /// is given.
+    pub async fn connect_to(context: Option<&str>, ssh_host: Option<&str>) -> Result<Self> {
+        match (context, ssh_host) {
+            (Some(context), Some(host)) => Self::from_context_over_ssh(context, host).await,
+            (Some(context), None) => Self::from_context(context).await,
+            (None, _) => Self::from_kubeconfig().await,
+        }
+    }
+
+    /// The current context of the local kubeconfig.
+    pub fn current_context_name() -> Option<String> {
+        Self::read_kubeconfig_with_fallback().ok()?.current_context
+    }
+
+    fn context_namespace(context: &str) -> Option<String> {
+        let kubeconfig = Self::read_kubeconfig_with_fallback().ok()?;
+        let named = kubeconfig.contexts.into_iter().find(|c| c.name == context)?;
+        named.context?.namespace
+    }
+
+    fn connect(mut config: Config, current_context: String) -> Result<Self> {
+        // kube takes HTTPS_PROXY from the environment but does not honour NO_PROXY. A cluster's
+        // own proxy-url always applies, as with kubectl.
+        let env = ProxyEnv::from_env();
+        let from_env = env.https_proxy.as_deref().and_then(|p| p.parse::<hyper::Uri>().ok());
+        if config.proxy_url.is_some()
+            && config.proxy_url == from_env
+            && env_proxy_bypassed(&config.cluster_url.to_string(), &env)
+        {
+            config.proxy_url = None;
+        }
         let default_ns = config.default_namespace.clone();
         let client = Client::try_from(config)?;
-        Ok(Self { client, current_namespace: default_ns, current_context: context.to_string() })
+        Ok(Self { client, current_namespace: default_ns, current_context, tunnel: None })
+    }
+
+    /// Connects to `context`, through `ssh_host` when given, and times a request to the API
+    /// server's version endpoint. The whole probe, including credential plugins, is bounded by
+    /// `timeout`.
+    pub async fn probe_context(context: &str, ssh_host: Option<&str>, timeout: Duration) -> Result<Duration> {
+        let probe = async {
+            let client = Self::connect_to(Some(context), ssh_host).await?;
+            let started = Instant::now();
+            client.client.apiserver_version().await?;
+            Ok(started.elapsed())
+        };
+        tokio::time::timeout(timeout, probe).await.map_err(|_| anyhow!("timed out after {}ms", timeout.as_millis()))?
+    }
+
+    /// Times a request to the API server's version endpoint, bounded by `timeout`.
+    pub async fn api_latency(&self, timeout: Duration) -> Result<Duration> {
+        let started = Instant::now();
+        tokio::time::timeout(timeout, self.client.apiserver_version())
+            .await
+            .map_err(|_| anyhow!("timed out after {}ms", timeout.as_millis()))??;
+        Ok(started.elapsed())
     }
 
     pub fn cluster_context(&self) -> ClusterContext {
@@ -0,0 +1,257 @@
+use std::fmt::Debug;
+use std::time::Duration;
+
+use anyhow::Result;
+use jiff::Timestamp;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{Event, Node, Pod};
+use kube::api::ListParams;
+use kube::{Api, Client, Resource};
+use serde::de::DeserializeOwned;
+
+use crate::namespace_overview::{last_seen, pod_phase, EVENT_WINDOW};
+
+/// Pod phases in lifecycle order, the order the overview lists them in.
+const PHASES: [&str; 5] = ["Running", "Pending", "Succeeded", "Failed", "Unknown"];
+
+/// Cluster-wide health aggregates for the dashboard.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClusterOverview {
+    pub nodes_total: usize,
+    /// Nodes whose Ready condition is not `True`.
+    pub nodes_not_ready: Vec<String>,
+    /// Pod count per phase; phases without pods are left out.
+    pub pod_phases: Vec<(String, usize)>,
+    /// `namespace/name` of every pod in the Pending phase.
+    pub pending_pods: Vec<String>,
+    pub failing_deployments: Vec<FailingDeployment>,
+    /// Warning events seen within the last hour, newest first.
+    pub warnings: Vec<WarningEvent>,
+    /// Lists that could not be read, with their error; their sections are left empty.
+    pub errors: Vec<(OverviewSection, String)>,
+}
+
+/// The cluster-wide list each part of the overview is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverviewSection {
+    Nodes,
+    /// Backs both the pod phases and the pending pods.
+    Pods,
+    Deployments,
+    Warnings,
+}
+
+/// A Deployment with fewer ready replicas than it asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailingDeployment {
+    pub namespace: String,
+    pub name: String,
+    pub ready: i32,
+    pub desired: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarningEvent {
+    pub namespace: String,
+    /// Kind and name of the object the event is about, e.g. `Pod` and `api-0`.
+    pub kind: String,
+    pub name: String,
+    pub reason: String,
+    pub message: String,
+    pub age: Duration,
+}
+
+/// Objects fetched per LIST request; larger clusters are read in several pages.
+const LIST_PAGE_SIZE: u32 = 500;
+
+/// Lists nodes, pods, deployments and warning events across the cluster. A list that fails, e.g.
+/// because it is forbidden, leaves its sections empty and is recorded in
+/// [`ClusterOverview::errors`]; only when every list fails is the whole overview an error.
+pub async fn cluster_overview(client: Client) -> Result<ClusterOverview> {
+    let (nodes, pods, deployments, events) = tokio::join!(
+        list_all(Api::<Node>::all(client.clone()), ListParams::default()),
+        list_all(Api::<Pod>::all(client.clone()), ListParams::default()),
+        list_all(Api::<Deployment>::all(client.clone()), ListParams::default()),
+        list_all(Api::<Event>::all(client), ListParams::default().fields("type=Warning")),
+    );
+    let mut errors = Vec::new();
+    let nodes = section_items(OverviewSection::Nodes, nodes, &mut errors);
+    let pods = section_items(OverviewSection::Pods, pods, &mut errors);
+    let deployments = section_items(OverviewSection::Deployments, deployments, &mut errors);
+    let events = section_items(OverviewSection::Warnings, events, &mut errors);
+    if errors.len() == 4 {
+        anyhow::bail!("{}", errors[0].1);
+    }
+    let overview = ClusterOverview::build(&nodes, &pods, &deployments, &events, Timestamp::now());
+    Ok(ClusterOverview { errors, ..overview })
+}
+
+/// Every object `api` lists, fetched in pages of [`LIST_PAGE_SIZE`].
+async fn list_all<K>(api: Api<K>, params: ListParams) -> kube::Result<Vec<K>>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug,
+{
+    let mut params = params.limit(LIST_PAGE_SIZE);
+    let mut items = Vec::new();
+    loop {
+        let page = api.list(&params).await?;
+        items.extend(page.items);
+        match page.metadata.continue_ {
+            Some(token) if !token.is_empty() => params.continue_token = Some(token),
+            _ => return Ok(items),
+        }
+    }
+}
+
+/// The items of a list, or none with its error recorded against `section` when it failed.
+pub(crate) fn section_items<S, K>(section: S, result: kube::Result<Vec<K>>, errors: &mut Vec<(S, String)>) -> Vec<K> {
+    result.unwrap_or_else(|e| {
+        errors.push((section, e.to_string()));
+        Vec::new()
+    })
+}
+
+impl ClusterOverview {
+    /// Why the list behind `section` could not be read, if it failed.
+    pub fn error(&self, section: OverviewSection) -> Option<&str> {
+        self.errors.iter().find(|(s, _)| *s == section).map(|(_, e)| e.as_str())
+    }
+
+    pub fn build(nodes: &[Node], pods: &[Pod], deployments: &[Deployment], events: &[Event], now: Timestamp) -> Self {
+        let nodes_not_ready = nodes.iter().filter(|n| !node_ready(n)).map(|n| object_name(&n.metadata.name)).collect();
+        let pod_phases = PHASES
+            .iter()
+            .map(|phase| {
+                (phase.to_string(), pods.iter().filter(|p| pod_phase(p).unwrap_or("Unknown") == *phase).count())
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        let pending_pods = pods
+            .iter()
+            .filter(|p| pod_phase(p) == Some("Pending"))
+            .map(|p| format!("{}/{}", object_name(&p.metadata.namespace), object_name(&p.metadata.name)))
+            .collect();
+        let failing_deployments = deployments.iter().filter_map(failing_deployment).collect();
+
+        Self {
+            nodes_total: nodes.len(),
+            nodes_not_ready,
+            pod_phases,
+            pending_pods,
+            failing_deployments,
+            warnings: recent_warnings(events, now),
+            errors: Vec::new(),
+        }
+    }
+}
+
+fn object_name(name: &Option<String>) -> String {
+    name.clone().unwrap_or_default()
+}
+
+fn node_ready(node: &Node) -> bool {
+    let conditions = node.status.as_ref().and_then(|s| s.conditions.as_ref());
+    conditions.into_iter().flatten().any(|c| c.type_ == "Ready" && c.status == "True")
+}
+
+fn failing_deployment(deployment: &Deployment) -> Option<FailingDeployment> {
+    let desired = deployment.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let ready = deployment.status.as_ref().and_then(|s| s.ready_replicas).unwrap_or(0);
+    (ready < desired).then(|| FailingDeployment {
+        namespace: object_name(&deployment.metadata.namespace),
+        name: object_name(&deployment.metadata.name),
+        ready,
+        desired,
+    })
+}
+
+fn recent_warnings(events: &[Event], now: Timestamp) -> Vec<WarningEvent> {
+    let mut recent: Vec<(Timestamp, &Event)> = events
+        .iter()
+        .filter(|e| e.type_.as_deref() == Some("Warning"))
+        .filter_map(|e| Some((last_seen(e)?, e)))
+        .filter(|(seen, _)| now.duration_since(*seen) <= EVENT_WINDOW)
+        .collect();
+    recent.sort_by_key(|(seen, _)| std::cmp::Reverse(*seen));
+    recent
+        .into_iter()
+        .map(|(seen, event)| {
+            let object = &event.involved_object;
+            WarningEvent {
+                namespace: object.namespace.clone().or_else(|| event.metadata.namespace.clone()).unwrap_or_default(),
+                kind: object.kind.clone().unwrap_or_default(),
+                name: object.name.clone().unwrap_or_default(),
+                reason: event.reason.clone().unwrap_or_default(),
+                message: event.message.as_deref().unwrap_or_default().trim().to_string(),
+                age: now.duration_since(seen).unsigned_abs(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, ready: &str) -> Node {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name },
+            "status": { "conditions": [{ "type": "Ready", "status": ready }] }
+        }))
+        .unwrap()
+    }
+
+    fn pod(name: &str, phase: &str) -> Pod {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": "web" },
+            "status": { "phase": phase }
+        }))
+        .unwrap()
+    }
+
+    fn deployment(name: &str, replicas: i32, ready: i32) -> Deployment {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": "web" },
+            "spec": { "replicas": replicas, "selector": {}, "template": {} },
+            "status": { "readyReplicas": ready }
+        }))
+        .unwrap()
+    }
+
+    fn warning(reason: &str, last_seen: &str) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": format!("{reason}.1"), "namespace": "web" },
+            "involvedObject": { "kind": "Pod", "name": "api-0", "namespace": "web" },
+            "reason": reason,
+            "message": "Back-off restarting failed container\n",
+            "type": "Warning",
+            "lastTimestamp": last_seen
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn overview_aggregates_nodes_pods_deployments_and_warnings() {
+        let now: Timestamp = "2024-03-01T12:00:00Z".parse().unwrap();
+        let overview = ClusterOverview::build(
+            &[node("node-1", "True"), node("node-2", "Unknown")],
+            &[pod("api-0", "Running"), pod("api-1", "Running"), pod("db-0", "Pending")],
+            &[deployment("api", 2, 2), deployment("db", 3, 1)],
+            &[warning("FailedMount", "2024-03-01T09:00:00Z"), warning("BackOff", "2024-03-01T11:50:00Z")],
+            now,
+        );
+
+        assert_eq!(overview.nodes_total, 2);
+        assert_eq!(overview.nodes_not_ready, ["node-2"]);
+        assert_eq!(overview.pod_phases, [("Running".to_string(), 2), ("Pending".to_string(), 1)]);
+        assert_eq!(overview.pending_pods, ["web/db-0"]);
+        assert_eq!(
+            overview.failing_deployments,
+            [FailingDeployment { namespace: "web".into(), name: "db".into(), ready: 1, desired: 3 }]
+        );
+        assert_eq!(overview.warnings.len(), 1);
+        assert_eq!(overview.warnings[0].reason, "BackOff");
+        assert_eq!(overview.warnings[0].message, "Back-off restarting failed container");
+        assert_eq!(overview.warnings[0].age, Duration::from_secs(600));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+use k8s_openapi::api::core::v1::Pod;
+
+/// Last container picked for logs/exec, keyed by workload so the choice outlives pod restarts.
+/// `default()` keeps the choices in memory only.
+#[derive(Debug, Clone, Default)]
+pub struct ContainerMemory {
+    entries: BTreeMap<String, String>,
+    path: Option<PathBuf>,
+}
+
+impl ContainerMemory {
+    pub fn load() -> Self {
+        Self::load_from(container_memory_path())
+    }
+
+    pub fn load_from(path: PathBuf) -> Self {
+        let entries =
+            std::fs::read_to_string(&path).ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        Self { entries, path: Some(path) }
+    }
+
+    pub fn key(context: &str, namespace: &str, workload: &str) -> String {
+        format!("{context}/{namespace}/{workload}")
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    pub fn remember(&mut self, key: String, container: &str) -> io::Result<()> {
+        if self.get(&key) == Some(container) {
+            return Ok(());
+        }
+        self.entries.insert(key, container.to_string());
+        self.save()
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(&self.entries).map_err(io::Error::other)?;
+        std::fs::write(path, data)
+    }
+}
+
+fn container_memory_path() -> PathBuf {
+    dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("container_memory.json")
+}
+
+/// Names the workload that owns `pod`, e.g. `Deployment/api` or `StatefulSet/db`. ReplicaSets
+/// created by a Deployment resolve to the Deployment through the pod-template-hash suffix; bare
+/// pods name themselves.
+pub fn pod_workload(pod: &Pod) -> String {
+    pod_owner_chain(pod)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| format!("Pod/{}", pod.metadata.name.as_deref().unwrap_or_default()))
+}
+
+/// Controllers above `pod`, outermost first, e.g. `["Deployment/api", "ReplicaSet/api-5d8f9c7b6"]`.
+/// Empty for bare pods.
+pub fn pod_owner_chain(pod: &Pod) -> Vec<String> {
+    let Some(owner) =
+        pod.metadata.owner_references.as_ref().and_then(|refs| refs.iter().find(|r| r.controller == Some(true)))
+    else {
+        return Vec::new();
+    };
+
+    let direct = format!("{}/{}", owner.kind, owner.name);
+    if owner.kind == "ReplicaSet" {
+        let hash = pod.metadata.labels.as_ref().and_then(|l| l.get("pod-template-hash"));
+        if let Some(deployment) = hash.and_then(|h| owner.name.strip_suffix(&format!("-{h}"))) {
+            return vec![format!("Deployment/{deployment}"), direct];
+        }
+    }
+    vec![direct]
+}
+
+/// Regular container names of `pod`, in spec order.
+pub fn pod_containers(pod: &Pod) -> Vec<String> {
+    pod.spec.as_ref().map(|s| s.containers.iter().map(|c| c.name.clone()).collect()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    fn pod_owned_by(kind: &str, owner: &str, hash: Option<&str>) -> Pod {
+        let mut pod = Pod::default();
+        pod.metadata.name = Some(format!("{owner}-x7k2p"));
+        pod.metadata.owner_references = Some(vec![OwnerReference {
+            kind: kind.into(),
+            name: owner.into(),
+            controller: Some(true),
+            ..Default::default()
+        }]);
+        pod.metadata.labels = hash.map(|h| [("pod-template-hash".to_string(), h.to_string())].into());
+        pod
+    }
+
+    #[test]
+    fn replicaset_pods_resolve_to_their_deployment() {
+        let pod = pod_owned_by("ReplicaSet", "api-5d8f9c7b6", Some("5d8f9c7b6"));
+        assert_eq!(pod_workload(&pod), "Deployment/api");
+        assert_eq!(pod_owner_chain(&pod), ["Deployment/api", "ReplicaSet/api-5d8f9c7b6"]);
+    }
+
+    #[test]
+    fn other_controllers_and_bare_pods_keep_their_owner() {
+        assert_eq!(pod_workload(&pod_owned_by("StatefulSet", "db", None)), "StatefulSet/db");
+        assert_eq!(pod_workload(&pod_owned_by("ReplicaSet", "legacy", None)), "ReplicaSet/legacy");
+
+        let mut bare = Pod::default();
+        bare.metadata.name = Some("debug".into());
+        assert_eq!(pod_workload(&bare), "Pod/debug");
+        assert!(pod_owner_chain(&bare).is_empty());
+    }
+
+    #[test]
+    fn remembered_container_survives_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("container_memory.json");
+        let key = ContainerMemory::key("prod", "web", "Deployment/api");
+
+        let mut memory = ContainerMemory::load_from(path.clone());
+        assert_eq!(memory.get(&key), None);
+        memory.remember(key.clone(), "sidecar").unwrap();
+
+        assert_eq!(ContainerMemory::load_from(path).get(&key), Some("sidecar"));
+    }
+}
@@ -7,11 +7,13 @@ pub struct ClusterContext {
 #[derive(Clone)]
 pub struct ContextResolver {
     active: Option<ClusterContext>,
+    /// Namespaces watched together when more than one is selected; empty otherwise.
+    multi_namespaces: Vec<String>,
 }
 
 impl ContextResolver {
     pub fn new() -> Self {
-        Self { active: None }
+        Self { active: None, multi_namespaces: Vec::new() }
     }
 
     pub fn resolve(&self) -> Option<&ClusterContext> {
@@ -20,12 +22,33 @@ impl ContextResolver {
 
     pub fn set_context(&mut self, ctx: ClusterContext) {
         self.active = Some(ctx);
+        self.multi_namespaces.clear();
     }
 
     pub fn set_namespace(&mut self, ns: &str) {
         if let Some(ref mut ctx) = self.active {
             ctx.namespace = ns.to_string();
         }
+        self.multi_namespaces.clear();
+    }
+
+    /// Selects several namespaces at once. The first one becomes the current namespace, which is
+    /// used wherever a single namespace is needed. A single entry behaves like `set_namespace`.
+    pub fn set_namespaces(&mut self, namespaces: &[String]) {
+        let Some(first) = namespaces.first() else { return };
+        self.set_namespace(first);
+        if namespaces.len() > 1 && self.active.is_some() {
+            self.multi_namespaces = namespaces.to_vec();
+        }
+    }
+
+    /// The selected namespaces: all of them after `set_namespaces`, otherwise just the current one.
+    pub fn namespaces(&self) -> Vec<&str> {
+        if self.multi_namespaces.is_empty() {
+            self.namespace().into_iter().collect()
+        } else {
+            self.multi_namespaces.iter().map(String::as_str).collect()
+        }
     }
 
     pub fn context_name(&self) -> Option<&str> {
@@ -48,3 +48,17 @@ fn env_vars_produces_correct_pairs() {
     assert_eq!(vars[0], ("K8S_CONTEXT".into(), "prod".into()));
     assert_eq!(vars[1], ("K8S_NAMESPACE".into(), "monitoring".into()));
 }
+
+#[test]
+fn set_namespaces_selects_several_until_a_single_namespace_is_set() {
+    let mut resolver = ContextResolver::new();
+    resolver.set_context(ClusterContext { name: "prod".into(), namespace: "default".into() });
+    assert_eq!(resolver.namespaces(), vec!["default"]);
+
+    resolver.set_namespaces(&["web".into(), "api".into()]);
+    assert_eq!(resolver.namespace(), Some("web"));
+    assert_eq!(resolver.namespaces(), vec!["web", "api"]);
+
+    resolver.set_namespace("default");
+    assert_eq!(resolver.namespaces(), vec!["default"]);
+}
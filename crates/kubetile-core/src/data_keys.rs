@@ -0,0 +1,131 @@
+use anyhow::Result;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use kube::api::{Api, Patch, PatchParams};
+use kube::Client;
+
+use crate::actions::ResourceKind;
+use crate::audit;
+
+/// One key of a ConfigMap or Secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataEntry {
+    pub key: String,
+    /// The value as text; `None` for binary values, which are not edited as text.
+    pub value: Option<String>,
+}
+
+/// The data keys of a ConfigMap or Secret at the `resource_version` they were read at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSnapshot {
+    pub resource_version: String,
+    pub entries: Vec<DataEntry>,
+}
+
+pub async fn read_data(client: Client, kind: &ResourceKind, name: &str, ns: &str) -> Result<DataSnapshot> {
+    match kind {
+        ResourceKind::ConfigMaps => Ok(configmap_snapshot(&Api::<ConfigMap>::namespaced(client, ns).get(name).await?)),
+        ResourceKind::Secrets => Ok(secret_snapshot(&Api::<Secret>::namespaced(client, ns).get(name).await?)),
+        _ => anyhow::bail!("Data keys are not supported for {:?}", kind),
+    }
+}
+
+fn configmap_snapshot(cm: &ConfigMap) -> DataSnapshot {
+    let text = cm.data.iter().flatten().map(|(key, value)| DataEntry { key: key.clone(), value: Some(value.clone()) });
+    let binary = cm.binary_data.iter().flatten().map(|(key, _)| DataEntry { key: key.clone(), value: None });
+    let mut entries: Vec<DataEntry> = text.chain(binary).collect();
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    DataSnapshot { resource_version: cm.metadata.resource_version.clone().unwrap_or_default(), entries }
+}
+
+fn secret_snapshot(secret: &Secret) -> DataSnapshot {
+    let entries = secret
+        .data
+        .iter()
+        .flatten()
+        .map(|(key, bytes)| DataEntry { key: key.clone(), value: String::from_utf8(bytes.0.clone()).ok() })
+        .collect();
+    DataSnapshot { resource_version: secret.metadata.resource_version.clone().unwrap_or_default(), entries }
+}
+
+/// Replaces the value of one key and leaves the others alone. The patch carries the resource
+/// version the value was read at, so it is refused when the object changed in the meantime.
+pub async fn patch_data_key(
+    client: Client,
+    kind: &ResourceKind,
+    name: &str,
+    ns: &str,
+    resource_version: &str,
+    key: &str,
+    value: &str,
+) -> Result<()> {
+    let patch = data_key_patch(kind, resource_version, key, value);
+    let pp = PatchParams::apply("kubetile");
+    let verb = format!("edit key {key}");
+    let result = match kind {
+        ResourceKind::ConfigMaps => {
+            Api::<ConfigMap>::namespaced(client, ns).patch(name, &pp, &Patch::Merge(&patch)).await.map(|_| ())
+        }
+        ResourceKind::Secrets => {
+            Api::<Secret>::namespaced(client, ns).patch(name, &pp, &Patch::Merge(&patch)).await.map(|_| ())
+        }
+        _ => anyhow::bail!("Data keys are not supported for {:?}", kind),
+    };
+    let result = result.map_err(anyhow::Error::from);
+    let resource = if *kind == ResourceKind::Secrets { "secrets" } else { "configmaps" };
+    audit::record(&verb, resource, name, ns, &result);
+    result
+}
+
+/// Secrets take the new value through `stringData`, which the API server encodes into `data`.
+fn data_key_patch(kind: &ResourceKind, resource_version: &str, key: &str, value: &str) -> serde_json::Value {
+    let field = if *kind == ResourceKind::Secrets { "stringData" } else { "data" };
+    serde_json::json!({
+        "metadata": { "resourceVersion": resource_version },
+        field: { key: value },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshots_list_keys_and_leave_binary_values_out() {
+        let cm: ConfigMap = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "settings", "resourceVersion": "41" },
+            "data": { "app.conf": "port = 80\n" },
+            "binaryData": { "logo.png": "iVBORw0K" }
+        }))
+        .unwrap();
+        let snapshot = configmap_snapshot(&cm);
+        assert_eq!(snapshot.resource_version, "41");
+        assert_eq!(
+            snapshot.entries,
+            vec![
+                DataEntry { key: "app.conf".into(), value: Some("port = 80\n".into()) },
+                DataEntry { key: "logo.png".into(), value: None },
+            ]
+        );
+
+        let secret: Secret = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "creds", "resourceVersion": "7" },
+            "data": { "password": "aHVudGVyMg==", "keystore": "/w==" }
+        }))
+        .unwrap();
+        let entries = secret_snapshot(&secret).entries;
+        assert_eq!(entries[0], DataEntry { key: "keystore".into(), value: None });
+        assert_eq!(entries[1], DataEntry { key: "password".into(), value: Some("hunter2".into()) });
+    }
+
+    #[test]
+    fn data_key_patch_touches_one_key_at_the_read_version() {
+        assert_eq!(
+            data_key_patch(&ResourceKind::ConfigMaps, "41", "app.conf", "port = 8080\n"),
+            serde_json::json!({ "metadata": { "resourceVersion": "41" }, "data": { "app.conf": "port = 8080\n" } })
+        );
+        assert_eq!(
+            data_key_patch(&ResourceKind::Secrets, "7", "password", "hunter3"),
+            serde_json::json!({ "metadata": { "resourceVersion": "7" }, "stringData": { "password": "hunter3" } })
+        );
+    }
+}
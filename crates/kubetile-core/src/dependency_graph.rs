@@ -0,0 +1,328 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{bail, Result};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{Pod, PodSpec, Service};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::actions::ResourceKind;
+use crate::ingress_routes::{ingress_routes, RouteBackend};
+
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+/// One object of a dependency graph with the objects it leads to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphNode {
+    /// Kind as the API names it, e.g. `Service` or `PersistentVolumeClaim`.
+    pub kind: String,
+    pub name: String,
+    /// Short status shown after the name, e.g. `Running` or `2/3 ready`.
+    pub note: Option<String>,
+    pub children: Vec<GraphNode>,
+}
+
+impl GraphNode {
+    fn new(kind: &str, name: impl Into<String>, note: Option<String>) -> Self {
+        Self { kind: kind.into(), name: name.into(), note, children: Vec::new() }
+    }
+}
+
+/// A node drawn as one line of an ASCII tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphLine {
+    /// Branch drawing before the node, e.g. `│   ├── `.
+    pub prefix: String,
+    pub kind: String,
+    pub name: String,
+    pub note: Option<String>,
+}
+
+/// Flattens `root` depth-first into tree lines.
+pub fn graph_lines(root: &GraphNode) -> Vec<GraphLine> {
+    let mut lines = Vec::new();
+    push_lines(root, String::new(), String::new(), &mut lines);
+    lines
+}
+
+fn push_lines(node: &GraphNode, prefix: String, child_prefix: String, lines: &mut Vec<GraphLine>) {
+    lines.push(GraphLine { prefix, kind: node.kind.clone(), name: node.name.clone(), note: node.note.clone() });
+    let last = node.children.len().saturating_sub(1);
+    for (i, child) in node.children.iter().enumerate() {
+        let (branch, continuation) = if i == last { ("└── ", "    ") } else { ("├── ", "│   ") };
+        push_lines(child, format!("{child_prefix}{branch}"), format!("{child_prefix}{continuation}"), lines);
+    }
+}
+
+/// The namespace objects a graph is built from, read once.
+pub struct NamespaceObjects {
+    pub services: Vec<Service>,
+    pub slices: Vec<EndpointSlice>,
+    pub pods: Vec<Pod>,
+}
+
+/// Reads the selected object and what it leads to: an Ingress to its Services, a Service to its
+/// EndpointSlices and their pods, a workload to its pods, and a pod to the PVCs, ConfigMaps and
+/// Secrets it uses.
+pub async fn dependency_graph(client: Client, kind: &ResourceKind, name: &str, namespace: &str) -> Result<GraphNode> {
+    let lp = ListParams::default();
+    let objects = NamespaceObjects {
+        services: Api::<Service>::namespaced(client.clone(), namespace).list(&lp).await?.items,
+        slices: Api::<EndpointSlice>::namespaced(client.clone(), namespace).list(&lp).await?.items,
+        pods: Api::<Pod>::namespaced(client.clone(), namespace).list(&lp).await?.items,
+    };
+    Ok(match kind {
+        ResourceKind::Ingresses => {
+            ingress_node(&Api::<Ingress>::namespaced(client, namespace).get(name).await?, &objects)
+        }
+        ResourceKind::Services => match objects.services.iter().find(|s| s.metadata.name.as_deref() == Some(name)) {
+            Some(service) => service_node(service, &objects),
+            None => bail!("Service {name} not found"),
+        },
+        ResourceKind::Deployments => {
+            let deployment = Api::<Deployment>::namespaced(client, namespace).get(name).await?;
+            workload_node("Deployment", name, deployment.spec.as_ref().map(|s| &s.selector), &objects)
+        }
+        ResourceKind::StatefulSets => {
+            let statefulset = Api::<StatefulSet>::namespaced(client, namespace).get(name).await?;
+            workload_node("StatefulSet", name, statefulset.spec.as_ref().map(|s| &s.selector), &objects)
+        }
+        ResourceKind::DaemonSets => {
+            let daemonset = Api::<DaemonSet>::namespaced(client, namespace).get(name).await?;
+            workload_node("DaemonSet", name, daemonset.spec.as_ref().map(|s| &s.selector), &objects)
+        }
+        ResourceKind::Pods => match objects.pods.iter().find(|p| p.metadata.name.as_deref() == Some(name)) {
+            Some(pod) => pod_node(pod),
+            None => bail!("Pod {name} not found"),
+        },
+        _ => bail!("Dependency graphs are not supported for {:?}", kind),
+    })
+}
+
+/// An Ingress with each Service its rules and default backend route to.
+pub fn ingress_node(ingress: &Ingress, objects: &NamespaceObjects) -> GraphNode {
+    let name = ingress.metadata.name.clone().unwrap_or_default();
+    let routes = ingress_routes(ingress);
+    let hosts: BTreeSet<&str> = routes.iter().map(|r| r.host.as_str()).collect();
+    let hosts = hosts.into_iter().collect::<Vec<_>>().join(", ");
+    let mut node = GraphNode::new("Ingress", name, Some(hosts).filter(|h| !h.is_empty()));
+    let mut services: Vec<&str> = Vec::new();
+    for route in &routes {
+        if let RouteBackend::Service { name, .. } = &route.backend {
+            if !services.contains(&name.as_str()) {
+                services.push(name);
+            }
+        }
+    }
+    node.children = services
+        .into_iter()
+        .map(|service| match objects.services.iter().find(|s| s.metadata.name.as_deref() == Some(service)) {
+            Some(found) => service_node(found, objects),
+            None => GraphNode::new("Service", service, Some("not found".into())),
+        })
+        .collect();
+    node
+}
+
+/// A Service with its EndpointSlices, each with the pods behind its endpoints.
+pub fn service_node(service: &Service, objects: &NamespaceObjects) -> GraphNode {
+    let name = service.metadata.name.clone().unwrap_or_default();
+    let service_type = service.spec.as_ref().and_then(|s| s.type_.clone());
+    let mut node = GraphNode::new("Service", name.as_str(), service_type);
+    node.children = objects
+        .slices
+        .iter()
+        .filter(|slice| slice.metadata.labels.as_ref().and_then(|l| l.get(SERVICE_NAME_LABEL)) == Some(&name))
+        .map(|slice| slice_node(slice, objects))
+        .collect();
+    node
+}
+
+fn slice_node(slice: &EndpointSlice, objects: &NamespaceObjects) -> GraphNode {
+    let ready = slice.endpoints.iter().filter(|e| e.conditions.as_ref().and_then(|c| c.ready).unwrap_or(false)).count();
+    let note = format!("{ready}/{} ready", slice.endpoints.len());
+    let mut node = GraphNode::new("EndpointSlice", slice.metadata.name.clone().unwrap_or_default(), Some(note));
+    let mut pods: Vec<&str> = Vec::new();
+    for target in slice.endpoints.iter().filter_map(|e| e.target_ref.as_ref()) {
+        if target.kind.as_deref() == Some("Pod") {
+            if let Some(pod) = target.name.as_deref().filter(|pod| !pods.contains(pod)) {
+                pods.push(pod);
+            }
+        }
+    }
+    node.children = pods
+        .into_iter()
+        .map(|pod| match objects.pods.iter().find(|p| p.metadata.name.as_deref() == Some(pod)) {
+            Some(found) => pod_node(found),
+            None => GraphNode::new("Pod", pod, Some("not found".into())),
+        })
+        .collect();
+    node
+}
+
+/// A workload with the pods its selector matches.
+pub fn workload_node(
+    kind: &str,
+    name: &str,
+    selector: Option<&LabelSelector>,
+    objects: &NamespaceObjects,
+) -> GraphNode {
+    let mut node = GraphNode::new(kind, name, None);
+    let Some(labels) = selector.and_then(|s| s.match_labels.as_ref()).filter(|l| !l.is_empty()) else {
+        return node;
+    };
+    node.children = objects.pods.iter().filter(|pod| selects(labels, pod)).map(pod_node).collect();
+    node.note = Some(match node.children.len() {
+        1 => "1 pod".to_string(),
+        n => format!("{n} pods"),
+    });
+    node
+}
+
+fn selects(labels: &BTreeMap<String, String>, pod: &Pod) -> bool {
+    let pod_labels = pod.metadata.labels.as_ref();
+    labels.iter().all(|(k, v)| pod_labels.and_then(|l| l.get(k)) == Some(v))
+}
+
+/// A pod with the PVCs, ConfigMaps and Secrets it mounts or reads its environment from.
+pub fn pod_node(pod: &Pod) -> GraphNode {
+    let phase = pod.status.as_ref().and_then(|s| s.phase.clone());
+    let mut node = GraphNode::new("Pod", pod.metadata.name.clone().unwrap_or_default(), phase);
+    if let Some(spec) = &pod.spec {
+        node.children =
+            pod_spec_dependencies(spec).into_iter().map(|(kind, name)| GraphNode::new(kind, name, None)).collect();
+    }
+    node
+}
+
+/// PVCs first, then ConfigMaps, then Secrets, each listed once.
+fn pod_spec_dependencies(spec: &PodSpec) -> Vec<(&'static str, String)> {
+    let mut claims = Vec::new();
+    let mut config_maps = Vec::new();
+    let mut secrets = Vec::new();
+    for volume in spec.volumes.iter().flatten() {
+        claims.extend(volume.persistent_volume_claim.as_ref().map(|c| c.claim_name.clone()));
+        config_maps.extend(volume.config_map.as_ref().map(|c| c.name.clone()));
+        secrets.extend(volume.secret.as_ref().and_then(|s| s.secret_name.clone()));
+        for source in volume.projected.iter().flat_map(|p| p.sources.iter().flatten()) {
+            config_maps.extend(source.config_map.as_ref().map(|c| c.name.clone()));
+            secrets.extend(source.secret.as_ref().map(|s| s.name.clone()));
+        }
+    }
+    for container in spec.init_containers.iter().flatten().chain(&spec.containers) {
+        for source in container.env.iter().flatten().filter_map(|e| e.value_from.as_ref()) {
+            config_maps.extend(source.config_map_key_ref.as_ref().map(|r| r.name.clone()));
+            secrets.extend(source.secret_key_ref.as_ref().map(|r| r.name.clone()));
+        }
+        for from in container.env_from.iter().flatten() {
+            config_maps.extend(from.config_map_ref.as_ref().map(|r| r.name.clone()));
+            secrets.extend(from.secret_ref.as_ref().map(|r| r.name.clone()));
+        }
+    }
+    secrets.extend(spec.image_pull_secrets.iter().flatten().map(|s| s.name.clone()));
+
+    let mut dependencies = Vec::new();
+    for (kind, names) in [("PersistentVolumeClaim", claims), ("ConfigMap", config_maps), ("Secret", secrets)] {
+        for name in names {
+            if !name.is_empty() && !dependencies.iter().any(|(k, n)| *k == kind && *n == name) {
+                dependencies.push((kind, name));
+            }
+        }
+    }
+    dependencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(name: &str, app: &str) -> Pod {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "labels": { "app": app } },
+            "spec": {
+                "volumes": [
+                    { "name": "data", "persistentVolumeClaim": { "claimName": format!("data-{name}") } },
+                    { "name": "config", "configMap": { "name": "settings" } }
+                ],
+                "containers": [{
+                    "name": "api",
+                    "envFrom": [{ "configMapRef": { "name": "settings" } }, { "secretRef": { "name": "creds" } }]
+                }]
+            },
+            "status": { "phase": "Running" }
+        }))
+        .unwrap()
+    }
+
+    fn objects() -> NamespaceObjects {
+        let service = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "api" },
+            "spec": { "type": "ClusterIP", "selector": { "app": "api" } }
+        }))
+        .unwrap();
+        let slice = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "api-x7k2p", "labels": { "kubernetes.io/service-name": "api" } },
+            "addressType": "IPv4",
+            "endpoints": [
+                { "addresses": ["10.0.0.7"], "conditions": { "ready": true },
+                  "targetRef": { "kind": "Pod", "name": "api-0" } },
+                { "addresses": ["10.0.0.8"], "conditions": { "ready": false },
+                  "targetRef": { "kind": "Pod", "name": "api-1" } }
+            ]
+        }))
+        .unwrap();
+        NamespaceObjects {
+            services: vec![service],
+            slices: vec![slice],
+            pods: vec![pod("api-0", "api"), pod("worker", "jobs")],
+        }
+    }
+
+    #[test]
+    fn ingress_leads_through_services_and_endpoints_to_pod_dependencies() {
+        let ingress: Ingress = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "shop" },
+            "spec": { "rules": [{ "host": "shop.example.com", "http": { "paths": [
+                { "path": "/api", "pathType": "Prefix",
+                  "backend": { "service": { "name": "api", "port": { "number": 80 } } } },
+                { "path": "/v2", "pathType": "Prefix",
+                  "backend": { "service": { "name": "api", "port": { "number": 80 } } } },
+                { "path": "/old", "pathType": "Prefix",
+                  "backend": { "service": { "name": "legacy", "port": { "number": 80 } } } }
+            ] } }] }
+        }))
+        .unwrap();
+
+        let text: Vec<String> = graph_lines(&ingress_node(&ingress, &objects()))
+            .into_iter()
+            .map(|l| format!("{}{} {} {}", l.prefix, l.kind, l.name, l.note.unwrap_or_default()).trim_end().to_string())
+            .collect();
+        assert_eq!(
+            text,
+            [
+                "Ingress shop shop.example.com",
+                "├── Service api ClusterIP",
+                "│   └── EndpointSlice api-x7k2p 1/2 ready",
+                "│       ├── Pod api-0 Running",
+                "│       │   ├── PersistentVolumeClaim data-api-0",
+                "│       │   ├── ConfigMap settings",
+                "│       │   └── Secret creds",
+                "│       └── Pod api-1 not found",
+                "└── Service legacy not found",
+            ]
+        );
+    }
+
+    #[test]
+    fn workloads_lead_to_the_pods_their_selector_matches() {
+        let selector =
+            LabelSelector { match_labels: Some([("app".into(), "jobs".into())].into()), ..Default::default() };
+        let node = workload_node("Deployment", "worker", Some(&selector), &objects());
+        assert_eq!(node.note.as_deref(), Some("1 pod"));
+        assert_eq!(node.children[0].name, "worker");
+        assert!(workload_node("Deployment", "worker", None, &objects()).children.is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::actions::ActionExecutor;
+use crate::network_policy::selector_matches;
+
+/// Set on static pods' API mirrors; the kubelet owns them, so they cannot be evicted.
+const MIRROR_ANNOTATION: &str = "kubernetes.io/config.mirror";
+
+/// Waits between evictions a PodDisruptionBudget refused, about a minute in all.
+const EVICTION_BACKOFF: [Duration; 6] = [
+    Duration::from_secs(1),
+    Duration::from_secs(2),
+    Duration::from_secs(4),
+    Duration::from_secs(8),
+    Duration::from_secs(15),
+    Duration::from_secs(30),
+];
+
+/// Pods a drain evicts besides those a controller recreates, as `kubectl drain` flags them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DrainOptions {
+    /// Also evict pods without a controller (`--force`).
+    pub force: bool,
+    /// Also evict pods using `emptyDir` volumes (`--delete-emptydir-data`).
+    pub delete_emptydir_data: bool,
+}
+
+/// What draining a node would do: the pods it evicts and the budgets those evictions break.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrainPlan {
+    pub node: String,
+    /// `(namespace, name)` of every pod the drain evicts.
+    pub pods: Vec<(String, String)>,
+    /// DaemonSet and mirror pods left in place.
+    pub skipped: usize,
+    /// `(namespace, name)` of pods without a controller, left in place unless forced.
+    pub unmanaged: Vec<(String, String)>,
+    /// `(namespace, name)` of pods with `emptyDir` volumes, left in place unless their data may go.
+    pub local_data: Vec<(String, String)>,
+    pub violations: Vec<BudgetViolation>,
+}
+
+/// A PodDisruptionBudget that would refuse some of a drain's evictions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetViolation {
+    pub namespace: String,
+    pub name: String,
+    pub allowed: i32,
+    /// The running pods covered by the budget that the drain evicts.
+    pub pods: Vec<String>,
+}
+
+impl BudgetViolation {
+    pub fn describe(&self) -> String {
+        format!(
+            "{}/{} allows {} disruption(s) but the drain evicts {}: {}",
+            self.namespace,
+            self.name,
+            self.allowed,
+            self.pods.len(),
+            self.pods.join(", ")
+        )
+    }
+}
+
+/// Lists the pods on `node` and every PodDisruptionBudget, and plans the drain.
+pub async fn plan_drain(client: Client, node: &str, options: DrainOptions) -> Result<DrainPlan> {
+    let on_node = ListParams::default().fields(&format!("spec.nodeName={node}"));
+    let pods = Api::<Pod>::all(client.clone()).list(&on_node).await?.items;
+    let budgets = Api::<PodDisruptionBudget>::all(client).list(&ListParams::default()).await?.items;
+    Ok(drain_plan(node, &pods, &budgets, options))
+}
+
+pub fn drain_plan(node: &str, pods: &[Pod], budgets: &[PodDisruptionBudget], options: DrainOptions) -> DrainPlan {
+    let (evictable, skipped): (Vec<&Pod>, Vec<&Pod>) = pods.iter().partition(|p| is_evictable(p));
+    let unmanaged: Vec<&Pod> = evictable.iter().copied().filter(|p| !is_finished(p) && !has_controller(p)).collect();
+    let local_data: Vec<&Pod> = evictable.iter().copied().filter(|p| !is_finished(p) && has_empty_dir(p)).collect();
+    let evicted: Vec<&Pod> = evictable
+        .into_iter()
+        .filter(|p| options.force || !unmanaged.contains(p))
+        .filter(|p| options.delete_emptydir_data || !local_data.contains(p))
+        .collect();
+    DrainPlan {
+        node: node.to_string(),
+        pods: evicted.iter().map(|p| pod_key(p)).collect(),
+        skipped: skipped.len(),
+        unmanaged: if options.force { Vec::new() } else { unmanaged.iter().map(|p| pod_key(p)).collect() },
+        local_data: if options.delete_emptydir_data {
+            Vec::new()
+        } else {
+            local_data.iter().map(|p| pod_key(p)).collect()
+        },
+        violations: budget_violations(&evicted, budgets),
+    }
+}
+
+fn pod_key(pod: &Pod) -> (String, String) {
+    (pod.metadata.namespace.clone().unwrap_or_default(), pod.metadata.name.clone().unwrap_or_default())
+}
+
+/// Evicts a pod, retrying with backoff while a PodDisruptionBudget refuses it.
+pub async fn evict(executor: &ActionExecutor, namespace: &str, name: &str) -> Result<()> {
+    retry_while_refused(&EVICTION_BACKOFF, || executor.evict_pod(name, namespace)).await
+}
+
+/// Runs `attempt`, waiting out each backoff step while the API server answers 429.
+async fn retry_while_refused<F, Fut>(backoff: &[Duration], mut attempt: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut waits = backoff.iter();
+    loop {
+        match attempt().await {
+            Err(e) if is_refused(&e) => match waits.next() {
+                Some(wait) => tokio::time::sleep(*wait).await,
+                None => return Err(e),
+            },
+            result => return result,
+        }
+    }
+}
+
+fn is_refused(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<kube::Error>(), Some(kube::Error::Api(status)) if status.code == 429)
+}
+
+/// Whether a drain evicts this pod; DaemonSet pods would be recreated in place and mirror pods
+/// cannot be evicted.
+pub fn is_evictable(pod: &Pod) -> bool {
+    let daemon = pod.metadata.owner_references.iter().flatten().any(|o| o.kind == "DaemonSet");
+    let mirror = pod.metadata.annotations.as_ref().is_some_and(|a| a.contains_key(MIRROR_ANNOTATION));
+    !daemon && !mirror
+}
+
+/// Whether some controller would recreate the pod elsewhere once it is evicted.
+fn has_controller(pod: &Pod) -> bool {
+    pod.metadata.owner_references.iter().flatten().any(|o| o.controller == Some(true))
+}
+
+fn has_empty_dir(pod: &Pod) -> bool {
+    pod.spec.iter().flat_map(|s| s.volumes.iter().flatten()).any(|v| v.empty_dir.is_some())
+}
+
+/// Succeeded and failed pods lose nothing by going, so they are evicted either way.
+fn is_finished(pod: &Pod) -> bool {
+    matches!(pod.status.as_ref().and_then(|s| s.phase.as_deref()), Some("Succeeded" | "Failed"))
+}
+
+/// Budgets whose `disruptionsAllowed` is lower than the number of running pods the drain takes
+/// from them. Pods that are not running do not count, as evicting them costs the budget nothing.
+pub fn budget_violations(pods: &[&Pod], budgets: &[PodDisruptionBudget]) -> Vec<BudgetViolation> {
+    let empty = BTreeMap::new();
+    budgets
+        .iter()
+        .filter_map(|budget| {
+            let namespace = budget.metadata.namespace.as_deref().unwrap_or_default();
+            let selector = budget.spec.as_ref()?.selector.as_ref()?;
+            let allowed = budget.status.as_ref().map(|s| s.disruptions_allowed).unwrap_or(0);
+            let covered: Vec<String> = pods
+                .iter()
+                .filter(|p| p.metadata.namespace.as_deref() == Some(namespace))
+                .filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running"))
+                .filter(|p| selector_matches(selector, p.metadata.labels.as_ref().unwrap_or(&empty)))
+                .map(|p| p.metadata.name.clone().unwrap_or_default())
+                .collect();
+            (covered.len() > allowed.max(0) as usize).then(|| BudgetViolation {
+                namespace: namespace.to_string(),
+                name: budget.metadata.name.clone().unwrap_or_default(),
+                allowed,
+                pods: covered,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(name: &str, labels: serde_json::Value, owner: Option<&str>) -> Pod {
+        let owners = owner
+            .map(|kind| {
+                serde_json::json!([{ "apiVersion": "apps/v1", "kind": kind, "name": "x", "uid": "u", "controller": true }])
+            })
+            .unwrap_or(serde_json::json!([]));
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": "web", "labels": labels, "ownerReferences": owners },
+            "status": { "phase": "Running" }
+        }))
+        .unwrap()
+    }
+
+    fn budget(name: &str, app: &str, allowed: i32) -> PodDisruptionBudget {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": "web" },
+            "spec": { "selector": { "matchLabels": { "app": app } } },
+            "status": { "disruptionsAllowed": allowed, "currentHealthy": 2, "desiredHealthy": 2, "expectedPods": 2 }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn drain_plan_skips_daemon_set_pods_and_flags_exhausted_budgets() {
+        let pods = [
+            pod("web-1", serde_json::json!({ "app": "web" }), Some("ReplicaSet")),
+            pod("web-2", serde_json::json!({ "app": "web" }), Some("ReplicaSet")),
+            pod("api-1", serde_json::json!({ "app": "api" }), Some("ReplicaSet")),
+            pod("fluentd", serde_json::json!({ "app": "web" }), Some("DaemonSet")),
+        ];
+        let budgets = [budget("web-pdb", "web", 1), budget("api-pdb", "api", 1)];
+
+        let plan = drain_plan("node-1", &pods, &budgets, DrainOptions::default());
+        assert_eq!(plan.pods.len(), 3);
+        assert_eq!(plan.skipped, 1);
+        assert_eq!(
+            plan.violations,
+            vec![BudgetViolation {
+                namespace: "web".into(),
+                name: "web-pdb".into(),
+                allowed: 1,
+                pods: vec!["web-1".into(), "web-2".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn budgets_in_other_namespaces_do_not_apply() {
+        let pods = [pod("web-1", serde_json::json!({ "app": "web" }), Some("ReplicaSet"))];
+        let mut other = budget("web-pdb", "web", 0);
+        other.metadata.namespace = Some("staging".into());
+        assert!(drain_plan("node-1", &pods, &[other], DrainOptions::default()).violations.is_empty());
+    }
+
+    #[test]
+    fn unmanaged_and_empty_dir_pods_stay_unless_enabled() {
+        let mut scratch = pod("cache-1", serde_json::json!({}), Some("ReplicaSet"));
+        scratch.spec = Some(
+            serde_json::from_value(serde_json::json!({
+                "containers": [],
+                "volumes": [{ "name": "tmp", "emptyDir": {} }]
+            }))
+            .unwrap(),
+        );
+        let mut finished = pod("job-run", serde_json::json!({}), None);
+        finished.status.as_mut().unwrap().phase = Some("Succeeded".into());
+        let pods = [
+            pod("web-1", serde_json::json!({}), Some("ReplicaSet")),
+            pod("bare", serde_json::json!({}), None),
+            scratch,
+            finished,
+        ];
+
+        let plan = drain_plan("node-1", &pods, &[], DrainOptions::default());
+        let names: Vec<&str> = plan.pods.iter().map(|(_, name)| name.as_str()).collect();
+        assert_eq!(names, ["web-1", "job-run"]);
+        assert_eq!(plan.unmanaged, [("web".to_string(), "bare".to_string())]);
+        assert_eq!(plan.local_data, [("web".to_string(), "cache-1".to_string())]);
+
+        let all = drain_plan("node-1", &pods, &[], DrainOptions { force: true, delete_emptydir_data: true });
+        assert_eq!(all.pods.len(), 4);
+        assert!(all.unmanaged.is_empty() && all.local_data.is_empty());
+    }
+
+    fn refused() -> anyhow::Error {
+        kube::Error::Api(kube::core::Status::failure("Cannot evict pod", "TooManyRequests").with_code(429).boxed())
+            .into()
+    }
+
+    const SHORT_BACKOFF: [Duration; 2] = [Duration::from_millis(1), Duration::from_millis(1)];
+
+    #[tokio::test]
+    async fn refused_evictions_are_retried_until_the_backoff_runs_out() {
+        let mut calls = 0;
+        let result = retry_while_refused(&SHORT_BACKOFF, || {
+            calls += 1;
+            let outcome = if calls < 3 { Err(refused()) } else { Ok(()) };
+            async move { outcome }
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+
+        let mut calls = 0;
+        let result = retry_while_refused(&SHORT_BACKOFF, || {
+            calls += 1;
+            async { Err(refused()) }
+        })
+        .await;
+        assert!(is_refused(&result.unwrap_err()));
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn other_eviction_errors_are_not_retried() {
+        let mut calls = 0;
+        let result = retry_while_refused(&SHORT_BACKOFF, || {
+            calls += 1;
+            async { Err(anyhow::anyhow!("pods \"web-1\" not found")) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
@@ -87,6 +87,19 @@ pub struct ChannelReader {
     pos: usize,
 }
 
+impl ChannelReader {
+    /// Blocks until more output arrives; `None` once the remote process has exited.
+    pub fn recv(&mut self) -> Option<Vec<u8>> {
+        if self.pos < self.pending.len() {
+            let rest = self.pending.split_off(self.pos);
+            self.pos = 0;
+            self.pending.clear();
+            return Some(rest);
+        }
+        self.rx.recv().ok()
+    }
+}
+
 impl std::io::Read for ChannelReader {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         if self.pos >= self.pending.len() {
@@ -193,6 +206,20 @@ mod tests {
         assert_eq!(&buf[..n], b"d");
     }
 
+    #[test]
+    fn channel_reader_recv_returns_unread_rest_then_blocks_for_more() {
+        let (tx, rx) = std::sync::mpsc::channel::<Vec<u8>>();
+        let mut reader = ChannelReader { rx, pending: Vec::new(), pos: 0 };
+        tx.send(b"hello world".to_vec()).unwrap();
+        let mut buf = [0u8; 6];
+        assert_eq!(reader.read(&mut buf).unwrap(), 6);
+        assert_eq!(reader.recv().as_deref(), Some(&b"world"[..]));
+        tx.send(b"!".to_vec()).unwrap();
+        drop(tx);
+        assert_eq!(reader.recv().as_deref(), Some(&b"!"[..]));
+        assert_eq!(reader.recv(), None);
+    }
+
     #[test]
     fn channel_writer_sends_data() {
         let (tx, mut rx) = mpsc::unbounded_channel::<Vec<u8>>();
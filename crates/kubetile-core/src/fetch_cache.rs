@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use kube::core::GroupVersionKind;
+use kube::Resource;
+use tokio::sync::OnceCell;
+
+/// Which rendering of an object was fetched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchView {
+    Yaml,
+    Describe,
+}
+
+/// One object rendered one way; cluster-scoped objects have an empty namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FetchKey {
+    pub gvk: GroupVersionKind,
+    pub namespace: String,
+    pub name: String,
+    pub view: FetchView,
+}
+
+impl FetchKey {
+    pub fn of<K: Resource<DynamicType = ()>>(namespace: &str, name: &str, view: FetchView) -> Self {
+        Self {
+            gvk: GroupVersionKind::gvk(&K::group(&()), &K::version(&()), &K::kind(&())),
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            view,
+        }
+    }
+}
+
+/// Rendered text and the `resourceVersion` of the object it was rendered from, if it only
+/// depends on that object.
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    pub resource_version: Option<String>,
+    pub text: String,
+}
+
+/// Entries kept at most; the one fetched longest ago makes room for a new one.
+const MAX_SLOTS: usize = 256;
+
+/// A finished fetch, stamped when it completed so a slow fetch starts its TTL fresh.
+struct Entry {
+    fetched_at: Instant,
+    fetched: Fetched,
+}
+
+type Slot = Arc<OnceCell<Entry>>;
+
+enum Lookup {
+    Live(Slot),
+    Expired(Fetched),
+}
+
+/// Short-lived cache of YAML and describe output. Callers asking for the same object while a
+/// fetch is in flight wait for that fetch instead of starting their own; a result older than the
+/// TTL is reused as long as the object's `resourceVersion` has not moved. A zero TTL disables it.
+#[derive(Clone)]
+pub struct FetchCache {
+    ttl: Duration,
+    slots: Arc<Mutex<HashMap<FetchKey, Slot>>>,
+}
+
+impl Default for FetchCache {
+    fn default() -> Self {
+        Self::new(Duration::ZERO)
+    }
+}
+
+impl FetchCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self { ttl, slots: Arc::default() }
+    }
+
+    /// The text for `key`, from the cache or from `fetch`. `current_version` is only awaited for
+    /// an expired entry and names the object's `resourceVersion` on the server right now.
+    pub async fn get<V, F>(&self, key: FetchKey, current_version: V, fetch: F) -> Result<String>
+    where
+        V: Future<Output = Result<Option<String>>>,
+        F: Future<Output = Result<Fetched>>,
+    {
+        if self.ttl.is_zero() {
+            return Ok(fetch.await?.text);
+        }
+        let slot = match self.lookup(&key) {
+            Lookup::Live(slot) => slot,
+            Lookup::Expired(previous) => {
+                let unchanged = match previous.resource_version.as_deref() {
+                    Some(version) => current_version.await.ok().flatten().as_deref() == Some(version),
+                    None => false,
+                };
+                if unchanged {
+                    let text = previous.text.clone();
+                    self.renew(&key, Some(previous));
+                    return Ok(text);
+                }
+                self.renew(&key, None)
+            }
+        };
+        let entry = slot.get_or_try_init(|| async { Ok(Entry { fetched: fetch.await?, fetched_at: Instant::now() }) });
+        match entry.await {
+            Ok(entry) => Ok(entry.fetched.text.clone()),
+            Err(e) => {
+                self.forget_failed(&key, &slot);
+                Err(e)
+            }
+        }
+    }
+
+    /// Drops everything, e.g. after a mutation or when the cluster changes.
+    pub fn clear(&self) {
+        self.slots.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn lookup(&self, key: &FetchKey) -> Lookup {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(slot) = slots.get(key) else {
+            return Lookup::Live(self.insert(&mut slots, key, Slot::default()));
+        };
+        match slot.get() {
+            Some(entry) if entry.fetched_at.elapsed() >= self.ttl => Lookup::Expired(entry.fetched.clone()),
+            _ => Lookup::Live(slot.clone()),
+        }
+    }
+
+    /// Starts a new TTL for `key`, unless another caller already did.
+    fn renew(&self, key: &FetchKey, fetched: Option<Fetched>) -> Slot {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(slot) = slots.get(key) {
+            if slot.get().is_none_or(|entry| entry.fetched_at.elapsed() < self.ttl) {
+                return slot.clone();
+            }
+        }
+        let entry = fetched.map(|fetched| Entry { fetched, fetched_at: Instant::now() });
+        self.insert(&mut slots, key, Arc::new(OnceCell::new_with(entry)))
+    }
+
+    /// Stores `slot` under `key`. Expired entries that cannot be revalidated by their
+    /// `resourceVersion` go first, then the oldest entry if the cache is still full.
+    fn insert(&self, slots: &mut HashMap<FetchKey, Slot>, key: &FetchKey, slot: Slot) -> Slot {
+        slots.retain(|_, slot| {
+            slot.get().is_none_or(|e| e.fetched.resource_version.is_some() || e.fetched_at.elapsed() < self.ttl)
+        });
+        if slots.len() >= MAX_SLOTS && !slots.contains_key(key) {
+            let oldest = slots
+                .iter()
+                .filter_map(|(key, slot)| Some((key, slot.get()?.fetched_at)))
+                .min_by_key(|(_, fetched_at)| *fetched_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                slots.remove(&oldest);
+            }
+        }
+        slots.insert(key.clone(), slot.clone());
+        slot
+    }
+
+    /// Removes the slot of a failed fetch so it does not linger empty; a retry starts a new one.
+    fn forget_failed(&self, key: &FetchKey, failed: &Slot) {
+        let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+        if slots.get(key).is_some_and(|slot| Arc::ptr_eq(slot, failed) && slot.get().is_none()) {
+            slots.remove(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use k8s_openapi::api::core::v1::Pod;
+
+    use super::*;
+
+    fn key() -> FetchKey {
+        FetchKey::of::<Pod>("default", "web-0", FetchView::Yaml)
+    }
+
+    async fn counted(calls: &AtomicUsize, version: &str) -> Result<Fetched> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::task::yield_now().await;
+        Ok(Fetched { resource_version: Some(version.into()), text: format!("rv {version}") })
+    }
+
+    async fn version(version: &str) -> Result<Option<String>> {
+        Ok(Some(version.into()))
+    }
+
+    #[tokio::test]
+    async fn concurrent_fetches_of_one_object_share_a_request() {
+        let cache = FetchCache::new(Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+        let (a, b) = tokio::join!(
+            cache.get(key(), version("1"), counted(&calls, "1")),
+            cache.get(key(), version("1"), counted(&calls, "1")),
+        );
+        assert_eq!((a.unwrap(), b.unwrap()), ("rv 1".into(), "rv 1".into()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        cache.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        let other = FetchKey::of::<Pod>("default", "web-0", FetchView::Describe);
+        cache.get(other, version("1"), counted(&calls, "1")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn expired_entries_are_kept_while_the_resource_version_holds() {
+        let cache = FetchCache::new(Duration::from_millis(1));
+        let calls = AtomicUsize::new(0);
+        cache.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let text = cache.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        assert_eq!((text.as_str(), calls.load(Ordering::SeqCst)), ("rv 1", 1));
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let text = cache.get(key(), version("2"), counted(&calls, "2")).await.unwrap();
+        assert_eq!((text.as_str(), calls.load(Ordering::SeqCst)), ("rv 2", 2));
+    }
+
+    #[tokio::test]
+    async fn failures_and_a_zero_ttl_are_not_cached() {
+        let cache = FetchCache::new(Duration::from_secs(60));
+        let failed = cache.get(key(), version("1"), async { Err(anyhow::anyhow!("boom")) }).await;
+        assert!(failed.is_err());
+        let calls = AtomicUsize::new(0);
+        cache.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(cache.slots.lock().unwrap().len(), 1, "the failed fetch left no slot behind");
+
+        let off = FetchCache::default();
+        off.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        off.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn the_ttl_starts_when_a_slow_fetch_finishes() {
+        let cache = FetchCache::new(Duration::from_millis(200));
+        let calls = AtomicUsize::new(0);
+        let slow = async {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            counted(&calls, "1").await
+        };
+        cache.get(key(), version("1"), slow).await.unwrap();
+        let text = cache.get(key(), async { panic!("a live entry is not revalidated") }, counted(&calls, "2")).await;
+        assert_eq!((text.unwrap().as_str(), calls.load(Ordering::SeqCst)), ("rv 1", 1));
+    }
+
+    #[tokio::test]
+    async fn the_cache_stays_bounded() {
+        let cache = FetchCache::new(Duration::from_secs(60));
+        let calls = AtomicUsize::new(0);
+        for i in 0..MAX_SLOTS + 10 {
+            let key = FetchKey::of::<Pod>("default", &format!("web-{i}"), FetchView::Yaml);
+            cache.get(key, version("1"), counted(&calls, "1")).await.unwrap();
+        }
+        assert_eq!(cache.slots.lock().unwrap().len(), MAX_SLOTS);
+        let newest = FetchKey::of::<Pod>("default", &format!("web-{}", MAX_SLOTS + 9), FetchView::Yaml);
+        assert!(cache.slots.lock().unwrap().contains_key(&newest));
+
+        let expired = FetchCache::new(Duration::from_millis(1));
+        let describe = |name: &str| FetchKey::of::<Pod>("default", name, FetchView::Describe);
+        let unversioned = async { Ok(Fetched { resource_version: None, text: "describe".into() }) };
+        expired.get(describe("web-0"), version("1"), unversioned).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        expired.get(key(), version("1"), counted(&calls, "1")).await.unwrap();
+        assert!(!expired.slots.lock().unwrap().contains_key(&describe("web-0")), "nothing could revalidate it");
+    }
+}
@@ -0,0 +1,248 @@
+use std::cmp::Reverse;
+use std::collections::BTreeSet;
+
+use futures::future::join_all;
+use k8s_openapi::api::core::v1::{Container, ContainerStatus, Pod, PodSpec};
+
+use crate::registry::list_tags;
+use crate::resource::DetailSection;
+
+/// Newer tags listed per image before the rest is left out.
+const MAX_NEWER_TAGS: usize = 3;
+
+/// The image a container runs, or the one a pod template asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContainerImage {
+    pub container: String,
+    pub init: bool,
+    pub image: String,
+    pub pull_policy: String,
+    /// Digest the kubelet pulled, from the container status; `None` for templates and images
+    /// that were not pulled yet.
+    pub digest: Option<String>,
+}
+
+impl ContainerImage {
+    fn describe(&self) -> String {
+        match &self.digest {
+            Some(digest) => format!("{} ({}) {}", self.image, self.pull_policy, short_digest(digest)),
+            None => format!("{} ({})", self.image, self.pull_policy),
+        }
+    }
+}
+
+/// Images of a pod's init and app containers, with the digests its container statuses report.
+pub fn pod_images(pod: &Pod) -> Vec<ContainerImage> {
+    let Some(spec) = pod.spec.as_ref() else { return Vec::new() };
+    let status = pod.status.as_ref();
+    let init_statuses = status.and_then(|s| s.init_container_statuses.as_deref()).unwrap_or_default();
+    let statuses = status.and_then(|s| s.container_statuses.as_deref()).unwrap_or_default();
+    let init = spec.init_containers.iter().flatten().map(|c| container_image(c, true, init_statuses));
+    init.chain(spec.containers.iter().map(|c| container_image(c, false, statuses))).collect()
+}
+
+/// Images a pod template asks for; templates have no digests.
+pub fn template_images(spec: Option<&PodSpec>) -> Vec<ContainerImage> {
+    let Some(spec) = spec else { return Vec::new() };
+    let init = spec.init_containers.iter().flatten().map(|c| container_image(c, true, &[]));
+    init.chain(spec.containers.iter().map(|c| container_image(c, false, &[]))).collect()
+}
+
+fn container_image(container: &Container, init: bool, statuses: &[ContainerStatus]) -> ContainerImage {
+    let image = container.image.clone().unwrap_or_default();
+    let pull_policy = container.image_pull_policy.clone().unwrap_or_else(|| default_pull_policy(&image).into());
+    let digest = statuses.iter().find(|s| s.name == container.name).and_then(|s| image_digest(&s.image_id));
+    ContainerImage { container: container.name.clone(), init, image, pull_policy, digest }
+}
+
+/// The policy the API server fills in when a container sets none.
+fn default_pull_policy(image: &str) -> &'static str {
+    let reference = ImageRef::parse(image);
+    match reference.tag.as_deref() {
+        _ if reference.digest.is_some() => "IfNotPresent",
+        None | Some("latest") => "Always",
+        Some(_) => "IfNotPresent",
+    }
+}
+
+/// The `sha256:…` part of a status `imageID` such as `docker.io/library/nginx@sha256:…`.
+fn image_digest(image_id: &str) -> Option<String> {
+    let digest = image_id.rsplit_once('@').map_or(image_id, |(_, digest)| digest);
+    digest.starts_with("sha256:").then(|| digest.to_string())
+}
+
+fn short_digest(digest: &str) -> String {
+    let hex = digest.trim_start_matches("sha256:");
+    format!("@sha256:{}", &hex[..hex.len().min(12)])
+}
+
+/// An image reference split into the parts the registry API needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRef {
+    /// Registry host; Docker Hub images name `docker.io`.
+    pub registry: String,
+    /// Repository path, with Docker Hub's `library/` prefix for official images.
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+}
+
+impl ImageRef {
+    pub fn parse(image: &str) -> Self {
+        let (name, digest) = match image.split_once('@') {
+            Some((name, digest)) => (name, Some(digest.to_string())),
+            None => (image, None),
+        };
+        let last_slash = name.rfind('/').map_or(0, |i| i + 1);
+        let (name, tag) = match name[last_slash..].rfind(':') {
+            Some(i) => (&name[..last_slash + i], Some(name[last_slash + i + 1..].to_string())),
+            None => (name, None),
+        };
+        let (registry, repository) = match name.split_once('/') {
+            Some((host, rest)) if host.contains(['.', ':']) || host == "localhost" => (host, rest.to_string()),
+            _ => ("docker.io", name.to_string()),
+        };
+        let repository = if registry == "docker.io" && !repository.contains('/') {
+            format!("library/{repository}")
+        } else {
+            repository
+        };
+        Self { registry: registry.to_string(), repository, tag, digest }
+    }
+}
+
+/// A tag read as a version: `v1.25.3-alpine` has the numbers 1, 25, 3 and the suffix `alpine`.
+#[derive(Debug, PartialEq, Eq)]
+struct TagVersion<'a> {
+    prefix: bool,
+    numbers: Vec<u64>,
+    suffix: &'a str,
+}
+
+fn tag_version(tag: &str) -> Option<TagVersion<'_>> {
+    let (prefix, rest) = match tag.strip_prefix('v') {
+        Some(rest) => (true, rest),
+        None => (false, tag),
+    };
+    let (core, suffix) = rest.split_once('-').unwrap_or((rest, ""));
+    let numbers = core.split('.').map(|n| n.parse().ok()).collect::<Option<Vec<u64>>>()?;
+    (!numbers.is_empty()).then_some(TagVersion { prefix, numbers, suffix })
+}
+
+/// Tags of the same shape as `current` (same `v` prefix, number of parts and suffix) with a
+/// higher version, newest first.
+pub fn newer_tags(current: &str, tags: &[String]) -> Vec<String> {
+    let Some(current) = tag_version(current) else { return Vec::new() };
+    let mut newer: Vec<(Vec<u64>, &String)> = tags
+        .iter()
+        .filter_map(|tag| Some((tag_version(tag)?, tag)))
+        .filter(|(v, _)| v.prefix == current.prefix && v.suffix == current.suffix)
+        .filter(|(v, _)| v.numbers.len() == current.numbers.len() && v.numbers > current.numbers)
+        .map(|(v, tag)| (v.numbers, tag))
+        .collect();
+    newer.sort_by_key(|(numbers, _)| Reverse(numbers.clone()));
+    newer.into_iter().map(|(_, tag)| tag.clone()).collect()
+}
+
+/// One field per container: image, pull policy and the digest it runs.
+pub fn images_section(images: &[ContainerImage]) -> DetailSection {
+    let fields = images
+        .iter()
+        .map(|i| {
+            let name = if i.init { format!("{} (init)", i.container) } else { i.container.clone() };
+            (name, i.describe())
+        })
+        .collect();
+    DetailSection { title: "Images".into(), fields }
+}
+
+/// Looks up newer tags of every distinct image in its registry.
+pub async fn updates_section(images: &[ContainerImage]) -> DetailSection {
+    let distinct: BTreeSet<&str> = images.iter().map(|i| i.image.as_str()).collect();
+    let fields = join_all(distinct.into_iter().map(|image| async move {
+        let reference = ImageRef::parse(image);
+        let status = match (&reference.tag, &reference.digest) {
+            (_, Some(_)) => "pinned to a digest".to_string(),
+            (Some(tag), None) if tag_version(tag).is_some() => match list_tags(&reference).await {
+                Ok(tags) => match newer_tags(tag, &tags) {
+                    newer if newer.is_empty() => "up to date".to_string(),
+                    newer if newer.len() > MAX_NEWER_TAGS => {
+                        format!(
+                            "newer: {} (+{} more)",
+                            newer[..MAX_NEWER_TAGS].join(", "),
+                            newer.len() - MAX_NEWER_TAGS
+                        )
+                    }
+                    newer => format!("newer: {}", newer.join(", ")),
+                },
+                Err(e) => format!("lookup failed: {e}"),
+            },
+            _ => "not a version tag".to_string(),
+        };
+        (image.to_string(), status)
+    }))
+    .await;
+    DetailSection { title: "Image Updates".into(), fields }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn image_refs_default_to_docker_hub() {
+        assert_eq!(
+            ImageRef::parse("nginx:1.25"),
+            ImageRef {
+                registry: "docker.io".into(),
+                repository: "library/nginx".into(),
+                tag: Some("1.25".into()),
+                digest: None
+            }
+        );
+        let ghcr = ImageRef::parse("ghcr.io/org/app@sha256:abc");
+        assert_eq!((ghcr.registry.as_str(), ghcr.repository.as_str()), ("ghcr.io", "org/app"));
+        assert_eq!((ghcr.tag, ghcr.digest.as_deref()), (None, Some("sha256:abc")));
+        let local = ImageRef::parse("localhost:5000/team/api:v2");
+        assert_eq!((local.registry.as_str(), local.tag.as_deref()), ("localhost:5000", Some("v2")));
+        assert_eq!(ImageRef::parse("bitnami/redis").repository, "bitnami/redis");
+    }
+
+    #[test]
+    fn newer_tags_keep_the_shape_of_the_current_tag() {
+        let available = tags(&["1.25.3", "1.25.4", "1.27.0", "1.26.0-alpine", "1.26", "latest", "v1.30.0", "1.9.9"]);
+        assert_eq!(newer_tags("1.25.3", &available), ["1.27.0", "1.25.4"]);
+        assert_eq!(newer_tags("1.25.0-alpine", &available), ["1.26.0-alpine"]);
+        assert!(newer_tags("latest", &available).is_empty());
+    }
+
+    #[test]
+    fn pod_images_pair_containers_with_their_digests() {
+        let pod: Pod = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "api-0" },
+            "spec": {
+                "initContainers": [{ "name": "migrate", "image": "ghcr.io/org/migrate:v3" }],
+                "containers": [{ "name": "api", "image": "nginx", "imagePullPolicy": "Always" }]
+            },
+            "status": {
+                "containerStatuses": [{
+                    "name": "api", "image": "nginx:latest", "ready": true, "restartCount": 0,
+                    "imageID": "docker.io/library/nginx@sha256:0123456789abcdef0123"
+                }]
+            }
+        }))
+        .unwrap();
+        let section = images_section(&pod_images(&pod));
+        assert_eq!(
+            section.fields,
+            vec![
+                ("migrate (init)".to_string(), "ghcr.io/org/migrate:v3 (IfNotPresent)".to_string()),
+                ("api".to_string(), "nginx (Always) @sha256:0123456789ab".to_string()),
+            ]
+        );
+    }
+}
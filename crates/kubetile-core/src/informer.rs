@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use std::time::Duration;
 
 use futures::StreamExt;
+use kube::api::ListParams;
 use kube::runtime::watcher::{self, Event};
 use kube::{Api, Resource, ResourceExt};
 use serde::de::DeserializeOwned;
@@ -10,23 +11,66 @@ use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
+use crate::connection::is_auth_expired;
 use crate::resource::ResourceSummary;
 
 #[derive(Debug, Clone)]
 pub enum ResourceEvent<S> {
     Updated(Vec<S>),
+    /// Partial snapshot emitted after each page of the initial list; more pages follow. `expected`
+    /// is the size of the whole list once the server has reported it.
+    Loading {
+        items: Vec<S>,
+        expected: Option<usize>,
+    },
+    /// The watch stream failed. The watcher re-lists and re-watches after a backoff, and sends a
+    /// fresh `Updated` once it recovers; receivers may keep showing the last snapshot meanwhile.
     Error(String),
+    /// The API server answered 401: the session's credentials expired. Otherwise handled like
+    /// `Error`; the watcher keeps retrying until they are refreshed.
+    Unauthorized(String),
 }
 
+/// Objects requested per LIST page when no page size is configured.
+pub const DEFAULT_PAGE_SIZE: u32 = 500;
+
 pub struct ResourceWatcher {
     cancel: CancellationToken,
 }
 
-fn backoff_duration(attempt: u32) -> Duration {
+/// Delay before reconnect attempt `attempt`: doubles from 1s and is capped at 30s.
+pub fn backoff_duration(attempt: u32) -> Duration {
     let secs = 2u64.pow(attempt.min(5)).min(30);
     Duration::from_secs(secs)
 }
 
+/// Number of objects the watch's initial list will deliver, from a one-item LIST whose
+/// `remainingItemCount` covers the rest. `None` when the server does not report it.
+async fn list_total<K>(api: Api<K>, config: &watcher::Config) -> Option<usize>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug,
+{
+    let mut params = ListParams::default().limit(1);
+    params.label_selector = config.label_selector.clone();
+    params.field_selector = config.field_selector.clone();
+    let list = api.list_metadata(&params).await.ok()?;
+    match list.metadata.remaining_item_count {
+        Some(remaining) => Some(list.items.len() + usize::try_from(remaining).ok()?),
+        None if list.metadata.continue_.as_deref().unwrap_or_default().is_empty() => Some(list.items.len()),
+        None => None,
+    }
+}
+
+/// A page size of 0 disables pagination and lists everything in a single request.
+fn watcher_config(page_size: u32) -> watcher::Config {
+    let config = watcher::Config::default();
+    if page_size == 0 {
+        watcher::Config { page_size: None, ..config }
+    } else {
+        config.page_size(page_size)
+    }
+}
+
 impl ResourceWatcher {
     /// Watch any Kubernetes resource type and emit summary snapshots.
     ///
@@ -42,18 +86,69 @@ impl ResourceWatcher {
         K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
         S: ResourceSummary + From<K> + Clone + Send + 'static,
     {
+        Self::watch_with_page_size(api, tx, DEFAULT_PAGE_SIZE)
+    }
+
+    /// Like [`ResourceWatcher::watch`], but lists in pages of `page_size` objects (0 = unpaginated).
+    ///
+    /// Until the first complete snapshot is sent, a [`ResourceEvent::Loading`] is emitted after each
+    /// page so large namespaces fill in incrementally instead of blocking on the whole list. Its
+    /// expected total comes from a one-item LIST sent alongside the first page.
+    pub fn watch_with_page_size<K, S>(api: Api<K>, tx: mpsc::Sender<ResourceEvent<S>>, page_size: u32) -> Self
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+        S: ResourceSummary + From<K> + Clone + Send + 'static,
+    {
+        Self::watch_with_config(api, tx, watcher_config(page_size))
+    }
+
+    /// Like [`ResourceWatcher::watch_with_page_size`], but only watches objects matching
+    /// `label_selector`, e.g. `app=api,tier=web`, and `field_selector`, e.g. `status.phase=Pending`.
+    pub fn watch_with_selectors<K, S>(
+        api: Api<K>,
+        tx: mpsc::Sender<ResourceEvent<S>>,
+        page_size: u32,
+        label_selector: Option<&str>,
+        field_selector: Option<&str>,
+    ) -> Self
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+        S: ResourceSummary + From<K> + Clone + Send + 'static,
+    {
+        let mut config = watcher_config(page_size);
+        if let Some(selector) = label_selector {
+            config = config.labels(selector);
+        }
+        if let Some(selector) = field_selector {
+            config = config.fields(selector);
+        }
+        Self::watch_with_config(api, tx, config)
+    }
+
+    fn watch_with_config<K, S>(api: Api<K>, tx: mpsc::Sender<ResourceEvent<S>>, config: watcher::Config) -> Self
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+        S: ResourceSummary + From<K> + Clone + Send + 'static,
+    {
+        let page_size = config.page_size.unwrap_or(0);
         let cancel = CancellationToken::new();
         let cancel_clone = cancel.clone();
 
         tokio::spawn(async move {
             let mut consecutive_failures: u32 = 0;
+            let mut synced = false;
+            let mut expected = None;
 
             'outer: loop {
-                let stream = watcher::watcher(api.clone(), watcher::Config::default());
+                let stream = watcher::watcher(api.clone(), config.clone());
                 tokio::pin!(stream);
+                let total = list_total(api.clone(), &config);
+                tokio::pin!(total);
+                let mut counting = !synced && page_size > 0 && expected.is_none();
 
                 let mut snapshot: HashMap<String, S> = HashMap::new();
                 let mut initializing = false;
+                let mut init_count: u32 = 0;
 
                 loop {
                     tokio::select! {
@@ -61,6 +156,10 @@ impl ResourceWatcher {
                             info!("Resource watcher cancelled");
                             break 'outer;
                         }
+                        count = &mut total, if counting => {
+                            counting = false;
+                            expected = count;
+                        }
                         item = stream.next() => {
                             match item {
                                 Some(Ok(event)) => {
@@ -73,6 +172,11 @@ impl ResourceWatcher {
                                                 None => summary.name().to_string(),
                                             };
                                             snapshot.insert(key, summary);
+                                            init_count += 1;
+                                            if !synced && page_size > 0 && init_count.is_multiple_of(page_size) {
+                                                let items: Vec<S> = snapshot.values().cloned().collect();
+                                                let _ = tx.send(ResourceEvent::Loading { items, expected }).await;
+                                            }
                                             false
                                         }
                                         Event::Apply(resource) => {
@@ -97,10 +201,12 @@ impl ResourceWatcher {
                                         Event::Init => {
                                             snapshot.clear();
                                             initializing = true;
+                                            init_count = 0;
                                             false
                                         }
                                         Event::InitDone => {
                                             initializing = false;
+                                            synced = true;
                                             true
                                         }
                                     };
@@ -112,7 +218,12 @@ impl ResourceWatcher {
                                 Some(Err(e)) => {
                                     warn!("Watcher stream error: {e}");
                                     consecutive_failures += 1;
-                                    let _ = tx.send(ResourceEvent::Error(e.to_string())).await;
+                                    let event = if is_auth_expired(&e) {
+                                        ResourceEvent::Unauthorized(e.to_string())
+                                    } else {
+                                        ResourceEvent::Error(e.to_string())
+                                    };
+                                    let _ = tx.send(event).await;
                                     break;
                                 }
                                 None => {
@@ -142,6 +253,79 @@ impl ResourceWatcher {
         Self { cancel }
     }
 
+    /// Watches several APIs of one kind, typically one per namespace, and emits their combined
+    /// snapshot. Snapshots are sent as `Loading` until every watch has finished its initial list.
+    /// Each API comes with the label its errors are reported under, e.g. its namespace.
+    pub fn watch_merged<K, S>(apis: Vec<(String, Api<K>)>, tx: mpsc::Sender<ResourceEvent<S>>, page_size: u32) -> Self
+    where
+        K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug + Send + 'static,
+        S: ResourceSummary + From<K> + Clone + Send + 'static,
+    {
+        let parts = apis
+            .into_iter()
+            .map(|(label, api)| {
+                let (part_tx, part_rx) = mpsc::channel(16);
+                (label, Self::watch_with_page_size(api, part_tx, page_size), part_rx)
+            })
+            .collect();
+        Self::merge(parts, tx)
+    }
+
+    /// Combines already started watchers into one, like [`Self::watch_merged`]. The parts may watch
+    /// different kinds as long as they share a summary type, e.g. every workload kind.
+    ///
+    /// A part that fails, e.g. because listing its kind is forbidden, counts as synced with the
+    /// items it had so the others still show; every snapshot is then followed by an `Error` naming
+    /// the failed parts until they recover.
+    pub fn merge<S>(
+        parts: Vec<(String, ResourceWatcher, mpsc::Receiver<ResourceEvent<S>>)>,
+        tx: mpsc::Sender<ResourceEvent<S>>,
+    ) -> Self
+    where
+        S: Clone + Send + 'static,
+    {
+        let cancel = CancellationToken::new();
+        let cancel_clone = cancel.clone();
+        let mut merged = MergedSnapshot::new(parts.iter().map(|(label, _, _)| label.clone()).collect());
+        let (merge_tx, mut merge_rx) = mpsc::channel(16);
+
+        let mut watchers = Vec::with_capacity(parts.len());
+        for (index, (_, watcher, mut part_rx)) in parts.into_iter().enumerate() {
+            watchers.push(watcher);
+            let merge_tx = merge_tx.clone();
+            tokio::spawn(async move {
+                while let Some(event) = part_rx.recv().await {
+                    if merge_tx.send((index, event)).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+
+        tokio::spawn(async move {
+            // The part watchers stop when this task ends and drops them.
+            let _watchers = watchers;
+            'merge: loop {
+                tokio::select! {
+                    _ = cancel_clone.cancelled() => {
+                        info!("Merged resource watcher cancelled");
+                        break;
+                    }
+                    item = merge_rx.recv() => {
+                        let Some((index, event)) = item else { break };
+                        for event in merged.apply(index, event) {
+                            if tx.send(event).await.is_err() {
+                                break 'merge;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Self { cancel }
+    }
+
     pub fn stop(&self) {
         self.cancel.cancel();
     }
@@ -153,6 +337,79 @@ impl Drop for ResourceWatcher {
     }
 }
 
+/// The latest snapshot of each watch feeding a merged watcher.
+struct MergedSnapshot<S> {
+    labels: Vec<String>,
+    parts: Vec<Vec<S>>,
+    synced: Vec<bool>,
+    expected: Vec<Option<usize>>,
+    /// Last error of each part that has not sent a snapshot since.
+    failed: Vec<Option<String>>,
+}
+
+impl<S: Clone> MergedSnapshot<S> {
+    fn new(labels: Vec<String>) -> Self {
+        let count = labels.len();
+        Self {
+            labels,
+            parts: vec![Vec::new(); count],
+            synced: vec![false; count],
+            expected: vec![None; count],
+            failed: vec![None; count],
+        }
+    }
+
+    fn apply(&mut self, index: usize, event: ResourceEvent<S>) -> Vec<ResourceEvent<S>> {
+        match event {
+            ResourceEvent::Updated(items) => {
+                self.parts[index] = items;
+                self.synced[index] = true;
+                self.failed[index] = None;
+            }
+            ResourceEvent::Loading { items, expected } => {
+                self.parts[index] = items;
+                self.expected[index] = expected;
+            }
+            ResourceEvent::Error(error) => {
+                self.synced[index] = true;
+                self.failed[index] = Some(error);
+            }
+            // Every part shares the session's credentials, so one rejection speaks for all.
+            ResourceEvent::Unauthorized(error) => {
+                self.synced[index] = true;
+                self.failed[index] = Some(error.clone());
+                return vec![ResourceEvent::Unauthorized(error)];
+            }
+        }
+        if self.failed.iter().all(Option::is_some) {
+            return vec![ResourceEvent::Error(self.failure())];
+        }
+        let items = self.parts.concat();
+        if !self.synced.iter().all(|&synced| synced) {
+            let expected = (0..self.parts.len())
+                .map(|i| if self.synced[i] { Some(self.parts[i].len()) } else { self.expected[i] })
+                .sum();
+            return vec![ResourceEvent::Loading { items, expected }];
+        }
+        let mut events = vec![ResourceEvent::Updated(items)];
+        if self.failed.iter().any(Option::is_some) {
+            events.push(ResourceEvent::Error(self.failure()));
+        }
+        events
+    }
+
+    /// The errors of the failed parts, each prefixed with its label.
+    fn failure(&self) -> String {
+        let errors: Vec<String> = self
+            .labels
+            .iter()
+            .zip(&self.failed)
+            .filter_map(|(label, error)| error.as_ref().map(|e| format!("{label}: {e}")))
+            .collect();
+        errors.join("; ")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +437,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_watcher_compiles_with_page_size() {
+        fn _check_paged_watcher_compiles() {
+            let _: fn(Api<Pod>, mpsc::Sender<ResourceEvent<PodSummary>>, u32) -> ResourceWatcher =
+                ResourceWatcher::watch_with_page_size::<Pod, PodSummary>;
+        }
+    }
+
+    #[test]
+    fn watcher_config_applies_page_size() {
+        assert_eq!(watcher_config(DEFAULT_PAGE_SIZE).page_size, Some(DEFAULT_PAGE_SIZE));
+        assert_eq!(watcher_config(100).page_size, Some(100));
+        assert_eq!(watcher_config(0).page_size, None);
+    }
+
     /// Test that CancellationToken stops the watcher
     #[tokio::test]
     async fn test_watcher_cancellation() {
@@ -219,4 +491,77 @@ mod tests {
             _ => panic!("Expected Error variant"),
         }
     }
+
+    #[test]
+    fn merged_snapshot_is_loading_until_every_part_has_synced() {
+        let mut merged = MergedSnapshot::new(vec!["web".into(), "api".into()]);
+        assert!(matches!(
+            &merged.apply(0, ResourceEvent::Updated(vec!["a"]))[..],
+            [ResourceEvent::Loading { items, expected: None }] if items == &["a"]
+        ));
+        assert!(matches!(
+            &merged.apply(1, ResourceEvent::Loading { items: vec!["b"], expected: Some(3) })[..],
+            [ResourceEvent::Loading { items, expected: Some(4) }] if items == &["a", "b"]
+        ));
+        assert!(matches!(
+            &merged.apply(1, ResourceEvent::Updated(vec!["b", "c"]))[..],
+            [ResourceEvent::Updated(items)] if items == &["a", "b", "c"]
+        ));
+        assert!(matches!(
+            &merged.apply(0, ResourceEvent::Error("gone".into()))[..],
+            [ResourceEvent::Updated(items), ResourceEvent::Error(e)] if items == &["a", "b", "c"] && e == "web: gone"
+        ));
+        assert!(matches!(
+            &merged.apply(0, ResourceEvent::Updated(vec![]))[..],
+            [ResourceEvent::Updated(items)] if items == &["b", "c"]
+        ));
+    }
+
+    #[test]
+    fn a_failed_part_does_not_hold_back_the_others() {
+        let labels = vec!["Deployments".into(), "CronJobs".into(), "Jobs".into()];
+        let mut merged = MergedSnapshot::new(labels);
+        assert!(matches!(
+            &merged.apply(1, ResourceEvent::Error("forbidden".into()))[..],
+            [ResourceEvent::Loading { items, .. }] if items.is_empty()
+        ));
+        merged.apply(0, ResourceEvent::Updated(vec!["deploy/api"]));
+        assert!(matches!(
+            &merged.apply(2, ResourceEvent::Updated(vec!["job/migrate"]))[..],
+            [ResourceEvent::Updated(items), ResourceEvent::Error(e)]
+                if items == &["deploy/api", "job/migrate"] && e == "CronJobs: forbidden"
+        ));
+
+        let mut merged = MergedSnapshot::<&str>::new(vec!["web".into(), "api".into()]);
+        merged.apply(0, ResourceEvent::Error("offline".into()));
+        assert!(matches!(
+            &merged.apply(1, ResourceEvent::Error("offline".into()))[..],
+            [ResourceEvent::Error(e)] if e == "web: offline; api: offline"
+        ));
+
+        let mut merged = MergedSnapshot::<&str>::new(vec!["web".into(), "api".into()]);
+        assert!(matches!(
+            &merged.apply(1, ResourceEvent::Unauthorized("401".into()))[..],
+            [ResourceEvent::Unauthorized(e)] if e == "401"
+        ));
+    }
+
+    #[tokio::test]
+    async fn merge_forwards_the_snapshot_of_every_part() {
+        let (first_tx, first_rx) = mpsc::channel(16);
+        let (second_tx, second_rx) = mpsc::channel(16);
+        let parts = vec![
+            ("Deployments".to_string(), ResourceWatcher { cancel: CancellationToken::new() }, first_rx),
+            ("Jobs".to_string(), ResourceWatcher { cancel: CancellationToken::new() }, second_rx),
+        ];
+        let (tx, mut rx) = mpsc::channel(16);
+        let _merged = ResourceWatcher::merge(parts, tx);
+
+        first_tx.send(ResourceEvent::Updated(vec!["deploy/api"])).await.unwrap();
+        assert!(matches!(rx.recv().await, Some(ResourceEvent::Loading { items, .. }) if items == ["deploy/api"]));
+        second_tx.send(ResourceEvent::Updated(vec!["job/migrate"])).await.unwrap();
+        assert!(
+            matches!(rx.recv().await, Some(ResourceEvent::Updated(items)) if items == ["deploy/api", "job/migrate"])
+        );
+    }
 }
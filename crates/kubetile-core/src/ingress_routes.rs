@@ -0,0 +1,408 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::networking::v1::{Ingress, IngressBackend};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::port_forward::PortForward;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Service port referenced by an ingress backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackendPort {
+    Number(i32),
+    Name(String),
+}
+
+impl fmt::Display for BackendPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(f, "{n}"),
+            Self::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Where requests for one host/path end up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteBackend {
+    Service { name: String, port: Option<BackendPort> },
+    Resource { kind: String, name: String },
+    None,
+}
+
+impl fmt::Display for RouteBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Service { name, port: Some(port) } => write!(f, "{name}:{port}"),
+            Self::Service { name, port: None } => f.write_str(name),
+            Self::Resource { kind, name } => write!(f, "{kind}/{name}"),
+            Self::None => f.write_str("<none>"),
+        }
+    }
+}
+
+/// One host/path rule of an ingress. The default backend has host `*` and no path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngressRoute {
+    pub host: String,
+    pub path: Option<String>,
+    pub path_type: String,
+    pub backend: RouteBackend,
+}
+
+impl IngressRoute {
+    /// Request path used when probing the route: the rule path, or `/` for the default backend.
+    pub fn probe_path(&self) -> &str {
+        self.path.as_deref().filter(|p| p.starts_with('/')).unwrap_or("/")
+    }
+}
+
+impl fmt::Display for IngressRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}{path} ({}) → {}", self.host, self.path_type, self.backend),
+            None => write!(f, "{} (default) → {}", self.host, self.backend),
+        }
+    }
+}
+
+/// A service port that a route resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedBackend {
+    pub service: String,
+    pub port: i32,
+    pub target_port: IntOrString,
+    pub selector: BTreeMap<String, String>,
+}
+
+/// Flattens the rules and default backend of an ingress.
+pub fn ingress_routes(ingress: &Ingress) -> Vec<IngressRoute> {
+    let Some(spec) = ingress.spec.as_ref() else { return Vec::new() };
+    let mut routes = Vec::new();
+    for rule in spec.rules.iter().flatten() {
+        let host = rule.host.clone().unwrap_or_else(|| "*".into());
+        for path in rule.http.iter().flat_map(|http| &http.paths) {
+            routes.push(IngressRoute {
+                host: host.clone(),
+                path: Some(path.path.clone().unwrap_or_else(|| "/".into())),
+                path_type: path.path_type.clone(),
+                backend: route_backend(&path.backend),
+            });
+        }
+    }
+    if let Some(backend) = &spec.default_backend {
+        routes.push(IngressRoute {
+            host: "*".into(),
+            path: None,
+            path_type: String::new(),
+            backend: route_backend(backend),
+        });
+    }
+    routes
+}
+
+fn route_backend(backend: &IngressBackend) -> RouteBackend {
+    if let Some(service) = &backend.service {
+        let port = service.port.as_ref().and_then(|p| match (p.number, &p.name) {
+            (Some(number), _) => Some(BackendPort::Number(number)),
+            (None, Some(name)) => Some(BackendPort::Name(name.clone())),
+            (None, None) => None,
+        });
+        return RouteBackend::Service { name: service.name.clone(), port };
+    }
+    match &backend.resource {
+        Some(resource) => RouteBackend::Resource { kind: resource.kind.clone(), name: resource.name.clone() },
+        None => RouteBackend::None,
+    }
+}
+
+/// Checks that the route's Service exists and exposes the referenced port.
+pub fn resolve_backend(route: &IngressRoute, services: &[Service]) -> Result<ResolvedBackend, String> {
+    let (name, port) = match &route.backend {
+        RouteBackend::Service { name, port } => (name, port),
+        RouteBackend::Resource { kind, name } => {
+            return Err(format!("resource backend {kind}/{name} is not a Service"))
+        }
+        RouteBackend::None => return Err("no backend".into()),
+    };
+    let service = services
+        .iter()
+        .find(|s| s.metadata.name.as_deref() == Some(name))
+        .ok_or_else(|| format!("Service {name} not found"))?;
+    let spec = service.spec.as_ref().ok_or_else(|| format!("Service {name} has no spec"))?;
+    let port = port.as_ref().ok_or_else(|| "backend has no port".to_string())?;
+    let service_port = spec
+        .ports
+        .iter()
+        .flatten()
+        .find(|p| match port {
+            BackendPort::Number(n) => p.port == *n,
+            BackendPort::Name(name) => p.name.as_deref() == Some(name.as_str()),
+        })
+        .ok_or_else(|| format!("Service {name} has no port {port}"))?;
+    Ok(ResolvedBackend {
+        service: name.clone(),
+        port: service_port.port,
+        target_port: service_port.target_port.clone().unwrap_or(IntOrString::Int(service_port.port)),
+        selector: spec.selector.clone().unwrap_or_default(),
+    })
+}
+
+/// Outcome of checking one route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteCheck {
+    /// The backend could not be resolved, so nothing was probed.
+    Invalid(String),
+    /// The backend resolved but the request could not be made.
+    Unreachable(String),
+    /// The pod answered with this status line.
+    Responded { pod: String, status: String },
+}
+
+/// Resolves and probes every route of an ingress, returning a plain-text report.
+pub async fn test_ingress(client: Client, name: &str, namespace: &str) -> Result<String> {
+    let ingress = Api::<Ingress>::namespaced(client.clone(), namespace).get(name).await?;
+    let services = Api::<Service>::namespaced(client.clone(), namespace).list(&ListParams::default()).await?.items;
+    let routes = ingress_routes(&ingress);
+
+    let mut checks = Vec::with_capacity(routes.len());
+    for route in &routes {
+        let check = match resolve_backend(route, &services) {
+            Err(problem) => RouteCheck::Invalid(problem),
+            Ok(backend) => match probe_route(&client, namespace, route, &backend).await {
+                Ok((pod, status)) => RouteCheck::Responded { pod, status },
+                Err(e) => RouteCheck::Unreachable(e.to_string()),
+            },
+        };
+        checks.push(check);
+    }
+    Ok(render_report(name, namespace, &routes, &checks))
+}
+
+/// Formats the per-route results of [`test_ingress`].
+pub fn render_report(name: &str, namespace: &str, routes: &[IngressRoute], checks: &[RouteCheck]) -> String {
+    let mut out = format!("Ingress {namespace}/{name}\n\n");
+    if routes.is_empty() {
+        out.push_str("No rules or default backend.\n");
+        return out;
+    }
+    for (route, check) in routes.iter().zip(checks) {
+        out.push_str(&format!("{route}\n"));
+        let line = match check {
+            RouteCheck::Invalid(problem) => format!("  INVALID  {problem}"),
+            RouteCheck::Unreachable(error) => format!("  FAILED   GET {}: {error}", route.probe_path()),
+            RouteCheck::Responded { pod, status } => {
+                format!("  OK       GET {} via {pod}: {status}", route.probe_path())
+            }
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+async fn probe_route(
+    client: &Client,
+    namespace: &str,
+    route: &IngressRoute,
+    backend: &ResolvedBackend,
+) -> Result<(String, String)> {
+    let (pod, port) = ready_endpoint(client, namespace, backend).await?;
+    let port = u16::try_from(port).map_err(|_| anyhow!("invalid target port {port}"))?;
+    let forward = PortForward::start(client, &pod, namespace, 0, port).await?;
+    let host = if route.host == "*" { "localhost" } else { &route.host };
+    let result = http_probe(forward.local_port(), host, route.probe_path()).await;
+    let _ = forward.stop().await;
+    Ok((pod, result?))
+}
+
+/// Picks a ready pod behind the service and the container port its target port maps to.
+async fn ready_endpoint(client: &Client, namespace: &str, backend: &ResolvedBackend) -> Result<(String, i32)> {
+    if backend.selector.is_empty() {
+        bail!("Service {} has no selector", backend.service);
+    }
+    let selector = backend.selector.iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join(",");
+    let pods = Api::<Pod>::namespaced(client.clone(), namespace).list(&ListParams::default().labels(&selector)).await?;
+    let pod = pods
+        .items
+        .into_iter()
+        .find(is_ready)
+        .ok_or_else(|| anyhow!("no ready pods behind Service {}", backend.service))?;
+    let port = container_port(&pod, &backend.target_port)
+        .ok_or_else(|| anyhow!("no container port named {:?}", backend.target_port))?;
+    Ok((pod.metadata.name.unwrap_or_default(), port))
+}
+
+fn is_ready(pod: &Pod) -> bool {
+    pod.metadata.deletion_timestamp.is_none()
+        && pod
+            .status
+            .as_ref()
+            .and_then(|s| s.conditions.as_ref())
+            .is_some_and(|c| c.iter().any(|c| c.type_ == "Ready" && c.status == "True"))
+}
+
+fn container_port(pod: &Pod, target: &IntOrString) -> Option<i32> {
+    match target {
+        IntOrString::Int(port) => Some(*port),
+        IntOrString::String(name) => pod
+            .spec
+            .as_ref()?
+            .containers
+            .iter()
+            .flat_map(|c| c.ports.iter().flatten())
+            .find(|p| p.name.as_deref() == Some(name.as_str()))
+            .map(|p| p.container_port),
+    }
+}
+
+/// Sends a `GET` with the given `Host` header to a local port and returns the response status line.
+pub async fn http_probe(local_port: u16, host: &str, path: &str) -> Result<String> {
+    let request = async {
+        let mut stream = TcpStream::connect(("127.0.0.1", local_port)).await?;
+        let request =
+            format!("GET {path} HTTP/1.1\r\nHost: {host}\r\nUser-Agent: kubetile\r\nConnection: close\r\n\r\n");
+        stream.write_all(request.as_bytes()).await?;
+
+        let mut response = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !response.contains(&b'\n') {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            response.extend_from_slice(&buf[..n]);
+        }
+        let response = String::from_utf8_lossy(&response);
+        match response.lines().next().map(str::trim) {
+            Some(line) if line.starts_with("HTTP/") => Ok(line.to_string()),
+            _ => bail!("no HTTP response"),
+        }
+    };
+    tokio::time::timeout(PROBE_TIMEOUT, request).await.map_err(|_| anyhow!("timed out after {PROBE_TIMEOUT:?}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    fn ingress() -> Ingress {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "networking.k8s.io/v1",
+            "kind": "Ingress",
+            "metadata": { "name": "shop", "namespace": "web" },
+            "spec": {
+                "defaultBackend": { "service": { "name": "fallback", "port": { "number": 80 } } },
+                "rules": [
+                    {
+                        "host": "shop.example.com",
+                        "http": { "paths": [
+                            { "path": "/api", "pathType": "Prefix",
+                              "backend": { "service": { "name": "api", "port": { "name": "http" } } } },
+                            { "path": "/static", "pathType": "Exact",
+                              "backend": { "resource": { "kind": "StorageBucket", "name": "assets" } } }
+                        ] }
+                    },
+                    {
+                        "http": { "paths": [
+                            { "path": "/", "pathType": "ImplementationSpecific",
+                              "backend": { "service": { "name": "api", "port": { "number": 9090 } } } }
+                        ] }
+                    }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn api_service() -> Service {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "api", "namespace": "web" },
+            "spec": {
+                "selector": { "app": "api" },
+                "ports": [ { "name": "http", "port": 80, "targetPort": "web" } ]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn routes_cover_rules_and_default_backend() {
+        let routes = ingress_routes(&ingress());
+        let lines: Vec<String> = routes.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "shop.example.com/api (Prefix) → api:http",
+                "shop.example.com/static (Exact) → StorageBucket/assets",
+                "*/ (ImplementationSpecific) → api:9090",
+                "* (default) → fallback:80",
+            ]
+        );
+        assert_eq!(routes[3].probe_path(), "/");
+    }
+
+    #[test]
+    fn resolve_backend_reports_missing_services_and_ports() {
+        let routes = ingress_routes(&ingress());
+        let services = vec![api_service()];
+
+        let resolved = resolve_backend(&routes[0], &services).unwrap();
+        assert_eq!(resolved.port, 80);
+        assert_eq!(resolved.target_port, IntOrString::String("web".into()));
+        assert_eq!(resolved.selector.get("app").map(String::as_str), Some("api"));
+
+        assert_eq!(
+            resolve_backend(&routes[1], &services).unwrap_err(),
+            "resource backend StorageBucket/assets is not a Service"
+        );
+        assert_eq!(resolve_backend(&routes[2], &services).unwrap_err(), "Service api has no port 9090");
+        assert_eq!(resolve_backend(&routes[3], &services).unwrap_err(), "Service fallback not found");
+    }
+
+    #[test]
+    fn report_lists_each_route_with_its_check() {
+        let routes = ingress_routes(&ingress());
+        let checks = vec![
+            RouteCheck::Responded { pod: "api-0".into(), status: "HTTP/1.1 200 OK".into() },
+            RouteCheck::Invalid("resource backend StorageBucket/assets is not a Service".into()),
+            RouteCheck::Invalid("Service api has no port 9090".into()),
+            RouteCheck::Unreachable("no ready pods behind Service fallback".into()),
+        ];
+        let report = render_report("shop", "web", &routes, &checks);
+        assert!(report.starts_with("Ingress web/shop\n\n"));
+        assert!(report.contains("  OK       GET /api via api-0: HTTP/1.1 200 OK\n"));
+        assert!(report.contains("  FAILED   GET /: no ready pods behind Service fallback\n"));
+    }
+
+    #[tokio::test]
+    async fn http_probe_sends_host_and_returns_status_line() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        });
+
+        let status = http_probe(port, "shop.example.com", "/api").await.unwrap();
+        assert_eq!(status, "HTTP/1.1 404 Not Found");
+        let request = server.await.unwrap();
+        assert!(request.starts_with("GET /api HTTP/1.1\r\n"));
+        assert!(request.contains("Host: shop.example.com\r\n"));
+    }
+}
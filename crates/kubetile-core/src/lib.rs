@@ -1,28 +1,85 @@
+pub mod access;
 pub mod actions;
+pub mod audit;
 pub mod client;
+pub mod cluster_overview;
+pub mod connection;
+pub mod container_memory;
 pub mod context;
+pub mod data_keys;
+pub mod dependency_graph;
+pub mod drain;
 pub mod error;
 pub mod exec;
+pub mod fetch_cache;
+pub mod images;
 pub mod informer;
+pub mod ingress_routes;
+pub mod listing;
 pub mod logs;
+pub mod manifests;
+pub mod namespace_memory;
+pub mod namespace_overview;
+pub mod network_policy;
+pub mod plugin_columns;
 pub mod port_forward;
 pub mod query;
 pub mod query_history;
+pub mod query_paging;
+pub mod query_params;
+pub mod query_plan;
+pub mod registry;
+pub mod related;
+pub mod remote;
 pub mod resource;
+pub mod resource_usage;
 pub mod resources;
+pub mod rollout;
+pub mod row_status;
 pub mod saved_queries;
+pub mod scripting;
+pub mod search;
+pub mod service_endpoints;
 pub mod terminal_manager;
+pub mod usages;
+pub mod volume_stats;
+pub mod warning_events;
 
+pub use access::{AccessCheck, AccessDecision};
 pub use actions::{ActionExecutor, ResourceAction, ResourceKind};
+pub use audit::AuditEntry;
 pub use client::KubeClient;
+pub use cluster_overview::ClusterOverview;
+pub use connection::{ConnectionProfile, Diagnosis};
+pub use container_memory::ContainerMemory;
 pub use context::{ClusterContext, ContextResolver};
+pub use data_keys::{DataEntry, DataSnapshot};
+pub use dependency_graph::{GraphLine, GraphNode};
+pub use drain::{DrainOptions, DrainPlan};
 pub use error::KubeError;
 pub use exec::ExecSession;
-pub use logs::{parse_raw_log_line, LogLine, LogRequest, LogStream, StreamStatus};
-pub use port_forward::{ForwardId, PortForward};
-pub use query::{QueryConfig, QueryResult};
+pub use fetch_cache::{FetchCache, FetchKey, FetchView};
+pub use images::{ContainerImage, ImageRef};
+pub use ingress_routes::{IngressRoute, RouteCheck};
+pub use listing::Listing;
+pub use logs::{parse_raw_log_line, LogLine, LogRequest, LogSource, LogStream, StreamStatus};
+pub use manifests::{clean_yaml, ApplyChange, DiffLine, PlannedApply};
+pub use namespace_memory::NamespaceMemory;
+pub use plugin_columns::{expand_placeholders, run_column_command, ColumnTarget};
+pub use port_forward::{ForwardId, ForwardStats, PortForward};
+pub use query::{QueryConfig, QueryEngine, QueryResult, QuerySession, SessionLost};
 pub use query_history::QueryHistory;
-pub use resource::{DetailSection, ResourceSummary};
+pub use related::Related;
+pub use remote::{kubectl_argv, ApiTunnel};
+pub use resource::{display_timezone, format_timestamp, DetailSection, ResourceSummary};
+pub use resource_usage::ResourceUsage;
 pub use resources::*;
-pub use saved_queries::{SavedQueries, SavedQuery};
+pub use rollout::{RolloutProgress, RolloutState};
+pub use row_status::{classify_row, RowStatus};
+pub use saved_queries::{QueryScope, SavedQueries, SavedQuery, ScopeLevel};
+pub use scripting::{ScriptBinding, ScriptHost, ScriptOutput, ScriptResource};
+pub use search::{SearchHit, SearchQuery};
 pub use terminal_manager::{SessionId, SessionKind, TerminalManager};
+pub use usages::Usage;
+pub use volume_stats::{VolumeUsage, VolumeUsageMap};
+pub use warning_events::{WarningNotice, WarningWatcher};
@@ -0,0 +1,217 @@
+use anyhow::{bail, Result};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
+};
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::ListParams;
+use kube::{Api, Client};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use crate::actions::ResourceKind;
+use crate::resource::ResourceSummary;
+use crate::resources::*;
+
+/// Summary columns of a list of resources, as the resource list shows them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Listing {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Listing {
+    pub fn from_summaries<S: ResourceSummary>(items: &[S]) -> Self {
+        let headers =
+            items.first().map(|s| s.columns().into_iter().map(|(h, _)| h.to_string()).collect()).unwrap_or_default();
+        Self { headers, rows: items.iter().map(ResourceSummary::row).collect() }
+    }
+
+    /// Left-aligned columns separated by three spaces, like `kubectl get`.
+    pub fn render_table(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        let line = |cells: &[String]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(i, (cell, width))| if i + 1 == widths.len() { cell.clone() } else { format!("{cell:<width$}") })
+                .collect();
+            padded.join("   ").trim_end().to_string()
+        };
+        let mut out = line(&self.headers);
+        for row in &self.rows {
+            out.push('\n');
+            out.push_str(&line(row));
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_yaml(&self) -> Result<String> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+}
+
+/// Serializes as a list of objects keyed by lowercase header, in column order.
+impl Serialize for Listing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        struct Row<'a>(&'a [String], &'a [String]);
+        impl Serialize for Row<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for (header, value) in self.0.iter().zip(self.1) {
+                    map.serialize_entry(&header.to_lowercase(), value)?;
+                }
+                map.end()
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.rows.len()))?;
+        for row in &self.rows {
+            seq.serialize_element(&Row(&self.headers, row))?;
+        }
+        seq.end()
+    }
+}
+
+/// Every built-in kind kubetile can list.
+pub const BUILTIN_KINDS: [ResourceKind; 18] = [
+    ResourceKind::Pods,
+    ResourceKind::Deployments,
+    ResourceKind::Services,
+    ResourceKind::StatefulSets,
+    ResourceKind::DaemonSets,
+    ResourceKind::Jobs,
+    ResourceKind::CronJobs,
+    ResourceKind::ConfigMaps,
+    ResourceKind::Secrets,
+    ResourceKind::Ingresses,
+    ResourceKind::Nodes,
+    ResourceKind::Namespaces,
+    ResourceKind::PersistentVolumes,
+    ResourceKind::PersistentVolumeClaims,
+    ResourceKind::NetworkPolicies,
+    ResourceKind::ResourceQuotas,
+    ResourceKind::LimitRanges,
+    ResourceKind::PodDisruptionBudgets,
+];
+
+/// Looks up a built-in kind by short name (`po`), plural (`pods`) or singular (`pod`), ignoring case.
+pub fn kind_from_name(name: &str) -> Option<ResourceKind> {
+    let name = name.to_lowercase();
+    BUILTIN_KINDS.into_iter().find(|kind| {
+        let plural = kind.display_name().to_lowercase();
+        let singulars = [
+            plural.strip_suffix('s').map(str::to_string),
+            plural.strip_suffix("es").map(str::to_string),
+            plural.strip_suffix("ies").map(|stem| format!("{stem}y")),
+        ];
+        kind.short_name() == name || plural == name || singulars.iter().flatten().any(|s| *s == name)
+    })
+}
+
+/// Lists `kind` in `namespace`, or across all namespaces when it is `None`.
+pub async fn list_resources(client: Client, kind: &ResourceKind, namespace: Option<&str>) -> Result<Listing> {
+    macro_rules! namespaced {
+        ($k8s_type:ty, $summary_type:ty) => {{
+            let api: Api<$k8s_type> = match namespace {
+                Some(ns) => Api::namespaced(client, ns),
+                None => Api::all(client),
+            };
+            let items = api.list(&ListParams::default()).await?.items;
+            let summaries: Vec<$summary_type> = items.into_iter().map(<$summary_type>::from).collect();
+            Listing::from_summaries(&summaries)
+        }};
+    }
+    macro_rules! cluster {
+        ($k8s_type:ty, $summary_type:ty) => {{
+            let items = Api::<$k8s_type>::all(client).list(&ListParams::default()).await?.items;
+            let summaries: Vec<$summary_type> = items.into_iter().map(<$summary_type>::from).collect();
+            Listing::from_summaries(&summaries)
+        }};
+    }
+
+    Ok(match kind {
+        ResourceKind::Pods => namespaced!(Pod, PodSummary),
+        ResourceKind::Deployments => namespaced!(Deployment, DeploymentSummary),
+        ResourceKind::Services => namespaced!(Service, ServiceSummary),
+        ResourceKind::StatefulSets => namespaced!(StatefulSet, StatefulSetSummary),
+        ResourceKind::DaemonSets => namespaced!(DaemonSet, DaemonSetSummary),
+        ResourceKind::Jobs => namespaced!(Job, JobSummary),
+        ResourceKind::CronJobs => namespaced!(CronJob, CronJobSummary),
+        ResourceKind::ConfigMaps => namespaced!(ConfigMap, ConfigMapSummary),
+        ResourceKind::Secrets => namespaced!(Secret, SecretSummary),
+        ResourceKind::Ingresses => namespaced!(Ingress, IngressSummary),
+        ResourceKind::PersistentVolumeClaims => namespaced!(PersistentVolumeClaim, PersistentVolumeClaimSummary),
+        ResourceKind::NetworkPolicies => namespaced!(NetworkPolicy, NetworkPolicySummary),
+        ResourceKind::ResourceQuotas => namespaced!(ResourceQuota, ResourceQuotaSummary),
+        ResourceKind::LimitRanges => namespaced!(LimitRange, LimitRangeSummary),
+        ResourceKind::PodDisruptionBudgets => namespaced!(PodDisruptionBudget, PodDisruptionBudgetSummary),
+        ResourceKind::Nodes => cluster!(Node, NodeSummary),
+        ResourceKind::Namespaces => cluster!(Namespace, NamespaceSummary),
+        ResourceKind::PersistentVolumes => cluster!(PersistentVolume, PersistentVolumeSummary),
+        ResourceKind::Custom(name) => bail!("listing custom resource `{name}` is not supported"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing() -> Listing {
+        Listing {
+            headers: vec!["NAME".into(), "READY".into(), "STATUS".into()],
+            rows: vec![
+                vec!["api-0".into(), "1/1".into(), "Running".into()],
+                vec!["worker-long-name".into(), "0/1".into(), "Pending".into()],
+            ],
+        }
+    }
+
+    #[test]
+    fn table_pads_columns_like_kubectl() {
+        assert_eq!(
+            listing().render_table(),
+            "NAME               READY   STATUS\n\
+             api-0              1/1     Running\n\
+             worker-long-name   0/1     Pending"
+        );
+    }
+
+    #[test]
+    fn json_and_yaml_keep_column_order() {
+        let json = listing().to_json().unwrap();
+        assert!(
+            json.starts_with("[\n  {\n    \"name\": \"api-0\",\n    \"ready\": \"1/1\",\n    \"status\": \"Running\"")
+        );
+        let yaml = listing().to_yaml().unwrap();
+        assert!(yaml.starts_with("- name: api-0\n  ready: 1/1\n  status: Running\n"));
+        assert_eq!(Listing::default().to_json().unwrap(), "[]");
+    }
+
+    #[test]
+    fn kinds_resolve_from_short_plural_and_singular_names() {
+        assert_eq!(kind_from_name("po"), Some(ResourceKind::Pods));
+        assert_eq!(kind_from_name("Deployments"), Some(ResourceKind::Deployments));
+        assert_eq!(kind_from_name("networkpolicy"), Some(ResourceKind::NetworkPolicies));
+        assert_eq!(kind_from_name("quota"), Some(ResourceKind::ResourceQuotas));
+        assert_eq!(kind_from_name("limitrange"), Some(ResourceKind::LimitRanges));
+        assert_eq!(kind_from_name("pdb"), Some(ResourceKind::PodDisruptionBudgets));
+        assert_eq!(kind_from_name("ingress"), Some(ResourceKind::Ingresses));
+        assert_eq!(kind_from_name("namespace"), Some(ResourceKind::Namespaces));
+        assert_eq!(kind_from_name("service"), Some(ResourceKind::Services));
+        assert_eq!(kind_from_name("widgets"), None);
+    }
+}
@@ -1,5 +1,10 @@
 use std::time::Duration;
 
+use futures::stream::{self, BoxStream};
+use futures::{AsyncBufReadExt as _, StreamExt};
+use k8s_openapi::api::core::v1::Pod;
+use kube::api::LogParams;
+use kube::Api;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 use tokio::sync::mpsc;
@@ -16,6 +21,8 @@ pub struct LogLine {
 #[derive(Debug, Clone)]
 pub struct LogRequest {
     pub context: Option<String>,
+    /// Host that runs kubectl over SSH instead of the local machine.
+    pub ssh_host: Option<String>,
     pub pod_name: String,
     pub namespace: String,
     pub container: Option<String>,
@@ -30,6 +37,7 @@ impl Default for LogRequest {
     fn default() -> Self {
         Self {
             context: None,
+            ssh_host: None,
             pod_name: String::new(),
             namespace: String::new(),
             container: None,
@@ -42,6 +50,15 @@ impl Default for LogRequest {
     }
 }
 
+/// Where a stream reads its lines from.
+#[derive(Clone)]
+pub enum LogSource {
+    /// `kubectl logs -f`, locally or on `LogRequest::ssh_host`.
+    Kubectl,
+    /// The pod log endpoint of the API server, read with the app's own client.
+    Api(kube::Client),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StreamStatus {
     Streaming,
@@ -58,13 +75,15 @@ pub struct LogStream {
 }
 
 impl LogStream {
-    pub async fn start(request: LogRequest) -> anyhow::Result<Self> {
+    /// Follows the request's logs from `source`. A follow that ends, as the API server does after
+    /// its request timeout, or fails is reopened from the last line seen.
+    pub async fn start(source: LogSource, request: LogRequest) -> anyhow::Result<Self> {
         let (tx, rx) = mpsc::unbounded_channel();
         let (status_tx, status_rx) = mpsc::unbounded_channel();
         let (cancel_tx, cancel_rx) = tokio::sync::watch::channel(false);
 
         tokio::spawn(async move {
-            stream_logs(request, tx, status_tx, cancel_rx).await;
+            stream_logs(source, request, tx, status_tx, cancel_rx).await;
         });
 
         Ok(Self { rx, status_rx, status: StreamStatus::Streaming, cancel: cancel_tx })
@@ -101,12 +120,14 @@ impl Drop for LogStream {
 }
 
 async fn stream_logs(
+    source: LogSource,
     request: LogRequest,
     tx: mpsc::UnboundedSender<LogLine>,
     status_tx: mpsc::UnboundedSender<StreamStatus>,
     mut cancel_rx: tokio::sync::watch::Receiver<bool>,
 ) {
     let container = request.container.clone().unwrap_or_default();
+    let via_kubectl = matches!(source, LogSource::Kubectl);
     let mut consecutive_failures: u32 = 0;
     let mut last_line_seen_at: Option<std::time::Instant> = None;
     let mut ever_connected = false;
@@ -117,24 +138,28 @@ async fn stream_logs(
             return;
         }
 
-        let mut cmd = build_kubectl_command(&request, ever_connected, last_line_seen_at);
+        let opened = tokio::select! {
+            opened = open_lines(&source, &request, ever_connected, last_line_seen_at) => opened,
+            _ = cancel_rx.changed() => {
+                let _ = status_tx.send(StreamStatus::Stopped);
+                return;
+            }
+        };
 
-        match cmd.spawn() {
-            Ok(mut child) => {
+        match opened {
+            Ok(mut lines) => {
                 consecutive_failures = 0;
                 ever_connected = true;
                 let _ = status_tx.send(StreamStatus::Streaming);
 
-                let stdout = child.stdout.take().expect("stdout is piped");
-                let mut lines = BufReader::new(stdout).lines();
                 let mut stream_read_error = false;
 
                 loop {
                     tokio::select! {
-                        line_result = lines.next_line() => {
+                        line_result = lines.next() => {
                             match line_result {
-                                Ok(Some(raw_line)) => {
-                                    if is_kubectl_noise(&raw_line) {
+                                Some(Ok(raw_line)) => {
+                                    if via_kubectl && is_kubectl_noise(&raw_line) {
                                         continue;
                                     }
                                     let log_line = parse_raw_log_line(&raw_line, &container);
@@ -143,15 +168,15 @@ async fn stream_logs(
                                     }
                                     last_line_seen_at = Some(std::time::Instant::now());
                                 }
-                                Ok(None) => {
-                                    debug!("kubectl logs exited");
+                                None => {
+                                    debug!("log follow ended");
                                     if !request.follow {
                                         let _ = status_tx.send(StreamStatus::Stopped);
                                         return;
                                     }
                                     break;
                                 }
-                                Err(e) => {
+                                Some(Err(e)) => {
                                     warn!("Log stream read error: {e}");
                                     stream_read_error = true;
                                     break;
@@ -159,7 +184,6 @@ async fn stream_logs(
                             }
                         }
                         _ = cancel_rx.changed() => {
-                            let _ = child.kill().await;
                             let _ = status_tx.send(StreamStatus::Stopped);
                             return;
                         }
@@ -171,7 +195,7 @@ async fn stream_logs(
                 }
             }
             Err(e) => {
-                warn!("Failed to spawn kubectl logs: {e}");
+                warn!("Failed to open log stream: {e}");
                 consecutive_failures += 1;
             }
         }
@@ -195,51 +219,121 @@ async fn stream_logs(
     }
 }
 
+/// Starts one follow and returns its lines. A kubectl child lives as long as the stream and is
+/// killed when it is dropped.
+async fn open_lines(
+    source: &LogSource,
+    request: &LogRequest,
+    ever_connected: bool,
+    last_line_seen_at: Option<std::time::Instant>,
+) -> anyhow::Result<BoxStream<'static, std::io::Result<String>>> {
+    match source {
+        LogSource::Kubectl => {
+            let mut child = build_kubectl_command(request, ever_connected, last_line_seen_at).spawn()?;
+            let stdout = child.stdout.take().expect("stdout is piped");
+            let lines = BufReader::new(stdout).lines();
+            Ok(stream::unfold((child, lines), |(child, mut lines)| async move {
+                lines.next_line().await.transpose().map(|line| (line, (child, lines)))
+            })
+            .boxed())
+        }
+        LogSource::Api(client) => {
+            let pods: Api<Pod> = Api::namespaced(client.clone(), &request.namespace);
+            let params = log_params(request, ever_connected, last_line_seen_at);
+            let reader = pods.log_stream(&request.pod_name, &params).await?;
+            Ok(reader.lines().boxed())
+        }
+    }
+}
+
+/// How far back a follow starts.
+#[derive(Debug, PartialEq, Eq)]
+enum Window {
+    SinceSeconds(i64),
+    /// Last N lines; negative means all of them.
+    Tail(i64),
+}
+
+fn window(request: &LogRequest, ever_connected: bool, last_line_seen_at: Option<std::time::Instant>) -> Window {
+    if ever_connected {
+        // Reconnect: always use --since to avoid re-fetching historical lines.
+        // Fall back to 1s if we haven't tracked a specific last-line timestamp.
+        let since = reconnect_since_seconds(request.since_seconds, last_line_seen_at.map(|t| t.elapsed())).unwrap_or(1);
+        Window::SinceSeconds(since)
+    } else if let Some(since) = reconnect_since_seconds(request.since_seconds, None) {
+        Window::SinceSeconds(since)
+    } else {
+        Window::Tail(request.tail_lines.unwrap_or(0))
+    }
+}
+
+fn log_params(request: &LogRequest, ever_connected: bool, last_line_seen_at: Option<std::time::Instant>) -> LogParams {
+    let (since_seconds, tail_lines) = match window(request, ever_connected, last_line_seen_at) {
+        Window::SinceSeconds(since) => (Some(since), None),
+        Window::Tail(tail) => (None, (tail >= 0).then_some(tail)),
+    };
+    LogParams {
+        container: request.container.clone().filter(|c| !c.is_empty()),
+        follow: request.follow,
+        previous: request.previous,
+        timestamps: request.timestamps,
+        since_seconds,
+        tail_lines,
+        ..Default::default()
+    }
+}
+
 fn build_kubectl_command(
     request: &LogRequest,
     ever_connected: bool,
     last_line_seen_at: Option<std::time::Instant>,
 ) -> Command {
-    let mut cmd = Command::new("kubectl");
-    cmd.arg("logs");
+    let argv = crate::remote::kubectl_argv(
+        request.ssh_host.as_deref(),
+        false,
+        &kubectl_logs_args(request, ever_connected, last_line_seen_at),
+    );
+    let mut cmd = Command::new(&argv[0]);
+    cmd.args(&argv[1..]);
+    cmd.stdout(std::process::Stdio::piped());
+    cmd.stderr(std::process::Stdio::null());
+    cmd.kill_on_drop(true);
+    cmd
+}
+
+fn kubectl_logs_args(
+    request: &LogRequest,
+    ever_connected: bool,
+    last_line_seen_at: Option<std::time::Instant>,
+) -> Vec<String> {
+    let mut args = vec!["logs".to_string()];
 
     if request.follow {
-        cmd.arg("--follow=true");
+        args.push("--follow=true".into());
     }
     if request.timestamps {
-        cmd.arg("--timestamps=true");
+        args.push("--timestamps=true".into());
     }
     if request.previous {
-        cmd.arg("--previous=true");
+        args.push("--previous=true".into());
     }
     if let Some(ctx) = &request.context {
-        cmd.arg(format!("--context={ctx}"));
+        args.push(format!("--context={ctx}"));
     }
 
-    cmd.arg(format!("--namespace={}", request.namespace));
-    cmd.arg(&request.pod_name);
+    args.push(format!("--namespace={}", request.namespace));
+    args.push(request.pod_name.clone());
 
     let container = request.container.as_deref().unwrap_or("");
     if !container.is_empty() {
-        cmd.arg(format!("--container={container}"));
+        args.push(format!("--container={container}"));
     }
 
-    if ever_connected {
-        // Reconnect: always use --since to avoid re-fetching historical lines.
-        // Fall back to 1s if we haven't tracked a specific last-line timestamp.
-        let since = reconnect_since_seconds(request.since_seconds, last_line_seen_at.map(|t| t.elapsed())).unwrap_or(1);
-        cmd.arg(format!("--since={since}s"));
-    } else if let Some(since) = reconnect_since_seconds(request.since_seconds, None) {
-        cmd.arg(format!("--since={since}s"));
-    } else {
-        let tail = request.tail_lines.unwrap_or(0);
-        cmd.arg(format!("--tail={tail}"));
+    match window(request, ever_connected, last_line_seen_at) {
+        Window::SinceSeconds(since) => args.push(format!("--since={since}s")),
+        Window::Tail(tail) => args.push(format!("--tail={tail}")),
     }
-
-    cmd.stdout(std::process::Stdio::piped());
-    cmd.stderr(std::process::Stdio::null());
-    cmd.kill_on_drop(true);
-    cmd
+    args
 }
 
 fn is_kubectl_noise(line: &str) -> bool {
@@ -331,6 +425,7 @@ mod tests {
         assert!(!req.previous);
         assert!(req.container.is_none());
         assert!(req.context.is_none());
+        assert!(req.ssh_host.is_none());
     }
 
     #[test]
@@ -351,6 +446,23 @@ mod tests {
         assert_eq!(computed, None);
     }
 
+    #[test]
+    fn api_params_follow_the_same_window_as_kubectl() {
+        let request = LogRequest { container: Some("app".into()), tail_lines: Some(-1), ..Default::default() };
+        let first = log_params(&request, false, None);
+        assert_eq!((first.tail_lines, first.since_seconds), (None, None), "-1 asks for every line");
+        assert_eq!(first.container.as_deref(), Some("app"));
+        assert!(first.follow && first.timestamps);
+
+        let reconnect = log_params(&request, true, Some(std::time::Instant::now() - Duration::from_secs(3)));
+        assert_eq!((reconnect.tail_lines, reconnect.since_seconds), (None, Some(4)));
+        assert!(kubectl_logs_args(&request, true, None).contains(&"--since=1s".to_string()));
+
+        let fresh = LogRequest { tail_lines: Some(0), ..Default::default() };
+        assert_eq!(log_params(&fresh, false, None).tail_lines, Some(0));
+        assert_eq!(log_params(&fresh, false, None).container, None);
+    }
+
     #[test]
     fn try_parse_timestamp_prefix_valid() {
         let line = "2024-06-01T12:00:00Z some content here";
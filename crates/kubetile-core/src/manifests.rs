@@ -0,0 +1,565 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use kube::api::{DynamicObject, Patch, PatchParams, PostParams};
+use kube::core::GroupVersionKind;
+use kube::discovery::{pinned_kind, ApiResource, Scope};
+use kube::{Api, Client};
+use serde::Deserialize;
+use similar::{capture_diff_slices, Algorithm, ChangeTag, DiffTag};
+
+use crate::audit;
+
+/// Field manager recorded on the fields this app applies.
+pub const FIELD_MANAGER: &str = "crystal";
+/// Unchanged lines kept on each side of a change in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// One document of a manifest file.
+#[derive(Debug, Clone)]
+pub struct Manifest {
+    /// File the document came from, with its position when the file holds several: `app.yaml#2`.
+    pub source: String,
+    pub gvk: GroupVersionKind,
+    pub object: DynamicObject,
+}
+
+/// Reads the manifests of a YAML or JSON file, or of every such file directly inside a directory,
+/// in file name order.
+pub fn load_manifests(path: &Path) -> Result<Vec<Manifest>> {
+    let mut manifests = Vec::new();
+    for file in manifest_files(path)? {
+        let text = fs::read_to_string(&file).with_context(|| format!("cannot read {}", file.display()))?;
+        let name = file.file_name().map_or_else(|| file.display().to_string(), |n| n.to_string_lossy().into_owned());
+        manifests.extend(parse_manifests(&text, &name).with_context(|| format!("cannot parse {name}"))?);
+    }
+    Ok(manifests)
+}
+
+fn manifest_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        if !path.exists() {
+            bail!("{} does not exist", path.display());
+        }
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(path)
+        .with_context(|| format!("cannot list {}", path.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "yaml" || ext == "yml" || ext == "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Splits a multi-document YAML stream into manifests, skipping empty documents and unpacking
+/// `List` kinds into their items.
+pub fn parse_manifests(text: &str, source: &str) -> Result<Vec<Manifest>> {
+    let mut values = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(text) {
+        let value = serde_yaml::Value::deserialize(document)?;
+        if value.is_null() {
+            continue;
+        }
+        let is_list = value.get("kind").and_then(|k| k.as_str()).is_some_and(|k| k.ends_with("List"));
+        match value.get("items").and_then(|items| items.as_sequence()) {
+            Some(items) if is_list => values.extend(items.iter().cloned()),
+            _ => values.push(value),
+        }
+    }
+
+    let several = values.len() > 1;
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let source = if several { format!("{source}#{}", i + 1) } else { source.to_string() };
+            let object: DynamicObject = serde_yaml::from_value(value).with_context(|| source.clone())?;
+            let types = object.types.as_ref().with_context(|| format!("{source}: apiVersion and kind are required"))?;
+            let gvk = GroupVersionKind::try_from(types).with_context(|| source.clone())?;
+            Ok(Manifest { source, gvk, object })
+        })
+        .collect()
+}
+
+/// Metadata the API server fills in, which a manifest applied elsewhere must not carry.
+const SERVER_METADATA: &[&str] = &[
+    "managedFields",
+    "creationTimestamp",
+    "uid",
+    "resourceVersion",
+    "generation",
+    "selfLink",
+    "deletionTimestamp",
+    "deletionGracePeriodSeconds",
+];
+/// Annotations tools write on every apply or rollout.
+const SERVER_ANNOTATIONS: &[&str] =
+    &["kubectl.kubernetes.io/last-applied-configuration", "deployment.kubernetes.io/revision"];
+/// Prefix of the projected service account token volume added to every pod.
+const API_ACCESS_VOLUME: &str = "kube-api-access-";
+
+/// Strips the server-populated fields from a fetched object, leaving YAML that can be applied
+/// again, as `kubectl neat` does. Fails when the text is not a Kubernetes object.
+pub fn clean_yaml(text: &str) -> Result<String> {
+    let mut value: serde_yaml::Value = serde_yaml::from_str(text)?;
+    if value.get("apiVersion").is_none() || value.get("kind").is_none() {
+        bail!("not a Kubernetes object");
+    }
+    let is_list = value.get("kind").and_then(|k| k.as_str()).is_some_and(|k| k.ends_with("List"));
+    match value.get_mut("items").and_then(|items| items.as_sequence_mut()) {
+        Some(items) if is_list => items.iter_mut().for_each(clean_object),
+        _ => clean_object(&mut value),
+    }
+    Ok(serde_yaml::to_string(&value)?)
+}
+
+fn clean_object(object: &mut serde_yaml::Value) {
+    let Some(object) = object.as_mapping_mut() else { return };
+    object.shift_remove("status");
+    let kind = object.get("kind").and_then(|k| k.as_str()).map(str::to_string);
+    if let Some(meta) = object.get_mut("metadata").and_then(|m| m.as_mapping_mut()) {
+        for key in SERVER_METADATA {
+            meta.shift_remove(*key);
+        }
+        if let Some(annotations) = meta.get_mut("annotations").and_then(|a| a.as_mapping_mut()) {
+            for key in SERVER_ANNOTATIONS {
+                annotations.shift_remove(*key);
+            }
+            if annotations.is_empty() {
+                meta.shift_remove("annotations");
+            }
+        }
+    }
+    let Some(spec) = object.get_mut("spec").and_then(|s| s.as_mapping_mut()) else { return };
+    match kind.as_deref() {
+        // Cluster IPs are allocated per cluster; carrying them over makes the apply fail elsewhere.
+        // `None` is not an address but what makes a Service headless, so it stays.
+        Some("Service") if spec.get("clusterIP").and_then(|ip| ip.as_str()) != Some("None") => {
+            spec.shift_remove("clusterIP");
+            spec.shift_remove("clusterIPs");
+        }
+        // The scheduler's choice of node and the token volume the admission plugin injects.
+        Some("Pod") => {
+            spec.shift_remove("nodeName");
+            remove_api_access_volume(spec);
+        }
+        _ => {}
+    }
+}
+
+/// Drops the `kube-api-access-*` service account token volume and its mounts from a pod spec.
+fn remove_api_access_volume(spec: &mut serde_yaml::Mapping) {
+    let is_api_access = |v: &serde_yaml::Value| {
+        v.get("name").and_then(|n| n.as_str()).is_some_and(|n| n.starts_with(API_ACCESS_VOLUME))
+    };
+    if let Some(volumes) = spec.get_mut("volumes").and_then(|v| v.as_sequence_mut()) {
+        volumes.retain(|v| !is_api_access(v));
+        if volumes.is_empty() {
+            spec.shift_remove("volumes");
+        }
+    }
+    for containers in ["initContainers", "containers", "ephemeralContainers"] {
+        let Some(containers) = spec.get_mut(containers).and_then(|c| c.as_sequence_mut()) else { continue };
+        for container in containers.iter_mut().filter_map(|c| c.as_mapping_mut()) {
+            let Some(mounts) = container.get_mut("volumeMounts").and_then(|m| m.as_sequence_mut()) else { continue };
+            mounts.retain(|m| !is_api_access(m));
+            if mounts.is_empty() {
+                container.shift_remove("volumeMounts");
+            }
+        }
+    }
+}
+
+/// A line of a diff between the live object and the object after the apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Context(String),
+    Added(String),
+    Removed(String),
+    /// Unchanged lines left out between two changes.
+    Skipped(usize),
+}
+
+/// Line diff of `old` and `new`, keeping a few unchanged lines around each change. Empty when
+/// the texts are equal.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // Myers keeps large manifests with scattered edits fast, where a full LCS table would not.
+    let ops = capture_diff_slices(Algorithm::Myers, &old, &new);
+    if ops.iter().all(|op| op.tag() == DiffTag::Equal) {
+        return Vec::new();
+    }
+    let full = ops
+        .iter()
+        .flat_map(|op| op.iter_changes(&old, &new))
+        .map(|change| match change.tag() {
+            ChangeTag::Equal => DiffLine::Context(change.value().to_string()),
+            ChangeTag::Delete => DiffLine::Removed(change.value().to_string()),
+            ChangeTag::Insert => DiffLine::Added(change.value().to_string()),
+        })
+        .collect();
+    collapse_context(full)
+}
+
+fn collapse_context(full: Vec<DiffLine>) -> Vec<DiffLine> {
+    let changes: Vec<usize> =
+        full.iter().enumerate().filter(|(_, l)| !matches!(l, DiffLine::Context(_))).map(|(i, _)| i).collect();
+    let near_change = |i: usize| {
+        let next = changes.partition_point(|&c| c < i);
+        let after = changes.get(next).is_some_and(|&c| c - i <= DIFF_CONTEXT);
+        let before = next > 0 && i - changes[next - 1] <= DIFF_CONTEXT;
+        after || before
+    };
+
+    let mut lines = Vec::new();
+    let mut skipped = 0;
+    for (i, line) in full.into_iter().enumerate() {
+        if matches!(line, DiffLine::Context(_)) && !near_change(i) {
+            skipped += 1;
+            continue;
+        }
+        if skipped > 0 {
+            lines.push(DiffLine::Skipped(skipped));
+            skipped = 0;
+        }
+        lines.push(line);
+    }
+    if skipped > 0 {
+        lines.push(DiffLine::Skipped(skipped));
+    }
+    lines
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyChange {
+    Create,
+    Update,
+    Unchanged,
+}
+
+/// What a server-side dry run says applying a document would do.
+#[derive(Debug, Clone)]
+pub struct PlannedChange {
+    pub change: ApplyChange,
+    pub diff: Vec<DiffLine>,
+}
+
+/// A manifest resolved against the cluster, ready to be applied.
+#[derive(Debug, Clone)]
+pub struct PlannedApply {
+    pub source: String,
+    pub kind: String,
+    pub name: String,
+    /// Namespace the object lands in; `None` for cluster-scoped kinds.
+    pub namespace: Option<String>,
+    /// The dry run's verdict, or why the document cannot be applied.
+    pub outcome: Result<PlannedChange, String>,
+    object: DynamicObject,
+    resource: Option<ApiResource>,
+    force: bool,
+}
+
+impl PlannedApply {
+    /// `Deployment web/api`, or `ClusterRole reader` for cluster-scoped kinds.
+    pub fn target(&self) -> String {
+        match &self.namespace {
+            Some(ns) => format!("{} {ns}/{}", self.kind, self.name),
+            None => format!("{} {}", self.kind, self.name),
+        }
+    }
+
+    /// Server-side apply parameters; forcing takes over fields other managers own instead of
+    /// failing with a conflict.
+    fn patch_params(&self) -> PatchParams {
+        let params = PatchParams::apply(FIELD_MANAGER);
+        if self.force {
+            params.force()
+        } else {
+            params
+        }
+    }
+}
+
+/// Dry-runs a server-side apply of every manifest, placing namespaced objects without a
+/// namespace in `default_namespace`. With `force`, fields owned by other managers are taken over
+/// rather than reported as conflicts.
+pub async fn plan_apply(
+    client: &Client,
+    manifests: Vec<Manifest>,
+    default_namespace: &str,
+    force: bool,
+) -> Vec<PlannedApply> {
+    let mut discovered: HashMap<GroupVersionKind, Result<(ApiResource, bool), String>> = HashMap::new();
+    let mut plans = Vec::with_capacity(manifests.len());
+    for manifest in manifests {
+        if !discovered.contains_key(&manifest.gvk) {
+            let found = pinned_kind(client, &manifest.gvk)
+                .await
+                .map(|(resource, caps)| (resource, caps.scope == Scope::Namespaced))
+                .map_err(|e| format!("unknown kind {}: {e}", manifest.gvk.kind));
+            discovered.insert(manifest.gvk.clone(), found);
+        }
+        let found = discovered[&manifest.gvk].clone();
+
+        let mut object = manifest.object;
+        let name = object.metadata.name.clone().unwrap_or_default();
+        let namespace = match &found {
+            Ok((_, true)) => Some(object.metadata.namespace.clone().unwrap_or_else(|| default_namespace.to_string())),
+            _ => None,
+        };
+        object.metadata.namespace = namespace.clone();
+        let mut plan = PlannedApply {
+            source: manifest.source,
+            kind: manifest.gvk.kind,
+            name,
+            namespace,
+            outcome: Err(String::new()),
+            object,
+            resource: None,
+            force,
+        };
+        plan.outcome = match found {
+            Ok(_) if plan.name.is_empty() => Err("metadata.name is required".into()),
+            Ok((resource, _)) => {
+                let outcome = dry_run(client, &plan, &resource).await.map_err(|e| format!("{e:#}"));
+                plan.resource = Some(resource);
+                outcome
+            }
+            Err(e) => Err(e),
+        };
+        plans.push(plan);
+    }
+    plans
+}
+
+fn api_for(client: &Client, resource: &ApiResource, namespace: Option<&str>) -> Api<DynamicObject> {
+    match namespace {
+        Some(ns) => Api::namespaced_with(client.clone(), ns, resource),
+        None => Api::all_with(client.clone(), resource),
+    }
+}
+
+async fn dry_run(client: &Client, plan: &PlannedApply, resource: &ApiResource) -> Result<PlannedChange> {
+    let api = api_for(client, resource, plan.namespace.as_deref());
+    let current = api.get_opt(&plan.name).await?;
+    let params = plan.patch_params().dry_run();
+    let applied = api.patch(&plan.name, &params, &Patch::Apply(&plan.object)).await?;
+
+    let before = current.as_ref().map(comparable_yaml).transpose()?.unwrap_or_default();
+    let after = comparable_yaml(&applied)?;
+    let diff = diff_lines(&before, &after);
+    let change = match current {
+        None => ApplyChange::Create,
+        Some(_) if diff.is_empty() => ApplyChange::Unchanged,
+        Some(_) => ApplyChange::Update,
+    };
+    Ok(PlannedChange { change, diff })
+}
+
+/// The object as YAML without the bookkeeping every write changes.
+fn comparable_yaml(object: &DynamicObject) -> Result<String> {
+    let mut object = object.clone();
+    let meta = &mut object.metadata;
+    meta.managed_fields = None;
+    meta.resource_version = None;
+    meta.generation = None;
+    meta.uid = None;
+    meta.creation_timestamp = None;
+    Ok(serde_yaml::to_string(&object)?)
+}
+
+/// Server-side applies a planned document for real.
+pub async fn apply(client: &Client, plan: &PlannedApply) -> Result<()> {
+    let Some(resource) = &plan.resource else { bail!("{} was not planned", plan.target()) };
+    let api = api_for(client, resource, plan.namespace.as_deref());
+    let result = api
+        .patch(&plan.name, &plan.patch_params(), &Patch::Apply(&plan.object))
+        .await
+        .map(|_| ())
+        .map_err(anyhow::Error::from);
+    audit::record("apply", &resource.plural, &plan.name, plan.namespace.as_deref().unwrap_or_default(), &result);
+    result
+}
+
+/// Creates an object again from the manifest captured before it was deleted. Fails when an
+/// object of that name has appeared since.
+pub async fn recreate(client: &Client, manifest: &str) -> Result<()> {
+    let Some(mut document) = parse_manifests(manifest, "manifest")?.into_iter().next() else { bail!("empty manifest") };
+    unbind(&document.gvk.kind, &mut document.object.data);
+    let (resource, caps) =
+        pinned_kind(client, &document.gvk).await.with_context(|| format!("unknown kind {}", document.gvk.kind))?;
+    let name = document.object.metadata.name.clone().unwrap_or_default();
+    let namespace = document.object.metadata.namespace.clone().filter(|_| caps.scope == Scope::Namespaced);
+    let api = api_for(client, &resource, namespace.as_deref());
+    let params = PostParams { field_manager: Some(FIELD_MANAGER.to_string()), ..PostParams::default() };
+    let result = api.create(&params, &document.object).await.map(|_| ()).map_err(anyhow::Error::from);
+    audit::record("recreate", &resource.plural, &name, namespace.as_deref().unwrap_or_default(), &result);
+    result
+}
+
+/// Drops the bindings a recreated object cannot take over: the node a pod ran on and the volume
+/// a claim was bound to, which is released by the delete and left with the old claim's uid.
+fn unbind(kind: &str, data: &mut serde_json::Value) {
+    let field = match kind {
+        "Pod" => "nodeName",
+        "PersistentVolumeClaim" => "volumeName",
+        _ => return,
+    };
+    if let Some(spec) = data.get_mut("spec").and_then(|s| s.as_object_mut()) {
+        spec.remove(field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_document_files_split_into_manifests() {
+        let text = "\
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: api
+---
+# only a comment
+---
+apiVersion: v1
+kind: List
+items:
+- apiVersion: v1
+  kind: ConfigMap
+  metadata: { name: settings, namespace: web }
+- apiVersion: rbac.authorization.k8s.io/v1
+  kind: ClusterRole
+  metadata: { name: reader }
+";
+        let manifests = parse_manifests(text, "app.yaml").unwrap();
+        let summary: Vec<(&str, &str, &str)> =
+            manifests.iter().map(|m| (m.source.as_str(), m.gvk.group.as_str(), m.gvk.kind.as_str())).collect();
+        assert_eq!(
+            summary,
+            [
+                ("app.yaml#1", "apps", "Deployment"),
+                ("app.yaml#2", "", "ConfigMap"),
+                ("app.yaml#3", "rbac.authorization.k8s.io", "ClusterRole")
+            ]
+        );
+        assert_eq!(manifests[1].object.metadata.namespace.as_deref(), Some("web"));
+        assert!(parse_manifests("metadata: { name: x }", "bad.yaml").is_err());
+    }
+
+    #[test]
+    fn directories_load_yaml_files_in_name_order() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("b.yaml"), "apiVersion: v1\nkind: Secret\nmetadata: { name: b }\n").unwrap();
+        fs::write(dir.path().join("a.yml"), "apiVersion: v1\nkind: ConfigMap\nmetadata: { name: a }\n").unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a manifest").unwrap();
+        let manifests = load_manifests(dir.path()).unwrap();
+        let sources: Vec<&str> = manifests.iter().map(|m| m.source.as_str()).collect();
+        assert_eq!(sources, ["a.yml", "b.yaml"]);
+        assert!(load_manifests(&dir.path().join("missing.yaml")).is_err());
+    }
+
+    #[test]
+    fn diff_keeps_context_around_changes_only() {
+        let old = (1..=12).map(|i| format!("line{i}")).collect::<Vec<_>>().join("\n");
+        let new = old.replace("line6", "line6b");
+        let diff = diff_lines(&old, &new);
+        assert_eq!(diff.first(), Some(&DiffLine::Skipped(2)));
+        assert_eq!(diff.last(), Some(&DiffLine::Skipped(3)));
+        assert!(diff.contains(&DiffLine::Removed("line6".into())));
+        assert!(diff.contains(&DiffLine::Added("line6b".into())));
+        assert_eq!(diff.iter().filter(|l| matches!(l, DiffLine::Context(_))).count(), 6);
+        assert!(diff_lines(&old, &old).is_empty());
+        assert_eq!(diff_lines("", "a: 1"), [DiffLine::Added("a: 1".into())]);
+    }
+
+    #[test]
+    fn clean_yaml_drops_server_populated_fields() {
+        let text = "\
+apiVersion: v1
+kind: Service
+metadata:
+  name: api
+  namespace: web
+  uid: 1234
+  resourceVersion: \"42\"
+  creationTimestamp: 2024-01-01T00:00:00Z
+  managedFields:
+  - manager: kubectl
+  annotations:
+    kubectl.kubernetes.io/last-applied-configuration: '{}'
+  labels:
+    app: api
+spec:
+  clusterIP: 10.0.0.1
+  ports:
+  - port: 80
+status:
+  loadBalancer: {}
+";
+        let cleaned = clean_yaml(text).unwrap();
+        assert_eq!(
+            cleaned,
+            "apiVersion: v1\nkind: Service\nmetadata:\n  name: api\n  namespace: web\n  labels:\n    app: api\n\
+             spec:\n  ports:\n  - port: 80\n"
+        );
+        assert!(clean_yaml("Routes of web/api:\n  /  -> api:80").is_err());
+    }
+
+    #[test]
+    fn recreated_claims_and_pods_are_unbound() {
+        let text =
+            "apiVersion: v1\nkind: PersistentVolumeClaim\nmetadata:\n  name: data\nspec:\n  volumeName: pv-1\n  \
+                    storageClassName: ssd\n";
+        let mut claim = parse_manifests(text, "manifest").unwrap().remove(0);
+        unbind(&claim.gvk.kind, &mut claim.object.data);
+        assert_eq!(claim.object.data["spec"], serde_json::json!({ "storageClassName": "ssd" }));
+
+        let mut pod = serde_json::json!({ "spec": { "nodeName": "node-1", "containers": [] } });
+        unbind("Pod", &mut pod);
+        assert_eq!(pod, serde_json::json!({ "spec": { "containers": [] } }));
+    }
+
+    #[test]
+    fn clean_yaml_keeps_headless_services_headless() {
+        let text =
+            "apiVersion: v1\nkind: Service\nmetadata:\n  name: db\nspec:\n  clusterIP: None\n  clusterIPs:\n  - None\n";
+        assert_eq!(clean_yaml(text).unwrap(), text);
+    }
+
+    #[test]
+    fn clean_yaml_drops_the_node_and_token_volume_of_pods() {
+        let text = "\
+apiVersion: v1
+kind: Pod
+metadata:
+  name: api-0
+spec:
+  nodeName: node-1
+  containers:
+  - name: api
+    volumeMounts:
+    - name: data
+      mountPath: /data
+    - name: kube-api-access-x7k2p
+      mountPath: /var/run/secrets/kubernetes.io/serviceaccount
+  volumes:
+  - name: data
+    emptyDir: {}
+  - name: kube-api-access-x7k2p
+    projected: {}
+";
+        assert_eq!(
+            clean_yaml(text).unwrap(),
+            "apiVersion: v1\nkind: Pod\nmetadata:\n  name: api-0\nspec:\n  containers:\n  - name: api\n    volumeMounts:\n    \
+             - name: data\n      mountPath: /data\n  volumes:\n  - name: data\n    emptyDir: {}\n"
+        );
+    }
+}
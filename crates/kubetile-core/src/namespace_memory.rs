@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Recently used namespaces kept per context.
+const RECENT_LIMIT: usize = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct ContextNamespaces {
+    /// Most recent first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    recent: Vec<String>,
+    /// In the order they were pinned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    favorites: Vec<String>,
+}
+
+/// Recently used and pinned namespaces of each context, listed first by the namespace selector.
+/// `default()` keeps them in memory only.
+#[derive(Debug, Clone, Default)]
+pub struct NamespaceMemory {
+    contexts: BTreeMap<String, ContextNamespaces>,
+    path: Option<PathBuf>,
+}
+
+impl NamespaceMemory {
+    pub fn load() -> Self {
+        Self::load_from(namespace_memory_path())
+    }
+
+    pub fn load_from(path: PathBuf) -> Self {
+        let contexts =
+            std::fs::read_to_string(&path).ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        Self { contexts, path: Some(path) }
+    }
+
+    pub fn recent(&self, context: &str) -> &[String] {
+        self.contexts.get(context).map_or(&[], |c| &c.recent)
+    }
+
+    pub fn favorites(&self, context: &str) -> &[String] {
+        self.contexts.get(context).map_or(&[], |c| &c.favorites)
+    }
+
+    /// Moves `namespace` to the front of the context's recent list.
+    pub fn record_use(&mut self, context: &str, namespace: &str) -> io::Result<()> {
+        if self.recent(context).first().is_some_and(|n| n == namespace) {
+            return Ok(());
+        }
+        let entry = self.contexts.entry(context.to_string()).or_default();
+        entry.recent.retain(|n| n != namespace);
+        entry.recent.insert(0, namespace.to_string());
+        entry.recent.truncate(RECENT_LIMIT);
+        self.save()
+    }
+
+    /// Pins or unpins `namespace`; returns whether it is a favorite now.
+    pub fn toggle_favorite(&mut self, context: &str, namespace: &str) -> io::Result<bool> {
+        let entry = self.contexts.entry(context.to_string()).or_default();
+        let pinned = match entry.favorites.iter().position(|n| n == namespace) {
+            Some(i) => {
+                entry.favorites.remove(i);
+                false
+            }
+            None => {
+                entry.favorites.push(namespace.to_string());
+                true
+            }
+        };
+        self.save()?;
+        Ok(pinned)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(&self.contexts).map_err(io::Error::other)?;
+        std::fs::write(path, data)
+    }
+}
+
+fn namespace_memory_path() -> PathBuf {
+    dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("namespace_memory.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_namespaces_are_deduplicated_and_capped() {
+        let mut memory = NamespaceMemory::default();
+        for ns in ["a", "b", "c", "a", "d", "e", "f"] {
+            memory.record_use("prod", ns).unwrap();
+        }
+        assert_eq!(memory.recent("prod"), ["f", "e", "d", "a", "c"]);
+        assert!(memory.recent("staging").is_empty());
+    }
+
+    #[test]
+    fn favorites_toggle_and_survive_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("namespace_memory.json");
+
+        let mut memory = NamespaceMemory::load_from(path.clone());
+        assert!(memory.toggle_favorite("prod", "payments").unwrap());
+        assert!(memory.toggle_favorite("prod", "web").unwrap());
+        assert!(!memory.toggle_favorite("prod", "payments").unwrap());
+        memory.record_use("prod", "api").unwrap();
+
+        let reloaded = NamespaceMemory::load_from(path);
+        assert_eq!(reloaded.favorites("prod"), ["web"]);
+        assert_eq!(reloaded.recent("prod"), ["api"]);
+    }
+}
@@ -0,0 +1,313 @@
+use anyhow::Result;
+use jiff::tz::TimeZone;
+use jiff::{SignedDuration, Timestamp};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Event, Namespace, Pod, ResourceQuota};
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::cluster_overview::section_items;
+use crate::resource::{format_duration, parse_quantity, DetailSection, ResourceSummary};
+use crate::resources::{NamespaceSummary, ResourceQuotaSummary};
+use crate::volume_stats::format_bytes;
+
+const MAX_LISTED_EVENTS: usize = 10;
+pub(crate) const EVENT_WINDOW: SignedDuration = SignedDuration::from_hours(1);
+
+/// Everything in a namespace the overview is built from.
+#[derive(Debug, Default)]
+pub struct NamespaceInventory {
+    /// Object counts per workload kind, in display order.
+    pub workloads: Vec<(&'static str, usize)>,
+    pub pods: Vec<Pod>,
+    pub quotas: Vec<ResourceQuota>,
+    pub events: Vec<Event>,
+    /// Lists that could not be read, e.g. because they are forbidden, keyed by `Deployments` …
+    /// `CronJobs`, `Pods`, `ResourceQuotas` or `Events`; their sections show the error instead.
+    pub errors: Vec<(&'static str, String)>,
+}
+
+impl NamespaceInventory {
+    /// Why the list behind `list` could not be read, if it failed.
+    pub fn error(&self, list: &str) -> Option<&str> {
+        self.errors.iter().find(|(l, _)| *l == list).map(|(_, e)| e.as_str())
+    }
+}
+
+/// Reads a Namespace and lists its workloads, pods, quotas and warning events, returning its
+/// detail sections plus the overview. A list that fails, e.g. because it is forbidden, only marks
+/// its own sections; the overview is an error only when the Namespace itself cannot be read.
+pub async fn namespace_overview_sections(client: Client, namespace: &str, tz: &TimeZone) -> Result<Vec<DetailSection>> {
+    let ns = Api::<Namespace>::all(client.clone()).get(namespace).await?;
+    let lp = ListParams::default();
+    let warnings = ListParams::default().fields("type=Warning");
+    let deployments = Api::<Deployment>::namespaced(client.clone(), namespace);
+    let statefulsets = Api::<StatefulSet>::namespaced(client.clone(), namespace);
+    let daemonsets = Api::<DaemonSet>::namespaced(client.clone(), namespace);
+    let jobs = Api::<Job>::namespaced(client.clone(), namespace);
+    let cronjobs = Api::<CronJob>::namespaced(client.clone(), namespace);
+    let pods = Api::<Pod>::namespaced(client.clone(), namespace);
+    let quotas = Api::<ResourceQuota>::namespaced(client.clone(), namespace);
+    let events = Api::<Event>::namespaced(client, namespace);
+    let (deployments, statefulsets, daemonsets, jobs, cronjobs, pods, quotas, events) = tokio::join!(
+        async { deployments.list(&lp).await.map(|l| l.items) },
+        async { statefulsets.list(&lp).await.map(|l| l.items) },
+        async { daemonsets.list(&lp).await.map(|l| l.items) },
+        async { jobs.list(&lp).await.map(|l| l.items) },
+        async { cronjobs.list(&lp).await.map(|l| l.items) },
+        async { pods.list(&lp).await.map(|l| l.items) },
+        async { quotas.list(&lp).await.map(|l| l.items) },
+        async { events.list(&warnings).await.map(|l| l.items) },
+    );
+    let mut errors = Vec::new();
+    let workloads = vec![
+        ("Deployments", section_items("Deployments", deployments, &mut errors).len()),
+        ("StatefulSets", section_items("StatefulSets", statefulsets, &mut errors).len()),
+        ("DaemonSets", section_items("DaemonSets", daemonsets, &mut errors).len()),
+        ("Jobs", section_items("Jobs", jobs, &mut errors).len()),
+        ("CronJobs", section_items("CronJobs", cronjobs, &mut errors).len()),
+    ];
+    let pods = section_items("Pods", pods, &mut errors);
+    let quotas = section_items("ResourceQuotas", quotas, &mut errors);
+    let events = section_items("Events", events, &mut errors);
+
+    let inventory = NamespaceInventory { workloads, pods, quotas, events, errors };
+    let mut sections = NamespaceSummary::from(&ns).detail_sections(tz);
+    sections.extend(overview_sections(&inventory, Timestamp::now()));
+    Ok(sections)
+}
+
+/// The "Workloads", "Requests", "Quotas" and "Warning Events" sections of a namespace overview.
+pub fn overview_sections(inventory: &NamespaceInventory, now: Timestamp) -> Vec<DetailSection> {
+    vec![
+        workloads_section(inventory),
+        or_error(inventory, "Pods", requests_section(&inventory.pods)),
+        or_error(inventory, "ResourceQuotas", quotas_section(&inventory.quotas)),
+        or_error(inventory, "Events", events_section(&inventory.events, now)),
+    ]
+}
+
+/// `section` with its fields replaced by the error of `list`, when that list could not be read.
+fn or_error(inventory: &NamespaceInventory, list: &str, section: DetailSection) -> DetailSection {
+    match inventory.error(list) {
+        Some(error) => DetailSection { title: section.title, fields: vec![(String::new(), error.to_string())] },
+        None => section,
+    }
+}
+
+fn workloads_section(inventory: &NamespaceInventory) -> DetailSection {
+    let mut fields: Vec<(String, String)> = inventory
+        .workloads
+        .iter()
+        .map(|(kind, n)| (kind.to_string(), inventory.error(kind).map_or_else(|| n.to_string(), str::to_string)))
+        .collect();
+    let running = inventory.pods.iter().filter(|p| pod_phase(p) == Some("Running")).count();
+    let pods = match inventory.error("Pods") {
+        Some(error) => error.to_string(),
+        None => format!("{} ({running} running)", inventory.pods.len()),
+    };
+    fields.push(("Pods".into(), pods));
+    DetailSection { title: "Workloads".into(), fields }
+}
+
+/// Sums container requests of pods that still hold their resources (not Succeeded or Failed).
+fn requests_section(pods: &[Pod]) -> DetailSection {
+    let mut cpu_millis = 0.0;
+    let mut memory_bytes = 0.0;
+    let mut without_requests = 0;
+    let active = pods.iter().filter(|p| !matches!(pod_phase(p), Some("Succeeded" | "Failed")));
+    for pod in active {
+        let containers = pod.spec.as_ref().map(|s| s.containers.as_slice()).unwrap_or_default();
+        let mut has_requests = false;
+        for requests in containers.iter().filter_map(|c| c.resources.as_ref()?.requests.as_ref()) {
+            if let Some(cpu) = requests.get("cpu").and_then(|q| parse_quantity(&q.0)) {
+                cpu_millis += cpu * 1000.0;
+                has_requests = true;
+            }
+            if let Some(memory) = requests.get("memory").and_then(|q| parse_quantity(&q.0)) {
+                memory_bytes += memory;
+                has_requests = true;
+            }
+        }
+        if !has_requests {
+            without_requests += 1;
+        }
+    }
+
+    let mut fields = vec![
+        ("CPU".to_string(), format!("{}m", cpu_millis.round() as u64)),
+        ("Memory".to_string(), format_bytes(memory_bytes.round() as u64)),
+    ];
+    if without_requests > 0 {
+        fields.push(("Without requests".into(), format!("{without_requests} pod(s)")));
+    }
+    DetailSection { title: "Requests".into(), fields }
+}
+
+fn quotas_section(quotas: &[ResourceQuota]) -> DetailSection {
+    let mut fields = Vec::new();
+    for quota in quotas.iter().map(ResourceQuotaSummary::from) {
+        for usage in &quota.usage {
+            fields.push((format!("{}: {}", quota.name, usage.resource), usage.display()));
+        }
+    }
+    if fields.is_empty() {
+        fields.push((String::new(), "No ResourceQuotas".into()));
+    }
+    DetailSection { title: "Quotas".into(), fields }
+}
+
+/// Warning events seen within the last hour, newest first.
+fn events_section(events: &[Event], now: Timestamp) -> DetailSection {
+    let mut recent: Vec<(Timestamp, &Event)> = events
+        .iter()
+        .filter(|e| e.type_.as_deref() == Some("Warning"))
+        .filter_map(|e| Some((last_seen(e)?, e)))
+        .filter(|(seen, _)| now.duration_since(*seen) <= EVENT_WINDOW)
+        .collect();
+    recent.sort_by_key(|(seen, _)| std::cmp::Reverse(*seen));
+
+    let mut fields = vec![("Count".to_string(), recent.len().to_string())];
+    for (seen, event) in recent.iter().take(MAX_LISTED_EVENTS) {
+        let object = &event.involved_object;
+        let ago = format_duration(now.duration_since(*seen).unsigned_abs());
+        let times = event.count.filter(|&n| n > 1).map(|n| format!(" (x{n})")).unwrap_or_default();
+        fields.push((
+            event.reason.clone().unwrap_or_default(),
+            format!(
+                "{ago} ago, {}/{}: {}{times}",
+                object.kind.as_deref().unwrap_or("?"),
+                object.name.as_deref().unwrap_or("?"),
+                event.message.as_deref().unwrap_or_default().trim()
+            ),
+        ));
+    }
+    if recent.len() > MAX_LISTED_EVENTS {
+        fields.push((String::new(), format!("… {} more", recent.len() - MAX_LISTED_EVENTS)));
+    }
+    DetailSection { title: "Warning Events (last hour)".into(), fields }
+}
+
+pub(crate) fn last_seen(event: &Event) -> Option<Timestamp> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|t| t.0)
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+pub(crate) fn pod_phase(pod: &Pod) -> Option<&str> {
+    pod.status.as_ref()?.phase.as_deref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod(name: &str, phase: &str, requests: serde_json::Value) -> Pod {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": name, "namespace": "web" },
+            "spec": { "containers": [ { "name": "app", "resources": { "requests": requests } } ] },
+            "status": { "phase": phase }
+        }))
+        .unwrap()
+    }
+
+    fn event(reason: &str, last_seen: &str, type_: &str) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Event",
+            "metadata": { "name": format!("{reason}.1"), "namespace": "web" },
+            "involvedObject": { "kind": "Pod", "name": "api-0" },
+            "reason": reason,
+            "message": "Back-off restarting failed container",
+            "type": type_,
+            "count": 4,
+            "lastTimestamp": last_seen
+        }))
+        .unwrap()
+    }
+
+    fn fields(section: &DetailSection) -> Vec<(&str, &str)> {
+        section.fields.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()
+    }
+
+    #[test]
+    fn summarizes_workloads_requests_quotas_and_recent_warnings() {
+        let quota: ResourceQuota = serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ResourceQuota",
+            "metadata": { "name": "compute", "namespace": "web" },
+            "status": {
+                "hard": { "requests.cpu": "2", "pods": "10" },
+                "used": { "requests.cpu": "500m", "pods": "3" }
+            }
+        }))
+        .unwrap();
+        let inventory = NamespaceInventory {
+            workloads: vec![("Deployments", 2), ("Jobs", 1)],
+            pods: vec![
+                pod("api-0", "Running", serde_json::json!({ "cpu": "250m", "memory": "512Mi" })),
+                pod("api-1", "Running", serde_json::json!({ "cpu": "0.25", "memory": "512Mi" })),
+                pod("migrate", "Succeeded", serde_json::json!({ "cpu": "1", "memory": "1Gi" })),
+                pod("sidecar", "Pending", serde_json::json!({})),
+            ],
+            quotas: vec![quota],
+            events: vec![
+                event("BackOff", "2024-03-01T11:50:00Z", "Warning"),
+                event("FailedMount", "2024-03-01T10:00:00Z", "Warning"),
+                event("Pulled", "2024-03-01T11:55:00Z", "Normal"),
+            ],
+            errors: Vec::new(),
+        };
+        let sections = overview_sections(&inventory, "2024-03-01T12:00:00Z".parse().unwrap());
+
+        assert_eq!(fields(&sections[0]), vec![("Deployments", "2"), ("Jobs", "1"), ("Pods", "4 (2 running)")]);
+        assert_eq!(fields(&sections[1]), vec![("CPU", "500m"), ("Memory", "1.0Gi"), ("Without requests", "1 pod(s)")]);
+        assert_eq!(
+            fields(&sections[2]),
+            vec![("compute: pods", "3 / 10 (30%)"), ("compute: requests.cpu", "500m / 2 (25%)")]
+        );
+        assert_eq!(sections[3].title, "Warning Events (last hour)");
+        assert_eq!(
+            fields(&sections[3]),
+            vec![("Count", "1"), ("BackOff", "10m ago, Pod/api-0: Back-off restarting failed container (x4)")]
+        );
+    }
+
+    #[test]
+    fn forbidden_lists_mark_only_their_sections() {
+        let inventory = NamespaceInventory {
+            workloads: vec![("Deployments", 2), ("Jobs", 0)],
+            pods: Vec::new(),
+            quotas: Vec::new(),
+            events: vec![event("BackOff", "2024-03-01T11:50:00Z", "Warning")],
+            errors: vec![
+                ("Jobs", "jobs.batch is forbidden".into()),
+                ("Pods", "pods is forbidden".into()),
+                ("ResourceQuotas", "resourcequotas is forbidden".into()),
+            ],
+        };
+        let sections = overview_sections(&inventory, "2024-03-01T12:00:00Z".parse().unwrap());
+
+        assert_eq!(
+            fields(&sections[0]),
+            vec![("Deployments", "2"), ("Jobs", "jobs.batch is forbidden"), ("Pods", "pods is forbidden")]
+        );
+        assert_eq!(sections[1].title, "Requests");
+        assert_eq!(fields(&sections[1]), vec![("", "pods is forbidden")]);
+        assert_eq!(fields(&sections[2]), vec![("", "resourcequotas is forbidden")]);
+        assert_eq!(fields(&sections[3])[0], ("Count", "1"));
+    }
+
+    #[test]
+    fn empty_namespace_says_so() {
+        let sections = overview_sections(&NamespaceInventory::default(), Timestamp::now());
+        assert_eq!(fields(&sections[2]), vec![("", "No ResourceQuotas")]);
+        assert_eq!(fields(&sections[3]), vec![("Count", "0")]);
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use jiff::tz::TimeZone;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::networking::v1::{NetworkPolicy, NetworkPolicyPeer, NetworkPolicyPort};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::resource::{DetailSection, ResourceSummary};
+use crate::resources::NetworkPolicySummary;
+
+const MAX_LISTED_PODS: usize = 20;
+
+/// Reads a NetworkPolicy and the pods of its namespace, returning its detail sections plus the
+/// covered pods and an ingress/egress tree.
+pub async fn network_policy_detail_sections(
+    client: Client,
+    name: &str,
+    namespace: &str,
+    tz: &TimeZone,
+) -> Result<Vec<DetailSection>> {
+    let policy = Api::<NetworkPolicy>::namespaced(client.clone(), namespace).get(name).await?;
+    let pods = Api::<Pod>::namespaced(client, namespace).list(&ListParams::default()).await?.items;
+    let mut sections = NetworkPolicySummary::from(&policy).detail_sections(tz);
+    sections.extend(policy_sections(&policy, &pods));
+    Ok(sections)
+}
+
+/// Which directions a policy restricts. Without `policyTypes`, ingress is always restricted and
+/// egress only when the policy has egress rules.
+pub fn policy_types(policy: &NetworkPolicy) -> (bool, bool) {
+    let Some(spec) = policy.spec.as_ref() else { return (false, false) };
+    match &spec.policy_types {
+        Some(types) => (types.iter().any(|t| t == "Ingress"), types.iter().any(|t| t == "Egress")),
+        None => (true, spec.egress.as_ref().is_some_and(|e| !e.is_empty())),
+    }
+}
+
+/// Renders a label selector the way `kubectl` does, or `<all>` when it selects everything.
+pub fn format_selector(selector: &LabelSelector) -> String {
+    let mut parts: Vec<String> = selector.match_labels.iter().flatten().map(|(k, v)| format!("{k}={v}")).collect();
+    for expr in selector.match_expressions.iter().flatten() {
+        let values = expr.values.as_deref().unwrap_or_default().join(",");
+        parts.push(match expr.operator.as_str() {
+            "In" => format!("{} in ({values})", expr.key),
+            "NotIn" => format!("{} notin ({values})", expr.key),
+            "Exists" => expr.key.clone(),
+            "DoesNotExist" => format!("!{}", expr.key),
+            other => format!("{} {other} ({values})", expr.key),
+        });
+    }
+    if parts.is_empty() {
+        "<all>".into()
+    } else {
+        parts.join(",")
+    }
+}
+
+pub fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector.match_labels.iter().flatten().all(|(k, v)| labels.get(k) == Some(v));
+    labels_match
+        && selector.match_expressions.iter().flatten().all(|expr| {
+            let values = expr.values.as_deref().unwrap_or_default();
+            let value = labels.get(&expr.key);
+            match expr.operator.as_str() {
+                "In" => value.is_some_and(|v| values.contains(v)),
+                "NotIn" => value.is_none_or(|v| !values.contains(v)),
+                "Exists" => value.is_some(),
+                "DoesNotExist" => value.is_none(),
+                _ => false,
+            }
+        })
+}
+
+/// The "Covered Pods", "Ingress" and "Egress" sections of a policy, evaluated against `pods`.
+pub fn policy_sections(policy: &NetworkPolicy, pods: &[Pod]) -> Vec<DetailSection> {
+    let Some(spec) = policy.spec.as_ref() else { return Vec::new() };
+    let (restricts_ingress, restricts_egress) = policy_types(policy);
+
+    let pod_selector = spec.pod_selector.clone().unwrap_or_default();
+    let empty = BTreeMap::new();
+    let mut covered: Vec<&str> = pods
+        .iter()
+        .filter(|p| selector_matches(&pod_selector, p.metadata.labels.as_ref().unwrap_or(&empty)))
+        .filter_map(|p| p.metadata.name.as_deref())
+        .collect();
+    covered.sort_unstable();
+    let mut covered_fields = vec![
+        ("Selector".to_string(), format_selector(&pod_selector)),
+        ("Matches".to_string(), format!("{} of {} pods", covered.len(), pods.len())),
+    ];
+    covered_fields.extend(covered.iter().take(MAX_LISTED_PODS).map(|name| (String::new(), name.to_string())));
+    if covered.len() > MAX_LISTED_PODS {
+        covered_fields.push((String::new(), format!("… {} more", covered.len() - MAX_LISTED_PODS)));
+    }
+
+    let ingress = spec
+        .ingress
+        .iter()
+        .flatten()
+        .map(|rule| (rule.ports.as_deref().unwrap_or_default(), rule.from.as_deref().unwrap_or_default()));
+    let egress = spec
+        .egress
+        .iter()
+        .flatten()
+        .map(|rule| (rule.ports.as_deref().unwrap_or_default(), rule.to.as_deref().unwrap_or_default()));
+
+    vec![
+        DetailSection { title: "Covered Pods".into(), fields: covered_fields },
+        DetailSection { title: "Ingress".into(), fields: direction_tree(restricts_ingress, "from", ingress) },
+        DetailSection { title: "Egress".into(), fields: direction_tree(restricts_egress, "to", egress) },
+    ]
+}
+
+fn direction_tree<'a>(
+    restricted: bool,
+    verb: &str,
+    rules: impl Iterator<Item = (&'a [NetworkPolicyPort], &'a [NetworkPolicyPeer])>,
+) -> Vec<(String, String)> {
+    if !restricted {
+        return vec![(String::new(), "Not restricted by this policy".into())];
+    }
+    let mut fields = Vec::new();
+    for (idx, (ports, peers)) in rules.enumerate() {
+        let ports = if ports.is_empty() {
+            "all ports".to_string()
+        } else {
+            ports.iter().map(format_port).collect::<Vec<_>>().join(", ")
+        };
+        fields.push((format!("Rule {}", idx + 1), ports));
+        if peers.is_empty() {
+            fields.push((String::new(), format!("└─ {verb} anywhere")));
+            continue;
+        }
+        for (i, peer) in peers.iter().enumerate() {
+            let branch = if i + 1 == peers.len() { "└─" } else { "├─" };
+            fields.push((String::new(), format!("{branch} {verb} {}", format_peer(peer))));
+        }
+    }
+    if fields.is_empty() {
+        fields.push((String::new(), "Deny all".into()));
+    }
+    fields
+}
+
+fn format_port(port: &NetworkPolicyPort) -> String {
+    let protocol = port.protocol.as_deref().unwrap_or("TCP");
+    match (&port.port, port.end_port) {
+        (Some(IntOrString::Int(start)), Some(end)) => format!("{start}-{end}/{protocol}"),
+        (Some(IntOrString::Int(n)), None) => format!("{n}/{protocol}"),
+        (Some(IntOrString::String(name)), _) => format!("{name}/{protocol}"),
+        (None, _) => format!("all/{protocol}"),
+    }
+}
+
+fn format_peer(peer: &NetworkPolicyPeer) -> String {
+    if let Some(block) = &peer.ip_block {
+        return match block.except.as_deref().filter(|e| !e.is_empty()) {
+            Some(except) => format!("{} except {}", block.cidr, except.join(", ")),
+            None => block.cidr.clone(),
+        };
+    }
+    let namespaces = peer.namespace_selector.as_ref().map(|s| match format_selector(s).as_str() {
+        "<all>" => "all namespaces".to_string(),
+        selector => format!("namespaces {selector}"),
+    });
+    let pods = peer.pod_selector.as_ref().map(|s| match format_selector(s).as_str() {
+        "<all>" => "all pods".to_string(),
+        selector => format!("pods {selector}"),
+    });
+    match (pods, namespaces) {
+        (Some(pods), Some(namespaces)) => format!("{pods} in {namespaces}"),
+        (None, Some(namespaces)) => format!("all pods in {namespaces}"),
+        (Some(pods), None) => format!("{pods} in this namespace"),
+        (None, None) => "nothing".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> NetworkPolicy {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "networking.k8s.io/v1",
+            "kind": "NetworkPolicy",
+            "metadata": { "name": "api-allow", "namespace": "web" },
+            "spec": {
+                "podSelector": {
+                    "matchLabels": { "app": "api" },
+                    "matchExpressions": [ { "key": "tier", "operator": "NotIn", "values": ["canary"] } ]
+                },
+                "policyTypes": ["Ingress", "Egress"],
+                "ingress": [
+                    {
+                        "from": [
+                            { "podSelector": { "matchLabels": { "app": "frontend" } } },
+                            { "namespaceSelector": { "matchLabels": { "team": "ops" } },
+                              "podSelector": { "matchLabels": { "app": "prometheus" } } }
+                        ],
+                        "ports": [ { "port": 8080 }, { "port": 9000, "endPort": 9100, "protocol": "UDP" } ]
+                    },
+                    { "from": [ { "ipBlock": { "cidr": "10.0.0.0/8", "except": ["10.1.0.0/16"] } } ] }
+                ]
+            }
+        }))
+        .unwrap()
+    }
+
+    fn pod(name: &str, labels: serde_json::Value) -> Pod {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": name, "namespace": "web", "labels": labels }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn selectors_format_and_match_expressions() {
+        let selector = policy().spec.unwrap().pod_selector.unwrap();
+        assert_eq!(format_selector(&selector), "app=api,tier notin (canary)");
+        assert_eq!(format_selector(&LabelSelector::default()), "<all>");
+
+        let labels = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        assert!(selector_matches(&selector, &labels(&[("app", "api")])));
+        assert!(selector_matches(&selector, &labels(&[("app", "api"), ("tier", "stable")])));
+        assert!(!selector_matches(&selector, &labels(&[("app", "api"), ("tier", "canary")])));
+        assert!(!selector_matches(&selector, &labels(&[("app", "web")])));
+    }
+
+    #[test]
+    fn sections_list_covered_pods_and_peer_tree() {
+        let pods = vec![
+            pod("api-1", serde_json::json!({ "app": "api" })),
+            pod("api-0", serde_json::json!({ "app": "api" })),
+            pod("api-canary", serde_json::json!({ "app": "api", "tier": "canary" })),
+            pod("frontend", serde_json::json!({ "app": "frontend" })),
+        ];
+        let sections = policy_sections(&policy(), &pods);
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Covered Pods", "Ingress", "Egress"]);
+
+        let covered: Vec<&str> = sections[0].fields.iter().map(|(_, v)| v.as_str()).collect();
+        assert_eq!(covered, vec!["app=api,tier notin (canary)", "2 of 4 pods", "api-0", "api-1"]);
+
+        let ingress: Vec<(&str, &str)> = sections[1].fields.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(
+            ingress,
+            vec![
+                ("Rule 1", "8080/TCP, 9000-9100/UDP"),
+                ("", "├─ from pods app=frontend in this namespace"),
+                ("", "└─ from pods app=prometheus in namespaces team=ops"),
+                ("Rule 2", "all ports"),
+                ("", "└─ from 10.0.0.0/8 except 10.1.0.0/16"),
+            ]
+        );
+        assert_eq!(sections[2].fields, vec![(String::new(), "Deny all".to_string())]);
+    }
+
+    #[test]
+    fn policy_types_default_from_rules() {
+        let mut np = policy();
+        assert_eq!(policy_types(&np), (true, true));
+        np.spec.as_mut().unwrap().policy_types = None;
+        assert_eq!(policy_types(&np), (true, false));
+        let sections = policy_sections(&np, &[]);
+        assert_eq!(sections[2].fields, vec![(String::new(), "Not restricted by this policy".to_string())]);
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use tokio::process::Command;
+
+use crate::remote::shell_quote;
+
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Object a plugin column command is run for; exposed to the command as `KUBETILE_*` env vars.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnTarget {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub context: String,
+}
+
+/// Replaces `{kind}`, `{name}`, `{namespace}` and `{context}` in a plugin command with the target's
+/// values, shell-quoted so they reach `sh -c` as single words.
+pub fn expand_placeholders(template: &str, target: &ColumnTarget) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let value = tail.find('}').and_then(|end| {
+            let value = match &tail[1..end] {
+                "kind" => &target.kind,
+                "name" => &target.name,
+                "namespace" => &target.namespace,
+                "context" => &target.context,
+                _ => return None,
+            };
+            Some((value, end))
+        });
+        match value {
+            Some((value, end)) => {
+                out.push_str(&shell_quote(value));
+                rest = &tail[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Runs a plugin column command through `sh -c` and returns the first line of its stdout.
+pub async fn run_column_command(command: &str, target: &ColumnTarget) -> Result<String> {
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("KUBETILE_KIND", &target.kind)
+        .env("KUBETILE_NAME", &target.name)
+        .env("KUBETILE_NAMESPACE", &target.namespace)
+        .env("KUBETILE_CONTEXT", &target.context)
+        .kill_on_drop(true)
+        .output();
+
+    let output = tokio::time::timeout(COMMAND_TIMEOUT, child)
+        .await
+        .map_err(|_| anyhow!("timed out after {}s", COMMAND_TIMEOUT.as_secs()))?
+        .with_context(|| format!("failed to run `{command}`"))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("{}: {}", output.status, stderr.lines().next().unwrap_or_default()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout.lines().next().unwrap_or_default().trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target() -> ColumnTarget {
+        ColumnTarget { kind: "pods".into(), name: "nginx".into(), namespace: "web".into(), context: "kind-dev".into() }
+    }
+
+    #[test]
+    fn placeholders_expand_and_quote() {
+        let mut target = target();
+        assert_eq!(
+            expand_placeholders("stern {name} -n {namespace} --context {context} # {kind}", &target),
+            "stern nginx -n web --context kind-dev # pods"
+        );
+        target.context = "it's prod".into();
+        assert_eq!(
+            expand_placeholders("kubectl --context {context} get {kind}/{name} {unknown} {", &target),
+            r"kubectl --context 'it'\''s prod' get pods/nginx {unknown} {"
+        );
+    }
+
+    #[tokio::test]
+    async fn command_sees_target_env_and_returns_first_line() {
+        let value =
+            run_column_command("echo \"$KUBETILE_NAMESPACE/$KUBETILE_NAME\"; echo ignored", &target()).await.unwrap();
+        assert_eq!(value, "web/nginx");
+    }
+
+    #[tokio::test]
+    async fn failing_command_returns_error() {
+        let err = run_column_command("echo boom >&2; exit 3", &target()).await.unwrap_err();
+        assert!(err.to_string().contains("boom"));
+    }
+}
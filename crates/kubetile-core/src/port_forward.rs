@@ -1,8 +1,10 @@
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use k8s_openapi::api::core::v1::Pod;
 use kube::{Api, Client};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::net::{TcpListener, TcpStream};
 use tokio::task::JoinHandle;
 use tracing::{debug, error, warn};
@@ -11,6 +13,39 @@ static NEXT_FORWARD_ID: AtomicU64 = AtomicU64::new(1);
 
 pub type ForwardId = u64;
 
+/// Traffic counters shared between a forward and its connection tasks.
+#[derive(Debug, Default)]
+struct ForwardCounters {
+    active: AtomicU64,
+    connections: AtomicU64,
+    bytes_up: AtomicU64,
+    bytes_down: AtomicU64,
+    /// Milliseconds after the start of the forward, plus one; zero until the first byte.
+    last_activity_ms: AtomicU64,
+}
+
+impl ForwardCounters {
+    fn record(&self, counter: &AtomicU64, bytes: usize, started_at: Instant) {
+        counter.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.last_activity_ms.store(started_at.elapsed().as_millis() as u64 + 1, Ordering::Relaxed);
+    }
+}
+
+/// A snapshot of the traffic through a port forward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ForwardStats {
+    /// Connections currently open.
+    pub active: u64,
+    /// Connections accepted since the forward started.
+    pub connections: u64,
+    /// Bytes sent from the local side to the pod.
+    pub bytes_up: u64,
+    /// Bytes received from the pod.
+    pub bytes_down: u64,
+    /// Time since data last moved in either direction.
+    pub idle: Option<Duration>,
+}
+
 /// Port forwarding session from a local port to a pod port.
 ///
 /// This struct manages a Kubernetes port forward session, which tunnels traffic
@@ -41,6 +76,7 @@ pub struct PortForward {
     namespace: String,
     pod_uid: Option<String>,
     started_at: Instant,
+    stats: Arc<ForwardCounters>,
     handle: JoinHandle<()>,
     shutdown_tx: tokio::sync::watch::Sender<bool>,
 }
@@ -86,11 +122,13 @@ impl PortForward {
         let namespace_str = namespace.to_string();
         let pod_name_clone = pod_name_str.clone();
         let namespace_clone = namespace_str.clone();
+        let started_at = Instant::now();
+        let stats = Arc::new(ForwardCounters::default());
+        let task_stats = Arc::clone(&stats);
 
         let handle = tokio::spawn(async move {
-            if let Err(e) =
-                run_port_forward(listener, client, &pod_name_clone, &namespace_clone, remote_port, shutdown_rx).await
-            {
+            let target = ForwardTarget { pod_name: &pod_name_clone, namespace: &namespace_clone, remote_port };
+            if let Err(e) = run_port_forward(listener, client, target, task_stats, started_at, shutdown_rx).await {
                 error!("Port forward {} error: {}", id, e);
             }
             debug!("Port forward {} stopped", id);
@@ -103,7 +141,8 @@ impl PortForward {
             pod_name: pod_name_str,
             namespace: namespace_str,
             pod_uid,
-            started_at: Instant::now(),
+            started_at,
+            stats,
             handle,
             shutdown_tx,
         })
@@ -150,20 +189,39 @@ impl PortForward {
         self.started_at.elapsed()
     }
 
+    /// Get the traffic through this forward so far.
+    pub fn stats(&self) -> ForwardStats {
+        let last = self.stats.last_activity_ms.load(Ordering::Relaxed);
+        ForwardStats {
+            active: self.stats.active.load(Ordering::Relaxed),
+            connections: self.stats.connections.load(Ordering::Relaxed),
+            bytes_up: self.stats.bytes_up.load(Ordering::Relaxed),
+            bytes_down: self.stats.bytes_down.load(Ordering::Relaxed),
+            idle: (last > 0).then(|| self.age().saturating_sub(Duration::from_millis(last - 1))),
+        }
+    }
+
     /// Get the unique identifier for this forward.
     pub fn id(&self) -> ForwardId {
         self.id
     }
 }
 
+struct ForwardTarget<'a> {
+    pod_name: &'a str,
+    namespace: &'a str,
+    remote_port: u16,
+}
+
 async fn run_port_forward(
     listener: TcpListener,
     client: Client,
-    pod_name: &str,
-    namespace: &str,
-    remote_port: u16,
+    target: ForwardTarget<'_>,
+    stats: Arc<ForwardCounters>,
+    started_at: Instant,
     mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
 ) -> anyhow::Result<()> {
+    let ForwardTarget { pod_name, namespace, remote_port } = target;
     let pods: Api<Pod> = Api::namespaced(client, namespace);
 
     loop {
@@ -193,11 +251,16 @@ async fn run_port_forward(
                             }
                         };
 
+                        stats.connections.fetch_add(1, Ordering::Relaxed);
+                        stats.active.fetch_add(1, Ordering::Relaxed);
+                        let stats = Arc::clone(&stats);
+
                         // Spawn a task to handle this specific connection
                         tokio::spawn(async move {
-                            if let Err(e) = proxy_connection(&mut local_stream, &mut upstream).await {
+                            if let Err(e) = proxy_connection(&mut local_stream, &mut upstream, &stats, started_at).await {
                                 debug!("Connection proxy error: {}", e);
                             }
+                            stats.active.fetch_sub(1, Ordering::Relaxed);
                         });
                     }
                     Err(e) => {
@@ -212,16 +275,20 @@ async fn run_port_forward(
 
 async fn proxy_connection(
     local: &mut TcpStream,
-    upstream: &mut (impl tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin),
+    upstream: &mut (impl AsyncRead + AsyncWrite + Unpin),
+    stats: &ForwardCounters,
+    started_at: Instant,
 ) -> anyhow::Result<()> {
     let (mut local_read, mut local_write) = local.split();
     let (mut upstream_read, mut upstream_write) = tokio::io::split(upstream);
+    let up = |n| stats.record(&stats.bytes_up, n, started_at);
+    let down = |n| stats.record(&stats.bytes_down, n, started_at);
 
     tokio::select! {
-        result = tokio::io::copy(&mut local_read, &mut upstream_write) => {
+        result = copy_counted(&mut local_read, &mut upstream_write, up) => {
             result?;
         }
-        result = tokio::io::copy(&mut upstream_read, &mut local_write) => {
+        result = copy_counted(&mut upstream_read, &mut local_write, down) => {
             result?;
         }
     }
@@ -229,6 +296,26 @@ async fn proxy_connection(
     Ok(())
 }
 
+/// Like `tokio::io::copy`, reporting each chunk to `on_chunk` once it is written.
+async fn copy_counted(
+    reader: &mut (impl AsyncRead + Unpin),
+    writer: &mut (impl AsyncWrite + Unpin),
+    on_chunk: impl Fn(usize),
+) -> std::io::Result<u64> {
+    let mut buf = [0u8; 8192];
+    let mut total = 0u64;
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            writer.flush().await?;
+            return Ok(total);
+        }
+        writer.write_all(&buf[..n]).await?;
+        total += n as u64;
+        on_chunk(n);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,6 +327,25 @@ mod tests {
         assert!(id2 > id1);
     }
 
+    #[tokio::test]
+    async fn copy_counted_reports_every_byte() {
+        let counters = ForwardCounters::default();
+        let started_at = Instant::now();
+        let (mut client, mut server) = tokio::io::duplex(64);
+        let writer = tokio::spawn(async move {
+            client.write_all(&[7u8; 20_000]).await.unwrap();
+        });
+
+        let mut sink = Vec::new();
+        let copied =
+            copy_counted(&mut server, &mut sink, |n| counters.record(&counters.bytes_up, n, started_at)).await.unwrap();
+        writer.await.unwrap();
+        assert_eq!(copied, 20_000);
+        assert_eq!(sink.len(), 20_000);
+        assert_eq!(counters.bytes_up.load(Ordering::Relaxed), 20_000);
+        assert!(counters.last_activity_ms.load(Ordering::Relaxed) > 0);
+    }
+
     #[tokio::test]
     async fn port_forward_binds_to_specified_port() {
         // This test verifies that we can bind to a port, but we can't actually
@@ -264,6 +370,7 @@ mod tests {
             namespace: "default".to_string(),
             pod_uid: Some("pod-uid-1".to_string()),
             started_at: Instant::now(),
+            stats: Arc::default(),
             handle,
             shutdown_tx,
         };
@@ -291,6 +398,7 @@ mod tests {
             namespace: "default".to_string(),
             pod_uid: None,
             started_at: Instant::now(),
+            stats: Arc::default(),
             handle,
             shutdown_tx,
         };
@@ -333,6 +441,7 @@ mod tests {
             namespace: "default".to_string(),
             pod_uid: None,
             started_at: Instant::now(),
+            stats: Arc::default(),
             handle: handle1,
             shutdown_tx: shutdown_tx1,
         };
@@ -345,6 +454,7 @@ mod tests {
             namespace: "default".to_string(),
             pod_uid: None,
             started_at: Instant::now(),
+            stats: Arc::default(),
             handle: handle2,
             shutdown_tx: shutdown_tx2,
         };
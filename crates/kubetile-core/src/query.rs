@@ -3,17 +3,133 @@ use kube::api::AttachParams;
 use kube::Api;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+
+/// The database client a query pane talks through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueryEngine {
+    #[default]
+    Postgres,
+    MySql,
+    /// `redis-cli`; each line of the editor is one command.
+    Redis,
+    /// `mongosh`; the editor holds JavaScript evaluated against the database.
+    Mongo,
+}
+
+impl QueryEngine {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Postgres => "PostgreSQL",
+            Self::MySql => "MySQL/MariaDB",
+            Self::Redis => "Redis",
+            Self::Mongo => "MongoDB",
+        }
+    }
+
+    pub fn default_port(self) -> &'static str {
+        match self {
+            Self::Postgres => "5432",
+            Self::MySql => "3306",
+            Self::Redis => "6379",
+            Self::Mongo => "27017",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Self::Postgres => Self::MySql,
+            Self::MySql => Self::Redis,
+            Self::Redis => Self::Mongo,
+            Self::Mongo => Self::Postgres,
+        }
+    }
+
+    /// Whether the engine speaks SQL. Paging, transactions and schema completion need it.
+    pub fn is_sql(self) -> bool {
+        matches!(self, Self::Postgres | Self::MySql)
+    }
+
+    /// The statement a new pane runs to test the connection and learn the server version.
+    pub fn version_query(self) -> &'static str {
+        match self {
+            Self::Postgres | Self::MySql => "SELECT version()",
+            Self::Redis => "INFO server",
+            Self::Mongo => "db.version()",
+        }
+    }
+
+    /// Lists `table_schema, table_name, column_name, data_type` for the user tables.
+    pub fn schema_sql(self) -> Option<&'static str> {
+        match self {
+            Self::Postgres => Some(
+                "SELECT table_schema, table_name, column_name, data_type \
+                 FROM information_schema.columns \
+                 WHERE table_schema NOT IN ('pg_catalog','information_schema') \
+                 ORDER BY table_name, ordinal_position",
+            ),
+            Self::MySql => Some(
+                "SELECT table_schema, table_name, column_name, data_type \
+                 FROM information_schema.columns \
+                 WHERE table_schema NOT IN ('mysql','sys','performance_schema','information_schema') \
+                 ORDER BY table_name, ordinal_position",
+            ),
+            Self::Redis | Self::Mongo => None,
+        }
+    }
+
+    /// The server name and version from the result of [`Self::version_query`].
+    pub fn connected_label(self, result: &QueryResult) -> Option<String> {
+        let first = result.rows.first().and_then(|row| row.first());
+        match self {
+            Self::Redis => result
+                .rows
+                .iter()
+                .find_map(|row| row.first()?.strip_prefix("redis_version:"))
+                .map(|version| format!("Redis {}", version.trim())),
+            Self::Mongo => first.map(|version| format!("MongoDB {}", version.trim_matches('"'))),
+            Self::Postgres | Self::MySql => first.map(|version| self.version_label(version)),
+        }
+    }
+
+    /// Shortens a `SELECT version()` result to the server name and version.
+    pub fn version_label(self, version: &str) -> String {
+        match self {
+            // "PostgreSQL 15.2 on x86_64-pc-linux-gnu..." → "PostgreSQL 15.2"
+            Self::Postgres => {
+                let mut parts = version.splitn(3, ' ');
+                match (parts.next(), parts.next()) {
+                    (Some(name), Some(ver)) => format!("{name} {ver}"),
+                    _ => version.to_string(),
+                }
+            }
+            // "10.11.6-MariaDB-1:10.11.6+maria~ubu2204" → "MariaDB 10.11.6", "8.0.36" → "MySQL 8.0.36"
+            Self::MySql => {
+                let ver = version.split('-').next().unwrap_or(version);
+                if version.contains("MariaDB") {
+                    format!("MariaDB {ver}")
+                } else {
+                    format!("MySQL {ver}")
+                }
+            }
+            Self::Redis | Self::Mongo => format!("{} {version}", self.label()),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct QueryConfig {
     pub pod: String,
     pub namespace: String,
     pub container: Option<String>,
+    pub engine: QueryEngine,
     pub database: String,
     pub user: String,
     pub password: String,
     pub port: String,
+    /// Database a MongoDB user is defined in; `admin` when empty. Other engines ignore it.
+    pub auth_database: String,
 }
 
 #[derive(Debug, Clone)]
@@ -22,15 +138,20 @@ pub struct QueryResult {
     pub rows: Vec<Vec<String>>,
 }
 
-pub async fn read_postgres_env(client: &kube::Client, pod: &str, namespace: &str) -> QueryConfig {
+/// Picks the database container of a pod and pre-fills connection settings from its environment.
+/// The engine follows the container's port or image, then which `POSTGRES_*`, `MYSQL_*`,
+/// `REDIS_*` or `MONGO_*` variables it sets.
+pub async fn read_database_env(client: &kube::Client, pod: &str, namespace: &str) -> QueryConfig {
     let mut config = QueryConfig {
         pod: pod.to_string(),
         namespace: namespace.to_string(),
         container: None,
+        engine: QueryEngine::Postgres,
         database: String::new(),
         user: String::new(),
         password: String::new(),
-        port: "5432".to_string(),
+        port: QueryEngine::Postgres.default_port().to_string(),
+        auth_database: String::new(),
     };
 
     let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
@@ -41,7 +162,7 @@ pub async fn read_postgres_env(client: &kube::Client, pod: &str, namespace: &str
         return config;
     };
 
-    let Some(container) = select_postgres_container(&spec.containers) else {
+    let Some((container, engine_hint)) = select_database_container(&spec.containers) else {
         return config;
     };
     config.container = Some(container.name.clone());
@@ -51,7 +172,6 @@ pub async fn read_postgres_env(client: &kube::Client, pod: &str, namespace: &str
     let secrets = Api::<k8s_openapi::api::core::v1::Secret>::namespaced(client.clone(), namespace);
     let mut config_map_cache: HashMap<String, BTreeMap<String, String>> = HashMap::new();
     let mut secret_cache: HashMap<String, BTreeMap<String, String>> = HashMap::new();
-
     if let Some(env_froms) = &container.env_from {
         for env_from in env_froms {
             let prefix = env_from.prefix.clone().unwrap_or_default();
@@ -98,20 +218,97 @@ pub async fn read_postgres_env(client: &kube::Client, pod: &str, namespace: &str
         }
     }
 
-    if let Some(val) = resolved_env.get("POSTGRES_DB") {
-        config.database = val.clone();
-    }
-    if let Some(val) = resolved_env.get("POSTGRES_USER") {
-        config.user = val.clone();
-    }
-    if let Some(val) = resolved_env.get("POSTGRES_PASSWORD") {
-        config.password = val.clone();
-    }
-    if let Some(val) = resolved_env.get("PGPORT").or_else(|| resolved_env.get("POSTGRES_PORT")) {
-        config.port = val.clone();
+    let engine = engine_hint.unwrap_or_else(|| detect_engine(&resolved_env));
+    apply_env(&mut config, engine, &resolved_env);
+    config
+}
+
+fn detect_engine(env: &HashMap<String, String>) -> QueryEngine {
+    // Prefixes alone would match the `REDIS_PORT`-style variables Kubernetes injects for every
+    // Service named redis, so Redis and MongoDB need a variable their images read.
+    const REDIS_KEYS: [&str; 3] = ["REDIS_PASSWORD", "REDIS_ARGS", "REDIS_PORT_NUMBER"];
+    if env.keys().any(|k| k.starts_with("MYSQL_") || k.starts_with("MARIADB_")) {
+        QueryEngine::MySql
+    } else if env.keys().any(|k| k.starts_with("POSTGRES_")) {
+        QueryEngine::Postgres
+    } else if env.keys().any(|k| k.starts_with("MONGO_INITDB_") || k.starts_with("MONGODB_ROOT_")) {
+        QueryEngine::Mongo
+    } else if REDIS_KEYS.iter().any(|k| env.contains_key(*k)) {
+        QueryEngine::Redis
+    } else {
+        QueryEngine::Postgres
     }
+}
 
-    config
+fn apply_env(config: &mut QueryConfig, engine: QueryEngine, env: &HashMap<String, String>) {
+    let first = |keys: &[&str]| keys.iter().find_map(|k| env.get(*k)).cloned();
+    config.engine = engine;
+    config.port = engine.default_port().to_string();
+    match engine {
+        QueryEngine::Postgres => {
+            if let Some(val) = first(&["POSTGRES_DB"]) {
+                config.database = val;
+            }
+            if let Some(val) = first(&["POSTGRES_USER"]) {
+                config.user = val;
+            }
+            if let Some(val) = first(&["POSTGRES_PASSWORD"]) {
+                config.password = val;
+            }
+            if let Some(val) = first(&["PGPORT", "POSTGRES_PORT"]) {
+                config.port = val;
+            }
+        }
+        QueryEngine::MySql => {
+            if let Some(val) = first(&["MYSQL_DATABASE", "MARIADB_DATABASE"]) {
+                config.database = val;
+            }
+            // The official images create MYSQL_USER alongside root; without it only root exists.
+            match first(&["MYSQL_USER", "MARIADB_USER"]) {
+                Some(user) => {
+                    config.user = user;
+                    config.password = first(&["MYSQL_PASSWORD", "MARIADB_PASSWORD"]).unwrap_or_default();
+                }
+                None => {
+                    config.user = "root".to_string();
+                    config.password = first(&["MYSQL_ROOT_PASSWORD", "MARIADB_ROOT_PASSWORD"]).unwrap_or_default();
+                }
+            }
+            if let Some(val) = first(&["MYSQL_TCP_PORT"]) {
+                config.port = val;
+            }
+        }
+        QueryEngine::Redis => {
+            if let Some(val) = first(&["REDIS_USERNAME"]) {
+                config.user = val;
+            }
+            if let Some(val) = first(&["REDIS_PASSWORD"]) {
+                config.password = val;
+            }
+            if let Some(val) = first(&["REDIS_PORT_NUMBER"]) {
+                config.port = val;
+            }
+        }
+        QueryEngine::Mongo => {
+            if let Some(val) = first(&["MONGO_INITDB_DATABASE", "MONGODB_DATABASE"]) {
+                config.database = val;
+            }
+            // The Bitnami image names its root user `root` unless MONGODB_ROOT_USER says otherwise.
+            match first(&["MONGO_INITDB_ROOT_USERNAME", "MONGODB_ROOT_USER"]) {
+                Some(user) => config.user = user,
+                None if env.contains_key("MONGODB_ROOT_PASSWORD") => config.user = "root".to_string(),
+                None => {}
+            }
+            if let Some(val) = first(&["MONGO_INITDB_ROOT_PASSWORD", "MONGODB_ROOT_PASSWORD"]) {
+                config.password = val;
+            }
+            if let Some(val) = first(&["MONGODB_PORT_NUMBER"]) {
+                config.port = val;
+            }
+            // Both images create the root user in `admin`.
+            config.auth_database = "admin".to_string();
+        }
+    }
 }
 
 async fn load_config_map_data(
@@ -157,20 +354,7 @@ async fn load_secret_data(
 pub async fn execute_query(client: &kube::Client, config: &QueryConfig, sql: &str) -> anyhow::Result<QueryResult> {
     let pods: Api<Pod> = Api::namespaced(client.clone(), &config.namespace);
 
-    let command = vec![
-        "env".to_string(),
-        format!("PGPASSWORD={}", config.password),
-        "psql".to_string(),
-        "-U".to_string(),
-        config.user.clone(),
-        "-d".to_string(),
-        config.database.clone(),
-        "-p".to_string(),
-        config.port.clone(),
-        "--csv".to_string(),
-        "-c".to_string(),
-        sql.to_string(),
-    ];
+    let command = query_command(config, sql);
 
     let mut attach = AttachParams::default();
     if let Some(container) = &config.container {
@@ -189,41 +373,356 @@ pub async fn execute_query(client: &kube::Client, config: &QueryConfig, sql: &st
 
     attached.join().await?;
 
-    let stderr_str = String::from_utf8_lossy(&stderr_buf);
-    let stderr_trimmed = stderr_str.trim();
-    if !stderr_trimmed.is_empty() {
-        return Err(anyhow::anyhow!("{}", stderr_trimmed));
+    let errors = significant_stderr(config.engine, &String::from_utf8_lossy(&stderr_buf));
+    if !errors.is_empty() {
+        return Err(anyhow::anyhow!("{}", errors));
     }
 
-    parse_csv_output(&String::from_utf8_lossy(&stdout_buf))
+    parse_output(config.engine, &String::from_utf8_lossy(&stdout_buf))
+}
+
+fn significant_stderr(engine: QueryEngine, stderr: &str) -> String {
+    let stderr = stderr.trim();
+    match engine {
+        QueryEngine::Postgres => stderr.to_string(),
+        // The mysql client warns on stderr about passwords passed through the environment.
+        QueryEngine::MySql => stderr.lines().filter(|l| !l.contains("[Warning]")).collect::<Vec<_>>().join("\n"),
+        QueryEngine::Redis | QueryEngine::Mongo => stderr.to_string(),
+    }
+}
+
+fn parse_output(engine: QueryEngine, stdout: &str) -> anyhow::Result<QueryResult> {
+    match engine {
+        QueryEngine::Postgres => parse_csv_output(stdout),
+        QueryEngine::MySql => Ok(parse_tsv_output(stdout)),
+        QueryEngine::Redis => Ok(parse_line_output(stdout)),
+        QueryEngine::Mongo => Ok(parse_json_output(stdout)),
+    }
+}
+
+/// Printed after every statement of a [`QuerySession`] to find where its output ends.
+const SESSION_MARKER: &str = "kubetile_statement_done";
+
+/// Client errors meaning the connection is gone, and with it any open transaction.
+const CONNECTION_LOST: &[&str] = &[
+    // MySQL/MariaDB: "server has gone away" and "Lost connection to server during query".
+    "ERROR 2006",
+    "ERROR 2013",
+    "server closed the connection unexpectedly",
+    "connection to server was lost",
+];
+
+/// The session's database connection ended; an open transaction on it was rolled back.
+#[derive(Debug)]
+pub struct SessionLost(pub String);
+
+impl std::fmt::Display for SessionLost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "database session lost: {}", self.0)
+    }
 }
 
-fn select_postgres_container(
+impl std::error::Error for SessionLost {}
+
+/// What the session's client wrote, in the order the pod wrote it.
+enum SessionOutput {
+    Stdout(String),
+    Stderr(String),
+}
+
+/// A database client kept running in the pod, so statements share one connection and a
+/// transaction can span several queries. Dropping the session closes the client's stdin, which
+/// ends the connection and rolls back an open transaction.
+pub struct QuerySession {
+    engine: QueryEngine,
+    stdin: Box<dyn AsyncWrite + Unpin + Send>,
+    output: mpsc::UnboundedReceiver<SessionOutput>,
+}
+
+impl QuerySession {
+    pub async fn open(client: &kube::Client, config: &QueryConfig) -> anyhow::Result<Self> {
+        if !config.engine.is_sql() {
+            anyhow::bail!("{} sessions are not supported", config.engine.label());
+        }
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &config.namespace);
+        let mut attach = AttachParams::default().stdin(true);
+        if let Some(container) = &config.container {
+            attach = attach.container(container.clone());
+        }
+
+        let mut attached = pods.exec(&config.pod, session_command(config), &attach).await?;
+        let stdin = attached.stdin().ok_or_else(|| anyhow::anyhow!("stdin not available"))?;
+        let stdout = attached.stdout().ok_or_else(|| anyhow::anyhow!("stdout not available"))?;
+        let stderr = attached.stderr().ok_or_else(|| anyhow::anyhow!("stderr not available"))?;
+        tokio::spawn(async move {
+            let _ = attached.join().await;
+        });
+        Ok(Self::from_streams(config.engine, stdin, stdout, stderr))
+    }
+
+    /// Reads both output streams in one task. The exec stream hands each stream its data in the
+    /// order the pod wrote it, so preferring stderr puts a statement's errors before its marker.
+    fn from_streams(
+        engine: QueryEngine,
+        stdin: impl AsyncWrite + Unpin + Send + 'static,
+        stdout: impl AsyncRead + Unpin + Send + 'static,
+        mut stderr: impl AsyncRead + Unpin + Send + 'static,
+    ) -> Self {
+        let (output_tx, output) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            let mut buf = [0u8; 4096];
+            let mut stderr_open = true;
+            loop {
+                let output = tokio::select! {
+                    biased;
+                    read = stderr.read(&mut buf), if stderr_open => match read {
+                        Ok(n) if n > 0 => SessionOutput::Stderr(String::from_utf8_lossy(&buf[..n]).into_owned()),
+                        _ => {
+                            stderr_open = false;
+                            continue;
+                        }
+                    },
+                    line = lines.next_line() => match line {
+                        Ok(Some(line)) => SessionOutput::Stdout(line),
+                        _ => break,
+                    },
+                };
+                if output_tx.send(output).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { engine, stdin: Box::new(stdin), output }
+    }
+
+    /// Runs `sql` on the session's connection and waits for its output. Fails with
+    /// [`SessionLost`] once the connection is gone.
+    pub async fn execute(&mut self, sql: &str) -> anyhow::Result<QueryResult> {
+        let marker = match self.engine {
+            // A meta-command, so it still prints inside an aborted transaction.
+            QueryEngine::Postgres => format!("\\echo {SESSION_MARKER}"),
+            QueryEngine::MySql => format!("SELECT '{SESSION_MARKER}' AS {SESSION_MARKER};"),
+            QueryEngine::Redis | QueryEngine::Mongo => {
+                anyhow::bail!("{} sessions are not supported", self.engine.label())
+            }
+        };
+        // The terminator goes on its own line so a trailing `--` comment cannot swallow it.
+        let statement = sql.trim().trim_end_matches(';');
+        let sent = self.stdin.write_all(format!("{statement}\n;\n{marker}\n").as_bytes()).await;
+        if let Err(e) = sent.and(self.stdin.flush().await) {
+            return Err(SessionLost(e.to_string()).into());
+        }
+
+        let mut output = String::new();
+        let mut stderr = String::new();
+        loop {
+            let Some(next) = self.output.recv().await else {
+                let reason = significant_stderr(self.engine, &stderr);
+                let reason = if reason.is_empty() { "the client exited".to_string() } else { reason };
+                return Err(SessionLost(reason).into());
+            };
+            match next {
+                SessionOutput::Stderr(text) => stderr.push_str(&text),
+                SessionOutput::Stdout(line) if line == SESSION_MARKER => {
+                    if self.engine == QueryEngine::MySql {
+                        // The marker query prints a header and a value row.
+                        self.output.recv().await;
+                    }
+                    break;
+                }
+                SessionOutput::Stdout(line) => {
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+            }
+        }
+
+        let errors = significant_stderr(self.engine, &stderr);
+        if CONNECTION_LOST.iter().any(|lost| errors.contains(lost)) {
+            return Err(SessionLost(errors).into());
+        }
+        if !errors.is_empty() {
+            return Err(anyhow::anyhow!("{}", errors));
+        }
+        parse_output(self.engine, &output)
+    }
+}
+
+/// The client invocation shared by one-shot queries and sessions, without the statement.
+fn client_command(config: &QueryConfig) -> Vec<String> {
+    match config.engine {
+        QueryEngine::Postgres => vec![
+            "env".to_string(),
+            format!("PGPASSWORD={}", config.password),
+            "psql".to_string(),
+            "-U".to_string(),
+            config.user.clone(),
+            "-d".to_string(),
+            config.database.clone(),
+            "-p".to_string(),
+            config.port.clone(),
+            "--csv".to_string(),
+        ],
+        // MariaDB 11 images ship only the `mariadb` client, older ones only `mysql`.
+        QueryEngine::MySql => vec![
+            "env".to_string(),
+            format!("MYSQL_PWD={}", config.password),
+            "sh".to_string(),
+            "-c".to_string(),
+            r#"command -v mariadb >/dev/null 2>&1 && exec mariadb "$@"; exec mysql "$@""#.to_string(),
+            "mysql".to_string(),
+            "-h".to_string(),
+            "127.0.0.1".to_string(),
+            "-P".to_string(),
+            config.port.clone(),
+            "-u".to_string(),
+            config.user.clone(),
+            "--batch".to_string(),
+        ],
+        // Commands go through stdin, one per line, which redis-cli answers in raw format.
+        QueryEngine::Redis => {
+            // An empty REDISCLI_AUTH would still send AUTH and fail on servers without a password.
+            let mut command = vec!["env".to_string()];
+            if !config.password.is_empty() {
+                command.push(format!("REDISCLI_AUTH={}", config.password));
+            }
+            command.extend([
+                "redis-cli".to_string(),
+                "-h".to_string(),
+                "127.0.0.1".to_string(),
+                "-p".to_string(),
+                config.port.clone(),
+            ]);
+            if !config.user.is_empty() {
+                command.extend(["--user".to_string(), config.user.clone()]);
+            }
+            if !config.database.is_empty() {
+                command.extend(["-n".to_string(), config.database.clone()]);
+            }
+            command
+        }
+        // mongosh has no password variable, so it starts without a connection and the script
+        // connects with credentials read from the environment; see `mongo_connect`.
+        QueryEngine::Mongo => vec![
+            "env".to_string(),
+            format!("{MONGO_USER_VAR}={}", config.user),
+            format!("{MONGO_PASSWORD_VAR}={}", config.password),
+            "mongosh".to_string(),
+            "--nodb".to_string(),
+            "--quiet".to_string(),
+            "--json=relaxed".to_string(),
+        ],
+    }
+}
+
+fn query_command(config: &QueryConfig, sql: &str) -> Vec<String> {
+    let mut command = client_command(config);
+    match config.engine {
+        QueryEngine::Postgres => command.extend(["-c".to_string(), sql.to_string()]),
+        QueryEngine::MySql => {
+            command.extend(["-e".to_string(), sql.to_string()]);
+            if !config.database.is_empty() {
+                command.push(config.database.clone());
+            }
+        }
+        QueryEngine::Redis => {
+            let mut piped = vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                r#"commands=$1; shift; printf '%s\n' "$commands" | "$@""#.to_string(),
+                "sh".to_string(),
+                sql.to_string(),
+            ];
+            piped.append(&mut command);
+            return piped;
+        }
+        QueryEngine::Mongo => command.extend(["--eval".to_string(), format!("{}\n{sql}", mongo_connect(config))]),
+    }
+    command
+}
+
+/// Variables the Mongo credentials reach mongosh in, instead of its command line.
+const MONGO_USER_VAR: &str = "KUBETILE_MONGO_USER";
+const MONGO_PASSWORD_VAR: &str = "KUBETILE_MONGO_PASSWORD";
+
+/// JavaScript that connects `db` to the configured database, authenticating against
+/// `auth_database` with the credentials from the environment when a user is set.
+fn mongo_connect(config: &QueryConfig) -> String {
+    let address = format!("127.0.0.1:{}/{}", config.port, config.database);
+    if config.user.is_empty() {
+        return format!("db = connect({});", serde_json::json!(format!("mongodb://{address}")));
+    }
+    let auth_database = if config.auth_database.is_empty() { "admin" } else { &config.auth_database };
+    let target = serde_json::json!(format!("@{address}?authSource={auth_database}"));
+    format!(
+        "db = connect(\"mongodb://\" + encodeURIComponent(process.env.{MONGO_USER_VAR}) + \":\" + \
+         encodeURIComponent(process.env.{MONGO_PASSWORD_VAR}) + {target});"
+    )
+}
+
+/// Reads statements from stdin. psql stays quiet so command tags don't mix into results. The
+/// mysql client flushes after every statement, keeps going after a failed one instead of exiting,
+/// and never reconnects, which would silently drop the open transaction.
+fn session_command(config: &QueryConfig) -> Vec<String> {
+    let mut command = client_command(config);
+    match config.engine {
+        QueryEngine::Postgres => command.extend(["-q".to_string(), "-X".to_string()]),
+        QueryEngine::MySql => {
+            command.extend(["--unbuffered".to_string(), "--force".to_string(), "--skip-reconnect".to_string()]);
+            if !config.database.is_empty() {
+                command.push(config.database.clone());
+            }
+        }
+        QueryEngine::Redis | QueryEngine::Mongo => {}
+    }
+    command
+}
+
+/// Prefers the database container over sidecars (for example postgres-exporter), returning the
+/// engine when its port or image gives it away.
+fn select_database_container(
     containers: &[k8s_openapi::api::core::v1::Container],
-) -> Option<&k8s_openapi::api::core::v1::Container> {
+) -> Option<(&k8s_openapi::api::core::v1::Container, Option<QueryEngine>)> {
     if containers.is_empty() {
         return None;
     }
 
-    // Prefer the database container over sidecars (for example postgres-exporter).
-    if let Some(c) = containers
-        .iter()
-        .find(|c| c.ports.as_ref().map(|ports| ports.iter().any(|p| p.container_port == 5432)).unwrap_or(false))
-    {
-        return Some(c);
+    let ports = [
+        (5432, QueryEngine::Postgres),
+        (3306, QueryEngine::MySql),
+        (6379, QueryEngine::Redis),
+        (27017, QueryEngine::Mongo),
+    ];
+    for (port, engine) in ports {
+        if let Some(c) = containers
+            .iter()
+            .find(|c| c.ports.as_ref().map(|ports| ports.iter().any(|p| p.container_port == port)).unwrap_or(false))
+        {
+            return Some((c, Some(engine)));
+        }
     }
 
-    if let Some(c) = containers.iter().find(|c| {
-        let name = c.name.to_ascii_lowercase();
-        let image = c.image.as_deref().unwrap_or_default().to_ascii_lowercase();
-        (name.contains("postgres") || image.contains("postgres"))
-            && !name.contains("exporter")
-            && !image.contains("exporter")
-    }) {
-        return Some(c);
+    let names: [(&str, QueryEngine); 5] = [
+        ("postgres", QueryEngine::Postgres),
+        ("mysql", QueryEngine::MySql),
+        ("mariadb", QueryEngine::MySql),
+        ("redis", QueryEngine::Redis),
+        ("mongo", QueryEngine::Mongo),
+    ];
+    for (needle, engine) in names {
+        if let Some(c) = containers.iter().find(|c| {
+            let name = c.name.to_ascii_lowercase();
+            let image = c.image.as_deref().unwrap_or_default().to_ascii_lowercase();
+            (name.contains(needle) || image.contains(needle))
+                && !name.contains("exporter")
+                && !image.contains("exporter")
+        }) {
+            return Some((c, Some(engine)));
+        }
     }
 
-    containers.first()
+    containers.first().map(|c| (c, None))
 }
 
 fn parse_csv_output(output: &str) -> anyhow::Result<QueryResult> {
@@ -238,3 +737,282 @@ fn parse_csv_output(output: &str) -> anyhow::Result<QueryResult> {
 
     Ok(QueryResult { headers, rows })
 }
+
+/// Parses `mysql --batch` output: tab-separated with a header row, and tabs, newlines and
+/// backslashes inside values escaped as `\t`, `\n` and `\\`.
+fn parse_tsv_output(output: &str) -> QueryResult {
+    let mut lines = output.lines().filter(|l| !l.is_empty());
+    let split = |line: &str| line.split('\t').map(unescape_batch_value).collect::<Vec<_>>();
+    let headers = lines.next().map(split).unwrap_or_default();
+    let rows = lines.map(split).collect();
+    QueryResult { headers, rows }
+}
+
+/// One `result` row per line of redis-cli output.
+fn parse_line_output(output: &str) -> QueryResult {
+    QueryResult {
+        headers: vec!["result".to_string()],
+        rows: output.lines().map(|line| vec![line.to_string()]).collect(),
+    }
+}
+
+/// One `result` row per document of a JSON array printed by `mongosh --json`, a single row for
+/// any other value, and one row per line when the output is not JSON.
+fn parse_json_output(output: &str) -> QueryResult {
+    let rows = match serde_json::from_str::<serde_json::Value>(output.trim()) {
+        Ok(serde_json::Value::Array(items)) => items.iter().map(|item| vec![item.to_string()]).collect(),
+        Ok(serde_json::Value::String(text)) => vec![vec![text]],
+        Ok(value) => vec![vec![value.to_string()]],
+        Err(_) => return parse_line_output(output),
+    };
+    QueryResult { headers: vec!["result".to_string()], rows }
+}
+
+fn unescape_batch_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('0') => out.push('\0'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::Container;
+
+    fn env(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn config() -> QueryConfig {
+        QueryConfig {
+            pod: "db-0".into(),
+            namespace: "data".into(),
+            container: None,
+            engine: QueryEngine::Postgres,
+            database: String::new(),
+            user: String::new(),
+            password: String::new(),
+            port: "5432".into(),
+            auth_database: String::new(),
+        }
+    }
+
+    fn container(name: &str, image: &str, port: Option<i32>) -> Container {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "image": image,
+            "ports": port.map(|p| vec![serde_json::json!({ "containerPort": p })]).unwrap_or_default(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn mysql_env_uses_app_user_or_falls_back_to_root() {
+        let mut cfg = config();
+        let vars = env(&[("MYSQL_DATABASE", "shop"), ("MYSQL_USER", "app"), ("MYSQL_PASSWORD", "pw")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!(cfg.engine, QueryEngine::MySql);
+        assert_eq!((cfg.database.as_str(), cfg.user.as_str(), cfg.password.as_str()), ("shop", "app", "pw"));
+        assert_eq!(cfg.port, "3306");
+
+        let mut cfg = config();
+        let vars = env(&[("MARIADB_ROOT_PASSWORD", "secret"), ("MYSQL_TCP_PORT", "3307")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!((cfg.user.as_str(), cfg.password.as_str(), cfg.port.as_str()), ("root", "secret", "3307"));
+    }
+
+    #[test]
+    fn postgres_env_is_the_default_engine() {
+        let mut cfg = config();
+        let vars = env(&[("POSTGRES_DB", "app"), ("POSTGRES_USER", "pg")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!(cfg.engine, QueryEngine::Postgres);
+        assert_eq!((cfg.database.as_str(), cfg.user.as_str(), cfg.port.as_str()), ("app", "pg", "5432"));
+    }
+
+    #[test]
+    fn container_selection_detects_engine_from_port_and_image() {
+        let containers =
+            vec![container("exporter", "prom/mysqld-exporter", Some(9104)), container("db", "mysql:8.0", None)];
+        let (c, engine) = select_database_container(&containers).unwrap();
+        assert_eq!((c.name.as_str(), engine), ("db", Some(QueryEngine::MySql)));
+
+        let containers = vec![container("app", "shop:1", None), container("store", "bitnami/mariadb", Some(3306))];
+        let (c, engine) = select_database_container(&containers).unwrap();
+        assert_eq!((c.name.as_str(), engine), ("store", Some(QueryEngine::MySql)));
+
+        let containers = vec![container("app", "shop:1", None)];
+        assert_eq!(select_database_container(&containers).unwrap().1, None);
+    }
+
+    #[test]
+    fn redis_and_mongo_env_need_image_variables() {
+        let mut cfg = config();
+        let vars = env(&[("REDIS_PASSWORD", "pw"), ("REDIS_PORT_NUMBER", "6380")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!(cfg.engine, QueryEngine::Redis);
+        assert_eq!((cfg.password.as_str(), cfg.port.as_str()), ("pw", "6380"));
+
+        let mut cfg = config();
+        let vars = env(&[("MONGO_INITDB_ROOT_USERNAME", "admin"), ("MONGO_INITDB_ROOT_PASSWORD", "pw")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!(cfg.engine, QueryEngine::Mongo);
+        assert_eq!((cfg.user.as_str(), cfg.password.as_str(), cfg.port.as_str()), ("admin", "pw", "27017"));
+        assert_eq!(cfg.auth_database, "admin");
+
+        let mut cfg = config();
+        let vars = env(&[("MONGODB_ROOT_PASSWORD", "pw"), ("MONGODB_DATABASE", "shop")]);
+        apply_env(&mut cfg, detect_engine(&vars), &vars);
+        assert_eq!((cfg.user.as_str(), cfg.database.as_str()), ("root", "shop"));
+
+        // Service links injected by Kubernetes say nothing about the pod's own database.
+        let vars = env(&[("REDIS_PORT", "tcp://10.0.0.1:6379"), ("REDIS_SERVICE_HOST", "10.0.0.1")]);
+        assert_eq!(detect_engine(&vars), QueryEngine::Postgres);
+    }
+
+    #[test]
+    fn redis_and_mongo_containers_are_found_by_port_and_image() {
+        let containers = vec![container("app", "shop:1", None), container("cache", "redis:7", Some(6379))];
+        assert_eq!(select_database_container(&containers).unwrap().1, Some(QueryEngine::Redis));
+
+        let containers =
+            vec![container("exporter", "percona/mongodb_exporter", None), container("db", "mongo:7", None)];
+        let (c, engine) = select_database_container(&containers).unwrap();
+        assert_eq!((c.name.as_str(), engine), ("db", Some(QueryEngine::Mongo)));
+    }
+
+    #[test]
+    fn mongo_credentials_stay_off_the_command_line() {
+        let cfg = QueryConfig {
+            engine: QueryEngine::Mongo,
+            database: "shop".into(),
+            user: "root".into(),
+            password: "s3cret".into(),
+            port: "27017".into(),
+            auth_database: "users".into(),
+            ..config()
+        };
+        let command = query_command(&cfg, "db.orders.find()");
+        let mongosh = command.iter().position(|a| a == "mongosh").unwrap();
+        assert_eq!(command[..mongosh], ["env", "KUBETILE_MONGO_USER=root", "KUBETILE_MONGO_PASSWORD=s3cret"]);
+        assert!(command[mongosh..].iter().all(|a| !a.contains("s3cret") && !a.contains("root")));
+        assert_eq!(
+            command.last().unwrap(),
+            "db = connect(\"mongodb://\" + encodeURIComponent(process.env.KUBETILE_MONGO_USER) + \":\" + \
+             encodeURIComponent(process.env.KUBETILE_MONGO_PASSWORD) + \"@127.0.0.1:27017/shop?authSource=users\");\n\
+             db.orders.find()"
+        );
+
+        let anonymous = QueryConfig { user: String::new(), password: String::new(), ..cfg };
+        let command = query_command(&anonymous, "db.version()");
+        assert_eq!(command.last().unwrap(), "db = connect(\"mongodb://127.0.0.1:27017/shop\");\ndb.version()");
+    }
+
+    #[test]
+    fn redis_and_mongo_output_becomes_result_rows() {
+        let result =
+            parse_output(QueryEngine::Redis, "# Server\nredis_version:7.2.4\nredis_mode:standalone\n").unwrap();
+        assert_eq!(result.headers, vec!["result"]);
+        assert_eq!(result.rows.len(), 3);
+        assert_eq!(QueryEngine::Redis.connected_label(&result).unwrap(), "Redis 7.2.4");
+
+        let result = parse_output(QueryEngine::Mongo, r#"[{"_id":1,"name":"a"},{"_id":2}]"#).unwrap();
+        assert_eq!(result.rows, vec![vec![r#"{"_id":1,"name":"a"}"#], vec![r#"{"_id":2}"#]]);
+
+        let result = parse_output(QueryEngine::Mongo, "\"7.0.5\"\n").unwrap();
+        assert_eq!(QueryEngine::Mongo.connected_label(&result).unwrap(), "MongoDB 7.0.5");
+
+        let result = parse_output(QueryEngine::Mongo, "MongoServerError: not authorized\n").unwrap();
+        assert_eq!(result.rows, vec![vec!["MongoServerError: not authorized"]]);
+    }
+
+    #[test]
+    fn redis_commands_are_piped_through_stdin() {
+        let mut cfg = config();
+        cfg.engine = QueryEngine::Redis;
+        cfg.port = "6379".into();
+        let command = query_command(&cfg, "SET a 1\nGET a");
+        assert_eq!(command[..2], ["sh", "-c"]);
+        assert_eq!(command[4], "SET a 1\nGET a");
+        assert_eq!(command[5..], ["env", "redis-cli", "-h", "127.0.0.1", "-p", "6379"]);
+    }
+
+    #[test]
+    fn tsv_output_unescapes_values() {
+        let result = parse_tsv_output("id\tnote\n1\tline one\\nline two\n2\ttab\\there \\\\ NULL\n");
+        assert_eq!(result.headers, vec!["id", "note"]);
+        assert_eq!(result.rows, vec![vec!["1", "line one\nline two"], vec!["2", "tab\there \\ NULL"]]);
+        assert!(parse_tsv_output("").headers.is_empty());
+    }
+
+    #[test]
+    fn version_labels() {
+        let pg = "PostgreSQL 15.2 on x86_64-pc-linux-gnu, compiled by gcc (Debian 12.2.0-14) 12.2.0, 64-bit";
+        assert_eq!(QueryEngine::Postgres.version_label(pg), "PostgreSQL 15.2");
+        assert_eq!(QueryEngine::Postgres.version_label("PostgreSQL"), "PostgreSQL");
+        assert_eq!(QueryEngine::MySql.version_label("8.0.36"), "MySQL 8.0.36");
+        assert_eq!(QueryEngine::MySql.version_label("10.11.6-MariaDB-1:10.11.6+maria~ubu2204"), "MariaDB 10.11.6");
+    }
+
+    /// The pod's ends of a session over in-memory streams.
+    struct FakePod {
+        _stdin: tokio::io::DuplexStream,
+        stdout: tokio::io::DuplexStream,
+        stderr: tokio::io::DuplexStream,
+    }
+
+    fn fake_session(engine: QueryEngine) -> (QuerySession, FakePod) {
+        let (stdin, stdin_pod) = tokio::io::duplex(4096);
+        let (stdout, stdout_pod) = tokio::io::duplex(4096);
+        let (stderr, stderr_pod) = tokio::io::duplex(4096);
+        let pod = FakePod { _stdin: stdin_pod, stdout: stdout_pod, stderr: stderr_pod };
+        (QuerySession::from_streams(engine, stdin, stdout, stderr), pod)
+    }
+
+    #[tokio::test]
+    async fn session_errors_belong_to_the_statement_that_wrote_them() {
+        let (mut session, mut pod) = fake_session(QueryEngine::Postgres);
+        pod.stderr.write_all(b"psql:<stdin>:1: ERROR:  relation \"orgs\" does not exist\n").await.unwrap();
+        pod.stdout.write_all(format!("{SESSION_MARKER}\nid\n1\n{SESSION_MARKER}\n").as_bytes()).await.unwrap();
+
+        let error = session.execute("SELECT * FROM orgs").await.unwrap_err();
+        assert!(error.to_string().contains("relation \"orgs\" does not exist"));
+        assert!(error.downcast_ref::<SessionLost>().is_none());
+        let result = session.execute("SELECT 1 AS id").await.unwrap();
+        assert_eq!(result.rows, [["1"]]);
+    }
+
+    #[tokio::test]
+    async fn a_dead_session_or_lost_connection_is_reported_as_lost() {
+        let (mut session, pod) = fake_session(QueryEngine::Postgres);
+        drop(pod.stdout);
+        let error = session.execute("UPDATE orgs SET name = 'x'").await.unwrap_err();
+        assert!(error.downcast_ref::<SessionLost>().is_some());
+
+        let (mut session, mut pod) = fake_session(QueryEngine::MySql);
+        pod.stderr.write_all(b"ERROR 2013 (HY000): Lost connection to server during query\n").await.unwrap();
+        pod.stdout.write_all(format!("{SESSION_MARKER}\n{SESSION_MARKER}\n").as_bytes()).await.unwrap();
+        let error = session.execute("UPDATE orgs SET name = 'x'").await.unwrap_err();
+        assert!(error.downcast_ref::<SessionLost>().is_some());
+    }
+
+    #[test]
+    fn mysql_sessions_survive_errors_without_reconnecting() {
+        let command = session_command(&QueryConfig { engine: QueryEngine::MySql, ..config() });
+        assert!(command.contains(&"--force".to_string()));
+        assert!(command.contains(&"--skip-reconnect".to_string()));
+    }
+}
@@ -0,0 +1,131 @@
+//! LIMIT/OFFSET paging of query pane result sets.
+//!
+//! A read-only statement is fetched one page at a time so `SELECT * FROM events` on a large table
+//! returns its first rows quickly instead of loading everything into the pane.
+
+use crate::query::QueryEngine;
+use crate::query_params::{is_ident_byte, skip_dollar_quoted, skip_quoted};
+
+/// `sql` restricted to `limit` rows starting at `offset`, or `None` when it is not a single
+/// query that can be paged (DML, DDL, several statements, `SELECT … INTO` or locking reads).
+///
+/// The clause is appended when the statement has no top-level `LIMIT`, `OFFSET` or `FETCH`, which
+/// keeps its `ORDER BY` in effect. Otherwise the statement is paged as a subquery. Either way the
+/// statement ends its own line, so a trailing `--` comment cannot swallow what follows.
+pub fn page_sql(sql: &str, limit: usize, offset: usize, engine: QueryEngine) -> Option<String> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+    let words = words(sql, engine)?;
+    let first = words.first()?;
+    if first.depth > 0 || !["select", "with", "values", "table"].contains(&first.text.as_str()) {
+        return None;
+    }
+    let has = |keywords: &[&str]| words.iter().any(|w| w.depth == 0 && keywords.contains(&w.text.as_str()));
+    // Data-modifying CTEs hide their statement in parentheses.
+    if has(&["into"]) || words.iter().any(|w| ["insert", "update", "delete", "merge"].contains(&w.text.as_str())) {
+        return None;
+    }
+    if has(&["limit", "offset", "fetch"]) {
+        return Some(format!("SELECT * FROM (\n{sql}\n) AS kubetile_page LIMIT {limit} OFFSET {offset}"));
+    }
+    if has(&["for"]) {
+        return None;
+    }
+    Some(format!("{sql}\nLIMIT {limit} OFFSET {offset}"))
+}
+
+struct Word {
+    text: String,
+    /// Parenthesis nesting depth; 0 for the statement's own clauses.
+    depth: usize,
+}
+
+/// Lowercased words outside literals, quoted identifiers and comments, or `None` when the text
+/// holds more than one statement.
+fn words(sql: &str, engine: QueryEngine) -> Option<Vec<Word>> {
+    let bytes = sql.as_bytes();
+    let mut words = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => i = skip_quoted(bytes, i, quote, engine),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |n| i + n + 1);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = sql[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 2);
+            }
+            b'$' => i = skip_dollar_quoted(sql, i),
+            b'(' => {
+                depth += 1;
+                i += 1;
+            }
+            b')' => {
+                depth = depth.saturating_sub(1);
+                i += 1;
+            }
+            b';' if depth == 0 => return None,
+            b if b.is_ascii_alphabetic() || b == b'_' => {
+                let end = bytes[i..].iter().position(|b| !is_ident_byte(*b)).map_or(bytes.len(), |n| i + n);
+                words.push(Word { text: sql[i..end].to_ascii_lowercase(), depth });
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    Some(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_limit_to_plain_queries() {
+        assert_eq!(
+            page_sql("SELECT * FROM events ORDER BY id;\n", 101, 200, QueryEngine::Postgres).unwrap(),
+            "SELECT * FROM events ORDER BY id\nLIMIT 101 OFFSET 200"
+        );
+        assert_eq!(
+            page_sql("with t as (select 1 limit 1) select * from t", 10, 0, QueryEngine::Postgres).unwrap(),
+            "with t as (select 1 limit 1) select * from t\nLIMIT 10 OFFSET 0"
+        );
+    }
+
+    #[test]
+    fn wraps_queries_with_their_own_limit() {
+        assert_eq!(
+            page_sql("SELECT id FROM t LIMIT 5000", 100, 0, QueryEngine::Postgres).unwrap(),
+            "SELECT * FROM (\nSELECT id FROM t LIMIT 5000\n) AS kubetile_page LIMIT 100 OFFSET 0"
+        );
+    }
+
+    #[test]
+    fn trailing_comments_do_not_swallow_the_clause() {
+        assert_eq!(
+            page_sql("SELECT * FROM events -- newest first", 10, 0, QueryEngine::Postgres).unwrap(),
+            "SELECT * FROM events -- newest first\nLIMIT 10 OFFSET 0"
+        );
+        assert_eq!(
+            page_sql("SELECT id FROM t LIMIT 5 -- sample", 10, 0, QueryEngine::Postgres).unwrap(),
+            "SELECT * FROM (\nSELECT id FROM t LIMIT 5 -- sample\n) AS kubetile_page LIMIT 10 OFFSET 0"
+        );
+    }
+
+    #[test]
+    fn leaves_other_statements_alone() {
+        for sql in [
+            "UPDATE t SET a = 1",
+            "SHOW TABLES",
+            "SELECT 1; SELECT 2",
+            "SELECT * INTO backup FROM t",
+            "SELECT * FROM t FOR UPDATE",
+            "WITH d AS (DELETE FROM t RETURNING *) SELECT * FROM d",
+        ] {
+            assert_eq!(page_sql(sql, 10, 0, QueryEngine::Postgres), None, "{sql}");
+        }
+        assert!(page_sql("SELECT ';', 'limit' -- limit;\nFROM t", 10, 0, QueryEngine::Postgres)
+            .unwrap()
+            .ends_with("FROM t\nLIMIT 10 OFFSET 0"));
+    }
+}
@@ -0,0 +1,217 @@
+//! Named `:param` placeholders in query pane SQL.
+//!
+//! Placeholders are replaced by quoted literals before the SQL reaches the database client, so a
+//! saved query such as `SELECT * FROM orgs WHERE id = :org_id` can be run against any environment.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::query::QueryEngine;
+
+/// Names of the placeholders in `sql`, in order of first appearance.
+pub fn parameter_names(sql: &str, engine: QueryEngine) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (_, name) in placeholders(sql, engine) {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+/// Replaces every placeholder with its value quoted as a string literal for `engine`.
+pub fn bind_parameters(sql: &str, values: &HashMap<String, String>, engine: QueryEngine) -> anyhow::Result<String> {
+    let mut bound = String::with_capacity(sql.len());
+    let mut last = 0;
+    for (span, name) in placeholders(sql, engine) {
+        let value = values.get(name).ok_or_else(|| anyhow::anyhow!("no value for :{name}"))?;
+        bound.push_str(&sql[last..span.start]);
+        bound.push_str(&quote_literal(value, engine));
+        last = span.end;
+    }
+    bound.push_str(&sql[last..]);
+    Ok(bound)
+}
+
+/// Quotes `value` as a string literal. The databases coerce it where a number is expected;
+/// redis-cli and mongosh take it as a string argument.
+pub fn quote_literal(value: &str, engine: QueryEngine) -> String {
+    let escaped = match engine {
+        QueryEngine::Postgres => value.replace('\'', "''"),
+        // MySQL treats backslash as an escape character inside literals by default.
+        QueryEngine::MySql => value.replace('\\', "\\\\").replace('\'', "''"),
+        // redis-cli keeps backslashes inside single quotes, except before a quote, so a value
+        // ending in one could not be closed; double quotes take the usual escapes.
+        QueryEngine::Redis => return format!("\"{}\"", escape_backslashed(value, '"')),
+        QueryEngine::Mongo => escape_backslashed(value, '\''),
+    };
+    format!("'{escaped}'")
+}
+
+/// Backslash-escapes `quote`, backslashes and line breaks, which would end the statement.
+fn escape_backslashed(value: &str, quote: char) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c if c == quote => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Byte spans (including the colon) and names of the placeholders outside literals, quoted
+/// identifiers, comments and dollar-quoted bodies. `::` casts and `:=` are not placeholders.
+fn placeholders(sql: &str, engine: QueryEngine) -> Vec<(Range<usize>, &str)> {
+    let bytes = sql.as_bytes();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => i = skip_quoted(bytes, i, quote, engine),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                i = sql[i..].find('\n').map_or(bytes.len(), |n| i + n + 1);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = sql[i + 2..].find("*/").map_or(bytes.len(), |n| i + 2 + n + 2);
+            }
+            b'$' => i = skip_dollar_quoted(sql, i),
+            b':' if i == 0 || !is_ident_byte(bytes[i - 1]) && bytes[i - 1] != b':' => {
+                let start = i + 1;
+                let starts_name = bytes.get(start).is_some_and(|b| b.is_ascii_alphabetic() || *b == b'_');
+                if !starts_name {
+                    i += 1;
+                    continue;
+                }
+                let end = bytes[start..].iter().position(|b| !is_ident_byte(*b)).map_or(bytes.len(), |n| start + n);
+                found.push((i..end, &sql[start..end]));
+                i = end;
+            }
+            _ => i += 1,
+        }
+    }
+    found
+}
+
+pub(crate) fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// Index just past a literal or quoted identifier opened at `start`. Doubled quotes stay inside
+/// it. Backslash escapes do too in `'` literals of MySQL, redis-cli and mongosh; PostgreSQL
+/// standard strings take backslashes literally and only `E'…'` escape strings honor them.
+pub(crate) fn skip_quoted(bytes: &[u8], start: usize, quote: u8, engine: QueryEngine) -> usize {
+    let backslash_escapes = quote == b'\''
+        && match engine {
+            QueryEngine::Postgres => is_escape_string(bytes, start),
+            QueryEngine::MySql | QueryEngine::Redis | QueryEngine::Mongo => true,
+        };
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && backslash_escapes {
+            i += 2;
+        } else if bytes[i] == quote {
+            if bytes.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return i + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    bytes.len()
+}
+
+/// Whether the quote at `start` opens a PostgreSQL `E'…'` escape string.
+fn is_escape_string(bytes: &[u8], start: usize) -> bool {
+    let prefix = start.checked_sub(1).map(|i| bytes[i]);
+    let before = start.checked_sub(2).map(|i| bytes[i]);
+    matches!(prefix, Some(b'E' | b'e')) && !before.is_some_and(is_ident_byte)
+}
+
+/// Index just past a PostgreSQL `$tag$ … $tag$` body opened at `start`, or the next byte when
+/// the `$` does not open one (for example a `$1` positional parameter).
+pub(crate) fn skip_dollar_quoted(sql: &str, start: usize) -> usize {
+    let rest = &sql[start + 1..];
+    let Some(tag_len) = rest.find('$') else {
+        return start + 1;
+    };
+    let tag = &rest[..tag_len];
+    if !tag.bytes().all(is_ident_byte) || tag.bytes().next().is_some_and(|b| b.is_ascii_digit()) {
+        return start + 1;
+    }
+    let delimiter = &sql[start..start + tag_len + 2];
+    let body = start + delimiter.len();
+    sql[body..].find(delimiter).map_or(sql.len(), |n| body + n + delimiter.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn finds_named_parameters_once_in_order() {
+        let sql = "SELECT * FROM orgs WHERE id = :org_id AND region = :region OR parent = :org_id";
+        assert_eq!(parameter_names(sql, QueryEngine::Postgres), vec!["org_id", "region"]);
+    }
+
+    #[test]
+    fn ignores_casts_literals_comments_and_dollar_quotes() {
+        let sql = "SELECT created_at::date, ':not_a' AS \"col:x\", `t:y` -- :comment\n\
+                   /* :block */ FROM t WHERE a[1:n] = $1 AND b = $$ :body $$ AND c = :real AND @v := 1";
+        assert_eq!(parameter_names(sql, QueryEngine::Postgres), vec!["real"]);
+    }
+
+    #[test]
+    fn backslashes_escape_quotes_only_where_the_engine_says_so() {
+        let sql = r"SELECT 'C:\' AS dir WHERE a = :a";
+        assert_eq!(parameter_names(sql, QueryEngine::Postgres), vec!["a"]);
+        assert!(parameter_names(sql, QueryEngine::MySql).is_empty());
+
+        let sql = r"SELECT E'it\'s :x', 'C:\' WHERE b = :b";
+        assert_eq!(parameter_names(sql, QueryEngine::Postgres), vec!["b"]);
+        let sql = r"SELECT * FROM t WHERE name = 'it\'s :x' AND b = :b";
+        assert_eq!(parameter_names(sql, QueryEngine::MySql), vec!["b"]);
+    }
+
+    #[test]
+    fn binds_quoted_and_escaped_values() {
+        let sql = "SELECT * FROM users WHERE name = :name AND org = :org";
+        let bound =
+            bind_parameters(sql, &values(&[("name", "O'Brien"), ("org", "42")]), QueryEngine::Postgres).unwrap();
+        assert_eq!(bound, "SELECT * FROM users WHERE name = 'O''Brien' AND org = '42'");
+
+        let bound = bind_parameters("SELECT :v", &values(&[("v", r"a\' OR 1=1")]), QueryEngine::MySql).unwrap();
+        assert_eq!(bound, r"SELECT 'a\\'' OR 1=1'");
+    }
+
+    #[test]
+    fn redis_and_mongo_values_keep_their_backslashes() {
+        let vals = values(&[("v", r"C:\dir\")]);
+        assert_eq!(bind_parameters("GET :v", &vals, QueryEngine::Redis).unwrap(), r#"GET "C:\\dir\\""#);
+        let vals = values(&[("v", "say \"hi\"\nbye")]);
+        assert_eq!(bind_parameters("GET :v", &vals, QueryEngine::Redis).unwrap(), r#"GET "say \"hi\"\nbye""#);
+        let vals = values(&[("v", "it's")]);
+        assert_eq!(
+            bind_parameters("db.c.find({n: :v})", &vals, QueryEngine::Mongo).unwrap(),
+            r"db.c.find({n: 'it\'s'})"
+        );
+    }
+
+    #[test]
+    fn missing_value_is_an_error() {
+        let err = bind_parameters("SELECT :a", &HashMap::new(), QueryEngine::Postgres).unwrap_err();
+        assert_eq!(err.to_string(), "no value for :a");
+    }
+}
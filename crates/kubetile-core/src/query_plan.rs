@@ -0,0 +1,182 @@
+//! PostgreSQL `EXPLAIN (ANALYZE, FORMAT JSON)` plans for the query pane's plan view.
+
+use anyhow::Context;
+use serde_json::Value;
+
+/// One node of an executed plan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanNode {
+    /// `Node Type`, e.g. `Seq Scan` or `Hash Join`.
+    pub node_type: String,
+    /// The relation and index the node reads, e.g. `on users u using users_pkey`.
+    pub target: Option<String>,
+    pub startup_cost: f64,
+    pub total_cost: f64,
+    pub plan_rows: f64,
+    /// `Actual Total Time` of one loop in milliseconds; missing when the node never ran.
+    pub actual_time: Option<f64>,
+    pub actual_rows: Option<f64>,
+    pub loops: Option<f64>,
+    pub children: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    pub fn label(&self) -> String {
+        match &self.target {
+            Some(target) => format!("{} {target}", self.node_type),
+            None => self.node_type.clone(),
+        }
+    }
+
+    /// Time spent in this node across all loops, in milliseconds.
+    pub fn total_time(&self) -> Option<f64> {
+        Some(self.actual_time? * self.loops.unwrap_or(1.0))
+    }
+
+    /// Time spent in this node itself, without its children.
+    pub fn self_time(&self) -> Option<f64> {
+        let children: f64 = self.children.iter().filter_map(PlanNode::total_time).sum();
+        Some((self.total_time()? - children).max(0.0))
+    }
+
+    /// `cost=… rows=…` followed by the measured time, rows and loops when the node ran.
+    pub fn metrics(&self) -> String {
+        let mut out = format!("cost={:.2}..{:.2} rows={}", self.startup_cost, self.total_cost, self.plan_rows);
+        match (self.actual_time, self.self_time()) {
+            (Some(time), Some(self_time)) => out.push_str(&format!(
+                "  time={time:.3}ms self={self_time:.3}ms rows={} loops={}",
+                self.actual_rows.unwrap_or_default(),
+                self.loops.unwrap_or(1.0)
+            )),
+            _ => out.push_str("  (never executed)"),
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryPlan {
+    pub root: PlanNode,
+    pub planning_time: Option<f64>,
+    pub execution_time: Option<f64>,
+}
+
+/// Wraps `sql` in `EXPLAIN (ANALYZE, FORMAT JSON)`. ANALYZE runs the statement.
+pub fn explain_sql(sql: &str) -> String {
+    format!("EXPLAIN (ANALYZE, FORMAT JSON)\n{}", statement(sql))
+}
+
+/// Wraps `sql` in a plain `EXPLAIN (FORMAT JSON)`, which estimates without running it.
+pub fn estimate_sql(sql: &str) -> String {
+    format!("EXPLAIN (FORMAT JSON)\n{}", statement(sql))
+}
+
+/// The statement without its terminator. It goes on a line after the `EXPLAIN` clause, so a
+/// leading `--` comment cannot swallow the statement.
+fn statement(sql: &str) -> &str {
+    sql.trim().trim_end_matches(';').trim_end()
+}
+
+/// Parses the single `QUERY PLAN` value PostgreSQL returns for a JSON explain.
+pub fn parse_plan(json: &str) -> anyhow::Result<QueryPlan> {
+    let value: Value = serde_json::from_str(json).context("EXPLAIN did not return JSON")?;
+    let entry = value.get(0).unwrap_or(&value);
+    let root = entry.get("Plan").context("EXPLAIN output has no Plan")?;
+    Ok(QueryPlan {
+        root: parse_node(root),
+        planning_time: entry.get("Planning Time").and_then(Value::as_f64),
+        execution_time: entry.get("Execution Time").and_then(Value::as_f64),
+    })
+}
+
+fn parse_node(node: &Value) -> PlanNode {
+    let text = |key: &str| node.get(key).and_then(Value::as_str);
+    let number = |key: &str| node.get(key).and_then(Value::as_f64);
+
+    let mut target = Vec::new();
+    if let Some(relation) = text("Relation Name") {
+        target.push(format!("on {relation}"));
+        if let Some(alias) = text("Alias").filter(|a| *a != relation) {
+            target.push(alias.to_string());
+        }
+    }
+    if let Some(index) = text("Index Name") {
+        target.push(format!("using {index}"));
+    }
+    let loops = number("Actual Loops");
+
+    PlanNode {
+        node_type: text("Node Type").unwrap_or("?").to_string(),
+        target: (!target.is_empty()).then(|| target.join(" ")),
+        startup_cost: number("Startup Cost").unwrap_or_default(),
+        total_cost: number("Total Cost").unwrap_or_default(),
+        plan_rows: number("Plan Rows").unwrap_or_default(),
+        // Nodes that never ran report zero loops.
+        actual_time: number("Actual Total Time").filter(|_| loops != Some(0.0)),
+        actual_rows: number("Actual Rows"),
+        loops,
+        children: node
+            .get("Plans")
+            .and_then(Value::as_array)
+            .map(|plans| plans.iter().map(parse_node).collect())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAN: &str = r#"[{
+        "Plan": {
+            "Node Type": "Hash Join", "Startup Cost": 1.5, "Total Cost": 40.25, "Plan Rows": 10,
+            "Actual Total Time": 2.5, "Actual Rows": 8, "Actual Loops": 1,
+            "Plans": [
+                { "Node Type": "Seq Scan", "Relation Name": "orders", "Alias": "o",
+                  "Startup Cost": 0, "Total Cost": 30, "Plan Rows": 1000,
+                  "Actual Total Time": 1.5, "Actual Rows": 1000, "Actual Loops": 1 },
+                { "Node Type": "Index Scan", "Relation Name": "users", "Alias": "users",
+                  "Index Name": "users_pkey", "Startup Cost": 0.25, "Total Cost": 8.27, "Plan Rows": 1,
+                  "Actual Total Time": 0.1, "Actual Rows": 1, "Actual Loops": 4 },
+                { "Node Type": "Materialize", "Startup Cost": 0, "Total Cost": 1, "Plan Rows": 1,
+                  "Actual Total Time": 0, "Actual Rows": 0, "Actual Loops": 0 }
+            ]
+        },
+        "Planning Time": 0.2,
+        "Execution Time": 2.75
+    }]"#;
+
+    #[test]
+    fn parses_nodes_targets_and_timings() {
+        let plan = parse_plan(PLAN).unwrap();
+        assert_eq!(plan.planning_time, Some(0.2));
+        assert_eq!(plan.execution_time, Some(2.75));
+
+        let root = &plan.root;
+        assert_eq!(root.label(), "Hash Join");
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(root.children[0].label(), "Seq Scan on orders o");
+        assert_eq!(root.children[1].label(), "Index Scan on users using users_pkey");
+        assert!((root.children[1].total_time().unwrap() - 0.4).abs() < 1e-9);
+        assert!((root.self_time().unwrap() - 0.6).abs() < 1e-9);
+        assert_eq!(root.children[2].actual_time, None);
+    }
+
+    #[test]
+    fn metrics_show_estimates_and_measurements() {
+        let plan = parse_plan(PLAN).unwrap();
+        assert_eq!(
+            plan.root.children[0].metrics(),
+            "cost=0.00..30.00 rows=1000  time=1.500ms self=1.500ms rows=1000 loops=1"
+        );
+        assert_eq!(plan.root.children[2].metrics(), "cost=0.00..1.00 rows=1  (never executed)");
+    }
+
+    #[test]
+    fn explain_wraps_statement_without_trailing_semicolon() {
+        assert_eq!(explain_sql("SELECT 1;\n"), "EXPLAIN (ANALYZE, FORMAT JSON)\nSELECT 1");
+        assert_eq!(estimate_sql("SELECT 1;"), "EXPLAIN (FORMAT JSON)\nSELECT 1");
+        assert_eq!(estimate_sql("-- one\nSELECT 1 -- first"), "EXPLAIN (FORMAT JSON)\n-- one\nSELECT 1 -- first");
+        assert!(parse_plan("not json").is_err());
+    }
+}
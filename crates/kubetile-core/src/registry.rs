@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use http_body_util::{BodyExt, Empty};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{ACCEPT, AUTHORIZATION, LINK, WWW_AUTHENTICATE};
+use hyper::{Request, Response, StatusCode, Uri};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use serde::Deserialize;
+
+use crate::images::ImageRef;
+
+/// Time one image's tag lookup may take, token exchange and every page included.
+const LOOKUP_TIMEOUT: Duration = Duration::from_secs(10);
+/// Pages of 1000 tags read before the rest of a long tag list is left out.
+const MAX_PAGES: usize = 5;
+
+type HttpsClient = Client<HttpsConnector<HttpConnector>, Empty<Bytes>>;
+
+#[derive(Deserialize)]
+struct TagList {
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// A `WWW-Authenticate: Bearer …` challenge: where to get a token and the parameters to ask with.
+#[derive(Debug, PartialEq, Eq)]
+struct Challenge {
+    realm: String,
+    params: Vec<(String, String)>,
+}
+
+/// Lists the tags of `image`'s repository through the registry v2 API, with an anonymous token
+/// when the registry asks for one.
+pub async fn list_tags(image: &ImageRef) -> Result<Vec<String>> {
+    tokio::time::timeout(LOOKUP_TIMEOUT, fetch_tags(image)).await.map_err(|_| anyhow!("registry timed out"))?
+}
+
+async fn fetch_tags(image: &ImageRef) -> Result<Vec<String>> {
+    let https = HttpsConnectorBuilder::new().with_native_roots()?.https_or_http().enable_http1().build();
+    let client: HttpsClient = Client::builder(TokioExecutor::new()).build(https);
+    let host = if image.registry == "docker.io" { "registry-1.docker.io" } else { image.registry.as_str() };
+
+    let mut token = None;
+    let mut tags = Vec::new();
+    let mut next = Some(format!("/v2/{}/tags/list?n=1000", image.repository));
+    for _ in 0..MAX_PAGES {
+        let Some(path) = next.take() else { break };
+        let uri: Uri = format!("https://{host}{path}").parse()?;
+        let mut response = get(&client, &uri, token.as_deref()).await?;
+        if response.status() == StatusCode::UNAUTHORIZED && token.is_none() {
+            let challenge = response
+                .headers()
+                .get(WWW_AUTHENTICATE)
+                .and_then(|v| v.to_str().ok())
+                .and_then(parse_challenge)
+                .context("registry needs credentials")?;
+            token = Some(fetch_token(&client, &challenge).await?);
+            response = get(&client, &uri, token.as_deref()).await?;
+        }
+        if !response.status().is_success() {
+            bail!("registry answered {}", response.status());
+        }
+        next = response.headers().get(LINK).and_then(|v| v.to_str().ok()).and_then(next_page);
+        let body = response.into_body().collect().await?.to_bytes();
+        let list: TagList = serde_json::from_slice(&body)?;
+        tags.extend(list.tags.unwrap_or_default());
+    }
+    Ok(tags)
+}
+
+async fn get(client: &HttpsClient, uri: &Uri, token: Option<&str>) -> Result<Response<Incoming>> {
+    let mut request = Request::get(uri).header(ACCEPT, "application/json");
+    if let Some(token) = token {
+        request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+    }
+    Ok(client.request(request.body(Empty::new())?).await?)
+}
+
+async fn fetch_token(client: &HttpsClient, challenge: &Challenge) -> Result<String> {
+    let query: Vec<String> = challenge.params.iter().map(|(k, v)| format!("{k}={}", v.replace(' ', "%20"))).collect();
+    let uri: Uri = format!("{}?{}", challenge.realm, query.join("&")).parse()?;
+    let response = get(client, &uri, None).await?;
+    if !response.status().is_success() {
+        bail!("token endpoint answered {}", response.status());
+    }
+    let body = response.into_body().collect().await?.to_bytes();
+    let token: TokenResponse = serde_json::from_slice(&body)?;
+    token.token.or(token.access_token).context("token endpoint returned no token")
+}
+
+fn parse_challenge(header: &str) -> Option<Challenge> {
+    let (scheme, rest) = header.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("bearer") {
+        return None;
+    }
+    let mut realm = None;
+    let mut params = Vec::new();
+    for (key, value) in split_params(rest) {
+        if key == "realm" {
+            realm = Some(value);
+        } else {
+            params.push((key, value));
+        }
+    }
+    Some(Challenge { realm: realm?, params })
+}
+
+/// Splits `key="value",key=value` pairs, keeping commas inside quotes.
+fn split_params(params: &str) -> Vec<(String, String)> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in params.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let (key, value) = part.trim().split_once('=')?;
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Path of the next page from a `Link: </v2/…?last=x&n=1000>; rel="next"` header.
+fn next_page(link: &str) -> Option<String> {
+    if !link.contains("rel=\"next\"") {
+        return None;
+    }
+    let target = link.split_once('<')?.1.split_once('>')?.0;
+    match target.strip_prefix("https://") {
+        Some(absolute) => absolute.find('/').map(|i| absolute[i..].to_string()),
+        None => Some(target.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_challenge_yields_realm_and_token_params() {
+        let header = r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:library/nginx:pull""#;
+        assert_eq!(
+            parse_challenge(header),
+            Some(Challenge {
+                realm: "https://auth.docker.io/token".into(),
+                params: vec![
+                    ("service".into(), "registry.docker.io".into()),
+                    ("scope".into(), "repository:library/nginx:pull".into()),
+                ],
+            })
+        );
+        assert_eq!(parse_challenge(r#"Basic realm="registry""#), None);
+    }
+
+    #[test]
+    fn link_header_gives_the_next_page() {
+        let link = r#"</v2/library/nginx/tags/list?last=1.25&n=1000>; rel="next""#;
+        assert_eq!(next_page(link).as_deref(), Some("/v2/library/nginx/tags/list?last=1.25&n=1000"));
+        let absolute = r#"<https://ghcr.io/v2/org/app/tags/list?last=v3>; rel="next""#;
+        assert_eq!(next_page(absolute).as_deref(), Some("/v2/org/app/tags/list?last=v3"));
+        assert_eq!(next_page(r#"</v2/x>; rel="prev""#), None);
+    }
+}
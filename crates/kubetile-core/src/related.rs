@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::{Api, Client};
+
+use crate::container_memory::pod_workload;
+
+/// A resource one hop away from another, for "go to related" navigation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Related {
+    /// The controller of a pod; a ReplicaSet created by a Deployment resolves to the Deployment.
+    Owner {
+        kind: String,
+        name: String,
+    },
+    Node(String),
+    ServiceAccount(String),
+    PersistentVolumeClaim(String),
+    /// The pods a workload selects, as a label selector such as `app=api,tier=web`.
+    Pods(String),
+}
+
+impl Related {
+    pub fn label(&self) -> String {
+        match self {
+            Self::Owner { kind, name } => format!("{kind} {name}"),
+            Self::Node(name) => format!("Node {name}"),
+            Self::ServiceAccount(name) => format!("ServiceAccount {name}"),
+            Self::PersistentVolumeClaim(name) => format!("PersistentVolumeClaim {name}"),
+            Self::Pods(selector) => format!("Pods {selector}"),
+        }
+    }
+}
+
+/// Reads a pod and returns its owner, node, service account and claimed PVCs.
+pub async fn pod_related(client: Client, name: &str, namespace: &str) -> Result<Vec<Related>> {
+    let pod = Api::<Pod>::namespaced(client, namespace).get(name).await?;
+    Ok(pod_relations(&pod))
+}
+
+pub fn pod_relations(pod: &Pod) -> Vec<Related> {
+    let mut related = Vec::new();
+    if let Some((kind, name)) = pod_workload(pod).split_once('/').filter(|(kind, _)| *kind != "Pod") {
+        related.push(Related::Owner { kind: kind.to_string(), name: name.to_string() });
+    }
+    let spec = pod.spec.as_ref();
+    if let Some(node) = spec.and_then(|s| s.node_name.clone()) {
+        related.push(Related::Node(node));
+    }
+    let service_account = spec.and_then(|s| s.service_account_name.clone()).unwrap_or_else(|| "default".into());
+    related.push(Related::ServiceAccount(service_account));
+    let claims = spec
+        .and_then(|s| s.volumes.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.persistent_volume_claim.as_ref())
+        .map(|pvc| Related::PersistentVolumeClaim(pvc.claim_name.clone()));
+    related.extend(claims);
+    related
+}
+
+/// Joins `matchLabels` into a selector string; `None` when the selector has no match labels.
+pub fn label_selector_string(selector: &LabelSelector) -> Option<String> {
+    let labels: &BTreeMap<String, String> = selector.match_labels.as_ref().filter(|l| !l.is_empty())?;
+    Some(labels.iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::core::v1::{PersistentVolumeClaimVolumeSource, PodSpec, Volume};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    use super::*;
+
+    #[test]
+    fn pod_relations_cover_owner_node_service_account_and_claims() {
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("db-0".into());
+        pod.metadata.owner_references = Some(vec![OwnerReference {
+            kind: "StatefulSet".into(),
+            name: "db".into(),
+            controller: Some(true),
+            ..Default::default()
+        }]);
+        let claim = |name: &str| Volume {
+            name: name.into(),
+            persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                claim_name: format!("{name}-db-0"),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        pod.spec = Some(PodSpec {
+            node_name: Some("worker-1".into()),
+            volumes: Some(vec![claim("data"), Volume { name: "tmp".into(), ..Default::default() }, claim("wal")]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            pod_relations(&pod),
+            [
+                Related::Owner { kind: "StatefulSet".into(), name: "db".into() },
+                Related::Node("worker-1".into()),
+                Related::ServiceAccount("default".into()),
+                Related::PersistentVolumeClaim("data-db-0".into()),
+                Related::PersistentVolumeClaim("wal-db-0".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn bare_unscheduled_pod_only_has_its_service_account() {
+        let mut pod = Pod::default();
+        pod.metadata.name = Some("debug".into());
+        pod.spec = Some(PodSpec { service_account_name: Some("ci".into()), ..Default::default() });
+        assert_eq!(pod_relations(&pod), [Related::ServiceAccount("ci".into())]);
+    }
+
+    #[test]
+    fn selector_string_joins_match_labels() {
+        let selector = LabelSelector {
+            match_labels: Some([("app".to_string(), "api".to_string()), ("tier".into(), "web".into())].into()),
+            ..Default::default()
+        };
+        assert_eq!(label_selector_string(&selector).as_deref(), Some("app=api,tier=web"));
+        assert_eq!(label_selector_string(&LabelSelector::default()), None);
+    }
+}
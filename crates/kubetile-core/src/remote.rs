@@ -0,0 +1,157 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::{Child, Command};
+
+/// How long the SSH session may take to bring up `kubectl proxy` on the remote host.
+const TUNNEL_TIMEOUT: Duration = Duration::from_secs(20);
+/// What `kubectl proxy` prints once it accepts connections.
+const PROXY_READY: &str = "Starting to serve on";
+
+/// Builds the argv for a kubectl invocation. With an SSH host, kubectl runs on that host (where the
+/// kubeconfig and credentials live) and `ssh` carries its output back; `tty` allocates a remote
+/// terminal for interactive sessions.
+pub fn kubectl_argv(ssh_host: Option<&str>, tty: bool, args: &[String]) -> Vec<String> {
+    let Some(host) = ssh_host.filter(|h| !h.is_empty()) else {
+        return std::iter::once("kubectl".to_string()).chain(args.iter().cloned()).collect();
+    };
+
+    let mut argv = vec!["ssh".to_string()];
+    argv.push(if tty { "-tt" } else { "-T" }.to_string());
+    argv.extend(["-o".to_string(), "BatchMode=yes".to_string(), host.to_string(), "--".to_string()]);
+    // ssh joins the remaining words into one remote shell command, so each argument is quoted.
+    let remote = std::iter::once("kubectl").chain(args.iter().map(String::as_str)).map(shell_quote);
+    argv.push(remote.collect::<Vec<_>>().join(" "));
+    argv
+}
+
+/// `kubectl proxy` running on an SSH host with its socket forwarded to a local port, so API
+/// requests, and the port-forwards and exec sessions made through them, use the remote host's
+/// kubeconfig. Dropping it ends the SSH session, which stops the proxy.
+pub struct ApiTunnel {
+    _session: Child,
+    local_port: u16,
+}
+
+impl ApiTunnel {
+    /// Starts the proxy for `context` on `host` and waits until it serves.
+    pub async fn open(host: &str, context: &str) -> Result<Self> {
+        let local_port = std::net::TcpListener::bind("127.0.0.1:0")?.local_addr()?.port();
+        let socket = format!("/tmp/kubetile-api-{}-{local_port}.sock", std::process::id());
+        let argv = proxy_argv(host, context, local_port, &socket);
+        let mut session = Command::new(&argv[0])
+            .args(&argv[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("cannot run ssh")?;
+
+        let stdout = session.stdout.take().context("ssh stdout")?;
+        let mut stderr = session.stderr.take().context("ssh stderr")?;
+        // Both pipes are drained for the life of the session so the proxy never blocks on them.
+        let errors = tokio::spawn(async move {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text).await;
+            text
+        });
+        let ready = async {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if line.starts_with(PROXY_READY) {
+                    tokio::spawn(async move { while let Ok(Some(_)) = lines.next_line().await {} });
+                    return true;
+                }
+            }
+            false
+        };
+        match tokio::time::timeout(TUNNEL_TIMEOUT, ready).await {
+            Ok(true) => Ok(Self { _session: session, local_port }),
+            Ok(false) => {
+                let _ = session.wait().await;
+                let errors = errors.await.unwrap_or_default();
+                bail!("kubectl proxy on {host} did not start: {}", errors.trim())
+            }
+            Err(_) => bail!("kubectl proxy on {host} did not start within {}s", TUNNEL_TIMEOUT.as_secs()),
+        }
+    }
+
+    /// Where the local end of the tunnel accepts plain HTTP.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.local_port)
+    }
+}
+
+/// The `ssh` argv that runs `kubectl proxy` for `context` on a Unix socket of `host` and forwards
+/// `local_port` to it. Filtering is off because only the SSH forward reaches the socket, and exec
+/// requests must pass.
+fn proxy_argv(host: &str, context: &str, local_port: u16, socket: &str) -> Vec<String> {
+    let context = format!("--context={context}");
+    let socket_arg = format!("--unix-socket={socket}");
+    let kubectl = ["kubectl", &context, "proxy", &socket_arg, "--disable-filter=true"].map(shell_quote).join(" ");
+    // A proxy left running would outlive the session, so the remote shell stops it once ssh closes
+    // its stdin.
+    let remote = format!("{kubectl} & cat >/dev/null; kill $!; rm -f {}", shell_quote(socket));
+    let forward = format!("127.0.0.1:{local_port}:{socket}");
+    ["ssh", "-T", "-o", "BatchMode=yes", "-o", "ExitOnForwardFailure=yes", "-L", &forward, host, "--", &remote]
+        .map(str::to_string)
+        .to_vec()
+}
+
+/// Quotes `arg` for a POSIX shell, leaving words made only of safe characters as they are.
+pub(crate) fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_=.,:/@+".contains(c)) {
+        return arg.to_string();
+    }
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn local_kubectl_runs_directly() {
+        let argv = kubectl_argv(None, false, &args(&["logs", "--namespace=web", "api-0"]));
+        assert_eq!(argv, args(&["kubectl", "logs", "--namespace=web", "api-0"]));
+        assert_eq!(kubectl_argv(Some(""), false, &args(&["version"])), args(&["kubectl", "version"]));
+    }
+
+    #[test]
+    fn remote_kubectl_is_wrapped_in_ssh() {
+        let argv = kubectl_argv(
+            Some("ops@bastion"),
+            true,
+            &args(&["exec", "-it", "api-0", "--", "sh", "-c", "echo 'hi'; exec sh"]),
+        );
+        assert_eq!(&argv[..5], &args(&["ssh", "-tt", "-o", "BatchMode=yes", "ops@bastion"])[..]);
+        assert_eq!(argv[5], "--");
+        assert_eq!(argv[6], r"kubectl exec -it api-0 -- sh -c 'echo '\''hi'\''; exec sh'");
+    }
+
+    #[test]
+    fn api_tunnel_forwards_a_local_port_to_a_remote_proxy() {
+        let argv = proxy_argv("ops@bastion", "prod eu", 40123, "/tmp/kubetile-api-7-40123.sock");
+        assert_eq!(&argv[..4], &args(&["ssh", "-T", "-o", "BatchMode=yes"])[..]);
+        assert_eq!(argv[6..10], args(&["-L", "127.0.0.1:40123:/tmp/kubetile-api-7-40123.sock", "ops@bastion", "--"]));
+        assert_eq!(
+            argv[10],
+            "kubectl '--context=prod eu' proxy --unix-socket=/tmp/kubetile-api-7-40123.sock --disable-filter=true \
+             & cat >/dev/null; kill $!; rm -f /tmp/kubetile-api-7-40123.sock"
+        );
+    }
+
+    #[test]
+    fn shell_quote_leaves_plain_words_alone() {
+        assert_eq!(shell_quote("--context=prod"), "--context=prod");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("a b"), "'a b'");
+    }
+}
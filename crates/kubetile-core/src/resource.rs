@@ -1,5 +1,8 @@
+#[cfg(test)]
+use std::cell::Cell;
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use jiff::Timestamp;
 
 #[derive(Debug, Clone)]
@@ -15,13 +18,34 @@ pub trait ResourceSummary: Send + Sync {
     fn age(&self) -> Duration;
     fn columns(&self) -> Vec<(&str, String)>;
     fn row(&self) -> Vec<String>;
-    fn detail_sections(&self) -> Vec<DetailSection>;
+    /// Sections of the detail view, with absolute timestamps shown in `tz`.
+    fn detail_sections(&self, tz: &TimeZone) -> Vec<DetailSection>;
+}
+
+#[cfg(test)]
+thread_local! {
+    static PINNED_NOW: Cell<Option<Timestamp>> = const { Cell::new(None) };
+}
+
+/// The current time, which tests can pin for their thread with [`pin_now`].
+pub(crate) fn now() -> Timestamp {
+    #[cfg(test)]
+    if let Some(now) = PINNED_NOW.with(Cell::get) {
+        return now;
+    }
+    Timestamp::now()
+}
+
+/// Makes [`now`] return `now` on this thread, so ages and durations of fixtures are exact.
+#[cfg(test)]
+pub(crate) fn pin_now(now: Timestamp) {
+    PINNED_NOW.with(|pinned| pinned.set(Some(now)));
 }
 
 pub fn calculate_age(creation: Option<&k8s_openapi::apimachinery::pkg::apis::meta::v1::Time>) -> Duration {
     creation
         .and_then(|ts| {
-            let diff = Timestamp::now().since(ts.0).ok()?;
+            let diff = now().since(ts.0).ok()?;
             Some(Duration::from_secs(diff.get_seconds().unsigned_abs()))
         })
         .unwrap_or_default()
@@ -40,6 +64,51 @@ pub fn format_duration(d: Duration) -> String {
     }
 }
 
+/// A Kubernetes quantity (`250m`, `1.5Gi`, `2`) as a plain number in its base unit.
+pub fn parse_quantity(raw: &str) -> Option<f64> {
+    let s = raw.trim();
+    let split = s.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(s.len());
+    let (number, suffix) = s.split_at(split);
+    let value: f64 = number.parse().ok()?;
+    let mult = match suffix {
+        "" => 1.0,
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024.0,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        _ => return None,
+    };
+    Some(value * mult)
+}
+
+/// The timezone absolute timestamps are rendered in. `"local"` selects the system timezone.
+pub fn display_timezone(name: &str) -> Result<TimeZone, jiff::Error> {
+    if name.eq_ignore_ascii_case("local") {
+        Ok(TimeZone::system())
+    } else {
+        TimeZone::get(name)
+    }
+}
+
+pub fn format_timestamp(ts: Timestamp, tz: &TimeZone) -> String {
+    ts.to_zoned(tz.clone()).strftime("%Y-%m-%d %H:%M:%S %Z").to_string()
+}
+
+pub fn format_time(time: Option<Timestamp>, tz: &TimeZone) -> String {
+    time.map(|t| format_timestamp(t, tz)).unwrap_or_else(|| "<none>".into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +120,23 @@ mod tests {
         assert_eq!(format_duration(Duration::from_secs(7200)), "2h");
         assert_eq!(format_duration(Duration::from_secs(172800)), "2d");
     }
+
+    #[test]
+    fn format_timestamp_converts_to_zone() {
+        let ts: Timestamp = "2024-03-01T02:00:00Z".parse().unwrap();
+        assert_eq!(format_timestamp(ts, &TimeZone::UTC), "2024-03-01 02:00:00 UTC");
+        let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+        assert_eq!(format_timestamp(ts, &tokyo), "2024-03-01 11:00:00 JST");
+    }
+
+    #[test]
+    fn format_time_handles_missing_value() {
+        assert_eq!(format_time(None, &TimeZone::UTC), "<none>");
+    }
+
+    #[test]
+    fn display_timezone_rejects_unknown_zone() {
+        assert!(display_timezone("Not/AZone").is_err());
+        assert_eq!(display_timezone("UTC").unwrap(), TimeZone::UTC);
+    }
 }
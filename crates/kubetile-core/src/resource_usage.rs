@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_WEEK: f64 = 7.0 * 24.0 * 3600.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Usage {
+    count: u32,
+    /// Unix seconds of the last switch.
+    last_used: i64,
+}
+
+/// How often and how recently each resource view was picked in the resource switcher, keyed by
+/// short name, so the switcher can offer the usual views first. `default()` keeps the counts in
+/// memory only.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceUsage {
+    entries: BTreeMap<String, Usage>,
+    path: Option<PathBuf>,
+}
+
+impl ResourceUsage {
+    pub fn load() -> Self {
+        Self::load_from(resource_usage_path())
+    }
+
+    pub fn load_from(path: PathBuf) -> Self {
+        let entries =
+            std::fs::read_to_string(&path).ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        Self { entries, path: Some(path) }
+    }
+
+    pub fn record(&mut self, short_name: &str) -> io::Result<()> {
+        self.record_at(short_name, jiff::Timestamp::now().as_second())
+    }
+
+    fn record_at(&mut self, short_name: &str, now: i64) -> io::Result<()> {
+        let usage = self.entries.entry(short_name.to_string()).or_default();
+        usage.count = usage.count.saturating_add(1);
+        usage.last_used = now;
+        self.save()
+    }
+
+    /// Frecency of a view: its use count, halved for every week since it was last used.
+    pub fn score(&self, short_name: &str) -> f64 {
+        self.score_at(short_name, jiff::Timestamp::now().as_second())
+    }
+
+    fn score_at(&self, short_name: &str, now: i64) -> f64 {
+        let Some(usage) = self.entries.get(short_name) else { return 0.0 };
+        let weeks = (now - usage.last_used).max(0) as f64 / SECONDS_PER_WEEK;
+        f64::from(usage.count) * 0.5f64.powf(weeks)
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(&self.entries).map_err(io::Error::other)?;
+        std::fs::write(path, data)
+    }
+}
+
+fn resource_usage_path() -> PathBuf {
+    dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("resource_usage.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recent_use_outweighs_old_frequent_use() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("resource_usage.json");
+        let now = 1_800_000_000;
+        let three_weeks = (3.0 * SECONDS_PER_WEEK) as i64;
+
+        let mut usage = ResourceUsage::load_from(path.clone());
+        for _ in 0..4 {
+            usage.record_at("deploy", now - three_weeks).unwrap();
+        }
+        usage.record_at("svc", now).unwrap();
+
+        let usage = ResourceUsage::load_from(path);
+        assert_eq!(usage.score_at("deploy", now), 0.5);
+        assert_eq!(usage.score_at("svc", now), 1.0);
+        assert_eq!(usage.score_at("po", now), 0.0);
+    }
+}
@@ -1,17 +1,21 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
 use k8s_openapi::api::batch::v1::CronJob;
 
-use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+use crate::resource::{calculate_age, format_duration, format_time, now, DetailSection, ResourceSummary};
 
 #[derive(Debug, Clone)]
 pub struct CronJobSummary {
     pub name: String,
     pub namespace: String,
     pub schedule: String,
+    pub time_zone: String,
     pub suspend: bool,
     pub active: i32,
     pub last_schedule: String,
+    pub last_schedule_time: Option<Timestamp>,
     pub age: Duration,
 }
 
@@ -59,7 +63,7 @@ impl ResourceSummary for CronJobSummary {
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
@@ -71,13 +75,18 @@ impl ResourceSummary for CronJobSummary {
             },
             DetailSection {
                 title: "Spec".into(),
-                fields: vec![("Schedule".into(), self.schedule.clone()), ("Suspend".into(), self.suspend.to_string())],
+                fields: vec![
+                    ("Schedule".into(), self.schedule.clone()),
+                    ("Time Zone".into(), self.time_zone.clone()),
+                    ("Suspend".into(), self.suspend.to_string()),
+                ],
             },
             DetailSection {
                 title: "Status".into(),
                 fields: vec![
                     ("Active".into(), self.active.to_string()),
                     ("Last Schedule".into(), self.last_schedule.clone()),
+                    ("Last Schedule Time".into(), format_time(self.last_schedule_time, tz)),
                 ],
             },
         ]
@@ -92,6 +101,7 @@ impl From<&CronJob> for CronJobSummary {
 
         let spec = cj.spec.as_ref();
         let schedule = spec.map(|s| s.schedule.clone()).unwrap_or_default();
+        let time_zone = spec.and_then(|s| s.time_zone.clone()).unwrap_or_else(|| "<controller>".into());
         let suspend = spec.and_then(|s| s.suspend).unwrap_or(false);
 
         let status = cj.status.as_ref();
@@ -100,14 +110,15 @@ impl From<&CronJob> for CronJobSummary {
         let last_schedule = status
             .and_then(|s| s.last_schedule_time.as_ref())
             .and_then(|ts| {
-                let diff = jiff::Timestamp::now().since(ts.0).ok()?;
+                let diff = now().since(ts.0).ok()?;
                 Some(format_duration(Duration::from_secs(diff.get_seconds().unsigned_abs())))
             })
             .unwrap_or_else(|| "<none>".into());
+        let last_schedule_time = status.and_then(|s| s.last_schedule_time.as_ref()).map(|t| t.0);
 
         let age = calculate_age(meta.creation_timestamp.as_ref());
 
-        Self { name, namespace, schedule, suspend, active, last_schedule, age }
+        Self { name, namespace, schedule, time_zone, suspend, active, last_schedule, last_schedule_time, age }
     }
 }
 
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::apps::v1::Deployment;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -54,7 +55,7 @@ impl ResourceSummary for DeploymentSummary {
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
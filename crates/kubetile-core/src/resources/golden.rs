@@ -0,0 +1,140 @@
+//! Golden tests for list rows. Each manifest in `fixtures/summaries/*.yaml` is converted to its
+//! summary and compared with the matching `.golden` file: one `HEADER: value` line per column,
+//! then the list row as `row: a | b | c` (some kinds leave NAMESPACE out of the row).
+//! Timestamps may be written as `{{ago:90m}}` (units `s`, `m`, `h`, `d`); they expand relative to
+//! a pinned clock, so AGE and DURATION are exact. Run with `KUBETILE_BLESS=1` to rewrite the
+//! golden files.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use jiff::Timestamp;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
+};
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use serde::de::DeserializeOwned;
+
+use crate::resource::{pin_now, ResourceSummary};
+
+use super::*;
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures").join("summaries")
+}
+
+fn parse_ago(spec: &str) -> Duration {
+    let (amount, unit) = spec.split_at(spec.len() - 1);
+    let amount: u64 = amount.parse().unwrap_or_else(|_| panic!("bad {{{{ago:{spec}}}}}"));
+    let secs = match unit {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 3600,
+        "d" => amount * 86400,
+        _ => panic!("bad {{{{ago:{spec}}}}} unit"),
+    };
+    Duration::from_secs(secs)
+}
+
+fn expand_timestamps(manifest: &str, now: Timestamp) -> String {
+    let mut out = String::with_capacity(manifest.len());
+    let mut rest = manifest;
+    while let Some(start) = rest.find("{{ago:") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + "{{ago:".len()..];
+        let end = after.find("}}").expect("unterminated {{ago:...}}");
+        let ago = jiff::SignedDuration::try_from(parse_ago(&after[..end])).unwrap();
+        out.push_str(&now.checked_sub(ago).unwrap().to_string());
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn summarize(manifest: serde_json::Value) -> Box<dyn ResourceSummary> {
+    fn parse<K: DeserializeOwned>(manifest: serde_json::Value) -> K {
+        serde_json::from_value(manifest).expect("fixture does not match its kind")
+    }
+    let kind = manifest["kind"].as_str().expect("fixture has no kind").to_string();
+    match kind.as_str() {
+        "Pod" => Box::new(PodSummary::from(&parse::<Pod>(manifest))),
+        "Deployment" => Box::new(DeploymentSummary::from(&parse::<Deployment>(manifest))),
+        "StatefulSet" => Box::new(StatefulSetSummary::from(&parse::<StatefulSet>(manifest))),
+        "DaemonSet" => Box::new(DaemonSetSummary::from(&parse::<DaemonSet>(manifest))),
+        "Job" => Box::new(JobSummary::from(&parse::<Job>(manifest))),
+        "CronJob" => Box::new(CronJobSummary::from(&parse::<CronJob>(manifest))),
+        "Service" => Box::new(ServiceSummary::from(&parse::<Service>(manifest))),
+        "Ingress" => Box::new(IngressSummary::from(&parse::<Ingress>(manifest))),
+        "NetworkPolicy" => Box::new(NetworkPolicySummary::from(&parse::<NetworkPolicy>(manifest))),
+        "ResourceQuota" => Box::new(ResourceQuotaSummary::from(&parse::<ResourceQuota>(manifest))),
+        "LimitRange" => Box::new(LimitRangeSummary::from(&parse::<LimitRange>(manifest))),
+        "PodDisruptionBudget" => Box::new(PodDisruptionBudgetSummary::from(&parse::<PodDisruptionBudget>(manifest))),
+        "ConfigMap" => Box::new(ConfigMapSummary::from(&parse::<ConfigMap>(manifest))),
+        "Secret" => Box::new(SecretSummary::from(&parse::<Secret>(manifest))),
+        "Namespace" => Box::new(NamespaceSummary::from(&parse::<Namespace>(manifest))),
+        "Node" => Box::new(NodeSummary::from(&parse::<Node>(manifest))),
+        "PersistentVolume" => Box::new(PersistentVolumeSummary::from(&parse::<PersistentVolume>(manifest))),
+        "PersistentVolumeClaim" => {
+            Box::new(PersistentVolumeClaimSummary::from(&parse::<PersistentVolumeClaim>(manifest)))
+        }
+        other => panic!("no summary for kind {other}"),
+    }
+}
+
+fn render(summary: &dyn ResourceSummary) -> String {
+    let mut out: String = summary.columns().iter().map(|(header, value)| format!("{header}: {value}\n")).collect();
+    out.push_str(&format!("row: {}\n", summary.row().join(" | ")));
+    out
+}
+
+#[test]
+fn summary_rows_match_golden_files() {
+    let bless = std::env::var_os("KUBETILE_BLESS").is_some();
+    let now: Timestamp = "2024-03-01T12:00:00Z".parse().unwrap();
+    pin_now(now);
+    let mut fixtures: Vec<PathBuf> = std::fs::read_dir(fixtures_dir())
+        .expect("fixtures/summaries is missing")
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|ext| ext == "yaml"))
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty());
+
+    let mut failures = Vec::new();
+    for fixture in &fixtures {
+        let manifest = expand_timestamps(&std::fs::read_to_string(fixture).unwrap(), now);
+        let manifest: serde_json::Value = serde_yaml::from_str(&manifest).unwrap();
+        let actual = render(summarize(manifest).as_ref());
+
+        let golden = fixture.with_extension("golden");
+        if bless {
+            std::fs::write(&golden, &actual).unwrap();
+            continue;
+        }
+        match std::fs::read_to_string(&golden) {
+            Ok(expected) if expected == actual => {}
+            Ok(expected) => {
+                failures.push(format!("{}\n--- expected\n{expected}--- actual\n{actual}", golden.display()))
+            }
+            Err(_) => failures.push(format!("{} is missing; run with KUBETILE_BLESS=1", golden.display())),
+        }
+    }
+    assert!(failures.is_empty(), "{} golden mismatch(es):\n\n{}", failures.len(), failures.join("\n"));
+}
+
+#[test]
+fn ago_placeholders_expand_relative_to_now() {
+    let now: Timestamp = "2024-03-01T12:00:00Z".parse().unwrap();
+    assert_eq!(
+        expand_timestamps("creationTimestamp: \"{{ago:90m}}\"", now),
+        "creationTimestamp: \"2024-03-01T10:30:00Z\""
+    );
+    assert_eq!(
+        expand_timestamps("a: {{ago:2d}} b: {{ago:5s}}", now),
+        "a: 2024-02-28T12:00:00Z b: 2024-03-01T11:59:55Z"
+    );
+}
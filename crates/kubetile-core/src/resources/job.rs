@@ -1,8 +1,10 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
 use k8s_openapi::api::batch::v1::Job;
 
-use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+use crate::resource::{calculate_age, format_duration, format_time, now, DetailSection, ResourceSummary};
 
 #[derive(Debug, Clone)]
 pub struct JobSummary {
@@ -10,6 +12,8 @@ pub struct JobSummary {
     pub namespace: String,
     pub completions: String,
     pub duration: String,
+    pub start_time: Option<Timestamp>,
+    pub completion_time: Option<Timestamp>,
     pub age: Duration,
 }
 
@@ -44,7 +48,7 @@ impl ResourceSummary for JobSummary {
         vec![self.name.clone(), self.completions.clone(), self.duration.clone(), format_duration(self.age)]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
@@ -59,6 +63,8 @@ impl ResourceSummary for JobSummary {
                 fields: vec![
                     ("Completions".into(), self.completions.clone()),
                     ("Duration".into(), self.duration.clone()),
+                    ("Started".into(), format_time(self.start_time, tz)),
+                    ("Completed".into(), format_time(self.completion_time, tz)),
                 ],
             },
         ]
@@ -82,15 +88,17 @@ impl From<&Job> for JobSummary {
             .and_then(|s| {
                 let start = s.start_time.as_ref()?;
                 let end = s.completion_time.as_ref();
-                let end_ts = end.map(|t| t.0).unwrap_or_else(jiff::Timestamp::now);
+                let end_ts = end.map(|t| t.0).unwrap_or_else(now);
                 let diff = end_ts.since(start.0).ok()?;
                 Some(format_duration(Duration::from_secs(diff.get_seconds().unsigned_abs())))
             })
             .unwrap_or_else(|| "<none>".into());
+        let start_time = status.and_then(|s| s.start_time.as_ref()).map(|t| t.0);
+        let completion_time = status.and_then(|s| s.completion_time.as_ref()).map(|t| t.0);
 
         let age = calculate_age(meta.creation_timestamp.as_ref());
 
-        Self { name, namespace, completions, duration, age }
+        Self { name, namespace, completions, duration, start_time, completion_time, age }
     }
 }
 
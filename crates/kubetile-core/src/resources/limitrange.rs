@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use jiff::tz::TimeZone;
+use k8s_openapi::api::core::v1::{LimitRange, LimitRangeItem};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+
+#[derive(Debug, Clone)]
+pub struct LimitRangeSummary {
+    pub name: String,
+    pub namespace: String,
+    /// The limit types in spec order, e.g. `Container`, `Pod`, `PersistentVolumeClaim`.
+    pub types: Vec<String>,
+    /// One `(title, fields)` pair per limit, each field reading e.g. `cpu default` → `500m`.
+    pub limits: Vec<(String, Vec<(String, String)>)>,
+    pub age: Duration,
+}
+
+impl LimitRangeSummary {
+    fn types_display(&self) -> String {
+        if self.types.is_empty() {
+            "-".into()
+        } else {
+            self.types.join(",")
+        }
+    }
+
+    fn constraint_count(&self) -> usize {
+        self.limits.iter().map(|(_, fields)| fields.len()).sum()
+    }
+}
+
+impl ResourceSummary for LimitRangeSummary {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        Some(&self.namespace)
+    }
+
+    fn status_display(&self) -> String {
+        self.types_display()
+    }
+
+    fn age(&self) -> Duration {
+        self.age
+    }
+
+    fn columns(&self) -> Vec<(&str, String)> {
+        vec![
+            ("NAME", self.name.clone()),
+            ("NAMESPACE", self.namespace.clone()),
+            ("TYPES", self.types_display()),
+            ("CONSTRAINTS", self.constraint_count().to_string()),
+            ("AGE", format_duration(self.age)),
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.namespace.clone(),
+            self.types_display(),
+            self.constraint_count().to_string(),
+            format_duration(self.age),
+        ]
+    }
+
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
+        let mut sections = vec![DetailSection {
+            title: "Metadata".into(),
+            fields: vec![
+                ("Name".into(), self.name.clone()),
+                ("Namespace".into(), self.namespace.clone()),
+                ("Age".into(), format_duration(self.age)),
+            ],
+        }];
+        sections.extend(
+            self.limits.iter().map(|(title, fields)| DetailSection { title: title.clone(), fields: fields.clone() }),
+        );
+        sections
+    }
+}
+
+/// Flattens one limit into `resource constraint` → value fields, grouped by resource.
+fn limit_fields(item: &LimitRangeItem) -> Vec<(String, String)> {
+    let constraints: [(&str, Option<&BTreeMap<String, Quantity>>); 5] = [
+        ("min", item.min.as_ref()),
+        ("max", item.max.as_ref()),
+        ("default request", item.default_request.as_ref()),
+        ("default", item.default.as_ref()),
+        ("max limit/request", item.max_limit_request_ratio.as_ref()),
+    ];
+    let mut by_resource: BTreeMap<&str, Vec<(String, String)>> = BTreeMap::new();
+    for (constraint, values) in constraints {
+        for (resource, quantity) in values.into_iter().flatten() {
+            by_resource.entry(resource).or_default().push((format!("{resource} {constraint}"), quantity.0.clone()));
+        }
+    }
+    by_resource.into_values().flatten().collect()
+}
+
+impl From<&LimitRange> for LimitRangeSummary {
+    fn from(lr: &LimitRange) -> Self {
+        let meta = &lr.metadata;
+        let name = meta.name.clone().unwrap_or_default();
+        let namespace = meta.namespace.clone().unwrap_or_else(|| "default".into());
+
+        let items = lr.spec.as_ref().map(|s| s.limits.as_slice()).unwrap_or_default();
+        let types = items.iter().map(|i| i.type_.clone()).collect();
+        let limits = items.iter().map(|i| (format!("{} Limits", i.type_), limit_fields(i))).collect();
+
+        let age = calculate_age(meta.creation_timestamp.as_ref());
+
+        Self { name, namespace, types, limits, age }
+    }
+}
+
+impl From<LimitRange> for LimitRangeSummary {
+    fn from(lr: LimitRange) -> Self {
+        Self::from(&lr)
+    }
+}
@@ -4,14 +4,19 @@ mod daemonset;
 mod deployment;
 mod ingress;
 mod job;
+mod limitrange;
 mod namespace;
+mod networkpolicy;
 mod node;
+mod pdb;
 mod pod;
 mod pv;
 mod pvc;
+mod resourcequota;
 mod secret;
 mod service;
 mod statefulset;
+mod workload;
 
 pub use configmap::ConfigMapSummary;
 pub use cronjob::CronJobSummary;
@@ -19,14 +24,21 @@ pub use daemonset::DaemonSetSummary;
 pub use deployment::DeploymentSummary;
 pub use ingress::IngressSummary;
 pub use job::JobSummary;
+pub use limitrange::LimitRangeSummary;
 pub use namespace::NamespaceSummary;
+pub use networkpolicy::NetworkPolicySummary;
 pub use node::NodeSummary;
-pub use pod::{PodPhase, PodSummary};
+pub use pdb::PodDisruptionBudgetSummary;
+pub use pod::{ContainerRestarts, ContainerTermination, PodPhase, PodSummary};
 pub use pv::PersistentVolumeSummary;
 pub use pvc::PersistentVolumeClaimSummary;
+pub use resourcequota::{QuotaUsage, ResourceQuotaSummary};
 pub use secret::SecretSummary;
 pub use service::ServiceSummary;
 pub use statefulset::StatefulSetSummary;
+pub use workload::{WorkloadSummary, WORKLOAD_KINDS};
 
+#[cfg(test)]
+mod golden;
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::core::v1::Namespace;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -36,7 +37,7 @@ impl ResourceSummary for NamespaceSummary {
         vec![self.name.clone(), self.status.clone(), format_duration(self.age)]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
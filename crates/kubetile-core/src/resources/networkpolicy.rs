@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use jiff::tz::TimeZone;
+use k8s_openapi::api::networking::v1::NetworkPolicy;
+
+use crate::network_policy::{format_selector, policy_types};
+use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+
+#[derive(Debug, Clone)]
+pub struct NetworkPolicySummary {
+    pub name: String,
+    pub namespace: String,
+    pub pod_selector: String,
+    pub ingress: String,
+    pub egress: String,
+    pub age: Duration,
+}
+
+impl ResourceSummary for NetworkPolicySummary {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        Some(&self.namespace)
+    }
+
+    fn status_display(&self) -> String {
+        self.pod_selector.clone()
+    }
+
+    fn age(&self) -> Duration {
+        self.age
+    }
+
+    fn columns(&self) -> Vec<(&str, String)> {
+        vec![
+            ("NAME", self.name.clone()),
+            ("NAMESPACE", self.namespace.clone()),
+            ("POD-SELECTOR", self.pod_selector.clone()),
+            ("INGRESS", self.ingress.clone()),
+            ("EGRESS", self.egress.clone()),
+            ("AGE", format_duration(self.age)),
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.namespace.clone(),
+            self.pod_selector.clone(),
+            self.ingress.clone(),
+            self.egress.clone(),
+            format_duration(self.age),
+        ]
+    }
+
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
+        vec![
+            DetailSection {
+                title: "Metadata".into(),
+                fields: vec![
+                    ("Name".into(), self.name.clone()),
+                    ("Namespace".into(), self.namespace.clone()),
+                    ("Age".into(), format_duration(self.age)),
+                ],
+            },
+            DetailSection {
+                title: "Spec".into(),
+                fields: vec![
+                    ("Pod Selector".into(), self.pod_selector.clone()),
+                    ("Ingress".into(), self.ingress.clone()),
+                    ("Egress".into(), self.egress.clone()),
+                ],
+            },
+        ]
+    }
+}
+
+/// Describes one direction of a policy: `-` when it does not restrict that direction,
+/// `deny all` when it does with no rules, otherwise the rule count.
+fn direction_display(restricted: bool, rules: usize) -> String {
+    match (restricted, rules) {
+        (false, _) => "-".into(),
+        (true, 0) => "deny all".into(),
+        (true, 1) => "1 rule".into(),
+        (true, n) => format!("{n} rules"),
+    }
+}
+
+impl From<&NetworkPolicy> for NetworkPolicySummary {
+    fn from(np: &NetworkPolicy) -> Self {
+        let meta = &np.metadata;
+        let name = meta.name.clone().unwrap_or_default();
+        let namespace = meta.namespace.clone().unwrap_or_else(|| "default".into());
+
+        let spec = np.spec.as_ref();
+        let pod_selector =
+            spec.and_then(|s| s.pod_selector.as_ref()).map(format_selector).unwrap_or_else(|| "<all>".into());
+        let (restricts_ingress, restricts_egress) = policy_types(np);
+        let ingress_rules = spec.and_then(|s| s.ingress.as_ref()).map_or(0, Vec::len);
+        let egress_rules = spec.and_then(|s| s.egress.as_ref()).map_or(0, Vec::len);
+
+        let age = calculate_age(meta.creation_timestamp.as_ref());
+
+        Self {
+            name,
+            namespace,
+            pod_selector,
+            ingress: direction_display(restricts_ingress, ingress_rules),
+            egress: direction_display(restricts_egress, egress_rules),
+            age,
+        }
+    }
+}
+
+impl From<NetworkPolicy> for NetworkPolicySummary {
+    fn from(np: NetworkPolicy) -> Self {
+        Self::from(&np)
+    }
+}
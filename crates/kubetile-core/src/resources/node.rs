@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::core::v1::Node;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -50,7 +51,7 @@ impl ResourceSummary for NodeSummary {
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
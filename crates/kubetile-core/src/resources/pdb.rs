@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use jiff::tz::TimeZone;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+use crate::network_policy::format_selector;
+use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+
+#[derive(Debug, Clone)]
+pub struct PodDisruptionBudgetSummary {
+    pub name: String,
+    pub namespace: String,
+    pub min_available: Option<String>,
+    pub max_unavailable: Option<String>,
+    /// `status.disruptionsAllowed`; `None` until the disruption controller has reported.
+    pub allowed_disruptions: Option<i32>,
+    pub current_healthy: i32,
+    pub desired_healthy: i32,
+    pub expected_pods: i32,
+    pub selector: String,
+    pub age: Duration,
+}
+
+impl PodDisruptionBudgetSummary {
+    fn allowed_display(&self) -> String {
+        self.allowed_disruptions.map(|n| n.to_string()).unwrap_or_else(|| "-".into())
+    }
+}
+
+fn or_dash(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".into())
+}
+
+fn int_or_string(value: &IntOrString) -> String {
+    match value {
+        IntOrString::Int(n) => n.to_string(),
+        IntOrString::String(s) => s.clone(),
+    }
+}
+
+impl ResourceSummary for PodDisruptionBudgetSummary {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        Some(&self.namespace)
+    }
+
+    fn status_display(&self) -> String {
+        self.allowed_display()
+    }
+
+    fn age(&self) -> Duration {
+        self.age
+    }
+
+    fn columns(&self) -> Vec<(&str, String)> {
+        vec![
+            ("NAME", self.name.clone()),
+            ("NAMESPACE", self.namespace.clone()),
+            ("MIN AVAILABLE", or_dash(&self.min_available)),
+            ("MAX UNAVAILABLE", or_dash(&self.max_unavailable)),
+            ("ALLOWED DISRUPTIONS", self.allowed_display()),
+            ("AGE", format_duration(self.age)),
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.namespace.clone(),
+            or_dash(&self.min_available),
+            or_dash(&self.max_unavailable),
+            self.allowed_display(),
+            format_duration(self.age),
+        ]
+    }
+
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
+        vec![
+            DetailSection {
+                title: "Metadata".into(),
+                fields: vec![
+                    ("Name".into(), self.name.clone()),
+                    ("Namespace".into(), self.namespace.clone()),
+                    ("Age".into(), format_duration(self.age)),
+                ],
+            },
+            DetailSection {
+                title: "Spec".into(),
+                fields: vec![
+                    ("Selector".into(), self.selector.clone()),
+                    ("Min Available".into(), or_dash(&self.min_available)),
+                    ("Max Unavailable".into(), or_dash(&self.max_unavailable)),
+                ],
+            },
+            DetailSection {
+                title: "Status".into(),
+                fields: vec![
+                    ("Allowed Disruptions".into(), self.allowed_display()),
+                    ("Current Healthy".into(), self.current_healthy.to_string()),
+                    ("Desired Healthy".into(), self.desired_healthy.to_string()),
+                    ("Expected Pods".into(), self.expected_pods.to_string()),
+                ],
+            },
+        ]
+    }
+}
+
+impl From<&PodDisruptionBudget> for PodDisruptionBudgetSummary {
+    fn from(pdb: &PodDisruptionBudget) -> Self {
+        let meta = &pdb.metadata;
+        let name = meta.name.clone().unwrap_or_default();
+        let namespace = meta.namespace.clone().unwrap_or_else(|| "default".into());
+
+        let spec = pdb.spec.as_ref();
+        let min_available = spec.and_then(|s| s.min_available.as_ref()).map(int_or_string);
+        let max_unavailable = spec.and_then(|s| s.max_unavailable.as_ref()).map(int_or_string);
+        let selector = spec.and_then(|s| s.selector.as_ref()).map(format_selector).unwrap_or_else(|| "<none>".into());
+
+        let status = pdb.status.as_ref();
+        let allowed_disruptions = status.map(|s| s.disruptions_allowed);
+        let current_healthy = status.map(|s| s.current_healthy).unwrap_or(0);
+        let desired_healthy = status.map(|s| s.desired_healthy).unwrap_or(0);
+        let expected_pods = status.map(|s| s.expected_pods).unwrap_or(0);
+
+        let age = calculate_age(meta.creation_timestamp.as_ref());
+
+        Self {
+            name,
+            namespace,
+            min_available,
+            max_unavailable,
+            allowed_disruptions,
+            current_healthy,
+            desired_healthy,
+            expected_pods,
+            selector,
+            age,
+        }
+    }
+}
+
+impl From<PodDisruptionBudget> for PodDisruptionBudgetSummary {
+    fn from(pdb: PodDisruptionBudget) -> Self {
+        Self::from(&pdb)
+    }
+}
@@ -1,9 +1,12 @@
 use std::fmt;
 use std::time::Duration;
 
-use k8s_openapi::api::core::v1::Pod;
+use jiff::tz::TimeZone;
+use jiff::Timestamp;
+use k8s_openapi::api::core::v1::{ContainerStatus, Pod};
 
-use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
+use crate::container_memory::pod_owner_chain;
+use crate::resource::{calculate_age, format_duration, format_timestamp, DetailSection, ResourceSummary};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PodPhase {
@@ -12,6 +15,8 @@ pub enum PodPhase {
     Succeeded,
     Failed,
     Unknown,
+    /// The pod has a deletion timestamp and is shutting down.
+    Terminating,
 }
 
 impl fmt::Display for PodPhase {
@@ -22,10 +27,51 @@ impl fmt::Display for PodPhase {
             Self::Succeeded => write!(f, "Succeeded"),
             Self::Failed => write!(f, "Failed"),
             Self::Unknown => write!(f, "Unknown"),
+            Self::Terminating => write!(f, "Terminating"),
         }
     }
 }
 
+/// How a container's previous run ended (`lastState.terminated`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerTermination {
+    pub exit_code: i32,
+    pub reason: Option<String>,
+    pub finished_at: Option<Timestamp>,
+}
+
+impl ContainerTermination {
+    /// `Reason (exit N)`, followed by when it happened in `tz` if known.
+    pub fn describe(&self, tz: &TimeZone) -> String {
+        let mut out = format!("{} (exit {})", self.reason.as_deref().unwrap_or("Terminated"), self.exit_code);
+        if let Some(at) = self.finished_at {
+            out.push_str(&format!(" at {}", format_timestamp(at, tz)));
+        }
+        out
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerRestarts {
+    pub name: String,
+    pub restarts: i32,
+    pub waiting_reason: Option<String>,
+    pub last_termination: Option<ContainerTermination>,
+}
+
+impl From<&ContainerStatus> for ContainerRestarts {
+    fn from(status: &ContainerStatus) -> Self {
+        let waiting_reason = status.state.as_ref().and_then(|s| s.waiting.as_ref()).and_then(|w| w.reason.clone());
+        let last_termination =
+            status.last_state.as_ref().and_then(|s| s.terminated.as_ref()).map(|t| ContainerTermination {
+                exit_code: t.exit_code,
+                reason: t.reason.clone(),
+                finished_at: t.finished_at.as_ref().map(|time| time.0),
+            });
+        Self { name: status.name.clone(), restarts: status.restart_count, waiting_reason, last_termination }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PodSummary {
     pub name: String,
@@ -37,6 +83,24 @@ pub struct PodSummary {
     pub age: Duration,
     pub node: Option<String>,
     pub debug_mode: bool,
+    pub containers: Vec<ContainerRestarts>,
+    /// Controllers above the pod, outermost first and joined by ` > `, e.g.
+    /// `Deployment/api > ReplicaSet/api-5d8f9c7b6`. Empty for bare pods.
+    pub owner: String,
+}
+
+impl PodSummary {
+    /// Flags pods whose containers were OOM-killed on their last run or are crash looping.
+    pub fn alert(&self) -> Option<&'static str> {
+        let last_reason = |c: &ContainerRestarts| c.last_termination.as_ref().and_then(|t| t.reason.clone());
+        if self.containers.iter().any(|c| last_reason(c).as_deref() == Some("OOMKilled")) {
+            Some("OOMKilled")
+        } else if self.containers.iter().any(|c| c.waiting_reason.as_deref() == Some("CrashLoopBackOff")) {
+            Some("CrashLoop")
+        } else {
+            None
+        }
+    }
 }
 
 impl ResourceSummary for PodSummary {
@@ -66,6 +130,8 @@ impl ResourceSummary for PodSummary {
             ("AGE", format_duration(self.age)),
             ("NODE", self.node.clone().unwrap_or_default()),
             ("UID", self.uid.clone().unwrap_or_default()),
+            ("ALERT", self.alert().unwrap_or_default().to_string()),
+            ("OWNER", self.owner.clone()),
         ]
     }
 
@@ -80,10 +146,12 @@ impl ResourceSummary for PodSummary {
             format_duration(self.age),
             self.node.clone().unwrap_or_default(),
             self.uid.clone().unwrap_or_default(),
+            self.alert().unwrap_or_default().to_string(),
+            self.owner.clone(),
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, tz: &TimeZone) -> Vec<DetailSection> {
         let mut metadata = vec![
             ("Name".into(), self.name.clone()),
             ("Namespace".into(), self.namespace.clone()),
@@ -96,10 +164,28 @@ impl ResourceSummary for PodSummary {
 
         let status_section = vec![("Ready".into(), self.ready.clone()), ("Restarts".into(), self.restarts.to_string())];
 
-        vec![
+        let mut sections = vec![
             DetailSection { title: "Metadata".into(), fields: metadata },
             DetailSection { title: "Status".into(), fields: status_section },
-        ]
+        ];
+        if !self.containers.is_empty() {
+            let fields = self
+                .containers
+                .iter()
+                .map(|c| {
+                    let mut value = format!("{} restarts", c.restarts);
+                    if let Some(reason) = &c.waiting_reason {
+                        value.push_str(&format!(", waiting: {reason}"));
+                    }
+                    if let Some(last) = &c.last_termination {
+                        value.push_str(&format!(", last: {}", last.describe(tz)));
+                    }
+                    (c.name.clone(), value)
+                })
+                .collect();
+            sections.push(DetailSection { title: "Containers".into(), fields });
+        }
+        sections
     }
 }
 
@@ -110,18 +196,21 @@ impl From<&Pod> for PodSummary {
         let namespace = metadata.namespace.clone().unwrap_or_else(|| "default".into());
         let uid = metadata.uid.clone();
 
-        let status = pod
-            .status
-            .as_ref()
-            .and_then(|s| s.phase.as_deref())
-            .map(|p| match p {
-                "Running" => PodPhase::Running,
-                "Pending" => PodPhase::Pending,
-                "Succeeded" => PodPhase::Succeeded,
-                "Failed" => PodPhase::Failed,
-                _ => PodPhase::Unknown,
-            })
-            .unwrap_or(PodPhase::Unknown);
+        let status = if metadata.deletion_timestamp.is_some() {
+            PodPhase::Terminating
+        } else {
+            pod.status
+                .as_ref()
+                .and_then(|s| s.phase.as_deref())
+                .map(|p| match p {
+                    "Running" => PodPhase::Running,
+                    "Pending" => PodPhase::Pending,
+                    "Succeeded" => PodPhase::Succeeded,
+                    "Failed" => PodPhase::Failed,
+                    _ => PodPhase::Unknown,
+                })
+                .unwrap_or(PodPhase::Unknown)
+        };
 
         let container_statuses = pod.status.as_ref().and_then(|s| s.container_statuses.as_ref());
 
@@ -142,7 +231,12 @@ impl From<&Pod> for PodSummary {
 
         let debug_mode = metadata.annotations.as_ref().is_some_and(|a| a.contains_key("debug.kubetile.io/debug-mode"));
 
-        Self { name, namespace, uid, status, ready, restarts, age, node, debug_mode }
+        let containers =
+            container_statuses.map(|cs| cs.iter().map(ContainerRestarts::from).collect()).unwrap_or_default();
+
+        let owner = pod_owner_chain(pod).join(" > ");
+
+        Self { name, namespace, uid, status, ready, restarts, age, node, debug_mode, containers, owner }
     }
 }
 
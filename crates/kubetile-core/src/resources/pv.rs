@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::core::v1::PersistentVolume;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -59,7 +60,7 @@ impl ResourceSummary for PersistentVolumeSummary {
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::core::v1::PersistentVolumeClaim;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -49,6 +50,7 @@ impl ResourceSummary for PersistentVolumeClaimSummary {
     fn row(&self) -> Vec<String> {
         vec![
             self.name.clone(),
+            self.namespace.clone(),
             self.status.clone(),
             self.volume.clone(),
             self.capacity.clone(),
@@ -58,7 +60,7 @@ impl ResourceSummary for PersistentVolumeClaimSummary {
         ]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
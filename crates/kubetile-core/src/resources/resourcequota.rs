@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use jiff::tz::TimeZone;
+use k8s_openapi::api::core::v1::ResourceQuota;
+
+use crate::resource::{calculate_age, format_duration, parse_quantity, DetailSection, ResourceSummary};
+
+/// How much of one quota'd resource is in use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuotaUsage {
+    pub resource: String,
+    pub used: String,
+    pub hard: String,
+    /// `used / hard` as a percentage, when both quantities parse and `hard` is not zero.
+    pub percent: Option<f64>,
+}
+
+impl QuotaUsage {
+    /// `used / hard (percent%)`, the way the detail views show it.
+    pub fn display(&self) -> String {
+        match self.percent {
+            Some(percent) => format!("{} / {} ({percent:.0}%)", self.used, self.hard),
+            None => format!("{} / {}", self.used, self.hard),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceQuotaSummary {
+    pub name: String,
+    pub namespace: String,
+    /// One entry per resource in `status.hard`, sorted by resource name.
+    pub usage: Vec<QuotaUsage>,
+    pub age: Duration,
+}
+
+impl ResourceQuotaSummary {
+    /// The resource closest to (or furthest over) its hard limit.
+    pub fn most_used(&self) -> Option<&QuotaUsage> {
+        self.usage.iter().filter_map(|u| Some((u.percent?, u))).max_by(|a, b| a.0.total_cmp(&b.0)).map(|(_, u)| u)
+    }
+
+    fn highest_display(&self) -> String {
+        self.most_used().map(|u| format!("{} {}/{}", u.resource, u.used, u.hard)).unwrap_or_else(|| "-".into())
+    }
+
+    fn use_percent_display(&self) -> String {
+        self.most_used().and_then(|u| u.percent).map(|p| format!("{p:.0}%")).unwrap_or_else(|| "-".into())
+    }
+}
+
+impl ResourceSummary for ResourceQuotaSummary {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        Some(&self.namespace)
+    }
+
+    fn status_display(&self) -> String {
+        self.use_percent_display()
+    }
+
+    fn age(&self) -> Duration {
+        self.age
+    }
+
+    fn columns(&self) -> Vec<(&str, String)> {
+        vec![
+            ("NAME", self.name.clone()),
+            ("NAMESPACE", self.namespace.clone()),
+            ("RESOURCES", self.usage.len().to_string()),
+            ("HIGHEST", self.highest_display()),
+            ("USE%", self.use_percent_display()),
+            ("AGE", format_duration(self.age)),
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.namespace.clone(),
+            self.usage.len().to_string(),
+            self.highest_display(),
+            self.use_percent_display(),
+            format_duration(self.age),
+        ]
+    }
+
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
+        let mut usage: Vec<(String, String)> = self.usage.iter().map(|u| (u.resource.clone(), u.display())).collect();
+        if usage.is_empty() {
+            usage.push((String::new(), "No hard limits".into()));
+        }
+        vec![
+            DetailSection {
+                title: "Metadata".into(),
+                fields: vec![
+                    ("Name".into(), self.name.clone()),
+                    ("Namespace".into(), self.namespace.clone()),
+                    ("Age".into(), format_duration(self.age)),
+                ],
+            },
+            DetailSection { title: "Usage".into(), fields: usage },
+        ]
+    }
+}
+
+impl From<&ResourceQuota> for ResourceQuotaSummary {
+    fn from(quota: &ResourceQuota) -> Self {
+        let meta = &quota.metadata;
+        let name = meta.name.clone().unwrap_or_default();
+        let namespace = meta.namespace.clone().unwrap_or_else(|| "default".into());
+
+        // The controller copies spec.hard into status.hard once it has computed usage.
+        let status = quota.status.as_ref();
+        let hard = status.and_then(|s| s.hard.as_ref()).or_else(|| quota.spec.as_ref().and_then(|s| s.hard.as_ref()));
+        let used = status.and_then(|s| s.used.as_ref());
+        let usage = hard
+            .into_iter()
+            .flatten()
+            .map(|(resource, hard)| {
+                let used = used.and_then(|u| u.get(resource)).map_or("0", |q| q.0.as_str());
+                let percent = match (parse_quantity(used), parse_quantity(&hard.0)) {
+                    (Some(u), Some(h)) if h > 0.0 => Some(u / h * 100.0),
+                    _ => None,
+                };
+                QuotaUsage { resource: resource.clone(), used: used.to_string(), hard: hard.0.clone(), percent }
+            })
+            .collect();
+
+        let age = calculate_age(meta.creation_timestamp.as_ref());
+
+        Self { name, namespace, usage, age }
+    }
+}
+
+impl From<ResourceQuota> for ResourceQuotaSummary {
+    fn from(quota: ResourceQuota) -> Self {
+        Self::from(&quota)
+    }
+}
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::core::v1::Secret;
 
 use crate::resource::{calculate_age, format_duration, DetailSection, ResourceSummary};
@@ -44,7 +45,7 @@ impl ResourceSummary for SecretSummary {
         vec![self.name.clone(), self.type_.clone(), self.data_count.to_string(), format_duration(self.age)]
     }
 
-    fn detail_sections(&self) -> Vec<DetailSection> {
+    fn detail_sections(&self, _tz: &TimeZone) -> Vec<DetailSection> {
         vec![
             DetailSection {
                 title: "Metadata".into(),
@@ -1,11 +1,15 @@
 use std::time::Duration;
 
+use jiff::tz::TimeZone;
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
 };
 use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
 
 use crate::resource::ResourceSummary;
 
@@ -217,8 +221,8 @@ fn pod_phase_display() {
 #[test]
 fn pod_summary_columns_and_row_length() {
     let s = PodSummary::from(&default_pod());
-    assert_eq!(s.columns().len(), 8);
-    assert_eq!(s.row().len(), 8);
+    assert_eq!(s.columns().len(), 10);
+    assert_eq!(s.row().len(), 10);
 }
 
 #[test]
@@ -245,15 +249,17 @@ fn pod_summary_row_values() {
         age: Duration::from_secs(300),
         node: Some("node-1".into()),
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
     let row = s.row();
-    assert_eq!(row, vec!["nginx", "default", "Running", "1/1", "0", "5m", "node-1", "pod-uid-1"]);
+    assert_eq!(row, vec!["nginx", "default", "Running", "1/1", "0", "5m", "node-1", "pod-uid-1", "", ""]);
 }
 
 #[test]
 fn pod_summary_detail_sections() {
     let s = PodSummary::from(&default_pod());
-    let sections = s.detail_sections();
+    let sections = s.detail_sections(&TimeZone::UTC);
     assert!(!sections.is_empty());
     assert_eq!(sections[0].title, "Metadata");
 }
@@ -272,7 +278,7 @@ fn pod_summary_missing_status() {
 }
 
 #[test]
-fn pod_summary_columns_returns_eight_entries() {
+fn pod_summary_columns_returns_ten_entries() {
     let summary = PodSummary {
         name: "nginx".into(),
         namespace: "default".into(),
@@ -283,13 +289,17 @@ fn pod_summary_columns_returns_eight_entries() {
         age: Duration::from_secs(3600),
         node: Some("node-1".into()),
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
     let cols = summary.columns();
-    assert_eq!(cols.len(), 8);
+    assert_eq!(cols.len(), 10);
     assert_eq!(cols[0], ("NAME", "nginx".into()));
     assert_eq!(cols[2], ("STATUS", "Running".into()));
     assert_eq!(cols[5], ("AGE", "1h".into()));
     assert_eq!(cols[7], ("UID", "pod-uid-1".into()));
+    assert_eq!(cols[8], ("ALERT", "".into()));
+    assert_eq!(cols[9], ("OWNER", "".into()));
 }
 
 #[test]
@@ -304,6 +314,8 @@ fn resource_summary_trait_is_object_safe() {
         age: Duration::from_secs(120),
         node: None,
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
     let boxed: Box<dyn ResourceSummary> = Box::new(summary);
     assert_eq!(boxed.name(), "test");
@@ -322,9 +334,11 @@ fn pod_summary_row_includes_namespace_column() {
         age: Duration::from_secs(7200),
         node: Some("node-1".into()),
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
     let row = summary.row();
-    assert_eq!(row.len(), 8);
+    assert_eq!(row.len(), 10);
     assert_eq!(row[0], "nginx");
     assert_eq!(row[1], "default");
     assert_eq!(row[2], "Running");
@@ -347,8 +361,10 @@ fn pod_summary_detail_sections_has_metadata_and_status() {
         age: Duration::from_secs(60),
         node: None,
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
-    let sections = summary.detail_sections();
+    let sections = summary.detail_sections(&TimeZone::UTC);
     assert_eq!(sections.len(), 2);
     assert_eq!(sections[0].title, "Metadata");
     assert_eq!(sections[1].title, "Status");
@@ -368,12 +384,68 @@ fn pod_summary_detail_sections_includes_node_when_present() {
         age: Duration::from_secs(300),
         node: Some("worker-2".into()),
         debug_mode: false,
+        containers: Vec::new(),
+        owner: String::new(),
     };
-    let sections = summary.detail_sections();
+    let sections = summary.detail_sections(&TimeZone::UTC);
     assert_eq!(sections[0].fields.len(), 5);
     assert_eq!(sections[0].fields[4], ("Node".into(), "worker-2".into()));
 }
 
+fn crash_looping_pod(last_reason: &str, exit_code: i32) -> Pod {
+    serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1", "kind": "Pod",
+        "metadata": { "name": "api", "namespace": "web" },
+        "status": {
+            "phase": "Running",
+            "containerStatuses": [
+                {
+                    "name": "app", "ready": false, "restartCount": 7, "image": "api:1", "imageID": "",
+                    "state": { "waiting": { "reason": "CrashLoopBackOff" } },
+                    "lastState": { "terminated": {
+                        "exitCode": exit_code, "reason": last_reason, "finishedAt": "2024-03-01T02:00:00Z"
+                    } }
+                },
+                {
+                    "name": "sidecar", "ready": true, "restartCount": 0, "image": "proxy:1", "imageID": "",
+                    "state": { "running": {} }
+                }
+            ]
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn pod_summary_reads_last_termination_per_container() {
+    let s = PodSummary::from(&crash_looping_pod("Error", 1));
+    assert_eq!(s.containers.len(), 2);
+    let app = &s.containers[0];
+    assert_eq!(app.restarts, 7);
+    assert_eq!(app.waiting_reason.as_deref(), Some("CrashLoopBackOff"));
+    let last = app.last_termination.as_ref().unwrap();
+    assert_eq!(last.exit_code, 1);
+    assert_eq!(last.reason.as_deref(), Some("Error"));
+    assert_eq!(last.finished_at, Some("2024-03-01T02:00:00Z".parse().unwrap()));
+    assert_eq!(s.containers[1].last_termination, None);
+
+    let containers = s.detail_sections(&TimeZone::UTC).into_iter().find(|sec| sec.title == "Containers").unwrap();
+    assert_eq!(containers.fields[1], ("sidecar".into(), "0 restarts".into()));
+    assert_eq!(
+        containers.fields[0].1,
+        "7 restarts, waiting: CrashLoopBackOff, last: Error (exit 1) at 2024-03-01 02:00:00 UTC"
+    );
+}
+
+#[test]
+fn pod_summary_alert_prefers_oom_over_crash_loop() {
+    assert_eq!(PodSummary::from(&crash_looping_pod("Error", 1)).alert(), Some("CrashLoop"));
+    let oom = PodSummary::from(&crash_looping_pod("OOMKilled", 137));
+    assert_eq!(oom.alert(), Some("OOMKilled"));
+    assert_eq!(oom.row()[8], "OOMKilled");
+    assert_eq!(PodSummary::from(&default_pod()).alert(), None);
+}
+
 // --- Deployment ---
 
 #[test]
@@ -410,7 +482,7 @@ fn deployment_summary_row_values() {
 #[test]
 fn deployment_summary_detail_sections() {
     let s = DeploymentSummary::from(&default_deployment());
-    let sections = s.detail_sections();
+    let sections = s.detail_sections(&TimeZone::UTC);
     assert!(!sections.is_empty());
     assert_eq!(sections[0].title, "Metadata");
 }
@@ -449,7 +521,7 @@ fn service_summary_from_k8s() {
 #[test]
 fn service_summary_detail_sections() {
     let s = ServiceSummary::from(&default_service());
-    let sections = s.detail_sections();
+    let sections = s.detail_sections(&TimeZone::UTC);
     assert!(!sections.is_empty());
 }
 
@@ -472,7 +544,7 @@ fn statefulset_summary_from_k8s() {
 #[test]
 fn statefulset_summary_detail_sections() {
     let s = StatefulSetSummary::from(&default_statefulset());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- DaemonSet ---
@@ -496,7 +568,7 @@ fn daemonset_summary_from_k8s() {
 #[test]
 fn daemonset_summary_detail_sections() {
     let s = DaemonSetSummary::from(&default_daemonset());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Job ---
@@ -518,7 +590,26 @@ fn job_summary_from_k8s() {
 #[test]
 fn job_summary_detail_sections() {
     let s = JobSummary::from(&default_job());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
+}
+
+#[test]
+fn job_summary_without_times_shows_none() {
+    let s = JobSummary::from(&default_job());
+    assert_eq!(s.start_time, None);
+    let status = s.detail_sections(&TimeZone::UTC).pop().unwrap();
+    assert!(status.fields.contains(&("Started".into(), "<none>".into())));
+    assert!(status.fields.contains(&("Completed".into(), "<none>".into())));
+}
+
+#[test]
+fn job_summary_shows_times_in_the_given_zone() {
+    let mut job = default_job();
+    job.status.as_mut().unwrap().start_time = Some(Time("2024-03-01T02:00:00Z".parse().unwrap()));
+    let s = JobSummary::from(&job);
+    let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+    let status = s.detail_sections(&tokyo).pop().unwrap();
+    assert!(status.fields.contains(&("Started".into(), "2024-03-01 11:00:00 JST".into())));
 }
 
 // --- CronJob ---
@@ -542,10 +633,54 @@ fn cronjob_summary_from_k8s() {
 #[test]
 fn cronjob_summary_detail_sections() {
     let s = CronJobSummary::from(&default_cronjob());
-    let sections = s.detail_sections();
+    let sections = s.detail_sections(&TimeZone::UTC);
     assert_eq!(sections.len(), 3);
 }
 
+#[test]
+fn cronjob_summary_reports_schedule_time_zone() {
+    let mut cj = default_cronjob();
+    assert_eq!(CronJobSummary::from(&cj).time_zone, "<controller>");
+    assert_eq!(CronJobSummary::from(&cj).last_schedule_time, None);
+
+    cj.spec.as_mut().unwrap().time_zone = Some("Europe/Berlin".into());
+    assert_eq!(CronJobSummary::from(&cj).time_zone, "Europe/Berlin");
+}
+
+// --- Workload ---
+
+#[test]
+fn workload_summary_rows_carry_their_kind() {
+    let rows: Vec<Vec<String>> = [
+        WorkloadSummary::from(default_statefulset()),
+        WorkloadSummary::from(default_daemonset()),
+        WorkloadSummary::from(default_job()),
+        WorkloadSummary::from(default_cronjob()),
+    ]
+    .iter()
+    .map(|w| {
+        assert_eq!(w.columns().len(), w.row().len());
+        w.row()
+    })
+    .collect();
+    assert_eq!(rows[0][..4], ["redis", "default", "StatefulSet", "2/3"]);
+    assert_eq!(rows[1][..4], ["fluentd", "kube-system", "DaemonSet", "4/5"]);
+    assert_eq!(rows[2][2], "Job");
+    assert_eq!(rows[3][..4], ["backup", "default", "CronJob", "Active"]);
+
+    let deploy = WorkloadSummary::from(default_deployment());
+    assert_eq!(
+        deploy.detail_sections(&TimeZone::UTC).len(),
+        DeploymentSummary::from(&default_deployment()).detail_sections(&TimeZone::UTC).len()
+    );
+    for label in ["Deployment", "StatefulSet", "DaemonSet", "Job", "CronJob"] {
+        let kind = WorkloadSummary::kind_from_label(label).unwrap();
+        assert!(WORKLOAD_KINDS.contains(&kind), "{label}");
+    }
+    assert_eq!(WorkloadSummary::kind_from_label(deploy.kind_label()), Some(deploy.kind()));
+    assert_eq!(WorkloadSummary::kind_from_label("Pod"), None);
+}
+
 // --- ConfigMap ---
 
 #[test]
@@ -565,7 +700,7 @@ fn configmap_summary_from_k8s() {
 #[test]
 fn configmap_summary_detail_sections() {
     let s = ConfigMapSummary::from(&default_configmap());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Secret ---
@@ -588,7 +723,7 @@ fn secret_summary_from_k8s() {
 #[test]
 fn secret_detail_sections_never_show_values() {
     let s = SecretSummary::from(&default_secret());
-    let sections = s.detail_sections();
+    let sections = s.detail_sections(&TimeZone::UTC);
     for section in &sections {
         for (_key, value) in &section.fields {
             assert!(!value.contains("dXNlcg=="), "secret value leaked in detail_sections");
@@ -600,7 +735,7 @@ fn secret_detail_sections_never_show_values() {
 #[test]
 fn secret_summary_detail_sections() {
     let s = SecretSummary::from(&default_secret());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Ingress ---
@@ -624,7 +759,7 @@ fn ingress_summary_from_k8s() {
 #[test]
 fn ingress_summary_detail_sections() {
     let s = IngressSummary::from(&default_ingress());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Node ---
@@ -654,7 +789,7 @@ fn node_summary_namespace_is_none() {
 #[test]
 fn node_summary_detail_sections() {
     let s = NodeSummary::from(&default_node());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Namespace ---
@@ -682,7 +817,7 @@ fn namespace_summary_namespace_is_none() {
 #[test]
 fn namespace_summary_detail_sections() {
     let s = NamespaceSummary::from(&default_namespace());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- PersistentVolume ---
@@ -715,7 +850,7 @@ fn pv_summary_namespace_is_none() {
 #[test]
 fn pv_summary_detail_sections() {
     let s = PersistentVolumeSummary::from(&default_pv());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- PersistentVolumeClaim ---
@@ -724,7 +859,8 @@ fn pv_summary_detail_sections() {
 fn pvc_summary_columns_and_row_length() {
     let s = PersistentVolumeClaimSummary::from(&default_pvc());
     assert_eq!(s.columns().len(), 8);
-    assert_eq!(s.row().len(), 7);
+    assert_eq!(s.row().len(), 8);
+    assert_eq!(s.row()[1], s.namespace);
 }
 
 #[test]
@@ -741,7 +877,7 @@ fn pvc_summary_from_k8s() {
 #[test]
 fn pvc_summary_detail_sections() {
     let s = PersistentVolumeClaimSummary::from(&default_pvc());
-    assert!(!s.detail_sections().is_empty());
+    assert!(!s.detail_sections(&TimeZone::UTC).is_empty());
 }
 
 // --- Cross-cutting: minimal/empty objects don't panic ---
@@ -827,3 +963,94 @@ fn empty_pvc_does_not_panic() {
     .unwrap();
     let _ = PersistentVolumeClaimSummary::from(&pvc);
 }
+
+#[test]
+fn resource_quota_detail_shows_used_over_hard() {
+    let quota: ResourceQuota = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1", "kind": "ResourceQuota",
+        "metadata": { "name": "compute", "namespace": "web" },
+        "status": {
+            "hard": { "pods": "10", "requests.cpu": "2", "services": "5" },
+            "used": { "pods": "10", "requests.cpu": "500m" }
+        }
+    }))
+    .unwrap();
+    let summary = ResourceQuotaSummary::from(&quota);
+    assert_eq!(summary.most_used().map(|u| u.resource.as_str()), Some("pods"));
+    assert_eq!(summary.status_display(), "100%");
+
+    let sections = summary.detail_sections(&TimeZone::UTC);
+    let usage = sections.iter().find(|s| s.title == "Usage").unwrap();
+    assert_eq!(
+        usage.fields,
+        vec![
+            ("pods".to_string(), "10 / 10 (100%)".to_string()),
+            ("requests.cpu".to_string(), "500m / 2 (25%)".to_string()),
+            ("services".to_string(), "0 / 5 (0%)".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn limit_range_detail_has_a_section_per_type() {
+    let lr: LimitRange = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1", "kind": "LimitRange",
+        "metadata": { "name": "defaults", "namespace": "web" },
+        "spec": { "limits": [
+            { "type": "Container", "default": { "cpu": "500m" }, "defaultRequest": { "cpu": "100m" }, "max": { "memory": "1Gi" } },
+            { "type": "Pod", "max": { "cpu": "4" } }
+        ] }
+    }))
+    .unwrap();
+    let sections = LimitRangeSummary::from(&lr).detail_sections(&TimeZone::UTC);
+    let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, ["Metadata", "Container Limits", "Pod Limits"]);
+    assert_eq!(
+        sections[1].fields,
+        vec![
+            ("cpu default request".to_string(), "100m".to_string()),
+            ("cpu default".to_string(), "500m".to_string()),
+            ("memory max".to_string(), "1Gi".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn empty_quota_and_limit_range_do_not_panic() {
+    let quota: ResourceQuota = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1", "kind": "ResourceQuota", "metadata": {}
+    }))
+    .unwrap();
+    assert_eq!(ResourceQuotaSummary::from(&quota).row()[4], "-");
+    let lr: LimitRange = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1", "kind": "LimitRange", "metadata": {}
+    }))
+    .unwrap();
+    assert_eq!(LimitRangeSummary::from(&lr).row()[2], "-");
+}
+
+#[test]
+fn pod_disruption_budget_shows_percentages_and_allowed_disruptions() {
+    let pdb: PodDisruptionBudget = serde_json::from_value(serde_json::json!({
+        "apiVersion": "policy/v1", "kind": "PodDisruptionBudget",
+        "metadata": { "name": "web-pdb", "namespace": "web" },
+        "spec": { "maxUnavailable": "25%", "selector": { "matchLabels": { "app": "web" } } },
+        "status": { "disruptionsAllowed": 0, "currentHealthy": 3, "desiredHealthy": 3, "expectedPods": 4 }
+    }))
+    .unwrap();
+    let summary = PodDisruptionBudgetSummary::from(&pdb);
+    assert_eq!(summary.row()[2..5], ["-", "25%", "0"]);
+
+    let sections = summary.detail_sections(&TimeZone::UTC);
+    let spec = sections.iter().find(|s| s.title == "Spec").unwrap();
+    assert_eq!(spec.fields[0], ("Selector".to_string(), "app=web".to_string()));
+}
+
+#[test]
+fn empty_pod_disruption_budget_does_not_panic() {
+    let pdb: PodDisruptionBudget = serde_json::from_value(serde_json::json!({
+        "apiVersion": "policy/v1", "kind": "PodDisruptionBudget", "metadata": {}
+    }))
+    .unwrap();
+    assert_eq!(PodDisruptionBudgetSummary::from(&pdb).row()[4], "-");
+}
@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+use jiff::tz::TimeZone;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+
+use crate::actions::ResourceKind;
+use crate::resource::{format_duration, DetailSection, ResourceSummary};
+
+use super::{CronJobSummary, DaemonSetSummary, DeploymentSummary, JobSummary, StatefulSetSummary};
+
+/// Kinds listed together in the workloads view.
+pub const WORKLOAD_KINDS: [ResourceKind; 5] = [
+    ResourceKind::Deployments,
+    ResourceKind::StatefulSets,
+    ResourceKind::DaemonSets,
+    ResourceKind::Jobs,
+    ResourceKind::CronJobs,
+];
+
+/// A row of the workloads view: any workload kind, shown with a KIND column and its readiness.
+#[derive(Debug, Clone)]
+pub enum WorkloadSummary {
+    Deployment(DeploymentSummary),
+    StatefulSet(StatefulSetSummary),
+    DaemonSet(DaemonSetSummary),
+    Job(JobSummary),
+    CronJob(CronJobSummary),
+}
+
+impl WorkloadSummary {
+    fn inner(&self) -> &dyn ResourceSummary {
+        match self {
+            Self::Deployment(s) => s,
+            Self::StatefulSet(s) => s,
+            Self::DaemonSet(s) => s,
+            Self::Job(s) => s,
+            Self::CronJob(s) => s,
+        }
+    }
+
+    pub fn kind(&self) -> ResourceKind {
+        match self {
+            Self::Deployment(_) => ResourceKind::Deployments,
+            Self::StatefulSet(_) => ResourceKind::StatefulSets,
+            Self::DaemonSet(_) => ResourceKind::DaemonSets,
+            Self::Job(_) => ResourceKind::Jobs,
+            Self::CronJob(_) => ResourceKind::CronJobs,
+        }
+    }
+
+    /// The KIND cell of a row, e.g. `Deployment`.
+    pub fn kind_label(&self) -> &'static str {
+        match self {
+            Self::Deployment(_) => "Deployment",
+            Self::StatefulSet(_) => "StatefulSet",
+            Self::DaemonSet(_) => "DaemonSet",
+            Self::Job(_) => "Job",
+            Self::CronJob(_) => "CronJob",
+        }
+    }
+
+    /// The kind behind a KIND cell, for acting on the selected row.
+    pub fn kind_from_label(label: &str) -> Option<ResourceKind> {
+        match label {
+            "Deployment" => Some(ResourceKind::Deployments),
+            "StatefulSet" => Some(ResourceKind::StatefulSets),
+            "DaemonSet" => Some(ResourceKind::DaemonSets),
+            "Job" => Some(ResourceKind::Jobs),
+            "CronJob" => Some(ResourceKind::CronJobs),
+            _ => None,
+        }
+    }
+}
+
+impl ResourceSummary for WorkloadSummary {
+    fn name(&self) -> &str {
+        self.inner().name()
+    }
+
+    fn namespace(&self) -> Option<&str> {
+        self.inner().namespace()
+    }
+
+    fn status_display(&self) -> String {
+        self.inner().status_display()
+    }
+
+    fn age(&self) -> Duration {
+        self.inner().age()
+    }
+
+    fn columns(&self) -> Vec<(&str, String)> {
+        vec![
+            ("NAME", self.name().to_string()),
+            ("NAMESPACE", self.namespace().unwrap_or_default().to_string()),
+            ("KIND", self.kind_label().to_string()),
+            ("READY", self.status_display()),
+            ("AGE", format_duration(self.age())),
+        ]
+    }
+
+    fn row(&self) -> Vec<String> {
+        self.columns().into_iter().map(|(_, value)| value).collect()
+    }
+
+    fn detail_sections(&self, tz: &TimeZone) -> Vec<DetailSection> {
+        self.inner().detail_sections(tz)
+    }
+}
+
+impl From<Deployment> for WorkloadSummary {
+    fn from(d: Deployment) -> Self {
+        Self::Deployment(d.into())
+    }
+}
+
+impl From<StatefulSet> for WorkloadSummary {
+    fn from(s: StatefulSet) -> Self {
+        Self::StatefulSet(s.into())
+    }
+}
+
+impl From<DaemonSet> for WorkloadSummary {
+    fn from(d: DaemonSet) -> Self {
+        Self::DaemonSet(d.into())
+    }
+}
+
+impl From<Job> for WorkloadSummary {
+    fn from(j: Job) -> Self {
+        Self::Job(j.into())
+    }
+}
+
+impl From<CronJob> for WorkloadSummary {
+    fn from(c: CronJob) -> Self {
+        Self::CronJob(c.into())
+    }
+}
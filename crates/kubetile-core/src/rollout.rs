@@ -0,0 +1,212 @@
+use anyhow::Result;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use kube::{Api, Client};
+
+use crate::actions::ResourceKind;
+
+/// Where a workload rollout stands, judged the way `kubectl rollout status` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloutState {
+    /// The controller has not seen the latest spec yet.
+    Pending,
+    Progressing,
+    Complete,
+    /// The Progressing condition of a Deployment reports `ProgressDeadlineExceeded`.
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RolloutProgress {
+    pub desired: i32,
+    pub updated: i32,
+    pub available: i32,
+    /// Old replicas still running next to the updated ones.
+    pub old: i32,
+    pub state: RolloutState,
+}
+
+impl RolloutProgress {
+    /// `2 of 3 updated, 1 available`, naming old replicas while they wind down.
+    pub fn describe(&self) -> String {
+        let mut text = format!("{} of {} updated, {} available", self.updated, self.desired, self.available);
+        if self.old > 0 {
+            text.push_str(&format!(", {} old terminating", self.old));
+        }
+        text
+    }
+}
+
+pub fn rollout_progress(deploy: &Deployment) -> RolloutProgress {
+    let generation = deploy.metadata.generation.unwrap_or(0);
+    let desired = deploy.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let status = deploy.status.clone().unwrap_or_default();
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let old = status.replicas.unwrap_or(0).saturating_sub(updated).max(0);
+    let deadline_exceeded = status
+        .conditions
+        .unwrap_or_default()
+        .iter()
+        .any(|c| c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded"));
+
+    let state = if status.observed_generation.unwrap_or(0) < generation {
+        RolloutState::Pending
+    } else if deadline_exceeded {
+        RolloutState::Failed
+    } else if updated < desired || old > 0 || available < updated {
+        RolloutState::Progressing
+    } else {
+        RolloutState::Complete
+    };
+    RolloutProgress { desired, updated, available, old, state }
+}
+
+/// StatefulSets replace pods in place, so the old ones are those still at `currentRevision`
+/// until it catches up with `updateRevision`.
+pub fn statefulset_rollout_progress(sts: &StatefulSet) -> RolloutProgress {
+    let generation = sts.metadata.generation.unwrap_or(0);
+    let desired = sts.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let status = sts.status.clone().unwrap_or_default();
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let old = if status.current_revision != status.update_revision { status.current_replicas.unwrap_or(0) } else { 0 };
+
+    let state = if status.observed_generation.unwrap_or(0) < generation {
+        RolloutState::Pending
+    } else if updated < desired || old > 0 || available < desired {
+        RolloutState::Progressing
+    } else {
+        RolloutState::Complete
+    };
+    RolloutProgress { desired, updated, available, old, state }
+}
+
+/// DaemonSets want one pod per eligible node, so `desiredNumberScheduled` stands in for replicas.
+pub fn daemonset_rollout_progress(ds: &DaemonSet) -> RolloutProgress {
+    let generation = ds.metadata.generation.unwrap_or(0);
+    let status = ds.status.clone().unwrap_or_default();
+    let desired = status.desired_number_scheduled;
+    let updated = status.updated_number_scheduled.unwrap_or(0);
+    let available = status.number_available.unwrap_or(0);
+    let old = status.current_number_scheduled.saturating_sub(updated).max(0);
+
+    let state = if status.observed_generation.unwrap_or(0) < generation {
+        RolloutState::Pending
+    } else if updated < desired || available < desired {
+        RolloutState::Progressing
+    } else {
+        RolloutState::Complete
+    };
+    RolloutProgress { desired, updated, available, old, state }
+}
+
+pub async fn fetch_rollout_progress(
+    client: Client,
+    kind: &ResourceKind,
+    name: &str,
+    ns: &str,
+) -> Result<RolloutProgress> {
+    match kind {
+        ResourceKind::Deployments => Ok(rollout_progress(&Api::<Deployment>::namespaced(client, ns).get(name).await?)),
+        ResourceKind::StatefulSets => {
+            Ok(statefulset_rollout_progress(&Api::<StatefulSet>::namespaced(client, ns).get(name).await?))
+        }
+        ResourceKind::DaemonSets => {
+            Ok(daemonset_rollout_progress(&Api::<DaemonSet>::namespaced(client, ns).get(name).await?))
+        }
+        _ => anyhow::bail!("Rollout status not supported for {:?}", kind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deployment(generation: i64, status: serde_json::Value) -> Deployment {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "api", "namespace": "web", "generation": generation },
+            "spec": { "replicas": 3, "selector": {}, "template": {} },
+            "status": status
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn rollout_progress_follows_the_controller() {
+        let unseen = deployment(2, serde_json::json!({ "observedGeneration": 1, "replicas": 3, "updatedReplicas": 3 }));
+        assert_eq!(rollout_progress(&unseen).state, RolloutState::Pending);
+
+        let rolling = deployment(
+            2,
+            serde_json::json!({ "observedGeneration": 2, "replicas": 4, "updatedReplicas": 2, "availableReplicas": 3 }),
+        );
+        let progress = rollout_progress(&rolling);
+        assert_eq!(progress.state, RolloutState::Progressing);
+        assert_eq!(progress.describe(), "2 of 3 updated, 3 available, 2 old terminating");
+
+        let done = deployment(
+            2,
+            serde_json::json!({ "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }),
+        );
+        assert_eq!(rollout_progress(&done).state, RolloutState::Complete);
+
+        let stuck = deployment(
+            2,
+            serde_json::json!({
+                "observedGeneration": 2,
+                "replicas": 4,
+                "updatedReplicas": 1,
+                "conditions": [{ "type": "Progressing", "status": "False", "reason": "ProgressDeadlineExceeded" }]
+            }),
+        );
+        assert_eq!(rollout_progress(&stuck).state, RolloutState::Failed);
+    }
+
+    #[test]
+    fn statefulset_rollouts_wait_for_the_current_revision() {
+        let sts = |status: serde_json::Value| -> StatefulSet {
+            serde_json::from_value(serde_json::json!({
+                "metadata": { "name": "db", "namespace": "data", "generation": 2 },
+                "spec": { "replicas": 3, "selector": {}, "template": {}, "serviceName": "db" },
+                "status": status
+            }))
+            .unwrap()
+        };
+        let rolling = sts(serde_json::json!({
+            "observedGeneration": 2, "replicas": 3, "updatedReplicas": 1, "availableReplicas": 2,
+            "currentReplicas": 2, "currentRevision": "db-1", "updateRevision": "db-2"
+        }));
+        let progress = statefulset_rollout_progress(&rolling);
+        assert_eq!(progress.state, RolloutState::Progressing);
+        assert_eq!(progress.describe(), "1 of 3 updated, 2 available, 2 old terminating");
+
+        let done = sts(serde_json::json!({
+            "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3,
+            "currentReplicas": 3, "currentRevision": "db-2", "updateRevision": "db-2"
+        }));
+        assert_eq!(statefulset_rollout_progress(&done).state, RolloutState::Complete);
+    }
+
+    #[test]
+    fn daemonset_rollouts_count_scheduled_pods() {
+        let ds = |status: serde_json::Value| -> DaemonSet {
+            serde_json::from_value(serde_json::json!({
+                "metadata": { "name": "agent", "namespace": "ops", "generation": 4 },
+                "spec": { "selector": {}, "template": {} },
+                "status": status
+            }))
+            .unwrap()
+        };
+        let counts = |updated: i32, available: i32| {
+            serde_json::json!({
+                "observedGeneration": 4, "desiredNumberScheduled": 5, "currentNumberScheduled": 5,
+                "numberMisscheduled": 0, "numberReady": available, "updatedNumberScheduled": updated,
+                "numberAvailable": available
+            })
+        };
+        let rolling = daemonset_rollout_progress(&ds(counts(2, 4)));
+        assert_eq!(rolling.state, RolloutState::Progressing);
+        assert_eq!(rolling.describe(), "2 of 5 updated, 4 available, 3 old terminating");
+        assert_eq!(daemonset_rollout_progress(&ds(counts(5, 5))).state, RolloutState::Complete);
+    }
+}
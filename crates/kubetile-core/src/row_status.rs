@@ -0,0 +1,96 @@
+/// How a list row should stand out, judged from the cells it displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    Healthy,
+    /// Not serving everything it should yet: pending, or fewer replicas/containers ready than wanted.
+    /// Also a quota at 90% or more of a hard limit, or a disruption budget that allows no evictions.
+    Degraded,
+    Failed,
+    /// Deletion has started.
+    Terminating,
+}
+
+/// Quotas this full are shown as degraded; at 100% they are failed.
+const QUOTA_WARN_PERCENT: f64 = 90.0;
+
+/// Classifies a resource list row from its STATUS, READY and ALERT cells. `kind` is the display
+/// name of the list, e.g. `Pods`, matched case-insensitively.
+pub fn classify_row(kind: &str, headers: &[String], row: &[String]) -> RowStatus {
+    let cell = |name: &str| headers.iter().position(|h| h == name).and_then(|i| row.get(i)).map(String::as_str);
+    let status = cell("STATUS").unwrap_or_default();
+    if status == "Terminating" {
+        return RowStatus::Terminating;
+    }
+    let under_ready = cell("READY").and_then(parse_ready).is_some_and(|(ready, wanted)| ready < wanted);
+
+    match kind.to_ascii_lowercase().as_str() {
+        "pods" => match status {
+            "Failed" | "Unknown" => RowStatus::Failed,
+            _ if cell("ALERT").is_some_and(|a| !a.is_empty()) => RowStatus::Failed,
+            "Pending" => RowStatus::Degraded,
+            "Running" if under_ready => RowStatus::Degraded,
+            _ => RowStatus::Healthy,
+        },
+        "nodes" => match status {
+            "NotReady" | "Unknown" => RowStatus::Failed,
+            s if s.contains("SchedulingDisabled") => RowStatus::Degraded,
+            _ => RowStatus::Healthy,
+        },
+        "persistentvolumeclaims" | "persistentvolumes" => match status {
+            "Lost" | "Failed" => RowStatus::Failed,
+            "Pending" => RowStatus::Degraded,
+            _ => RowStatus::Healthy,
+        },
+        "resourcequotas" => match cell("USE%").and_then(|p| p.strip_suffix('%')?.parse::<f64>().ok()) {
+            Some(p) if p >= 100.0 => RowStatus::Failed,
+            Some(p) if p >= QUOTA_WARN_PERCENT => RowStatus::Degraded,
+            _ => RowStatus::Healthy,
+        },
+        "poddisruptionbudgets" if cell("ALLOWED DISRUPTIONS") == Some("0") => RowStatus::Degraded,
+        _ if under_ready => RowStatus::Degraded,
+        _ => RowStatus::Healthy,
+    }
+}
+
+fn parse_ready(cell: &str) -> Option<(u64, u64)> {
+    let (ready, wanted) = cell.split_once('/')?;
+    Some((ready.trim().parse().ok()?, wanted.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(kind: &str, cells: &[(&str, &str)]) -> RowStatus {
+        let headers: Vec<String> = cells.iter().map(|(h, _)| h.to_string()).collect();
+        let row: Vec<String> = cells.iter().map(|(_, v)| v.to_string()).collect();
+        classify_row(kind, &headers, &row)
+    }
+
+    #[test]
+    fn pods_classify_by_phase_readiness_and_alert() {
+        assert_eq!(classify("Pods", &[("STATUS", "Running"), ("READY", "2/2")]), RowStatus::Healthy);
+        assert_eq!(classify("Pods", &[("STATUS", "Running"), ("READY", "1/2")]), RowStatus::Degraded);
+        assert_eq!(classify("Pods", &[("STATUS", "Pending"), ("READY", "0/1")]), RowStatus::Degraded);
+        assert_eq!(classify("Pods", &[("STATUS", "Failed"), ("READY", "0/1")]), RowStatus::Failed);
+        assert_eq!(classify("Pods", &[("STATUS", "Running"), ("ALERT", "CrashLoop")]), RowStatus::Failed);
+        assert_eq!(classify("Pods", &[("STATUS", "Succeeded"), ("READY", "0/1")]), RowStatus::Healthy);
+        assert_eq!(classify("Pods", &[("STATUS", "Terminating"), ("READY", "1/1")]), RowStatus::Terminating);
+    }
+
+    #[test]
+    fn other_kinds_use_status_or_ready_counts() {
+        assert_eq!(classify("Nodes", &[("STATUS", "NotReady")]), RowStatus::Failed);
+        assert_eq!(classify("Nodes", &[("STATUS", "Ready")]), RowStatus::Healthy);
+        assert_eq!(classify("Namespaces", &[("STATUS", "Terminating")]), RowStatus::Terminating);
+        assert_eq!(classify("PersistentVolumeClaims", &[("STATUS", "Pending")]), RowStatus::Degraded);
+        assert_eq!(classify("Deployments", &[("READY", "2/3")]), RowStatus::Degraded);
+        assert_eq!(classify("Deployments", &[("READY", "3/3")]), RowStatus::Healthy);
+        assert_eq!(classify("ConfigMaps", &[("DATA", "3")]), RowStatus::Healthy);
+        assert_eq!(classify("ResourceQuotas", &[("USE%", "100%")]), RowStatus::Failed);
+        assert_eq!(classify("ResourceQuotas", &[("USE%", "92%")]), RowStatus::Degraded);
+        assert_eq!(classify("ResourceQuotas", &[("USE%", "-")]), RowStatus::Healthy);
+        assert_eq!(classify("PodDisruptionBudgets", &[("ALLOWED DISRUPTIONS", "0")]), RowStatus::Degraded);
+        assert_eq!(classify("PodDisruptionBudgets", &[("ALLOWED DISRUPTIONS", "1")]), RowStatus::Healthy);
+    }
+}
@@ -1,13 +1,94 @@
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
+/// Where a saved query is offered. Unset fields match everywhere, so a query without a scope is
+/// global.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct QueryScope {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database: Option<String>,
+}
+
+impl QueryScope {
+    /// Narrows to `level`, taking the fields from the pane's context, namespace and database.
+    pub fn at(level: ScopeLevel, context: &str, namespace: &str, database: &str) -> Self {
+        let field = |min: ScopeLevel, value: &str| (level >= min).then(|| value.to_string());
+        Self {
+            context: field(ScopeLevel::Context, context),
+            namespace: field(ScopeLevel::Namespace, namespace),
+            database: field(ScopeLevel::Database, database),
+        }
+    }
+
+    pub fn matches(&self, context: &str, namespace: &str, database: &str) -> bool {
+        let field = |scoped: &Option<String>, value: &str| scoped.as_deref().is_none_or(|s| s == value);
+        field(&self.context, context) && field(&self.namespace, namespace) && field(&self.database, database)
+    }
+
+    /// `global`, or the set fields joined with `/`, e.g. `prod/billing`.
+    pub fn label(&self) -> String {
+        let parts: Vec<&str> =
+            [&self.context, &self.namespace, &self.database].into_iter().filter_map(|f| f.as_deref()).collect();
+        if parts.is_empty() {
+            "global".to_string()
+        } else {
+            parts.join("/")
+        }
+    }
+}
+
+/// How narrowly a query is saved, from everywhere down to one database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScopeLevel {
+    #[default]
+    Global,
+    Context,
+    Namespace,
+    Database,
+}
+
+impl ScopeLevel {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Global => Self::Context,
+            Self::Context => Self::Namespace,
+            Self::Namespace => Self::Database,
+            Self::Database => Self::Global,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Global => "everywhere",
+            Self::Context => "this context",
+            Self::Namespace => "this namespace",
+            Self::Database => "this database",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SavedQuery {
     pub name: String,
     pub sql: String,
+    /// Missing in hand-written query packs.
+    #[serde(default)]
     pub ts: String,
+    #[serde(flatten, default)]
+    pub scope: QueryScope,
+}
+
+/// The file layout of an exported query library.
+#[derive(Deserialize, Serialize)]
+struct QueryPack {
+    queries: Vec<SavedQuery>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -19,15 +100,18 @@ pub struct SavedQueries {
 
 impl SavedQueries {
     pub fn load() -> Self {
-        let path = saved_queries_path();
+        Self::load_from(saved_queries_path())
+    }
+
+    fn load_from(path: PathBuf) -> Self {
         let entries =
             std::fs::read_to_string(&path).ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
         Self { entries, path }
     }
 
-    pub fn add(&mut self, name: &str, sql: &str) -> io::Result<()> {
+    pub fn add(&mut self, name: &str, sql: &str, scope: QueryScope) -> io::Result<()> {
         let ts = jiff::Timestamp::now().to_string();
-        self.entries.push(SavedQuery { name: name.to_string(), sql: sql.to_string(), ts });
+        self.entries.push(SavedQuery { name: name.to_string(), sql: sql.to_string(), ts, scope });
         self.save()
     }
 
@@ -47,6 +131,34 @@ impl SavedQueries {
         Ok(())
     }
 
+    /// Writes the library to `path` as TOML when it ends in `.toml`, otherwise as YAML.
+    pub fn export(&self, path: &Path) -> anyhow::Result<usize> {
+        let pack = QueryPack { queries: self.entries.clone() };
+        let data = if is_toml(path) { toml::to_string_pretty(&pack)? } else { serde_yaml::to_string(&pack)? };
+        std::fs::write(path, data).with_context(|| format!("writing {}", path.display()))?;
+        Ok(pack.queries.len())
+    }
+
+    /// Merges a library written by [`Self::export`]. A query with the name and scope of an
+    /// existing one replaces it. Returns how many queries the file held.
+    pub fn import(&mut self, path: &Path) -> anyhow::Result<usize> {
+        let data = std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let pack: QueryPack = if is_toml(path) {
+            toml::from_str(&data).with_context(|| format!("parsing {}", path.display()))?
+        } else {
+            serde_yaml::from_str(&data).with_context(|| format!("parsing {}", path.display()))?
+        };
+        let count = pack.queries.len();
+        for query in pack.queries {
+            match self.entries.iter_mut().find(|e| e.name == query.name && e.scope == query.scope) {
+                Some(existing) => *existing = query,
+                None => self.entries.push(query),
+            }
+        }
+        self.save()?;
+        Ok(count)
+    }
+
     fn save(&self) -> io::Result<()> {
         if let Some(parent) = self.path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -56,6 +168,56 @@ impl SavedQueries {
     }
 }
 
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
 fn saved_queries_path() -> PathBuf {
     dirs::config_dir().unwrap_or_else(|| PathBuf::from(".")).join("kubetile").join("saved_queries.json")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_narrow_by_level() {
+        let scope = QueryScope::at(ScopeLevel::Namespace, "prod", "billing", "app");
+        assert_eq!(scope.label(), "prod/billing");
+        assert!(scope.matches("prod", "billing", "other"));
+        assert!(!scope.matches("staging", "billing", "app"));
+        assert!(QueryScope::default().matches("any", "where", "at-all"));
+        assert_eq!(QueryScope::default().label(), "global");
+    }
+
+    #[test]
+    fn export_and_import_round_trip_and_replace_by_name_and_scope() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut library = SavedQueries::load_from(dir.path().join("saved.json"));
+        library.add("open orders", "SELECT 1", QueryScope::default()).unwrap();
+        library.add("open orders", "SELECT 2", QueryScope::at(ScopeLevel::Context, "prod", "", "")).unwrap();
+
+        for file in ["pack.yaml", "pack.toml"] {
+            let pack = dir.path().join(file);
+            assert_eq!(library.export(&pack).unwrap(), 2);
+
+            let mut other = SavedQueries::load_from(dir.path().join(format!("{file}.json")));
+            other.add("open orders", "SELECT old", QueryScope::default()).unwrap();
+            assert_eq!(other.import(&pack).unwrap(), 2);
+            assert_eq!(other.entries.len(), 2);
+            assert_eq!(other.entries[0].sql, "SELECT 1");
+            assert_eq!(other.entries[1].scope.context.as_deref(), Some("prod"));
+        }
+    }
+
+    #[test]
+    fn hand_written_packs_need_only_name_and_sql() {
+        let dir = tempfile::tempdir().unwrap();
+        let pack = dir.path().join("team.yml");
+        std::fs::write(&pack, "queries:\n  - name: locks\n    sql: SELECT * FROM pg_locks\n    namespace: db\n")
+            .unwrap();
+        let mut library = SavedQueries::load_from(dir.path().join("saved.json"));
+        assert_eq!(library.import(&pack).unwrap(), 1);
+        assert_eq!(library.entries[0].scope.label(), "db");
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+use mlua::{Function, HookTriggers, Lua, LuaOptions, RegistryKey, StdLib, Table, Value};
+
+/// Version of the `kubetile` table scripts are written against. Bumped only when a field or
+/// function is removed or changes meaning; additions keep the version.
+pub const API_VERSION: u32 = 1;
+
+/// Longest a single script call may run before it is stopped.
+const CALL_BUDGET: Duration = Duration::from_millis(500);
+/// Instructions between two checks of the call budget.
+const BUDGET_CHECK_EVERY: u32 = 10_000;
+
+/// The resource a script function is called for, passed to Lua as
+/// `{ kind, name, namespace, context, cells = { HEADER = value } }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptResource {
+    /// Plural kind, e.g. `pods`.
+    pub kind: String,
+    pub name: String,
+    /// Empty for cluster-scoped kinds.
+    pub namespace: String,
+    pub context: String,
+    /// The row's cells as shown in the list, by column header.
+    pub cells: BTreeMap<String, String>,
+}
+
+/// A key-bound script entry, listed with the plugins in the help.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptBinding {
+    pub name: String,
+    pub key: String,
+    pub description: String,
+}
+
+/// What running a script entry produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptOutput {
+    /// An action finished, optionally with a message for the user.
+    Message(Option<String>),
+    /// A pane rendered this text.
+    Pane(String),
+}
+
+struct ScriptColumn {
+    header: String,
+    kinds: Vec<String>,
+    value: RegistryKey,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    Action,
+    Pane,
+}
+
+struct ScriptEntry {
+    kind: EntryKind,
+    name: String,
+    key: String,
+    description: String,
+    kinds: Vec<String>,
+    function: RegistryKey,
+}
+
+/// What the scripts registered while they loaded.
+#[derive(Default)]
+struct Registry {
+    columns: Vec<ScriptColumn>,
+    entries: Vec<ScriptEntry>,
+}
+
+/// End of the running call's budget, checked by the instruction hook.
+struct Deadline(Option<Instant>);
+
+/// Lua scripts from the plugins directory. Each `*.lua` file is run once at load time and
+/// registers columns, actions and panes through the `kubetile` table:
+///
+/// - `kubetile.column { header, kinds, value = function(resource) }` adds a list column
+/// - `kubetile.action { name, key, description, kinds, run = function(resource) }` binds a key;
+///   a returned string is shown as a message
+/// - `kubetile.pane { name, key, description, kinds, render = function(resource) }` binds a key
+///   that opens a pane with the returned text (a string or a list of lines)
+/// - `kubetile.log(message)` writes to the app log, and `kubetile.api_version` is [`API_VERSION`]
+pub struct ScriptHost {
+    lua: Mutex<Lua>,
+    /// Kept outside the Lua state so listing columns and bindings never waits on a running script.
+    registry: Arc<Mutex<Registry>>,
+    errors: Vec<String>,
+}
+
+impl ScriptHost {
+    /// A host without scripts.
+    pub fn new() -> Self {
+        let lua = Lua::new_with(StdLib::ALL_SAFE, LuaOptions::default()).expect("the safe Lua libraries load");
+        lua.set_app_data(Deadline(None));
+        lua.set_hook(HookTriggers::new().every_nth_instruction(BUDGET_CHECK_EVERY), |lua, _| {
+            let over = lua.app_data_ref::<Deadline>().and_then(|d| d.0).is_some_and(|end| Instant::now() > end);
+            if over {
+                return Err(mlua::Error::RuntimeError(format!("script ran longer than {}ms", CALL_BUDGET.as_millis())));
+            }
+            Ok(())
+        });
+        let registry = Arc::new(Mutex::new(Registry::default()));
+        let mut errors = Vec::new();
+        if let Err(e) = install_api(&lua, &registry) {
+            errors.push(format!("kubetile API: {e}"));
+        }
+        Self { lua: Mutex::new(lua), registry, errors }
+    }
+
+    /// Loads every `*.lua` file in `dir`, in file-name order. A script that fails to load is
+    /// skipped and reported by [`ScriptHost::errors`]; what it registered before failing is kept.
+    pub fn load_dir(dir: &Path) -> Self {
+        let mut host = Self::new();
+        let Ok(entries) = std::fs::read_dir(dir) else { return host };
+        let mut paths: Vec<PathBuf> =
+            entries.flatten().map(|e| e.path()).filter(|p| p.extension().is_some_and(|ext| ext == "lua")).collect();
+        paths.sort();
+        for path in paths {
+            if let Err(e) = host.load_file(&path) {
+                host.errors.push(format!("{}: {e}", path.display()));
+            }
+        }
+        host
+    }
+
+    pub fn load_file(&mut self, path: &Path) -> Result<()> {
+        let source = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+        self.load_source(&name, &source)
+    }
+
+    /// Runs `source` as a script called `name`.
+    pub fn load_source(&mut self, name: &str, source: &str) -> Result<()> {
+        let lua = self.lua();
+        with_budget(&lua, || lua.load(source).set_name(name).exec())?;
+        Ok(())
+    }
+
+    /// Scripts that failed to load, with the reason.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registry(|r| r.columns.is_empty() && r.entries.is_empty())
+    }
+
+    /// Headers of the script columns shown for `kind`, in registration order.
+    pub fn column_headers(&self, kind: &str) -> Vec<String> {
+        self.registry(|r| r.columns.iter().filter(|c| applies(&c.kinds, kind)).map(|c| c.header.clone()).collect())
+    }
+
+    /// Values of the script columns for one row, in the order of [`ScriptHost::column_headers`].
+    /// A column whose function fails gets the error instead of a value.
+    pub fn column_values(&self, resource: &ScriptResource) -> Vec<Result<String>> {
+        let lua = self.lua();
+        let functions: Vec<mlua::Result<Function>> = self.registry(|r| {
+            r.columns
+                .iter()
+                .filter(|c| applies(&c.kinds, &resource.kind))
+                .map(|c| lua.registry_value::<Function>(&c.value))
+                .collect()
+        });
+        functions
+            .into_iter()
+            .map(|function| {
+                let value = call(&lua, &function?, resource)?;
+                Ok(match value {
+                    Value::Nil => String::new(),
+                    other => lua.coerce_string(other)?.map(|s| s.to_string_lossy().into_owned()).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+
+    /// Registered actions and panes, for binding their keys.
+    pub fn bindings(&self) -> Vec<ScriptBinding> {
+        self.registry(|r| {
+            r.entries
+                .iter()
+                .map(|e| ScriptBinding { name: e.name.clone(), key: e.key.clone(), description: e.description.clone() })
+                .collect()
+        })
+    }
+
+    /// Whether the action or pane `name` exists and may run on `kind`.
+    pub fn applies_to(&self, name: &str, kind: &str) -> bool {
+        self.registry(|r| r.entries.iter().any(|e| e.name == name && applies(&e.kinds, kind)))
+    }
+
+    /// Runs the action or renders the pane `name` for `resource`.
+    pub fn run(&self, name: &str, resource: &ScriptResource) -> Result<ScriptOutput> {
+        let lua = self.lua();
+        let (kind, function) = self
+            .registry(|r| {
+                r.entries.iter().find(|e| e.name == name).map(|e| (e.kind, lua.registry_value::<Function>(&e.function)))
+            })
+            .ok_or_else(|| anyhow!("no script action or pane named {name}"))?;
+        let value = call(&lua, &function?, resource)?;
+        match kind {
+            EntryKind::Action => Ok(ScriptOutput::Message(match value {
+                Value::Nil => None,
+                other => lua.coerce_string(other)?.map(|s| s.to_string_lossy().into_owned()),
+            })),
+            EntryKind::Pane => Ok(ScriptOutput::Pane(pane_text(&lua, value)?)),
+        }
+    }
+
+    fn lua(&self) -> MutexGuard<'_, Lua> {
+        self.lua.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn registry<T>(&self, f: impl FnOnce(&Registry) -> T) -> T {
+        f(&self.registry.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+impl Default for ScriptHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn install_api(lua: &Lua, registry: &Arc<Mutex<Registry>>) -> mlua::Result<()> {
+    let api = lua.create_table()?;
+    api.set("api_version", API_VERSION)?;
+    api.set(
+        "log",
+        lua.create_function(|_, message: String| {
+            tracing::info!("script: {message}");
+            Ok(())
+        })?,
+    )?;
+    let columns = registry.clone();
+    api.set(
+        "column",
+        lua.create_function(move |lua, spec: Table| {
+            let column = ScriptColumn {
+                header: spec.get::<_, String>("header")?,
+                kinds: kinds_of(&spec)?,
+                value: lua.create_registry_value(spec.get::<_, Function>("value")?)?,
+            };
+            columns.lock().unwrap_or_else(|e| e.into_inner()).columns.push(column);
+            Ok(())
+        })?,
+    )?;
+    let actions = registry.clone();
+    api.set(
+        "action",
+        lua.create_function(move |lua, spec: Table| register_entry(lua, &actions, spec, EntryKind::Action))?,
+    )?;
+    let panes = registry.clone();
+    api.set("pane", lua.create_function(move |lua, spec: Table| register_entry(lua, &panes, spec, EntryKind::Pane))?)?;
+    lua.globals().set("kubetile", api)
+}
+
+/// Calls `function` with the resource table, stopped once it runs past the call budget.
+fn call<'lua>(lua: &'lua Lua, function: &Function<'lua>, resource: &ScriptResource) -> Result<Value<'lua>> {
+    let table = resource_table(lua, resource)?;
+    Ok(with_budget(lua, || function.call::<_, Value>(table))?)
+}
+
+fn with_budget<T>(lua: &Lua, f: impl FnOnce() -> mlua::Result<T>) -> mlua::Result<T> {
+    if let Some(mut deadline) = lua.app_data_mut::<Deadline>() {
+        deadline.0 = Some(Instant::now() + CALL_BUDGET);
+    }
+    let result = f();
+    if let Some(mut deadline) = lua.app_data_mut::<Deadline>() {
+        deadline.0 = None;
+    }
+    result
+}
+
+fn applies(kinds: &[String], kind: &str) -> bool {
+    kinds.is_empty() || kinds.iter().any(|k| k == kind)
+}
+
+fn kinds_of(spec: &Table) -> mlua::Result<Vec<String>> {
+    Ok(spec.get::<_, Option<Vec<String>>>("kinds")?.unwrap_or_default())
+}
+
+fn register_entry(lua: &Lua, registry: &Mutex<Registry>, spec: Table, kind: EntryKind) -> mlua::Result<()> {
+    let name: String = spec.get("name")?;
+    let function_field = if kind == EntryKind::Action { "run" } else { "render" };
+    let entry = ScriptEntry {
+        kind,
+        key: spec.get("key")?,
+        description: spec.get::<_, Option<String>>("description")?.unwrap_or_else(|| format!("Script: {name}")),
+        kinds: kinds_of(&spec)?,
+        function: lua.create_registry_value(spec.get::<_, Function>(function_field)?)?,
+        name,
+    };
+    let mut registry = registry.lock().unwrap_or_else(|e| e.into_inner());
+    if registry.entries.iter().any(|e| e.name == entry.name) {
+        return Err(mlua::Error::RuntimeError(format!("`{}` is already registered", entry.name)));
+    }
+    registry.entries.push(entry);
+    Ok(())
+}
+
+fn resource_table<'lua>(lua: &'lua Lua, resource: &ScriptResource) -> mlua::Result<Table<'lua>> {
+    let table = lua.create_table()?;
+    table.set("kind", resource.kind.as_str())?;
+    table.set("name", resource.name.as_str())?;
+    table.set("namespace", resource.namespace.as_str())?;
+    table.set("context", resource.context.as_str())?;
+    let cells = lua.create_table()?;
+    for (header, value) in &resource.cells {
+        cells.set(header.as_str(), value.as_str())?;
+    }
+    table.set("cells", cells)?;
+    Ok(table)
+}
+
+fn pane_text(lua: &Lua, value: Value) -> Result<String> {
+    match value {
+        Value::Nil => Ok(String::new()),
+        Value::Table(lines) => {
+            let lines: Vec<String> = lines.sequence_values::<String>().collect::<mlua::Result<_>>()?;
+            Ok(lines.join("\n"))
+        }
+        other => match lua.coerce_string(other)? {
+            Some(text) => Ok(text.to_string_lossy().into_owned()),
+            None => bail!("a pane must render a string or a list of lines"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pod() -> ScriptResource {
+        ScriptResource {
+            kind: "pods".into(),
+            name: "api-0".into(),
+            namespace: "web".into(),
+            context: "kind-dev".into(),
+            cells: BTreeMap::from([("STATUS".into(), "Running".into())]),
+        }
+    }
+
+    fn host(source: &str) -> ScriptHost {
+        let mut host = ScriptHost::new();
+        host.load_source("test.lua", source).unwrap();
+        host
+    }
+
+    #[test]
+    fn columns_see_the_resource_and_its_cells() {
+        let host = host(
+            r#"
+            kubetile.column { header = "WHERE", kinds = { "pods" }, value = function(r)
+                return r.namespace .. "/" .. r.name .. " " .. r.cells.STATUS
+            end }
+            kubetile.column { header = "API", value = function() return kubetile.api_version end }
+            "#,
+        );
+        assert_eq!(host.column_headers("pods"), ["WHERE", "API"]);
+        assert_eq!(host.column_headers("services"), ["API"]);
+        let values: Vec<String> = host.column_values(&pod()).into_iter().map(Result::unwrap).collect();
+        assert_eq!(values, ["web/api-0 Running", "1"]);
+    }
+
+    #[test]
+    fn actions_and_panes_are_bound_and_run() {
+        let host = host(
+            r#"
+            kubetile.action { name = "touch", key = "ctrl+alt+t", kinds = { "pods" }, run = function(r)
+                return "touched " .. r.name
+            end }
+            kubetile.pane { name = "summary", key = "ctrl+alt+s", description = "Summary", render = function(r)
+                return { "name: " .. r.name, "context: " .. r.context }
+            end }
+            "#,
+        );
+        let bindings = host.bindings();
+        assert_eq!(bindings[0].description, "Script: touch");
+        assert_eq!(bindings[1].key, "ctrl+alt+s");
+        assert!(host.applies_to("touch", "pods"));
+        assert!(!host.applies_to("touch", "services"));
+        assert_eq!(host.run("touch", &pod()).unwrap(), ScriptOutput::Message(Some("touched api-0".into())));
+        assert_eq!(host.run("summary", &pod()).unwrap(), ScriptOutput::Pane("name: api-0\ncontext: kind-dev".into()));
+    }
+
+    #[test]
+    fn failing_and_runaway_scripts_report_errors() {
+        let host = host(
+            r#"
+            kubetile.column { header = "BOOM", value = function() error("no data") end }
+            kubetile.action { name = "spin", key = "ctrl+alt+x", run = function() while true do end end }
+            "#,
+        );
+        let err = host.column_values(&pod()).remove(0).unwrap_err();
+        assert!(err.to_string().contains("no data"), "{err}");
+        let err = host.run("spin", &pod()).unwrap_err();
+        assert!(err.to_string().contains("ran longer than"), "{err}");
+
+        let mut host = host;
+        assert!(host.load_source("dup.lua", r#"kubetile.action { name = "spin", key = "f9", run = print }"#).is_err());
+    }
+
+    #[test]
+    fn load_dir_reads_lua_files_in_order_and_keeps_going_past_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.lua"), r#"kubetile.column { header = "B", value = tostring }"#).unwrap();
+        std::fs::write(dir.path().join("a.lua"), r#"kubetile.column { header = "A", value = tostring }"#).unwrap();
+        std::fs::write(dir.path().join("broken.lua"), "this is not lua").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let host = ScriptHost::load_dir(dir.path());
+        assert_eq!(host.column_headers("pods"), ["A", "B"]);
+        assert_eq!(host.errors().len(), 1);
+        assert!(host.errors()[0].contains("broken.lua"));
+    }
+}
@@ -0,0 +1,166 @@
+use futures::future::join_all;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+    ConfigMap, LimitRange, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+    Service,
+};
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::actions::ResourceKind;
+use crate::listing::kind_from_name;
+
+/// An object found by the global search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub kind: ResourceKind,
+    /// `None` for cluster-scoped kinds.
+    pub namespace: Option<String>,
+    pub name: String,
+}
+
+/// A global search as typed: `api` searches every kind, `deploy,svc/api` only Deployments and
+/// Services.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// `None` searches every kind.
+    pub kinds: Option<Vec<ResourceKind>>,
+    /// Kind names before the `/` that name no built-in kind.
+    pub unknown_kinds: Vec<String>,
+    pub name: String,
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> Self {
+        let Some((kinds, name)) = input.split_once('/') else {
+            return Self { kinds: None, unknown_kinds: Vec::new(), name: input.trim().to_string() };
+        };
+        let mut known = Vec::new();
+        let mut unknown_kinds = Vec::new();
+        for kind in kinds.split(',').map(str::trim).filter(|k| !k.is_empty()) {
+            match kind_from_name(kind) {
+                Some(k) if !known.contains(&k) => known.push(k),
+                Some(_) => {}
+                None => unknown_kinds.push(kind.to_string()),
+            }
+        }
+        let kinds = (!known.is_empty() || !unknown_kinds.is_empty()).then_some(known);
+        Self { kinds, unknown_kinds, name: name.trim().to_string() }
+    }
+
+    pub fn includes(&self, kind: &ResourceKind) -> bool {
+        self.kinds.as_ref().is_none_or(|kinds| kinds.contains(kind))
+    }
+}
+
+/// Lists the names of every `kinds` object in `namespace`, or across the cluster when it is
+/// `None`; cluster-scoped kinds are only listed cluster-wide. Kinds are listed concurrently and
+/// metadata only. Kinds that could not be listed, e.g. for lack of RBAC, are returned with
+/// their error instead of failing the whole search.
+pub async fn list_names(
+    client: Client,
+    kinds: &[ResourceKind],
+    namespace: Option<&str>,
+) -> (Vec<SearchHit>, Vec<(ResourceKind, String)>) {
+    let listings = kinds.iter().map(|kind| {
+        let client = client.clone();
+        async move { (kind.clone(), list_kind_names(client, kind, namespace).await) }
+    });
+
+    let mut hits = Vec::new();
+    let mut failed = Vec::new();
+    for (kind, result) in join_all(listings).await {
+        match result {
+            Ok(names) => hits.extend(names.into_iter().map(|(namespace, name)| SearchHit {
+                kind: kind.clone(),
+                namespace,
+                name,
+            })),
+            Err(e) => failed.push((kind, e.to_string())),
+        }
+    }
+    (hits, failed)
+}
+
+async fn list_kind_names(
+    client: Client,
+    kind: &ResourceKind,
+    namespace: Option<&str>,
+) -> kube::Result<Vec<(Option<String>, String)>> {
+    macro_rules! namespaced {
+        ($k8s_type:ty) => {{
+            let api: Api<$k8s_type> = match namespace {
+                Some(ns) => Api::namespaced(client, ns),
+                None => Api::all(client),
+            };
+            api.list_metadata(&ListParams::default())
+                .await?
+                .items
+                .into_iter()
+                .map(|item| (item.metadata.namespace, item.metadata.name.unwrap_or_default()))
+                .collect()
+        }};
+    }
+    macro_rules! cluster {
+        ($k8s_type:ty) => {{
+            if namespace.is_some() {
+                return Ok(Vec::new());
+            }
+            Api::<$k8s_type>::all(client)
+                .list_metadata(&ListParams::default())
+                .await?
+                .items
+                .into_iter()
+                .map(|item| (None, item.metadata.name.unwrap_or_default()))
+                .collect()
+        }};
+    }
+
+    Ok(match kind {
+        ResourceKind::Pods => namespaced!(Pod),
+        ResourceKind::Deployments => namespaced!(Deployment),
+        ResourceKind::Services => namespaced!(Service),
+        ResourceKind::StatefulSets => namespaced!(StatefulSet),
+        ResourceKind::DaemonSets => namespaced!(DaemonSet),
+        ResourceKind::Jobs => namespaced!(Job),
+        ResourceKind::CronJobs => namespaced!(CronJob),
+        ResourceKind::ConfigMaps => namespaced!(ConfigMap),
+        ResourceKind::Secrets => namespaced!(Secret),
+        ResourceKind::Ingresses => namespaced!(Ingress),
+        ResourceKind::PersistentVolumeClaims => namespaced!(PersistentVolumeClaim),
+        ResourceKind::NetworkPolicies => namespaced!(NetworkPolicy),
+        ResourceKind::ResourceQuotas => namespaced!(ResourceQuota),
+        ResourceKind::LimitRanges => namespaced!(LimitRange),
+        ResourceKind::PodDisruptionBudgets => namespaced!(PodDisruptionBudget),
+        ResourceKind::Nodes => cluster!(Node),
+        ResourceKind::Namespaces => cluster!(Namespace),
+        ResourceKind::PersistentVolumes => cluster!(PersistentVolume),
+        ResourceKind::Custom(_) => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_before_the_slash_narrow_the_search() {
+        let query = SearchQuery::parse("api");
+        assert_eq!(query, SearchQuery { kinds: None, unknown_kinds: Vec::new(), name: "api".into() });
+        assert!(query.includes(&ResourceKind::Secrets));
+
+        let query = SearchQuery::parse("deploy, svc,deployment/ api");
+        assert_eq!(query.kinds, Some(vec![ResourceKind::Deployments, ResourceKind::Services]));
+        assert_eq!(query.name, "api");
+        assert!(!query.includes(&ResourceKind::Pods));
+
+        let query = SearchQuery::parse("widgets/api");
+        assert_eq!(query.kinds, Some(Vec::new()));
+        assert_eq!(query.unknown_kinds, ["widgets"]);
+
+        assert_eq!(SearchQuery::parse("/api").kinds, None);
+    }
+}
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use jiff::tz::TimeZone;
+use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::discovery::v1::EndpointSlice;
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::resource::{DetailSection, ResourceSummary};
+use crate::resources::ServiceSummary;
+
+const SERVICE_NAME_LABEL: &str = "kubernetes.io/service-name";
+
+/// Reads a Service with its EndpointSlices and selected pods, returning its detail sections plus
+/// an "Endpoints" section.
+pub async fn service_detail_sections(
+    client: Client,
+    name: &str,
+    namespace: &str,
+    tz: &TimeZone,
+) -> Result<Vec<DetailSection>> {
+    let service = Api::<Service>::namespaced(client.clone(), namespace).get(name).await?;
+    let slices = Api::<EndpointSlice>::namespaced(client.clone(), namespace)
+        .list(&ListParams::default().labels(&format!("{SERVICE_NAME_LABEL}={name}")))
+        .await?
+        .items;
+    let pods = match selector_string(&service) {
+        Some(selector) => {
+            Api::<Pod>::namespaced(client, namespace).list(&ListParams::default().labels(&selector)).await?.items
+        }
+        None => Vec::new(),
+    };
+
+    let mut sections = ServiceSummary::from(&service).detail_sections(tz);
+    sections.push(endpoints_section(&service, &slices, &pods));
+    Ok(sections)
+}
+
+/// Lists ready addresses first, then not-ready ones, each with the pod behind it. When nothing is
+/// ready, a "Why" field explains what the selector does or doesn't match.
+pub fn endpoints_section(service: &Service, slices: &[EndpointSlice], pods: &[Pod]) -> DetailSection {
+    let mut ready = Vec::new();
+    let mut not_ready = Vec::new();
+    for endpoint in slices.iter().flat_map(|s| &s.endpoints) {
+        let conditions = endpoint.conditions.as_ref();
+        let is_ready = conditions.and_then(|c| c.ready).unwrap_or(false);
+        let terminating = conditions.and_then(|c| c.terminating).unwrap_or(false);
+
+        let mut value = match (is_ready, terminating) {
+            (true, _) => "Ready".to_string(),
+            (false, true) => "Not ready (terminating)".to_string(),
+            (false, false) => "Not ready".to_string(),
+        };
+        if let Some(target) = &endpoint.target_ref {
+            let kind = target.kind.as_deref().unwrap_or("Pod");
+            value.push_str(&format!(", {kind} {}", target.name.as_deref().unwrap_or("?")));
+        }
+        if let Some(node) = &endpoint.node_name {
+            value.push_str(&format!(" on {node}"));
+        }
+        for address in &endpoint.addresses {
+            let entry = (address.clone(), value.clone());
+            if is_ready {
+                ready.push(entry);
+            } else {
+                not_ready.push(entry);
+            }
+        }
+    }
+
+    let mut ports: Vec<String> = slices
+        .iter()
+        .flat_map(|s| s.ports.iter().flatten())
+        .map(|p| {
+            let port = p.port.map(|n| n.to_string()).unwrap_or_else(|| "?".into());
+            let protocol = p.protocol.as_deref().unwrap_or("TCP");
+            match p.name.as_deref().filter(|n| !n.is_empty()) {
+                Some(name) => format!("{name} {port}/{protocol}"),
+                None => format!("{port}/{protocol}"),
+            }
+        })
+        .collect();
+    ports.sort();
+    ports.dedup();
+
+    let mut fields = vec![("Ready".to_string(), format!("{} of {}", ready.len(), ready.len() + not_ready.len()))];
+    if !ports.is_empty() {
+        fields.push(("Ports".into(), ports.join(", ")));
+    }
+    if ready.is_empty() {
+        fields.push(("Why".into(), no_endpoints_reason(service, !not_ready.is_empty(), pods)));
+    }
+    fields.extend(ready);
+    fields.extend(not_ready);
+    DetailSection { title: "Endpoints".into(), fields }
+}
+
+fn no_endpoints_reason(service: &Service, has_not_ready: bool, pods: &[Pod]) -> String {
+    let spec = service.spec.as_ref();
+    if spec.and_then(|s| s.type_.as_deref()) == Some("ExternalName") {
+        return "ExternalName Services have no endpoints".into();
+    }
+    let Some(selector) = selector_string(service) else {
+        return "Service has no selector; endpoints must be managed manually".into();
+    };
+    let live: Vec<&Pod> = pods.iter().filter(|p| p.metadata.deletion_timestamp.is_none()).collect();
+    if live.is_empty() {
+        return format!("No pods match selector {selector}");
+    }
+    let running = live.iter().filter(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running")).count();
+    match (running, has_not_ready) {
+        (0, _) => format!("{} pod(s) match {selector} but none are running", live.len()),
+        (_, true) => format!("{} pod(s) match {selector} but none pass their readiness probe", live.len()),
+        (_, false) => format!("{} pod(s) match {selector} but no endpoints are published yet", live.len()),
+    }
+}
+
+fn selector_string(service: &Service) -> Option<String> {
+    let selector = service.spec.as_ref()?.selector.as_ref().filter(|s| !s.is_empty())?;
+    Some(selector.iter().map(|(k, v)| format!("{k}={v}")).collect::<Vec<_>>().join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(selector: serde_json::Value) -> Service {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "api", "namespace": "web" },
+            "spec": { "selector": selector, "ports": [ { "name": "http", "port": 80, "targetPort": 8080 } ] }
+        }))
+        .unwrap()
+    }
+
+    fn slice(endpoints: serde_json::Value) -> EndpointSlice {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "discovery.k8s.io/v1",
+            "kind": "EndpointSlice",
+            "metadata": { "name": "api-abc12", "namespace": "web" },
+            "addressType": "IPv4",
+            "ports": [ { "name": "http", "port": 8080, "protocol": "TCP" } ],
+            "endpoints": endpoints
+        }))
+        .unwrap()
+    }
+
+    fn pod(name: &str, phase: &str) -> Pod {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": name, "namespace": "web", "labels": { "app": "api" } },
+            "status": { "phase": phase }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn endpoints_list_ready_addresses_before_not_ready() {
+        let slices = vec![slice(serde_json::json!([
+            { "addresses": ["10.0.0.7"], "conditions": { "ready": false, "terminating": true },
+              "targetRef": { "kind": "Pod", "name": "api-old" } },
+            { "addresses": ["10.0.0.5"], "conditions": { "ready": true }, "nodeName": "worker-1",
+              "targetRef": { "kind": "Pod", "name": "api-0" } }
+        ]))];
+        let section = endpoints_section(&service(serde_json::json!({ "app": "api" })), &slices, &[]);
+        assert_eq!(section.title, "Endpoints");
+        assert_eq!(
+            section.fields,
+            vec![
+                ("Ready".to_string(), "1 of 2".to_string()),
+                ("Ports".to_string(), "http 8080/TCP".to_string()),
+                ("10.0.0.5".to_string(), "Ready, Pod api-0 on worker-1".to_string()),
+                ("10.0.0.7".to_string(), "Not ready (terminating), Pod api-old".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn explains_why_a_service_has_no_endpoints() {
+        let why = |svc: &Service, slices: &[EndpointSlice], pods: &[Pod]| {
+            let section = endpoints_section(svc, slices, pods);
+            section.fields.into_iter().find(|(k, _)| k == "Why").map(|(_, v)| v).unwrap()
+        };
+        let selected = service(serde_json::json!({ "app": "api" }));
+
+        assert_eq!(
+            why(&service(serde_json::json!({})), &[], &[]),
+            "Service has no selector; endpoints must be managed manually"
+        );
+        assert_eq!(why(&selected, &[], &[]), "No pods match selector app=api");
+        assert_eq!(why(&selected, &[], &[pod("api-0", "Pending")]), "1 pod(s) match app=api but none are running");
+
+        let unready = vec![slice(serde_json::json!([
+            { "addresses": ["10.0.0.5"], "conditions": { "ready": false } }
+        ]))];
+        assert_eq!(
+            why(&selected, &unready, &[pod("api-0", "Running")]),
+            "1 pod(s) match app=api but none pass their readiness probe"
+        );
+    }
+}
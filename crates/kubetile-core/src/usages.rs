@@ -0,0 +1,224 @@
+use anyhow::Result;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Pod, PodSpec};
+use kube::api::ListParams;
+use kube::{Api, Client};
+
+use crate::actions::ResourceKind;
+use crate::container_memory::pod_workload;
+use crate::resource::DetailSection;
+
+/// A pod or workload that references a ConfigMap or Secret, with the places it does so,
+/// e.g. `volume config` or `env DB_HOST in api`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Usage {
+    pub kind: String,
+    pub name: String,
+    pub references: Vec<String>,
+    /// The workload a pod belongs to, e.g. `Deployment/api`; `None` for workloads and bare pods.
+    pub owner: Option<String>,
+}
+
+/// Every place `spec` references the ConfigMap or Secret `name`: volumes (projected included),
+/// `env` and `envFrom` of its containers and, for Secrets, image pull secrets.
+pub fn pod_spec_references(spec: &PodSpec, kind: &ResourceKind, name: &str) -> Vec<String> {
+    let secret = *kind == ResourceKind::Secrets;
+    let mut references = Vec::new();
+
+    for volume in spec.volumes.iter().flatten() {
+        let direct = if secret {
+            volume.secret.as_ref().and_then(|s| s.secret_name.as_deref()) == Some(name)
+        } else {
+            volume.config_map.as_ref().is_some_and(|c| c.name == name)
+        };
+        let projected = volume.projected.iter().flat_map(|p| p.sources.iter().flatten()).any(|source| {
+            if secret {
+                source.secret.as_ref().is_some_and(|s| s.name == name)
+            } else {
+                source.config_map.as_ref().is_some_and(|c| c.name == name)
+            }
+        });
+        if direct {
+            references.push(format!("volume {}", volume.name));
+        } else if projected {
+            references.push(format!("projected volume {}", volume.name));
+        }
+    }
+
+    let containers = spec.init_containers.iter().flatten().chain(&spec.containers);
+    for container in containers {
+        for env in container.env.iter().flatten() {
+            let source = env.value_from.as_ref();
+            let matches = if secret {
+                source.and_then(|s| s.secret_key_ref.as_ref()).is_some_and(|r| r.name == name)
+            } else {
+                source.and_then(|s| s.config_map_key_ref.as_ref()).is_some_and(|r| r.name == name)
+            };
+            if matches {
+                references.push(format!("env {} in {}", env.name, container.name));
+            }
+        }
+        let env_from = container.env_from.iter().flatten().any(|from| {
+            if secret {
+                from.secret_ref.as_ref().is_some_and(|r| r.name == name)
+            } else {
+                from.config_map_ref.as_ref().is_some_and(|r| r.name == name)
+            }
+        });
+        if env_from {
+            references.push(format!("envFrom in {}", container.name));
+        }
+    }
+
+    if secret && spec.image_pull_secrets.iter().flatten().any(|s| s.name == name) {
+        references.push("image pull secret".into());
+    }
+    references
+}
+
+/// Lists the pods and workloads of `namespace` that reference the ConfigMap or Secret `name`.
+pub async fn find_usages(client: Client, kind: &ResourceKind, name: &str, namespace: &str) -> Result<Vec<Usage>> {
+    let lp = ListParams::default();
+    let mut usages = Vec::new();
+
+    let mut workload = |kind_name: &str, object_name: Option<String>, spec: Option<&PodSpec>| {
+        let references = spec.map(|spec| pod_spec_references(spec, kind, name)).unwrap_or_default();
+        if !references.is_empty() {
+            usages.push(Usage {
+                kind: kind_name.into(),
+                name: object_name.unwrap_or_default(),
+                references,
+                owner: None,
+            });
+        }
+    };
+    for d in Api::<Deployment>::namespaced(client.clone(), namespace).list(&lp).await?.items {
+        workload("Deployment", d.metadata.name, d.spec.as_ref().and_then(|s| s.template.spec.as_ref()));
+    }
+    for s in Api::<StatefulSet>::namespaced(client.clone(), namespace).list(&lp).await?.items {
+        workload("StatefulSet", s.metadata.name, s.spec.as_ref().and_then(|s| s.template.spec.as_ref()));
+    }
+    for d in Api::<DaemonSet>::namespaced(client.clone(), namespace).list(&lp).await?.items {
+        workload("DaemonSet", d.metadata.name, d.spec.as_ref().and_then(|s| s.template.spec.as_ref()));
+    }
+    for c in Api::<CronJob>::namespaced(client.clone(), namespace).list(&lp).await?.items {
+        let spec = c.spec.as_ref().and_then(|s| s.job_template.spec.as_ref()).and_then(|s| s.template.spec.as_ref());
+        workload("CronJob", c.metadata.name, spec);
+    }
+    for j in Api::<Job>::namespaced(client.clone(), namespace).list(&lp).await?.items {
+        workload("Job", j.metadata.name, j.spec.as_ref().and_then(|s| s.template.spec.as_ref()));
+    }
+
+    for pod in Api::<Pod>::namespaced(client, namespace).list(&lp).await?.items {
+        let references = pod.spec.as_ref().map(|spec| pod_spec_references(spec, kind, name)).unwrap_or_default();
+        if references.is_empty() {
+            continue;
+        }
+        let owner = Some(pod_workload(&pod)).filter(|owner| !owner.starts_with("Pod/"));
+        usages.push(Usage { kind: "Pod".into(), name: pod.metadata.name.unwrap_or_default(), references, owner });
+    }
+    Ok(usages)
+}
+
+/// Workloads first, then pods, each with where it references the object.
+pub fn usage_sections(usages: &[Usage]) -> Vec<DetailSection> {
+    let (pods, workloads): (Vec<&Usage>, Vec<&Usage>) = usages.iter().partition(|u| u.kind == "Pod");
+    let workloads = workloads.iter().map(|u| (format!("{}/{}", u.kind, u.name), u.references.join(", "))).collect();
+    let pods = pods
+        .iter()
+        .map(|u| {
+            let references = u.references.join(", ");
+            let value = match &u.owner {
+                Some(owner) => format!("{references} (from {owner})"),
+                None => references,
+            };
+            (u.name.clone(), value)
+        })
+        .collect();
+    let none = || vec![("None".to_string(), "nothing references it".to_string())];
+    let section = |title: &str, fields: Vec<(String, String)>| DetailSection {
+        title: title.into(),
+        fields: if fields.is_empty() { none() } else { fields },
+    };
+    vec![section("Workloads", workloads), section("Pods", pods)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> PodSpec {
+        serde_json::from_value(serde_json::json!({
+            "imagePullSecrets": [{ "name": "registry" }],
+            "volumes": [
+                { "name": "config", "configMap": { "name": "settings" } },
+                { "name": "certs", "secret": { "secretName": "tls" } },
+                { "name": "bundle", "projected": { "sources": [
+                    { "configMap": { "name": "settings" } },
+                    { "secret": { "name": "registry" } }
+                ] } }
+            ],
+            "initContainers": [{
+                "name": "migrate",
+                "envFrom": [{ "secretRef": { "name": "registry" } }]
+            }],
+            "containers": [{
+                "name": "api",
+                "env": [
+                    { "name": "PORT", "value": "80" },
+                    { "name": "LOG_LEVEL", "valueFrom": { "configMapKeyRef": { "name": "settings", "key": "level" } } },
+                    { "name": "TOKEN", "valueFrom": { "secretKeyRef": { "name": "settings", "key": "token" } } }
+                ],
+                "envFrom": [{ "configMapRef": { "name": "settings" } }]
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn configmap_references_cover_volumes_env_and_env_from() {
+        assert_eq!(
+            pod_spec_references(&spec(), &ResourceKind::ConfigMaps, "settings"),
+            ["volume config", "projected volume bundle", "env LOG_LEVEL in api", "envFrom in api"]
+        );
+        assert!(pod_spec_references(&spec(), &ResourceKind::ConfigMaps, "tls").is_empty());
+    }
+
+    #[test]
+    fn secret_references_include_image_pull_secrets() {
+        assert_eq!(
+            pod_spec_references(&spec(), &ResourceKind::Secrets, "registry"),
+            ["projected volume bundle", "envFrom in migrate", "image pull secret"]
+        );
+        assert_eq!(pod_spec_references(&spec(), &ResourceKind::Secrets, "tls"), ["volume certs"]);
+        assert_eq!(pod_spec_references(&spec(), &ResourceKind::Secrets, "settings"), ["env TOKEN in api"]);
+    }
+
+    #[test]
+    fn sections_split_workloads_from_pods() {
+        let usage = |kind: &str, name: &str, owner: Option<&str>| Usage {
+            kind: kind.into(),
+            name: name.into(),
+            references: vec!["volume config".into()],
+            owner: owner.map(Into::into),
+        };
+        let sections = usage_sections(&[
+            usage("Deployment", "api", None),
+            usage("Pod", "api-5d8f9c7b6-x2x9k", Some("Deployment/api")),
+            usage("Pod", "debug", None),
+        ]);
+        assert_eq!(sections[0].title, "Workloads");
+        assert_eq!(sections[0].fields, [("Deployment/api".to_string(), "volume config".to_string())]);
+        assert_eq!(
+            sections[1].fields,
+            [
+                ("api-5d8f9c7b6-x2x9k".to_string(), "volume config (from Deployment/api)".to_string()),
+                ("debug".to_string(), "volume config".to_string()),
+            ]
+        );
+
+        let empty = usage_sections(&[]);
+        assert_eq!(empty[1].fields, [("None".to_string(), "nothing references it".to_string())]);
+    }
+}
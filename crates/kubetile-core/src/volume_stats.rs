@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use futures::future::join_all;
+use k8s_openapi::api::core::v1::Node;
+use kube::api::{GetParams, ListParams};
+use kube::{Api, Client};
+use serde::Deserialize;
+
+/// Filesystem usage of a mounted PVC as reported by the kubelet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolumeUsage {
+    pub capacity_bytes: u64,
+    pub used_bytes: u64,
+}
+
+impl VolumeUsage {
+    pub fn percent(&self) -> Option<u64> {
+        (self.capacity_bytes > 0).then(|| self.used_bytes * 100 / self.capacity_bytes)
+    }
+}
+
+/// PVC usage keyed by `(namespace, name)`.
+pub type VolumeUsageMap = HashMap<(String, String), VolumeUsage>;
+
+#[derive(Deserialize)]
+struct StatsSummary {
+    #[serde(default)]
+    pods: Vec<PodStats>,
+}
+
+#[derive(Deserialize)]
+struct PodStats {
+    #[serde(default)]
+    volume: Vec<VolumeStats>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VolumeStats {
+    pvc_ref: Option<PvcRef>,
+    capacity_bytes: Option<u64>,
+    used_bytes: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct PvcRef {
+    name: String,
+    namespace: String,
+}
+
+/// Extracts PVC-backed volumes from a kubelet `/stats/summary` response.
+pub fn parse_stats_summary(body: &str) -> Result<VolumeUsageMap> {
+    let summary: StatsSummary = serde_json::from_str(body)?;
+    let usage = summary
+        .pods
+        .into_iter()
+        .flat_map(|pod| pod.volume)
+        .filter_map(|volume| {
+            let pvc = volume.pvc_ref?;
+            let usage = VolumeUsage { capacity_bytes: volume.capacity_bytes?, used_bytes: volume.used_bytes? };
+            Some(((pvc.namespace, pvc.name), usage))
+        })
+        .collect();
+    Ok(usage)
+}
+
+/// Reads volume stats from every node's kubelet through the API server proxy. This needs `get` on
+/// `nodes/proxy`, which many roles lack; nodes that refuse are skipped unless all of them do.
+pub async fn fetch_pvc_usage(client: Client) -> Result<VolumeUsageMap> {
+    let nodes: Api<Node> = Api::all(client.clone());
+    let names: Vec<String> =
+        nodes.list(&ListParams::default()).await?.items.into_iter().filter_map(|n| n.metadata.name).collect();
+
+    let requests = names.iter().map(|node| {
+        let client = client.clone();
+        async move {
+            let request = kube::core::Request::new("/api/v1/nodes")
+                .get(&format!("{node}/proxy/stats/summary"), &GetParams::default())?;
+            parse_stats_summary(&client.request_text(request).await?)
+        }
+    });
+
+    let mut usage = VolumeUsageMap::new();
+    let mut first_error = None;
+    for (node, result) in names.iter().zip(join_all(requests).await) {
+        match result {
+            Ok(node_usage) => usage.extend(node_usage),
+            Err(e) => {
+                tracing::debug!("Volume stats from node {node} failed: {e}");
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    match first_error {
+        Some(e) if usage.is_empty() => bail!("{e}"),
+        _ => Ok(usage),
+    }
+}
+
+/// Formats a byte count with binary units, e.g. `1.5Gi`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["Ki", "Mi", "Gi", "Ti", "Pi"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value >= 10.0 {
+        format!("{value:.0}{}", UNITS[unit])
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_summary_keeps_only_pvc_volumes() {
+        let body = r#"{
+            "node": { "nodeName": "worker-1" },
+            "pods": [
+                {
+                    "podRef": { "name": "postgres-0", "namespace": "data" },
+                    "volume": [
+                        { "name": "kube-api-access", "capacityBytes": 100, "usedBytes": 1 },
+                        {
+                            "name": "data",
+                            "capacityBytes": 21474836480,
+                            "usedBytes": 5368709120,
+                            "pvcRef": { "name": "data-postgres-0", "namespace": "data" }
+                        }
+                    ]
+                },
+                { "podRef": { "name": "idle", "namespace": "web" } }
+            ]
+        }"#;
+        let usage = parse_stats_summary(body).unwrap();
+        assert_eq!(usage.len(), 1);
+        let pvc = usage[&("data".to_string(), "data-postgres-0".to_string())];
+        assert_eq!(pvc.percent(), Some(25));
+        assert_eq!(format_bytes(pvc.used_bytes), "5.0Gi");
+    }
+
+    #[test]
+    fn format_bytes_picks_binary_unit() {
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(1536), "1.5Ki");
+        assert_eq!(format_bytes(20 * 1024 * 1024 * 1024), "20Gi");
+        assert_eq!(VolumeUsage { capacity_bytes: 0, used_bytes: 0 }.percent(), None);
+    }
+}
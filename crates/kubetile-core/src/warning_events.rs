@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use jiff::Timestamp;
+use k8s_openapi::api::core::v1::Event;
+use kube::runtime::watcher::{self, Event as WatchEvent};
+use kube::Api;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::informer::backoff_duration;
+use crate::namespace_overview::last_seen;
+
+/// Events the tracker remembers; the least recently seen are forgotten beyond this.
+const SEEN_MAX: usize = 1000;
+
+/// A Warning event as the status bar ticker and toasts show it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarningNotice {
+    pub namespace: String,
+    /// Kind and name of the object the event is about, e.g. `Pod/api-0`.
+    pub object: String,
+    pub reason: String,
+    pub message: String,
+}
+
+impl WarningNotice {
+    pub fn describe(&self) -> String {
+        format!("{} {}: {}", self.reason, self.object, self.message)
+    }
+}
+
+/// Decides which watched events are new: an event counts once when it is first seen after the
+/// watch started, and again each time it recurs.
+#[derive(Debug)]
+pub struct WarningTracker {
+    /// Events last seen at or before this are old; it moves up as events are forgotten.
+    since: Timestamp,
+    /// Last time each event was seen, keyed by `namespace/name`.
+    seen: HashMap<String, Timestamp>,
+}
+
+impl WarningTracker {
+    pub fn new(since: Timestamp) -> Self {
+        Self { since, seen: HashMap::new() }
+    }
+
+    pub fn notice(&mut self, event: &Event) -> Option<WarningNotice> {
+        if event.type_.as_deref() != Some("Warning") {
+            return None;
+        }
+        let seen = last_seen(event)?;
+        let key = format!(
+            "{}/{}",
+            event.metadata.namespace.as_deref().unwrap_or_default(),
+            event.metadata.name.as_deref().unwrap_or_default()
+        );
+        let previous = self.seen.insert(key, seen).unwrap_or(self.since);
+        if self.seen.len() > SEEN_MAX {
+            self.forget_oldest();
+        }
+        if seen <= previous {
+            return None;
+        }
+
+        let object = &event.involved_object;
+        Some(WarningNotice {
+            namespace: object.namespace.clone().or_else(|| event.metadata.namespace.clone()).unwrap_or_default(),
+            object: format!(
+                "{}/{}",
+                object.kind.as_deref().unwrap_or_default(),
+                object.name.as_deref().unwrap_or_default()
+            ),
+            reason: event.reason.clone().unwrap_or_default(),
+            message: event.message.as_deref().unwrap_or_default().trim().to_string(),
+        })
+    }
+
+    /// Drops the least recently seen event, raising `since` so it stays old unless it recurs.
+    fn forget_oldest(&mut self) {
+        let Some((key, at)) = self.seen.iter().min_by_key(|(_, at)| **at).map(|(k, at)| (k.clone(), *at)) else {
+            return;
+        };
+        self.seen.remove(&key);
+        self.since = self.since.max(at);
+    }
+}
+
+/// Background watch on the Warning events of one namespace (or the whole cluster).
+pub struct WarningWatcher {
+    cancel: CancellationToken,
+}
+
+impl WarningWatcher {
+    /// Sends every Warning event seen through `api` after now, re-watching with backoff when the
+    /// stream fails, until dropped or `tx` closes.
+    pub fn watch(api: Api<Event>, tx: mpsc::Sender<WarningNotice>) -> Self {
+        let cancel = CancellationToken::new();
+        let cancel_clone = cancel.clone();
+        let config = watcher::Config::default().fields("type=Warning");
+
+        tokio::spawn(async move {
+            let mut tracker = WarningTracker::new(Timestamp::now());
+            let mut consecutive_failures: u32 = 0;
+            loop {
+                let stream = watcher::watcher(api.clone(), config.clone());
+                tokio::pin!(stream);
+                loop {
+                    let item = tokio::select! {
+                        _ = cancel_clone.cancelled() => return,
+                        item = stream.next() => item,
+                    };
+                    match item {
+                        Some(Ok(WatchEvent::Apply(event) | WatchEvent::InitApply(event))) => {
+                            consecutive_failures = 0;
+                            if let Some(notice) = tracker.notice(&event) {
+                                if tx.send(notice).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                        Some(Ok(_)) => consecutive_failures = 0,
+                        Some(Err(e)) => {
+                            warn!("Warning event watch failed: {e}");
+                            consecutive_failures += 1;
+                            break;
+                        }
+                        None => {
+                            consecutive_failures += 1;
+                            break;
+                        }
+                    }
+                }
+                tokio::select! {
+                    _ = tokio::time::sleep(backoff_duration(consecutive_failures)) => {}
+                    _ = cancel_clone.cancelled() => return,
+                }
+            }
+        });
+
+        Self { cancel }
+    }
+}
+
+impl Drop for WarningWatcher {
+    fn drop(&mut self) {
+        self.cancel.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(type_: &str, last_seen: &str) -> Event {
+        named_event("api-0.17a", type_, last_seen)
+    }
+
+    fn named_event(name: &str, type_: &str, last_seen: &str) -> Event {
+        serde_json::from_value(serde_json::json!({
+            "metadata": { "name": name, "namespace": "web" },
+            "involvedObject": { "kind": "Pod", "name": "api-0", "namespace": "web" },
+            "reason": "BackOff",
+            "message": "Back-off restarting failed container\n",
+            "type": type_,
+            "lastTimestamp": last_seen
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn tracker_reports_new_and_recurring_warnings_only() {
+        let mut tracker = WarningTracker::new("2024-03-01T12:00:00Z".parse().unwrap());
+        assert_eq!(tracker.notice(&event("Warning", "2024-03-01T11:59:00Z")), None, "seen before the watch");
+        assert_eq!(tracker.notice(&event("Normal", "2024-03-01T12:01:00Z")), None);
+
+        let notice = tracker.notice(&event("Warning", "2024-03-01T12:01:00Z")).unwrap();
+        assert_eq!(notice.describe(), "BackOff Pod/api-0: Back-off restarting failed container");
+        assert_eq!(notice.namespace, "web");
+
+        assert_eq!(tracker.notice(&event("Warning", "2024-03-01T12:01:00Z")), None, "same occurrence");
+        assert!(tracker.notice(&event("Warning", "2024-03-01T12:03:00Z")).is_some(), "recurred");
+    }
+
+    #[test]
+    fn tracker_forgets_the_oldest_events_beyond_its_cap() {
+        let mut tracker = WarningTracker::new("2024-03-01T12:00:00Z".parse().unwrap());
+        let start: Timestamp = "2024-03-01T12:01:00Z".parse().unwrap();
+        for i in 0..=SEEN_MAX {
+            let at = (start + jiff::SignedDuration::from_secs(i as i64)).to_string();
+            assert!(tracker.notice(&named_event(&format!("e{i}"), "Warning", &at)).is_some());
+        }
+        assert_eq!(tracker.seen.len(), SEEN_MAX);
+        assert!(!tracker.seen.contains_key("web/e0"));
+        let first = start.to_string();
+        assert_eq!(tracker.notice(&named_event("e0", "Warning", &first)), None, "forgotten but not new");
+    }
+}
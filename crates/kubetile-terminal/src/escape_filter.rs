@@ -0,0 +1,300 @@
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+const CAN: u8 = 0x18;
+const SUB: u8 = 0x1a;
+/// OSC strings are held until their terminator decides whether they pass; longer ones are
+/// treated as inline images, which are the only OSC payloads that get this big.
+const MAX_OSC: usize = 64 * 1024;
+const ITERM2_IMAGE: &[u8] = b"1337;File=";
+/// Longer CSI parameter lists are dropped; real ones are a few dozen bytes.
+const MAX_CSI: usize = 1024;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    Csi,
+    /// DCS parameters, up to the final byte that names the sequence (`q` for sixel).
+    DcsHead,
+    DcsBody,
+    DcsEscape,
+    /// First byte of an APC string; kitty graphics commands start with `G`.
+    ApcStart,
+    /// APC, PM and SOS strings.
+    Ignored,
+    IgnoredEscape,
+    Osc,
+    OscEscape,
+}
+
+/// Strips escape sequences vt100 cannot draw, such as sixel, iTerm2 and kitty images, from a
+/// program's output before it reaches the screen, counting the images it hides, and rewrites
+/// SGR color forms vt100 misreads into ones it knows. Everything else passes through
+/// unchanged; sequences split across reads are carried over.
+#[derive(Default)]
+pub struct EscapeFilter {
+    state: State,
+    image: bool,
+    buf: Vec<u8>,
+    overflowed: bool,
+}
+
+/// Output with unsupported sequences removed, and how many of them were images.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Filtered {
+    pub bytes: Vec<u8>,
+    pub images: usize,
+}
+
+impl EscapeFilter {
+    pub fn filter(&mut self, input: &[u8]) -> Filtered {
+        let mut out = Filtered { bytes: Vec::with_capacity(input.len()), images: 0 };
+        for &byte in input {
+            self.state = self.step(byte, &mut out);
+        }
+        out
+    }
+
+    fn step(&mut self, byte: u8, out: &mut Filtered) -> State {
+        match (self.state, byte) {
+            (State::Ground, ESC) => State::Escape,
+            (State::Ground, _) => {
+                out.bytes.push(byte);
+                State::Ground
+            }
+            (State::Escape, b'P') => {
+                self.image = true;
+                State::DcsHead
+            }
+            (State::Escape, b'_' | b'^' | b'X') => {
+                self.image = false;
+                if byte == b'_' {
+                    State::ApcStart
+                } else {
+                    State::Ignored
+                }
+            }
+            (State::Escape, b'[' | b']') => {
+                self.buf.clear();
+                self.overflowed = false;
+                if byte == b'[' {
+                    State::Csi
+                } else {
+                    State::Osc
+                }
+            }
+            (State::Escape, ESC) => {
+                out.bytes.push(ESC);
+                State::Escape
+            }
+            (State::Escape, _) => {
+                out.bytes.extend([ESC, byte]);
+                State::Ground
+            }
+            (_, CAN | SUB) => State::Ground,
+            (State::Csi, 0x40..=0x7e) => {
+                self.finish_csi(byte, out);
+                State::Ground
+            }
+            (State::Csi, ESC) => {
+                // Interrupted by another sequence, which vt100 also aborts the CSI for.
+                State::Escape
+            }
+            (State::Csi, _) => {
+                self.push(byte, MAX_CSI);
+                State::Csi
+            }
+            // Sixel is `q` with parameters only; with an intermediate it is some other request.
+            (State::DcsHead, 0x20..=0x2f) => {
+                self.image = false;
+                State::DcsHead
+            }
+            (State::DcsHead, 0x40..=0x7e) => {
+                self.image &= byte == b'q';
+                State::DcsBody
+            }
+            (State::DcsHead | State::DcsBody, ESC) => State::DcsEscape,
+            (State::DcsHead, _) => State::DcsHead,
+            (State::DcsBody, _) => State::DcsBody,
+            (State::DcsEscape, b'\\') => {
+                out.images += usize::from(self.image);
+                State::Ground
+            }
+            (State::DcsEscape, _) => State::DcsBody,
+            (State::ApcStart | State::Ignored, ESC) => State::IgnoredEscape,
+            (State::ApcStart, _) => {
+                self.image = byte == b'G';
+                State::Ignored
+            }
+            (State::Ignored, _) => State::Ignored,
+            (State::IgnoredEscape, b'\\') => {
+                out.images += usize::from(self.image);
+                State::Ground
+            }
+            (State::IgnoredEscape, _) => State::Ignored,
+            (State::Osc, BEL) => {
+                self.finish_osc(&[BEL], out);
+                State::Ground
+            }
+            (State::Osc, ESC) => State::OscEscape,
+            (State::Osc, _) => {
+                self.push(byte, MAX_OSC);
+                State::Osc
+            }
+            (State::OscEscape, b'\\') => {
+                self.finish_osc(&[ESC, b'\\'], out);
+                State::Ground
+            }
+            (State::OscEscape, _) => {
+                // An unterminated OSC ended by another sequence: drop it, replay the escape.
+                self.buf.clear();
+                self.state = State::Escape;
+                self.step(byte, out)
+            }
+        }
+    }
+
+    fn push(&mut self, byte: u8, max: usize) {
+        if self.buf.len() < max {
+            self.buf.push(byte);
+        } else {
+            self.overflowed = true;
+        }
+    }
+
+    fn finish_csi(&mut self, final_byte: u8, out: &mut Filtered) {
+        if self.overflowed {
+            return;
+        }
+        let params = std::mem::take(&mut self.buf);
+        let params = match final_byte {
+            b'm' if params.first().is_none_or(|b| b.is_ascii_digit() || matches!(b, b';' | b':')) => {
+                match normalize_sgr(&params) {
+                    Some(params) => params,
+                    None => return,
+                }
+            }
+            _ => params,
+        };
+        out.bytes.extend([ESC, b'[']);
+        out.bytes.extend(params);
+        out.bytes.push(final_byte);
+    }
+
+    fn finish_osc(&mut self, terminator: &[u8], out: &mut Filtered) {
+        let osc = std::mem::take(&mut self.buf);
+        if self.overflowed || osc.starts_with(ITERM2_IMAGE) {
+            out.images += 1;
+            return;
+        }
+        out.bytes.extend([ESC, b']']);
+        out.bytes.extend(osc);
+        out.bytes.extend(terminator);
+    }
+}
+
+/// Rewrites SGR parameters into the forms vt100 0.15 parses:
+/// - `38:2:<space>:r:g:b` truecolor with a color-space field becomes `38:2:r:g:b`;
+/// - styled underlines (`4:3` curly, `4:4` dotted, ...) become a plain `4`, `4:0` a `24`;
+/// - underline colors (`58;...`, `58:...`) are dropped, since vt100 reads their numbers as
+///   separate attributes such as bold.
+///
+/// Returns `None` when nothing is left, as an empty SGR would reset every attribute.
+fn normalize_sgr(params: &[u8]) -> Option<Vec<u8>> {
+    if params.is_empty() {
+        return Some(Vec::new());
+    }
+    let groups: Vec<&[u8]> = params.split(|&b| b == b';').collect();
+    let mut kept: Vec<Vec<u8>> = Vec::with_capacity(groups.len());
+    let mut i = 0;
+    while i < groups.len() {
+        let group = groups[i];
+        i += 1;
+        let parts: Vec<&[u8]> = group.split(|&b| b == b':').collect();
+        match parts.as_slice() {
+            [b"58"] => {
+                // Skips `5;n` or `2;r;g;b`.
+                i += match groups.get(i) {
+                    Some(&b"5") => 2,
+                    Some(&b"2") => 4,
+                    _ => 0,
+                };
+            }
+            [b"58", ..] => {}
+            [b"4", b"0"] => kept.push(b"24".to_vec()),
+            [b"4", _] => kept.push(b"4".to_vec()),
+            [selector @ (b"38" | b"48"), b"2", _space, r, g, b, ..] => {
+                kept.push([*selector, b"2", r, g, b].join(&b':'))
+            }
+            _ => kept.push(group.to_vec()),
+        }
+    }
+    if kept.is_empty() {
+        return None;
+    }
+    Some(kept.join(&b';'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(input: &[u8]) -> Filtered {
+        EscapeFilter::default().filter(input)
+    }
+
+    #[test]
+    fn plain_text_and_csi_pass_through() {
+        let input = b"hello \x1b[31mred\x1b[0m \x1b]0;title\x07\x1b]52;c;aGk=\x1b\\";
+        assert_eq!(filter(input), Filtered { bytes: input.to_vec(), images: 0 });
+    }
+
+    #[test]
+    fn images_are_removed_and_counted() {
+        let sixel = b"a\x1bPq#0;2;0;0;0#0~~@@vv\x1b\\b";
+        assert_eq!(filter(sixel), Filtered { bytes: b"ab".to_vec(), images: 1 });
+
+        let iterm = b"a\x1b]1337;File=inline=1:iVBORw0KGgo=\x07b";
+        assert_eq!(filter(iterm), Filtered { bytes: b"ab".to_vec(), images: 1 });
+
+        let kitty = b"a\x1b_Gf=100,a=T;iVBORw0KGgo=\x1b\\b";
+        assert_eq!(filter(kitty), Filtered { bytes: b"ab".to_vec(), images: 1 });
+    }
+
+    #[test]
+    fn other_dcs_strings_are_dropped_without_counting() {
+        assert_eq!(filter(b"a\x1bP$qm\x1b\\b"), Filtered { bytes: b"ab".to_vec(), images: 0 });
+    }
+
+    #[test]
+    fn sequences_split_across_reads_are_handled() {
+        let mut filter = EscapeFilter::default();
+        let first = filter.filter(b"a\x1b");
+        let second = filter.filter(b"Pq#0~~\x1b");
+        let third = filter.filter(b"\\b\x1b");
+        let fourth = filter.filter(b"[1m");
+        assert_eq!(first.bytes, b"a");
+        assert!(second.bytes.is_empty());
+        assert_eq!((third.bytes.as_slice(), third.images), (&b"b"[..], 1));
+        assert_eq!(fourth.bytes, b"\x1b[1m");
+    }
+
+    #[test]
+    fn sgr_forms_vt100_misreads_are_rewritten() {
+        assert_eq!(filter(b"\x1b[38:2::10:20:30mA").bytes, b"\x1b[38:2:10:20:30mA");
+        assert_eq!(filter(b"\x1b[1;48:2:0:1:2:3mA").bytes, b"\x1b[1;48:2:1:2:3mA");
+        assert_eq!(filter(b"\x1b[4:3;58;5;196;31mA").bytes, b"\x1b[4;31mA");
+        assert_eq!(filter(b"\x1b[4:0m").bytes, b"\x1b[24m");
+        assert_eq!(filter(b"\x1b[58:2::1:2:3mA").bytes, b"A", "an SGR left empty would reset");
+        assert_eq!(filter(b"\x1b[mA\x1b[>4;1m").bytes, b"\x1b[mA\x1b[>4;1m");
+    }
+
+    #[test]
+    fn oversized_osc_is_treated_as_an_image() {
+        let mut input = b"\x1b]9;".to_vec();
+        input.extend(std::iter::repeat_n(b'A', MAX_OSC + 10));
+        input.push(BEL);
+        assert_eq!(filter(&input), Filtered { bytes: Vec::new(), images: 1 });
+    }
+}
@@ -1,9 +1,13 @@
 mod context_env;
+mod escape_filter;
+mod osc52;
 mod pty;
 pub mod renderer;
 mod vt;
 
 pub use context_env::ContextEnv;
+pub use escape_filter::{EscapeFilter, Filtered};
+pub use osc52::{osc52_copy_sequence, Osc52Scanner};
 pub use pty::PtySession;
 pub use renderer::render_terminal_screen;
 pub use vt::VtParser;
@@ -0,0 +1,122 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+/// Longer OSC payloads are dropped rather than buffered; tmux caps its own at about 1 MiB.
+const MAX_PAYLOAD: usize = 1024 * 1024;
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    Osc,
+    OscEscape,
+}
+
+/// Picks OSC 52 clipboard writes out of a program's output, which vt100 itself ignores.
+/// Sequences may be split across reads, so state carries over between calls to `scan`.
+#[derive(Default)]
+pub struct Osc52Scanner {
+    state: State,
+    payload: Vec<u8>,
+    overflowed: bool,
+}
+
+impl Osc52Scanner {
+    /// Returns the text of every complete clipboard write in `bytes`. Clipboard reads (`?`) are
+    /// ignored, so a program in the pod never sees what the host has copied.
+    pub fn scan(&mut self, bytes: &[u8]) -> Vec<String> {
+        let mut copied = Vec::new();
+        for &byte in bytes {
+            self.state = match (self.state, byte) {
+                (State::Ground, ESC) => State::Escape,
+                (State::Ground, _) => State::Ground,
+                (State::Escape, b']') => {
+                    self.payload.clear();
+                    self.overflowed = false;
+                    State::Osc
+                }
+                (State::Escape, ESC) => State::Escape,
+                (State::Escape, _) => State::Ground,
+                (State::Osc, BEL) | (State::OscEscape, b'\\') => {
+                    copied.extend(self.finish());
+                    State::Ground
+                }
+                (State::Osc, ESC) => State::OscEscape,
+                (State::Osc, _) => {
+                    self.push(byte);
+                    State::Osc
+                }
+                // Any other escape ends the OSC unterminated and may start a new sequence.
+                (State::OscEscape, b']') => {
+                    self.payload.clear();
+                    self.overflowed = false;
+                    State::Osc
+                }
+                (State::OscEscape, _) => State::Ground,
+            };
+        }
+        copied
+    }
+
+    fn push(&mut self, byte: u8) {
+        if self.payload.len() < MAX_PAYLOAD {
+            self.payload.push(byte);
+        } else {
+            self.overflowed = true;
+        }
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        let payload = std::mem::take(&mut self.payload);
+        if self.overflowed {
+            return None;
+        }
+        let rest = payload.strip_prefix(b"52;")?;
+        let data = &rest[rest.iter().position(|&b| b == b';')? + 1..];
+        if data == b"?" {
+            return None;
+        }
+        let decoded = STANDARD.decode(data).ok()?;
+        Some(String::from_utf8_lossy(&decoded).into_owned())
+    }
+}
+
+/// The OSC 52 sequence that asks a terminal to put `text` on its clipboard.
+pub fn osc52_copy_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_bel_and_st_terminated_writes() {
+        let mut scanner = Osc52Scanner::default();
+        let out = scanner.scan(b"before\x1b]52;c;aGVsbG8=\x07mid\x1b]52;;d29ybGQ=\x1b\\after");
+        assert_eq!(out, vec!["hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn keeps_state_across_split_reads() {
+        let mut scanner = Osc52Scanner::default();
+        assert!(scanner.scan(b"\x1b]52;c;aGVs").is_empty());
+        assert_eq!(scanner.scan(b"bG8=\x1b"), Vec::<String>::new());
+        assert_eq!(scanner.scan(b"\\"), vec!["hello".to_string()]);
+    }
+
+    #[test]
+    fn ignores_reads_titles_and_bad_payloads() {
+        let mut scanner = Osc52Scanner::default();
+        assert!(scanner.scan(b"\x1b]52;c;?\x07\x1b]0;title\x07\x1b]52;c;!!\x07").is_empty());
+    }
+
+    #[test]
+    fn copy_sequence_round_trips() {
+        let mut scanner = Osc52Scanner::default();
+        assert_eq!(scanner.scan(osc52_copy_sequence("kubectl get pods").as_bytes()), vec!["kubectl get pods"]);
+    }
+}
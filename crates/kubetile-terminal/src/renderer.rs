@@ -12,12 +12,17 @@ fn convert_color(color: vt100::Color) -> Color {
 fn cell_style(cell: &vt100::Cell) -> Style {
     let mut style = Style::new();
 
-    let (fg, bg) = if cell.inverse() {
+    let default_colors = cell.fgcolor() == vt100::Color::Default && cell.bgcolor() == vt100::Color::Default;
+    let (fg, bg) = if cell.inverse() && !default_colors {
         (convert_color(cell.bgcolor()), convert_color(cell.fgcolor()))
     } else {
         (convert_color(cell.fgcolor()), convert_color(cell.bgcolor()))
     };
     style = style.fg(fg).bg(bg);
+    // Swapping two defaults would change nothing; the host terminal knows what they are.
+    if cell.inverse() && default_colors {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
 
     if cell.bold() {
         style = style.add_modifier(Modifier::BOLD);
@@ -170,6 +175,13 @@ mod tests {
         assert_eq!(cell.fg, Color::Reset);
     }
 
+    #[test]
+    fn inverse_with_default_colors_reverses() {
+        let parser = make_screen(24, 80, b"\x1b[7mI\x1b[0m ");
+        let buf = render_to_buf(parser.screen(), 80, 24);
+        assert!(buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
     #[test]
     fn cursor_position_renders_highlight() {
         // Write "AB" — cursor ends at (0, 2)
@@ -1,14 +1,18 @@
+use crate::escape_filter::EscapeFilter;
+
+/// vt100 behind an [`EscapeFilter`], so images are left out and every SGR color form renders.
 pub struct VtParser {
     parser: vt100::Parser,
+    filter: EscapeFilter,
 }
 
 impl VtParser {
     pub fn new(rows: u16, cols: u16) -> Self {
-        Self { parser: vt100::Parser::new(rows, cols, 0) }
+        Self { parser: vt100::Parser::new(rows, cols, 0), filter: EscapeFilter::default() }
     }
 
     pub fn process(&mut self, bytes: &[u8]) {
-        self.parser.process(bytes);
+        self.parser.process(&self.filter.filter(bytes).bytes);
     }
 
     pub fn screen(&self) -> &vt100::Screen {
@@ -40,6 +44,15 @@ mod tests {
         assert!(contents.starts_with("Hello, world!"));
     }
 
+    #[test]
+    fn iso_truecolor_and_underline_color_render_as_colors() {
+        let mut vt = VtParser::new(2, 10);
+        vt.process(b"\x1b[38:2::10:20:30;58;5;1mA");
+        let cell = vt.screen().cell(0, 0).unwrap();
+        assert_eq!(cell.fgcolor(), vt100::Color::Rgb(10, 20, 30));
+        assert!(!cell.bold(), "the underline color is not read as bold");
+    }
+
     #[test]
     fn resize_changes_screen_dimensions() {
         let mut vt = VtParser::new(24, 80);
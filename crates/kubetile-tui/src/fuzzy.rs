@@ -0,0 +1,161 @@
+//! Fuzzy matching for the filtering popups: the query's characters must appear in order, and
+//! matches at word starts and in runs score higher, so `kbsys` ranks `kube-system` first.
+
+use ratatui::prelude::{Modifier, Span, Style};
+
+use crate::theme::Theme;
+
+const SCORE_MATCH: i64 = 16;
+/// A match at the start of the text or of a word (after `-`, `_`, `.`, `/`, `:`, a space, or a
+/// lower-to-upper case change).
+const BONUS_BOUNDARY: i64 = 10;
+/// A match right after the previous one.
+const BONUS_CONSECUTIVE: i64 = 6;
+const PENALTY_GAP_START: i64 = 3;
+const PENALTY_GAP_EXTENSION: i64 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// Char indices of `text` that matched, ascending.
+    pub positions: Vec<usize>,
+}
+
+/// Matches `query` against `text`, ignoring case. An empty query matches everything with
+/// score 0; `None` means some query character is missing or out of order.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+    if query.len() > lower.len() {
+        return None;
+    }
+    let bonus: Vec<i64> = (0..chars.len()).map(|j| boundary_bonus(&chars, j)).collect();
+
+    // best[i][j]: score of the best alignment of query[..=i] that puts query[i] on text[j].
+    let mut best = vec![vec![None::<i64>; lower.len()]; query.len()];
+    let mut from = vec![vec![0usize; lower.len()]; query.len()];
+    for (i, &qc) in query.iter().enumerate() {
+        for j in i..lower.len() {
+            if lower[j] != qc {
+                continue;
+            }
+            let here = SCORE_MATCH + bonus[j];
+            if i == 0 {
+                best[i][j] = Some(here);
+                continue;
+            }
+            for k in (i - 1)..j {
+                let Some(prev) = best[i - 1][k] else { continue };
+                let step = if k + 1 == j {
+                    BONUS_CONSECUTIVE
+                } else {
+                    -(PENALTY_GAP_START + PENALTY_GAP_EXTENSION * (j - k - 2) as i64)
+                };
+                let score = prev + here + step;
+                if best[i][j].is_none_or(|b| score > b) {
+                    best[i][j] = Some(score);
+                    from[i][j] = k;
+                }
+            }
+        }
+    }
+
+    let last = query.len() - 1;
+    // Ties go to the earliest end, which keeps matches to the left.
+    let (mut j, score) = best[last].iter().enumerate().filter_map(|(j, s)| s.map(|s| (j, s))).fold(
+        None,
+        |acc: Option<(usize, i64)>, (j, s)| match acc {
+            Some((_, b)) if b >= s => acc,
+            _ => Some((j, s)),
+        },
+    )?;
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = from[i][j];
+        }
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+fn boundary_bonus(chars: &[char], j: usize) -> i64 {
+    let Some(&prev) = j.checked_sub(1).and_then(|p| chars.get(p)) else { return BONUS_BOUNDARY };
+    let separator = matches!(prev, '-' | '_' | '.' | '/' | ':' | ' ');
+    let camel = prev.is_lowercase() && chars[j].is_uppercase();
+    if separator || camel {
+        BONUS_BOUNDARY
+    } else {
+        0
+    }
+}
+
+/// Items whose key matches `query`, best match first; equal scores keep their input order.
+pub fn fuzzy_filter<T>(query: &str, items: impl IntoIterator<Item = T>, key: impl Fn(&T) -> &str) -> Vec<T> {
+    let mut scored: Vec<(i64, T)> =
+        items.into_iter().filter_map(|item| fuzzy_match(query, key(&item)).map(|m| (m.score, item))).collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+/// `text` split into spans with the characters matching `query` underlined in the accent color.
+pub fn highlight(text: &str, query: &str, base: Style, theme: &Theme) -> Vec<Span<'static>> {
+    let highlight = base.fg(theme.accent).add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let positions = fuzzy_match(query, text).map(|m| m.positions).unwrap_or_default();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (i, c) in text.chars().enumerate() {
+        let matched = positions.binary_search(&i).is_ok();
+        if matched != run_matched && !run.is_empty() {
+            spans.push(Span::styled(std::mem::take(&mut run), if run_matched { highlight } else { base }));
+        }
+        run_matched = matched;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(Span::styled(run, if run_matched { highlight } else { base }));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_starts_beat_scattered_letters() {
+        let m = fuzzy_match("kbsys", "kube-system").unwrap();
+        assert_eq!(m.positions, [0, 2, 5, 6, 7]);
+        assert_eq!(fuzzy_match("KBSYS", "kube-system"), Some(m));
+        assert_eq!(fuzzy_match("sk", "kube-system"), None);
+
+        let ranked = fuzzy_filter("kbsys", ["kube-public", "backbone-sys", "kube-system"], |s| s);
+        assert_eq!(ranked, ["kube-system", "backbone-sys"]);
+    }
+
+    #[test]
+    fn runs_and_camel_case_humps_score_higher() {
+        let run = fuzzy_match("pod", "pods").unwrap().score;
+        let spread = fuzzy_match("pod", "persistentvolumeclaims-old").map_or(i64::MIN, |m| m.score);
+        assert!(run > spread);
+        assert_eq!(fuzzy_match("pdb", "PodDisruptionBudgets").unwrap().positions, [0, 3, 13]);
+    }
+
+    #[test]
+    fn empty_query_keeps_everything_in_order() {
+        assert_eq!(fuzzy_filter("", ["b", "a"], |s| s), ["b", "a"]);
+    }
+
+    #[test]
+    fn highlight_splits_matched_runs() {
+        let base = Style::default();
+        let spans = highlight("kube-system", "kbsys", base, &Theme::default());
+        let parts: Vec<(&str, bool)> = spans.iter().map(|s| (s.content.as_ref(), s.style != base)).collect();
+        assert_eq!(parts, [("k", true), ("u", false), ("b", true), ("e-", false), ("sys", true), ("tem", false)]);
+    }
+}
@@ -4,28 +4,59 @@ use ratatui::prelude::*;
 
 use crate::pane::{Pane, PaneId, PaneTree, ResourceKind};
 use crate::theme::Theme;
+pub use crate::widgets::apply_dialog::ApplyDialogView;
+use crate::widgets::apply_dialog::ApplyDialogWidget;
+use crate::widgets::can_i_dialog::CanIDialogWidget;
+pub use crate::widgets::can_i_dialog::{CanIDialogView, CanIStatusView};
 use crate::widgets::confirm_dialog::ConfirmDialogWidget;
-use crate::widgets::context_selector::ContextSelectorWidget;
+pub use crate::widgets::container_picker::ContainerPickerView;
+use crate::widgets::container_picker::ContainerPickerWidget;
+use crate::widgets::context_selector::{ContextHealth, ContextSelectorWidget};
+pub use crate::widgets::data_key_picker::DataKeyPickerView;
+use crate::widgets::data_key_picker::DataKeyPickerWidget;
+pub use crate::widgets::exec_sessions::ExecSessionsView;
+use crate::widgets::exec_sessions::ExecSessionsWidget;
+use crate::widgets::global_search::GlobalSearchWidget;
+pub use crate::widgets::global_search::{GlobalSearchView, SearchHitView};
 use crate::widgets::namespace_selector::NamespaceSelectorWidget;
+pub use crate::widgets::notification_center::NotificationCenterView;
+use crate::widgets::notification_center::NotificationCenterWidget;
+use crate::widgets::offline_banner::OfflineBannerWidget;
+pub use crate::widgets::offline_banner::{OfflineBannerView, OfflineStatusView};
 pub use crate::widgets::pane_help::PaneHelpView;
 use crate::widgets::pane_help::PaneHelpWidget;
 use crate::widgets::port_forward_dialog::PortForwardDialogWidget;
 use crate::widgets::query_dialog::QueryDialogWidget;
+pub use crate::widgets::related_picker::RelatedPickerView;
+use crate::widgets::related_picker::RelatedPickerWidget;
 use crate::widgets::resource_switcher::ResourceSwitcherWidget;
+pub use crate::widgets::rollout_panel::RolloutPanelView;
+use crate::widgets::rollout_panel::RolloutPanelWidget;
+pub use crate::widgets::set_image_dialog::SetImageDialogView;
+use crate::widgets::set_image_dialog::SetImageDialogWidget;
 use crate::widgets::status_bar::StatusBarWidget;
+pub use crate::widgets::status_bar::{ConnectionHealthView, MemoryBudgetView, WarningTickerView};
 use crate::widgets::tab_bar::TabBarWidget;
 use crate::widgets::toast::{ToastMessage, ToastWidget};
+pub use crate::widgets::undo_list::UndoListView;
+use crate::widgets::undo_list::UndoListWidget;
+pub use crate::widgets::welcome::WelcomeView;
+use crate::widgets::welcome::WelcomeWidget;
 
 pub struct NamespaceSelectorView<'a> {
     pub namespaces: &'a [String],
     pub filter: &'a str,
     pub selected: usize,
+    pub marked: &'a [String],
+    pub favorites: &'a [String],
+    pub recent: &'a [String],
 }
 
 pub struct ContextSelectorView<'a> {
     pub contexts: &'a [String],
     pub filter: &'a str,
     pub selected: usize,
+    pub health: &'a HashMap<String, ContextHealth>,
 }
 
 pub struct ResourceSwitcherView<'a> {
@@ -36,6 +67,12 @@ pub struct ResourceSwitcherView<'a> {
 
 pub struct ConfirmDialogView<'a> {
     pub message: &'a str,
+    pub type_to_confirm: Option<&'a str>,
+    pub input: &'a str,
+    /// Whether Enter confirms rather than cancels a y/n prompt.
+    pub confirm_focused: bool,
+    /// Seconds left before the prompt cancels itself.
+    pub countdown: Option<u64>,
 }
 
 #[derive(Clone, Copy)]
@@ -49,7 +86,9 @@ pub enum QueryDialogFieldView {
     Database,
     User,
     Password,
+    AuthDatabase,
     Port,
+    Engine,
 }
 
 pub struct PortForwardDialogView<'a> {
@@ -63,9 +102,12 @@ pub struct PortForwardDialogView<'a> {
 pub struct QueryDialogView<'a> {
     pub pod: &'a str,
     pub namespace: &'a str,
+    pub engine: &'a str,
     pub database: &'a str,
     pub user: &'a str,
     pub password: &'a str,
+    /// Database the user authenticates against; `None` for engines without one.
+    pub auth_database: Option<&'a str>,
     pub port: &'a str,
     pub active_field: QueryDialogFieldView,
 }
@@ -73,13 +115,27 @@ pub struct QueryDialogView<'a> {
 pub struct RenderContext<'a> {
     pub cluster_name: Option<&'a str>,
     pub namespace: Option<&'a str>,
+    pub read_only: bool,
+    pub offline: Option<OfflineBannerView<'a>>,
     pub namespace_selector: Option<NamespaceSelectorView<'a>>,
     pub context_selector: Option<ContextSelectorView<'a>>,
     pub resource_switcher: Option<ResourceSwitcherView<'a>>,
     pub confirm_dialog: Option<ConfirmDialogView<'a>>,
     pub port_forward_dialog: Option<PortForwardDialogView<'a>>,
     pub query_dialog: Option<QueryDialogView<'a>>,
+    pub can_i_dialog: Option<CanIDialogView<'a>>,
+    pub apply_dialog: Option<ApplyDialogView<'a>>,
+    pub set_image_dialog: Option<SetImageDialogView<'a>>,
+    pub container_picker: Option<ContainerPickerView<'a>>,
+    pub related_picker: Option<RelatedPickerView<'a>>,
+    pub data_key_picker: Option<DataKeyPickerView<'a>>,
+    pub undo_list: Option<UndoListView<'a>>,
+    pub exec_sessions: Option<ExecSessionsView<'a>>,
+    pub global_search: Option<GlobalSearchView<'a>>,
+    pub rollouts: Option<RolloutPanelView<'a>>,
     pub pane_help: Option<PaneHelpView<'a>>,
+    pub notification_center: Option<NotificationCenterView<'a>>,
+    pub welcome: Option<WelcomeView<'a>>,
     pub toasts: &'a [ToastMessage],
     pub pane_tree: &'a PaneTree,
     pub focused_pane: Option<PaneId>,
@@ -95,18 +151,47 @@ pub struct RenderContext<'a> {
     pub close_pane_key: Option<&'a str>,
     pub new_tab_key: Option<&'a str>,
     pub quit_key: Option<&'a str>,
+    pub warning_ticker: Option<WarningTickerView<'a>>,
+    pub connection_health: Option<ConnectionHealthView>,
+    pub memory_budget: Option<MemoryBudgetView>,
     pub theme: &'a Theme,
 }
 
-pub fn render_root(frame: &mut Frame, ctx: &RenderContext) {
-    let chunks = Layout::default()
+/// Tab bar, offline banner (empty unless `offline`), pane body and status bar of a terminal `area`.
+fn root_chunks(area: Rect, offline: bool) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)])
-        .split(frame.area());
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(u16::from(offline)),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(area)
+}
+
+/// Where the panes of a terminal `area` are drawn, below the tab bar and the offline banner.
+pub fn body_area(area: Rect, offline: bool) -> Rect {
+    root_chunks(area, offline)[2]
+}
+
+/// Area of each pane drawn in `body`: the fullscreen pane alone, otherwise every pane of `tree`.
+pub fn pane_areas(body: Rect, tree: &PaneTree, fullscreen_pane: Option<PaneId>) -> Vec<(PaneId, Rect)> {
+    match fullscreen_pane {
+        Some(id) => vec![(id, body)],
+        None => tree.layout(body),
+    }
+}
+
+pub fn render_root(frame: &mut Frame, ctx: &RenderContext) {
+    let chunks = root_chunks(frame.area(), ctx.offline.is_some());
 
     render_tab_bar(frame, chunks[0], ctx);
-    render_body(frame, chunks[1], ctx);
-    render_status_bar(frame, chunks[2], ctx);
+    if let Some(ref offline) = ctx.offline {
+        OfflineBannerWidget { view: offline, theme: ctx.theme }.render(frame, chunks[1]);
+    }
+    render_body(frame, chunks[2], ctx);
+    render_status_bar(frame, chunks[3], ctx);
 }
 
 fn render_tab_bar(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
@@ -115,33 +200,38 @@ fn render_tab_bar(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
 }
 
 fn render_body(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
-    if let Some(fs_id) = ctx.fullscreen_pane {
-        if let Some(pane) = ctx.panes.get(&fs_id) {
-            pane.render(frame, area, true, ctx.theme);
-        }
-    } else {
-        let pane_rects = ctx.pane_tree.layout(area);
-        for (pane_id, pane_area) in &pane_rects {
-            if let Some(pane) = ctx.panes.get(pane_id) {
-                let focused = ctx.focused_pane == Some(*pane_id);
-                pane.render(frame, *pane_area, focused, ctx.theme);
-            }
+    for (pane_id, pane_area) in pane_areas(area, ctx.pane_tree, ctx.fullscreen_pane) {
+        if let Some(pane) = ctx.panes.get(&pane_id) {
+            let focused = ctx.fullscreen_pane.is_some() || ctx.focused_pane == Some(pane_id);
+            pane.render(frame, pane_area, focused, ctx.theme);
         }
     }
 
+    if let Some(ref rp) = ctx.rollouts {
+        RolloutPanelWidget { view: rp, theme: ctx.theme }.render(frame, area);
+    }
+
     if let Some(ref ns) = ctx.namespace_selector {
         let widget = NamespaceSelectorWidget {
             namespaces: ns.namespaces,
             filter: ns.filter,
             selected: ns.selected,
+            marked: ns.marked,
+            favorites: ns.favorites,
+            recent: ns.recent,
             theme: ctx.theme,
         };
         widget.render(frame, area);
     }
 
     if let Some(ref cs) = ctx.context_selector {
-        let widget =
-            ContextSelectorWidget { contexts: cs.contexts, filter: cs.filter, selected: cs.selected, theme: ctx.theme };
+        let widget = ContextSelectorWidget {
+            contexts: cs.contexts,
+            filter: cs.filter,
+            selected: cs.selected,
+            health: cs.health,
+            theme: ctx.theme,
+        };
         widget.render(frame, area);
     }
 
@@ -152,7 +242,14 @@ fn render_body(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
     }
 
     if let Some(ref cd) = ctx.confirm_dialog {
-        let widget = ConfirmDialogWidget { message: cd.message, theme: ctx.theme };
+        let widget = ConfirmDialogWidget {
+            message: cd.message,
+            type_to_confirm: cd.type_to_confirm,
+            input: cd.input,
+            confirm_focused: cd.confirm_focused,
+            countdown: cd.countdown,
+            theme: ctx.theme,
+        };
         widget.render(frame, area);
     }
 
@@ -172,9 +269,11 @@ fn render_body(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
         let widget = QueryDialogWidget {
             pod: qd.pod,
             namespace: qd.namespace,
+            engine: qd.engine,
             database: qd.database,
             user: qd.user,
             password: qd.password,
+            auth_database: qd.auth_database,
             port: qd.port,
             active_field: qd.active_field,
             theme: ctx.theme,
@@ -182,11 +281,62 @@ fn render_body(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
         widget.render(frame, area);
     }
 
+    if let Some(ref ci) = ctx.can_i_dialog {
+        let widget = CanIDialogWidget {
+            input: ci.input,
+            namespace: ci.namespace,
+            query: ci.query,
+            status: ci.status,
+            theme: ctx.theme,
+        };
+        widget.render(frame, area);
+    }
+
+    if let Some(ref ad) = ctx.apply_dialog {
+        ApplyDialogWidget { view: ad, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref sd) = ctx.set_image_dialog {
+        SetImageDialogWidget { view: sd, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref cp) = ctx.container_picker {
+        ContainerPickerWidget { view: cp, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref rp) = ctx.related_picker {
+        RelatedPickerWidget { view: rp, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref dp) = ctx.data_key_picker {
+        DataKeyPickerWidget { view: dp, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref ul) = ctx.undo_list {
+        UndoListWidget { view: ul, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref es) = ctx.exec_sessions {
+        ExecSessionsWidget { view: es, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref gs) = ctx.global_search {
+        GlobalSearchWidget { view: gs, theme: ctx.theme }.render(frame, area);
+    }
+
     if let Some(ref ph) = ctx.pane_help {
         let widget = PaneHelpWidget { view: ph, theme: ctx.theme };
         widget.render(frame, area);
     }
 
+    if let Some(ref nc) = ctx.notification_center {
+        NotificationCenterWidget { view: nc, theme: ctx.theme }.render(frame, area);
+    }
+
+    if let Some(ref welcome) = ctx.welcome {
+        WelcomeWidget { view: welcome, theme: ctx.theme }.render(frame, area);
+    }
+
     if !ctx.toasts.is_empty() {
         let widget = ToastWidget { toasts: ctx.toasts, theme: ctx.theme };
         widget.render(frame, area);
@@ -197,6 +347,7 @@ fn render_status_bar(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
     let widget = StatusBarWidget {
         mode: ctx.mode_name,
         context: ctx.cluster_name,
+        read_only: ctx.read_only,
         help_key: ctx.help_key,
         pane_help_key: ctx.pane_help_key,
         namespace_key: ctx.namespace_key,
@@ -204,6 +355,9 @@ fn render_status_bar(frame: &mut Frame, area: Rect, ctx: &RenderContext) {
         close_pane_key: ctx.close_pane_key,
         new_tab_key: ctx.new_tab_key,
         quit_key: ctx.quit_key,
+        warning_ticker: ctx.warning_ticker.as_ref(),
+        health: ctx.connection_health.as_ref(),
+        memory: ctx.memory_budget.as_ref(),
         theme: ctx.theme,
     };
     widget.render(frame, area);
@@ -8,13 +8,27 @@ fn render_context_requires_theme() {
     let ctx = RenderContext {
         cluster_name: None,
         namespace: None,
+        read_only: false,
+        offline: None,
         namespace_selector: None,
         context_selector: None,
         resource_switcher: None,
         confirm_dialog: None,
         port_forward_dialog: None,
         query_dialog: None,
+        can_i_dialog: None,
+        apply_dialog: None,
+        set_image_dialog: None,
+        container_picker: None,
+        related_picker: None,
+        data_key_picker: None,
+        undo_list: None,
+        exec_sessions: None,
+        global_search: None,
+        rollouts: None,
         pane_help: None,
+        notification_center: None,
+        welcome: None,
         toasts: &[],
         pane_tree: &pane_tree,
         focused_pane: None,
@@ -30,6 +44,9 @@ fn render_context_requires_theme() {
         close_pane_key: None,
         new_tab_key: None,
         quit_key: None,
+        warning_ticker: None,
+        connection_health: None,
+        memory_budget: None,
         theme: &theme,
     };
     assert_eq!(ctx.active_tab, 0);
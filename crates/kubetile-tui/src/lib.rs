@@ -1,3 +1,4 @@
+pub mod fuzzy;
 pub mod layout;
 pub mod pane;
 pub mod tab;
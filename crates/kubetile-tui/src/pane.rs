@@ -24,6 +24,17 @@ pub trait Pane {
     fn handle_command(&mut self, cmd: &PaneCommand);
     fn view_type(&self) -> &ViewType;
     fn on_focus_change(&mut self, _previous: Option<&ViewType>) {}
+    /// Takes the area the pane is drawn in from the next frame on, e.g. after a terminal resize.
+    fn fit(&mut self, _area: Rect) {}
+    /// Approximate bytes held in the pane's buffers, such as log lines, result rows or scrollback.
+    fn memory_usage(&self) -> usize {
+        0
+    }
+    /// Whether content arrived on the pane's own, e.g. polled log lines, since the last call.
+    /// Changes made through events and commands are redrawn anyway.
+    fn take_redraw(&mut self) -> bool {
+        false
+    }
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
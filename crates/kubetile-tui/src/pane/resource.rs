@@ -14,6 +14,13 @@ pub enum ResourceKind {
     Namespaces,
     PersistentVolumes,
     PersistentVolumeClaims,
+    NetworkPolicies,
+    ResourceQuotas,
+    LimitRanges,
+    PodDisruptionBudgets,
+    /// Every workload kind in one list, like `kubectl get all`; rows name their kind in a KIND
+    /// column.
+    Workloads,
     Custom(String),
 }
 
@@ -34,10 +41,42 @@ impl ResourceKind {
             Self::Namespaces => "ns",
             Self::PersistentVolumes => "pv",
             Self::PersistentVolumeClaims => "pvc",
+            Self::NetworkPolicies => "netpol",
+            Self::ResourceQuotas => "quota",
+            Self::LimitRanges => "limits",
+            Self::PodDisruptionBudgets => "pdb",
+            Self::Workloads => "all",
             Self::Custom(s) => s.as_str(),
         }
     }
 
+    /// Names typed to jump straight to this kind: the kubectl short name plus the common k9s
+    /// aliases.
+    pub fn aliases(&self) -> &[&str] {
+        match self {
+            Self::Pods => &["po", "pod"],
+            Self::Deployments => &["deploy", "dp"],
+            Self::Services => &["svc"],
+            Self::StatefulSets => &["sts"],
+            Self::DaemonSets => &["ds"],
+            Self::Jobs => &["job"],
+            Self::CronJobs => &["cj"],
+            Self::ConfigMaps => &["cm"],
+            Self::Secrets => &["secret", "sec"],
+            Self::Ingresses => &["ing"],
+            Self::Nodes => &["no"],
+            Self::Namespaces => &["ns"],
+            Self::PersistentVolumes => &["pv"],
+            Self::PersistentVolumeClaims => &["pvc"],
+            Self::NetworkPolicies => &["netpol", "np"],
+            Self::ResourceQuotas => &["quota"],
+            Self::LimitRanges => &["limits"],
+            Self::PodDisruptionBudgets => &["pdb"],
+            Self::Workloads => &["all", "workloads"],
+            Self::Custom(_) => &[],
+        }
+    }
+
     pub fn display_name(&self) -> &str {
         match self {
             Self::Pods => "Pods",
@@ -54,6 +93,11 @@ impl ResourceKind {
             Self::Namespaces => "Namespaces",
             Self::PersistentVolumes => "PersistentVolumes",
             Self::PersistentVolumeClaims => "PersistentVolumeClaims",
+            Self::NetworkPolicies => "NetworkPolicies",
+            Self::ResourceQuotas => "ResourceQuotas",
+            Self::LimitRanges => "LimitRanges",
+            Self::PodDisruptionBudgets => "PodDisruptionBudgets",
+            Self::Workloads => "Workloads",
             Self::Custom(s) => s.as_str(),
         }
     }
@@ -74,6 +118,11 @@ impl ResourceKind {
             Self::Namespaces,
             Self::PersistentVolumes,
             Self::PersistentVolumeClaims,
+            Self::NetworkPolicies,
+            Self::ResourceQuotas,
+            Self::LimitRanges,
+            Self::PodDisruptionBudgets,
+            Self::Workloads,
         ]
     }
 
@@ -188,6 +188,39 @@ fn resize_adjusts_ratio() {
     assert_eq!(rects[1].1.width, 40);
 }
 
+#[test]
+fn swap_exchanges_positions_and_keeps_views() {
+    let mut tree = PaneTree::new(pods_view());
+    tree.split(1, SplitDirection::Vertical, logs_view());
+    tree.split(1, SplitDirection::Horizontal, help_view());
+    tree.resize(1, 0.1, true);
+    let before = tree.layout(area(100, 50));
+
+    assert!(tree.swap(1, 2));
+    assert_eq!(tree.leaf_ids(), vec![2, 3, 1]);
+    assert!(matches!(tree.find(1), Some(PaneNode::Leaf { view: ViewType::ResourceList(ResourceKind::Pods), .. })));
+    let after = tree.layout(area(100, 50));
+    assert_eq!(after[0].1, before[0].1);
+    assert_eq!(after[2].1, before[2].1);
+
+    assert!(!tree.swap(1, 99));
+    assert!(!tree.swap(1, 1));
+    assert_eq!(tree.leaf_ids(), vec![2, 3, 1]);
+}
+
+#[test]
+fn rotate_cycles_panes_of_the_parent_split() {
+    let mut tree = PaneTree::new(pods_view());
+    assert!(!tree.rotate(1));
+    tree.split(1, SplitDirection::Vertical, logs_view());
+    tree.split(2, SplitDirection::Horizontal, help_view());
+    // Tree: Split(V) -> [Leaf(1), Split(H) -> [Leaf(2), Leaf(3)]]
+    assert!(tree.rotate(2));
+    assert_eq!(tree.leaf_ids(), vec![1, 3, 2]);
+    assert!(tree.rotate(1));
+    assert_eq!(tree.leaf_ids(), vec![2, 1, 3]);
+}
+
 #[test]
 fn find_returns_correct_node() {
     let mut tree = PaneTree::new(pods_view());
@@ -319,8 +352,8 @@ fn focus_cycling_wraps_backward() {
 // --- ResourceKind tests ---
 
 #[test]
-fn resource_kind_all_returns_14_variants() {
-    assert_eq!(ResourceKind::all().len(), 14);
+fn resource_kind_all_returns_19_variants() {
+    assert_eq!(ResourceKind::all().len(), 19);
 }
 
 #[test]
@@ -333,6 +366,16 @@ fn resource_kind_short_names_are_unique() {
     assert_eq!(names.len(), count);
 }
 
+#[test]
+fn resource_kind_aliases_are_unique_and_include_short_name() {
+    let mut aliases: Vec<&str> = ResourceKind::all().iter().flat_map(|k| k.aliases().iter().copied()).collect();
+    let count = aliases.len();
+    aliases.sort();
+    aliases.dedup();
+    assert_eq!(aliases.len(), count);
+    assert!(ResourceKind::all().iter().all(|k| k.aliases().contains(&k.short_name())));
+}
+
 #[test]
 fn resource_kind_is_namespaced() {
     let cluster_scoped = [ResourceKind::Nodes, ResourceKind::Namespaces, ResourceKind::PersistentVolumes];
@@ -88,6 +88,41 @@ impl PaneTree {
         self.root.resize_at(target, amount, grow);
     }
 
+    /// Swap the positions of two panes, keeping the split ratios where they are.
+    /// Returns false if either pane was not found.
+    pub fn swap(&mut self, a: PaneId, b: PaneId) -> bool {
+        if a == b || self.root.find(a).is_none() || self.root.find(b).is_none() {
+            return false;
+        }
+        let placeholder = || PaneNode::Leaf { id: 0, view: ViewType::Empty };
+        let Some(slot_a) = self.root.leaf_mut(a) else { return false };
+        let node_a = std::mem::replace(slot_a, placeholder());
+        let Some(slot_b) = self.root.leaf_mut(b) else { return false };
+        let node_b = std::mem::replace(slot_b, node_a);
+        if let Some(slot) = self.root.leaf_mut(0) {
+            *slot = node_b;
+        }
+        true
+    }
+
+    /// Rotate the panes of the split directly containing the target: each pane moves one
+    /// position forward in depth-first order and the last one wraps around to the front.
+    /// Returns false if the target was not found or is the only pane.
+    pub fn rotate(&mut self, target: PaneId) -> bool {
+        let Some(parent) = self.root.parent_mut(target) else { return false };
+        let mut slots = Vec::new();
+        parent.collect_leaves_mut(&mut slots);
+        let mut nodes: Vec<PaneNode> = slots
+            .iter_mut()
+            .map(|slot| std::mem::replace(&mut **slot, PaneNode::Leaf { id: 0, view: ViewType::Empty }))
+            .collect();
+        nodes.rotate_right(1);
+        for (slot, node) in slots.into_iter().zip(nodes) {
+            *slot = node;
+        }
+        true
+    }
+
     /// Get all leaf pane IDs in depth-first order (for focus cycling).
     pub fn leaf_ids(&self) -> Vec<PaneId> {
         self.root.leaf_ids()
@@ -199,6 +234,42 @@ impl PaneNode {
         }
     }
 
+    fn leaf_mut(&mut self, target: PaneId) -> Option<&mut PaneNode> {
+        match self {
+            PaneNode::Leaf { id, .. } if *id == target => Some(self),
+            PaneNode::Split { first, second, .. } => match first.leaf_mut(target) {
+                Some(leaf) => Some(leaf),
+                None => second.leaf_mut(target),
+            },
+            _ => None,
+        }
+    }
+
+    /// The innermost split with the target as a direct child.
+    fn parent_mut(&mut self, target: PaneId) -> Option<&mut PaneNode> {
+        let PaneNode::Split { first, second, .. } = self else { return None };
+        let is_child = |node: &PaneNode| matches!(node, PaneNode::Leaf { id, .. } if *id == target);
+        if is_child(first) || is_child(second) {
+            return Some(self);
+        }
+        let PaneNode::Split { first, second, .. } = self else { return None };
+        if first.contains_leaf(target) {
+            first.parent_mut(target)
+        } else {
+            second.parent_mut(target)
+        }
+    }
+
+    fn collect_leaves_mut<'a>(&'a mut self, out: &mut Vec<&'a mut PaneNode>) {
+        match self {
+            PaneNode::Leaf { .. } => out.push(self),
+            PaneNode::Split { first, second, .. } => {
+                first.collect_leaves_mut(out);
+                second.collect_leaves_mut(out);
+            }
+        }
+    }
+
     pub fn leaf_ids(&self) -> Vec<PaneId> {
         let mut result = Vec::new();
         self.collect_leaf_ids(&mut result);
@@ -22,6 +22,8 @@ pub enum PaneCommand {
     PageDown,
     ToggleFollow,
     ToggleWrap,
+    ToggleCollapse,
+    CycleLogLevel,
     ScrollLeft,
     ScrollRight,
     SendInput(String),
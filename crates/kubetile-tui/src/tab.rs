@@ -1,4 +1,4 @@
-use crate::pane::{PaneId, PaneTree, SplitDirection, ViewType};
+use crate::pane::{PaneId, PaneNode, PaneTree, SplitDirection, ViewType};
 
 pub struct Tab {
     pub id: u32,
@@ -6,6 +6,10 @@ pub struct Tab {
     pub pane_tree: PaneTree,
     pub focused_pane: PaneId,
     pub fullscreen_pane: Option<PaneId>,
+    /// While a pane is fullscreen, moving focus fullscreens the newly focused pane instead.
+    pub zoom_follows_focus: bool,
+    /// Keys typed into an exec pane are sent to every exec pane in the tab.
+    pub sync_input: bool,
 }
 
 pub struct TabManager {
@@ -24,6 +28,8 @@ impl TabManager {
             pane_tree: PaneTree::with_initial_id(pane_id, initial_view),
             focused_pane: pane_id,
             fullscreen_pane: None,
+            zoom_follows_focus: false,
+            sync_input: false,
         };
         Self { tabs: vec![tab], active_tab: 0, next_pane_id: 2, next_tab_id: 2 }
     }
@@ -38,6 +44,8 @@ impl TabManager {
             pane_tree: PaneTree::with_initial_id(pane_id, initial_view),
             focused_pane: pane_id,
             fullscreen_pane: None,
+            zoom_follows_focus: false,
+            sync_input: false,
         };
         self.tabs.push(tab);
         self.active_tab = self.tabs.len() - 1;
@@ -59,6 +67,59 @@ impl TabManager {
         }
     }
 
+    /// Move a pane out of the active tab into a new tab of its own, keeping its ID.
+    /// Returns the new tab's ID, or None if the pane is not in the active tab or is its only pane.
+    pub fn move_pane_to_new_tab(&mut self, pane: PaneId, name: &str) -> Option<u32> {
+        let tab = &mut self.tabs[self.active_tab];
+        let Some(PaneNode::Leaf { view, .. }) = tab.pane_tree.find(pane) else { return None };
+        let view = view.clone();
+        if !tab.pane_tree.close(pane) {
+            return None;
+        }
+        if tab.focused_pane == pane {
+            tab.focused_pane = tab.pane_tree.leaf_ids()[0];
+        }
+        if tab.fullscreen_pane == Some(pane) {
+            tab.fullscreen_pane = None;
+        }
+
+        let tab_id = self.next_tab_id;
+        self.next_tab_id += 1;
+        self.tabs.push(Tab {
+            id: tab_id,
+            name: name.to_string(),
+            pane_tree: PaneTree::with_initial_id(pane, view),
+            focused_pane: pane,
+            fullscreen_pane: None,
+            zoom_follows_focus: false,
+            sync_input: false,
+        });
+        self.active_tab = self.tabs.len() - 1;
+        Some(tab_id)
+    }
+
+    /// Merge the active tab, which must hold a single pane, into the previous tab as a split next
+    /// to that tab's focused pane. Returns the moved pane's ID, or None if the active tab is the
+    /// first one or has several panes.
+    pub fn merge_into_previous_tab(&mut self, direction: SplitDirection) -> Option<PaneId> {
+        if self.active_tab == 0 {
+            return None;
+        }
+        let PaneNode::Leaf { id: pane, view } = self.tabs[self.active_tab].pane_tree.root() else { return None };
+        let (pane, view) = (*pane, view.clone());
+        let prev = &mut self.tabs[self.active_tab - 1];
+        if !prev.pane_tree.split_with_id(prev.focused_pane, direction, view, pane) {
+            return None;
+        }
+
+        self.tabs.remove(self.active_tab);
+        self.active_tab -= 1;
+        let tab = &mut self.tabs[self.active_tab];
+        tab.focused_pane = pane;
+        tab.fullscreen_pane = None;
+        Some(pane)
+    }
+
     pub fn active(&self) -> &Tab {
         &self.tabs[self.active_tab]
     }
@@ -1,5 +1,5 @@
 use super::*;
-use crate::pane::{ResourceKind, ViewType};
+use crate::pane::{PaneNode, ResourceKind, ViewType};
 
 fn pods_view() -> ViewType {
     ViewType::ResourceList(ResourceKind::Pods)
@@ -176,3 +176,40 @@ fn close_nonexistent_tab_returns_false() {
     assert!(!tm.close_tab(99));
     assert_eq!(tm.tabs().len(), 2);
 }
+
+#[test]
+fn move_pane_to_new_tab_keeps_its_id_and_view() {
+    let mut tm = TabManager::new(pods_view());
+    let p2 = tm.split_pane(1, SplitDirection::Vertical, empty_view()).unwrap();
+    tm.active_mut().focused_pane = p2;
+
+    let tab_id = tm.move_pane_to_new_tab(p2, "Moved").unwrap();
+    assert_eq!(tm.active().id, tab_id);
+    assert_eq!(tm.active().focused_pane, p2);
+    assert!(matches!(tm.active().pane_tree.find(p2), Some(PaneNode::Leaf { view: ViewType::Empty, .. })));
+    assert_eq!(tm.tabs()[0].pane_tree.leaf_ids(), vec![1]);
+    assert_eq!(tm.tabs()[0].focused_pane, 1);
+
+    tm.switch_tab(0);
+    assert!(tm.move_pane_to_new_tab(1, "Alone").is_none());
+    assert_eq!(tm.tabs().len(), 2);
+}
+
+#[test]
+fn merge_into_previous_tab_splits_next_to_its_focused_pane() {
+    let mut tm = TabManager::new(pods_view());
+    assert!(tm.merge_into_previous_tab(SplitDirection::Vertical).is_none());
+
+    tm.new_tab("Second", empty_view());
+    let moved = tm.active().focused_pane;
+    assert_eq!(tm.merge_into_previous_tab(SplitDirection::Vertical), Some(moved));
+    assert_eq!(tm.tabs().len(), 1);
+    assert_eq!(tm.active().pane_tree.leaf_ids(), vec![1, moved]);
+    assert_eq!(tm.active().focused_pane, moved);
+
+    tm.new_tab("Third", pods_view());
+    let root = tm.active().focused_pane;
+    tm.split_pane(root, SplitDirection::Vertical, empty_view());
+    assert!(tm.merge_into_previous_tab(SplitDirection::Vertical).is_none());
+    assert_eq!(tm.tabs().len(), 2);
+}
@@ -1,5 +1,14 @@
-use kubetile_config::ThemeConfig;
-use ratatui::style::{Color, Style};
+use std::collections::HashMap;
+
+use kubetile_config::{context_matches, StatusRules, ThemeConfig};
+use ratatui::style::{Color, Modifier, Style};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusLevel {
+    Ok,
+    Warn,
+    Error,
+}
 
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -23,6 +32,12 @@ pub struct Theme {
     pub yaml_boolean: Style,
     pub yaml_null: Style,
     pub insert_mode: Style,
+    /// Lowercased resource kind → lowercased status keyword → level.
+    pub status_rules: HashMap<String, HashMap<String, StatusLevel>>,
+    /// Context name patterns with their accent colors, in config order.
+    pub context_accents: Vec<(String, Color)>,
+    /// Accent of the current context when one of `context_accents` matched it.
+    pub context_accent: Option<Color>,
 }
 
 impl Default for Theme {
@@ -80,10 +95,56 @@ impl Theme {
             yaml_boolean: Style::default().fg(yaml_boolean),
             yaml_null: Style::default().fg(yaml_null),
             insert_mode: Style::default().fg(insert_mode_fg).bg(insert_mode_bg),
+            status_rules: config
+                .status_rules
+                .iter()
+                .map(|(kind, rules)| (kind.to_lowercase(), status_keywords(rules)))
+                .collect(),
+            context_accents: config
+                .context_accents
+                .iter()
+                .map(|(pattern, color)| (pattern.clone(), parse_color_or_default(color)))
+                .collect(),
+            context_accent: None,
+        }
+    }
+
+    /// This theme recolored for `context`: the first matching `context_accents` color becomes the
+    /// accent, the focused border and the status bar background. Call it on the configured theme.
+    pub fn for_context(&self, context: Option<&str>) -> Theme {
+        let mut theme = self.clone();
+        let matched =
+            context.and_then(|ctx| self.context_accents.iter().find(|(pattern, _)| context_matches(pattern, ctx)));
+        let Some(&(_, accent)) = matched else { return theme };
+        theme.accent = accent;
+        theme.border_active = Style::default().fg(accent);
+        theme.status_bar = Style::default().fg(self.header.bg.unwrap_or(Color::Black)).bg(accent);
+        theme.context_accent = Some(accent);
+        theme
+    }
+
+    /// Level of a STATUS value for `kind` (its display name); the kind's own rules win over `default`.
+    pub fn status_level(&self, kind: &str, status: &str) -> Option<StatusLevel> {
+        let status = status.to_lowercase();
+        [kind.to_lowercase().as_str(), "default"]
+            .into_iter()
+            .find_map(|key| self.status_rules.get(key).and_then(|keywords| keywords.get(&status)).copied())
+    }
+
+    pub fn status_style(&self, kind: &str, status: &str) -> Style {
+        match self.status_level(kind, status) {
+            Some(StatusLevel::Ok) => self.status_running,
+            Some(StatusLevel::Error) => self.status_failed,
+            Some(StatusLevel::Warn) | None => self.status_pending,
         }
     }
 }
 
+fn status_keywords(rules: &StatusRules) -> HashMap<String, StatusLevel> {
+    let levels = [(&rules.ok, StatusLevel::Ok), (&rules.warn, StatusLevel::Warn), (&rules.error, StatusLevel::Error)];
+    levels.into_iter().flat_map(|(keywords, level)| keywords.iter().map(move |k| (k.to_lowercase(), level))).collect()
+}
+
 fn parse_color_or_default(s: &str) -> Color {
     parse_color(s).unwrap_or(Color::Reset)
 }
@@ -151,10 +212,57 @@ pub fn parse_color(s: &str) -> anyhow::Result<Color> {
     }
 }
 
+/// Parse a style string: space-separated words, each a foreground color as accepted by
+/// [`parse_color`], `bg:<color>`, or one of `bold`, `italic`, `underlined`, `dim`, `reversed`.
+/// `"red bold"` and `"#1e1e2e bg:#f38ba8"` are both styles.
+pub fn parse_style(s: &str) -> anyhow::Result<Style> {
+    let mut style = Style::default();
+    for word in s.split_whitespace() {
+        style = match word.to_lowercase().as_str() {
+            "bold" => style.add_modifier(Modifier::BOLD),
+            "italic" => style.add_modifier(Modifier::ITALIC),
+            "underline" | "underlined" => style.add_modifier(Modifier::UNDERLINED),
+            "dim" => style.add_modifier(Modifier::DIM),
+            "reversed" => style.add_modifier(Modifier::REVERSED),
+            _ => match word.strip_prefix("bg:") {
+                Some(bg) => style.bg(parse_color(bg)?),
+                None => style.fg(parse_color(word)?),
+            },
+        };
+    }
+    Ok(style)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn context_accent_recolors_the_first_matching_context() {
+        let mut config = ThemeConfig::default();
+        config.context_accents.insert("prod-*".into(), "red".into());
+        config.context_accents.insert("*".into(), "yellow".into());
+        let theme = Theme::from_config(&config);
+
+        let prod = theme.for_context(Some("prod-eu"));
+        assert_eq!(prod.accent, Color::Red);
+        assert_eq!(prod.border_active.fg, Some(Color::Red));
+        assert_eq!(prod.status_bar.bg, Some(Color::Red));
+        assert_eq!(theme.for_context(Some("kind")).context_accent, Some(Color::Yellow));
+        assert_eq!(Theme::default().for_context(Some("prod-eu")).context_accent, None);
+        assert_eq!(theme.for_context(None).accent, theme.accent);
+    }
+
+    #[test]
+    fn parse_style_combines_colors_and_modifiers() {
+        assert_eq!(parse_style("red bold").unwrap(), Style::default().fg(Color::Red).add_modifier(Modifier::BOLD));
+        assert_eq!(
+            parse_style("#1e1e2e bg:#f38ba8").unwrap(),
+            Style::default().fg(Color::Rgb(30, 30, 46)).bg(Color::Rgb(243, 139, 168))
+        );
+        assert!(parse_style("red blinking").is_err());
+    }
+
     #[test]
     fn test_parse_hex() {
         assert_eq!(parse_color("#89b4fa").unwrap(), Color::Rgb(137, 180, 250));
@@ -206,4 +314,21 @@ mod tests {
         assert_eq!(theme.status_failed, Style::default().fg(Color::Rgb(243, 139, 168)));
         assert_eq!(theme.insert_mode, Style::default().fg(Color::Rgb(30, 30, 46)).bg(Color::Rgb(166, 227, 161)));
     }
+
+    #[test]
+    fn status_rules_prefer_kind_over_default() {
+        let mut config = ThemeConfig::default();
+        config.status_rules.insert(
+            "Certificates".into(),
+            StatusRules { ok: vec!["True".into()], warn: vec![], error: vec!["False".into(), "Running".into()] },
+        );
+        let theme = Theme::from_config(&config);
+
+        assert_eq!(theme.status_level("certificates", "true"), Some(StatusLevel::Ok));
+        assert_eq!(theme.status_level("Certificates", "Running"), Some(StatusLevel::Error));
+        assert_eq!(theme.status_level("Pods", "Running"), Some(StatusLevel::Ok));
+        assert_eq!(theme.status_level("Pods", "CrashLoopBackOff"), Some(StatusLevel::Error));
+        assert_eq!(theme.status_level("Pods", "Evicted"), None);
+        assert_eq!(theme.status_style("Pods", "Evicted"), theme.status_pending);
+    }
 }
@@ -0,0 +1,125 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::theme::Theme;
+
+pub struct ApplyDialogView<'a> {
+    /// Path of the file or directory to apply, as typed.
+    pub input: &'a str,
+    /// Namespace given to namespaced manifests that name none.
+    pub namespace: &'a str,
+    /// How many namespaces are selected to pick the target from.
+    pub namespace_count: usize,
+    /// Whether conflicts with other field managers are forced.
+    pub force: bool,
+    pub planning: bool,
+    pub error: Option<&'a str>,
+}
+
+pub struct ApplyDialogWidget<'a> {
+    pub view: &'a ApplyDialogView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> ApplyDialogWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 72.min(area.width.saturating_sub(4));
+        let height = 9.min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" Apply Manifests ")
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // usage
+                Constraint::Length(1), // input
+                Constraint::Length(1), // force
+                Constraint::Length(1), // blank
+                Constraint::Length(2), // status
+                Constraint::Length(1), // help
+            ])
+            .split(inner);
+
+        let namespace = if view.namespace_count > 1 {
+            format!("{}, Tab: next of {}", view.namespace, view.namespace_count)
+        } else {
+            view.namespace.to_string()
+        };
+        frame.render_widget(
+            Paragraph::new(format!("YAML file or directory   (default ns: {namespace})")).style(t.text_dim),
+            chunks[0],
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("> ", Style::default().fg(t.accent)),
+                Span::styled(view.input, Style::default().fg(t.fg).bold()),
+                Span::styled("_", Style::default().fg(t.accent)),
+            ])),
+            chunks[1],
+        );
+        let force = if view.force {
+            Span::styled("Force conflicts: on", t.status_pending)
+        } else {
+            Span::styled("Force conflicts: off", t.text_dim)
+        };
+        frame.render_widget(Paragraph::new(force), chunks[2]);
+
+        let status = match (view.planning, view.error) {
+            (true, _) => Line::from(Span::styled("Reading manifests and dry-running them…", t.text_dim)),
+            (false, Some(error)) => Line::from(Span::styled(format!("Error: {error}"), t.status_failed)),
+            (false, None) => Line::default(),
+        };
+        frame.render_widget(Paragraph::new(status).wrap(Wrap { trim: true }), chunks[4]);
+
+        frame.render_widget(
+            Paragraph::new("Enter diff │ Ctrl+F force │ Esc close").style(t.text_dim).alignment(Alignment::Center),
+            chunks[5],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn dialog_shows_path_and_error() {
+        let theme = Theme::default();
+        let view = ApplyDialogView {
+            input: "./deploy",
+            namespace: "web",
+            namespace_count: 2,
+            force: true,
+            planning: false,
+            error: Some("./deploy does not exist"),
+        };
+
+        let content = render_to_string(80, 20, |frame| {
+            ApplyDialogWidget { view: &view, theme: &theme }.render(frame, frame.area())
+        });
+        assert!(content.contains("Apply Manifests"));
+        assert!(content.contains("> ./deploy_"));
+        assert!(content.contains("(default ns: web, Tab: next of 2)"));
+        assert!(content.contains("Force conflicts: on"));
+        assert!(content.contains("Error: ./deploy does not exist"));
+    }
+}
@@ -0,0 +1,127 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme::Theme;
+
+#[derive(Clone, Copy)]
+pub enum CanIStatusView<'a> {
+    Idle,
+    Checking,
+    Allowed(&'a str),
+    Denied(&'a str),
+    Error(&'a str),
+}
+
+pub struct CanIDialogView<'a> {
+    pub input: &'a str,
+    pub namespace: &'a str,
+    pub query: Option<&'a str>,
+    pub status: CanIStatusView<'a>,
+}
+
+pub struct CanIDialogWidget<'a> {
+    pub input: &'a str,
+    pub namespace: &'a str,
+    /// The parsed question the status refers to, e.g. `delete deployments.apps -n prod`.
+    pub query: Option<&'a str>,
+    pub status: CanIStatusView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> CanIDialogWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let width = 64.min(area.width.saturating_sub(4));
+        let height = 9.min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" Can I? ")
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // usage
+                Constraint::Length(1), // input
+                Constraint::Length(1), // blank
+                Constraint::Length(2), // result
+                Constraint::Length(1), // help
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(format!("VERB RESOURCE[.GROUP][/SUB] [-n NS | -A]   (ns: {})", self.namespace))
+                .style(t.text_dim),
+            chunks[0],
+        );
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled("> ", Style::default().fg(t.accent)),
+                Span::styled(self.input, Style::default().fg(t.fg).bold()),
+                Span::styled("_", Style::default().fg(t.accent)),
+            ])),
+            chunks[1],
+        );
+
+        let query = self.query.unwrap_or_default();
+        let result: Vec<Line> = match self.status {
+            CanIStatusView::Idle => vec![],
+            CanIStatusView::Checking => vec![Line::from(Span::styled(format!("Checking {query}…"), t.text_dim))],
+            CanIStatusView::Allowed(reason) => vec![
+                Line::from(vec![Span::styled("yes  ", t.status_running.bold()), Span::raw(query.to_string())]),
+                Line::from(Span::styled(reason.to_string(), t.text_dim)),
+            ],
+            CanIStatusView::Denied(reason) => vec![
+                Line::from(vec![Span::styled("no   ", t.status_failed.bold()), Span::raw(query.to_string())]),
+                Line::from(Span::styled(reason.to_string(), t.text_dim)),
+            ],
+            CanIStatusView::Error(error) => vec![Line::from(Span::styled(format!("Error: {error}"), t.status_failed))],
+        };
+        frame.render_widget(Paragraph::new(result), chunks[3]);
+
+        frame.render_widget(
+            Paragraph::new("Enter check │ Esc close").style(t.text_dim).alignment(Alignment::Center),
+            chunks[4],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn dialog_renders_denied_answer() {
+        let theme = Theme::default();
+
+        let content = render_to_string(80, 24, |frame| {
+            let widget = CanIDialogWidget {
+                input: "delete pods -n prod",
+                namespace: "default",
+                query: Some("delete pods -n prod"),
+                status: CanIStatusView::Denied("no RBAC policy matched"),
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains("Can I?"));
+        assert!(content.contains("> delete pods -n prod"));
+        assert!(content.contains("no   delete pods -n prod"));
+        assert!(content.contains("no RBAC policy matched"));
+    }
+}
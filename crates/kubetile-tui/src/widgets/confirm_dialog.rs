@@ -5,6 +5,12 @@ use crate::theme::Theme;
 
 pub struct ConfirmDialogWidget<'a> {
     pub message: &'a str,
+    pub type_to_confirm: Option<&'a str>,
+    pub input: &'a str,
+    /// Whether Enter confirms rather than cancels a y/n prompt.
+    pub confirm_focused: bool,
+    /// Seconds left before the prompt cancels itself.
+    pub countdown: Option<u64>,
     pub theme: &'a Theme,
 }
 
@@ -12,9 +18,12 @@ impl<'a> ConfirmDialogWidget<'a> {
     pub fn render(self, frame: &mut Frame, area: Rect) {
         let t = self.theme;
         let lines: Vec<&str> = self.message.lines().collect();
-        let max_line_width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        let prompt = self.type_to_confirm.map(|name| format!("Type '{name}' to confirm:"));
+        let prompt_width = prompt.as_ref().map_or(0, |p| p.len());
+        let max_line_width = lines.iter().map(|l| l.len()).max().unwrap_or(0).max(prompt_width);
+        let prompt_height = if prompt.is_some() { 2 } else { 0 };
         let width = (max_line_width as u16 + 6).max(40).min(area.width.saturating_sub(4));
-        let height = (lines.len() as u16 + 6).min(area.height.saturating_sub(2));
+        let height = (lines.len() as u16 + 7 + prompt_height).min(area.height.saturating_sub(2));
 
         let popup = Rect {
             x: area.x + (area.width.saturating_sub(width)) / 2,
@@ -37,62 +46,126 @@ impl<'a> ConfirmDialogWidget<'a> {
 
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(1), Constraint::Length(1)])
+            .constraints([
+                Constraint::Min(1),
+                Constraint::Length(prompt_height),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
             .split(inner);
 
         let msg = Paragraph::new(self.message).style(Style::default().fg(t.fg)).alignment(Alignment::Center);
         frame.render_widget(msg, chunks[0]);
 
         let status_fg = t.status_bar.fg.unwrap_or(Color::Reset);
+        let typed = prompt.is_some();
+        if let Some(prompt) = prompt {
+            let input = Paragraph::new(vec![
+                Line::from(Span::styled(prompt, Style::default().fg(t.fg))),
+                Line::from(vec![
+                    Span::styled(self.input, Style::default().fg(t.accent).add_modifier(Modifier::BOLD)),
+                    Span::styled("_", Style::default().fg(t.accent)),
+                ]),
+            ])
+            .alignment(Alignment::Center);
+            frame.render_widget(input, chunks[1]);
+        }
+
+        if let Some(secs) = self.countdown {
+            let countdown =
+                Paragraph::new(format!("Cancels in {secs}s")).style(t.text_dim).alignment(Alignment::Center);
+            frame.render_widget(countdown, chunks[2]);
+        }
+
+        let button = |label: &'static str, style: Style, focused: bool| {
+            let style = if focused { style.add_modifier(Modifier::REVERSED) } else { style };
+            Span::styled(label, style.add_modifier(Modifier::BOLD))
+        };
+        let (confirm, cancel, hint) = if typed {
+            (
+                button("[Enter]", t.status_running, false),
+                button("[Esc]", t.status_failed, false),
+                "type the name, then Enter",
+            )
+        } else {
+            (
+                button("[y]", t.status_running, self.confirm_focused),
+                button("[n/Esc]", t.status_failed, !self.confirm_focused),
+                "←/→ switch · Enter selects",
+            )
+        };
         let buttons = Paragraph::new(Line::from(vec![
-            Span::styled("[y]", t.status_running.add_modifier(Modifier::BOLD)),
+            confirm,
             Span::styled(" Confirm  ", Style::default().fg(status_fg)),
-            Span::styled("[n/Esc]", t.status_failed.add_modifier(Modifier::BOLD)),
+            cancel,
             Span::styled(" Cancel", Style::default().fg(status_fg)),
         ]))
         .alignment(Alignment::Center);
-        frame.render_widget(buttons, chunks[2]);
+        frame.render_widget(buttons, chunks[3]);
+        frame.render_widget(Paragraph::new(hint).style(t.text_dim).alignment(Alignment::Center), chunks[4]);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ratatui::backend::TestBackend;
+    use crate::widgets::test_support::{buffer_to_string, render_buffer, render_to_string};
     use ratatui::buffer::Buffer;
-    use ratatui::Terminal;
 
     #[test]
     fn confirm_dialog_renders_message_and_buttons() {
-        let backend = TestBackend::new(60, 20);
-        let mut terminal = Terminal::new(backend).unwrap();
         let theme = Theme::default();
 
-        terminal
-            .draw(|frame| {
-                let widget =
-                    ConfirmDialogWidget { message: "Delete pod nginx-abc123\nin namespace default?", theme: &theme };
-                widget.render(frame, frame.area());
-            })
-            .unwrap();
-
-        let buf = terminal.backend().buffer().clone();
+        let buf = render_buffer(60, 20, |frame| {
+            let widget = ConfirmDialogWidget {
+                message: "Delete pod nginx-abc123\nin namespace default?",
+                type_to_confirm: None,
+                input: "",
+                confirm_focused: false,
+                countdown: Some(9),
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
         let content = buffer_to_string(&buf);
         assert!(content.contains("Confirm"), "should show Confirm title/button");
         assert!(content.contains("nginx-abc123"), "should show resource name");
         assert!(content.contains("Cancel"), "should show cancel button");
         assert!(content.contains("[y]"), "should show y key hint");
+        assert!(content.contains("←/→ switch · Enter selects"));
+        assert!(content.contains("Cancels in 9s"));
+        let (x, y) = find(&buf, "[n/Esc]");
+        assert!(buf[(x, y)].modifier.contains(Modifier::REVERSED), "cancel is focused");
+        let (x, y) = find(&buf, "[y]");
+        assert!(!buf[(x, y)].modifier.contains(Modifier::REVERSED));
     }
 
-    fn buffer_to_string(buf: &Buffer) -> String {
-        let mut s = String::new();
-        for y in 0..buf.area.height {
-            for x in 0..buf.area.width {
-                let cell = &buf[(x, y)];
-                s.push_str(cell.symbol());
-            }
-            s.push('\n');
-        }
-        s
+    #[test]
+    fn confirm_dialog_renders_type_name_prompt() {
+        let theme = Theme::default();
+
+        let content = render_to_string(60, 20, |frame| {
+            let widget = ConfirmDialogWidget {
+                message: "Delete pod nginx-abc123\nin namespace prod?",
+                type_to_confirm: Some("nginx-abc123"),
+                input: "ngi",
+                confirm_focused: false,
+                countdown: None,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains("Type 'nginx-abc123' to confirm:"));
+        assert!(content.contains("ngi_"));
+        assert!(content.contains("[Enter]"));
+        assert!(!content.contains("[y]"));
+    }
+
+    fn find(buf: &Buffer, text: &str) -> (u16, u16) {
+        let content = buffer_to_string(buf);
+        let (y, line) = content.lines().enumerate().find(|(_, l)| l.contains(text)).unwrap();
+        let x = line[..line.find(text).unwrap()].chars().count();
+        (x as u16, y as u16)
     }
 }
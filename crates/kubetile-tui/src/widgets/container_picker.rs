@@ -0,0 +1,97 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::Theme;
+
+pub struct ContainerPickerView<'a> {
+    /// What the container is picked for, e.g. `Logs` or `Exec`.
+    pub purpose: &'a str,
+    pub pod: &'a str,
+    pub containers: &'a [String],
+    pub selected: usize,
+    pub remembered: Option<&'a str>,
+}
+
+pub struct ContainerPickerWidget<'a> {
+    pub view: &'a ContainerPickerView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> ContainerPickerWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 50.min(area.width.saturating_sub(4));
+        let height = (view.containers.len() as u16 + 5).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" {} — Select Container ", view.purpose))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(format!(" {}", view.pod)).style(t.text_dim), chunks[0]);
+
+        let items: Vec<ListItem> = view
+            .containers
+            .iter()
+            .map(|name| {
+                let mut spans = vec![Span::styled(format!("  {name}"), Style::default().fg(t.fg))];
+                if view.remembered == Some(name.as_str()) {
+                    spans.push(Span::styled("  (last used)", t.text_dim));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.containers.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+        frame.render_widget(Paragraph::new(" Enter:select  Esc:cancel").style(t.text_dim), chunks[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn picker_lists_containers_and_marks_last_used() {
+        let theme = Theme::default();
+        let containers = vec!["app".to_string(), "istio-proxy".to_string()];
+        let view = ContainerPickerView {
+            purpose: "Logs",
+            pod: "api-5d8f9c7b6-x7k2p",
+            containers: &containers,
+            selected: 1,
+            remembered: Some("istio-proxy"),
+        };
+
+        let content = render_to_string(80, 20, |frame| {
+            ContainerPickerWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("Logs — Select Container"));
+        assert!(content.contains("api-5d8f9c7b6-x7k2p"));
+        assert!(content.contains("  app"));
+        assert!(content.contains("istio-proxy  (last used)"));
+    }
+}
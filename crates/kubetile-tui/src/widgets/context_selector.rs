@@ -1,23 +1,35 @@
+use std::collections::HashMap;
+
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 
+use crate::fuzzy::{fuzzy_filter, highlight};
 use crate::theme::Theme;
 
+/// Result of probing a context's API server while the selector is open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextHealth {
+    Probing,
+    Reachable { latency_ms: u64 },
+    Unreachable(String),
+}
+
 pub struct ContextSelectorWidget<'a> {
     pub contexts: &'a [String],
     pub filter: &'a str,
     pub selected: usize,
+    pub health: &'a HashMap<String, ContextHealth>,
     pub theme: &'a Theme,
 }
 
+/// Contexts fuzzy-matching `filter`, best match first.
+pub fn selector_entries<'a>(contexts: &'a [String], filter: &str) -> Vec<&'a str> {
+    fuzzy_filter(filter, contexts.iter().map(String::as_str), |ctx| ctx)
+}
+
 impl<'a> ContextSelectorWidget<'a> {
     pub fn filtered_contexts(&self) -> Vec<&'a str> {
-        let filter_lower = self.filter.to_lowercase();
-        self.contexts
-            .iter()
-            .filter(|ctx| filter_lower.is_empty() || ctx.to_lowercase().contains(&filter_lower))
-            .map(|ctx| ctx.as_str())
-            .collect()
+        selector_entries(self.contexts, self.filter)
     }
 
     pub fn render(self, frame: &mut Frame, area: Rect) {
@@ -55,8 +67,27 @@ impl<'a> ContextSelectorWidget<'a> {
         frame.render_widget(filter_line, chunks[0]);
 
         let filtered = self.filtered_contexts();
-        let items: Vec<ListItem> =
-            filtered.iter().map(|ctx| ListItem::new(format!("  {ctx}")).style(Style::default().fg(t.fg))).collect();
+        let name_width = filtered.iter().map(|ctx| ctx.len()).max().unwrap_or(0);
+        let items: Vec<ListItem> = filtered
+            .iter()
+            .map(|ctx| {
+                let status = match self.health.get(*ctx) {
+                    Some(ContextHealth::Probing) => Span::styled("…", t.text_dim),
+                    Some(ContextHealth::Reachable { latency_ms }) => {
+                        Span::styled(format!("✓ {latency_ms}ms"), t.status_running)
+                    }
+                    Some(ContextHealth::Unreachable(_)) => Span::styled("✗", t.status_failed),
+                    None => Span::raw(""),
+                };
+                let name_style = Style::default().fg(t.fg);
+                let mut spans = vec![Span::styled("  ", name_style)];
+                spans.extend(highlight(ctx, self.filter, name_style, t));
+                let padding = name_width - ctx.len() + 2;
+                spans.push(Span::styled(" ".repeat(padding), name_style));
+                spans.push(status);
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
 
         let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
         let mut list_state =
@@ -67,3 +98,29 @@ impl<'a> ContextSelectorWidget<'a> {
         frame.render_widget(hints, chunks[2]);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn selector_annotates_contexts_with_health() {
+        let theme = Theme::default();
+        let contexts = vec!["kind-dev".to_string(), "prod".to_string(), "staging".to_string()];
+        let health = HashMap::from([
+            ("kind-dev".to_string(), ContextHealth::Reachable { latency_ms: 12 }),
+            ("prod".to_string(), ContextHealth::Unreachable("timed out".into())),
+            ("staging".to_string(), ContextHealth::Probing),
+        ]);
+
+        let content = render_to_string(100, 30, |frame| {
+            let widget =
+                ContextSelectorWidget { contexts: &contexts, filter: "", selected: 0, health: &health, theme: &theme };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains("kind-dev  ✓ 12ms"));
+        assert!(content.contains("prod      ✗"));
+        assert!(content.contains("staging   …"));
+    }
+}
@@ -0,0 +1,86 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::Theme;
+
+/// Data keys of a ConfigMap or Secret, one of which is opened in the editor.
+pub struct DataKeyPickerView<'a> {
+    /// `cm web/settings`
+    pub subject: &'a str,
+    /// e.g. `app.conf  14 lines`
+    pub items: &'a [String],
+    pub selected: usize,
+}
+
+pub struct DataKeyPickerWidget<'a> {
+    pub view: &'a DataKeyPickerView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> DataKeyPickerWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 60.min(area.width.saturating_sub(4));
+        let height = (view.items.len() as u16 + 5).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" Edit Data Key ")
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(format!(" {}", view.subject)).style(t.text_dim), chunks[0]);
+
+        let items: Vec<ListItem> = view
+            .items
+            .iter()
+            .map(|label| ListItem::new(Span::styled(format!("  {label}"), Style::default().fg(t.fg))))
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.items.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+        frame.render_widget(Paragraph::new(" Enter:edit  Esc:cancel").style(t.text_dim), chunks[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn picker_lists_data_keys() {
+        let theme = Theme::default();
+        let items = vec!["app.conf  14 lines".to_string(), "logo.png  binary".to_string()];
+        let view = DataKeyPickerView { subject: "cm web/settings", items: &items, selected: 0 };
+
+        let content = render_to_string(80, 20, |frame| {
+            DataKeyPickerWidget { view: &view, theme: &theme }.render(frame, frame.area())
+        });
+        assert!(content.contains("Edit Data Key"));
+        assert!(content.contains(" cm web/settings"));
+        assert!(content.contains("  app.conf  14 lines"));
+        assert!(content.contains("  logo.png  binary"));
+        assert!(content.contains("Enter:edit"));
+    }
+}
@@ -0,0 +1,80 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::Theme;
+
+/// Exec sessions that were detached from their pane and still run.
+pub struct ExecSessionsView<'a> {
+    /// Oldest first, e.g. `db-0/postgres @ prod  detached 12m ago`.
+    pub items: &'a [String],
+    pub selected: usize,
+}
+
+pub struct ExecSessionsWidget<'a> {
+    pub view: &'a ExecSessionsView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> ExecSessionsWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 70.min(area.width.saturating_sub(4));
+        let height = (view.items.len() as u16 + 3).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" Exec Sessions ({}) ", view.items.len()))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let items: Vec<ListItem> = view
+            .items
+            .iter()
+            .map(|label| ListItem::new(Span::styled(format!("  {label}"), Style::default().fg(t.fg))))
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.items.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+        frame.render_widget(Paragraph::new(" Enter:reattach  x:end session  Esc:close").style(t.text_dim), chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn lists_detached_sessions() {
+        let theme = Theme::default();
+        let items = vec!["db-0/postgres @ prod  detached 12m ago".to_string()];
+        let view = ExecSessionsView { items: &items, selected: 0 };
+
+        let content = render_to_string(80, 20, |frame| {
+            ExecSessionsWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("Exec Sessions (1)"));
+        assert!(content.contains("  db-0/postgres @ prod  detached 12m ago"));
+        assert!(content.contains("Enter:reattach"));
+    }
+}
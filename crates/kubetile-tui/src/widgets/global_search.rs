@@ -0,0 +1,142 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::fuzzy::highlight;
+use crate::theme::Theme;
+
+pub struct SearchHitView<'a> {
+    /// Short kind name shown as a badge, e.g. `deploy`.
+    pub kind: &'a str,
+    pub namespace: Option<&'a str>,
+    pub name: &'a str,
+}
+
+/// The cluster-wide search popup.
+pub struct GlobalSearchView<'a> {
+    pub input: &'a str,
+    /// The name fragment of `input`, highlighted in the hits.
+    pub query: String,
+    /// Where the search looks, e.g. `namespace prod` or `cluster`.
+    pub scope: String,
+    /// The best matches, best first.
+    pub hits: Vec<SearchHitView<'a>>,
+    /// Matches in total, including those not shown.
+    pub total: usize,
+    pub selected: usize,
+    pub loading: bool,
+    /// Why some kinds are missing from the results, e.g. `Could not list: secret`.
+    pub notice: Option<String>,
+}
+
+pub struct GlobalSearchWidget<'a> {
+    pub view: &'a GlobalSearchView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> GlobalSearchWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 80.min(area.width.saturating_sub(4));
+        let height = (area.height * 3 / 5).clamp(8.min(area.height), 30);
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" Search {} ", view.scope))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(1), Constraint::Length(1), Constraint::Length(1)])
+            .split(inner);
+
+        let (input, input_style) = if view.input.is_empty() {
+            ("Type a name, or kind/name (deploy,svc/api)...", t.text_dim)
+        } else {
+            (view.input, Style::default().fg(t.fg))
+        };
+        frame.render_widget(Paragraph::new(format!(" > {input}")).style(input_style), chunks[0]);
+
+        let badge_width = view.hits.iter().map(|h| h.kind.len()).max().unwrap_or(0) + 2;
+        let items: Vec<ListItem> = view
+            .hits
+            .iter()
+            .map(|hit| {
+                let name_style = Style::default().fg(t.fg);
+                let mut spans = vec![
+                    Span::raw("  "),
+                    Span::styled(format!("{:<badge_width$}", format!("[{}]", hit.kind)), Style::default().fg(t.accent)),
+                    Span::raw(" "),
+                ];
+                spans.extend(highlight(hit.name, &view.query, name_style, t));
+                if let Some(namespace) = hit.namespace {
+                    spans.push(Span::styled(format!("  {namespace}"), t.text_dim));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.hits.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+        let status = if view.loading {
+            Span::styled(" Listing resources…", t.text_dim)
+        } else if let Some(notice) = &view.notice {
+            Span::styled(format!(" {notice}"), t.status_pending)
+        } else if view.total > view.hits.len() {
+            Span::styled(format!(" {} of {} matches", view.hits.len(), view.total), t.text_dim)
+        } else {
+            Span::styled(format!(" {} matches", view.total), t.text_dim)
+        };
+        frame.render_widget(Paragraph::new(Line::from(status)), chunks[2]);
+
+        let hints = " Enter:open  Tab:namespace/cluster  Esc:close";
+        frame.render_widget(Paragraph::new(hints).style(t.text_dim), chunks[3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn hits_show_kind_badges_and_namespaces() {
+        let theme = Theme::default();
+        let view = GlobalSearchView {
+            input: "api",
+            query: "api".into(),
+            scope: "namespace prod".into(),
+            hits: vec![
+                SearchHitView { kind: "deploy", namespace: Some("prod"), name: "api" },
+                SearchHitView { kind: "svc", namespace: Some("prod"), name: "api-internal" },
+            ],
+            total: 2,
+            selected: 0,
+            loading: false,
+            notice: None,
+        };
+
+        let content = render_to_string(100, 30, |frame| {
+            GlobalSearchWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("Search namespace prod"));
+        assert!(content.contains("[deploy] api  prod"));
+        assert!(content.contains("[svc]    api-internal  prod"));
+        assert!(content.contains("2 matches"));
+    }
+}
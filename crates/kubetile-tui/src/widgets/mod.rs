@@ -1,12 +1,55 @@
+pub mod apply_dialog;
 pub mod breadcrumb;
+pub mod can_i_dialog;
 pub mod confirm_dialog;
+pub mod container_picker;
 pub mod context_selector;
+pub mod data_key_picker;
+pub mod exec_sessions;
+pub mod global_search;
 pub mod namespace_selector;
+pub mod notification_center;
+pub mod offline_banner;
 pub mod pane_help;
 pub mod port_forward_dialog;
 pub mod query_dialog;
+pub mod related_picker;
 pub mod resource_list;
 pub mod resource_switcher;
+pub mod rollout_panel;
+pub mod set_image_dialog;
 pub mod status_bar;
 pub mod tab_bar;
 pub mod toast;
+pub mod undo_list;
+pub mod welcome;
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::{Frame, Terminal};
+
+    /// Draws `draw` on a `width`×`height` test terminal and returns the buffer.
+    pub(crate) fn render_buffer(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(draw).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    /// Draws `draw` like [`render_buffer`] and returns the screen as text, one line per row.
+    pub(crate) fn render_to_string(width: u16, height: u16, draw: impl FnOnce(&mut Frame)) -> String {
+        buffer_to_string(&render_buffer(width, height, draw))
+    }
+
+    pub(crate) fn buffer_to_string(buf: &Buffer) -> String {
+        let mut s = String::new();
+        for y in 0..buf.area.height {
+            for x in 0..buf.area.width {
+                s.push_str(buf[(x, y)].symbol());
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
@@ -1,31 +1,55 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 
+use crate::fuzzy::{fuzzy_match, highlight};
 use crate::theme::Theme;
 
 pub struct NamespaceSelectorWidget<'a> {
     pub namespaces: &'a [String],
     pub filter: &'a str,
     pub selected: usize,
+    /// Namespaces toggled for a multi-namespace selection.
+    pub marked: &'a [String],
+    pub favorites: &'a [String],
+    pub recent: &'a [String],
     pub theme: &'a Theme,
 }
 
-impl<'a> NamespaceSelectorWidget<'a> {
-    pub fn filtered_namespaces(&self) -> Vec<&'a str> {
-        let mut result: Vec<&str> = Vec::new();
-        let filter_lower = self.filter.to_lowercase();
-
-        if filter_lower.is_empty() || "all namespaces".contains(&filter_lower) {
-            result.push("All Namespaces");
-        }
+/// Entries of the namespace selector fuzzy-matching `filter`: "All Namespaces", then favorites in
+/// the order they were pinned, then recently used namespaces, then the rest. Within each group
+/// the best matches come first.
+pub fn selector_entries<'a>(
+    namespaces: &'a [String],
+    filter: &str,
+    favorites: &[String],
+    recent: &[String],
+) -> Vec<&'a str> {
+    let mut result: Vec<&str> = Vec::new();
+
+    if fuzzy_match(filter, "All Namespaces").is_some() {
+        result.push("All Namespaces");
+    }
 
-        for ns in self.namespaces {
-            if filter_lower.is_empty() || ns.to_lowercase().contains(&filter_lower) {
-                result.push(ns);
-            }
-        }
+    let rank = |ns: &String| {
+        favorites
+            .iter()
+            .position(|f| f == ns)
+            .map(|i| (0, i))
+            .or_else(|| recent.iter().position(|r| r == ns).map(|i| (1, i)))
+            .unwrap_or((2, 0))
+    };
+    let mut matching: Vec<(&String, i64)> =
+        namespaces.iter().filter_map(|ns| fuzzy_match(filter, ns).map(|m| (ns, m.score))).collect();
+    // Stable, so equal matches keep the order the cluster listed them in.
+    matching.sort_by_key(|&(ns, score)| (rank(ns), std::cmp::Reverse(score)));
+    result.extend(matching.into_iter().map(|(ns, _)| ns.as_str()));
+
+    result
+}
 
-        result
+impl<'a> NamespaceSelectorWidget<'a> {
+    pub fn filtered_namespaces(&self) -> Vec<&'a str> {
+        selector_entries(self.namespaces, self.filter, self.favorites, self.recent)
     }
 
     pub fn render(self, frame: &mut Frame, area: Rect) {
@@ -66,12 +90,25 @@ impl<'a> NamespaceSelectorWidget<'a> {
         let items: Vec<ListItem> = filtered
             .iter()
             .map(|ns| {
+                let marked = self.marked.iter().any(|m| m == ns);
                 let style = if *ns == "All Namespaces" {
                     Style::default().fg(t.accent).italic()
+                } else if marked {
+                    Style::default().fg(t.accent)
                 } else {
                     Style::default().fg(t.fg)
                 };
-                ListItem::new(format!("  {ns}")).style(style)
+                let mark = if marked { "✓" } else { " " };
+                let badge = if self.favorites.iter().any(|f| f == ns) {
+                    "★"
+                } else if self.recent.iter().any(|r| r == ns) {
+                    "↻"
+                } else {
+                    " "
+                };
+                let mut spans = vec![Span::styled(format!("{mark}{badge} "), style)];
+                spans.extend(highlight(ns, self.filter, style, t));
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -81,7 +118,43 @@ impl<'a> NamespaceSelectorWidget<'a> {
             ListState::default().with_selected(Some(self.selected.min(filtered.len().saturating_sub(1))));
         frame.render_stateful_widget(list, chunks[1], &mut list_state);
 
-        let hints = Paragraph::new(" Enter:select  Esc:cancel").style(t.text_dim);
+        let hints = if self.marked.is_empty() {
+            " Enter:select  Space:toggle  *:favorite  Esc:cancel".to_string()
+        } else {
+            format!(" Enter:watch {} namespaces  Space:toggle  *:favorite  Esc:cancel", self.marked.len())
+        };
+        let hints = Paragraph::new(hints).style(t.text_dim);
         frame.render_widget(hints, chunks[2]);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn favorites_then_recent_then_the_rest() {
+        let namespaces: Vec<String> = ["api", "billing", "db", "payments", "web"].map(String::from).to_vec();
+        let favorites = ["web".to_string(), "gone".to_string(), "api".to_string()];
+        let recent = ["db".to_string(), "web".to_string()];
+
+        assert_eq!(
+            selector_entries(&namespaces, "", &favorites, &recent),
+            ["All Namespaces", "web", "api", "db", "billing", "payments"]
+        );
+        assert_eq!(selector_entries(&namespaces, "b", &favorites, &recent), ["web", "db", "billing"]);
+    }
+
+    #[test]
+    fn entries_match_fuzzily() {
+        let namespaces: Vec<String> =
+            ["kube-public", "backbone-sys", "kube-system", "monitoring"].map(String::from).to_vec();
+
+        assert_eq!(selector_entries(&namespaces, "kbsys", &[], &[]), ["kube-system", "backbone-sys"]);
+        assert_eq!(
+            selector_entries(&namespaces, "kbsys", &["backbone-sys".to_string()], &[]),
+            ["backbone-sys", "kube-system"]
+        );
+        assert_eq!(selector_entries(&namespaces, "allns", &[], &[]), ["All Namespaces"]);
+    }
+}
@@ -0,0 +1,105 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme::Theme;
+use crate::widgets::toast::{ToastLevel, ToastRecord};
+
+pub struct NotificationCenterView<'a> {
+    /// Oldest first, as recorded.
+    pub entries: &'a [ToastRecord],
+    /// Entries skipped from the newest end.
+    pub scroll: usize,
+}
+
+pub struct NotificationCenterWidget<'a> {
+    pub view: &'a NotificationCenterView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> NotificationCenterWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let width = 90.min(area.width.saturating_sub(4));
+        let height = 20.min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" Notifications ({}) ", self.view.entries.len()))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        if inner.height < 2 {
+            return;
+        }
+
+        let list_area = Rect { height: inner.height - 1, ..inner };
+        let help_area = Rect { y: inner.y + inner.height - 1, height: 1, ..inner };
+
+        let lines: Vec<Line> = if self.view.entries.is_empty() {
+            vec![Line::from(Span::styled("No notifications yet", t.text_dim))]
+        } else {
+            self.view
+                .entries
+                .iter()
+                .rev()
+                .skip(self.view.scroll)
+                .take(list_area.height as usize)
+                .map(|entry| {
+                    let (label, style) = match entry.level {
+                        ToastLevel::Success => ("ok  ", t.status_running),
+                        ToastLevel::Error => ("err ", t.status_failed),
+                        ToastLevel::Info => ("info", Style::default().fg(t.accent)),
+                    };
+                    Line::from(vec![
+                        Span::styled(format!("{} ", entry.time), t.text_dim),
+                        Span::styled(format!("{label} "), style.bold()),
+                        Span::styled(entry.text.clone(), Style::default().fg(t.fg)),
+                    ])
+                })
+                .collect()
+        };
+        frame.render_widget(Paragraph::new(lines), list_area);
+
+        frame.render_widget(
+            Paragraph::new("j/k scroll │ c clear │ Esc close").style(t.text_dim).alignment(Alignment::Center),
+            help_area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_buffer;
+
+    #[test]
+    fn lists_newest_first_with_time_and_level() {
+        let theme = Theme::default();
+        let entries = [
+            ToastRecord { time: "10:00:01".into(), level: ToastLevel::Error, text: "Rollout failed".into() },
+            ToastRecord { time: "10:00:02".into(), level: ToastLevel::Success, text: "Scaled web".into() },
+        ];
+        let view = NotificationCenterView { entries: &entries, scroll: 0 };
+
+        let buf = render_buffer(80, 12, |frame| {
+            NotificationCenterWidget { view: &view, theme: &theme }.render(frame, frame.area())
+        });
+        let rows: Vec<String> =
+            (0..buf.area.height).map(|y| (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect()).collect();
+        let first = rows.iter().position(|r| r.contains("Scaled web")).unwrap();
+        let second = rows.iter().position(|r| r.contains("Rollout failed")).unwrap();
+        assert!(first < second);
+        assert!(rows[second].contains("10:00:01 err"));
+    }
+}
@@ -0,0 +1,89 @@
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+use crate::theme::Theme;
+
+#[derive(Clone, Copy)]
+pub enum OfflineStatusView {
+    RetryIn(u64),
+    Connecting,
+    Resuming,
+    AwaitingLogin,
+}
+
+pub struct OfflineBannerView<'a> {
+    pub reason: &'a str,
+    pub status: OfflineStatusView,
+    pub reconnect_key: Option<&'a str>,
+    pub diagnostics_key: Option<&'a str>,
+}
+
+pub struct OfflineBannerWidget<'a> {
+    pub view: &'a OfflineBannerView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> OfflineBannerWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let bg = t.status_bar.bg.unwrap_or(Color::Reset);
+        let fg = t.status_bar.fg.unwrap_or(Color::Reset);
+        let failed_fg = t.status_failed.fg.unwrap_or(Color::Red);
+        let text = Style::default().fg(fg).bg(bg);
+        let sep = Style::default().fg(t.border.fg.unwrap_or(Color::Reset)).bg(bg);
+
+        let status = match self.view.status {
+            OfflineStatusView::RetryIn(secs) => format!("retrying in {secs}s"),
+            OfflineStatusView::Connecting => "reconnecting…".into(),
+            OfflineStatusView::Resuming => "connected, waiting for data…".into(),
+            OfflineStatusView::AwaitingLogin => "credentials expired; log in, then reconnect".into(),
+        };
+
+        let mut spans = vec![
+            Span::styled(
+                " OFFLINE ",
+                Style::default().fg(t.header.bg.unwrap_or(Color::Reset)).bg(failed_fg).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" ", text),
+            Span::styled(self.view.reason.lines().next().unwrap_or_default().to_string(), text),
+            Span::styled(" │ ", sep),
+            Span::styled(status, text.add_modifier(Modifier::DIM)),
+        ];
+        if let Some(key) = self.view.reconnect_key {
+            spans.push(Span::styled(" │ ", sep));
+            spans.push(Span::styled(key.to_string(), Style::default().fg(t.accent).bg(bg)));
+            spans.push(Span::styled(" Reconnect", text));
+        }
+        if let Some(key) = self.view.diagnostics_key {
+            spans.push(Span::styled(" │ ", sep));
+            spans.push(Span::styled(key.to_string(), Style::default().fg(t.accent).bg(bg)));
+            spans.push(Span::styled(" Details", text));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)).style(Style::default().bg(bg)), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn banner_shows_reason_countdown_and_key() {
+        let theme = Theme::default();
+        let view = OfflineBannerView {
+            reason: "connection refused",
+            status: OfflineStatusView::RetryIn(4),
+            reconnect_key: Some("Ctrl+Shift+R"),
+            diagnostics_key: Some("Ctrl+Shift+Y"),
+        };
+
+        let content = render_to_string(120, 1, |frame| {
+            OfflineBannerWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("OFFLINE"));
+        assert!(content.contains("connection refused │ retrying in 4s"));
+        assert!(content.contains("Ctrl+Shift+R Reconnect │ Ctrl+Shift+Y Details"));
+    }
+}
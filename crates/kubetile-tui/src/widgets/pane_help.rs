@@ -3,9 +3,13 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::theme::Theme;
 
+const FORBIDDEN_SUFFIX: &str = " (forbidden)";
+
 pub struct PaneHelpView<'a> {
     pub title: &'a str,
     pub entries: &'a [(String, String)],
+    /// Indices of entries that are shown greyed out, e.g. actions RBAC forbids.
+    pub disabled: &'a [usize],
 }
 
 pub struct PaneHelpWidget<'a> {
@@ -19,7 +23,13 @@ impl<'a> PaneHelpWidget<'a> {
         let entries = self.view.entries;
 
         let key_col_width: usize = entries.iter().map(|(k, _)| k.len()).max().unwrap_or(8) + 2;
-        let desc_col_width: usize = entries.iter().map(|(_, d)| d.len()).max().unwrap_or(8) + 2;
+        let desc_col_width: usize = entries
+            .iter()
+            .enumerate()
+            .map(|(i, (_, d))| if self.view.disabled.contains(&i) { d.len() + FORBIDDEN_SUFFIX.len() } else { d.len() })
+            .max()
+            .unwrap_or(8)
+            + 2;
 
         let content_width = (key_col_width + desc_col_width) as u16;
         let width = (content_width + 4).max(40).min(area.width.saturating_sub(4));
@@ -52,7 +62,14 @@ impl<'a> PaneHelpWidget<'a> {
             .split(inner);
 
         let mut lines: Vec<Line> = Vec::new();
-        for (key, desc) in entries {
+        for (i, (key, desc)) in entries.iter().enumerate() {
+            if self.view.disabled.contains(&i) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{key:<key_col_width$}"), t.text_dim.add_modifier(Modifier::CROSSED_OUT)),
+                    Span::styled(format!("{desc}{FORBIDDEN_SUFFIX}"), t.text_dim.add_modifier(Modifier::CROSSED_OUT)),
+                ]));
+                continue;
+            }
             lines.push(Line::from(vec![
                 Span::styled(
                     format!("{key:<key_col_width$}"),
@@ -73,45 +73,26 @@ impl<'a> PortForwardDialogWidget<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ratatui::backend::TestBackend;
-    use ratatui::buffer::Buffer;
-    use ratatui::Terminal;
+    use crate::widgets::test_support::render_to_string;
 
     #[test]
     fn dialog_renders_both_ports_and_target() {
-        let backend = TestBackend::new(80, 24);
-        let mut terminal = Terminal::new(backend).unwrap();
         let theme = Theme::default();
 
-        terminal
-            .draw(|frame| {
-                let widget = PortForwardDialogWidget {
-                    pod: "api-7d8b6f5c9f",
-                    namespace: "default",
-                    local_port: "3715",
-                    remote_port: "8080",
-                    active_field: PortForwardFieldView::Remote,
-                    theme: &theme,
-                };
-                widget.render(frame, frame.area());
-            })
-            .unwrap();
-
-        let content = buffer_to_string(terminal.backend().buffer());
+        let content = render_to_string(80, 24, |frame| {
+            let widget = PortForwardDialogWidget {
+                pod: "api-7d8b6f5c9f",
+                namespace: "default",
+                local_port: "3715",
+                remote_port: "8080",
+                active_field: PortForwardFieldView::Remote,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
         assert!(content.contains("Port Forward"));
         assert!(content.contains("api-7d8b6f5c9f"));
         assert!(content.contains("Local port : 3715"));
         assert!(content.contains("Remote port: 8080"));
     }
-
-    fn buffer_to_string(buf: &Buffer) -> String {
-        let mut s = String::new();
-        for y in 0..buf.area.height {
-            for x in 0..buf.area.width {
-                s.push_str(buf[(x, y)].symbol());
-            }
-            s.push('\n');
-        }
-        s
-    }
 }
@@ -7,9 +7,11 @@ use crate::theme::Theme;
 pub struct QueryDialogWidget<'a> {
     pub pod: &'a str,
     pub namespace: &'a str,
+    pub engine: &'a str,
     pub database: &'a str,
     pub user: &'a str,
     pub password: &'a str,
+    pub auth_database: Option<&'a str>,
     pub port: &'a str,
     pub active_field: QueryDialogFieldView,
     pub theme: &'a Theme,
@@ -19,7 +21,7 @@ impl<'a> QueryDialogWidget<'a> {
     pub fn render(self, frame: &mut Frame, area: Rect) {
         let t = self.theme;
         let width = 60.min(area.width.saturating_sub(4));
-        let height = 11.min(area.height.saturating_sub(2));
+        let height = 13.min(area.height.saturating_sub(2));
         let popup = Rect {
             x: area.x + (area.width.saturating_sub(width)) / 2,
             y: area.y + (area.height.saturating_sub(height)) / 2,
@@ -47,7 +49,9 @@ impl<'a> QueryDialogWidget<'a> {
                 Constraint::Length(1), // database
                 Constraint::Length(1), // user
                 Constraint::Length(1), // password
+                Constraint::Length(1), // auth database
                 Constraint::Length(1), // port
+                Constraint::Length(1), // engine
                 Constraint::Length(1), // help
             ])
             .split(inner);
@@ -86,92 +90,109 @@ impl<'a> QueryDialogWidget<'a> {
                 .style(field_style(matches!(self.active_field, QueryDialogFieldView::Password))),
             chunks[4],
         );
+        if let Some(auth_database) = self.auth_database {
+            let auth_text = if auth_database.is_empty() { "_" } else { auth_database };
+            frame.render_widget(
+                Paragraph::new(format!("Auth DB  : {auth_text}"))
+                    .style(field_style(matches!(self.active_field, QueryDialogFieldView::AuthDatabase))),
+                chunks[5],
+            );
+        }
         frame.render_widget(
             Paragraph::new(format!("Port     : {port_text}"))
                 .style(field_style(matches!(self.active_field, QueryDialogFieldView::Port))),
-            chunks[5],
+            chunks[6],
         );
         frame.render_widget(
-            Paragraph::new("Tab next field │ Enter confirm │ Esc cancel")
-                .style(t.text_dim)
-                .alignment(Alignment::Center),
-            chunks[6],
+            Paragraph::new(format!("Engine   : {}", self.engine))
+                .style(field_style(matches!(self.active_field, QueryDialogFieldView::Engine))),
+            chunks[7],
         );
+        let help = if matches!(self.active_field, QueryDialogFieldView::Engine) {
+            "Space switch engine │ Tab next field │ Enter confirm"
+        } else {
+            "Tab next field │ Enter confirm │ Esc cancel"
+        };
+        frame.render_widget(Paragraph::new(help).style(t.text_dim).alignment(Alignment::Center), chunks[8]);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ratatui::backend::TestBackend;
-    use ratatui::buffer::Buffer;
-    use ratatui::Terminal;
-
-    fn buffer_to_string(buf: &Buffer) -> String {
-        let mut s = String::new();
-        for y in 0..buf.area.height {
-            for x in 0..buf.area.width {
-                s.push_str(buf[(x, y)].symbol());
-            }
-            s.push('\n');
-        }
-        s
-    }
+    use crate::widgets::test_support::render_to_string;
 
     #[test]
     fn dialog_renders_all_fields() {
-        let backend = TestBackend::new(80, 24);
-        let mut terminal = Terminal::new(backend).unwrap();
         let theme = Theme::default();
 
-        terminal
-            .draw(|frame| {
-                let widget = QueryDialogWidget {
-                    pod: "postgres-0",
-                    namespace: "kubetile-prod",
-                    database: "appdb",
-                    user: "postgres",
-                    password: "secret",
-                    port: "5432",
-                    active_field: QueryDialogFieldView::Database,
-                    theme: &theme,
-                };
-                widget.render(frame, frame.area());
-            })
-            .unwrap();
-
-        let content = buffer_to_string(terminal.backend().buffer());
+        let content = render_to_string(80, 24, |frame| {
+            let widget = QueryDialogWidget {
+                pod: "postgres-0",
+                namespace: "kubetile-prod",
+                engine: "PostgreSQL",
+                database: "appdb",
+                user: "postgres",
+                password: "secret",
+                auth_database: None,
+                port: "5432",
+                active_field: QueryDialogFieldView::Database,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
         assert!(content.contains("Query Database"));
         assert!(content.contains("postgres-0"));
         assert!(content.contains("Database : appdb"));
         assert!(content.contains("User     : postgres"));
         assert!(content.contains("Password : ***"));
         assert!(content.contains("Port     : 5432"));
+        assert!(content.contains("Engine   : PostgreSQL"));
+        assert!(!content.contains("Auth DB"));
+    }
+
+    #[test]
+    fn mongo_asks_for_the_auth_database() {
+        let theme = Theme::default();
+
+        let content = render_to_string(80, 24, |frame| {
+            let widget = QueryDialogWidget {
+                pod: "mongo-0",
+                namespace: "ns",
+                engine: "MongoDB",
+                database: "shop",
+                user: "root",
+                password: "pw",
+                auth_database: Some("admin"),
+                port: "27017",
+                active_field: QueryDialogFieldView::AuthDatabase,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains("Auth DB  : admin"));
+        assert!(content.contains("Port     : 27017"));
     }
 
     #[test]
     fn password_always_masked() {
-        let backend = TestBackend::new(80, 24);
-        let mut terminal = Terminal::new(backend).unwrap();
         let theme = Theme::default();
 
-        terminal
-            .draw(|frame| {
-                let widget = QueryDialogWidget {
-                    pod: "pg",
-                    namespace: "ns",
-                    database: "db",
-                    user: "u",
-                    password: "super-secret",
-                    port: "5432",
-                    active_field: QueryDialogFieldView::Password,
-                    theme: &theme,
-                };
-                widget.render(frame, frame.area());
-            })
-            .unwrap();
-
-        let content = buffer_to_string(terminal.backend().buffer());
+        let content = render_to_string(80, 24, |frame| {
+            let widget = QueryDialogWidget {
+                pod: "pg",
+                namespace: "ns",
+                engine: "MySQL/MariaDB",
+                database: "db",
+                user: "u",
+                password: "super-secret",
+                auth_database: None,
+                port: "5432",
+                active_field: QueryDialogFieldView::Password,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
         assert!(!content.contains("super-secret"));
         assert!(content.contains("***"));
     }
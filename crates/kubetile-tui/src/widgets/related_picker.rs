@@ -0,0 +1,83 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::Theme;
+
+pub struct RelatedPickerView<'a> {
+    /// The resource the related items belong to, e.g. `Pod api-7d9f`.
+    pub subject: &'a str,
+    pub items: &'a [String],
+    pub selected: usize,
+}
+
+pub struct RelatedPickerWidget<'a> {
+    pub view: &'a RelatedPickerView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> RelatedPickerWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 60.min(area.width.saturating_sub(4));
+        let height = (view.items.len() as u16 + 5).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" Go to Related ")
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        frame.render_widget(Paragraph::new(format!(" {}", view.subject)).style(t.text_dim), chunks[0]);
+
+        let items: Vec<ListItem> = view
+            .items
+            .iter()
+            .map(|label| ListItem::new(Span::styled(format!("  {label}"), Style::default().fg(t.fg))))
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.items.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[1], &mut list_state);
+
+        frame.render_widget(Paragraph::new(" Enter:go  Esc:cancel").style(t.text_dim), chunks[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn picker_lists_related_resources() {
+        let theme = Theme::default();
+        let items = vec!["Deployment api".to_string(), "Node worker-1".to_string()];
+        let view = RelatedPickerView { subject: "Pod api-5d8f9c7b6-x7k2p", items: &items, selected: 0 };
+
+        let content = render_to_string(80, 20, |frame| {
+            RelatedPickerWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("Go to Related"));
+        assert!(content.contains("Pod api-5d8f9c7b6-x7k2p"));
+        assert!(content.contains("  Deployment api"));
+        assert!(content.contains("  Node worker-1"));
+    }
+}
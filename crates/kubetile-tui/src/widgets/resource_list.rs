@@ -10,13 +10,26 @@ pub struct ResourceListWidget<'a> {
     pub selected: Option<usize>,
     pub scroll_offset: usize,
     pub loading: bool,
+    /// Rows are shown but the initial list is still being paged in.
+    pub loading_more: bool,
+    /// Size of the whole list while `loading_more`, once the server has reported it.
+    pub expected_count: Option<usize>,
     pub error: Option<&'a str>,
+    /// Rows are from the last snapshot; the watcher is retrying after an error.
+    pub stale: bool,
     pub focused: bool,
     pub filter_text: Option<&'a str>,
     pub sort_column: Option<usize>,
     pub sort_ascending: bool,
     pub total_count: usize,
     pub all_namespaces: bool,
+    /// Resource kind used to pick `[theme.status_rules]` for the STATUS column.
+    pub status_kind: &'a str,
+    /// Style for each row in `items` order, such as dimming terminating resources. Rows past the
+    /// end of the slice use the default style.
+    pub row_styles: &'a [Style],
+    /// Key hint shown under the message of an empty list, e.g. how to switch resource or filter.
+    pub empty_hint: Option<&'a str>,
     pub theme: &'a Theme,
 }
 
@@ -26,17 +39,23 @@ impl<'a> ResourceListWidget<'a> {
         let border_color = if self.focused { t.accent } else { t.border.fg.unwrap_or(Color::Reset) };
 
         let title_suffix = if self.all_namespaces { " (All Namespaces)" } else { "" };
-        let count_display = if self.filter_text.is_some() {
-            format!(" {}/{} ", self.items.len(), self.total_count)
-        } else {
-            format!(" {} ", self.total_count)
+        let loading_suffix = if self.loading_more { " loading…" } else { "" };
+        let count_display = match (self.filter_text, self.expected_count) {
+            (Some(_), _) => format!(" {}/{}{loading_suffix} ", self.items.len(), self.total_count),
+            (None, Some(expected)) if self.loading_more => format!(" loaded {}/{expected}… ", self.total_count),
+            (None, _) => format!(" {}{loading_suffix} ", self.total_count),
         };
 
+        let mut title =
+            vec![Span::styled(format!(" {}{} ", self.title, title_suffix), Style::default().fg(t.accent).bold())];
+        if self.stale {
+            title.push(Span::styled("stale (retrying…) ", t.status_failed));
+        }
+
         let block = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(border_color))
-            .title(format!(" {}{} ", self.title, title_suffix))
-            .title_style(Style::default().fg(t.accent).bold())
+            .title(Line::from(title))
             .title_bottom(Line::from(count_display).right_aligned().style(t.text_dim));
 
         if self.loading {
@@ -69,15 +88,13 @@ impl<'a> ResourceListWidget<'a> {
             frame.render_widget(Paragraph::new(filter_line), filter_area);
         }
 
-        if self.items.is_empty() && self.filter_text.is_none() {
-            let msg = Paragraph::new("No resources found").style(t.text_dim);
-            frame.render_widget(msg, content_area);
-            return;
-        }
-
         if self.items.is_empty() {
-            let msg = Paragraph::new("No matches").style(t.text_dim);
-            frame.render_widget(msg, content_area);
+            let msg = if self.filter_text.is_none() { "No resources found" } else { "No matches" };
+            let mut lines = vec![Line::styled(msg, t.text_dim)];
+            if let Some(hint) = self.empty_hint.filter(|h| !h.is_empty()) {
+                lines.extend([Line::raw(""), Line::styled(hint, t.text_dim)]);
+            }
+            frame.render_widget(Paragraph::new(lines), content_area);
             return;
         }
 
@@ -102,16 +119,21 @@ impl<'a> ResourceListWidget<'a> {
         let rows: Vec<Row> = self
             .items
             .iter()
-            .map(|item| {
+            .enumerate()
+            .map(|(row_idx, item)| {
                 let cells: Vec<Cell> = item
                     .iter()
                     .enumerate()
                     .map(|(col_idx, val)| {
-                        let style = if Some(col_idx) == status_col { status_style(val, t) } else { Style::default() };
+                        let style = if Some(col_idx) == status_col {
+                            t.status_style(self.status_kind, val)
+                        } else {
+                            Style::default()
+                        };
                         Cell::from(val.as_str()).style(style)
                     })
                     .collect();
-                Row::new(cells)
+                Row::new(cells).style(self.row_styles.get(row_idx).copied().unwrap_or_default())
             })
             .collect();
 
@@ -147,11 +169,161 @@ impl<'a> ResourceListWidget<'a> {
     }
 }
 
-fn status_style(status: &str, theme: &Theme) -> Style {
-    match status {
-        "Running" | "Succeeded" => theme.status_running,
-        "Pending" | "ContainerCreating" => theme.status_pending,
-        "Failed" | "Error" | "CrashLoopBackOff" | "ImagePullBackOff" => theme.status_failed,
-        _ => theme.status_pending,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::{buffer_to_string, render_buffer, render_to_string};
+
+    #[test]
+    fn stale_list_keeps_rows_and_marks_header() {
+        let theme = Theme::default();
+        let headers = vec!["NAME".to_string(), "STATUS".to_string()];
+        let row = vec!["nginx".to_string(), "Running".to_string()];
+        let items = [&row];
+
+        let content = render_to_string(80, 8, |frame| {
+            let widget = ResourceListWidget {
+                title: "Pods",
+                headers: &headers,
+                items: &items,
+                selected: Some(0),
+                scroll_offset: 0,
+                loading: false,
+                loading_more: false,
+                expected_count: None,
+                error: None,
+                stale: true,
+                focused: true,
+                filter_text: None,
+                sort_column: None,
+                sort_ascending: true,
+                total_count: 1,
+                all_namespaces: false,
+                status_kind: "Pods",
+                row_styles: &[],
+                empty_hint: None,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains(" Pods stale (retrying…)"));
+        assert!(content.contains("nginx"));
+    }
+
+    #[test]
+    fn row_styles_color_whole_rows_but_keep_status_cell_style() {
+        let theme = Theme::default();
+        let headers = vec!["NAME".to_string(), "STATUS".to_string()];
+        let failed = vec!["broken".to_string(), "Failed".to_string()];
+        let healthy = vec!["fine".to_string(), "Running".to_string()];
+        let items = [&failed, &healthy];
+        let dim = Style::default().fg(Color::DarkGray);
+
+        let buf = render_buffer(60, 6, |frame| {
+            let widget = ResourceListWidget {
+                title: "Pods",
+                headers: &headers,
+                items: &items,
+                selected: None,
+                scroll_offset: 0,
+                loading: false,
+                loading_more: false,
+                expected_count: None,
+                error: None,
+                stale: false,
+                focused: true,
+                filter_text: None,
+                sort_column: None,
+                sort_ascending: true,
+                total_count: 2,
+                all_namespaces: false,
+                status_kind: "Pods",
+                row_styles: &[dim],
+                empty_hint: None,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        let find = |text: &str| {
+            let content = buffer_to_string(&buf);
+            let (y, line) = content.lines().enumerate().find(|(_, l)| l.contains(text)).unwrap();
+            let x = line[..line.find(text).unwrap()].chars().count();
+            (x as u16, y as u16)
+        };
+        let (x, y) = find("broken");
+        assert_eq!(buf[(x, y)].fg, Color::DarkGray);
+        let (x, y) = find("Failed");
+        assert_eq!(buf[(x, y)].fg, theme.status_failed.fg.unwrap());
+        let (x, y) = find("fine");
+        assert_eq!(buf[(x, y)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn empty_list_shows_the_key_hint() {
+        let theme = Theme::default();
+        let headers = vec!["NAME".to_string()];
+
+        let content = render_to_string(80, 8, |frame| {
+            let widget = ResourceListWidget {
+                title: "Pods",
+                headers: &headers,
+                items: &[],
+                selected: None,
+                scroll_offset: 0,
+                loading: false,
+                loading_more: false,
+                expected_count: None,
+                error: None,
+                stale: false,
+                focused: true,
+                filter_text: None,
+                sort_column: None,
+                sort_ascending: true,
+                total_count: 0,
+                all_namespaces: false,
+                status_kind: "Pods",
+                row_styles: &[],
+                empty_hint: Some("Press : to switch resource, / to filter"),
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains("No resources found"));
+        assert!(content.contains("Press : to switch resource, / to filter"));
+    }
+
+    #[test]
+    fn paged_list_shows_loaded_out_of_expected() {
+        let theme = Theme::default();
+        let headers = vec!["NAME".to_string()];
+        let row = vec!["nginx".to_string()];
+        let items = [&row];
+
+        let content = render_to_string(60, 6, |frame| {
+            let widget = ResourceListWidget {
+                title: "Pods",
+                headers: &headers,
+                items: &items,
+                selected: Some(0),
+                scroll_offset: 0,
+                loading: false,
+                loading_more: true,
+                expected_count: Some(8200),
+                error: None,
+                stale: false,
+                focused: true,
+                filter_text: None,
+                sort_column: None,
+                sort_ascending: true,
+                total_count: 500,
+                all_namespaces: false,
+                status_kind: "Pods",
+                row_styles: &[],
+                empty_hint: None,
+                theme: &theme,
+            };
+            widget.render(frame, frame.area());
+        });
+        assert!(content.contains(" loaded 500/8200… "));
     }
 }
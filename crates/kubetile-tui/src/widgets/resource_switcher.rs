@@ -1,6 +1,7 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 
+use crate::fuzzy::highlight;
 use crate::pane::ResourceKind;
 use crate::theme::Theme;
 
@@ -53,10 +54,13 @@ impl<'a> ResourceSwitcherWidget<'a> {
                 let marker = if i == self.selected { "> " } else { "  " };
                 let short = kind.short_name();
                 let display = kind.display_name();
-                let text = format!("{marker}{short:<8} {display}");
                 let style =
                     if i == self.selected { Style::default().fg(t.accent).bold() } else { Style::default().fg(t.fg) };
-                ListItem::new(text).style(style)
+                let mut spans = vec![Span::styled(marker, style)];
+                spans.extend(highlight(short, self.input, style, t));
+                spans.push(Span::styled(" ".repeat(8usize.saturating_sub(short.len()) + 1), style));
+                spans.extend(highlight(display, self.input, style, t));
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
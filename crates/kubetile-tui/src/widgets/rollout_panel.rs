@@ -0,0 +1,78 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme::Theme;
+
+/// Rollouts being followed after a restart, drawn in the top-right corner of the body.
+pub struct RolloutPanelView<'a> {
+    /// `(target, progress)`, e.g. `("deploy web/api", "2 of 3 updated, 3 available  12s")`.
+    pub entries: &'a [(String, String)],
+}
+
+pub struct RolloutPanelWidget<'a> {
+    pub view: &'a RolloutPanelView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> RolloutPanelWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let entries = self.view.entries;
+        if entries.is_empty() {
+            return;
+        }
+        let target_width = entries.iter().map(|(target, _)| target.len()).max().unwrap_or(0);
+        let content_width = entries.iter().map(|(_, progress)| target_width + 2 + progress.len()).max().unwrap_or(0);
+        let width = (content_width as u16 + 2).max(30).min(area.width.saturating_sub(2));
+        let height = (entries.len() as u16 + 2).min(area.height);
+        let panel = Rect { x: area.x + area.width.saturating_sub(width + 1), y: area.y, width, height };
+
+        frame.render_widget(Clear, panel);
+
+        let block = Block::default()
+            .title(" Rollouts ")
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(panel);
+        frame.render_widget(block, panel);
+
+        let lines: Vec<Line> = entries
+            .iter()
+            .map(|(target, progress)| {
+                Line::from(vec![
+                    Span::styled(format!("{target:<target_width$}  "), Style::default().fg(t.fg).bold()),
+                    Span::styled(progress.as_str(), t.text_dim),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn rollout_panel_lists_followed_rollouts_top_right() {
+        let theme = Theme::default();
+        let entries = vec![
+            ("deploy web/api".to_string(), "2 of 3 updated, 3 available  12s".to_string()),
+            ("deploy web/worker".to_string(), "waiting for the controller  1s".to_string()),
+        ];
+        let view = RolloutPanelView { entries: &entries };
+
+        let content = render_to_string(100, 20, |frame| {
+            RolloutPanelWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        let first_line = content.lines().next().unwrap();
+        assert!(first_line.contains("Rollouts"));
+        assert!(first_line.starts_with("   "), "panel sits on the right: {first_line:?}");
+        assert!(content.contains("deploy web/api     2 of 3 updated, 3 available  12s"));
+        assert!(content.contains("deploy web/worker  waiting for the controller  1s"));
+    }
+}
@@ -0,0 +1,119 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::theme::Theme;
+
+/// Image editor for the containers of a Deployment, StatefulSet or DaemonSet.
+pub struct SetImageDialogView<'a> {
+    /// `deploy web/api`
+    pub target: &'a str,
+    /// `(container, current image, edited image)`; init containers are named `name (init)`.
+    pub containers: &'a [(String, String, String)],
+    pub selected: usize,
+    pub error: Option<&'a str>,
+}
+
+pub struct SetImageDialogWidget<'a> {
+    pub view: &'a SetImageDialogView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> SetImageDialogWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 80.min(area.width.saturating_sub(4));
+        let height = (view.containers.len() as u16 + 7).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" Set Image: {} ", view.target))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(1),    // containers
+                Constraint::Length(1), // current image
+                Constraint::Length(2), // error
+                Constraint::Length(1), // help
+            ])
+            .split(inner);
+
+        let name_width = view.containers.iter().map(|(name, _, _)| name.len()).max().unwrap_or(0);
+        let lines: Vec<Line> = view
+            .containers
+            .iter()
+            .enumerate()
+            .map(|(i, (name, current, edited))| {
+                let changed = edited != current;
+                let marker = if changed { "* " } else { "  " };
+                let mut spans = vec![
+                    Span::styled(marker, Style::default().fg(t.accent)),
+                    Span::styled(format!("{name:<name_width$}  "), t.text_dim),
+                ];
+                if i == view.selected {
+                    spans.push(Span::styled(edited.as_str(), Style::default().fg(t.fg).bold()));
+                    spans.push(Span::styled("_", Style::default().fg(t.accent)));
+                } else {
+                    spans.push(Span::styled(edited.as_str(), Style::default().fg(t.fg)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), chunks[0]);
+
+        if let Some((_, current, _)) = view.containers.get(view.selected) {
+            frame.render_widget(Paragraph::new(format!("  was {current}")).style(t.text_dim), chunks[1]);
+        }
+        if let Some(error) = view.error {
+            frame.render_widget(
+                Paragraph::new(Span::styled(format!("Error: {error}"), t.status_failed)).wrap(Wrap { trim: true }),
+                chunks[2],
+            );
+        }
+
+        frame.render_widget(
+            Paragraph::new("↑↓ container │ Enter apply │ Esc cancel").style(t.text_dim).alignment(Alignment::Center),
+            chunks[3],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn dialog_lists_containers_and_marks_edits() {
+        let theme = Theme::default();
+        let containers = vec![
+            ("migrate (init)".to_string(), "web/api:1.4".to_string(), "web/api:1.4".to_string()),
+            ("api".to_string(), "web/api:1.4".to_string(), "web/api:1.5".to_string()),
+        ];
+        let view = SetImageDialogView { target: "deploy web/api", containers: &containers, selected: 1, error: None };
+
+        let content = render_to_string(90, 20, |frame| {
+            SetImageDialogWidget { view: &view, theme: &theme }.render(frame, frame.area())
+        });
+        assert!(content.contains("Set Image: deploy web/api"));
+        assert!(content.contains("  migrate (init)  web/api:1.4 "));
+        assert!(content.contains("* api             web/api:1.5_"));
+        assert!(content.contains("was web/api:1.4"));
+        assert!(content.contains("Enter apply"));
+    }
+}
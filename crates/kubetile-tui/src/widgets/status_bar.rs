@@ -3,9 +3,52 @@ use ratatui::widgets::Paragraph;
 
 use crate::theme::Theme;
 
+/// Recent Warning events scrolling through the free space at the right of the status bar.
+pub struct WarningTickerView<'a> {
+    pub text: &'a str,
+    /// Characters the text has scrolled by; it only scrolls when it does not fit.
+    pub offset: usize,
+}
+
+/// API server round trip and the focused list's watcher, shown after the context.
+pub struct ConnectionHealthView {
+    /// Last measured round trip to the API server in milliseconds; `None` when it failed.
+    pub latency_ms: Option<u64>,
+    /// Time since the focused list's watcher last delivered rows, e.g. `12s`.
+    pub last_event: Option<String>,
+    /// The watcher reported an error and the rows are the last snapshot.
+    pub watch_stale: bool,
+}
+
+/// Pane memory against the configured budget, shown while background streams are suspended.
+pub struct MemoryBudgetView {
+    pub used_mb: u64,
+    pub budget_mb: u64,
+    /// Log streams and watchers of hidden panes paused to stay within the budget.
+    pub suspended: usize,
+}
+
+/// Round trips up to this are shown as healthy; up to four times it as slow.
+const FAST_LATENCY_MS: u64 = 250;
+
+/// Blank run between the end of the ticker text and its start coming round again.
+const TICKER_GAP: &str = "   ";
+
+impl WarningTickerView<'_> {
+    /// The part of the ticker visible in `width` columns.
+    pub fn window(&self, width: usize) -> String {
+        if self.text.chars().count() <= width {
+            return self.text.to_string();
+        }
+        let looped: Vec<char> = self.text.chars().chain(TICKER_GAP.chars()).collect();
+        looped.iter().cycle().skip(self.offset % looped.len()).take(width).collect()
+    }
+}
+
 pub struct StatusBarWidget<'a> {
     pub mode: &'a str,
     pub context: Option<&'a str>,
+    pub read_only: bool,
     pub help_key: Option<&'a str>,
     pub pane_help_key: Option<&'a str>,
     pub namespace_key: Option<&'a str>,
@@ -13,6 +56,9 @@ pub struct StatusBarWidget<'a> {
     pub close_pane_key: Option<&'a str>,
     pub new_tab_key: Option<&'a str>,
     pub quit_key: Option<&'a str>,
+    pub warning_ticker: Option<&'a WarningTickerView<'a>>,
+    pub health: Option<&'a ConnectionHealthView>,
+    pub memory: Option<&'a MemoryBudgetView>,
     pub theme: &'a Theme,
 }
 
@@ -23,13 +69,19 @@ impl<'a> StatusBarWidget<'a> {
         let status_bg = t.status_bar.bg.unwrap_or(Color::Reset);
         let status_fg = t.status_bar.fg.unwrap_or(Color::Reset);
         let sep = Style::default().fg(t.border.fg.unwrap_or(Color::Reset)).bg(status_bg);
-        let key_style = Style::default().fg(t.accent).bg(status_bg);
+        // A context accent fills the whole bar, so keys and the mode badge can't use it too.
+        let key_style = match t.context_accent {
+            Some(_) => Style::default().fg(status_fg).bg(status_bg).add_modifier(Modifier::BOLD),
+            None => Style::default().fg(t.accent).bg(status_bg),
+        };
         let desc_style = Style::default().fg(status_fg).bg(status_bg);
         let mut spans = Vec::new();
 
         let is_insert = self.mode.eq_ignore_ascii_case("insert");
         let mode_style = if is_insert {
             t.insert_mode.add_modifier(Modifier::BOLD)
+        } else if t.context_accent.is_some() {
+            Style::default().fg(t.accent).bg(header_bg).add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(header_bg).bg(t.accent).add_modifier(Modifier::BOLD)
         };
@@ -39,7 +91,48 @@ impl<'a> StatusBarWidget<'a> {
         let ctx_raw = self.context.unwrap_or("no-context");
         let ctx_text: String = if ctx_raw.len() > 15 { format!("{}…", &ctx_raw[..14]) } else { ctx_raw.to_string() };
         spans.push(Span::styled(" │ ", sep));
-        spans.push(Span::styled(ctx_text, Style::default().fg(status_fg).bg(status_bg).add_modifier(Modifier::DIM)));
+        let ctx_modifier = if t.context_accent.is_some() { Modifier::BOLD } else { Modifier::DIM };
+        spans.push(Span::styled(ctx_text, Style::default().fg(status_fg).bg(status_bg).add_modifier(ctx_modifier)));
+        if self.read_only {
+            spans.push(Span::styled(" ", Style::default().bg(status_bg)));
+            let failed_fg = t.status_failed.fg.unwrap_or(Color::Red);
+            spans.push(Span::styled(
+                " READ-ONLY ",
+                Style::default().fg(header_bg).bg(failed_fg).add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if let Some(health) = self.health {
+            let running = t.status_running.fg.unwrap_or(Color::Green);
+            let pending = t.status_pending.fg.unwrap_or(Color::Yellow);
+            let failed = t.status_failed.fg.unwrap_or(Color::Red);
+            let (latency, color) = match health.latency_ms {
+                Some(ms) if ms <= FAST_LATENCY_MS => (format!("API {ms}ms"), running),
+                Some(ms) if ms <= FAST_LATENCY_MS * 4 => (format!("API {ms}ms"), pending),
+                Some(ms) => (format!("API {ms}ms"), failed),
+                None => ("API ✗".to_string(), failed),
+            };
+            spans.push(Span::styled(" │ ", sep));
+            spans.push(Span::styled(latency, Style::default().fg(color).bg(status_bg)));
+            if let Some(age) = &health.last_event {
+                let (text, style) = if health.watch_stale {
+                    (format!(" · watch failing, last event {age} ago"), Style::default().fg(failed).bg(status_bg))
+                } else {
+                    (format!(" · last event {age} ago"), desc_style)
+                };
+                spans.push(Span::styled(text, style));
+            }
+        }
+
+        if let Some(memory) = self.memory {
+            let pending = t.status_pending.fg.unwrap_or(Color::Yellow);
+            let noun = if memory.suspended == 1 { "stream" } else { "streams" };
+            spans.push(Span::styled(" │ ", sep));
+            spans.push(Span::styled(
+                format!("MEM {}/{}MB · {} {noun} paused", memory.used_mb, memory.budget_mb, memory.suspended),
+                Style::default().fg(pending).bg(status_bg),
+            ));
+        }
 
         let keybindings: &[(&str, Option<&str>)] = &[
             ("Help", self.help_key),
@@ -59,6 +152,22 @@ impl<'a> StatusBarWidget<'a> {
             }
         }
 
+        if let Some(ticker) = self.warning_ticker {
+            let used: usize = spans.iter().map(|s| s.width()).sum();
+            let badge = " WARN ";
+            let room = (area.width as usize).saturating_sub(used + 3 + badge.len() + 1);
+            if room > 0 {
+                let pending_fg = t.status_pending.fg.unwrap_or(Color::Yellow);
+                spans.push(Span::styled(" │ ", sep));
+                spans.push(Span::styled(
+                    badge,
+                    Style::default().fg(header_bg).bg(pending_fg).add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(" ", Style::default().bg(status_bg)));
+                spans.push(Span::styled(ticker.window(room), Style::default().fg(pending_fg).bg(status_bg)));
+            }
+        }
+
         let left_used: u16 = spans.iter().map(|s| s.width() as u16).sum();
         let fill = area.width.saturating_sub(left_used);
         if fill > 0 {
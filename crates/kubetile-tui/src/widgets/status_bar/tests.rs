@@ -1,16 +1,8 @@
 use super::*;
-use ratatui::backend::TestBackend;
-use ratatui::Terminal;
+use crate::widgets::test_support::render_buffer;
 
 fn render(widget: &StatusBarWidget, width: u16) -> ratatui::buffer::Buffer {
-    let backend = TestBackend::new(width, 1);
-    let mut terminal = Terminal::new(backend).unwrap();
-    terminal
-        .draw(|frame| {
-            widget.render(frame, frame.area());
-        })
-        .unwrap();
-    terminal.backend().buffer().clone()
+    render_buffer(width, 1, |frame| widget.render(frame, frame.area()))
 }
 
 fn buf_text(buf: &ratatui::buffer::Buffer) -> String {
@@ -21,6 +13,7 @@ fn default_widget(theme: &Theme) -> StatusBarWidget<'_> {
     StatusBarWidget {
         mode: "Normal",
         context: Some("minikube"),
+        read_only: false,
         help_key: Some("F1"),
         pane_help_key: Some("F2"),
         namespace_key: Some("Ctrl+N"),
@@ -28,6 +21,9 @@ fn default_widget(theme: &Theme) -> StatusBarWidget<'_> {
         close_pane_key: Some("Alt+X"),
         new_tab_key: Some("Ctrl+T"),
         quit_key: Some("Ctrl+Q"),
+        warning_ticker: None,
+        health: None,
+        memory: None,
         theme,
     }
 }
@@ -100,3 +96,75 @@ fn insert_mode_has_distinct_style() {
     let insert_bg = buf_insert.cell((1, 0)).unwrap().bg;
     assert_ne!(normal_bg, insert_bg, "Insert mode should have a different background color");
 }
+
+#[test]
+fn shows_read_only_badge_only_when_set() {
+    let theme = Theme::default();
+    let mut w = default_widget(&theme);
+    assert!(!buf_text(&render(&w, 150)).contains("READ-ONLY"));
+
+    w.read_only = true;
+    assert!(buf_text(&render(&w, 150)).contains("READ-ONLY"));
+}
+
+#[test]
+fn warning_ticker_fills_the_free_space() {
+    let theme = Theme::default();
+    let ticker = WarningTickerView { text: "BackOff Pod/api-0: Back-off restarting failed container", offset: 0 };
+    let mut w = default_widget(&theme);
+    w.warning_ticker = Some(&ticker);
+    let text = buf_text(&render(&w, 200));
+    assert!(text.contains(" WARN  BackOff Pod/api-0: Back-off restarting failed container"));
+}
+
+#[test]
+fn warning_ticker_scrolls_when_it_does_not_fit() {
+    let short = WarningTickerView { text: "BackOff", offset: 3 };
+    assert_eq!(short.window(10), "BackOff");
+
+    let long = WarningTickerView { text: "abcdef", offset: 4 };
+    assert_eq!(long.window(4), "ef  ");
+    let wrapped = WarningTickerView { text: "abcdef", offset: 8 };
+    assert_eq!(wrapped.window(4), " abc");
+}
+
+#[test]
+fn context_accent_fills_the_bar() {
+    let mut theme = Theme::default();
+    theme.context_accents.push(("minikube".into(), Color::Red));
+    let theme = theme.for_context(Some("minikube"));
+    let w = default_widget(&theme);
+    let buf = render(&w, 120);
+    let last = buf.content().last().unwrap();
+    assert_eq!(last.bg, Color::Red);
+    let f1 = buf_text(&buf).find("F1").unwrap();
+    assert_ne!(buf.content()[f1].fg, Color::Red, "keys stay readable on the accent");
+}
+
+#[test]
+fn health_shows_latency_and_last_event_age() {
+    let theme = Theme::default();
+    let healthy = ConnectionHealthView { latency_ms: Some(42), last_event: Some("12s".into()), watch_stale: false };
+    let mut w = default_widget(&theme);
+    w.health = Some(&healthy);
+    let text = buf_text(&render(&w, 200));
+    assert!(text.contains("minikube │ API 42ms · last event 12s ago │ F1"));
+
+    let failing = ConnectionHealthView { latency_ms: None, last_event: Some("5m".into()), watch_stale: true };
+    w.health = Some(&failing);
+    let buf = render(&w, 200);
+    let text = buf_text(&buf);
+    assert!(text.contains("API ✗ · watch failing, last event 5m ago"));
+    let api = text[..text.find("API").unwrap()].chars().count();
+    assert_eq!(buf.content()[api].fg, theme.status_failed.fg.unwrap());
+}
+
+#[test]
+fn memory_budget_shows_usage_and_paused_streams() {
+    let theme = Theme::default();
+    let memory = MemoryBudgetView { used_mb: 612, budget_mb: 512, suspended: 3 };
+    let mut w = default_widget(&theme);
+    w.memory = Some(&memory);
+    let text = buf_text(&render(&w, 200));
+    assert!(text.contains("minikube │ MEM 612/512MB · 3 streams paused │ F1"));
+}
@@ -1,19 +1,9 @@
 use super::*;
-use ratatui::backend::TestBackend;
-use ratatui::Terminal;
+use crate::widgets::test_support::render_buffer;
 
 fn render_tab_bar(tabs: &[String], active: usize, width: u16) -> ratatui::buffer::Buffer {
-    let backend = TestBackend::new(width, 1);
-    let mut terminal = Terminal::new(backend).unwrap();
-    terminal
-        .draw(|frame| {
-            let area = frame.area();
-            let theme = Theme::default();
-            let widget = TabBarWidget { tabs, active, theme: &theme };
-            widget.render(frame, area);
-        })
-        .unwrap();
-    terminal.backend().buffer().clone()
+    let theme = Theme::default();
+    render_buffer(width, 1, |frame| TabBarWidget { tabs, active, theme: &theme }.render(frame, frame.area()))
 }
 
 fn buf_text(buf: &ratatui::buffer::Buffer) -> String {
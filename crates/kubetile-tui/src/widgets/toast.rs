@@ -38,6 +38,15 @@ impl ToastMessage {
     }
 }
 
+/// A toast kept after it expires, for the notification center.
+#[derive(Clone, Debug)]
+pub struct ToastRecord {
+    /// Wall-clock time it was shown, already formatted.
+    pub time: String,
+    pub level: ToastLevel,
+    pub text: String,
+}
+
 pub struct ToastWidget<'a> {
     pub toasts: &'a [ToastMessage],
     pub theme: &'a Theme,
@@ -0,0 +1,81 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::theme::Theme;
+
+/// Recently deleted resources that undo can still recreate.
+pub struct UndoListView<'a> {
+    /// Newest first, e.g. `deploy web/api  deleted 2m ago, 8m left`.
+    pub items: &'a [String],
+    pub selected: usize,
+}
+
+pub struct UndoListWidget<'a> {
+    pub view: &'a UndoListView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> UndoListWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let view = self.view;
+        let width = 70.min(area.width.saturating_sub(4));
+        let height = (view.items.len() as u16 + 3).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(format!(" Undo Delete ({}) ", view.items.len()))
+            .title_style(Style::default().fg(t.accent).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(t.accent))
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(inner);
+
+        let items: Vec<ListItem> = view
+            .items
+            .iter()
+            .map(|label| ListItem::new(Span::styled(format!("  {label}"), Style::default().fg(t.fg))))
+            .collect();
+        let list = List::new(items).highlight_style(t.selection.add_modifier(Modifier::BOLD));
+        let mut list_state =
+            ListState::default().with_selected(Some(view.selected.min(view.items.len().saturating_sub(1))));
+        frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+        frame.render_widget(Paragraph::new(" Enter:recreate  Esc:close").style(t.text_dim), chunks[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::widgets::test_support::render_to_string;
+
+    #[test]
+    fn undo_list_shows_deleted_resources() {
+        let theme = Theme::default();
+        let items = vec!["deploy web/api  deleted 2m ago, 8m left".to_string(), "cm web/settings".to_string()];
+        let view = UndoListView { items: &items, selected: 0 };
+
+        let content = render_to_string(80, 20, |frame| {
+            UndoListWidget { view: &view, theme: &theme }.render(frame, frame.area());
+        });
+        assert!(content.contains("Undo Delete (2)"));
+        assert!(content.contains("  deploy web/api  deleted 2m ago, 8m left"));
+        assert!(content.contains("  cm web/settings"));
+        assert!(content.contains("Enter:recreate"));
+    }
+}
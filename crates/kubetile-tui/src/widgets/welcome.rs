@@ -0,0 +1,82 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::theme::Theme;
+
+/// First-run overlay shown while no config file exists.
+pub struct WelcomeView<'a> {
+    /// Core shortcuts as `(key, description)`, from the active keymap.
+    pub entries: &'a [(String, String)],
+    /// Where `w` writes the default config.
+    pub config_path: &'a str,
+}
+
+pub struct WelcomeWidget<'a> {
+    pub view: &'a WelcomeView<'a>,
+    pub theme: &'a Theme,
+}
+
+impl<'a> WelcomeWidget<'a> {
+    pub fn render(self, frame: &mut Frame, area: Rect) {
+        let t = self.theme;
+        let entries = self.view.entries;
+        let key_col_width = entries.iter().map(|(k, _)| k.len()).max().unwrap_or(8) + 2;
+
+        let intro = [
+            "No config file found, so the built-in defaults are in use.".to_string(),
+            format!("Press w to write them to {}", self.view.config_path),
+        ];
+        let content_width = entries
+            .iter()
+            .map(|(_, d)| key_col_width + d.len())
+            .chain(intro.iter().map(|l| l.len()))
+            .max()
+            .unwrap_or(0) as u16;
+        let width = (content_width + 4).max(50).min(area.width.saturating_sub(4));
+        let height = (entries.len() as u16 + 7).min(area.height.saturating_sub(2));
+        let popup = Rect {
+            x: area.x + (area.width.saturating_sub(width)) / 2,
+            y: area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+
+        let block = Block::default()
+            .title(" Welcome to kubetile ")
+            .title_style(Style::default().fg(t.accent).add_modifier(Modifier::BOLD))
+            .borders(Borders::ALL)
+            .border_style(t.border_active)
+            .style(t.overlay);
+
+        let inner = block.inner(popup);
+        frame.render_widget(block, popup);
+        if inner.height < 2 {
+            return;
+        }
+
+        let key_style = Style::default().fg(t.accent).add_modifier(Modifier::BOLD);
+        let mut lines: Vec<Line> = intro.iter().map(|l| Line::styled(l.as_str(), Style::default().fg(t.fg))).collect();
+        lines.push(Line::raw(""));
+        lines.extend(entries.iter().map(|(key, desc)| {
+            Line::from(vec![
+                Span::styled(format!("{key:<key_col_width$}"), key_style),
+                Span::styled(desc.as_str(), t.text_dim),
+            ])
+        }));
+
+        let list_area = Rect { height: inner.height - 1, ..inner };
+        let footer_area = Rect { y: inner.y + inner.height - 1, height: 1, ..inner };
+        frame.render_widget(Paragraph::new(lines), list_area);
+
+        let footer = Paragraph::new(Line::from(vec![
+            Span::styled("w", key_style),
+            Span::styled("  write default config   ", t.text_dim),
+            Span::styled("Esc", key_style),
+            Span::styled("  close", t.text_dim),
+        ]))
+        .alignment(Alignment::Center);
+        frame.render_widget(footer, footer_area);
+    }
+}